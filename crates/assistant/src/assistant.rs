@@ -245,6 +245,10 @@ pub fn init(
     .log_err()
     .map(Arc::new)
     .unwrap_or_else(|| Arc::new(prompts::PromptBuilder::new(None).unwrap()));
+    workspace::PanelRegistry::register(cx, {
+        let prompt_builder = prompt_builder.clone();
+        move |workspace, cx| AssistantPanel::load(workspace, prompt_builder.clone(), cx)
+    });
     register_slash_commands(Some(prompt_builder.clone()), cx);
     register_tools(cx);
     inline_assistant::init(
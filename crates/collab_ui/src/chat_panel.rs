@@ -714,6 +714,17 @@ impl ChatPanel {
                         }
                     }),
                 )
+                .entry(
+                    "Copy message with formatting",
+                    None,
+                    cx.handler_for(this, move |this, cx| {
+                        if let Some(rich_text) =
+                            this.markdown_data.get(&ChannelMessageId::Saved(message_id))
+                        {
+                            cx.write_to_clipboard(rich_text.clipboard_item(cx))
+                        }
+                    }),
+                )
                 .when(can_delete_message, |menu| {
                     menu.entry(
                         "Delete message",
@@ -756,7 +767,7 @@ impl ChatPanel {
             body.push_str(MESSAGE_EDITED);
         }
 
-        let mut rich_text = RichText::new(body, &mentions, language_registry);
+        let mut rich_text = RichText::new(body, &mentions, language_registry).selectable();
 
         if message.edited_at.is_some() {
             let range = (rich_text.text.len() - MESSAGE_EDITED.len())..rich_text.text.len();
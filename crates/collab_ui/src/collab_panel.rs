@@ -32,7 +32,7 @@ use theme::{ActiveTheme, ThemeSettings};
 use ui::{
     prelude::*, tooltip_container, Avatar, AvatarAvailabilityIndicator, Button, Color, ContextMenu,
     Facepile, Icon, IconButton, IconName, IconSize, Indicator, Label, ListHeader, ListItem,
-    Tooltip,
+    OverlayLayer, Tooltip,
 };
 use util::{maybe, ResultExt, TryFutureExt};
 use workspace::{
@@ -2793,7 +2793,7 @@ impl Render for CollabPanel {
                         .anchor(gpui::AnchorCorner::TopLeft)
                         .child(menu.clone()),
                 )
-                .with_priority(1)
+                .with_priority(OverlayLayer::Popover.priority())
             }))
     }
 }
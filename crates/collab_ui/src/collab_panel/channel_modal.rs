@@ -11,7 +11,9 @@ use gpui::{
 };
 use picker::{Picker, PickerDelegate};
 use std::sync::Arc;
-use ui::{prelude::*, Avatar, CheckboxWithLabel, ContextMenu, ListItem, ListItemSpacing};
+use ui::{
+    prelude::*, Avatar, CheckboxWithLabel, ContextMenu, ListItem, ListItemSpacing, OverlayLayer,
+};
 use util::TryFutureExt;
 use workspace::{notifications::DetachAndPromptErr, ModalView};
 
@@ -416,7 +418,7 @@ impl PickerDelegate for ChannelModalDelegate {
                                                 .anchor(gpui::AnchorCorner::TopRight)
                                                 .child(menu.clone()),
                                         )
-                                        .with_priority(1),
+                                        .with_priority(OverlayLayer::Popover.priority()),
                                     )
                                 } else {
                                     None
@@ -19,7 +19,7 @@ pub use panel_settings::{
 use release_channel::ReleaseChannel;
 use settings::Settings;
 use ui::px;
-use workspace::AppState;
+use workspace::{AppState, PanelRegistry};
 
 pub fn init(app_state: &Arc<AppState>, cx: &mut AppContext) {
     CollaborationPanelSettings::register(cx);
@@ -33,6 +33,10 @@ pub fn init(app_state: &Arc<AppState>, cx: &mut AppContext) {
     notification_panel::init(cx);
     notifications::init(app_state, cx);
     title_bar::init(cx);
+
+    PanelRegistry::register(cx, collab_panel::CollabPanel::load);
+    PanelRegistry::register(cx, chat_panel::ChatPanel::load);
+    PanelRegistry::register(cx, notification_panel::NotificationPanel::load);
     vcs_menu::init(cx);
 }
 
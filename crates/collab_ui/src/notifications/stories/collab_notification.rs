@@ -6,6 +6,8 @@ use crate::notifications::collab_notification::CollabNotification;
 
 pub struct CollabNotificationStory;
 
+story::register_story!("collab_notification", CollabNotificationStory);
+
 impl Render for CollabNotificationStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         let window_container = |width, height| div().w(px(width)).h(px(height));
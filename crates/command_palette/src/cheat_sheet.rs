@@ -0,0 +1,126 @@
+use collections::BTreeMap;
+use command_palette_hooks::CommandPaletteFilter;
+use editor::Editor;
+use gpui::{
+    AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView, Render, Subscription, View,
+    ViewContext, VisualContext,
+};
+use ui::{prelude::*, CheatSheet, CheatSheetCategory, CheatSheetEntry, HeaderBar};
+use workspace::{ModalView, Workspace};
+
+use crate::{humanize_action_name, ToggleCheatSheet};
+
+/// A modal listing the key bindings of all available actions, grouped by namespace.
+pub struct CheatSheetModal {
+    query_editor: View<Editor>,
+    categories: Vec<CheatSheetCategory>,
+    previous_focus_handle: FocusHandle,
+    _subscription: Subscription,
+}
+
+impl ModalView for CheatSheetModal {}
+
+impl EventEmitter<DismissEvent> for CheatSheetModal {}
+
+impl FocusableView for CheatSheetModal {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.query_editor.focus_handle(cx)
+    }
+}
+
+impl CheatSheetModal {
+    pub(crate) fn register(workspace: &mut Workspace, _: &mut ViewContext<Workspace>) {
+        workspace.register_action(|workspace, _: &ToggleCheatSheet, cx| {
+            let Some(previous_focus_handle) = cx.focused() else {
+                return;
+            };
+            workspace.toggle_modal(cx, move |cx| Self::new(previous_focus_handle, cx));
+        });
+    }
+
+    fn new(previous_focus_handle: FocusHandle, cx: &mut ViewContext<Self>) -> Self {
+        let filter = CommandPaletteFilter::try_global(cx);
+
+        let mut categories = BTreeMap::<String, Vec<CheatSheetEntry>>::default();
+        for action in cx.available_actions() {
+            if filter.is_some_and(|filter| filter.is_hidden(&*action)) {
+                continue;
+            }
+            if cx
+                .bindings_for_action_in(&*action, &previous_focus_handle)
+                .is_empty()
+            {
+                continue;
+            }
+
+            let name = humanize_action_name(action.name());
+            let (namespace, label) = name.split_once(": ").unwrap_or(("", name.as_str()));
+            let entry = CheatSheetEntry::new(label.to_string(), action);
+            categories
+                .entry(namespace.to_string())
+                .or_default()
+                .push(entry);
+        }
+
+        let query_editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Search shortcuts...", cx);
+            editor
+        });
+        let subscription = cx.subscribe(&query_editor, Self::on_query_editor_event);
+
+        Self {
+            query_editor,
+            categories: categories
+                .into_iter()
+                .map(|(namespace, entries)| CheatSheetCategory::new(namespace).entries(entries))
+                .collect(),
+            previous_focus_handle,
+            _subscription: subscription,
+        }
+    }
+
+    fn on_query_editor_event(
+        &mut self,
+        _: View<Editor>,
+        event: &editor::EditorEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            editor::EditorEvent::Blurred => cx.emit(DismissEvent),
+            editor::EditorEvent::BufferEdited { .. } => cx.notify(),
+            _ => {}
+        }
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        cx.emit(DismissEvent);
+    }
+}
+
+impl Render for CheatSheetModal {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let query = self.query_editor.read(cx).text(cx);
+
+        v_flex()
+            .key_context("CheatSheet")
+            .on_action(cx.listener(Self::cancel))
+            .elevation_3(cx)
+            .w(rems(60.))
+            .max_h(vh(0.7, cx))
+            .overflow_hidden()
+            .child(HeaderBar::new().start_slot(self.query_editor.clone()))
+            .child(
+                div()
+                    .id("cheat-sheet")
+                    .flex_1()
+                    .p_4()
+                    .overflow_y_scroll()
+                    .child(
+                        CheatSheet::new(self.categories.clone())
+                            .query(query)
+                            .key_context(self.previous_focus_handle.clone()),
+                    ),
+            )
+    }
+}
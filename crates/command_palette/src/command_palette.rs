@@ -1,11 +1,8 @@
-use std::{
-    cmp::{self, Reverse},
-    sync::Arc,
-    time::Duration,
-};
+mod cheat_sheet;
+
+use std::{cmp, sync::Arc, time::Duration};
 
 use client::{parse_zed_link, telemetry::Telemetry};
-use collections::HashMap;
 use command_palette_hooks::{
     CommandInterceptResult, CommandPaletteFilter, CommandPaletteInterceptor,
 };
@@ -14,22 +11,25 @@ use gpui::{
     actions, Action, AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView, Global,
     ParentElement, Render, Styled, Task, UpdateGlobal, View, ViewContext, VisualContext, WeakView,
 };
-use picker::{Picker, PickerDelegate};
+use picker::{recent_items::RecentItems, Picker, PickerDelegate, PickerSection};
 
 use postage::{sink::Sink, stream::Stream};
 use settings::Settings;
-use ui::{h_flex, prelude::*, v_flex, HighlightedLabel, KeyBinding, ListItem, ListItemSpacing};
+use ui::{prelude::*, v_flex, KeyBinding, LabelWithDetail, ListItem, ListItemSpacing, PaletteHint};
 use util::ResultExt;
 use workspace::{ModalView, Workspace, WorkspaceSettings};
 use zed_actions::OpenZedUrl;
 
-actions!(command_palette, [Toggle]);
+pub use cheat_sheet::CheatSheetModal;
+
+actions!(command_palette, [Toggle, ToggleCheatSheet]);
 
 pub fn init(cx: &mut AppContext) {
     client::init_settings(cx);
-    cx.set_global(HitCounts::default());
+    cx.set_global(RecentCommands::default());
     command_palette_hooks::init(cx);
     cx.observe_new_views(CommandPalette::register).detach();
+    cx.observe_new_views(CheatSheetModal::register).detach();
 }
 
 impl ModalView for CommandPalette {}
@@ -135,6 +135,8 @@ pub struct CommandPaletteDelegate {
     commands: Vec<Command>,
     matches: Vec<StringMatch>,
     selected_ix: usize,
+    /// The number of recently used commands listed first for an empty query.
+    recent_count: usize,
     telemetry: Arc<Telemetry>,
     previous_focus_handle: FocusHandle,
     updating_matches: Option<(
@@ -157,13 +159,13 @@ impl Clone for Command {
     }
 }
 
-/// Hit count for each command in the palette.
+/// How often and how recently each command in the palette was used.
 /// We only account for commands triggered directly via command palette and not by e.g. keystrokes because
 /// if a user already knows a keystroke for a command, they are unlikely to use a command palette to look for it.
 #[derive(Default, Clone)]
-struct HitCounts(HashMap<String, usize>);
+struct RecentCommands(RecentItems<String>);
 
-impl Global for HitCounts {}
+impl Global for RecentCommands {}
 
 impl CommandPaletteDelegate {
     fn new(
@@ -178,6 +180,7 @@ impl CommandPaletteDelegate {
             matches: vec![],
             commands,
             selected_ix: 0,
+            recent_count: 0,
             telemetry,
             previous_focus_handle,
             updating_matches: None,
@@ -230,6 +233,15 @@ impl CommandPaletteDelegate {
                 },
             )
         }
+        self.recent_count = if query.trim().is_empty() {
+            let recent_commands = &cx.global::<RecentCommands>().0;
+            matches
+                .iter()
+                .take_while(|m| recent_commands.score(&commands[m.candidate_id].name) > 0.)
+                .count()
+        } else {
+            0
+        };
         self.commands = commands;
         self.matches = matches;
         if self.matches.is_empty() {
@@ -271,16 +283,14 @@ impl PickerDelegate for CommandPaletteDelegate {
         let (mut tx, mut rx) = postage::dispatch::channel(1);
         let task = cx.background_executor().spawn({
             let mut commands = self.all_commands.clone();
-            let hit_counts = cx.global::<HitCounts>().clone();
+            let recent_commands = cx.global::<RecentCommands>().clone();
             let executor = cx.background_executor().clone();
             let query = trim_consecutive_whitespaces(query.as_str());
             async move {
-                commands.sort_by_key(|action| {
-                    (
-                        Reverse(hit_counts.0.get(&action.name).cloned()),
-                        action.name.clone(),
-                    )
-                });
+                commands.sort_by(|a, b| a.name.cmp(&b.name));
+                recent_commands
+                    .0
+                    .sort_by_recency(&mut commands, |command| &command.name);
 
                 let candidates = commands
                     .iter()
@@ -378,8 +388,8 @@ impl PickerDelegate for CommandPaletteDelegate {
 
         self.matches.clear();
         self.commands.clear();
-        HitCounts::update_global(cx, |hit_counts, _cx| {
-            *hit_counts.0.entry(command.name).or_default() += 1;
+        RecentCommands::update_global(cx, |recent_commands, _cx| {
+            recent_commands.0.record_use(command.name);
         });
         let action = command.action;
         cx.focus(&self.previous_focus_handle);
@@ -387,6 +397,28 @@ impl PickerDelegate for CommandPaletteDelegate {
         cx.dispatch_action(action);
     }
 
+    fn empty_query_sections(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PickerSection> {
+        if self.recent_count == 0 {
+            return Vec::new();
+        }
+        vec![PickerSection::new("Recently Used", self.recent_count)]
+    }
+
+    fn separators_after_indices(&self) -> Vec<usize> {
+        if self.recent_count > 0 && self.recent_count < self.matches.len() {
+            vec![self.recent_count - 1]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn footer_hints(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PaletteHint> {
+        if self.matches.is_empty() {
+            return Vec::new();
+        }
+        vec![PaletteHint::new("Run", menu::Confirm.boxed_clone())]
+    }
+
     fn render_match(
         &self,
         ix: usize,
@@ -401,19 +433,18 @@ impl PickerDelegate for CommandPaletteDelegate {
                 .spacing(ListItemSpacing::Sparse)
                 .selected(selected)
                 .child(
-                    h_flex()
-                        .w_full()
-                        .py_px()
-                        .justify_between()
-                        .child(HighlightedLabel::new(
-                            command.name.clone(),
-                            r#match.positions.clone(),
-                        ))
-                        .children(KeyBinding::for_action_in(
-                            &*command.action,
-                            &self.previous_focus_handle,
-                            cx,
-                        )),
+                    div().w_full().py_px().child(
+                        LabelWithDetail::new(command.name.clone())
+                            .highlights(r#match.positions.clone())
+                            .when_some(
+                                KeyBinding::for_action_in(
+                                    &*command.action,
+                                    &self.previous_focus_handle,
+                                    cx,
+                                ),
+                                |label, key_binding| label.detail_element(key_binding),
+                            ),
+                    ),
                 ),
         )
     }
@@ -458,7 +489,7 @@ mod tests {
     use super::*;
     use editor::Editor;
     use go_to_line::GoToLine;
-    use gpui::TestAppContext;
+    use gpui::{TestAppContext, VisualTestContext};
     use language::Point;
     use project::Project;
     use settings::KeymapFile;
@@ -551,6 +582,60 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    async fn test_recent_commands_listed_first(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        let project = Project::test(app_state.fs.clone(), [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project.clone(), cx));
+
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_text("abc", cx);
+            editor
+        });
+
+        workspace.update(cx, |workspace, cx| {
+            workspace.add_item_to_active_pane(Box::new(editor.clone()), None, true, cx);
+            editor.update(cx, |editor, cx| editor.focus(cx))
+        });
+
+        let open_palette = |cx: &mut VisualTestContext| {
+            cx.simulate_keystrokes("cmd-shift-p");
+            workspace.update(cx, |workspace, cx| {
+                workspace
+                    .active_modal::<CommandPalette>(cx)
+                    .unwrap()
+                    .read(cx)
+                    .picker
+                    .clone()
+            })
+        };
+
+        let palette = open_palette(cx);
+        palette.update(cx, |palette, cx| {
+            assert!(palette.delegate.empty_query_sections(cx).is_empty());
+            assert!(palette.delegate.separators_after_indices().is_empty());
+        });
+
+        cx.simulate_input("bcksp");
+        cx.simulate_keystrokes("enter");
+
+        let palette = open_palette(cx);
+        palette.update(cx, |palette, cx| {
+            assert_eq!(palette.delegate.matches[0].string, "editor: backspace");
+            let sections = palette.delegate.empty_query_sections(cx);
+            assert_eq!(sections.len(), 1);
+            assert_eq!(sections[0].header, "Recently Used");
+            assert_eq!(sections[0].match_count, 1);
+            assert_eq!(palette.delegate.separators_after_indices(), vec![0]);
+        });
+
+        cx.simulate_input("a");
+        palette.update(cx, |palette, cx| {
+            assert!(palette.delegate.empty_query_sections(cx).is_empty());
+        });
+    }
+
     #[gpui::test]
     async fn test_go_to_line(cx: &mut TestAppContext) {
         let app_state = init_test(cx);
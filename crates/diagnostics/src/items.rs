@@ -1,7 +1,7 @@
 use editor::Editor;
 use gpui::{
-    rems, EventEmitter, IntoElement, ParentElement, Render, Styled, Subscription, View,
-    ViewContext, WeakView,
+    rems, Action, EventEmitter, IntoElement, ParentElement, Render, Styled, Subscription, View,
+    ViewContext, WeakView, WindowContext,
 };
 use language::Diagnostic;
 use ui::{h_flex, prelude::*, Button, ButtonLike, Color, Icon, IconName, Label, Tooltip};
@@ -171,4 +171,8 @@ impl StatusItemView for DiagnosticIndicator {
         }
         cx.notify();
     }
+
+    fn overflow_entry(&self, _: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+        Some(("Project Diagnostics".into(), Box::new(Deploy)))
+    }
 }
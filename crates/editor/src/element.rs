@@ -67,7 +67,7 @@ use std::{
 use sum_tree::Bias;
 use theme::{ActiveTheme, Appearance, PlayerColor};
 use ui::prelude::*;
-use ui::{h_flex, ButtonLike, ButtonStyle, ContextMenu, Tooltip};
+use ui::{h_flex, ButtonLike, ButtonStyle, ContextMenu, OverlayLayer, Tooltip};
 use util::RangeExt;
 use util::ResultExt;
 use workspace::{item::Item, Workspace};
@@ -1080,6 +1080,7 @@ impl EditorElement {
                                             color,
                                             background_color: None,
                                             strikethrough: None,
+                                            overline: None,
                                             underline: None,
                                         }],
                                     )
@@ -1902,6 +1903,7 @@ impl EditorElement {
                     background_color: None,
                     underline: None,
                     strikethrough: None,
+                    overline: None,
                 };
                 let shaped_line = cx
                     .text_system()
@@ -1999,6 +2001,7 @@ impl EditorElement {
                         background_color: None,
                         underline: Default::default(),
                         strikethrough: None,
+                        overline: None,
                     };
                     cx.text_system()
                         .shape_line(line.to_string().into(), font_size, &[run])
@@ -2746,7 +2749,7 @@ impl EditorElement {
                         .anchor(AnchorCorner::TopLeft)
                         .snap_to_window_with_margin(px(8.)),
                 )
-                .with_priority(1)
+                .with_priority(OverlayLayer::Popover.priority())
                 .into_any(),
             )
         })?;
@@ -4093,6 +4096,7 @@ impl EditorElement {
                     background_color: None,
                     underline: None,
                     strikethrough: None,
+                    overline: None,
                 }],
             )
             .unwrap();
@@ -4455,6 +4459,7 @@ impl LineWithInvisibles {
                             background_color: text_style.background_color,
                             underline: text_style.underline,
                             strikethrough: text_style.strikethrough,
+                            overline: text_style.overline,
                         });
 
                         if editor_mode == EditorMode::Full {
@@ -5514,6 +5519,7 @@ impl Element for EditorElement {
                                 background_color: None,
                                 underline: None,
                                 strikethrough: None,
+                                overline: None,
                             }],
                         )
                         .unwrap();
@@ -5529,6 +5535,7 @@ impl Element for EditorElement {
                                 background_color: None,
                                 underline: None,
                                 strikethrough: None,
+                                overline: None,
                             }],
                         )
                         .unwrap();
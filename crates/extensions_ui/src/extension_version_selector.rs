@@ -213,11 +213,8 @@ impl PickerDelegate for ExtensionVersionSelectorDelegate {
                 .selected(selected)
                 .disabled(disabled)
                 .child(
-                    HighlightedLabel::new(
-                        version_match.string.clone(),
-                        version_match.positions.clone(),
-                    )
-                    .when(disabled, |label| label.color(Color::Muted)),
+                    HighlightedLabel::from_fuzzy_match(version_match.string.clone(), version_match)
+                        .when(disabled, |label| label.color(Color::Muted)),
                 )
                 .end_slot(
                     h_flex()
@@ -15,16 +15,17 @@ use file_icons::FileIcons;
 use fuzzy::{CharBag, PathMatch, PathMatchCandidate};
 use gpui::{
     actions, rems, Action, AnyElement, AppContext, DismissEvent, EventEmitter, FocusHandle,
-    FocusableView, Model, Modifiers, ModifiersChangedEvent, ParentElement, Render, Styled, Task,
-    View, ViewContext, VisualContext, WeakView,
+    FocusableView, HighlightStyle, Model, Modifiers, ModifiersChangedEvent, ParentElement, Render,
+    Styled, Subscription, Task, View, ViewContext, VisualContext, WeakView,
 };
 use new_path_prompt::NewPathPrompt;
 use open_path_prompt::OpenPathPrompt;
-use picker::{Picker, PickerDelegate};
+use picker::{Picker, PickerDelegate, PickerSection};
 use project::{PathMatchCandidateSet, Project, ProjectPath, WorktreeId};
 use settings::Settings;
 use std::{
     cmp,
+    ops::Range,
     path::{Path, PathBuf},
     sync::{
         atomic::{self, AtomicBool},
@@ -32,7 +33,10 @@ use std::{
     },
 };
 use text::Point;
-use ui::{prelude::*, HighlightedLabel, ListItem, ListItemSpacing};
+use ui::{
+    prelude::*, CodeBlock, HighlightedLabel, ListItem, ListItemSpacing, PaletteHint,
+    PeekController, PeekEvent, PeekTrigger,
+};
 use util::{paths::PathWithPosition, post_inc, ResultExt};
 use workspace::{item::PreviewTabsSettings, notifications::NotifyResultExt, ModalView, Workspace};
 
@@ -40,9 +44,22 @@ actions!(file_finder, [SelectPrev]);
 
 impl ModalView for FileFinder {}
 
+/// The most rows of a file shown in the preview next to the file finder.
+const MAX_PREVIEW_ROWS: u32 = 200;
+
 pub struct FileFinder {
     picker: View<Picker<FileFinderDelegate>>,
     init_modifiers: Option<Modifiers>,
+    peek: Option<PeekController<ProjectPath>>,
+    preview: Option<FilePreview>,
+    _picker_subscription: Subscription,
+}
+
+struct FilePreview {
+    path: ProjectPath,
+    text: SharedString,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+    _load: Task<Option<()>>,
 }
 
 pub fn init_settings(cx: &mut AppContext) {
@@ -141,13 +158,140 @@ impl FileFinder {
         })
     }
 
-    fn new(delegate: FileFinderDelegate, cx: &mut ViewContext<Self>) -> Self {
+    fn new(mut delegate: FileFinderDelegate, cx: &mut ViewContext<Self>) -> Self {
+        let peek = FileFinderSettings::get_global(cx).preview.then(|| {
+            let file_finder = cx.view().downgrade();
+            PeekController::new(move |event, cx| {
+                // The selection is peeked while the picker is updated, possibly by the
+                // finder itself, e.g. when cycling through the matches.
+                let file_finder = file_finder.clone();
+                cx.defer(move |cx| {
+                    file_finder
+                        .update(cx, |file_finder, cx| {
+                            file_finder.handle_peek_event(event, cx)
+                        })
+                        .ok();
+                });
+            })
+        });
+        delegate.peek = peek.clone();
+        let picker = cx.new_view(|cx| Picker::uniform_list(delegate, cx));
+        let picker_subscription = cx.observe(&picker, |this, _, cx| this.peek_selected_match(cx));
+
         Self {
-            picker: cx.new_view(|cx| Picker::uniform_list(delegate, cx)),
+            picker,
             init_modifiers: cx.modifiers().modified().then_some(cx.modifiers()),
+            peek,
+            preview: None,
+            _picker_subscription: picker_subscription,
         }
     }
 
+    fn peek_selected_match(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(peek) = &self.peek else {
+            return;
+        };
+        let delegate = &self.picker.read(cx).delegate;
+        let selected_path = delegate
+            .matches
+            .get(delegate.selected_index)
+            .map(Match::project_path);
+        match selected_path {
+            Some(path) => peek.peek(path, PeekTrigger::Keyboard, cx),
+            None => peek.dismiss(cx),
+        }
+    }
+
+    fn handle_peek_event(&mut self, event: PeekEvent<ProjectPath>, cx: &mut ViewContext<Self>) {
+        match event {
+            PeekEvent::Peek(path) => self.load_preview(path, cx),
+            PeekEvent::Dismiss | PeekEvent::Open(_) => {
+                self.preview = None;
+                cx.notify();
+            }
+        }
+    }
+
+    fn load_preview(&mut self, path: ProjectPath, cx: &mut ViewContext<Self>) {
+        let project = self.picker.read(cx).delegate.project.clone();
+        let open_buffer = project.update(cx, |project, cx| project.open_buffer(path.clone(), cx));
+        let loaded_path = path.clone();
+        let load = cx.spawn(|this, mut cx| async move {
+            let buffer = open_buffer.await.log_err()?;
+            this.update(&mut cx, |this, cx| {
+                let snapshot = buffer.read(cx).snapshot();
+                let end = if snapshot.max_point().row > MAX_PREVIEW_ROWS {
+                    snapshot.point_to_offset(Point::new(MAX_PREVIEW_ROWS, 0))
+                } else {
+                    snapshot.len()
+                };
+
+                let syntax_theme = cx.theme().syntax().clone();
+                let mut text = String::new();
+                let mut highlights = Vec::new();
+                for chunk in snapshot.chunks(0..end, true) {
+                    let start = text.len();
+                    text.push_str(chunk.text);
+                    if let Some(style) = chunk
+                        .syntax_highlight_id
+                        .and_then(|id| id.style(&syntax_theme))
+                    {
+                        highlights.push((start..text.len(), style));
+                    }
+                }
+
+                if let Some(preview) = this
+                    .preview
+                    .as_mut()
+                    .filter(|preview| preview.path == loaded_path)
+                {
+                    preview.text = text.into();
+                    preview.highlights = highlights;
+                    cx.notify();
+                }
+            })
+            .ok()
+        });
+        self.preview = Some(FilePreview {
+            path,
+            text: SharedString::default(),
+            highlights: Vec::new(),
+            _load: load,
+        });
+        cx.notify();
+    }
+
+    fn render_preview(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let preview = self.preview.as_ref()?;
+        Some(
+            v_flex()
+                .id("file-finder-preview")
+                .w(rems(40.))
+                .max_h(rems(30.))
+                .overflow_y_scroll()
+                .elevation_3(cx)
+                .child(
+                    div()
+                        .px_2()
+                        .py_1()
+                        .border_b_1()
+                        .border_color(cx.theme().colors().border_variant)
+                        .child(
+                            Label::new(preview.path.path.to_string_lossy().to_string())
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        ),
+                )
+                .child(
+                    CodeBlock::new("file-finder-preview-code", preview.text.clone())
+                        .highlights(preview.highlights.clone())
+                        .line_numbers(true)
+                        .soft_wrap(false)
+                        .copy_button(false),
+                ),
+        )
+    }
+
     fn handle_modifiers_changed(
         &mut self,
         event: &ModifiersChangedEvent,
@@ -180,12 +324,14 @@ impl FocusableView for FileFinder {
 
 impl Render for FileFinder {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        v_flex()
+        h_flex()
             .key_context("FileFinder")
-            .w(rems(34.))
+            .items_start()
+            .gap_2()
             .on_modifiers_changed(cx.listener(Self::handle_modifiers_changed))
             .on_action(cx.listener(Self::handle_select_prev))
-            .child(self.picker.clone())
+            .child(v_flex().w(rems(34.)).child(self.picker.clone()))
+            .children(self.render_preview(cx))
     }
 }
 
@@ -205,6 +351,7 @@ pub struct FileFinderDelegate {
     history_items: Vec<FoundPath>,
     separate_history: bool,
     first_update: bool,
+    peek: Option<PeekController<ProjectPath>>,
 }
 
 /// Use a custom ordering for file finder: the regular one
@@ -263,6 +410,16 @@ impl Match {
         }
     }
 
+    fn project_path(&self) -> ProjectPath {
+        match self {
+            Match::History { path, .. } => path.project.clone(),
+            Match::Search(panel_match) => ProjectPath {
+                worktree_id: WorktreeId::from_usize(panel_match.0.worktree_id),
+                path: panel_match.0.path.clone(),
+            },
+        }
+    }
+
     fn panel_match(&self) -> Option<&ProjectPanelOrdMatch> {
         match self {
             Match::History { panel_match, .. } => panel_match.as_ref(),
@@ -533,6 +690,7 @@ impl FileFinderDelegate {
             history_items,
             separate_history,
             first_update: true,
+            peek: None,
         }
     }
 
@@ -868,6 +1026,29 @@ impl PickerDelegate for FileFinderDelegate {
         cx.notify();
     }
 
+    fn empty_query_sections(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PickerSection> {
+        let history_count = self
+            .matches
+            .matches
+            .iter()
+            .filter(|m| matches!(m, Match::History { .. }))
+            .count();
+        if history_count == 0 {
+            return Vec::new();
+        }
+        vec![PickerSection::new("Recent Files", history_count)]
+    }
+
+    fn footer_hints(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PaletteHint> {
+        if self.matches.matches.is_empty() {
+            return Vec::new();
+        }
+        vec![
+            PaletteHint::new("Open", menu::Confirm.boxed_clone()),
+            PaletteHint::new("Open in Split", menu::SecondaryConfirm.boxed_clone()),
+        ]
+    }
+
     fn separators_after_indices(&self) -> Vec<usize> {
         if self.separate_history {
             let first_non_history_index = self
@@ -948,6 +1129,9 @@ impl PickerDelegate for FileFinderDelegate {
     }
 
     fn confirm(&mut self, secondary: bool, cx: &mut ViewContext<Picker<FileFinderDelegate>>) {
+        if let Some(peek) = &self.peek {
+            peek.promote(cx);
+        }
         if let Some(m) = self.matches.get(self.selected_index()) {
             if let Some(workspace) = self.workspace.upgrade() {
                 let open_task = workspace.update(cx, move |workspace, cx| {
@@ -1112,6 +1296,16 @@ impl PickerDelegate for FileFinderDelegate {
                 .end_slot::<AnyElement>(history_icon)
                 .inset(true)
                 .selected(selected)
+                .when_some(self.peek.clone(), |this, peek| {
+                    let project_path = path_match.project_path();
+                    this.on_hover(move |hovered, cx| {
+                        if *hovered {
+                            peek.peek(project_path.clone(), PeekTrigger::Hover, cx);
+                        } else {
+                            peek.cancel_hover();
+                        }
+                    })
+                })
                 .child(
                     h_flex()
                         .gap_2()
@@ -6,6 +6,7 @@ use settings::{Settings, SettingsSources};
 #[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
 pub struct FileFinderSettings {
     pub file_icons: bool,
+    pub preview: bool,
 }
 
 #[derive(Clone, Default, Serialize, Deserialize, JsonSchema, Debug)]
@@ -14,6 +15,11 @@ pub struct FileFinderSettingsContent {
     ///
     /// Default: true
     pub file_icons: Option<bool>,
+    /// Whether to show a preview of the selected or hovered file next to the
+    /// file finder.
+    ///
+    /// Default: false
+    pub preview: Option<bool>,
 }
 
 impl Settings for FileFinderSettings {
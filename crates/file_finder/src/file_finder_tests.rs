@@ -1323,6 +1323,48 @@ async fn test_history_items_shown_in_order_of_open(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_recent_files_header_and_footer_hints(cx: &mut TestAppContext) {
+    let app_state = init_test(cx);
+
+    app_state
+        .fs
+        .as_fake()
+        .insert_tree(
+            "/test",
+            json!({
+                "test": {
+                    "1.txt": "// One",
+                    "2.txt": "// Two",
+                }
+            }),
+        )
+        .await;
+
+    let project = Project::test(app_state.fs.clone(), ["/test".as_ref()], cx).await;
+    let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+    let picker = open_file_picker(&workspace, cx);
+    picker.update(cx, |finder, cx| {
+        assert_eq!(finder.delegate.matches.len(), 0);
+        assert!(finder.delegate.empty_query_sections(cx).is_empty());
+        assert!(finder.delegate.footer_hints(cx).is_empty());
+    });
+    cx.dispatch_action(menu::Cancel);
+
+    open_queried_buffer("1", 1, "1.txt", &workspace, cx).await;
+
+    let picker = open_file_picker(&workspace, cx);
+    picker.update(cx, |finder, cx| {
+        assert_eq!(finder.delegate.matches.len(), 1);
+        let sections = finder.delegate.empty_query_sections(cx);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].header, "Recent Files");
+        assert_eq!(sections[0].match_count, 1);
+        assert_eq!(finder.delegate.footer_hints(cx).len(), 2);
+    });
+}
+
 #[gpui::test]
 async fn test_selected_history_item_stays_selected_on_worktree_updated(cx: &mut TestAppContext) {
     let app_state = init_test(cx);
@@ -1,5 +1,5 @@
 use editor::{Editor, ToPoint};
-use gpui::{AppContext, Subscription, View, WeakView};
+use gpui::{Action, AppContext, SharedString, Subscription, View, WeakView, WindowContext};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use settings::{Settings, SettingsSources};
@@ -170,6 +170,15 @@ impl StatusItemView for CursorPosition {
 
         cx.notify();
     }
+
+    fn overflow_entry(&self, _: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+        self.position.is_some().then(|| {
+            (
+                "Go to Line/Column".into(),
+                Box::new(editor::actions::ToggleGoToLine) as Box<dyn Action>,
+            )
+        })
+    }
 }
 
 #[derive(Clone, Copy, Default, PartialEq, JsonSchema, Deserialize, Serialize)]
@@ -377,6 +377,7 @@ impl Element for TextElement {
             background_color: None,
             underline: None,
             strikethrough: None,
+            overline: None,
         };
         let runs = if let Some(marked_range) = input.marked_range.as_ref() {
             vec![
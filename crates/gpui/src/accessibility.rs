@@ -0,0 +1,257 @@
+use crate::{Bounds, Pixels, SharedString};
+
+/// What an element is to assistive technology, like screen readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessibilityRole {
+    /// A container grouping related elements.
+    Group,
+    /// Static text.
+    Label,
+    /// A link, navigating somewhere when activated.
+    Link,
+    /// A button, performing an action when activated.
+    Button,
+    /// A checkbox, which can be checked, unchecked or indeterminate.
+    CheckBox,
+    /// A switch, which can be on or off.
+    Switch,
+    /// One of a group of mutually exclusive options.
+    RadioButton,
+    /// A control showing or hiding other content, like a tree item's chevron.
+    Disclosure,
+    /// A list of items.
+    List,
+    /// An item of a list.
+    ListItem,
+    /// A list of tabs.
+    TabList,
+    /// A tab of a tab list.
+    Tab,
+    /// A menu of items.
+    Menu,
+    /// An item of a menu.
+    MenuItem,
+    /// A field to enter text.
+    TextInput,
+    /// An image or icon conveying meaning.
+    Image,
+    /// An indicator of the progress of a task.
+    ProgressIndicator,
+    /// A dialog, like a modal.
+    Dialog,
+    /// A tooltip describing another element.
+    Tooltip,
+}
+
+/// The state of an element, as conveyed to assistive technology.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessibilityState {
+    /// Whether a checkbox, switch or radio button is checked, or `None` if it is
+    /// indeterminate or the element can't be checked.
+    pub checked: Option<bool>,
+    /// Whether an element showing or hiding other content is expanded, or `None`
+    /// if the element doesn't expand.
+    pub expanded: Option<bool>,
+    /// Whether the element is selected, like the active tab or a selected list item.
+    pub selected: bool,
+    /// Whether the element is disabled.
+    pub disabled: bool,
+}
+
+/// How an element is described to assistive technology: its role, its name and
+/// its state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibilityProperties {
+    /// The role of the element.
+    pub role: AccessibilityRole,
+    /// The name of the element, like a label's text or a button's label.
+    pub name: Option<SharedString>,
+    /// The state of the element.
+    pub state: AccessibilityState,
+}
+
+impl AccessibilityProperties {
+    /// Creates the properties of an element with the given role, without a name.
+    pub fn new(role: AccessibilityRole) -> Self {
+        Self {
+            role,
+            name: None,
+            state: AccessibilityState::default(),
+        }
+    }
+
+    /// Sets the name of the element.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets whether the element is checked.
+    pub fn checked(mut self, checked: Option<bool>) -> Self {
+        self.state.checked = checked;
+        self
+    }
+
+    /// Sets whether the element is expanded.
+    pub fn expanded(mut self, expanded: Option<bool>) -> Self {
+        self.state.expanded = expanded;
+        self
+    }
+
+    /// Sets whether the element is selected.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.state.selected = selected;
+        self
+    }
+
+    /// Sets whether the element is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.state.disabled = disabled;
+        self
+    }
+}
+
+/// An element of the [`AccessibilityTree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// How the element is described.
+    pub properties: AccessibilityProperties,
+    /// The bounds of the element in the window.
+    pub bounds: Bounds<Pixels>,
+    /// The number of ancestors of the element in the tree.
+    pub depth: usize,
+}
+
+/// The accessible elements of a window, as painted in the last frame.
+///
+/// Nodes are stored in paint order, each directly following its parent or its
+/// previous sibling. Elements drawn with [`deferred`](crate::deferred), like
+/// popovers, are at the root of the tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccessibilityTree {
+    pub(crate) nodes: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityTree {
+    /// Returns all nodes, in paint order.
+    pub fn nodes(&self) -> &[AccessibilityNode] {
+        &self.nodes
+    }
+
+    /// Returns the index of the parent of the node at the given index.
+    pub fn parent(&self, ix: usize) -> Option<usize> {
+        let depth = self.nodes.get(ix)?.depth;
+        if depth == 0 {
+            return None;
+        }
+        self.nodes[..ix]
+            .iter()
+            .rposition(|node| node.depth == depth - 1)
+    }
+
+    /// Returns the indices of the children of the node at the given index, or of the
+    /// roots of the tree if `ix` is `None`.
+    pub fn children(&self, ix: Option<usize>) -> Vec<usize> {
+        let (start, depth) = match ix {
+            Some(ix) => (ix + 1, self.nodes[ix].depth + 1),
+            None => (0, 0),
+        };
+        self.nodes[start..]
+            .iter()
+            .enumerate()
+            .take_while(|(_, node)| node.depth >= depth)
+            .filter(|(_, node)| node.depth == depth)
+            .map(|(offset, _)| start + offset)
+            .collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as gpui, div, px, InteractiveElement, IntoElement, ParentElement, Render, Styled,
+        TestAppContext, ViewContext, VisualTestContext,
+    };
+
+    use super::*;
+
+    struct TestButton {
+        name: SharedString,
+    }
+
+    impl Render for TestButton {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div()
+                .size_full()
+                .child(div().w(px(40.)).h(px(20.)).accessibility(
+                    AccessibilityProperties::new(AccessibilityRole::Button).name(self.name.clone()),
+                ))
+        }
+    }
+
+    #[test]
+    fn test_accessibility_tree_structure() {
+        let node = |role, depth| AccessibilityNode {
+            properties: AccessibilityProperties::new(role),
+            bounds: Bounds::default(),
+            depth,
+        };
+        let tree = AccessibilityTree {
+            nodes: vec![
+                node(AccessibilityRole::List, 0),
+                node(AccessibilityRole::ListItem, 1),
+                node(AccessibilityRole::Label, 2),
+                node(AccessibilityRole::ListItem, 1),
+                node(AccessibilityRole::Dialog, 0),
+            ],
+        };
+
+        assert_eq!(tree.children(None), vec![0, 4]);
+        assert_eq!(tree.children(Some(0)), vec![1, 3]);
+        assert_eq!(tree.children(Some(1)), vec![2]);
+        assert_eq!(tree.parent(2), Some(1));
+        assert_eq!(tree.parent(3), Some(0));
+        assert_eq!(tree.parent(4), None);
+    }
+
+    #[gpui::test]
+    fn test_accessibility_tree_exported_when_changed(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| TestButton {
+            name: "Save".into(),
+        });
+        let updates = |cx: &mut VisualTestContext| {
+            cx.update(|cx| {
+                cx.window
+                    .platform_window
+                    .as_test()
+                    .unwrap()
+                    .0
+                    .lock()
+                    .accessibility_tree_updates
+            })
+        };
+        cx.run_until_parked();
+        assert_eq!(updates(cx), 1);
+
+        // Redrawing an unchanged tree doesn't export it again.
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(updates(cx), 1);
+
+        view.update(cx, |view, cx| {
+            view.name = "Save All".into();
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert_eq!(updates(cx), 2);
+        cx.update(|cx| {
+            let nodes = cx.accessibility_tree().nodes();
+            assert_eq!(nodes.len(), 1);
+            assert_eq!(nodes[0].properties.name, Some("Save All".into()));
+        });
+    }
+}
@@ -250,12 +250,14 @@ pub struct AppContext {
     // TypeId is the type of the event that the listener callback expects
     pub(crate) event_listeners: SubscriberSet<EntityId, (TypeId, Listener)>,
     pub(crate) keystroke_observers: SubscriberSet<(), KeystrokeObserver>,
+    pub(crate) keystroke_interceptors: SubscriberSet<(), KeystrokeObserver>,
     pub(crate) release_listeners: SubscriberSet<EntityId, ReleaseListener>,
     pub(crate) global_observers: SubscriberSet<TypeId, Handler>,
     pub(crate) quit_observers: SubscriberSet<(), QuitHandler>,
     pub(crate) layout_id_buffer: Vec<LayoutId>, // We recycle this memory across layout requests.
     pub(crate) propagate_event: bool,
     pub(crate) prompt_builder: Option<PromptBuilder>,
+    pub(crate) prefers_reduced_motion: bool,
 }
 
 impl AppContext {
@@ -304,11 +306,13 @@ impl AppContext {
                 event_listeners: SubscriberSet::new(),
                 release_listeners: SubscriberSet::new(),
                 keystroke_observers: SubscriberSet::new(),
+                keystroke_interceptors: SubscriberSet::new(),
                 global_observers: SubscriberSet::new(),
                 quit_observers: SubscriberSet::new(),
                 layout_id_buffer: Default::default(),
                 propagate_event: true,
                 prompt_builder: Some(PromptBuilder::Default),
+                prefers_reduced_motion: platform.should_reduce_motion(),
             }),
         });
 
@@ -676,6 +680,32 @@ impl AppContext {
         self.platform.should_auto_hide_scrollbars()
     }
 
+    /// Returns whether the user has asked to reduce transparency at the platform level.
+    pub fn should_reduce_transparency(&self) -> bool {
+        self.platform.should_reduce_transparency()
+    }
+
+    /// Returns whether the user has asked to reduce motion at the platform level.
+    pub fn should_reduce_motion(&self) -> bool {
+        self.platform.should_reduce_motion()
+    }
+
+    /// Returns whether animations should jump to their end, and things like cursors
+    /// stop blinking. This follows [`Self::should_reduce_motion`] until it's set with
+    /// [`Self::set_prefers_reduced_motion`], e.g. from a setting.
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.prefers_reduced_motion
+    }
+
+    /// Sets whether animations should jump to their end, redrawing windows when it
+    /// changes so that running animations stop.
+    pub fn set_prefers_reduced_motion(&mut self, reduced: bool) {
+        if self.prefers_reduced_motion != reduced {
+            self.prefers_reduced_motion = reduced;
+            self.refresh();
+        }
+    }
+
     /// Restart the application.
     pub fn restart(&self, binary_path: Option<PathBuf>) {
         self.platform.restart(binary_path)
@@ -691,6 +721,20 @@ impl AppContext {
         self.http_client.clone()
     }
 
+    /// Registers an SVG under the given path at runtime, so that [`svg`](crate::svg)
+    /// elements can render it like the SVGs of the app's [`AssetSource`].
+    ///
+    /// Returns an error if the bytes aren't a valid SVG. Paths are expected to be
+    /// unique: sizes of the path that were already rendered aren't re-rendered when
+    /// it is registered again.
+    pub fn register_svg(
+        &self,
+        path: impl Into<SharedString>,
+        bytes: impl Into<Arc<[u8]>>,
+    ) -> Result<()> {
+        self.svg_renderer.register_svg(path.into(), bytes.into())
+    }
+
     /// Returns the SVG renderer GPUI uses
     pub(crate) fn svg_renderer(&self) -> SvgRenderer {
         self.svg_renderer.clone()
@@ -1075,6 +1119,30 @@ impl AppContext {
         )
     }
 
+    /// Register a callback to be invoked when a key is pressed in any window, before it's
+    /// matched against the keymap. Calling [`WindowContext::stop_propagation`] from the
+    /// callback consumes the keystroke, so that it neither triggers a binding nor reaches
+    /// any key listener. This is meant for capturing shortcuts while recording them.
+    pub fn intercept_keystrokes(
+        &mut self,
+        mut f: impl FnMut(&KeystrokeEvent, &mut WindowContext) + 'static,
+    ) -> Subscription {
+        let (subscription, activate) = self.keystroke_interceptors.insert(
+            (),
+            Box::new(move |event, cx| {
+                f(event, cx);
+                true
+            }),
+        );
+        activate();
+        subscription
+    }
+
+    /// Returns the keymap of the app, with every registered key binding.
+    pub fn key_bindings(&self) -> Rc<RefCell<Keymap>> {
+        self.keymap.clone()
+    }
+
     /// Register key bindings.
     pub fn bind_keys(&mut self, bindings: impl IntoIterator<Item = KeyBinding>) {
         self.keymap.borrow_mut().add_bindings(bindings);
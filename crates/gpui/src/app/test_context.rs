@@ -767,6 +767,20 @@ impl VisualTestContext {
         self.update(|cx| cx.window.rendered_frame.debug_bounds.get(selector).copied())
     }
 
+    /// Returns the bounds of the first element painted with the given test ID.
+    pub fn bounds_for_test_id(&mut self, test_id: &str) -> Option<Bounds<Pixels>> {
+        self.update(|cx| cx.bounds_for_test_id(test_id).first().copied())
+    }
+
+    /// Simulates a click in the center of the first element painted with the given
+    /// test ID, panicking if there is none.
+    pub fn simulate_click_on_test_id(&mut self, test_id: &str, modifiers: Modifiers) {
+        let bounds = self
+            .bounds_for_test_id(test_id)
+            .unwrap_or_else(|| panic!("no element with test ID {test_id:?}"));
+        self.simulate_click(bounds.center(), modifiers);
+    }
+
     /// Draw an element to the window. Useful for simulating events or actions
     pub fn draw<E>(
         &mut self,
@@ -33,7 +33,8 @@
 
 use crate::{
     util::FluentBuilder, ArenaBox, AvailableSpace, Bounds, DispatchNodeId, ElementId, FocusHandle,
-    LayoutId, Pixels, Point, Size, Style, ViewContext, WindowContext, ELEMENT_ARENA,
+    InspectedComponent, LayoutId, Pixels, Point, ProfileSpanId, Size, Style, ViewContext,
+    WindowContext, ELEMENT_ARENA,
 };
 use derive_more::{Deref, DerefMut};
 pub(crate) use smallvec::SmallVec;
@@ -164,12 +165,20 @@ pub trait ParentElement {
 /// An element for rendering components. An implementation detail of the [`IntoElement`] derive macro
 /// for [`RenderOnce`]
 #[doc(hidden)]
-pub struct Component<C: RenderOnce>(Option<C>);
+pub struct Component<C: RenderOnce> {
+    component: Option<C>,
+    profile_span: Option<ProfileSpanId>,
+    inspected: Option<InspectedComponent>,
+}
 
 impl<C: RenderOnce> Component<C> {
     /// Create a new component from the given RenderOnce type.
     pub fn new(component: C) -> Self {
-        Component(Some(component))
+        Component {
+            component: Some(component),
+            profile_span: None,
+            inspected: None,
+        }
     }
 }
 
@@ -186,9 +195,15 @@ impl<C: RenderOnce> Element for Component<C> {
         _id: Option<&GlobalElementId>,
         cx: &mut WindowContext,
     ) -> (LayoutId, Self::RequestLayoutState) {
-        let mut element = self.0.take().unwrap().render(cx).into_any_element();
-        let layout_id = element.request_layout(cx);
-        (layout_id, element)
+        let component = self.component.take().unwrap();
+        let inspected = &mut self.inspected;
+        cx.with_profile_span(&mut self.profile_span, std::any::type_name::<C>, |cx| {
+            cx.with_inspected_component(inspected, std::any::type_name::<C>, |cx| {
+                let mut element = component.render(cx).into_any_element();
+                let layout_id = element.request_layout(cx);
+                (layout_id, element)
+            })
+        })
     }
 
     fn prepaint(
@@ -198,7 +213,12 @@ impl<C: RenderOnce> Element for Component<C> {
         element: &mut AnyElement,
         cx: &mut WindowContext,
     ) {
-        element.prepaint(cx);
+        let inspected = &mut self.inspected;
+        cx.with_profile_span(&mut self.profile_span, std::any::type_name::<C>, |cx| {
+            cx.with_inspected_component(inspected, std::any::type_name::<C>, |cx| {
+                element.prepaint(cx)
+            })
+        });
     }
 
     fn paint(
@@ -209,7 +229,9 @@ impl<C: RenderOnce> Element for Component<C> {
         _: &mut Self::PrepaintState,
         cx: &mut WindowContext,
     ) {
-        element.paint(cx);
+        cx.with_profile_span(&mut self.profile_span, std::any::type_name::<C>, |cx| {
+            element.paint(cx)
+        });
     }
 }
 
@@ -285,6 +307,9 @@ impl<E: Element> Drawable<E> {
     fn request_layout(&mut self, cx: &mut WindowContext) -> LayoutId {
         match mem::take(&mut self.phase) {
             ElementDrawPhase::Start => {
+                if let Some(profiler) = cx.window.frame_profiler.as_mut() {
+                    profiler.record_element();
+                }
                 let global_id = self.element.id().map(|element_id| {
                     cx.window.element_id_stack.push(element_id);
                     GlobalElementId(cx.window.element_id_stack.clone())
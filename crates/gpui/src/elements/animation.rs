@@ -44,6 +44,9 @@ impl Animation {
 /// An extension trait for adding the animation wrapper to both Elements and Components
 pub trait AnimationExt {
     /// Render this component or element with an animation
+    ///
+    /// When [`AppContext::prefers_reduced_motion`](crate::AppContext::prefers_reduced_motion)
+    /// is set, the animation jumps straight to its end.
     fn with_animation(
         self,
         id: impl Into<ElementId>,
@@ -110,12 +113,22 @@ impl<E: IntoElement + 'static> Element for AnimationElement<E> {
             let state = state.unwrap_or_else(|| AnimationState {
                 start: Instant::now(),
             });
-            let mut delta =
-                state.start.elapsed().as_secs_f32() / self.animation.duration.as_secs_f32();
+            // Animations without a duration, and all animations when motion is
+            // reduced, end right away.
+            let duration = if cx.prefers_reduced_motion() {
+                Duration::ZERO
+            } else {
+                self.animation.duration
+            };
+            let mut delta = if duration.is_zero() {
+                f32::INFINITY
+            } else {
+                state.start.elapsed().as_secs_f32() / duration.as_secs_f32()
+            };
 
             let mut done = false;
             if delta > 1.0 {
-                if self.animation.oneshot {
+                if self.animation.oneshot || duration.is_zero() {
                     done = true;
                     delta = 1.0;
                 } else {
@@ -175,6 +188,11 @@ mod easing {
         delta * delta
     }
 
+    /// The quadratic ease-out function, which starts fast and slows down towards the end
+    pub fn ease_out(delta: f32) -> f32 {
+        1.0 - (1.0 - delta) * (1.0 - delta)
+    }
+
     /// The quadratic ease-in-out function, which starts and ends slowly but speeds up in the middle
     pub fn ease_in_out(delta: f32) -> f32 {
         if delta < 0.5 {
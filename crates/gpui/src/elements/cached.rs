@@ -0,0 +1,244 @@
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+use collections::FxHasher;
+use refineable::Refineable as _;
+
+use crate::{
+    AnyElement, Bounds, ContentMask, Element, ElementId, GlobalElementId, IntoElement, LayoutId,
+    PaintIndex, Pixels, PrepaintStateIndex, Style, StyleRefinement, Styled, TextStyle,
+    WindowContext,
+};
+
+/// Builds an element whose layout and paint output are reused across frames for as
+/// long as its key, bounds and inherited text style stay the same, without calling
+/// `render` again.
+///
+/// The key is a hash of every input of `render`, like the data of a list item and
+/// whether it is selected. The cache is also invalidated when a view it contains is
+/// notified, like an editor renaming the item, and when the window is refreshed, e.g.
+/// on theme and settings changes.
+///
+/// Like a cached view, the element is laid out independently of its contents, so its
+/// size has to be set with its style, e.g. `cached(id, key, render).w_full().h(px(24.))`.
+pub fn cached<E: IntoElement>(
+    id: impl Into<ElementId>,
+    key: impl Hash,
+    render: impl 'static + FnOnce(&mut WindowContext) -> E,
+) -> Cached {
+    let mut hasher = FxHasher::default();
+    key.hash(&mut hasher);
+    Cached {
+        id: id.into(),
+        key: hasher.finish(),
+        style: StyleRefinement::default(),
+        render: Some(Box::new(move |cx| render(cx).into_any_element())),
+    }
+}
+
+/// An element whose output is reused across frames. See [`cached`].
+pub struct Cached {
+    id: ElementId,
+    key: u64,
+    style: StyleRefinement,
+    render: Option<Box<dyn FnOnce(&mut WindowContext) -> AnyElement>>,
+}
+
+struct CachedState {
+    prepaint_range: Range<PrepaintStateIndex>,
+    paint_range: Range<PaintIndex>,
+    cache_key: CacheKey,
+}
+
+#[derive(PartialEq)]
+struct CacheKey {
+    key: u64,
+    bounds: Bounds<Pixels>,
+    content_mask: ContentMask<Pixels>,
+    text_style: TextStyle,
+    rem_size: Pixels,
+}
+
+impl IntoElement for Cached {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Cached {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<AnyElement>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.refine(&self.style);
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        cx.with_element_state::<CachedState, _>(global_id.unwrap(), |element_state, cx| {
+            let cache_key = CacheKey {
+                key: self.key,
+                bounds,
+                content_mask: cx.content_mask(),
+                text_style: cx.text_style(),
+                rem_size: cx.rem_size(),
+            };
+
+            if let Some(mut element_state) = element_state {
+                if element_state.cache_key == cache_key
+                    && !cx.prepaint_range_contains_dirty_views(&element_state.prepaint_range)
+                    && !cx.window.refreshing
+                {
+                    let prepaint_start = cx.prepaint_index();
+                    cx.reuse_prepaint(element_state.prepaint_range.clone());
+                    let prepaint_end = cx.prepaint_index();
+                    element_state.prepaint_range = prepaint_start..prepaint_end;
+                    return (None, element_state);
+                }
+            }
+
+            let prepaint_start = cx.prepaint_index();
+            let mut element = (self.render.take().unwrap())(cx);
+            element.layout_as_root(bounds.size.into(), cx);
+            element.prepaint_at(bounds.origin, cx);
+            let prepaint_end = cx.prepaint_index();
+
+            (
+                Some(element),
+                CachedState {
+                    prepaint_range: prepaint_start..prepaint_end,
+                    paint_range: PaintIndex::default()..PaintIndex::default(),
+                    cache_key,
+                },
+            )
+        })
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        element: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        cx.with_element_state::<CachedState, _>(global_id.unwrap(), |element_state, cx| {
+            let mut element_state = element_state.unwrap();
+
+            let paint_start = cx.paint_index();
+            if let Some(element) = element {
+                element.paint(cx);
+            } else {
+                cx.reuse_paint(element_state.paint_range.clone());
+            }
+            let paint_end = cx.paint_index();
+            element_state.paint_range = paint_start..paint_end;
+
+            ((), element_state)
+        })
+    }
+}
+
+impl Styled for Cached {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::{
+        self as gpui, div, px, IntoElement, ParentElement, Render, Styled, TestAppContext, View,
+        ViewContext, VisualContext as _,
+    };
+
+    use super::cached;
+
+    struct CachedView {
+        key: usize,
+        render_count: Rc<Cell<usize>>,
+        child: View<ChildView>,
+    }
+
+    impl Render for CachedView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            let render_count = self.render_count.clone();
+            let child = self.child.clone();
+            div().size_full().child(
+                cached("cached", self.key, move |_| {
+                    render_count.set(render_count.get() + 1);
+                    div().size_full().child(child)
+                })
+                .w(px(10.))
+                .h(px(10.)),
+            )
+        }
+    }
+
+    struct ChildView {
+        render_count: Rc<Cell<usize>>,
+    }
+
+    impl Render for ChildView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            self.render_count.set(self.render_count.get() + 1);
+            div().size_full()
+        }
+    }
+
+    #[gpui::test]
+    fn test_cached_rerenders_when_key_changes(cx: &mut TestAppContext) {
+        let render_count = Rc::new(Cell::new(0));
+        let child_render_count = Rc::new(Cell::new(0));
+        let (view, cx) = cx.add_window_view(|cx| CachedView {
+            key: 0,
+            render_count: render_count.clone(),
+            child: cx.new_view(|_| ChildView {
+                render_count: child_render_count.clone(),
+            }),
+        });
+        assert_eq!(render_count.get(), 1);
+        assert_eq!(child_render_count.get(), 1);
+
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(render_count.get(), 1);
+
+        view.update(cx, |view, cx| {
+            view.key = 1;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert_eq!(render_count.get(), 2);
+
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(render_count.get(), 2);
+
+        // Notifying a view drawn inside the cached element redraws it.
+        let child = view.update(cx, |view, _| view.child.clone());
+        child.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(render_count.get(), 3);
+        assert_eq!(child_render_count.get(), 3);
+    }
+}
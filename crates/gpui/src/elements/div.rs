@@ -16,10 +16,10 @@
 //! constructed by combining these two systems into an all-in-one element.
 
 use crate::{
-    point, px, size, Action, AnyDrag, AnyElement, AnyTooltip, AnyView, AppContext, Bounds,
-    ClickEvent, DispatchPhase, Element, ElementId, FocusHandle, Global, GlobalElementId, Hitbox,
-    HitboxId, IntoElement, IsZero, KeyContext, KeyDownEvent, KeyUpEvent, LayoutId,
-    ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
+    point, px, size, AccessibilityProperties, Action, AnyDrag, AnyElement, AnyTooltip, AnyView,
+    AppContext, Bounds, ClickEvent, DispatchPhase, Element, ElementId, FocusHandle, Global,
+    GlobalElementId, Hitbox, HitboxId, IntoElement, IsZero, KeyContext, KeyDownEvent, KeyUpEvent,
+    LayoutId, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
     ParentElement, Pixels, Point, Render, ScrollWheelEvent, SharedString, Size, Style,
     StyleRefinement, Styled, Task, TooltipId, View, Visibility, WindowContext,
 };
@@ -594,6 +594,33 @@ pub trait InteractiveElement: Sized {
         self
     }
 
+    /// Describes this element to assistive technology, like screen readers, adding
+    /// it to the window's [`AccessibilityTree`](crate::AccessibilityTree).
+    fn accessibility(mut self, properties: AccessibilityProperties) -> Self {
+        self.interactivity().accessibility = Some(properties);
+        self
+    }
+
+    #[cfg(any(test, feature = "test-support"))]
+    /// Attaches a stable identifier to this element, so tests can locate it with
+    /// [`WindowContext::bounds_for_test_id`] without matching on its text. IDs
+    /// don't need to be unique, e.g. for list rows.
+    /// This is a noop in release builds
+    fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.interactivity().test_id = Some(id.into());
+        self
+    }
+
+    #[cfg(not(any(test, feature = "test-support")))]
+    /// Attaches a stable identifier to this element, so tests can locate it with
+    /// `WindowContext::bounds_for_test_id` without matching on its text. IDs
+    /// don't need to be unique, e.g. for list rows.
+    /// This is a noop in release builds
+    #[inline]
+    fn test_id(self, _: impl Into<SharedString>) -> Self {
+        self
+    }
+
     #[cfg(any(test, feature = "test-support"))]
     /// Set a key that can be used to look up this element's bounds
     /// in the [`VisualTestContext::debug_bounds`] map
@@ -1276,6 +1303,9 @@ pub struct Interactivity {
     pub(crate) hover_listener: Option<Box<dyn Fn(&bool, &mut WindowContext)>>,
     pub(crate) tooltip_builder: Option<TooltipBuilder>,
     pub(crate) occlude_mouse: bool,
+    pub(crate) accessibility: Option<AccessibilityProperties>,
+    #[cfg(any(test, feature = "test-support"))]
+    pub(crate) test_id: Option<SharedString>,
 
     #[cfg(debug_assertions)]
     pub(crate) location: Option<core::panic::Location<'static>>,
@@ -1374,7 +1404,9 @@ impl Interactivity {
                     }
 
                     if let Some(active_tooltip) = element_state.active_tooltip.as_ref() {
-                        if let Some(active_tooltip) = active_tooltip.borrow().as_ref() {
+                        if cx.tooltips_suppressed() {
+                            active_tooltip.borrow_mut().take();
+                        } else if let Some(active_tooltip) = active_tooltip.borrow().as_ref() {
                             if let Some(tooltip) = active_tooltip.tooltip.clone() {
                                 self.tooltip_id = Some(cx.set_tooltip(tooltip));
                             }
@@ -1382,6 +1414,14 @@ impl Interactivity {
                     }
                 }
 
+                if cx.inspector_enabled() {
+                    #[cfg(debug_assertions)]
+                    let location = self.location;
+                    #[cfg(not(debug_assertions))]
+                    let location = None;
+                    cx.record_inspected_element(bounds, &style, location);
+                }
+
                 cx.with_text_style(style.text_style().cloned(), |cx| {
                     cx.with_content_mask(style.overflow_mask(bounds, cx.rem_size()), |cx| {
                         let hitbox = if self.should_insert_hitbox(&style) {
@@ -1492,6 +1532,11 @@ impl Interactivity {
                         .insert(debug_selector.clone(), bounds);
                 }
 
+                #[cfg(any(test, feature = "test-support"))]
+                if let Some(test_id) = &self.test_id {
+                    cx.insert_test_id(test_id.clone(), bounds);
+                }
+
                 self.paint_hover_group_handler(cx);
 
                 if style.visibility == Visibility::Hidden {
@@ -1527,7 +1572,11 @@ impl Interactivity {
                                     }
 
                                     self.paint_keyboard_listeners(cx);
-                                    f(&style, cx);
+                                    cx.with_accessibility_node(
+                                        self.accessibility.as_ref(),
+                                        bounds,
+                                        |cx| f(&style, cx),
+                                    );
 
                                     if hitbox.is_some() {
                                         if let Some(group) = self.group.as_ref() {
@@ -1909,7 +1958,7 @@ impl Interactivity {
                             return;
                         }
 
-                        if phase != DispatchPhase::Bubble {
+                        if phase != DispatchPhase::Bubble || cx.tooltips_suppressed() {
                             return;
                         }
 
@@ -1920,6 +1969,10 @@ impl Interactivity {
                                 move |mut cx| async move {
                                     cx.background_executor().timer(TOOLTIP_DELAY).await;
                                     cx.update(|cx| {
+                                        if cx.tooltips_suppressed() {
+                                            active_tooltip.borrow_mut().take();
+                                            return;
+                                        }
                                         active_tooltip.borrow_mut().replace(ActiveTooltip {
                                             tooltip: Some(AnyTooltip {
                                                 view: build_tooltip(cx),
@@ -92,6 +92,17 @@ impl From<Arc<RenderImage>> for ImageSource {
     }
 }
 
+/// How far the content of an [`ImageSource`] has loaded.
+#[derive(Clone, Debug)]
+pub enum ImageLoadState {
+    /// The image is being loaded or decoded on a background task.
+    Loading,
+    /// The image is ready to be painted.
+    Loaded(Arc<RenderImage>),
+    /// The image couldn't be loaded or decoded.
+    Failed,
+}
+
 /// An image element.
 pub struct Img {
     interactivity: Interactivity,
@@ -286,20 +297,57 @@ impl InteractiveElement for Img {
 
 impl ImageSource {
     pub(crate) fn use_data(&self, cx: &mut WindowContext) -> Option<Arc<RenderImage>> {
+        // Failures are logged once, when the image is loaded.
+        if let Some(uri_or_path) = self.uri_or_path() {
+            return cx.use_asset::<ImageAsset>(&uri_or_path)?.ok();
+        }
         match self {
-            ImageSource::Uri(_) | ImageSource::Embedded(_) | ImageSource::File(_) => {
-                let uri_or_path: UriOrPath = match self {
-                    ImageSource::Uri(uri) => uri.clone().into(),
-                    ImageSource::File(path) => path.clone().into(),
-                    ImageSource::Embedded(path) => UriOrPath::Embedded(path.clone()),
-                    _ => unreachable!(),
-                };
+            ImageSource::Render(data) => Some(data.to_owned()),
+            ImageSource::Image(data) => cx.use_asset::<ImageDecoder>(data)?.ok(),
+            ImageSource::Uri(_) | ImageSource::Embedded(_) | ImageSource::File(_) => None,
+        }
+    }
 
-                cx.use_asset::<ImageAsset>(&uri_or_path)?.log_err()
+    /// Loads the image on a background task if it isn't cached yet, and returns how
+    /// far it has loaded. The current view is redrawn once loading finishes.
+    pub fn use_load_state(&self, cx: &mut WindowContext) -> ImageLoadState {
+        // Failures are logged once, when the image is loaded, rather than every
+        // frame the failed image is drawn.
+        let data = if let Some(uri_or_path) = self.uri_or_path() {
+            cx.use_asset::<ImageAsset>(&uri_or_path)
+                .map(|result| result.ok())
+        } else {
+            match self {
+                ImageSource::Render(data) => Some(Some(data.to_owned())),
+                ImageSource::Image(data) => {
+                    cx.use_asset::<ImageDecoder>(data).map(|result| result.ok())
+                }
+                ImageSource::Uri(_) | ImageSource::Embedded(_) | ImageSource::File(_) => None,
             }
+        };
+        match data {
+            None => ImageLoadState::Loading,
+            Some(Some(data)) => ImageLoadState::Loaded(data),
+            Some(None) => ImageLoadState::Failed,
+        }
+    }
 
-            ImageSource::Render(data) => Some(data.to_owned()),
-            ImageSource::Image(data) => cx.use_asset::<ImageDecoder>(data)?.log_err(),
+    /// Removes the image from GPUI's asset cache, so that its decoded data is freed
+    /// once nothing else holds it, and it's loaded again the next time it's used.
+    pub fn remove_from_cache(&self, cx: &mut AppContext) {
+        if let Some(uri_or_path) = self.uri_or_path() {
+            cx.remove_cached_asset::<ImageAsset>(&uri_or_path);
+        } else if let ImageSource::Image(data) = self {
+            cx.remove_cached_asset::<ImageDecoder>(data);
+        }
+    }
+
+    fn uri_or_path(&self) -> Option<UriOrPath> {
+        match self {
+            ImageSource::Uri(uri) => Some(uri.clone().into()),
+            ImageSource::File(path) => Some(path.clone().into()),
+            ImageSource::Embedded(path) => Some(UriOrPath::Embedded(path.clone())),
+            ImageSource::Render(_) | ImageSource::Image(_) => None,
         }
     }
 
@@ -334,7 +382,10 @@ impl Asset for ImageDecoder {
         source: Self::Source,
         cx: &mut AppContext,
     ) -> impl Future<Output = Self::Output> + Send + 'static {
-        let result = source.to_image_data(cx).map_err(Arc::new);
+        let result = source
+            .to_image_data(cx)
+            .map_err(Arc::new)
+            .inspect_err(|error| log::error!("failed to decode image: {error}"));
         async { result }
     }
 }
@@ -355,7 +406,7 @@ impl Asset for ImageAsset {
         // let scale_factor = cx.scale_factor();
         let svg_renderer = cx.svg_renderer();
         let asset_source = cx.asset_source().clone();
-        async move {
+        let load = async move {
             let bytes = match source.clone() {
                 UriOrPath::Path(uri) => fs::read(uri.as_ref())?,
                 UriOrPath::Uri(uri) => {
@@ -436,7 +487,11 @@ impl Asset for ImageAsset {
                 RenderImage::new(SmallVec::from_elem(Frame::new(buffer), 1))
             };
 
-            Ok(Arc::new(data))
+            Ok::<_, ImageCacheError>(Arc::new(data))
+        };
+        async move {
+            load.await
+                .inspect_err(|error| log::error!("failed to load image: {error}"))
         }
     }
 }
@@ -1,5 +1,6 @@
 mod anchored;
 mod animation;
+mod cached;
 mod canvas;
 mod common;
 mod deferred;
@@ -13,6 +14,7 @@ mod uniform_list;
 
 pub use anchored::*;
 pub use animation::*;
+pub use cached::*;
 pub use canvas::*;
 pub use common::*;
 pub use deferred::*;
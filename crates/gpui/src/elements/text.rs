@@ -129,7 +129,7 @@ impl IntoElement for SharedString {
 /// and just pass text directly.
 pub struct StyledText {
     text: SharedString,
-    runs: Option<Vec<TextRun>>,
+    runs: Option<Arc<[TextRun]>>,
     layout: TextLayout,
 }
 
@@ -172,13 +172,14 @@ impl StyledText {
         if ix < self.text.len() {
             runs.push(default_style.to_run(self.text.len() - ix));
         }
-        self.runs = Some(runs);
+        self.runs = Some(runs.into());
         self
     }
 
-    /// Set the text runs for this piece of text.
-    pub fn with_runs(mut self, runs: Vec<TextRun>) -> Self {
-        self.runs = Some(runs);
+    /// Set the text runs for this piece of text. Runs shared with other elements,
+    /// like ones cached across frames, are used without being copied.
+    pub fn with_runs(mut self, runs: impl Into<Arc<[TextRun]>>) -> Self {
+        self.runs = Some(runs.into());
         self
     }
 }
@@ -254,7 +255,7 @@ impl TextLayout {
     fn layout(
         &self,
         text: SharedString,
-        runs: Option<Vec<TextRun>>,
+        runs: Option<Arc<[TextRun]>>,
         cx: &mut WindowContext,
     ) -> LayoutId {
         let text_style = cx.text_style();
@@ -266,7 +267,7 @@ impl TextLayout {
         let runs = if let Some(runs) = runs {
             runs
         } else {
-            vec![text_style.to_run(text.len())]
+            Arc::from([text_style.to_run(text.len())])
         };
 
         let layout_id = cx.request_measured_layout(Default::default(), {
@@ -574,7 +575,10 @@ impl Element for InteractiveText {
                     .map(|interactive_state| interactive_state.unwrap_or_default());
 
                 if let Some(interactive_state) = interactive_state.as_ref() {
-                    if let Some(active_tooltip) = interactive_state.active_tooltip.borrow().as_ref()
+                    if cx.tooltips_suppressed() {
+                        interactive_state.active_tooltip.take();
+                    } else if let Some(active_tooltip) =
+                        interactive_state.active_tooltip.borrow().as_ref()
                     {
                         if let Some(tooltip) = active_tooltip.tooltip.clone() {
                             cx.set_tooltip(tooltip);
@@ -690,7 +694,7 @@ impl Element for InteractiveText {
                         }
                         let position = position.unwrap();
 
-                        if phase != DispatchPhase::Bubble {
+                        if phase != DispatchPhase::Bubble || cx.tooltips_suppressed() {
                             return;
                         }
 
@@ -702,6 +706,10 @@ impl Element for InteractiveText {
                                 move |mut cx| async move {
                                     cx.background_executor().timer(TOOLTIP_DELAY).await;
                                     cx.update(|cx| {
+                                        if cx.tooltips_suppressed() {
+                                            active_tooltip.take();
+                                            return;
+                                        }
                                         let new_tooltip =
                                             tooltip_builder(position, cx).map(|tooltip| {
                                                 ActiveTooltip {
@@ -5,7 +5,7 @@
 //! elements with uniform height.
 
 use crate::{
-    point, size, AnyElement, AvailableSpace, Bounds, ContentMask, Element, ElementId,
+    point, px, size, AnyElement, AvailableSpace, Bounds, ContentMask, Element, ElementId,
     GlobalElementId, Hitbox, InteractiveElement, Interactivity, IntoElement, IsZero, LayoutId,
     ListSizingBehavior, Pixels, Render, ScrollHandle, Size, StyleRefinement, Styled, View,
     ViewContext, WindowContext,
@@ -95,6 +95,10 @@ pub struct UniformListScrollState {
     pub deferred_scroll_to_item: Option<usize>,
     /// Size of the item, captured during last layout.
     pub last_item_size: Option<ItemSize>,
+    /// Height of a single item, captured during last layout.
+    pub last_item_height: Option<Pixels>,
+    /// A logical scroll top to restore during the next layout, once the item height is known.
+    pub deferred_scroll_top: Option<(usize, Pixels)>,
 }
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -114,6 +118,8 @@ impl UniformListScrollHandle {
             base_handle: ScrollHandle::new(),
             deferred_scroll_to_item: None,
             last_item_size: None,
+            last_item_height: None,
+            deferred_scroll_top: None,
         })))
     }
 
@@ -128,6 +134,26 @@ impl UniformListScrollHandle {
         this.deferred_scroll_to_item
             .unwrap_or_else(|| this.base_handle.logical_scroll_top().0)
     }
+
+    /// Get the index of the topmost visible item, and how many pixels of it are scrolled
+    /// out of view.
+    pub fn logical_scroll_top(&self) -> (usize, Pixels) {
+        let this = self.0.borrow();
+        if let Some(scroll_top) = this.deferred_scroll_top {
+            return scroll_top;
+        }
+        let Some(item_height) = this.last_item_height.filter(|height| *height > px(0.)) else {
+            return (0, px(0.));
+        };
+        let scroll_top = -this.base_handle.offset().y;
+        let ix = (scroll_top / item_height).floor().max(0.) as usize;
+        (ix, scroll_top - item_height * ix)
+    }
+
+    /// Scroll the list so that the given item is at the top, offset by the given number of pixels.
+    pub fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        self.0.borrow_mut().deferred_scroll_top = Some((ix, offset_in_item));
+    }
 }
 
 impl Styled for UniformList {
@@ -228,12 +254,15 @@ impl Element for UniformList {
 
         let shared_scroll_offset = self.interactivity.scroll_offset.clone().unwrap();
         let item_height = longest_item_size.height;
+        let mut shared_scroll_top = None;
         let shared_scroll_to_item = self.scroll_handle.as_mut().and_then(|handle| {
             let mut handle = handle.0.borrow_mut();
             handle.last_item_size = Some(ItemSize {
                 item: padded_bounds.size,
                 contents: content_size,
             });
+            handle.last_item_height = Some(item_height);
+            shared_scroll_top = handle.deferred_scroll_top.take();
             handle.deferred_scroll_to_item.take()
         });
 
@@ -287,6 +316,14 @@ impl Element for UniformList {
                         scroll_offset = *updated_scroll_offset;
                     }
 
+                    if let Some((ix, offset_in_item)) = shared_scroll_top {
+                        let mut updated_scroll_offset = shared_scroll_offset.borrow_mut();
+                        updated_scroll_offset.y = (-(item_height * ix + offset_in_item))
+                            .max(min_vertical_scroll_offset)
+                            .min(Pixels::ZERO);
+                        scroll_offset = *updated_scroll_offset;
+                    }
+
                     let first_visible_element_ix =
                         (-(scroll_offset.y + padding.top) / item_height).floor() as usize;
                     let last_visible_element_ix = ((-scroll_offset.y + padded_bounds.size.height)
@@ -470,3 +507,52 @@ impl InteractiveElement for UniformList {
         &mut self.interactivity
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        self as gpui, div, px, uniform_list, IntoElement, ParentElement, Render, Styled,
+        TestAppContext, UniformListScrollHandle, ViewContext,
+    };
+
+    struct TestList {
+        scroll_handle: UniformListScrollHandle,
+    }
+
+    impl Render for TestList {
+        fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().w(px(100.)).h(px(100.)).child(
+                uniform_list(cx.view().clone(), "list", 100, |_, range, _| {
+                    range.map(|_| div().h(px(20.))).collect::<Vec<_>>()
+                })
+                .track_scroll(self.scroll_handle.clone())
+                .size_full(),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_logical_scroll_top(cx: &mut TestAppContext) {
+        let scroll_handle = UniformListScrollHandle::new();
+        let (view, cx) = cx.add_window_view(|_| TestList {
+            scroll_handle: scroll_handle.clone(),
+        });
+        assert_eq!(scroll_handle.logical_scroll_top(), (0, px(0.)));
+
+        // The position is reported as set until the list is laid out again.
+        scroll_handle.set_logical_scroll_top(5, px(4.));
+        assert_eq!(scroll_handle.logical_scroll_top(), (5, px(4.)));
+
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(scroll_handle.0.borrow().deferred_scroll_top, None);
+        assert_eq!(scroll_handle.0.borrow().base_handle.offset().y, px(-104.));
+        assert_eq!(scroll_handle.logical_scroll_top(), (5, px(4.)));
+
+        // Positions past the end of the list are clamped to its last page.
+        scroll_handle.set_logical_scroll_top(99, px(10.));
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        assert_eq!(scroll_handle.logical_scroll_top(), (95, px(0.)));
+    }
+}
@@ -467,8 +467,9 @@ impl PlatformInput {
 mod test {
 
     use crate::{
-        self as gpui, div, FocusHandle, InteractiveElement, IntoElement, KeyBinding, Keystroke,
-        ParentElement, Render, TestAppContext, VisualContext,
+        self as gpui, div, point, px, size, Bounds, FocusHandle, InteractiveElement, IntoElement,
+        KeyBinding, Keystroke, Modifiers, ParentElement, Render, Styled, TestAppContext,
+        VisualContext,
     };
 
     struct TestView {
@@ -535,4 +536,58 @@ mod test {
             })
             .unwrap();
     }
+
+    #[gpui::test]
+    fn test_tooltips_suppressed_by_keyboard(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|cx| TestView {
+            saw_key_down: false,
+            saw_action: false,
+            focus_handle: cx.focus_handle(),
+        });
+        view.update(cx, |view, cx| cx.focus(&view.focus_handle));
+
+        cx.simulate_mouse_move(point(px(10.), px(10.)), None, Modifiers::default());
+        assert!(!cx.update(|cx| cx.tooltips_suppressed()));
+
+        cx.simulate_keystrokes("a");
+        assert!(cx.update(|cx| cx.tooltips_suppressed()));
+
+        // A mouse move to where the mouse already is doesn't re-enable tooltips.
+        cx.simulate_mouse_move(point(px(10.), px(10.)), None, Modifiers::default());
+        assert!(cx.update(|cx| cx.tooltips_suppressed()));
+
+        cx.simulate_mouse_move(point(px(20.), px(10.)), None, Modifiers::default());
+        assert!(!cx.update(|cx| cx.tooltips_suppressed()));
+    }
+
+    struct TestIdView;
+
+    impl Render for TestIdView {
+        fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+            div()
+                .size_full()
+                .flex()
+                .flex_col()
+                .child(div().test_id("row").h(px(10.)).w(px(50.)))
+                .child(div().test_id("row").h(px(20.)).w(px(50.)))
+        }
+    }
+
+    #[gpui::test]
+    fn test_bounds_for_test_id(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| TestIdView);
+
+        assert_eq!(
+            cx.update(|cx| cx.bounds_for_test_id("row")),
+            vec![
+                Bounds::new(point(px(0.), px(0.)), size(px(50.), px(10.))),
+                Bounds::new(point(px(0.), px(10.)), size(px(50.), px(20.))),
+            ]
+        );
+        assert_eq!(
+            cx.bounds_for_test_id("row"),
+            Some(Bounds::new(point(px(0.), px(0.)), size(px(50.), px(10.))))
+        );
+        assert_eq!(cx.bounds_for_test_id("missing"), None);
+    }
 }
@@ -53,7 +53,7 @@ use crate::{
     Action, ActionRegistry, DispatchPhase, EntityId, FocusId, KeyBinding, KeyContext, Keymap,
     Keystroke, ModifiersChangedEvent, WindowContext,
 };
-use collections::FxHashMap;
+use collections::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
 use std::{
     any::{Any, TypeId},
@@ -547,6 +547,14 @@ impl DispatchTree {
         view_path
     }
 
+    /// Returns whether any of the nodes in the given range is the root of one of the views.
+    pub fn contains_any_view(&self, range: Range<usize>, view_ids: &FxHashSet<EntityId>) -> bool {
+        self.nodes[range].iter().any(|node| {
+            node.view_id
+                .map_or(false, |view_id| view_ids.contains(&view_id))
+        })
+    }
+
     pub fn node(&self, node_id: DispatchNodeId) -> &DispatchNode {
         &self.nodes[node_id.0]
     }
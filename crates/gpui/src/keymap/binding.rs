@@ -65,6 +65,11 @@ impl KeyBinding {
         self.keystrokes.as_slice()
     }
 
+    /// Get the context in which this binding is active, if it's restricted to one
+    pub fn predicate(&self) -> Option<&KeyBindingContextPredicate> {
+        self.context_predicate.as_ref()
+    }
+
     /// Get the action associated with this binding
     pub fn action(&self) -> &dyn Action {
         self.action.as_ref()
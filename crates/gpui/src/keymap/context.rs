@@ -241,6 +241,16 @@ impl KeyBindingContextPredicate {
         }
     }
 
+    fn precedence(&self) -> u32 {
+        match self {
+            Self::Child(_, _) => PRECEDENCE_CHILD,
+            Self::Or(_, _) => PRECEDENCE_OR,
+            Self::And(_, _) => PRECEDENCE_AND,
+            Self::Equal(_, _) | Self::NotEqual(_, _) => PRECEDENCE_EQ,
+            Self::Not(_) | Self::Identifier(_) => PRECEDENCE_NOT,
+        }
+    }
+
     fn parse_expr(mut source: &str, min_precedence: u32) -> anyhow::Result<(Self, &str)> {
         type Op = fn(
             KeyBindingContextPredicate,
@@ -345,6 +355,36 @@ impl KeyBindingContextPredicate {
     }
 }
 
+/// Writes the predicate in the syntax accepted by [`KeyBindingContextPredicate::parse`],
+/// with the parentheses needed to parse it back to the same predicate.
+impl fmt::Display for KeyBindingContextPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_operand =
+            |f: &mut fmt::Formatter<'_>, operand: &Self, min_precedence: u32| -> fmt::Result {
+                if operand.precedence() < min_precedence {
+                    write!(f, "({})", operand)
+                } else {
+                    write!(f, "{}", operand)
+                }
+            };
+        let (left, operator, right) = match self {
+            Self::Identifier(name) => return write!(f, "{}", name),
+            Self::Equal(left, right) => return write!(f, "{} == {}", left, right),
+            Self::NotEqual(left, right) => return write!(f, "{} != {}", left, right),
+            Self::Not(predicate) => {
+                write!(f, "!")?;
+                return write_operand(f, predicate, PRECEDENCE_NOT);
+            }
+            Self::Child(left, right) => (left, ">", right),
+            Self::And(left, right) => (left, "&&", right),
+            Self::Or(left, right) => (left, "||", right),
+        };
+        write_operand(f, left, self.precedence())?;
+        write!(f, " {} ", operator)?;
+        write_operand(f, right, self.precedence() + 1)
+    }
+}
+
 const PRECEDENCE_CHILD: u32 = 1;
 const PRECEDENCE_OR: u32 = 2;
 const PRECEDENCE_AND: u32 = 3;
@@ -531,4 +571,29 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_display_predicates() {
+        for source in [
+            "Editor",
+            "Editor && mode == full",
+            "!(a || b) && c != d",
+            "a && (b || (c > d))",
+            "Workspace > Pane > !Editor",
+        ] {
+            let predicate = KeyBindingContextPredicate::parse(source).unwrap();
+            let displayed = predicate.to_string();
+            assert_eq!(
+                KeyBindingContextPredicate::parse(&displayed).unwrap(),
+                predicate,
+                "{source} was displayed as {displayed}"
+            );
+        }
+        assert_eq!(
+            KeyBindingContextPredicate::parse("a&&b==c")
+                .unwrap()
+                .to_string(),
+            "a && b == c"
+        );
+    }
 }
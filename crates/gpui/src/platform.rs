@@ -24,11 +24,12 @@ mod test;
 mod windows;
 
 use crate::{
-    point, Action, AnyWindowHandle, AppContext, AsyncWindowContext, BackgroundExecutor, Bounds,
-    DevicePixels, DispatchEventResult, Font, FontId, FontMetrics, FontRun, ForegroundExecutor,
-    GPUSpecs, GlyphId, ImageSource, Keymap, LineLayout, Pixels, PlatformInput, Point,
-    RenderGlyphParams, RenderImage, RenderImageParams, RenderSvgParams, ScaledPixels, Scene,
-    SharedString, Size, SvgSize, Task, TaskLabel, WindowContext, DEFAULT_WINDOW_SIZE,
+    point, AccessibilityTree, Action, AnyWindowHandle, AppContext, AsyncWindowContext,
+    BackgroundExecutor, Bounds, DevicePixels, DispatchEventResult, Font, FontId, FontMetrics,
+    FontRun, ForegroundExecutor, GPUSpecs, GlyphId, ImageSource, Keymap, LineLayout, Pixels,
+    PlatformInput, Point, RenderGlyphParams, RenderImage, RenderImageParams, RenderSvgParams,
+    ScaledPixels, Scene, SharedString, Size, SvgSize, Task, TaskLabel, WindowContext,
+    DEFAULT_WINDOW_SIZE,
 };
 use anyhow::{anyhow, Result};
 use async_task::Runnable;
@@ -189,6 +190,8 @@ pub(crate) trait Platform: 'static {
 
     fn set_cursor_style(&self, style: CursorStyle);
     fn should_auto_hide_scrollbars(&self) -> bool;
+    fn should_reduce_transparency(&self) -> bool;
+    fn should_reduce_motion(&self) -> bool;
 
     #[cfg(target_os = "linux")]
     fn write_to_primary(&self, item: ClipboardItem);
@@ -373,6 +376,12 @@ pub(crate) trait PlatformWindow: HasWindowHandle + HasDisplayHandle {
     fn on_appearance_changed(&self, callback: Box<dyn FnMut()>);
     fn draw(&self, scene: &Scene);
     fn completed_frame(&self) {}
+    /// Exports the accessible elements of the window to the platform's assistive
+    /// technology APIs, after they changed.
+    ///
+    /// Only macOS implements this so far. Elsewhere the tree is only available to tests
+    /// and tools through [`WindowContext::accessibility_tree`](crate::WindowContext::accessibility_tree).
+    fn update_accessibility_tree(&self, _tree: &AccessibilityTree) {}
     fn sprite_atlas(&self) -> Arc<dyn PlatformAtlas>;
 
     // macOS specific methods
@@ -579,7 +588,7 @@ pub(crate) enum AtlasTextureKind {
     Path = 2,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 pub(crate) struct TileId(pub(crate) u32);
 
@@ -1117,12 +1126,22 @@ impl ClipboardItem {
     pub fn new_string_with_metadata(text: String, metadata: String) -> Self {
         Self {
             entries: vec![ClipboardEntry::String(ClipboardString {
-                text,
                 metadata: Some(metadata),
+                ..ClipboardString::new(text)
             })],
         }
     }
 
+    /// Create a new ClipboardItem::String with the given text, along with HTML and RTF
+    /// representations of it that keep its formatting when pasted into other applications
+    pub fn new_string_with_formatting(text: String, html: String, rtf: String) -> Self {
+        Self {
+            entries: vec![ClipboardEntry::String(
+                ClipboardString::new(text).with_html(html).with_rtf(rtf),
+            )],
+        }
+    }
+
     /// Create a new ClipboardItem::String with the given text and associated metadata
     pub fn new_string_with_json_metadata<T: Serialize>(text: String, metadata: T) -> Self {
         Self {
@@ -1146,7 +1165,7 @@ impl ClipboardItem {
         let mut any_entries = false;
 
         for entry in self.entries.iter() {
-            if let ClipboardEntry::String(ClipboardString { text, metadata: _ }) = entry {
+            if let ClipboardEntry::String(ClipboardString { text, .. }) = entry {
                 answer.push_str(text);
                 any_entries = true;
             }
@@ -1301,6 +1320,12 @@ impl Image {
 pub struct ClipboardString {
     pub(crate) text: String,
     pub(crate) metadata: Option<String>,
+    /// The text as an HTML fragment, offered to applications that paste formatted text.
+    /// Only written to the clipboard on macOS and Windows.
+    pub(crate) html: Option<String>,
+    /// The text as an RTF document, offered to applications that paste formatted text.
+    /// Only written to the clipboard on macOS and Windows.
+    pub(crate) rtf: Option<String>,
 }
 
 impl ClipboardString {
@@ -1309,6 +1334,8 @@ impl ClipboardString {
         Self {
             text,
             metadata: None,
+            html: None,
+            rtf: None,
         }
     }
 
@@ -1319,6 +1346,20 @@ impl ClipboardString {
         self
     }
 
+    /// Return a new clipboard string that also offers the given HTML representation of its
+    /// text to applications that paste formatted text.
+    pub fn with_html(mut self, html: String) -> Self {
+        self.html = Some(html);
+        self
+    }
+
+    /// Return a new clipboard string that also offers the given RTF representation of its
+    /// text to applications that paste formatted text.
+    pub fn with_rtf(mut self, rtf: String) -> Self {
+        self.rtf = Some(rtf);
+        self
+    }
+
     /// Get the text of the clipboard string
     pub fn text(&self) -> &String {
         &self.text
@@ -1329,6 +1370,16 @@ impl ClipboardString {
         self.text
     }
 
+    /// Get the HTML representation of the clipboard string's text, if any
+    pub fn html(&self) -> Option<&str> {
+        self.html.as_deref()
+    }
+
+    /// Get the RTF representation of the clipboard string's text, if any
+    pub fn rtf(&self) -> Option<&str> {
+        self.rtf.as_deref()
+    }
+
     /// Get the metadata of the clipboard string, formatted as JSON
     pub fn metadata_json<T>(&self) -> Option<T>
     where
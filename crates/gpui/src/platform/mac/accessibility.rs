@@ -0,0 +1,126 @@
+use super::{ns_string, BoolExt};
+use crate::{AccessibilityNode, AccessibilityRole, AccessibilityTree, Bounds, Pixels};
+use cocoa::{
+    appkit::NSView,
+    base::{id, nil},
+    foundation::{NSArray, NSAutoreleasePool, NSPoint, NSRect, NSSize},
+};
+use objc::{class, msg_send, sel, sel_impl};
+
+/// Exports the accessibility tree to AppKit, as accessibility elements nested under
+/// the window's view, replacing the elements of the previous tree.
+pub(crate) unsafe fn set_accessibility_children(native_view: id, tree: &AccessibilityTree) {
+    let view_size: crate::Size<Pixels> = NSView::frame(native_view).size.into();
+    let view_bounds = Bounds {
+        origin: Default::default(),
+        size: view_size,
+    };
+
+    let nodes = tree.nodes();
+    let mut elements = Vec::with_capacity(nodes.len());
+    let mut children = vec![Vec::new(); nodes.len()];
+    let mut roots = Vec::new();
+    // The indices of the ancestors of the current node, the root first.
+    let mut ancestors = Vec::new();
+    for (ix, node) in nodes.iter().enumerate() {
+        ancestors.truncate(node.depth);
+        let parent_ix = ancestors.last().copied();
+        let (parent, parent_bounds) = match parent_ix {
+            Some(parent_ix) => (elements[parent_ix], nodes[parent_ix].bounds),
+            None => (native_view, view_bounds),
+        };
+
+        let element = new_element(node, parent, parent_bounds);
+        match parent_ix {
+            Some(parent_ix) => children[parent_ix].push(element),
+            None => roots.push(element),
+        }
+        elements.push(element);
+        ancestors.push(ix);
+    }
+
+    for (element, children) in elements.into_iter().zip(children) {
+        let children = NSArray::arrayWithObjects(nil, &children);
+        let _: () = msg_send![element, setAccessibilityChildren: children];
+    }
+    let roots = NSArray::arrayWithObjects(nil, &roots);
+    let _: () = msg_send![native_view, setAccessibilityChildren: roots];
+}
+
+unsafe fn new_element(node: &AccessibilityNode, parent: id, parent_bounds: Bounds<Pixels>) -> id {
+    let element: id = msg_send![class!(NSAccessibilityElement), alloc];
+    let element: id = msg_send![element, init];
+    let element = element.autorelease();
+
+    let properties = &node.properties;
+    let (role, subrole) = ax_role(properties.role);
+    let _: () = msg_send![element, setAccessibilityRole: ns_string(role)];
+    if let Some(subrole) = subrole {
+        let _: () = msg_send![element, setAccessibilitySubrole: ns_string(subrole)];
+    }
+    if let Some(name) = properties.name.as_ref() {
+        // VoiceOver reads the value of static text rather than its label.
+        if properties.role == AccessibilityRole::Label {
+            let _: () = msg_send![element, setAccessibilityValue: ns_string(name)];
+        } else {
+            let _: () = msg_send![element, setAccessibilityLabel: ns_string(name)];
+        }
+    }
+
+    let state = &properties.state;
+    if matches!(
+        properties.role,
+        AccessibilityRole::CheckBox | AccessibilityRole::Switch | AccessibilityRole::RadioButton
+    ) {
+        // Checkboxes report being mixed with a value of 2.
+        let checked: isize = match state.checked {
+            Some(false) => 0,
+            Some(true) => 1,
+            None => 2,
+        };
+        let value: id = msg_send![class!(NSNumber), numberWithInteger: checked];
+        let _: () = msg_send![element, setAccessibilityValue: value];
+    }
+    if let Some(expanded) = state.expanded {
+        let _: () = msg_send![element, setAccessibilityExpanded: expanded.to_objc()];
+    }
+    let _: () = msg_send![element, setAccessibilitySelected: state.selected.to_objc()];
+    let _: () = msg_send![element, setAccessibilityEnabled: (!state.disabled).to_objc()];
+
+    // AppKit's y axis goes up from the bottom of the parent.
+    let bounds = node.bounds;
+    let frame = NSRect::new(
+        NSPoint::new(
+            (bounds.origin.x - parent_bounds.origin.x).0 as f64,
+            (parent_bounds.bottom() - bounds.bottom()).0 as f64,
+        ),
+        NSSize::new(bounds.size.width.0 as f64, bounds.size.height.0 as f64),
+    );
+    let _: () = msg_send![element, setAccessibilityFrameInParentSpace: frame];
+    let _: () = msg_send![element, setAccessibilityParent: parent];
+    element
+}
+
+/// Returns the AppKit role and subrole of the given role.
+fn ax_role(role: AccessibilityRole) -> (&'static str, Option<&'static str>) {
+    match role {
+        AccessibilityRole::Group | AccessibilityRole::ListItem => ("AXGroup", None),
+        AccessibilityRole::Label => ("AXStaticText", None),
+        AccessibilityRole::Link => ("AXLink", None),
+        AccessibilityRole::Button => ("AXButton", None),
+        AccessibilityRole::CheckBox => ("AXCheckBox", None),
+        AccessibilityRole::Switch => ("AXCheckBox", Some("AXSwitch")),
+        AccessibilityRole::RadioButton => ("AXRadioButton", None),
+        AccessibilityRole::Disclosure => ("AXDisclosureTriangle", None),
+        AccessibilityRole::List => ("AXList", None),
+        AccessibilityRole::TabList => ("AXTabGroup", None),
+        AccessibilityRole::Tab => ("AXRadioButton", Some("AXTabButton")),
+        AccessibilityRole::Menu => ("AXMenu", None),
+        AccessibilityRole::MenuItem => ("AXMenuItem", None),
+        AccessibilityRole::TextInput => ("AXTextField", None),
+        AccessibilityRole::Image => ("AXImage", None),
+        AccessibilityRole::ProgressIndicator => ("AXProgressIndicator", None),
+        AccessibilityRole::Dialog => ("AXGroup", Some("AXDialog")),
+        AccessibilityRole::Tooltip => ("AXHelpTag", None),
+    }
+}
@@ -16,8 +16,8 @@ use cocoa::{
     appkit::{
         NSApplication, NSApplicationActivationPolicy::NSApplicationActivationPolicyRegular,
         NSEventModifierFlags, NSMenu, NSMenuItem, NSModalResponse, NSOpenPanel, NSPasteboard,
-        NSPasteboardTypePNG, NSPasteboardTypeRTF, NSPasteboardTypeRTFD, NSPasteboardTypeString,
-        NSPasteboardTypeTIFF, NSSavePanel, NSWindow,
+        NSPasteboardTypeHTML, NSPasteboardTypePNG, NSPasteboardTypeRTF, NSPasteboardTypeRTFD,
+        NSPasteboardTypeString, NSPasteboardTypeTIFF, NSSavePanel, NSWindow,
     },
     base::{id, nil, selector, BOOL, YES},
     foundation::{
@@ -883,6 +883,23 @@ impl Platform for MacPlatform {
         }
     }
 
+    fn should_reduce_transparency(&self) -> bool {
+        unsafe {
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let reduce_transparency: BOOL =
+                msg_send![workspace, accessibilityDisplayShouldReduceTransparency];
+            reduce_transparency == YES
+        }
+    }
+
+    fn should_reduce_motion(&self) -> bool {
+        unsafe {
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let reduce_motion: BOOL = msg_send![workspace, accessibilityDisplayShouldReduceMotion];
+            reduce_motion == YES
+        }
+    }
+
     fn write_to_clipboard(&self, item: ClipboardItem) {
         use crate::ClipboardEntry;
 
@@ -912,8 +929,7 @@ impl Platform for MacPlatform {
                         .init_attributed_string(NSString::alloc(nil).init_str(""));
 
                     for entry in item.entries {
-                        if let ClipboardEntry::String(ClipboardString { text, metadata: _ }) = entry
-                        {
+                        if let ClipboardEntry::String(ClipboardString { text, .. }) = entry {
                             let to_append = NSAttributedString::alloc(nil)
                                 .init_attributed_string(NSString::alloc(nil).init_str(&text));
 
@@ -1130,7 +1146,10 @@ impl MacPlatform {
             });
 
         ClipboardItem {
-            entries: vec![ClipboardEntry::String(ClipboardString { text, metadata })],
+            entries: vec![ClipboardEntry::String(ClipboardString {
+                metadata,
+                ..ClipboardString::new(text)
+            })],
         }
     }
 
@@ -1147,6 +1166,20 @@ impl MacPlatform {
             .pasteboard
             .setData_forType(text_bytes, NSPasteboardTypeString);
 
+        for (format, pasteboard_type) in [
+            (string.html.as_ref(), NSPasteboardTypeHTML),
+            (string.rtf.as_ref(), NSPasteboardTypeRTF),
+        ] {
+            if let Some(format) = format {
+                let bytes = NSData::dataWithBytes_length_(
+                    nil,
+                    format.as_ptr() as *const c_void,
+                    format.len() as u64,
+                );
+                state.pasteboard.setData_forType(bytes, pasteboard_type);
+            }
+        }
+
         if let Some(metadata) = string.metadata.as_ref() {
             let hash_bytes = ClipboardString::text_hash(&string.text).to_be_bytes();
             let hash_bytes = NSData::dataWithBytes_length_(
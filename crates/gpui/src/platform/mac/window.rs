@@ -1,9 +1,12 @@
-use super::{ns_string, renderer, MacDisplay, NSRange, NSStringExt};
+use super::{
+    accessibility::set_accessibility_children, ns_string, renderer, MacDisplay, NSRange,
+    NSStringExt,
+};
 use crate::{
-    platform::PlatformInputHandler, point, px, size, AnyWindowHandle, Bounds, DisplayLink,
-    ExternalPaths, FileDropEvent, ForegroundExecutor, KeyDownEvent, Keystroke, Modifiers,
-    ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels,
-    PlatformAtlas, PlatformDisplay, PlatformInput, PlatformWindow, Point, PromptLevel,
+    platform::PlatformInputHandler, point, px, size, AccessibilityTree, AnyWindowHandle, Bounds,
+    DisplayLink, ExternalPaths, FileDropEvent, ForegroundExecutor, KeyDownEvent, Keystroke,
+    Modifiers, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
+    Pixels, PlatformAtlas, PlatformDisplay, PlatformInput, PlatformWindow, Point, PromptLevel,
     ScaledPixels, Size, Timer, WindowAppearance, WindowBackgroundAppearance, WindowBounds,
     WindowKind, WindowParams,
 };
@@ -1113,6 +1116,11 @@ impl PlatformWindow for MacWindow {
         this.renderer.draw(scene);
     }
 
+    fn update_accessibility_tree(&self, tree: &AccessibilityTree) {
+        let native_view = self.0.lock().native_view.as_ptr();
+        unsafe { set_accessibility_children(native_view, tree) }
+    }
+
     fn sprite_atlas(&self) -> Arc<dyn PlatformAtlas> {
         self.0.lock().renderer.sprite_atlas().clone()
     }
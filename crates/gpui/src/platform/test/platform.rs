@@ -285,6 +285,14 @@ impl Platform for TestPlatform {
         false
     }
 
+    fn should_reduce_transparency(&self) -> bool {
+        false
+    }
+
+    fn should_reduce_motion(&self) -> bool {
+        false
+    }
+
     #[cfg(target_os = "linux")]
     fn write_to_primary(&self, item: ClipboardItem) {
         *self.current_primary_item.lock() = Some(item);
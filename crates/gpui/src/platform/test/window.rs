@@ -28,6 +28,7 @@ pub(crate) struct TestWindowState {
     moved_callback: Option<Box<dyn FnMut()>>,
     input_handler: Option<PlatformInputHandler>,
     is_fullscreen: bool,
+    pub(crate) accessibility_tree_updates: usize,
 }
 
 #[derive(Clone)]
@@ -72,6 +73,7 @@ impl TestWindow {
             moved_callback: None,
             input_handler: None,
             is_fullscreen: false,
+            accessibility_tree_updates: 0,
         })))
     }
 
@@ -253,6 +255,10 @@ impl PlatformWindow for TestWindow {
 
     fn draw(&self, _scene: &crate::Scene) {}
 
+    fn update_accessibility_tree(&self, _tree: &crate::AccessibilityTree) {
+        self.0.lock().accessibility_tree_updates += 1;
+    }
+
     fn sprite_atlas(&self) -> sync::Arc<dyn crate::PlatformAtlas> {
         self.0.lock().sprite_atlas.clone()
     }
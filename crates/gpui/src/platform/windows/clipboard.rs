@@ -31,6 +31,10 @@ static CLIPBOARD_HASH_FORMAT: LazyLock<u32> =
     LazyLock::new(|| register_clipboard_format(windows::core::w!("GPUI internal text hash")));
 static CLIPBOARD_METADATA_FORMAT: LazyLock<u32> =
     LazyLock::new(|| register_clipboard_format(windows::core::w!("GPUI internal metadata")));
+static CLIPBOARD_HTML_FORMAT: LazyLock<u32> =
+    LazyLock::new(|| register_clipboard_format(windows::core::w!("HTML Format")));
+static CLIPBOARD_RTF_FORMAT: LazyLock<u32> =
+    LazyLock::new(|| register_clipboard_format(windows::core::w!("Rich Text Format")));
 static CLIPBOARD_SVG_FORMAT: LazyLock<u32> =
     LazyLock::new(|| register_clipboard_format(windows::core::w!("image/svg+xml")));
 static CLIPBOARD_GIF_FORMAT: LazyLock<u32> =
@@ -149,6 +153,19 @@ fn write_string_to_clipboard(item: &ClipboardString) -> Result<()> {
     let encode_wide = item.text.encode_utf16().chain(Some(0)).collect_vec();
     set_data_to_clipboard(&encode_wide, CF_UNICODETEXT.0 as u32)?;
 
+    // Unlike text, these formats are expected to be null-terminated UTF-8.
+    if let Some(html) = item.html.as_ref() {
+        let html = html_clipboard_format(html)
+            .bytes()
+            .chain(Some(0))
+            .collect_vec();
+        set_data_to_clipboard(&html, *CLIPBOARD_HTML_FORMAT)?;
+    }
+    if let Some(rtf) = item.rtf.as_ref() {
+        let rtf = rtf.bytes().chain(Some(0)).collect_vec();
+        set_data_to_clipboard(&rtf, *CLIPBOARD_RTF_FORMAT)?;
+    }
+
     if let Some(metadata) = item.metadata.as_ref() {
         let hash_result = {
             let hash = ClipboardString::text_hash(&item.text);
@@ -164,6 +181,28 @@ fn write_string_to_clipboard(item: &ClipboardString) -> Result<()> {
     Ok(())
 }
 
+// https://learn.microsoft.com/en-us/windows/win32/dataxchg/html-clipboard-format
+fn html_clipboard_format(html: &str) -> String {
+    const START_FRAGMENT: &str = "<html><body><!--StartFragment-->";
+    const END_FRAGMENT: &str = "<!--EndFragment--></body></html>";
+    // The offsets are zero-padded so that the header has the same length whatever they are.
+    let header =
+        |start_html: usize, end_html: usize, start_fragment: usize, end_fragment: usize| {
+            format!(
+                "Version:0.9\r\nStartHTML:{start_html:010}\r\nEndHTML:{end_html:010}\r\n\
+             StartFragment:{start_fragment:010}\r\nEndFragment:{end_fragment:010}\r\n"
+            )
+        };
+    let start_html = header(0, 0, 0, 0).len();
+    let start_fragment = start_html + START_FRAGMENT.len();
+    let end_fragment = start_fragment + html.len();
+    let end_html = end_fragment + END_FRAGMENT.len();
+    header(start_html, end_html, start_fragment, end_fragment)
+        + START_FRAGMENT
+        + html
+        + END_FRAGMENT
+}
+
 fn set_data_to_clipboard<T>(data: &[T], format: u32) -> Result<()> {
     unsafe {
         let global = GlobalAlloc(GMEM_MOVEABLE, std::mem::size_of_val(data))?;
@@ -283,8 +322,8 @@ fn read_string_from_clipboard() -> Option<ClipboardEntry> {
     };
     if hash == ClipboardString::text_hash(&text) {
         Some(ClipboardEntry::String(ClipboardString {
-            text,
             metadata: Some(metadata),
+            ..ClipboardString::new(text)
         }))
     } else {
         Some(ClipboardEntry::String(ClipboardString::new(text)))
@@ -344,10 +383,7 @@ fn read_files_from_clipboard() -> Option<ClipboardEntry> {
     with_file_names(hdrop, |file_name| {
         filenames.push_str(&file_name);
     });
-    Some(ClipboardEntry::String(ClipboardString {
-        text: filenames,
-        metadata: None,
-    }))
+    Some(ClipboardEntry::String(ClipboardString::new(filenames)))
 }
 
 impl From<ImageFormat> for image::ImageFormat {
@@ -332,6 +332,9 @@ pub struct TextStyle {
     /// The strikethrough style of the text
     pub strikethrough: Option<StrikethroughStyle>,
 
+    /// The overline style of the text
+    pub overline: Option<OverlineStyle>,
+
     /// How to handle whitespace in the text
     pub white_space: WhiteSpace,
 
@@ -360,6 +363,7 @@ impl Default for TextStyle {
             background_color: None,
             underline: None,
             strikethrough: None,
+            overline: None,
             white_space: WhiteSpace::Normal,
             truncate: None,
         }
@@ -397,6 +401,10 @@ impl TextStyle {
             self.strikethrough = Some(strikethrough);
         }
 
+        if let Some(overline) = style.overline {
+            self.overline = Some(overline);
+        }
+
         self
     }
 
@@ -431,6 +439,7 @@ impl TextStyle {
             background_color: self.background_color,
             underline: self.underline,
             strikethrough: self.strikethrough,
+            overline: self.overline,
         }
     }
 }
@@ -454,9 +463,12 @@ pub struct HighlightStyle {
     /// The underline style of the text
     pub underline: Option<UnderlineStyle>,
 
-    /// The underline style of the text
+    /// The strikethrough style of the text
     pub strikethrough: Option<StrikethroughStyle>,
 
+    /// The overline style of the text
+    pub overline: Option<OverlineStyle>,
+
     /// Similar to the CSS `opacity` property, this will cause the text to be less vibrant.
     pub fade_out: Option<f32>,
 }
@@ -471,6 +483,7 @@ impl Hash for HighlightStyle {
         self.background_color.hash(state);
         self.underline.hash(state);
         self.strikethrough.hash(state);
+        self.overline.hash(state);
         state.write_u32(u32::from_be_bytes(
             self.fade_out.map(|f| f.to_be_bytes()).unwrap_or_default(),
         ));
@@ -733,6 +746,17 @@ pub struct StrikethroughStyle {
     pub color: Option<Hsla>,
 }
 
+/// The properties that can be applied to an overline.
+#[derive(Refineable, Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[refineable(Debug)]
+pub struct OverlineStyle {
+    /// The thickness of the overline.
+    pub thickness: Pixels,
+
+    /// The color of the overline.
+    pub color: Option<Hsla>,
+}
+
 /// The kinds of fill that can be applied to a shape.
 #[derive(Clone, Debug)]
 pub enum Fill {
@@ -782,6 +806,7 @@ impl From<&TextStyle> for HighlightStyle {
             background_color: other.background_color,
             underline: other.underline,
             strikethrough: other.strikethrough,
+            overline: other.overline,
             fade_out: None,
         }
     }
@@ -828,6 +853,10 @@ impl HighlightStyle {
             self.strikethrough = other.strikethrough;
         }
 
+        if other.overline.is_some() {
+            self.overline = other.overline;
+        }
+
         match (other.fade_out, self.fade_out) {
             (Some(source_fade), None) => self.fade_out = Some(source_fade),
             (Some(source_fade), Some(dest_fade)) => {
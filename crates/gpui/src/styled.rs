@@ -1,7 +1,7 @@
 use crate::{
     self as gpui, px, relative, rems, AbsoluteLength, AlignItems, CursorStyle, DefiniteLength,
     Fill, FlexDirection, FlexWrap, Font, FontStyle, FontWeight, Hsla, JustifyContent, Length,
-    SharedString, StyleRefinement, WhiteSpace,
+    OverlineStyle, SharedString, StrikethroughStyle, StyleRefinement, WhiteSpace,
 };
 use crate::{TextStyleRefinement, Truncate};
 pub use gpui_macros::{
@@ -441,9 +441,34 @@ pub trait Styled: Sized {
 
     /// Remove the text decoration on this element, this value cascades to its child elements.
     fn text_decoration_none(mut self) -> Self {
+        let style = self.text_style().get_or_insert_with(Default::default);
+        style.underline = None;
+        style.strikethrough = None;
+        style.overline = None;
+        self
+    }
+
+    /// Draw a 1px line through the middle of the text in its color, like deleted text,
+    /// see the [Tailwind Docs](https://tailwindcss.com/docs/text-decoration)
+    fn line_through(mut self) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .strikethrough = Some(StrikethroughStyle {
+            thickness: px(1.),
+            color: None,
+        });
+        self
+    }
+
+    /// Draw a 1px line above the text in its color,
+    /// see the [Tailwind Docs](https://tailwindcss.com/docs/text-decoration)
+    fn overline(mut self) -> Self {
         self.text_style()
             .get_or_insert_with(Default::default)
-            .underline = None;
+            .overline = Some(OverlineStyle {
+            thickness: px(1.),
+            color: None,
+        });
         self
     }
 
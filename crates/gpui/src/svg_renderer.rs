@@ -1,7 +1,9 @@
 use crate::{AssetSource, DevicePixels, IsZero, Result, SharedString, Size};
 use anyhow::anyhow;
+use collections::HashMap;
+use parking_lot::RwLock;
 use resvg::tiny_skia::Pixmap;
-use std::{hash::Hash, sync::Arc};
+use std::{borrow::Cow, hash::Hash, sync::Arc};
 
 #[derive(Clone, PartialEq, Hash, Eq)]
 pub(crate) struct RenderSvgParams {
@@ -12,6 +14,7 @@ pub(crate) struct RenderSvgParams {
 #[derive(Clone)]
 pub(crate) struct SvgRenderer {
     asset_source: Arc<dyn AssetSource>,
+    registered_svgs: Arc<RwLock<HashMap<SharedString, Arc<[u8]>>>>,
 }
 
 pub enum SvgSize {
@@ -21,7 +24,25 @@ pub enum SvgSize {
 
 impl SvgRenderer {
     pub fn new(asset_source: Arc<dyn AssetSource>) -> Self {
-        Self { asset_source }
+        Self {
+            asset_source,
+            registered_svgs: Default::default(),
+        }
+    }
+
+    /// Registers an SVG under the given path, taking precedence over an asset at
+    /// the same path.
+    pub fn register_svg(&self, path: SharedString, bytes: Arc<[u8]>) -> Result<()> {
+        usvg::Tree::from_data(&bytes, &usvg::Options::default())?;
+        self.registered_svgs.write().insert(path, bytes);
+        Ok(())
+    }
+
+    fn load(&self, path: &SharedString) -> Result<Option<Cow<'static, [u8]>>> {
+        if let Some(bytes) = self.registered_svgs.read().get(path) {
+            return Ok(Some(Cow::Owned(bytes.to_vec())));
+        }
+        self.asset_source.load(path)
     }
 
     pub fn render(&self, params: &RenderSvgParams) -> Result<Option<Vec<u8>>> {
@@ -30,7 +51,7 @@ impl SvgRenderer {
         }
 
         // Load the tree.
-        let Some(bytes) = self.asset_source.load(&params.path)? else {
+        let Some(bytes) = self.load(&params.path)? else {
             return Ok(None);
         };
 
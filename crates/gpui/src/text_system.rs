@@ -13,8 +13,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    px, Bounds, DevicePixels, Hsla, Pixels, PlatformTextSystem, Point, Result, SharedString, Size,
-    StrikethroughStyle, UnderlineStyle,
+    px, Bounds, DevicePixels, Hsla, OverlineStyle, Pixels, PlatformTextSystem, Point, Result,
+    SharedString, Size, StrikethroughStyle, UnderlineStyle,
 };
 use anyhow::anyhow;
 use collections::FxHashMap;
@@ -341,6 +341,7 @@ impl WindowTextSystem {
                 if last_run.color == run.color
                     && last_run.underline == run.underline
                     && last_run.strikethrough == run.strikethrough
+                    && last_run.overline == run.overline
                     && last_run.background_color == run.background_color
                 {
                     last_run.len += run.len as u32;
@@ -353,6 +354,7 @@ impl WindowTextSystem {
                 background_color: run.background_color,
                 underline: run.underline,
                 strikethrough: run.strikethrough,
+                overline: run.overline,
             });
         }
 
@@ -408,6 +410,7 @@ impl WindowTextSystem {
                     last_run.color == run.color
                         && last_run.underline == run.underline
                         && last_run.strikethrough == run.strikethrough
+                        && last_run.overline == run.overline
                         && last_run.background_color == run.background_color
                 }) {
                     decoration_runs.last_mut().unwrap().len += run_len_within_line as u32;
@@ -418,6 +421,7 @@ impl WindowTextSystem {
                         background_color: run.background_color,
                         underline: run.underline,
                         strikethrough: run.strikethrough,
+                        overline: run.overline,
                     });
                 }
 
@@ -628,7 +632,7 @@ impl Display for FontStyle {
 }
 
 /// A styled run of text, for use in [`TextLayout`].
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TextRun {
     /// A number of utf8 bytes
     pub len: usize,
@@ -642,6 +646,8 @@ pub struct TextRun {
     pub underline: Option<UnderlineStyle>,
     /// The strikethrough style (if any)
     pub strikethrough: Option<StrikethroughStyle>,
+    /// The overline style (if any)
+    pub overline: Option<OverlineStyle>,
 }
 
 /// An identifier for a specific glyph, as returned by [`TextSystem::layout_line`].
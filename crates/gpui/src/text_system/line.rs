@@ -1,6 +1,7 @@
 use crate::{
-    black, fill, point, px, size, Bounds, Hsla, LineLayout, Pixels, Point, Result, SharedString,
-    StrikethroughStyle, UnderlineStyle, WindowContext, WrapBoundary, WrappedLineLayout,
+    black, fill, point, px, size, Bounds, Hsla, LineLayout, OverlineStyle, Pixels, Point, Result,
+    SharedString, StrikethroughStyle, UnderlineStyle, WindowContext, WrapBoundary,
+    WrappedLineLayout,
 };
 use derive_more::{Deref, DerefMut};
 use smallvec::SmallVec;
@@ -23,6 +24,9 @@ pub struct DecorationRun {
 
     /// The strikethrough style for this run
     pub strikethrough: Option<StrikethroughStyle>,
+
+    /// The overline style for this run
+    pub overline: Option<OverlineStyle>,
 }
 
 /// A line of text that has been shaped and decorated.
@@ -119,12 +123,18 @@ fn paint_line(
     cx.paint_layer(line_bounds, |cx| {
         let padding_top = (line_height - layout.ascent - layout.descent) / 2.;
         let baseline_offset = point(px(0.), padding_top + layout.ascent);
-        let mut decoration_runs = decoration_runs.iter();
+        let run_ends = decoration_runs
+            .iter()
+            .scan(0, |end, run| {
+                *end += run.len as usize;
+                Some(*end)
+            })
+            .collect::<SmallVec<[usize; 32]>>();
         let mut wraps = wrap_boundaries.iter().peekable();
-        let mut run_end = 0;
-        let mut color = black();
+        let mut current_run_ix = None;
         let mut current_underline: Option<(Point<Pixels>, UnderlineStyle)> = None;
         let mut current_strikethrough: Option<(Point<Pixels>, StrikethroughStyle)> = None;
+        let mut current_overline: Option<(Point<Pixels>, OverlineStyle)> = None;
         let mut current_background: Option<(Point<Pixels>, Hsla)> = None;
         let text_system = cx.text_system().clone();
         let mut glyph_origin = origin;
@@ -169,6 +179,15 @@ fn paint_line(
                         strikethrough_origin.x = origin.x;
                         strikethrough_origin.y += line_height;
                     }
+                    if let Some((overline_origin, overline_style)) = current_overline.as_mut() {
+                        cx.paint_overline(
+                            *overline_origin,
+                            glyph_origin.x - overline_origin.x,
+                            overline_style,
+                        );
+                        overline_origin.x = origin.x;
+                        overline_origin.y += line_height;
+                    }
 
                     glyph_origin.x = origin.x;
                     glyph_origin.y += line_height;
@@ -178,8 +197,11 @@ fn paint_line(
                 let mut finished_background: Option<(Point<Pixels>, Hsla)> = None;
                 let mut finished_underline: Option<(Point<Pixels>, UnderlineStyle)> = None;
                 let mut finished_strikethrough: Option<(Point<Pixels>, StrikethroughStyle)> = None;
-                if glyph.index >= run_end {
-                    if let Some(style_run) = decoration_runs.next() {
+                let mut finished_overline: Option<(Point<Pixels>, OverlineStyle)> = None;
+                let run_ix = decoration_run_ix(&run_ends, glyph.index);
+                if current_run_ix != Some(run_ix) {
+                    current_run_ix = Some(run_ix);
+                    if let Some(style_run) = decoration_runs.get(run_ix) {
                         if let Some((_, background_color)) = &mut current_background {
                             if style_run.background_color.as_ref() != Some(background_color) {
                                 finished_background = current_background.take();
@@ -216,11 +238,14 @@ fn paint_line(
                             }
                         }
                         if let Some(run_strikethrough) = style_run.strikethrough.as_ref() {
+                            // Strike through the middle of the lowercase letters, which is
+                            // about a third of the ascent above the baseline.
                             current_strikethrough.get_or_insert((
                                 point(
                                     glyph_origin.x,
-                                    glyph_origin.y
-                                        + (((layout.ascent * 0.5) + baseline_offset.y) * 0.5),
+                                    glyph_origin.y + baseline_offset.y
+                                        - layout.ascent * 0.3
+                                        - run_strikethrough.thickness / 2.,
                                 ),
                                 StrikethroughStyle {
                                     color: Some(run_strikethrough.color.unwrap_or(style_run.color)),
@@ -228,14 +253,28 @@ fn paint_line(
                                 },
                             ));
                         }
-
-                        run_end += style_run.len as usize;
-                        color = style_run.color;
+                        if let Some((_, overline_style)) = &mut current_overline {
+                            if style_run.overline.as_ref() != Some(overline_style) {
+                                finished_overline = current_overline.take();
+                            }
+                        }
+                        if let Some(run_overline) = style_run.overline.as_ref() {
+                            current_overline.get_or_insert((
+                                point(
+                                    glyph_origin.x,
+                                    glyph_origin.y + baseline_offset.y - layout.ascent,
+                                ),
+                                OverlineStyle {
+                                    color: Some(run_overline.color.unwrap_or(style_run.color)),
+                                    thickness: run_overline.thickness,
+                                },
+                            ));
+                        }
                     } else {
-                        run_end = layout.len;
                         finished_background = current_background.take();
                         finished_underline = current_underline.take();
                         finished_strikethrough = current_strikethrough.take();
+                        finished_overline = current_overline.take();
                     }
                 }
 
@@ -265,6 +304,15 @@ fn paint_line(
                     );
                 }
 
+                if let Some((overline_origin, overline_style)) = finished_overline {
+                    cx.paint_overline(
+                        overline_origin,
+                        glyph_origin.x - overline_origin.x,
+                        &overline_style,
+                    );
+                }
+
+                let color = run_color(decoration_runs, &run_ends, glyph.index);
                 let max_glyph_bounds = Bounds {
                     origin: glyph_origin,
                     size: max_glyph_size,
@@ -325,6 +373,75 @@ fn paint_line(
             );
         }
 
+        if let Some((overline_start, overline_style)) = current_overline.take() {
+            cx.paint_overline(
+                overline_start,
+                last_line_end_x - overline_start.x,
+                &overline_style,
+            );
+        }
+
         Ok(())
     })
 }
+
+/// Returns the index of the decoration run containing the byte at `index`, given the
+/// byte offsets at which the runs end, or the number of runs if it's past their end.
+///
+/// Glyphs are laid out in visual order, so in right-to-left text their indices
+/// decrease along the line, and can't be matched with runs in sequence.
+fn decoration_run_ix(run_ends: &[usize], index: usize) -> usize {
+    run_ends.partition_point(|end| *end <= index)
+}
+
+/// Returns the color of the decoration run containing the byte at `index`, or of the
+/// last run if it's past their end.
+fn run_color(decoration_runs: &[DecorationRun], run_ends: &[usize], index: usize) -> Hsla {
+    decoration_runs
+        .get(decoration_run_ix(run_ends, index))
+        .or(decoration_runs.last())
+        .map_or(black(), |run| run.color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{blue, red};
+
+    #[test]
+    fn test_run_color_in_visual_order() {
+        let run = |len, color| DecorationRun {
+            len,
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+            overline: None,
+        };
+        // Two 2-byte Hebrew letters, the second of which is highlighted.
+        let runs = [run(2, red()), run(2, blue())];
+        let run_ends = [2, 4];
+
+        // In visual order, the glyph of the second letter comes first.
+        assert_eq!(run_color(&runs, &run_ends, 2), blue());
+        assert_eq!(run_color(&runs, &run_ends, 0), red());
+        assert_eq!(run_color(&runs, &run_ends, 4), blue());
+
+        // Backgrounds and lines are resolved through the same runs, so a highlight
+        // of the second letter is drawn under its glyph, first on the line.
+        let highlighted = DecorationRun {
+            background_color: Some(blue()),
+            underline: Some(UnderlineStyle::default()),
+            ..run(2, blue())
+        };
+        let runs = [run(2, red()), highlighted];
+        let visual_order = [2, 0];
+        let backgrounds = visual_order
+            .iter()
+            .map(|index| runs[decoration_run_ix(&run_ends, *index)].background_color)
+            .collect::<Vec<_>>();
+        assert_eq!(backgrounds, vec![Some(blue()), None]);
+        assert!(runs[decoration_run_ix(&run_ends, 2)].underline.is_some());
+        assert_eq!(decoration_run_ix(&run_ends, 4), runs.len());
+    }
+}
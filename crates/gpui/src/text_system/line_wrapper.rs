@@ -385,6 +385,7 @@ mod tests {
                 color: Default::default(),
                 underline: Default::default(),
                 strikethrough: None,
+                overline: None,
                 background_color: None,
             };
             let bold = TextRun {
@@ -393,6 +394,7 @@ mod tests {
                 color: Default::default(),
                 underline: Default::default(),
                 strikethrough: None,
+                overline: None,
                 background_color: None,
             };
 
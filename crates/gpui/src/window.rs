@@ -1,19 +1,20 @@
 use crate::{
-    point, prelude::*, px, size, transparent_black, Action, AnyDrag, AnyElement, AnyTooltip,
-    AnyView, AppContext, Arena, Asset, AsyncWindowContext, AvailableSpace, Bounds, BoxShadow,
-    Context, Corners, CursorStyle, Decorations, DevicePixels, DispatchActionListener,
-    DispatchNodeId, DispatchTree, DisplayId, Edges, Effect, Entity, EntityId, EventEmitter,
-    FileDropEvent, Flatten, FontId, GPUSpecs, Global, GlobalElementId, GlyphId, Hsla, InputHandler,
-    IsZero, KeyBinding, KeyContext, KeyDownEvent, KeyEvent, Keystroke, KeystrokeEvent,
-    KeystrokeObserver, LayoutId, LineLayoutIndex, Model, ModelContext, Modifiers,
-    ModifiersChangedEvent, MonochromeSprite, MouseButton, MouseEvent, MouseMoveEvent, MouseUpEvent,
-    Path, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput, PlatformInputHandler,
-    PlatformWindow, Point, PolychromeSprite, PromptLevel, Quad, Render, RenderGlyphParams,
-    RenderImage, RenderImageParams, RenderSvgParams, Replay, ResizeEdge, ScaledPixels, Scene,
-    Shadow, SharedString, Size, StrikethroughStyle, Style, SubscriberSet, Subscription,
-    TaffyLayoutEngine, Task, TextStyle, TextStyleRefinement, TransformationMatrix, Underline,
-    UnderlineStyle, View, VisualContext, WeakView, WindowAppearance, WindowBackgroundAppearance,
-    WindowBounds, WindowControls, WindowDecorations, WindowOptions, WindowParams, WindowTextSystem,
+    point, prelude::*, px, size, transparent_black, AccessibilityNode, AccessibilityProperties,
+    AccessibilityTree, Action, AnyDrag, AnyElement, AnyTooltip, AnyView, AppContext, Arena, Asset,
+    AsyncWindowContext, AtlasTextureId, AvailableSpace, Bounds, BoxShadow, Context, Corners,
+    CursorStyle, Decorations, DevicePixels, DispatchActionListener, DispatchNodeId, DispatchTree,
+    DisplayId, Edges, Effect, Entity, EntityId, EventEmitter, FileDropEvent, Flatten, FontId,
+    GPUSpecs, Global, GlobalElementId, GlyphId, Hsla, InputHandler, IsZero, KeyBinding, KeyContext,
+    KeyDownEvent, KeyEvent, Keystroke, KeystrokeEvent, KeystrokeObserver, LayoutId,
+    LineLayoutIndex, Model, ModelContext, Modifiers, ModifiersChangedEvent, MonochromeSprite,
+    MouseButton, MouseEvent, MouseMoveEvent, MouseUpEvent, OverlineStyle, Path, Pixels,
+    PlatformAtlas, PlatformDisplay, PlatformInput, PlatformInputHandler, PlatformWindow, Point,
+    PolychromeSprite, PromptLevel, Quad, Render, RenderGlyphParams, RenderImage, RenderImageParams,
+    RenderSvgParams, Replay, ResizeEdge, ScaledPixels, Scene, Shadow, SharedString, Size,
+    StrikethroughStyle, Style, SubscriberSet, Subscription, TaffyLayoutEngine, Task, TextStyle,
+    TextStyleRefinement, TileId, TransformationMatrix, Underline, UnderlineStyle, View,
+    VisualContext, WeakView, WindowAppearance, WindowBackgroundAppearance, WindowBounds,
+    WindowControls, WindowDecorations, WindowOptions, WindowParams, WindowTextSystem,
     SUBPIXEL_VARIANTS,
 };
 use anyhow::{anyhow, Context as _, Result};
@@ -49,8 +50,14 @@ use util::post_inc;
 use util::{measure, ResultExt};
 use uuid::Uuid;
 
+mod frame_profiler;
+mod inspector;
+mod offscreen;
 mod prompts;
 
+pub use frame_profiler::*;
+pub use inspector::*;
+pub use offscreen::*;
 pub use prompts::*;
 
 pub(crate) const DEFAULT_WINDOW_SIZE: Size<Pixels> = size(px(1024.), px(700.));
@@ -408,6 +415,13 @@ pub(crate) struct Frame {
     pub(crate) input_handlers: Vec<Option<PlatformInputHandler>>,
     pub(crate) tooltip_requests: Vec<Option<TooltipRequest>>,
     pub(crate) cursor_styles: Vec<CursorStyleRequest>,
+    pub(crate) accessibility_tree: AccessibilityTree,
+    accessibility_depth: usize,
+    /// Whether a node of the accessibility tree differs from the node at its index in
+    /// the previous frame, so that the tree needs to be exported again.
+    accessibility_tree_dirty: bool,
+    #[cfg(any(test, feature = "test-support"))]
+    pub(crate) test_ids: Vec<(SharedString, Bounds<Pixels>)>,
     #[cfg(any(test, feature = "test-support"))]
     pub(crate) debug_bounds: FxHashMap<String, Bounds<Pixels>>,
 }
@@ -428,6 +442,10 @@ pub(crate) struct PaintIndex {
     mouse_listeners_index: usize,
     input_handlers_index: usize,
     cursor_styles_index: usize,
+    accessibility_nodes_index: usize,
+    accessibility_depth: usize,
+    #[cfg(any(test, feature = "test-support"))]
+    test_ids_index: usize,
     accessed_element_states_index: usize,
     line_layout_index: LineLayoutIndex,
 }
@@ -447,6 +465,11 @@ impl Frame {
             input_handlers: Vec::new(),
             tooltip_requests: Vec::new(),
             cursor_styles: Vec::new(),
+            accessibility_tree: AccessibilityTree::default(),
+            accessibility_depth: 0,
+            accessibility_tree_dirty: false,
+            #[cfg(any(test, feature = "test-support"))]
+            test_ids: Vec::new(),
 
             #[cfg(any(test, feature = "test-support"))]
             debug_bounds: FxHashMap::default(),
@@ -464,6 +487,11 @@ impl Frame {
         self.cursor_styles.clear();
         self.hitboxes.clear();
         self.deferred_draws.clear();
+        self.accessibility_tree.clear();
+        self.accessibility_depth = 0;
+        self.accessibility_tree_dirty = false;
+        #[cfg(any(test, feature = "test-support"))]
+        self.test_ids.clear();
         self.focus = None;
     }
 
@@ -536,6 +564,8 @@ pub struct Window {
     focus_lost_listeners: SubscriberSet<(), AnyObserver>,
     default_prevented: bool,
     mouse_position: Point<Pixels>,
+    /// Whether tooltips are hidden because the keyboard was used since the mouse last moved.
+    tooltips_suppressed: bool,
     mouse_hit_test: HitTest,
     modifiers: Modifiers,
     scale_factor: f32,
@@ -556,6 +586,9 @@ pub struct Window {
     pending_modifier: ModifierState,
     pending_input_observers: SubscriberSet<(), AnyObserver>,
     prompt: Option<RenderablePromptHandle>,
+    pub(crate) offscreen_tiles: Option<FxHashMap<(AtlasTextureId, TileId), OffscreenTile>>,
+    pub(crate) frame_profiler: Option<FrameProfiler>,
+    pub(crate) inspector: Option<RefCell<Inspector>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -813,6 +846,7 @@ impl Window {
             focus_lost_listeners: SubscriberSet::new(),
             default_prevented: true,
             mouse_position,
+            tooltips_suppressed: false,
             mouse_hit_test: HitTest::default(),
             modifiers,
             scale_factor,
@@ -833,6 +867,9 @@ impl Window {
             pending_modifier: ModifierState::default(),
             pending_input_observers: SubscriberSet::new(),
             prompt: None,
+            offscreen_tiles: None,
+            frame_profiler: None,
+            inspector: None,
         })
     }
     fn new_focus_listener(&self, value: AnyWindowFocusListener) -> (Subscription, impl FnOnce()) {
@@ -1045,6 +1082,24 @@ impl<'a> WindowContext<'a> {
             });
     }
 
+    /// Runs the callbacks registered with [`AppContext::intercept_keystrokes`], returning
+    /// whether the keystroke should still be dispatched.
+    fn dispatch_keystroke_interceptors(&mut self, event: &KeyDownEvent) -> bool {
+        self.propagate_event = true;
+        self.keystroke_interceptors
+            .clone()
+            .retain(&(), move |callback| {
+                (callback)(
+                    &KeystrokeEvent {
+                        keystroke: event.keystroke.clone(),
+                        action: None,
+                    },
+                    self,
+                )
+            });
+        self.propagate_event
+    }
+
     /// Schedules the given function to be run at the end of the current effect cycle, allowing entities
     /// that are currently on the stack to be returned to the app.
     pub fn defer(&mut self, f: impl FnOnce(&mut WindowContext) + 'static) {
@@ -1400,6 +1455,33 @@ impl<'a> WindowContext<'a> {
         self.window.mouse_position
     }
 
+    /// Whether tooltips and hover cards should stay hidden, because the keyboard
+    /// was used since the mouse last moved or something is being dragged.
+    pub fn tooltips_suppressed(&self) -> bool {
+        self.window.tooltips_suppressed || self.has_active_drag()
+    }
+
+    /// Whether a tooltip was shown in the last frame. Since key presses suppress
+    /// tooltips, this is still true while handling the key press hiding it.
+    pub fn is_tooltip_visible(&self) -> bool {
+        self.window
+            .rendered_frame
+            .tooltip_requests
+            .iter()
+            .any(Option::is_some)
+    }
+
+    /// Hides tooltips and hover cards until the mouse moves again, e.g. while
+    /// navigating a list with custom key handling.
+    pub fn suppress_tooltips(&mut self) {
+        if !self.window.tooltips_suppressed {
+            self.window.tooltips_suppressed = true;
+            if !self.window.rendered_frame.tooltip_requests.is_empty() {
+                self.refresh();
+            }
+        }
+    }
+
     /// The current state of the keyboard's modifiers
     pub fn modifiers(&self) -> Modifiers {
         self.window.modifiers
@@ -1413,6 +1495,16 @@ impl<'a> WindowContext<'a> {
     /// the contents of the new [Scene], use [present].
     #[profiling::function]
     pub fn draw(&mut self) {
+        let frame_start = Instant::now();
+        if let Some(profiler) = self.window.frame_profiler.as_mut() {
+            profiler.begin_frame();
+        }
+        if let Some(inspector) = self.window.inspector.as_ref() {
+            inspector.borrow_mut().begin_frame();
+            // Cached views would otherwise replay their last frame without
+            // recording their elements.
+            self.window.refreshing = true;
+        }
         self.window.dirty.set(false);
         self.window.requested_autoscroll = None;
 
@@ -1452,6 +1544,14 @@ impl<'a> WindowContext<'a> {
         let previous_focus_path = self.window.rendered_frame.focus_path();
         let previous_window_active = self.window.rendered_frame.window_active;
         mem::swap(&mut self.window.rendered_frame, &mut self.window.next_frame);
+        let accessibility_tree_dirty = self.window.rendered_frame.accessibility_tree_dirty
+            || self.window.rendered_frame.accessibility_tree.nodes.len()
+                != self.window.next_frame.accessibility_tree.nodes.len();
+        if accessibility_tree_dirty {
+            self.window
+                .platform_window
+                .update_accessibility_tree(&self.window.rendered_frame.accessibility_tree);
+        }
         self.window.next_frame.clear();
         let current_focus_path = self.window.rendered_frame.focus_path();
         let current_window_active = self.window.rendered_frame.window_active;
@@ -1488,6 +1588,12 @@ impl<'a> WindowContext<'a> {
         self.window.refreshing = false;
         self.window.draw_phase = DrawPhase::None;
         self.window.needs_present.set(true);
+        if let Some(profiler) = self.window.frame_profiler.as_mut() {
+            profiler.finish_frame(frame_start.elapsed());
+        }
+        if let Some(inspector) = self.window.inspector.as_ref() {
+            inspector.borrow_mut().finish_frame();
+        }
     }
 
     #[profiling::function]
@@ -1500,6 +1606,7 @@ impl<'a> WindowContext<'a> {
     }
 
     fn draw_roots(&mut self) {
+        let prepaint_start = Instant::now();
         self.window.draw_phase = DrawPhase::Prepaint;
         self.window.tooltip_bounds.take();
 
@@ -1532,6 +1639,11 @@ impl<'a> WindowContext<'a> {
 
         self.window.mouse_hit_test = self.window.next_frame.hit_test(self.window.mouse_position);
 
+        let paint_start = Instant::now();
+        if let Some(profiler) = self.window.frame_profiler.as_mut() {
+            profiler.record_layout(paint_start - prepaint_start);
+        }
+
         // Now actually paint the elements.
         self.window.draw_phase = DrawPhase::Paint;
         root_element.paint(self);
@@ -1545,6 +1657,12 @@ impl<'a> WindowContext<'a> {
         } else if let Some(mut tooltip_element) = tooltip_element {
             tooltip_element.paint(self);
         }
+
+        self.paint_inspector_highlight();
+
+        if let Some(profiler) = self.window.frame_profiler.as_mut() {
+            profiler.record_paint(paint_start.elapsed());
+        }
     }
 
     fn prepaint_tooltip(&mut self) -> Option<AnyElement> {
@@ -1554,6 +1672,8 @@ impl<'a> WindowContext<'a> {
         let mouse_position = tooltip_request.tooltip.mouse_position;
         let tooltip_size = element.layout_as_root(AvailableSpace::min_size(), self);
 
+        // Tooltips are positioned on every frame, so they stay within the window as
+        // it's resized.
         let mut tooltip_bounds = Bounds::new(mouse_position + point(px(1.), px(1.)), tooltip_size);
         let window_bounds = Bounds {
             origin: Point::default(),
@@ -1567,7 +1687,7 @@ impl<'a> WindowContext<'a> {
             } else {
                 tooltip_bounds.origin.x = cmp::max(
                     Pixels::ZERO,
-                    tooltip_bounds.origin.x - tooltip_bounds.right() - window_bounds.right(),
+                    window_bounds.right() - tooltip_bounds.size.width,
                 );
             }
         }
@@ -1579,7 +1699,7 @@ impl<'a> WindowContext<'a> {
             } else {
                 tooltip_bounds.origin.y = cmp::max(
                     Pixels::ZERO,
-                    tooltip_bounds.origin.y - tooltip_bounds.bottom() - window_bounds.bottom(),
+                    window_bounds.bottom() - tooltip_bounds.size.height,
                 );
             }
         }
@@ -1669,6 +1789,19 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    /// Returns whether a view drawn in the given range of the last frame's prepaint was
+    /// notified since.
+    pub(crate) fn prepaint_range_contains_dirty_views(
+        &self,
+        range: &Range<PrepaintStateIndex>,
+    ) -> bool {
+        !self.window.dirty_views.is_empty()
+            && self.window.rendered_frame.dispatch_tree.contains_any_view(
+                range.start.dispatch_tree_index..range.end.dispatch_tree_index,
+                &self.window.dirty_views,
+            )
+    }
+
     pub(crate) fn reuse_prepaint(&mut self, range: Range<PrepaintStateIndex>) {
         let window = &mut self.window;
         window.next_frame.hitboxes.extend(
@@ -1725,6 +1858,10 @@ impl<'a> WindowContext<'a> {
             mouse_listeners_index: self.window.next_frame.mouse_listeners.len(),
             input_handlers_index: self.window.next_frame.input_handlers.len(),
             cursor_styles_index: self.window.next_frame.cursor_styles.len(),
+            accessibility_nodes_index: self.window.next_frame.accessibility_tree.nodes.len(),
+            accessibility_depth: self.window.next_frame.accessibility_depth,
+            #[cfg(any(test, feature = "test-support"))]
+            test_ids_index: self.window.next_frame.test_ids.len(),
             accessed_element_states_index: self.window.next_frame.accessed_element_states.len(),
             line_layout_index: self.window.text_system.layout_index(),
         }
@@ -1739,6 +1876,28 @@ impl<'a> WindowContext<'a> {
                 .iter()
                 .cloned(),
         );
+        let depth = window.next_frame.accessibility_depth;
+        // The reused nodes only differ from the previous frame's when they moved.
+        if window.next_frame.accessibility_tree.nodes.len() != range.start.accessibility_nodes_index
+            || depth != range.start.accessibility_depth
+        {
+            window.next_frame.accessibility_tree_dirty = true;
+        }
+        window.next_frame.accessibility_tree.nodes.extend(
+            window.rendered_frame.accessibility_tree.nodes
+                [range.start.accessibility_nodes_index..range.end.accessibility_nodes_index]
+                .iter()
+                .map(|node| AccessibilityNode {
+                    depth: node.depth - range.start.accessibility_depth + depth,
+                    ..node.clone()
+                }),
+        );
+        #[cfg(any(test, feature = "test-support"))]
+        window.next_frame.test_ids.extend(
+            window.rendered_frame.test_ids[range.start.test_ids_index..range.end.test_ids_index]
+                .iter()
+                .cloned(),
+        );
         window.next_frame.input_handlers.extend(
             window.rendered_frame.input_handlers
                 [range.start.input_handlers_index..range.end.input_handlers_index]
@@ -1766,6 +1925,69 @@ impl<'a> WindowContext<'a> {
         );
     }
 
+    /// Adds an element described by the given properties to the accessibility tree,
+    /// with the elements added by `f` as its descendants. Adds nothing if the
+    /// properties are `None`. This method should only be called as part of the
+    /// paint phase of element drawing.
+    pub fn with_accessibility_node<R>(
+        &mut self,
+        properties: Option<&AccessibilityProperties>,
+        bounds: Bounds<Pixels>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let Some(properties) = properties else {
+            return f(self);
+        };
+
+        let frame = &mut self.window.next_frame;
+        let node = AccessibilityNode {
+            properties: properties.clone(),
+            bounds,
+            depth: frame.accessibility_depth,
+        };
+        let ix = frame.accessibility_tree.nodes.len();
+        if self.window.rendered_frame.accessibility_tree.nodes.get(ix) != Some(&node) {
+            frame.accessibility_tree_dirty = true;
+        }
+        frame.accessibility_tree.nodes.push(node);
+        frame.accessibility_depth += 1;
+        let result = f(self);
+        self.window.next_frame.accessibility_depth -= 1;
+        result
+    }
+
+    /// Returns the accessible elements of the window, as painted in the last frame.
+    pub fn accessibility_tree(&self) -> &AccessibilityTree {
+        &self.window.rendered_frame.accessibility_tree
+    }
+
+    /// Records the bounds of an element with the given test ID for this frame. This
+    /// method should only be called as part of the paint phase of element drawing.
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn insert_test_id(&mut self, test_id: SharedString, bounds: Bounds<Pixels>) {
+        self.window.next_frame.test_ids.push((test_id, bounds));
+    }
+
+    /// Returns the bounds of the elements painted in the last frame with the given
+    /// test ID, in paint order. See [`InteractiveElement::test_id`](crate::InteractiveElement::test_id).
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn bounds_for_test_id(&self, test_id: &str) -> Vec<Bounds<Pixels>> {
+        self.window
+            .rendered_frame
+            .test_ids
+            .iter()
+            .filter(|(id, _)| id.as_ref() == test_id)
+            .map(|(_, bounds)| *bounds)
+            .collect()
+    }
+
+    /// Returns the test IDs of the elements painted in the last frame with their
+    /// bounds, in paint order, e.g. to serialize the window for debugging.
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn test_ids(&self) -> &[(SharedString, Bounds<Pixels>)] {
+        &self.window.rendered_frame.test_ids
+    }
+
     /// Push a text style onto the stack, and call a function with that style active.
     /// Use [`AppContext::text_style`] to get the current, combined text style. This method
     /// should only be called as part of element drawing.
@@ -2411,6 +2633,35 @@ impl<'a> WindowContext<'a> {
         });
     }
 
+    /// Paint an overline into the scene for the next frame at the current z-index.
+    ///
+    /// This method should only be called as part of the paint phase of element drawing.
+    pub fn paint_overline(&mut self, origin: Point<Pixels>, width: Pixels, style: &OverlineStyle) {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+
+        let scale_factor = self.scale_factor();
+        let bounds = Bounds {
+            origin,
+            size: size(width, style.thickness),
+        };
+        let content_mask = self.content_mask();
+        let opacity = self.element_opacity();
+
+        self.window.next_frame.scene.insert_primitive(Underline {
+            order: 0,
+            pad: 0,
+            bounds: bounds.scale(scale_factor),
+            content_mask: content_mask.scale(scale_factor),
+            thickness: style.thickness.scale(scale_factor),
+            color: style.color.unwrap_or_default().opacity(opacity),
+            wavy: false,
+        });
+    }
+
     /// Paints a monochrome (non-emoji) glyph into the scene for the next frame at the current z-index.
     ///
     /// The y component of the origin is the baseline of the glyph.
@@ -2459,6 +2710,9 @@ impl<'a> WindowContext<'a> {
                     Ok(Some((size, Cow::Owned(bytes))))
                 })?
                 .expect("Callback above only errors or returns Some");
+            self.capture_offscreen_tile(&tile, |cx| {
+                cx.text_system().rasterize_glyph(&params).map(Some)
+            })?;
             let bounds = Bounds {
                 origin: glyph_origin.map(|px| px.floor()) + raster_bounds.origin.map(Into::into),
                 size: tile.bounds.size.map(Into::into),
@@ -2523,6 +2777,9 @@ impl<'a> WindowContext<'a> {
                     Ok(Some((size, Cow::Owned(bytes))))
                 })?
                 .expect("Callback above only errors or returns Some");
+            self.capture_offscreen_tile(&tile, |cx| {
+                cx.text_system().rasterize_glyph(&params).map(Some)
+            })?;
 
             let bounds = Bounds {
                 origin: glyph_origin.map(|px| px.floor()) + raster_bounds.origin.map(Into::into),
@@ -2587,6 +2844,10 @@ impl<'a> WindowContext<'a> {
         else {
             return Ok(());
         };
+        self.capture_offscreen_tile(&tile, |cx| {
+            let bytes = cx.svg_renderer.render(&params)?;
+            Ok(bytes.map(|bytes| (params.size, bytes)))
+        })?;
         let content_mask = self.content_mask().scale(scale_factor);
 
         self.window
@@ -2645,6 +2906,11 @@ impl<'a> WindowContext<'a> {
                 )))
             })?
             .expect("Callback above only returns Some");
+        self.capture_offscreen_tile(&tile, |_| {
+            Ok(data
+                .as_bytes(frame_index)
+                .map(|bytes| (data.size(frame_index), bytes.to_vec())))
+        })?;
         let content_mask = self.content_mask().scale(scale_factor);
         let corner_radii = corner_radii.scale(scale_factor);
         let opacity = self.element_opacity();
@@ -3082,6 +3348,11 @@ impl<'a> WindowContext<'a> {
             // Track the mouse position with our own state, since accessing the platform
             // API for the mouse position can only occur on the main thread.
             PlatformInput::MouseMove(mouse_move) => {
+                // Mouse moves are also synthesized after layout changes, with the mouse
+                // where it was, which doesn't re-enable tooltips.
+                if mouse_move.position != self.window.mouse_position {
+                    self.window.tooltips_suppressed = false;
+                }
                 self.window.mouse_position = mouse_move.position;
                 self.window.modifiers = mouse_move.modifiers;
                 PlatformInput::MouseMove(mouse_move)
@@ -3150,7 +3421,11 @@ impl<'a> WindowContext<'a> {
                     PlatformInput::FileDrop(FileDropEvent::Exited)
                 }
             },
-            PlatformInput::KeyDown(_) | PlatformInput::KeyUp(_) => event,
+            PlatformInput::KeyDown(_) => {
+                self.suppress_tooltips();
+                event
+            }
+            PlatformInput::KeyUp(_) => event,
         };
 
         if let Some(any_mouse_event) = event.mouse_event() {
@@ -3166,6 +3441,10 @@ impl<'a> WindowContext<'a> {
     }
 
     fn dispatch_mouse_event(&mut self, event: &dyn Any) {
+        if self.inspect_mouse_event(event) {
+            return;
+        }
+
         let hit_test = self.window.rendered_frame.hit_test(self.mouse_position());
         if hit_test != self.window.mouse_hit_test {
             self.window.mouse_hit_test = hit_test;
@@ -3265,6 +3544,9 @@ impl<'a> WindowContext<'a> {
             self.window.pending_modifier.modifiers = event.modifiers
         } else if let Some(key_down_event) = event.downcast_ref::<KeyDownEvent>() {
             self.window.pending_modifier.saw_keystroke = true;
+            if !self.dispatch_keystroke_interceptors(key_down_event) {
+                return;
+            }
             keystroke = Some(key_down_event.keystroke.clone());
         }
 
@@ -0,0 +1,172 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::WindowContext;
+
+/// The number of frames kept by a window while frame profiling is enabled.
+const PROFILED_FRAME_COUNT: usize = 120;
+
+/// The timings of a frame drawn by a window, recorded while frame profiling is
+/// enabled with [`WindowContext::set_frame_profiling`].
+#[derive(Clone, Debug, Default)]
+pub struct FrameProfile {
+    /// How long drawing the frame took.
+    pub frame_duration: Duration,
+    /// How long rendering and laying out the elements took, along with their
+    /// prepaint.
+    pub layout_duration: Duration,
+    /// How long painting the elements into the scene took.
+    pub paint_duration: Duration,
+    /// The number of elements drawn in the frame.
+    pub element_count: usize,
+    /// The spans of the components drawn in the frame, in the order they were
+    /// rendered, so that the descendants of a span follow it at a greater depth.
+    pub spans: Vec<ProfileSpan>,
+}
+
+/// The time spent drawing a component and its descendants in a frame.
+#[derive(Clone, Debug)]
+pub struct ProfileSpan {
+    /// The type name of the component.
+    pub name: &'static str,
+    /// The number of components this one is nested in.
+    pub depth: usize,
+    /// The time spent rendering, laying out, prepainting and painting the
+    /// component, including its descendants.
+    pub duration: Duration,
+}
+
+impl FrameProfile {
+    /// Returns the time spent in the given span, excluding its child spans.
+    pub fn self_duration(&self, span_ix: usize) -> Duration {
+        let span = &self.spans[span_ix];
+        let children_duration = self.spans[span_ix + 1..]
+            .iter()
+            .take_while(|child| child.depth > span.depth)
+            .filter(|child| child.depth == span.depth + 1)
+            .map(|child| child.duration)
+            .sum();
+        span.duration.saturating_sub(children_duration)
+    }
+}
+
+/// Identifies a span in the frame being drawn.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ProfileSpanId(usize);
+
+#[derive(Default)]
+pub(crate) struct FrameProfiler {
+    frames: VecDeque<FrameProfile>,
+    current: FrameProfile,
+    span_stack: Vec<usize>,
+}
+
+impl FrameProfiler {
+    pub(crate) fn begin_frame(&mut self) {
+        self.current = FrameProfile::default();
+        self.span_stack.clear();
+    }
+
+    pub(crate) fn record_layout(&mut self, duration: Duration) {
+        self.current.layout_duration += duration;
+    }
+
+    pub(crate) fn record_paint(&mut self, duration: Duration) {
+        self.current.paint_duration += duration;
+    }
+
+    pub(crate) fn record_element(&mut self) {
+        self.current.element_count += 1;
+    }
+
+    pub(crate) fn finish_frame(&mut self, frame_duration: Duration) {
+        let mut frame = std::mem::take(&mut self.current);
+        frame.frame_duration = frame_duration;
+        if self.frames.len() == PROFILED_FRAME_COUNT {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+}
+
+impl<'a> WindowContext<'a> {
+    /// Enables or disables recording the timings of the frames drawn by this
+    /// window, which can be read with [`WindowContext::frame_profiles`].
+    pub fn set_frame_profiling(&mut self, enabled: bool) {
+        if enabled != self.window.frame_profiler.is_some() {
+            self.window.frame_profiler = enabled.then(FrameProfiler::default);
+        }
+    }
+
+    /// Returns whether the timings of frames are being recorded.
+    pub fn frame_profiling(&self) -> bool {
+        self.window.frame_profiler.is_some()
+    }
+
+    /// Returns the timings of the frames recently drawn by this window, oldest
+    /// first, while frame profiling is enabled.
+    pub fn frame_profiles(&self) -> impl Iterator<Item = &FrameProfile> {
+        self.window
+            .frame_profiler
+            .iter()
+            .flat_map(|profiler| profiler.frames.iter())
+    }
+
+    /// Runs the given phase of drawing a component in its span, opening the
+    /// span on its first phase.
+    pub(crate) fn with_profile_span<R>(
+        &mut self,
+        span: &mut Option<ProfileSpanId>,
+        name: fn() -> &'static str,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let Some(profiler) = self.window.frame_profiler.as_mut() else {
+            return f(self);
+        };
+        let span_id = *span.get_or_insert_with(|| {
+            profiler.current.spans.push(ProfileSpan {
+                name: name(),
+                depth: profiler.span_stack.len(),
+                duration: Duration::ZERO,
+            });
+            ProfileSpanId(profiler.current.spans.len() - 1)
+        });
+        profiler.span_stack.push(span_id.0);
+
+        let start = Instant::now();
+        let result = f(self);
+        let duration = start.elapsed();
+
+        if let Some(profiler) = self.window.frame_profiler.as_mut() {
+            profiler.span_stack.pop();
+            if let Some(span) = profiler.current.spans.get_mut(span_id.0) {
+                span.duration += duration;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_duration() {
+        let span = |depth, millis| ProfileSpan {
+            name: "",
+            depth,
+            duration: Duration::from_millis(millis),
+        };
+        let frame = FrameProfile {
+            spans: vec![span(0, 10), span(1, 4), span(2, 3), span(1, 2), span(0, 5)],
+            ..Default::default()
+        };
+        assert_eq!(frame.self_duration(0), Duration::from_millis(4));
+        assert_eq!(frame.self_duration(1), Duration::from_millis(1));
+        assert_eq!(frame.self_duration(2), Duration::from_millis(3));
+        assert_eq!(frame.self_duration(4), Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,290 @@
+use std::{any::Any, mem};
+
+use crate::{
+    fill, hsla, outline, point, Bounds, Edges, Hsla, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, Pixels, SharedString, Style, WindowContext,
+};
+
+/// An element of the last frame drawn by a window, as described by the element
+/// inspector enabled with [`WindowContext::set_inspector_enabled`].
+#[derive(Clone, Debug)]
+pub struct InspectedElement {
+    /// The bounds of the element in the window.
+    pub bounds: Bounds<Pixels>,
+    /// The padding of the element, resolved to pixels.
+    pub padding: Edges<Pixels>,
+    /// The components the element was rendered by, outermost first.
+    pub components: Vec<InspectedComponent>,
+    /// Where the element was built, which is only tracked in debug builds.
+    pub source_location: Option<core::panic::Location<'static>>,
+}
+
+/// A component containing an [`InspectedElement`].
+#[derive(Clone, Debug)]
+pub struct InspectedComponent {
+    /// The type name of the component.
+    pub name: &'static str,
+    /// The theme tokens resolved while rendering the component, in the order they
+    /// were resolved.
+    pub theme_tokens: Vec<ThemeToken>,
+}
+
+/// A theme token resolved to a color, recorded with [`WindowContext::record_theme_token`].
+#[derive(Clone, Debug)]
+pub struct ThemeToken {
+    /// The name of the token, e.g. `Color::Muted`.
+    pub name: SharedString,
+    /// The color the token resolved to.
+    pub value: Hsla,
+}
+
+struct InspectorEntry {
+    element: InspectedElement,
+    /// The part of the element's bounds that isn't clipped by its ancestors.
+    visible_bounds: Bounds<Pixels>,
+}
+
+#[derive(Default)]
+pub(crate) struct Inspector {
+    component_stack: Vec<InspectedComponent>,
+    /// The elements of the frame being drawn, in the order they were prepainted,
+    /// so that an element follows its ancestors.
+    entries: Vec<InspectorEntry>,
+    last_entries: Vec<InspectorEntry>,
+    hovered: Option<usize>,
+    selected: Option<InspectedElement>,
+}
+
+impl Inspector {
+    pub(crate) fn begin_frame(&mut self) {
+        self.component_stack.clear();
+        self.entries.clear();
+    }
+
+    pub(crate) fn finish_frame(&mut self) {
+        mem::swap(&mut self.entries, &mut self.last_entries);
+        self.entries.clear();
+    }
+
+    /// Returns the innermost element at the given position.
+    fn element_at(entries: &[InspectorEntry], position: crate::Point<Pixels>) -> Option<usize> {
+        entries
+            .iter()
+            .rposition(|entry| entry.visible_bounds.contains(&position))
+    }
+}
+
+impl<'a> WindowContext<'a> {
+    /// Enables or disables the element inspector, which highlights the element
+    /// under the mouse and selects it when clicked instead of dispatching the
+    /// click. The selected element can be read with [`WindowContext::inspected_element`].
+    pub fn set_inspector_enabled(&mut self, enabled: bool) {
+        if enabled != self.window.inspector.is_some() {
+            self.window.inspector = enabled.then(Default::default);
+            self.refresh();
+        }
+    }
+
+    /// Returns whether the element inspector is enabled.
+    pub fn inspector_enabled(&self) -> bool {
+        self.window.inspector.is_some()
+    }
+
+    /// Returns the element last selected with the element inspector.
+    pub fn inspected_element(&self) -> Option<InspectedElement> {
+        self.window
+            .inspector
+            .as_ref()
+            .and_then(|inspector| inspector.borrow().selected.clone())
+    }
+
+    /// Records that the component being rendered resolved a theme token to the
+    /// given color, so that the element inspector can show which token produced
+    /// a color. The name is only computed while the inspector is enabled.
+    pub fn record_theme_token(&self, name: impl FnOnce() -> String, value: Hsla) {
+        let Some(inspector) = self.window.inspector.as_ref() else {
+            return;
+        };
+        if let Some(component) = inspector.borrow_mut().component_stack.last_mut() {
+            component.theme_tokens.push(ThemeToken {
+                name: name().into(),
+                value,
+            });
+        }
+    }
+
+    /// Runs the given phase of drawing a component with it on the inspector's
+    /// component stack, creating it on its first phase.
+    pub(crate) fn with_inspected_component<R>(
+        &mut self,
+        component: &mut Option<InspectedComponent>,
+        name: fn() -> &'static str,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let Some(inspector) = self.window.inspector.as_ref() else {
+            return f(self);
+        };
+        inspector
+            .borrow_mut()
+            .component_stack
+            .push(component.take().unwrap_or_else(|| InspectedComponent {
+                name: name(),
+                theme_tokens: Vec::new(),
+            }));
+
+        let result = f(self);
+
+        if let Some(inspector) = self.window.inspector.as_ref() {
+            *component = inspector.borrow_mut().component_stack.pop();
+        }
+        result
+    }
+
+    /// Records an element of the frame being drawn with its resolved style.
+    pub(crate) fn record_inspected_element(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        style: &Style,
+        source_location: Option<core::panic::Location<'static>>,
+    ) {
+        let Some(inspector) = self.window.inspector.as_ref() else {
+            return;
+        };
+        let rem_size = self.rem_size();
+        let padding = style.padding.to_pixels(bounds.size.into(), rem_size);
+        let visible_bounds = bounds.intersect(&self.content_mask().bounds);
+        let mut inspector = inspector.borrow_mut();
+        let components = inspector.component_stack.clone();
+        inspector.entries.push(InspectorEntry {
+            element: InspectedElement {
+                bounds,
+                padding,
+                components,
+                source_location,
+            },
+            visible_bounds,
+        });
+    }
+
+    /// Paints the bounds and padding of the element under the mouse on top of
+    /// the frame being drawn.
+    pub(crate) fn paint_inspector_highlight(&mut self) {
+        let Some(inspector) = self.window.inspector.as_ref() else {
+            return;
+        };
+        let inspector = inspector.borrow();
+        let Some(ix) = Inspector::element_at(&inspector.entries, self.mouse_position()) else {
+            return;
+        };
+        let bounds = inspector.entries[ix].element.bounds;
+        let padding = inspector.entries[ix].element.padding.clone();
+        drop(inspector);
+
+        let padding_color = hsla(0.33, 0.6, 0.5, 0.3);
+        let content_color = hsla(0.58, 0.8, 0.6, 0.3);
+        let content_bounds = Bounds::from_corners(
+            bounds.origin + point(padding.left, padding.top),
+            bounds.lower_right() - point(padding.right, padding.bottom),
+        );
+        for padding_bounds in [
+            Bounds::from_corners(bounds.origin, point(bounds.right(), content_bounds.top())),
+            Bounds::from_corners(
+                point(bounds.left(), content_bounds.bottom()),
+                bounds.lower_right(),
+            ),
+            Bounds::from_corners(
+                point(bounds.left(), content_bounds.top()),
+                content_bounds.lower_left(),
+            ),
+            Bounds::from_corners(
+                content_bounds.upper_right(),
+                point(bounds.right(), content_bounds.bottom()),
+            ),
+        ] {
+            if !padding_bounds.is_empty() {
+                self.paint_quad(fill(padding_bounds, padding_color));
+            }
+        }
+        if !content_bounds.is_empty() {
+            self.paint_quad(fill(content_bounds, content_color));
+        }
+        self.paint_quad(outline(bounds, hsla(0.58, 0.8, 0.5, 1.)));
+    }
+
+    /// Handles a mouse event while the inspector is enabled, returning whether
+    /// it was consumed rather than dispatched to the elements of the window.
+    pub(crate) fn inspect_mouse_event(&mut self, event: &dyn Any) -> bool {
+        let mouse_position = self.mouse_position();
+        let Some(inspector) = self.window.inspector.as_ref() else {
+            return false;
+        };
+        let mut inspector = inspector.borrow_mut();
+        let hovered = Inspector::element_at(&inspector.last_entries, mouse_position);
+
+        if event.is::<MouseMoveEvent>() {
+            // Mouse moves are still dispatched, so that hover styles keep working.
+            if mem::replace(&mut inspector.hovered, hovered) != hovered {
+                drop(inspector);
+                self.refresh();
+            }
+            false
+        } else if let Some(event) = event.downcast_ref::<MouseDownEvent>() {
+            if event.button != MouseButton::Left {
+                return false;
+            }
+            inspector.selected = hovered.map(|ix| inspector.last_entries[ix].element.clone());
+            drop(inspector);
+            self.refresh();
+            true
+        } else if let Some(event) = event.downcast_ref::<MouseUpEvent>() {
+            event.button == MouseButton::Left
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        div, prelude::*, px, size, Edges, Modifiers, Render, TestAppContext, ViewContext,
+        WindowContext,
+    };
+
+    #[derive(IntoElement)]
+    struct Swatch;
+
+    impl RenderOnce for Swatch {
+        fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+            cx.record_theme_token(|| "swatch".into(), crate::red());
+            div().size(px(40.)).p(px(4.))
+        }
+    }
+
+    struct InspectedView;
+
+    impl Render for InspectedView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div().size_full().child(Swatch)
+        }
+    }
+
+    #[crate::test]
+    fn test_inspect_element(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| InspectedView);
+        cx.simulate_resize(size(px(100.), px(100.)));
+        cx.update(|cx| cx.set_inspector_enabled(true));
+        cx.run_until_parked();
+
+        cx.simulate_click(crate::point(px(10.), px(10.)), Modifiers::none());
+        let element = cx.update(|cx| cx.inspected_element()).unwrap();
+        assert_eq!(element.bounds.size, size(px(40.), px(40.)));
+        assert_eq!(element.padding, Edges::all(px(4.)));
+        assert_eq!(element.source_location.is_some(), cfg!(debug_assertions));
+
+        let component = element.components.last().unwrap();
+        assert!(component.name.ends_with("Swatch"));
+        assert_eq!(component.theme_tokens.len(), 1);
+        assert_eq!(component.theme_tokens[0].name, "swatch");
+    }
+}
@@ -0,0 +1,973 @@
+use std::{fmt::Write as _, io::Cursor, mem};
+
+use anyhow::{anyhow, Result};
+use base64::Engine as _;
+use collections::FxHashMap;
+use resvg::tiny_skia::{
+    self, FillRule, FilterQuality, IntSize, Mask, Paint, PathBuilder, Pixmap, PixmapPaint,
+    Transform,
+};
+
+use crate::{
+    AtlasTextureId, AtlasTile, Bounds, ContentMask, Corners, DevicePixels, DispatchTree, Edges,
+    Hsla, IntoElement, MonochromeSprite, Path, Pixels, Point, PolychromeSprite, PrimitiveBatch,
+    Quad, Rgba, ScaledPixels, Scene, Shadow, Size, TileId, TransformationMatrix, Underline,
+    WindowContext,
+};
+
+use super::{DrawPhase, Frame};
+
+/// The bitmap of an atlas tile painted while rendering offscreen: an alpha mask
+/// for monochrome sprites, BGRA pixels for polychrome ones.
+pub(crate) struct OffscreenTile {
+    size: Size<DevicePixels>,
+    bytes: Vec<u8>,
+}
+
+/// An element tree rendered outside of the window, which can be exported as an
+/// image with [`OffscreenScene::to_png`] or [`OffscreenScene::to_svg`].
+///
+/// Produced by [`WindowContext::render_offscreen`].
+pub struct OffscreenScene {
+    scene: Scene,
+    tiles: FxHashMap<(AtlasTextureId, TileId), OffscreenTile>,
+    size: Size<DevicePixels>,
+    scale_factor: f32,
+}
+
+impl<'a> WindowContext<'a> {
+    /// Lays out and paints the given element at the given size and scale factor,
+    /// without presenting it in the window, e.g. to export a screenshot of a code
+    /// block or a thumbnail of a theme.
+    ///
+    /// The element is rendered as of its current state: assets that aren't
+    /// loaded yet, like images, are left out, as are deferred draws like popovers.
+    ///
+    /// This method can't be called while the window is being drawn.
+    pub fn render_offscreen(
+        &mut self,
+        element: impl IntoElement,
+        size: Size<Pixels>,
+        scale_factor: f32,
+    ) -> OffscreenScene {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::None,
+            "can't render offscreen while drawing the window"
+        );
+
+        let mut frame = Frame::new(DispatchTree::new(
+            self.app.keymap.clone(),
+            self.app.actions.clone(),
+        ));
+        mem::swap(&mut self.window.next_frame, &mut frame);
+        let window_scale_factor = mem::replace(&mut self.window.scale_factor, scale_factor);
+        // Hover styles of the rendered elements would be resolved against the
+        // hitboxes of the window.
+        let mouse_hit_test = mem::take(&mut self.window.mouse_hit_test);
+        // Views painted in the window would otherwise replay their primitives
+        // from its last frame.
+        let refreshing = mem::replace(&mut self.window.refreshing, true);
+        self.window.offscreen_tiles = Some(FxHashMap::default());
+        let inspector = self.window.inspector.take();
+
+        self.window.draw_phase = DrawPhase::Prepaint;
+        let mut element = element.into_any_element();
+        element.prepaint_as_root(Point::default(), size.into(), self);
+        self.window.draw_phase = DrawPhase::Paint;
+        element.paint(self);
+        self.window.draw_phase = DrawPhase::None;
+        drop(element);
+        self.window.layout_engine.as_mut().unwrap().clear();
+
+        let tiles = self.window.offscreen_tiles.take().unwrap_or_default();
+        self.window.inspector = inspector;
+        self.window.mouse_hit_test = mouse_hit_test;
+        self.window.refreshing = refreshing;
+        self.window.scale_factor = window_scale_factor;
+        mem::swap(&mut self.window.next_frame, &mut frame);
+
+        // Hand the state of elements that are also in the window back to it.
+        for (key, state) in frame.element_states.drain() {
+            self.window
+                .rendered_frame
+                .element_states
+                .entry(key)
+                .or_insert(state);
+        }
+
+        let mut scene = mem::take(&mut frame.scene);
+        scene.finish();
+        OffscreenScene {
+            scene,
+            tiles,
+            size: size
+                .scale(scale_factor)
+                .map(|pixels| DevicePixels::from(pixels.0.ceil() as i32)),
+            scale_factor,
+        }
+    }
+
+    /// Records the bitmap of a tile painted while rendering offscreen, which is
+    /// otherwise only uploaded to the GPU.
+    pub(crate) fn capture_offscreen_tile(
+        &mut self,
+        tile: &AtlasTile,
+        render: impl FnOnce(&mut Self) -> Result<Option<(Size<DevicePixels>, Vec<u8>)>>,
+    ) -> Result<()> {
+        let key = (tile.texture_id, tile.tile_id);
+        match self.window.offscreen_tiles.as_ref() {
+            Some(tiles) if !tiles.contains_key(&key) => {}
+            _ => return Ok(()),
+        }
+        if let Some((size, bytes)) = render(self)? {
+            if let Some(tiles) = self.window.offscreen_tiles.as_mut() {
+                tiles.insert(key, OffscreenTile { size, bytes });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl OffscreenScene {
+    /// The size of the rendered image in device pixels.
+    pub fn size(&self) -> Size<DevicePixels> {
+        self.size
+    }
+
+    /// Rasterizes the scene into a PNG.
+    ///
+    /// This is done on the CPU and approximates the GPU renderer: shadows are
+    /// blurred in steps and wavy underlines are drawn straight.
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        encode_png(&self.rasterize()?)
+    }
+
+    /// Writes the scene as an SVG document, with the size of the scene in logical
+    /// pixels and shapes at device pixel precision.
+    ///
+    /// Glyphs, icons and images are embedded as bitmaps at the scale the scene was
+    /// rendered at.
+    pub fn to_svg(&self) -> String {
+        let mut svg = SvgWriter::new(self.size, self.scale_factor);
+        for batch in self.scene.batches() {
+            match batch {
+                PrimitiveBatch::Shadows(shadows) => {
+                    shadows.iter().for_each(|shadow| svg.shadow(shadow))
+                }
+                PrimitiveBatch::Quads(quads) => quads.iter().for_each(|quad| svg.quad(quad)),
+                PrimitiveBatch::Paths(paths) => paths.iter().for_each(|path| svg.path(path)),
+                PrimitiveBatch::Underlines(underlines) => underlines
+                    .iter()
+                    .for_each(|underline| svg.underline(underline)),
+                PrimitiveBatch::MonochromeSprites { sprites, .. } => {
+                    for sprite in sprites {
+                        if let Some(tile) = self.tile(&sprite.tile) {
+                            svg.monochrome_sprite(sprite, tile);
+                        }
+                    }
+                }
+                PrimitiveBatch::PolychromeSprites { sprites, .. } => {
+                    for sprite in sprites {
+                        if let Some(tile) = self.tile(&sprite.tile) {
+                            svg.polychrome_sprite(sprite, tile);
+                        }
+                    }
+                }
+                PrimitiveBatch::Surfaces(_) => {}
+            }
+        }
+        svg.finish()
+    }
+
+    fn tile(&self, tile: &AtlasTile) -> Option<&OffscreenTile> {
+        self.tiles.get(&(tile.texture_id, tile.tile_id))
+    }
+
+    fn rasterize(&self) -> Result<Pixmap> {
+        let mut rasterizer = Rasterizer {
+            pixmap: Pixmap::new(self.size.width.0 as u32, self.size.height.0 as u32)
+                .ok_or_else(|| anyhow!("can't render at a zero size"))?,
+            masks: FxHashMap::default(),
+        };
+        for batch in self.scene.batches() {
+            match batch {
+                PrimitiveBatch::Shadows(shadows) => {
+                    shadows.iter().for_each(|shadow| rasterizer.shadow(shadow))
+                }
+                PrimitiveBatch::Quads(quads) => quads.iter().for_each(|quad| rasterizer.quad(quad)),
+                PrimitiveBatch::Paths(paths) => paths.iter().for_each(|path| rasterizer.path(path)),
+                PrimitiveBatch::Underlines(underlines) => underlines
+                    .iter()
+                    .for_each(|underline| rasterizer.underline(underline)),
+                PrimitiveBatch::MonochromeSprites { sprites, .. } => {
+                    for sprite in sprites {
+                        if let Some(tile) = self.tile(&sprite.tile) {
+                            rasterizer.monochrome_sprite(sprite, tile);
+                        }
+                    }
+                }
+                PrimitiveBatch::PolychromeSprites { sprites, .. } => {
+                    for sprite in sprites {
+                        if let Some(tile) = self.tile(&sprite.tile) {
+                            rasterizer.polychrome_sprite(sprite, tile);
+                        }
+                    }
+                }
+                PrimitiveBatch::Surfaces(_) => {}
+            }
+        }
+        Ok(rasterizer.pixmap)
+    }
+}
+
+/// The inner bounds and corner radii of a quad with the given border widths.
+fn inner_quad(
+    bounds: &Bounds<ScaledPixels>,
+    corner_radii: &Corners<ScaledPixels>,
+    border_widths: &Edges<ScaledPixels>,
+) -> (Bounds<ScaledPixels>, Corners<ScaledPixels>) {
+    let inner = Bounds::from_corners(
+        bounds.origin + Point::new(border_widths.left, border_widths.top),
+        bounds.lower_right() - Point::new(border_widths.right, border_widths.bottom),
+    );
+    let shrink = |radius: ScaledPixels, a: ScaledPixels, b: ScaledPixels| {
+        ScaledPixels((radius.0 - a.0.max(b.0)).max(0.))
+    };
+    let radii = Corners {
+        top_left: shrink(corner_radii.top_left, border_widths.top, border_widths.left),
+        top_right: shrink(
+            corner_radii.top_right,
+            border_widths.top,
+            border_widths.right,
+        ),
+        bottom_right: shrink(
+            corner_radii.bottom_right,
+            border_widths.bottom,
+            border_widths.right,
+        ),
+        bottom_left: shrink(
+            corner_radii.bottom_left,
+            border_widths.bottom,
+            border_widths.left,
+        ),
+    };
+    (inner, radii)
+}
+
+fn has_border(border_widths: &Edges<ScaledPixels>) -> bool {
+    [
+        border_widths.top,
+        border_widths.right,
+        border_widths.bottom,
+        border_widths.left,
+    ]
+    .iter()
+    .any(|width| width.0 > 0.)
+}
+
+/// Clamps the corner radii of a rectangle so that they fit in it.
+fn clamp_radii(bounds: &Bounds<ScaledPixels>, radii: &Corners<ScaledPixels>) -> Corners<f32> {
+    let max = bounds.size.width.0.min(bounds.size.height.0) / 2.;
+    Corners {
+        top_left: radii.top_left.0.clamp(0., max),
+        top_right: radii.top_right.0.clamp(0., max),
+        bottom_right: radii.bottom_right.0.clamp(0., max),
+        bottom_left: radii.bottom_left.0.clamp(0., max),
+    }
+}
+
+/// Converts a sprite transformation to the tiny-skia convention.
+fn skia_transform(transformation: &TransformationMatrix) -> Transform {
+    let [[a, b], [c, d]] = transformation.rotation_scale;
+    let [tx, ty] = transformation.translation;
+    Transform::from_row(a, c, b, d, tx, ty)
+}
+
+struct Rasterizer {
+    pixmap: Pixmap,
+    masks: FxHashMap<[u32; 4], Option<Mask>>,
+}
+
+/// Returns the mask clipping a pixmap of the given size to the given content
+/// mask, or `None` when it covers the whole pixmap.
+fn content_mask_clip<'a>(
+    masks: &'a mut FxHashMap<[u32; 4], Option<Mask>>,
+    pixmap: &Pixmap,
+    content_mask: &ContentMask<ScaledPixels>,
+) -> Option<&'a Mask> {
+    let bounds = content_mask.bounds;
+    let (width, height) = (pixmap.width(), pixmap.height());
+    masks
+        .entry(content_mask_key(content_mask))
+        .or_insert_with(|| {
+            let covers_pixmap = bounds.origin.x.0 <= 0.
+                && bounds.origin.y.0 <= 0.
+                && bounds.right().0 >= width as f32
+                && bounds.bottom().0 >= height as f32;
+            if covers_pixmap {
+                return None;
+            }
+            let mut mask = Mask::new(width, height)?;
+            if let Some(rect) = skia_rect(&bounds) {
+                let path = PathBuilder::from_rect(rect);
+                mask.fill_path(&path, FillRule::Winding, false, Transform::identity());
+            }
+            Some(mask)
+        })
+        .as_ref()
+}
+
+fn content_mask_key(content_mask: &ContentMask<ScaledPixels>) -> [u32; 4] {
+    let bounds = content_mask.bounds;
+    [
+        bounds.origin.x.0.to_bits(),
+        bounds.origin.y.0.to_bits(),
+        bounds.size.width.0.to_bits(),
+        bounds.size.height.0.to_bits(),
+    ]
+}
+
+impl Rasterizer {
+    fn fill(
+        &mut self,
+        path: Option<tiny_skia::Path>,
+        color: Hsla,
+        fill_rule: FillRule,
+        content_mask: &ContentMask<ScaledPixels>,
+    ) {
+        let Some(path) = path else {
+            return;
+        };
+        if color.a <= 0. {
+            return;
+        }
+        let mut paint = Paint::default();
+        paint.set_color(skia_color(color));
+        paint.anti_alias = true;
+        let mask = content_mask_clip(&mut self.masks, &self.pixmap, content_mask);
+        self.pixmap
+            .fill_path(&path, &paint, fill_rule, Transform::identity(), mask);
+    }
+
+    fn shadow(&mut self, shadow: &Shadow) {
+        // Approximate the gaussian blur with concentric rectangles, each adding
+        // a fraction of the color from the inside of the blur to its outside.
+        const STEPS: usize = 8;
+        let blur = shadow.blur_radius.0;
+        if blur <= 0. {
+            let path = rounded_rect_path(&shadow.bounds, &shadow.corner_radii);
+            self.fill(path, shadow.color, FillRule::Winding, &shadow.content_mask);
+            return;
+        }
+        let color = Hsla {
+            a: shadow.color.a / STEPS as f32,
+            ..shadow.color
+        };
+        for step in 0..STEPS {
+            let spread = blur * ((step as f32 + 0.5) / STEPS as f32 * 2. - 1.);
+            let mut bounds = shadow.bounds;
+            bounds.dilate(ScaledPixels(spread));
+            let radii = shadow
+                .corner_radii
+                .map(|radius| ScaledPixels((radius.0 + spread).max(0.)));
+            let path = rounded_rect_path(&bounds, &radii);
+            self.fill(path, color, FillRule::Winding, &shadow.content_mask);
+        }
+    }
+
+    fn quad(&mut self, quad: &Quad) {
+        let outer = rounded_rect_path(&quad.bounds, &quad.corner_radii);
+        self.fill(
+            outer.clone(),
+            quad.background,
+            FillRule::Winding,
+            &quad.content_mask,
+        );
+        if has_border(&quad.border_widths) {
+            let (inner_bounds, inner_radii) =
+                inner_quad(&quad.bounds, &quad.corner_radii, &quad.border_widths);
+            let mut builder = PathBuilder::new();
+            push_rounded_rect(&mut builder, &quad.bounds, &quad.corner_radii);
+            push_rounded_rect(&mut builder, &inner_bounds, &inner_radii);
+            self.fill(
+                builder.finish(),
+                quad.border_color,
+                FillRule::EvenOdd,
+                &quad.content_mask,
+            );
+        }
+    }
+
+    fn path(&mut self, path: &Path<ScaledPixels>) {
+        self.fill(
+            path_triangles(path),
+            path.color,
+            FillRule::EvenOdd,
+            &path.content_mask,
+        );
+    }
+
+    fn underline(&mut self, underline: &Underline) {
+        let mut bounds = underline.bounds;
+        bounds.size.height = underline.thickness;
+        let path = skia_rect(&bounds).map(PathBuilder::from_rect);
+        self.fill(
+            path,
+            underline.color,
+            FillRule::Winding,
+            &underline.content_mask,
+        );
+    }
+
+    fn draw_tile(
+        &mut self,
+        tile: Pixmap,
+        bounds: &Bounds<ScaledPixels>,
+        transformation: Transform,
+        opacity: f32,
+        content_mask: &ContentMask<ScaledPixels>,
+    ) {
+        let transform = Transform::from_scale(
+            bounds.size.width.0 / tile.width() as f32,
+            bounds.size.height.0 / tile.height() as f32,
+        )
+        .post_translate(bounds.origin.x.0, bounds.origin.y.0)
+        .post_concat(transformation);
+        let paint = PixmapPaint {
+            opacity,
+            quality: FilterQuality::Bilinear,
+            ..Default::default()
+        };
+        let mask = content_mask_clip(&mut self.masks, &self.pixmap, content_mask);
+        self.pixmap
+            .draw_pixmap(0, 0, tile.as_ref(), &paint, transform, mask);
+    }
+
+    fn monochrome_sprite(&mut self, sprite: &MonochromeSprite, tile: &OffscreenTile) {
+        let Some(pixmap) = monochrome_tile_pixmap(tile, sprite.color) else {
+            return;
+        };
+        self.draw_tile(
+            pixmap,
+            &sprite.bounds,
+            skia_transform(&sprite.transformation),
+            1.,
+            &sprite.content_mask,
+        );
+    }
+
+    fn polychrome_sprite(&mut self, sprite: &PolychromeSprite, tile: &OffscreenTile) {
+        let Some(pixmap) = polychrome_tile_pixmap(tile, sprite.grayscale) else {
+            return;
+        };
+        self.draw_tile(
+            pixmap,
+            &sprite.bounds,
+            Transform::identity(),
+            sprite.opacity,
+            &sprite.content_mask,
+        );
+    }
+}
+
+fn skia_rect(bounds: &Bounds<ScaledPixels>) -> Option<tiny_skia::Rect> {
+    tiny_skia::Rect::from_xywh(
+        bounds.origin.x.0,
+        bounds.origin.y.0,
+        bounds.size.width.0,
+        bounds.size.height.0,
+    )
+}
+
+fn skia_color(color: Hsla) -> tiny_skia::Color {
+    let rgba = Rgba::from(color);
+    tiny_skia::Color::from_rgba(
+        rgba.r.clamp(0., 1.),
+        rgba.g.clamp(0., 1.),
+        rgba.b.clamp(0., 1.),
+        rgba.a.clamp(0., 1.),
+    )
+    .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+fn rounded_rect_path(
+    bounds: &Bounds<ScaledPixels>,
+    radii: &Corners<ScaledPixels>,
+) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    push_rounded_rect(&mut builder, bounds, radii);
+    builder.finish()
+}
+
+fn push_rounded_rect(
+    builder: &mut PathBuilder,
+    bounds: &Bounds<ScaledPixels>,
+    radii: &Corners<ScaledPixels>,
+) {
+    // The distance of the control points of a cubic approximating a quarter circle.
+    const KAPPA: f32 = 0.552_284_8;
+    if bounds.size.width.0 <= 0. || bounds.size.height.0 <= 0. {
+        return;
+    }
+    let radii = clamp_radii(bounds, radii);
+    let (left, top) = (bounds.origin.x.0, bounds.origin.y.0);
+    let (right, bottom) = (bounds.right().0, bounds.bottom().0);
+    let k = |radius: f32| radius * (1. - KAPPA);
+
+    builder.move_to(left + radii.top_left, top);
+    builder.line_to(right - radii.top_right, top);
+    builder.cubic_to(
+        right - k(radii.top_right),
+        top,
+        right,
+        top + k(radii.top_right),
+        right,
+        top + radii.top_right,
+    );
+    builder.line_to(right, bottom - radii.bottom_right);
+    builder.cubic_to(
+        right,
+        bottom - k(radii.bottom_right),
+        right - k(radii.bottom_right),
+        bottom,
+        right - radii.bottom_right,
+        bottom,
+    );
+    builder.line_to(left + radii.bottom_left, bottom);
+    builder.cubic_to(
+        left + k(radii.bottom_left),
+        bottom,
+        left,
+        bottom - k(radii.bottom_left),
+        left,
+        bottom - radii.bottom_left,
+    );
+    builder.line_to(left, top + radii.top_left);
+    builder.cubic_to(
+        left,
+        top + k(radii.top_left),
+        left + k(radii.top_left),
+        top,
+        left + radii.top_left,
+        top,
+    );
+    builder.close();
+}
+
+/// Builds the triangles of a path, which cover its inside an odd number of times.
+/// Curved triangles cover the area between their curve and its chord.
+fn path_triangles(path: &Path<ScaledPixels>) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    for triangle in path.vertices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|ix| triangle[ix].xy_position);
+        builder.move_to(a.x.0, a.y.0);
+        if triangle[1].st_position.x == 0.5 {
+            builder.quad_to(b.x.0, b.y.0, c.x.0, c.y.0);
+        } else {
+            builder.line_to(b.x.0, b.y.0);
+            builder.line_to(c.x.0, c.y.0);
+        }
+        builder.close();
+    }
+    builder.finish()
+}
+
+fn tile_size(tile: &OffscreenTile) -> Option<IntSize> {
+    IntSize::from_wh(tile.size.width.0 as u32, tile.size.height.0 as u32)
+}
+
+/// Converts the alpha mask of a monochrome tile into premultiplied pixels of the
+/// given color.
+fn monochrome_tile_pixmap(tile: &OffscreenTile, color: Hsla) -> Option<Pixmap> {
+    let size = tile_size(tile)?;
+    let rgba = Rgba::from(color);
+    let mut data = Vec::with_capacity(tile.bytes.len() * 4);
+    for alpha in &tile.bytes {
+        let alpha = *alpha as f32 / 255. * rgba.a;
+        data.extend(
+            [rgba.r * alpha, rgba.g * alpha, rgba.b * alpha, alpha]
+                .map(|channel| (channel.clamp(0., 1.) * 255.).round() as u8),
+        );
+    }
+    Pixmap::from_vec(data, size)
+}
+
+/// Converts the BGRA pixels of a polychrome tile into premultiplied RGBA ones.
+fn polychrome_tile_pixmap(tile: &OffscreenTile, grayscale: bool) -> Option<Pixmap> {
+    let size = tile_size(tile)?;
+    let data = tile
+        .bytes
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let [b, g, r, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+            let [r, g, b] = if grayscale {
+                let gray = (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) as u8;
+                [gray; 3]
+            } else {
+                [r, g, b]
+            };
+            let premultiply = |channel: u8| (channel as u16 * a as u16 / 255) as u8;
+            [premultiply(r), premultiply(g), premultiply(b), a]
+        })
+        .collect();
+    Pixmap::from_vec(data, size)
+}
+
+fn encode_png(pixmap: &Pixmap) -> Result<Vec<u8>> {
+    let mut pixels = Vec::with_capacity(pixmap.data().len());
+    for pixel in pixmap.pixels() {
+        let color = pixel.demultiply();
+        pixels.extend([color.red(), color.green(), color.blue(), color.alpha()]);
+    }
+    let image = image::RgbaImage::from_raw(pixmap.width(), pixmap.height(), pixels)
+        .ok_or_else(|| anyhow!("invalid pixmap size"))?;
+    let mut png = Vec::new();
+    image.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?;
+    Ok(png)
+}
+
+fn encode_data_uri(pixmap: &Pixmap) -> Option<String> {
+    let png = encode_png(pixmap).ok()?;
+    Some(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(png)
+    ))
+}
+
+struct SvgWriter {
+    defs: String,
+    body: String,
+    size: Size<DevicePixels>,
+    scale_factor: f32,
+    clip_paths: FxHashMap<[u32; 4], usize>,
+    blur_filters: FxHashMap<u32, usize>,
+}
+
+impl SvgWriter {
+    fn new(size: Size<DevicePixels>, scale_factor: f32) -> Self {
+        Self {
+            defs: String::new(),
+            body: String::new(),
+            size,
+            scale_factor,
+            clip_paths: FxHashMap::default(),
+            blur_filters: FxHashMap::default(),
+        }
+    }
+
+    fn finish(self) -> String {
+        let (width, height) = (self.size.width.0, self.size.height.0);
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {width} {height}\"><defs>{}</defs>{}</svg>",
+            width as f32 / self.scale_factor,
+            height as f32 / self.scale_factor,
+            self.defs,
+            self.body
+        )
+    }
+
+    /// Returns the attribute clipping to the given content mask.
+    fn clip_path(&mut self, content_mask: &ContentMask<ScaledPixels>) -> String {
+        let bounds = content_mask.bounds;
+        let next_id = self.clip_paths.len();
+        let id = *self
+            .clip_paths
+            .entry(content_mask_key(content_mask)).or_insert_with(|| {
+            write!(
+                self.defs,
+                "<clipPath id=\"clip{next_id}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/></clipPath>",
+                bounds.origin.x.0, bounds.origin.y.0, bounds.size.width.0, bounds.size.height.0
+            )
+            .ok();
+            next_id
+        });
+        format!("clip-path=\"url(#clip{id})\"")
+    }
+
+    fn blur_filter(&mut self, blur_radius: f32) -> String {
+        let next_id = self.blur_filters.len();
+        let (width, height) = (self.size.width.0, self.size.height.0);
+        let id = *self
+            .blur_filters
+            .entry(blur_radius.to_bits())
+            .or_insert_with(|| {
+                write!(
+                    self.defs,
+                    "<filter id=\"blur{next_id}\" filterUnits=\"userSpaceOnUse\" x=\"0\" y=\"0\" width=\"{width}\" height=\"{height}\"><feGaussianBlur stdDeviation=\"{}\"/></filter>",
+                    blur_radius / 2.
+                )
+                .ok();
+                next_id
+            });
+        format!("filter=\"url(#blur{id})\"")
+    }
+
+    fn fill(color: Hsla) -> String {
+        let rgba = Rgba::from(color);
+        let channel = |value: f32| (value.clamp(0., 1.) * 255.).round() as u8;
+        format!(
+            "fill=\"rgb({},{},{})\" fill-opacity=\"{}\"",
+            channel(rgba.r),
+            channel(rgba.g),
+            channel(rgba.b),
+            rgba.a.clamp(0., 1.)
+        )
+    }
+
+    fn shadow(&mut self, shadow: &Shadow) {
+        if shadow.color.a <= 0. {
+            return;
+        }
+        let clip_path = self.clip_path(&shadow.content_mask);
+        let filter = if shadow.blur_radius.0 > 0. {
+            self.blur_filter(shadow.blur_radius.0)
+        } else {
+            String::new()
+        };
+        write!(
+            self.body,
+            "<g {clip_path}><path d=\"{}\" {} {filter}/></g>",
+            rounded_rect_svg_path(&shadow.bounds, &shadow.corner_radii),
+            Self::fill(shadow.color)
+        )
+        .ok();
+    }
+
+    fn quad(&mut self, quad: &Quad) {
+        let draws_border = has_border(&quad.border_widths) && quad.border_color.a > 0.;
+        if quad.background.a <= 0. && !draws_border {
+            return;
+        }
+        let clip_path = self.clip_path(&quad.content_mask);
+        let outer = rounded_rect_svg_path(&quad.bounds, &quad.corner_radii);
+        write!(self.body, "<g {clip_path}>").ok();
+        if quad.background.a > 0. {
+            write!(
+                self.body,
+                "<path d=\"{outer}\" {}/>",
+                Self::fill(quad.background)
+            )
+            .ok();
+        }
+        if draws_border {
+            let (inner_bounds, inner_radii) =
+                inner_quad(&quad.bounds, &quad.corner_radii, &quad.border_widths);
+            write!(
+                self.body,
+                "<path d=\"{outer}{}\" fill-rule=\"evenodd\" {}/>",
+                rounded_rect_svg_path(&inner_bounds, &inner_radii),
+                Self::fill(quad.border_color)
+            )
+            .ok();
+        }
+        write!(self.body, "</g>").ok();
+    }
+
+    fn path(&mut self, path: &Path<ScaledPixels>) {
+        let clip_path = self.clip_path(&path.content_mask);
+        let mut d = String::new();
+        for triangle in path.vertices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|ix| triangle[ix].xy_position);
+            let command = if triangle[1].st_position.x == 0.5 {
+                "Q"
+            } else {
+                "L"
+            };
+            write!(
+                d,
+                "M{} {}{command}{} {} {} {}Z",
+                a.x.0, a.y.0, b.x.0, b.y.0, c.x.0, c.y.0
+            )
+            .ok();
+        }
+        write!(
+            self.body,
+            "<path d=\"{d}\" fill-rule=\"evenodd\" {clip_path} {}/>",
+            Self::fill(path.color)
+        )
+        .ok();
+    }
+
+    fn underline(&mut self, underline: &Underline) {
+        let clip_path = self.clip_path(&underline.content_mask);
+        let bounds = underline.bounds;
+        write!(
+            self.body,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {clip_path} {}/>",
+            bounds.origin.x.0,
+            bounds.origin.y.0,
+            bounds.size.width.0,
+            underline.thickness.0,
+            Self::fill(underline.color)
+        )
+        .ok();
+    }
+
+    fn image(
+        &mut self,
+        pixmap: Pixmap,
+        bounds: &Bounds<ScaledPixels>,
+        transformation: &TransformationMatrix,
+        opacity: f32,
+        content_mask: &ContentMask<ScaledPixels>,
+    ) {
+        let Some(href) = encode_data_uri(&pixmap) else {
+            return;
+        };
+        let clip_path = self.clip_path(content_mask);
+        let [[a, b], [c, d]] = transformation.rotation_scale;
+        let [tx, ty] = transformation.translation;
+        write!(
+            self.body,
+            "<g {clip_path}><image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" opacity=\"{opacity}\" transform=\"matrix({a} {c} {b} {d} {tx} {ty})\" preserveAspectRatio=\"none\" href=\"{href}\"/></g>",
+            bounds.origin.x.0, bounds.origin.y.0, bounds.size.width.0, bounds.size.height.0,
+        )
+        .ok();
+    }
+
+    fn monochrome_sprite(&mut self, sprite: &MonochromeSprite, tile: &OffscreenTile) {
+        if let Some(pixmap) = monochrome_tile_pixmap(tile, sprite.color) {
+            self.image(
+                pixmap,
+                &sprite.bounds,
+                &sprite.transformation,
+                1.,
+                &sprite.content_mask,
+            );
+        }
+    }
+
+    fn polychrome_sprite(&mut self, sprite: &PolychromeSprite, tile: &OffscreenTile) {
+        if let Some(pixmap) = polychrome_tile_pixmap(tile, sprite.grayscale) {
+            self.image(
+                pixmap,
+                &sprite.bounds,
+                &TransformationMatrix::unit(),
+                sprite.opacity,
+                &sprite.content_mask,
+            );
+        }
+    }
+}
+
+fn rounded_rect_svg_path(bounds: &Bounds<ScaledPixels>, radii: &Corners<ScaledPixels>) -> String {
+    let radii = clamp_radii(bounds, radii);
+    let (left, top) = (bounds.origin.x.0, bounds.origin.y.0);
+    let (right, bottom) = (bounds.right().0, bounds.bottom().0);
+    format!(
+        "M{} {top}H{}A{r1} {r1} 0 0 1 {right} {}V{}A{r2} {r2} 0 0 1 {} {bottom}H{}A{r3} {r3} 0 0 1 {left} {}V{}A{r0} {r0} 0 0 1 {} {top}Z",
+        left + radii.top_left,
+        right - radii.top_right,
+        top + radii.top_right,
+        bottom - radii.bottom_right,
+        right - radii.bottom_right,
+        left + radii.bottom_left,
+        bottom - radii.bottom_left,
+        top + radii.top_left,
+        left + radii.top_left,
+        r0 = radii.top_left,
+        r1 = radii.top_right,
+        r2 = radii.bottom_right,
+        r3 = radii.bottom_left,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbaImage;
+
+    use super::OffscreenScene;
+    use crate::{div, prelude::*, px, rgb, size, TestAppContext, ViewContext};
+
+    struct ColorView {
+        color: u32,
+    }
+
+    impl Render for ColorView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div().size_full().bg(rgb(self.color))
+        }
+    }
+
+    fn rasterize(scene: &OffscreenScene) -> RgbaImage {
+        image::load_from_memory(&scene.to_png().unwrap())
+            .unwrap()
+            .to_rgba8()
+    }
+
+    #[crate::test]
+    fn test_render_offscreen(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let scene = cx.update(|cx| {
+            cx.render_offscreen(
+                div()
+                    .size_full()
+                    .bg(rgb(0xff0000))
+                    .child(div().size(px(2.)).bg(rgb(0x0000ff))),
+                size(px(8.), px(4.)),
+                2.,
+            )
+        });
+        assert_eq!(scene.size(), size(16.into(), 8.into()));
+
+        let png = scene.to_png().unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (16, 8));
+        assert_eq!(image.get_pixel(1, 1).0, [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(10, 6).0, [255, 0, 0, 255]);
+
+        let svg = scene.to_svg();
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"8\" height=\"4\"")
+        );
+        assert!(svg.contains("fill=\"rgb(255,0,0)\""));
+        assert!(svg.contains("fill=\"rgb(0,0,255)\""));
+        usvg::Tree::from_str(&svg, &usvg::Options::default()).unwrap();
+    }
+
+    #[crate::test]
+    fn test_render_view_offscreen(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| ColorView { color: 0x00ff00 });
+        cx.run_until_parked();
+
+        // The view is rendered as of its current state, rather than replaying what
+        // it painted in the window.
+        view.update(cx, |view, cx| {
+            view.color = 0xff0000;
+            cx.notify();
+        });
+        let scene = cx.update(|cx| cx.render_offscreen(view.clone(), size(px(2.25), px(1.25)), 2.));
+        // Fractional sizes are rounded up to whole device pixels.
+        assert_eq!(scene.size(), size(5.into(), 3.into()));
+
+        let image = rasterize(&scene);
+        assert_eq!(image.dimensions(), (5, 3));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(3, 1).0, [255, 0, 0, 255]);
+    }
+
+    #[crate::test]
+    fn test_render_offscreen_clips_to_content_mask(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let scene = cx.update(|cx| {
+            cx.render_offscreen(
+                div().size_full().bg(rgb(0xffffff)).child(
+                    div()
+                        .size(px(2.))
+                        .overflow_hidden()
+                        .child(div().size(px(4.)).bg(rgb(0x000000))),
+                ),
+                size(px(4.), px(4.)),
+                1.,
+            )
+        });
+        assert_eq!(scene.size(), size(4.into(), 4.into()));
+
+        let image = rasterize(&scene);
+        assert_eq!(image.get_pixel(1, 1).0, [0, 0, 0, 255]);
+        assert_eq!(image.get_pixel(3, 1).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(1, 3).0, [255, 255, 255, 255]);
+        assert_eq!(image.get_pixel(3, 3).0, [255, 255, 255, 255]);
+    }
+}
@@ -475,6 +475,7 @@ impl ConfigurationView {
             background_color: None,
             underline: None,
             strikethrough: None,
+            overline: None,
             white_space: WhiteSpace::Normal,
             truncate: None,
         };
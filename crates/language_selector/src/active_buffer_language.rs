@@ -1,5 +1,8 @@
 use editor::Editor;
-use gpui::{div, IntoElement, ParentElement, Render, Subscription, View, ViewContext, WeakView};
+use gpui::{
+    div, Action, IntoElement, ParentElement, Render, SharedString, Subscription, View, ViewContext,
+    WeakView, WindowContext,
+};
 use language::LanguageName;
 use ui::{Button, ButtonCommon, Clickable, FluentBuilder, LabelSize, Tooltip};
 use workspace::{item::ItemHandle, StatusItemView, Workspace};
@@ -76,4 +79,13 @@ impl StatusItemView for ActiveBufferLanguage {
 
         cx.notify();
     }
+
+    fn overflow_entry(&self, _: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+        self.active_language.is_some().then(|| {
+            (
+                "Select Language".into(),
+                Box::new(crate::Toggle) as Box<dyn Action>,
+            )
+        })
+    }
 }
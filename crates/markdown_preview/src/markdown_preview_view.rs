@@ -11,7 +11,7 @@ use gpui::{
     WeakView,
 };
 use language::LanguageRegistry;
-use ui::prelude::*;
+use ui::{prelude::*, ErrorBoundary};
 use workspace::item::{Item, ItemHandle};
 use workspace::{Pane, Workspace};
 
@@ -30,7 +30,7 @@ pub struct MarkdownPreviewView {
     workspace: WeakView<Workspace>,
     active_editor: Option<EditorState>,
     focus_handle: FocusHandle,
-    contents: Option<ParsedMarkdown>,
+    contents: Option<Arc<ParsedMarkdown>>,
     selected_block: usize,
     list_state: ListState,
     tab_description: Option<String>,
@@ -146,7 +146,7 @@ impl MarkdownPreviewView {
                 ListState::new(0, gpui::ListAlignment::Top, px(1000.), move |ix, cx| {
                     if let Some(view) = view.upgrade() {
                         view.update(cx, |this, cx| {
-                            let Some(contents) = &this.contents else {
+                            let Some(contents) = this.contents.clone() else {
                                 return div().into_any();
                             };
 
@@ -179,7 +179,14 @@ impl MarkdownPreviewView {
                                         }
                                     });
                             let block = contents.children.get(ix).unwrap();
-                            let rendered_block = render_markdown_block(block, &mut render_cx);
+                            // Parsed documents can contain anything, so a block that fails
+                            // to render shows an error instead of taking down the window.
+                            let rendered_block = ErrorBoundary::new(ix, {
+                                let contents = contents.clone();
+                                move |_| {
+                                    render_markdown_block(&contents.children[ix], &mut render_cx)
+                                }
+                            });
 
                             let should_apply_padding = Self::should_apply_padding_between(
                                 block,
@@ -365,7 +372,7 @@ impl MarkdownPreviewView {
             let contents = parsing_task.await;
             view.update(&mut cx, move |view, cx| {
                 let markdown_blocks_count = contents.children.len();
-                view.contents = Some(contents);
+                view.contents = Some(Arc::new(contents));
                 let scroll_top = view.list_state.logical_scroll_top();
                 view.list_state.reset(markdown_blocks_count);
                 view.list_state.scroll_to(scroll_top);
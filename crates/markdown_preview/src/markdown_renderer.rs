@@ -9,15 +9,14 @@ use gpui::{
     HighlightStyle, Hsla, InteractiveText, IntoElement, Keystroke, Length, Modifiers,
     ParentElement, SharedString, Styled, StyledText, TextStyle, WeakView, WindowContext,
 };
-use settings::Settings;
 use std::{
     ops::{Mul, Range},
     sync::Arc,
 };
-use theme::{ActiveTheme, SyntaxTheme, ThemeSettings};
+use theme::{ActiveTheme, SyntaxTheme};
 use ui::{
-    h_flex, relative, v_flex, Checkbox, FluentBuilder, InteractiveElement, LinkPreview, Selection,
-    StatefulInteractiveElement, Tooltip,
+    h_flex, relative, v_flex, Checkbox, CodeBlock, FluentBuilder, InteractiveElement, LinkPreview,
+    Selection, StatefulInteractiveElement, Tooltip,
 };
 use workspace::Workspace;
 
@@ -26,8 +25,6 @@ type CheckboxClickedCallback = Arc<Box<dyn Fn(bool, Range<usize>, &mut WindowCon
 pub struct RenderContext {
     workspace: Option<WeakView<Workspace>>,
     next_id: usize,
-    buffer_font_family: SharedString,
-    buffer_text_style: TextStyle,
     text_style: TextStyle,
     border_color: Hsla,
     text_color: Hsla,
@@ -43,17 +40,10 @@ impl RenderContext {
     pub fn new(workspace: Option<WeakView<Workspace>>, cx: &WindowContext) -> RenderContext {
         let theme = cx.theme().clone();
 
-        let settings = ThemeSettings::get_global(cx);
-        let buffer_font_family = settings.buffer_font.family.clone();
-        let mut buffer_text_style = cx.text_style();
-        buffer_text_style.font_family = buffer_font_family.clone();
-
         RenderContext {
             workspace,
             next_id: 0,
             indent: 0,
-            buffer_font_family,
-            buffer_text_style,
             text_style: cx.text_style(),
             syntax_theme: theme.syntax().clone(),
             border_color: theme.colors().border,
@@ -356,27 +346,21 @@ fn render_markdown_code_block(
     parsed: &ParsedMarkdownCodeBlock,
     cx: &mut RenderContext,
 ) -> AnyElement {
-    let body = if let Some(highlights) = parsed.highlights.as_ref() {
-        StyledText::new(parsed.contents.clone()).with_highlights(
-            &cx.buffer_text_style,
-            highlights.iter().filter_map(|(range, highlight_id)| {
-                highlight_id
-                    .style(cx.syntax_theme.as_ref())
-                    .map(|style| (range.clone(), style))
-            }),
-        )
-    } else {
-        StyledText::new(parsed.contents.clone())
-    };
+    let highlights = parsed
+        .highlights
+        .iter()
+        .flatten()
+        .filter_map(|(range, highlight_id)| {
+            highlight_id
+                .style(cx.syntax_theme.as_ref())
+                .map(|style| (range.clone(), style))
+        })
+        .collect::<Vec<_>>();
+    let code_block = CodeBlock::new(cx.next_id(&parsed.source_range), parsed.contents.clone())
+        .highlights(highlights)
+        .background(cx.code_block_background_color);
 
-    cx.with_common_p(div())
-        .font_family(cx.buffer_font_family.clone())
-        .px_3()
-        .py_3()
-        .bg(cx.code_block_background_color)
-        .rounded_md()
-        .child(body)
-        .into_any()
+    cx.with_common_p(div()).child(code_block).into_any()
 }
 
 fn render_markdown_paragraph(parsed: &ParsedMarkdownText, cx: &mut RenderContext) -> AnyElement {
@@ -0,0 +1,381 @@
+mod notification_center_settings;
+
+use anyhow::Result;
+use db::kvp::KEY_VALUE_STORE;
+use fs::Fs;
+use gpui::{
+    actions, AppContext, AsyncWindowContext, EventEmitter, FocusHandle, FocusableView, Model,
+    Pixels, Render, Subscription, Task, View, ViewContext, WeakView, WindowContext,
+};
+use notification_center_settings::NotificationCenterSettings;
+use serde::{Deserialize, Serialize};
+use settings::{Settings, SettingsStore};
+use std::sync::Arc;
+use ui::{prelude::*, utils::DateTimeType, Divider, EmptyState, Tooltip};
+use ui::{IconButtonShape, Tab, TimestampLabel};
+use util::{ResultExt, TryFutureExt};
+use workspace::{
+    dock::{DockPosition, Panel, PanelEvent},
+    notification_center::{NotificationCenter, PostedNotification},
+    PanelRegistry, Workspace,
+};
+
+const NOTIFICATION_CENTER_PANEL_KEY: &str = "NotificationCenterPanel";
+
+actions!(notification_center, [ToggleFocus, ClearAll]);
+
+pub fn init(cx: &mut AppContext) {
+    NotificationCenterSettings::register(cx);
+    PanelRegistry::register(cx, NotificationCenterPanel::load);
+
+    cx.observe_new_views(|workspace: &mut Workspace, _| {
+        workspace.register_action(|workspace, _: &ToggleFocus, cx| {
+            workspace.toggle_panel_focus::<NotificationCenterPanel>(cx);
+        });
+    })
+    .detach();
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedNotificationCenterPanel {
+    width: Option<Pixels>,
+}
+
+/// A dock panel listing the notifications posted to the [`NotificationCenter`]
+/// in this session, grouped by the feature that posted them.
+pub struct NotificationCenterPanel {
+    center: Model<NotificationCenter>,
+    fs: Arc<dyn Fs>,
+    focus_handle: FocusHandle,
+    width: Option<Pixels>,
+    active: bool,
+    pending_serialization: Task<Option<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NotificationCenterPanel {
+    fn new(workspace: &mut Workspace, cx: &mut ViewContext<Workspace>) -> Option<View<Self>> {
+        let center = NotificationCenter::global(cx)?;
+        let fs = workspace.app_state().fs.clone();
+        Some(cx.new_view(|cx| {
+            let subscriptions = vec![
+                cx.observe(&center, |this: &mut Self, center, cx| {
+                    // Notifications posted while the panel is open are seen right away.
+                    if this.active {
+                        center.update(cx, |center, cx| center.mark_all_read(cx));
+                    }
+                    cx.notify();
+                }),
+                ui::utils::observe_time_ago_ticks(cx),
+                cx.observe_global::<SettingsStore>(|_, cx| cx.notify()),
+            ];
+            Self {
+                center,
+                fs,
+                focus_handle: cx.focus_handle(),
+                width: None,
+                active: false,
+                pending_serialization: Task::ready(None),
+                _subscriptions: subscriptions,
+            }
+        }))
+    }
+
+    pub fn load(
+        workspace: WeakView<Workspace>,
+        cx: AsyncWindowContext,
+    ) -> Task<Result<View<Self>>> {
+        cx.spawn(|mut cx| async move {
+            let serialized_panel = match cx
+                .background_executor()
+                .spawn(async move { KEY_VALUE_STORE.read_kvp(NOTIFICATION_CENTER_PANEL_KEY) })
+                .await
+                .log_err()
+                .flatten()
+            {
+                Some(panel) => Some(serde_json::from_str::<SerializedNotificationCenterPanel>(
+                    &panel,
+                )?),
+                None => None,
+            };
+
+            workspace.update(&mut cx, |workspace, cx| {
+                let panel = Self::new(workspace, cx)
+                    .ok_or_else(|| anyhow::anyhow!("the notification center is not initialized"))?;
+                if let Some(serialized_panel) = serialized_panel {
+                    panel.update(cx, |panel, cx| {
+                        panel.width = serialized_panel.width.map(|width| width.round());
+                        cx.notify();
+                    });
+                }
+                Ok(panel)
+            })?
+        })
+    }
+
+    fn serialize(&mut self, cx: &mut ViewContext<Self>) {
+        let width = self.width;
+        self.pending_serialization = cx.background_executor().spawn(
+            async move {
+                KEY_VALUE_STORE
+                    .write_kvp(
+                        NOTIFICATION_CENTER_PANEL_KEY.into(),
+                        serde_json::to_string(&SerializedNotificationCenterPanel { width })?,
+                    )
+                    .await?;
+                anyhow::Ok(())
+            }
+            .log_err(),
+        );
+    }
+
+    fn clear_all(&mut self, _: &ClearAll, cx: &mut ViewContext<Self>) {
+        self.center.update(cx, |center, cx| center.clear_all(cx));
+    }
+
+    fn render_header(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_empty = self.center.read(cx).notifications().is_empty();
+        h_flex()
+            .flex_none()
+            .justify_between()
+            .h(rems(Tab::CONTAINER_HEIGHT_IN_REMS))
+            .px_2()
+            .border_b_1()
+            .border_color(cx.theme().colors().border)
+            .child(Label::new("Notifications"))
+            .child(
+                Button::new("clear-all", "Clear All")
+                    .label_size(LabelSize::Small)
+                    .disabled(is_empty)
+                    .on_click(cx.listener(|this, _, cx| this.clear_all(&ClearAll, cx))),
+            )
+    }
+
+    fn render_group(
+        &self,
+        source: SharedString,
+        notifications: Vec<PostedNotification>,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let count = notifications.len();
+        v_flex()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .px_2()
+                    .py_1()
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(
+                                Label::new(source.clone())
+                                    .size(LabelSize::Small)
+                                    .color(Color::Muted),
+                            )
+                            .child(
+                                Label::new(count.to_string())
+                                    .size(LabelSize::XSmall)
+                                    .color(Color::Muted),
+                            ),
+                    )
+                    .child(
+                        IconButton::new(
+                            SharedString::from(format!("clear-{source}")),
+                            IconName::Close,
+                        )
+                        .shape(IconButtonShape::Square)
+                        .icon_size(IconSize::XSmall)
+                        .tooltip(move |cx| Tooltip::text("Clear Group", cx))
+                        .on_click(cx.listener(move |this, _, cx| {
+                            this.center
+                                .update(cx, |center, cx| center.clear_source(&source, cx));
+                        })),
+                    ),
+            )
+            .children(
+                notifications
+                    .into_iter()
+                    .map(|posted| self.render_notification(&posted, cx)),
+            )
+            .child(Divider::horizontal())
+    }
+
+    fn render_notification(
+        &self,
+        posted: &PostedNotification,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let id = posted.id;
+        let notification = &posted.notification;
+        h_flex()
+            .id(("notification", id))
+            .items_start()
+            .gap_2()
+            .px_2()
+            .py_1p5()
+            .child(
+                Icon::new(notification.level.icon())
+                    .size(IconSize::Small)
+                    .color(notification.level.color()),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .justify_between()
+                            .gap_2()
+                            .child(
+                                Label::new(notification.title.clone())
+                                    .size(LabelSize::Small)
+                                    .selectable(("title", id)),
+                            )
+                            .child(TimestampLabel::new(DateTimeType::Local(posted.posted_at))),
+                    )
+                    .when_some(notification.body.clone(), |this, body| {
+                        this.child(
+                            Label::new(body)
+                                .size(LabelSize::Small)
+                                .color(Color::Muted)
+                                .selectable(("body", id)),
+                        )
+                    })
+                    .when(!notification.actions.is_empty(), |this| {
+                        this.child(h_flex().gap_1().children(
+                            notification.actions.iter().enumerate().map(|(ix, action)| {
+                                let handler = action.handler.clone();
+                                Button::new(("action", ix), action.label.clone())
+                                    .style(ButtonStyle::Filled)
+                                    .label_size(LabelSize::Small)
+                                    .on_click(cx.listener(move |this, _, cx| {
+                                        handler(cx);
+                                        this.center.update(cx, |center, cx| center.dismiss(id, cx));
+                                    }))
+                            }),
+                        ))
+                    }),
+            )
+            .child(
+                IconButton::new(("dismiss", id), IconName::Close)
+                    .shape(IconButtonShape::Square)
+                    .icon_size(IconSize::XSmall)
+                    .tooltip(move |cx| Tooltip::text("Dismiss", cx))
+                    .on_click(cx.listener(move |this, _, cx| {
+                        this.center.update(cx, |center, cx| center.dismiss(id, cx));
+                    })),
+            )
+    }
+}
+
+impl Render for NotificationCenterPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let groups = self
+            .center
+            .read(cx)
+            .groups()
+            .into_iter()
+            .map(|(source, notifications)| {
+                (
+                    source,
+                    notifications.into_iter().cloned().collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .key_context("NotificationCenterPanel")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::clear_all))
+            .size_full()
+            .child(self.render_header(cx))
+            .map(|this| {
+                if groups.is_empty() {
+                    this.child(
+                        EmptyState::new("No notifications")
+                            .icon(IconName::BellRing)
+                            .description("Notifications posted while Zed is running show up here."),
+                    )
+                } else {
+                    this.child(
+                        v_flex()
+                            .id("notifications")
+                            .flex_1()
+                            .overflow_y_scroll()
+                            .children(groups.into_iter().map(|(source, notifications)| {
+                                self.render_group(source, notifications, cx)
+                            })),
+                    )
+                }
+            })
+    }
+}
+
+impl FocusableView for NotificationCenterPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for NotificationCenterPanel {}
+
+impl Panel for NotificationCenterPanel {
+    fn persistent_name() -> &'static str {
+        "NotificationCenterPanel"
+    }
+
+    fn position(&self, cx: &WindowContext) -> DockPosition {
+        NotificationCenterSettings::get_global(cx).dock
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(&mut self, position: DockPosition, cx: &mut ViewContext<Self>) {
+        settings::update_settings_file::<NotificationCenterSettings>(
+            self.fs.clone(),
+            cx,
+            move |settings, _| settings.dock = Some(position),
+        );
+    }
+
+    fn size(&self, cx: &WindowContext) -> Pixels {
+        self.width
+            .unwrap_or_else(|| NotificationCenterSettings::get_global(cx).default_width)
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, cx: &mut ViewContext<Self>) {
+        self.width = size;
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, cx: &mut ViewContext<Self>) {
+        self.active = active;
+        if active {
+            self.center
+                .update(cx, |center, cx| center.mark_all_read(cx));
+        }
+    }
+
+    fn icon(&self, cx: &WindowContext) -> Option<IconName> {
+        NotificationCenterSettings::get_global(cx)
+            .button
+            .then_some(IconName::BellRing)
+    }
+
+    fn icon_tooltip(&self, _cx: &WindowContext) -> Option<&'static str> {
+        Some("Notification Center")
+    }
+
+    fn icon_label(&self, cx: &WindowContext) -> Option<String> {
+        let unread = self.center.read(cx).unread_count();
+        (unread > 0).then(|| unread.to_string())
+    }
+
+    fn toggle_action(&self) -> Box<dyn gpui::Action> {
+        Box::new(ToggleFocus)
+    }
+}
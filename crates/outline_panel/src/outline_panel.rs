@@ -50,7 +50,8 @@ use workspace::{
     ui::{
         h_flex, v_flex, ActiveTheme, ButtonCommon, Clickable, Color, ContextMenu, FluentBuilder,
         HighlightedLabel, Icon, IconButton, IconButtonShape, IconName, IconSize, Label,
-        LabelCommon, ListItem, Selectable, Spacing, StyledExt, StyledTypography, Tooltip,
+        LabelCommon, ListItem, OverlayLayer, Selectable, Spacing, StyledExt, StyledTypography,
+        Tooltip,
     },
     OpenInTerminal, WeakItemHandle, Workspace,
 };
@@ -566,6 +567,7 @@ pub fn init_settings(cx: &mut AppContext) {
 pub fn init(assets: impl AssetSource, cx: &mut AppContext) {
     init_settings(cx);
     file_icons::init(assets, cx);
+    workspace::PanelRegistry::register(cx, OutlinePanel::load);
 
     cx.observe_new_views(|workspace: &mut Workspace, _| {
         workspace.register_action(|workspace, _: &ToggleFocus, cx| {
@@ -4070,7 +4072,7 @@ impl Render for OutlinePanel {
                     .anchor(gpui::AnchorCorner::TopLeft)
                     .child(menu.clone()),
             )
-            .with_priority(1)
+            .with_priority(OverlayLayer::Popover.priority())
         }))
         .child(
             v_flex().child(horizontal_separator(cx)).child(
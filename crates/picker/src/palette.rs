@@ -0,0 +1,331 @@
+use std::sync::Arc;
+
+use fuzzy::{match_strings, StringMatch, StringMatchCandidate};
+use gpui::{Action, AnyElement, DismissEvent, Task, ViewContext, WindowContext};
+use ui::{prelude::*, HighlightedLabel, ListItem, ListItemSpacing, PaletteHint};
+use util::ResultExt;
+
+use crate::{recent_items::RecentItems, Picker, PickerDelegate, SecondaryAction};
+
+type RenderItem<T> = Box<dyn Fn(&T, &StringMatch, bool, &mut WindowContext) -> AnyElement>;
+type SecondaryActions<T> = Box<dyn Fn(&T) -> Vec<SecondaryAction>>;
+type ConfirmItem<T> = Box<dyn Fn(&T, bool, &mut WindowContext)>;
+type ConfirmSecondaryAction<T> = Box<dyn Fn(&T, usize, &mut WindowContext)>;
+
+/// A palette of items filtered by fuzzy-matching the query against each item's text,
+/// like the command palette or the theme selector.
+///
+/// Items are rendered as a label highlighting the matched characters unless a renderer
+/// is provided with [`Palette::render_item`]. When [`Palette::recent_items`] is set, the
+/// most recently used items come first for an empty query, and break ties between
+/// equally good matches otherwise.
+///
+/// Show it with `Picker::uniform_list(palette, cx)`; it dismisses itself once an item
+/// is confirmed.
+pub struct Palette<T: 'static> {
+    items: Vec<T>,
+    matches: Vec<StringMatch>,
+    selected_index: usize,
+    placeholder_text: Arc<str>,
+    item_text: Box<dyn Fn(&T) -> SharedString>,
+    render_item: Option<RenderItem<T>>,
+    secondary_actions: Option<SecondaryActions<T>>,
+    on_confirm: Option<ConfirmItem<T>>,
+    on_secondary_action: Option<ConfirmSecondaryAction<T>>,
+    recent_items: Option<RecentItems<SharedString>>,
+    footer_hints: Vec<(SharedString, Box<dyn Action>)>,
+}
+
+impl<T: 'static> Palette<T> {
+    pub fn new(items: Vec<T>, item_text: impl Fn(&T) -> SharedString + 'static) -> Self {
+        Self {
+            items,
+            matches: Vec::new(),
+            selected_index: 0,
+            placeholder_text: "Search…".into(),
+            item_text: Box::new(item_text),
+            render_item: None,
+            secondary_actions: None,
+            on_confirm: None,
+            on_secondary_action: None,
+            recent_items: None,
+            footer_hints: Vec::new(),
+        }
+    }
+
+    pub fn placeholder_text(mut self, placeholder_text: impl Into<Arc<str>>) -> Self {
+        self.placeholder_text = placeholder_text.into();
+        self
+    }
+
+    /// Renders each item, given the match of its text and whether it's selected.
+    pub fn render_item(
+        mut self,
+        render_item: impl Fn(&T, &StringMatch, bool, &mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        self.render_item = Some(Box::new(render_item));
+        self
+    }
+
+    /// The actions that can be performed on each item besides confirming it.
+    pub fn secondary_actions(
+        mut self,
+        secondary_actions: impl Fn(&T) -> Vec<SecondaryAction> + 'static,
+    ) -> Self {
+        self.secondary_actions = Some(Box::new(secondary_actions));
+        self
+    }
+
+    /// Called with the confirmed item and whether it was confirmed with the secondary
+    /// modifier held.
+    pub fn on_confirm(
+        mut self,
+        on_confirm: impl Fn(&T, bool, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_confirm = Some(Box::new(on_confirm));
+        self
+    }
+
+    /// Called with the item and the index of the secondary action performed on it, as
+    /// returned by [`Palette::secondary_actions`].
+    pub fn on_secondary_action(
+        mut self,
+        on_secondary_action: impl Fn(&T, usize, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_secondary_action = Some(Box::new(on_secondary_action));
+        self
+    }
+
+    /// Ranks items by how often and how recently they were used, keyed by their text.
+    ///
+    /// The palette doesn't record uses itself, so that hosts can keep them across
+    /// palettes, e.g. in a global updated from [`Palette::on_confirm`].
+    pub fn recent_items(mut self, recent_items: RecentItems<SharedString>) -> Self {
+        self.recent_items = Some(recent_items);
+        self
+    }
+
+    /// Adds a hint to the footer, shown while there are matches.
+    pub fn footer_hint(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        self.footer_hints.push((label.into(), action));
+        self
+    }
+
+    /// The item of the match at the given index.
+    pub fn item_for_match(&self, ix: usize) -> Option<&T> {
+        self.items.get(self.matches.get(ix)?.candidate_id)
+    }
+
+    fn recency_score(&self, candidate_id: usize) -> f64 {
+        match (&self.recent_items, self.items.get(candidate_id)) {
+            (Some(recent_items), Some(item)) => recent_items.score(&(self.item_text)(item)),
+            _ => 0.,
+        }
+    }
+
+    fn set_matches(&mut self, mut matches: Vec<StringMatch>) {
+        if self.recent_items.is_some() {
+            matches.sort_by(|a, b| {
+                b.score.total_cmp(&a.score).then_with(|| {
+                    self.recency_score(b.candidate_id)
+                        .total_cmp(&self.recency_score(a.candidate_id))
+                })
+            });
+        }
+        self.matches = matches;
+        self.selected_index = self
+            .selected_index
+            .min(self.matches.len().saturating_sub(1));
+    }
+}
+
+impl<T: 'static> PickerDelegate for Palette<T> {
+    type ListItem = AnyElement;
+
+    fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    fn set_selected_index(&mut self, ix: usize, _: &mut ViewContext<Picker<Self>>) {
+        self.selected_index = ix;
+    }
+
+    fn placeholder_text(&self, _: &mut WindowContext) -> Arc<str> {
+        self.placeholder_text.clone()
+    }
+
+    fn update_matches(&mut self, query: String, cx: &mut ViewContext<Picker<Self>>) -> Task<()> {
+        let background = cx.background_executor().clone();
+        let candidates = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(id, item)| {
+                let text = (self.item_text)(item);
+                StringMatchCandidate {
+                    id,
+                    char_bag: text.as_ref().into(),
+                    string: text.to_string(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        cx.spawn(|this, mut cx| async move {
+            let matches = if query.is_empty() {
+                candidates
+                    .into_iter()
+                    .map(|candidate| StringMatch {
+                        candidate_id: candidate.id,
+                        string: candidate.string,
+                        positions: Vec::new(),
+                        score: 0.0,
+                    })
+                    .collect()
+            } else {
+                match_strings(
+                    &candidates,
+                    &query,
+                    false,
+                    10000,
+                    &Default::default(),
+                    background,
+                )
+                .await
+            };
+
+            this.update(&mut cx, |this, _| this.delegate.set_matches(matches))
+                .log_err();
+        })
+    }
+
+    fn confirm(&mut self, secondary: bool, cx: &mut ViewContext<Picker<Self>>) {
+        let Some(item) = self.item_for_match(self.selected_index) else {
+            return;
+        };
+        if let Some(on_confirm) = &self.on_confirm {
+            on_confirm(item, secondary, cx);
+        }
+        cx.emit(DismissEvent);
+    }
+
+    fn secondary_actions(
+        &self,
+        ix: usize,
+        _: &mut ViewContext<Picker<Self>>,
+    ) -> Vec<SecondaryAction> {
+        match (&self.secondary_actions, self.item_for_match(ix)) {
+            (Some(secondary_actions), Some(item)) => secondary_actions(item),
+            _ => Vec::new(),
+        }
+    }
+
+    fn confirm_secondary_action(
+        &mut self,
+        ix: usize,
+        action_ix: usize,
+        cx: &mut ViewContext<Picker<Self>>,
+    ) {
+        if let (Some(on_secondary_action), Some(item)) =
+            (&self.on_secondary_action, self.item_for_match(ix))
+        {
+            on_secondary_action(item, action_ix, cx);
+        }
+    }
+
+    fn dismissed(&mut self, _: &mut ViewContext<Picker<Self>>) {}
+
+    fn footer_hints(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PaletteHint> {
+        if self.matches.is_empty() {
+            return Vec::new();
+        }
+        self.footer_hints
+            .iter()
+            .map(|(label, action)| PaletteHint::new(label.clone(), action.boxed_clone()))
+            .collect()
+    }
+
+    fn render_match(
+        &self,
+        ix: usize,
+        selected: bool,
+        cx: &mut ViewContext<Picker<Self>>,
+    ) -> Option<Self::ListItem> {
+        let string_match = self.matches.get(ix)?;
+        let item = self.items.get(string_match.candidate_id)?;
+        if let Some(render_item) = &self.render_item {
+            return Some(render_item(item, string_match, selected, cx));
+        }
+        Some(
+            ListItem::new(ix)
+                .inset(true)
+                .spacing(ListItemSpacing::Sparse)
+                .selected(selected)
+                .child(HighlightedLabel::new(
+                    (self.item_text)(item),
+                    string_match.positions.clone(),
+                ))
+                .into_any_element(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::TestAppContext;
+    use workspace::AppState;
+
+    use super::*;
+
+    #[gpui::test]
+    async fn test_palette_filters_and_ranks_recent_items(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let app_state = AppState::test(cx);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            editor::init(cx);
+            workspace::init(app_state, cx);
+        });
+
+        let mut recent_items = RecentItems::new();
+        recent_items.record_use(SharedString::from("Dark Blue"));
+        let confirmed = Rc::new(RefCell::new(Vec::new()));
+        let palette = Palette::new(
+            vec!["Dark Pink", "Dark Blue", "Light"],
+            |item: &&'static str| SharedString::from(*item),
+        )
+        .recent_items(recent_items)
+        .on_confirm({
+            let confirmed = confirmed.clone();
+            move |item, _, _| confirmed.borrow_mut().push(*item)
+        });
+        let (picker, cx) = cx.add_window_view(|cx| Picker::uniform_list(palette, cx));
+        cx.run_until_parked();
+
+        assert_eq!(
+            picker.update(cx, |picker, _| match_texts(picker)),
+            vec!["Dark Blue", "Dark Pink", "Light"]
+        );
+
+        picker.update(cx, |picker, cx| picker.set_query("dark", cx));
+        cx.run_until_parked();
+        assert_eq!(
+            picker.update(cx, |picker, _| match_texts(picker)),
+            vec!["Dark Blue", "Dark Pink"]
+        );
+
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(*confirmed.borrow(), vec!["Dark Blue"]);
+    }
+
+    fn match_texts(picker: &Picker<Palette<&'static str>>) -> Vec<&'static str> {
+        (0..picker.delegate.match_count())
+            .filter_map(|ix| picker.delegate.item_for_match(ix).copied())
+            .collect()
+    }
+}
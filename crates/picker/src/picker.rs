@@ -1,19 +1,26 @@
 use anyhow::Result;
 use editor::{scroll::Autoscroll, Editor};
 use gpui::{
-    actions, div, impl_actions, list, prelude::*, uniform_list, AnyElement, AppContext, ClickEvent,
-    DismissEvent, EventEmitter, FocusHandle, FocusableView, Length, ListSizingBehavior, ListState,
-    MouseButton, MouseUpEvent, Render, Task, UniformListScrollHandle, View, ViewContext,
+    actions, div, impl_actions, list, prelude::*, uniform_list, Action, AnyElement, AppContext,
+    ClickEvent, DismissEvent, EventEmitter, FocusHandle, FocusableView, Length, ListSizingBehavior,
+    ListState, MouseButton, MouseUpEvent, Render, Task, UniformListScrollHandle, View, ViewContext,
     WindowContext,
 };
 use head::Head;
 use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
-use ui::{prelude::*, v_flex, Color, Divider, Label, ListItem, ListItemSpacing};
+use ui::{
+    prelude::*, v_flex, Color, Divider, IconButtonShape, Label, ListItem, ListItemSpacing,
+    ListSubHeader, PaletteFooter, PaletteHint, Tooltip,
+};
 use workspace::ModalView;
 
 mod head;
 pub mod highlighted_match_with_paths;
+mod palette;
+pub mod recent_items;
+
+pub use palette::Palette;
 
 enum ElementContainer {
     List(ListState),
@@ -31,6 +38,49 @@ pub struct ConfirmInput {
 
 impl_actions!(picker, [ConfirmInput]);
 
+/// An action that can be performed on a match besides confirming it, like opening
+/// it to the side or revealing it in the file manager.
+///
+/// The action is performed when its `action` is dispatched while the match is
+/// selected, e.g. by a modifier-enter key binding, or when its button is clicked
+/// while the match is hovered. It is listed with its key binding in the footer.
+pub struct SecondaryAction {
+    pub label: SharedString,
+    pub icon: IconName,
+    pub action: Box<dyn Action>,
+}
+
+impl SecondaryAction {
+    pub fn new(label: impl Into<SharedString>, icon: IconName, action: Box<dyn Action>) -> Self {
+        Self {
+            label: label.into(),
+            icon,
+            action,
+        }
+    }
+}
+
+/// A section of the matches shown while the query is empty, e.g. "Recent Files".
+///
+/// The header of the first section is shown above the matches. Those of the following
+/// sections are shown above their first match by pickers built with `Picker::list`,
+/// while pickers built with `Picker::uniform_list` separate them with a border instead,
+/// as all of their matches must have the same height.
+pub struct PickerSection {
+    pub header: SharedString,
+    /// The number of consecutive matches in the section.
+    pub match_count: usize,
+}
+
+impl PickerSection {
+    pub fn new(header: impl Into<SharedString>, match_count: usize) -> Self {
+        Self {
+            header: header.into(),
+            match_count,
+        }
+    }
+}
+
 struct PendingUpdateMatches {
     delegate_update_matches: Option<Task<()>>,
     _task: Task<Result<()>>,
@@ -101,6 +151,24 @@ pub trait PickerDelegate: Sized + 'static {
         None
     }
     fn confirm(&mut self, secondary: bool, cx: &mut ViewContext<Picker<Self>>);
+    /// The actions that can be performed on the match at the given index besides
+    /// confirming it.
+    fn secondary_actions(
+        &self,
+        _ix: usize,
+        _cx: &mut ViewContext<Picker<Self>>,
+    ) -> Vec<SecondaryAction> {
+        Vec::new()
+    }
+    /// Performs the secondary action at `action_ix` of those returned by
+    /// `secondary_actions` on the match at `ix`.
+    fn confirm_secondary_action(
+        &mut self,
+        _ix: usize,
+        _action_ix: usize,
+        _cx: &mut ViewContext<Picker<Self>>,
+    ) {
+    }
     /// Instead of interacting with currently selected entry, treats editor input literally,
     /// performing some kind of action on it.
     fn confirm_input(&mut self, _secondary: bool, _: &mut ViewContext<Picker<Self>>) {}
@@ -145,8 +213,33 @@ pub trait PickerDelegate: Sized + 'static {
     fn render_header(&self, _: &mut ViewContext<Picker<Self>>) -> Option<AnyElement> {
         None
     }
-    fn render_footer(&self, _: &mut ViewContext<Picker<Self>>) -> Option<AnyElement> {
-        None
+    /// The sources of the matches shown while the query is empty, like recently opened
+    /// files followed by suggested ones, each listed in its own section.
+    ///
+    /// Delegates returning sections are expected to report the matches of each section
+    /// in turn from `update_matches` when the query is empty, in the section's own order,
+    /// rather than every item. Matches after the last section are listed without one.
+    fn empty_query_sections(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PickerSection> {
+        Vec::new()
+    }
+    /// Hints about the actions available on the selected match, like confirming it or
+    /// opening it in a split, shown in the default footer.
+    fn footer_hints(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PaletteHint> {
+        Vec::new()
+    }
+    fn render_footer(&self, cx: &mut ViewContext<Picker<Self>>) -> Option<AnyElement> {
+        let mut hints = self.footer_hints(cx);
+        if self.match_count() > 0 {
+            hints.extend(
+                self.secondary_actions(self.selected_index(), cx)
+                    .into_iter()
+                    .map(|action| PaletteHint::new(action.label, action.action)),
+            );
+        }
+        if hints.is_empty() {
+            return None;
+        }
+        Some(PaletteFooter::new().hints(hints).into_any_element())
     }
 }
 
@@ -365,6 +458,19 @@ impl<D: PickerDelegate> Picker<D> {
         }
     }
 
+    fn confirm_secondary_action(
+        &mut self,
+        ix: usize,
+        action_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if ix >= self.delegate.match_count() {
+            return;
+        }
+        self.set_selected_index(ix, false, cx);
+        self.delegate.confirm_secondary_action(ix, action_ix, cx);
+    }
+
     fn confirm_input(&mut self, input: &ConfirmInput, cx: &mut ViewContext<Self>) {
         self.delegate.confirm_input(input.secondary, cx);
     }
@@ -495,9 +601,79 @@ impl<D: PickerDelegate> Picker<D> {
         }
     }
 
+    /// Renders the buttons of the match's secondary actions, shown at the end of the
+    /// match while it's hovered.
+    fn render_secondary_action_buttons(
+        &self,
+        ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<impl IntoElement> {
+        let actions = self.delegate.secondary_actions(ix, cx);
+        if actions.is_empty() {
+            return None;
+        }
+        let group_name = SharedString::from(format!("picker-item-{ix}"));
+        Some(
+            h_flex()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .right_2()
+                .gap_1()
+                .visible_on_hover(group_name)
+                .children(actions.into_iter().enumerate().map(|(action_ix, action)| {
+                    let label = action.label;
+                    let tooltip_action = action.action;
+                    IconButton::new(("secondary-action", action_ix), action.icon)
+                        .shape(IconButtonShape::Square)
+                        .icon_size(IconSize::Small)
+                        .tooltip(move |cx| Tooltip::for_action(label.clone(), &*tooltip_action, cx))
+                        .on_click(cx.listener(move |this, _, cx| {
+                            cx.stop_propagation();
+                            this.confirm_secondary_action(ix, action_ix, cx);
+                        }))
+                })),
+        )
+    }
+
+    fn empty_query_sections(&self, cx: &mut ViewContext<Self>) -> Vec<PickerSection> {
+        if self.query(cx).is_empty() {
+            self.delegate.empty_query_sections(cx)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the header of the section following the first one that starts at the given
+    /// match, and whether another section starts right after it.
+    fn section_boundaries_at(
+        &self,
+        ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> (Option<SharedString>, bool) {
+        let mut header = None;
+        let mut section_starts_after = false;
+        let mut start = 0;
+        for (section_ix, section) in self.empty_query_sections(cx).into_iter().enumerate() {
+            if section_ix > 0 {
+                if start == ix {
+                    header = Some(section.header);
+                } else if start == ix + 1 {
+                    section_starts_after = true;
+                }
+            }
+            start += section.match_count;
+        }
+        (header, section_starts_after)
+    }
+
     fn render_element(&self, cx: &mut ViewContext<Self>, ix: usize) -> impl IntoElement {
-        div()
+        let (section_header, section_starts_after) = self.section_boundaries_at(ix, cx);
+        let is_list = matches!(self.element_container, ElementContainer::List(_));
+        let item = div()
             .id(("item", ix))
+            .group(format!("picker-item-{ix}"))
+            .relative()
             .cursor_pointer()
             .on_click(cx.listener(move |this, event: &ClickEvent, cx| {
                 this.handle_click(ix, event.down.modifiers.secondary(), cx)
@@ -518,15 +694,24 @@ impl<D: PickerDelegate> Picker<D> {
                 self.delegate
                     .render_match(ix, ix == self.delegate.selected_index(), cx),
             )
+            .children(self.render_secondary_action_buttons(ix, cx))
             .when(
-                self.delegate.separators_after_indices().contains(&ix),
+                self.delegate.separators_after_indices().contains(&ix)
+                    || (section_starts_after && !is_list),
                 |picker| {
                     picker
                         .border_color(cx.theme().colors().border_variant)
                         .border_b_1()
                         .py(px(-1.0))
                 },
+            );
+        v_flex()
+            .children(
+                section_header
+                    .filter(|_| is_list)
+                    .map(|header| ListSubHeader::new(header).inset(true)),
             )
+            .child(item)
     }
 
     fn render_element_container(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
@@ -596,6 +781,24 @@ impl<D: PickerDelegate> Render for Picker<D> {
             .on_action(cx.listener(Self::secondary_confirm))
             .on_action(cx.listener(Self::confirm_completion))
             .on_action(cx.listener(Self::confirm_input))
+            .map(|this| {
+                if self.delegate.match_count() == 0 {
+                    return this;
+                }
+                let selected_ix = self.delegate.selected_index();
+                self.delegate
+                    .secondary_actions(selected_ix, cx)
+                    .into_iter()
+                    .enumerate()
+                    .fold(this, |this, (action_ix, action)| {
+                        this.on_boxed_action(
+                            &*action.action,
+                            cx.listener(move |this, _, cx| {
+                                this.confirm_secondary_action(selected_ix, action_ix, cx)
+                            }),
+                        )
+                    })
+            })
             .children(match &self.head {
                 Head::Editor(editor) => {
                     if editor_position == PickerEditorPosition::Start {
@@ -613,6 +816,12 @@ impl<D: PickerDelegate> Render for Picker<D> {
                         .when_some(self.max_height, |div, max_h| div.max_h(max_h))
                         .overflow_hidden()
                         .children(self.delegate.render_header(cx))
+                        .children(
+                            self.empty_query_sections(cx)
+                                .into_iter()
+                                .next()
+                                .map(|section| ListSubHeader::new(section.header).inset(true)),
+                        )
                         .child(self.render_element_container(cx)),
                 )
             })
@@ -642,3 +851,136 @@ impl<D: PickerDelegate> Render for Picker<D> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::TestAppContext;
+    use project::Project;
+    use workspace::{AppState, Workspace};
+
+    use super::*;
+
+    struct TestDelegate {
+        selected_index: usize,
+        dismissed: Rc<Cell<bool>>,
+        sections: Vec<(&'static str, usize)>,
+    }
+
+    impl PickerDelegate for TestDelegate {
+        type ListItem = ListItem;
+
+        fn match_count(&self) -> usize {
+            2
+        }
+
+        fn selected_index(&self) -> usize {
+            self.selected_index
+        }
+
+        fn set_selected_index(&mut self, ix: usize, _: &mut ViewContext<Picker<Self>>) {
+            self.selected_index = ix;
+        }
+
+        fn placeholder_text(&self, _: &mut WindowContext) -> Arc<str> {
+            "Select an item...".into()
+        }
+
+        fn update_matches(&mut self, _: String, _: &mut ViewContext<Picker<Self>>) -> Task<()> {
+            Task::ready(())
+        }
+
+        fn confirm(&mut self, _: bool, _: &mut ViewContext<Picker<Self>>) {}
+
+        fn dismissed(&mut self, _: &mut ViewContext<Picker<Self>>) {
+            self.dismissed.set(true);
+        }
+
+        fn empty_query_sections(&self, _: &mut ViewContext<Picker<Self>>) -> Vec<PickerSection> {
+            self.sections
+                .iter()
+                .map(|(header, match_count)| PickerSection::new(*header, *match_count))
+                .collect()
+        }
+
+        fn render_match(
+            &self,
+            ix: usize,
+            selected: bool,
+            _: &mut ViewContext<Picker<Self>>,
+        ) -> Option<Self::ListItem> {
+            Some(
+                ListItem::new(ix)
+                    .selected(selected)
+                    .child(Label::new(format!("Item {ix}"))),
+            )
+        }
+    }
+
+    #[gpui::test]
+    async fn test_escape_calls_dismissed(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        let project = Project::test(app_state.fs.clone(), [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        // Delegates like the theme selector's revert their preview when dismissed, so
+        // Escape must reach the picker rather than only closing its modal.
+        let dismissed = Rc::new(Cell::new(false));
+        workspace.update(cx, |workspace, cx| {
+            let delegate = TestDelegate {
+                selected_index: 0,
+                dismissed: dismissed.clone(),
+                sections: Vec::new(),
+            };
+            workspace.toggle_modal(cx, |cx| Picker::uniform_list(delegate, cx));
+        });
+        cx.run_until_parked();
+
+        cx.dispatch_action(menu::Cancel);
+        cx.run_until_parked();
+        assert!(dismissed.get());
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.active_modal::<Picker<TestDelegate>>(cx).is_none());
+        });
+    }
+
+    #[gpui::test]
+    async fn test_empty_query_sections(cx: &mut TestAppContext) {
+        init_test(cx);
+        let delegate = TestDelegate {
+            selected_index: 0,
+            dismissed: Rc::default(),
+            sections: vec![("Recent", 1), ("Suggested", 1)],
+        };
+        let (picker, cx) = cx.add_window_view(|cx| Picker::list(delegate, cx));
+        cx.run_until_parked();
+
+        picker.update(cx, |picker, cx| {
+            assert_eq!(picker.section_boundaries_at(0, cx), (None, true));
+            assert_eq!(
+                picker.section_boundaries_at(1, cx),
+                (Some("Suggested".into()), false)
+            );
+
+            picker.set_query("item", cx);
+        });
+        cx.run_until_parked();
+        picker.update(cx, |picker, cx| {
+            assert!(picker.empty_query_sections(cx).is_empty());
+            assert_eq!(picker.section_boundaries_at(1, cx), (None, false));
+        });
+    }
+
+    fn init_test(cx: &mut TestAppContext) -> Arc<AppState> {
+        cx.update(|cx| {
+            let app_state = AppState::test(cx);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            editor::init(cx);
+            workspace::init(app_state.clone(), cx);
+            Project::init_settings(cx);
+            app_state
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+/// Tracks which items of a picker were recently confirmed, to rank them first.
+///
+/// Items are ranked by "frecency": how often they were used, discounted by how many
+/// other items were used since.
+#[derive(Debug, Clone)]
+pub struct RecentItems<K> {
+    uses: HashMap<K, RecentUse>,
+    generation: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RecentUse {
+    count: usize,
+    last_used_at: usize,
+}
+
+impl<K> Default for RecentItems<K> {
+    fn default() -> Self {
+        Self {
+            uses: HashMap::default(),
+            generation: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq> RecentItems<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the given item was used.
+    pub fn record_use(&mut self, key: K) {
+        self.generation += 1;
+        let recent_use = self.uses.entry(key).or_insert(RecentUse {
+            count: 0,
+            last_used_at: 0,
+        });
+        recent_use.count += 1;
+        recent_use.last_used_at = self.generation;
+    }
+
+    /// Returns the ranking score of an item, or `0.` if it was never used.
+    pub fn score(&self, key: &K) -> f64 {
+        let Some(recent_use) = self.uses.get(key) else {
+            return 0.;
+        };
+        let uses_since = self.generation - recent_use.last_used_at;
+        recent_use.count as f64 / (1. + uses_since as f64 / 10.)
+    }
+
+    /// Returns up to `limit` of the used items, the highest ranked first, e.g. to
+    /// populate the recent section of an empty query.
+    pub fn most_recent(&self, limit: usize) -> Vec<&K> {
+        let mut keys = self.uses.keys().collect::<Vec<_>>();
+        keys.sort_by(|a, b| {
+            self.score(b)
+                .partial_cmp(&self.score(a))
+                .unwrap_or(Ordering::Equal)
+        });
+        keys.truncate(limit);
+        keys
+    }
+
+    /// Stably sorts items so that the most recently used ones come first, e.g. to
+    /// order the results of an empty query.
+    pub fn sort_by_recency<T>(&self, items: &mut [T], key: impl Fn(&T) -> &K) {
+        items.sort_by(|a, b| {
+            self.score(key(b))
+                .partial_cmp(&self.score(key(a)))
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_items_ranking() {
+        let mut recent_items = RecentItems::new();
+        recent_items.record_use("a");
+        recent_items.record_use("a");
+        recent_items.record_use("b");
+
+        assert!(recent_items.score(&"a") > recent_items.score(&"b"));
+        assert_eq!(recent_items.score(&"c"), 0.);
+
+        let mut items = vec!["c", "b", "a"];
+        recent_items.sort_by_recency(&mut items, |item| item);
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert_eq!(recent_items.most_recent(1), vec![&"a"]);
+
+        // Items used long ago are eventually outranked by recently used ones.
+        for _ in 0..30 {
+            recent_items.record_use("b");
+        }
+        recent_items.sort_by_recency(&mut items, |item| item);
+        assert_eq!(items, vec!["b", "a", "c"]);
+        assert_eq!(recent_items.most_recent(5), vec![&"b", &"a"]);
+    }
+}
@@ -16,9 +16,9 @@ use anyhow::{anyhow, Context as _, Result};
 use collections::{hash_map, BTreeSet, HashMap};
 use git::repository::GitFileStatus;
 use gpui::{
-    actions, anchored, deferred, div, impl_actions, point, px, size, uniform_list, Action,
-    AnyElement, AppContext, AssetSource, AsyncWindowContext, Bounds, ClipboardItem, DismissEvent,
-    Div, DragMoveEvent, EventEmitter, ExternalPaths, FocusHandle, FocusableView,
+    actions, anchored, cached, deferred, div, impl_actions, point, px, size, uniform_list, Action,
+    AnyElement, AppContext, AssetSource, AsyncWindowContext, Bounds, Cached, ClipboardItem,
+    DismissEvent, Div, DragMoveEvent, EventEmitter, ExternalPaths, FocusHandle, FocusableView,
     InteractiveElement, KeyContext, ListHorizontalSizingBehavior, ListSizingBehavior, Model,
     MouseButton, MouseDownEvent, ParentElement, Pixels, Point, PromptLevel, Render, Stateful,
     Styled, Subscription, Task, UniformListScrollHandle, View, ViewContext, VisualContext as _,
@@ -45,13 +45,13 @@ use std::{
 use theme::ThemeSettings;
 use ui::{
     prelude::*, v_flex, ContextMenu, Icon, IndentGuideColors, IndentGuideLayout, KeyBinding, Label,
-    ListItem, Tooltip,
+    ListItem, OverlayLayer, ScrollAnchor, Tooltip,
 };
 use util::{maybe, ResultExt, TryFutureExt};
 use workspace::{
     dock::{DockPosition, Panel, PanelEvent},
     notifications::{DetachAndPromptErr, NotifyTaskExt},
-    DraggedSelection, OpenInTerminal, SelectedEntry, Workspace,
+    DraggedSelection, OpenInTerminal, PanelRegistry, SelectedEntry, Workspace,
 };
 use worktree::CreatedEntry;
 
@@ -88,6 +88,8 @@ pub struct ProjectPanel {
     horizontal_scrollbar_state: ScrollbarState,
     hide_scrollbar_task: Option<Task<()>>,
     max_width_item_index: Option<usize>,
+    /// The scroll position restored from the last session, until its entry is shown.
+    pending_scroll_anchor: Option<ScrollAnchor>,
 }
 
 #[derive(Clone, Debug)]
@@ -112,7 +114,7 @@ enum ClipboardEntry {
     Cut(BTreeSet<SelectedEntry>),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 struct EntryDetails {
     filename: String,
     icon: Option<SharedString>,
@@ -192,6 +194,7 @@ pub fn init_settings(cx: &mut AppContext) {
 pub fn init(assets: impl AssetSource, cx: &mut AppContext) {
     init_settings(cx);
     file_icons::init(assets, cx);
+    PanelRegistry::register(cx, ProjectPanel::load);
 
     cx.observe_new_views(|workspace: &mut Workspace, _| {
         workspace.register_action(|workspace, _: &ToggleFocus, cx| {
@@ -218,6 +221,8 @@ pub enum Event {
 #[derive(Serialize, Deserialize)]
 struct SerializedProjectPanel {
     width: Option<Pixels>,
+    #[serde(default)]
+    scroll_anchor: Option<ScrollAnchor>,
 }
 
 struct DraggedProjectEntryView {
@@ -235,6 +240,7 @@ impl ProjectPanel {
             cx.on_focus(&focus_handle, Self::focus_in).detach();
             cx.on_focus_out(&focus_handle, |this, _, cx| {
                 this.hide_scrollbar(cx);
+                this.serialize(cx);
             })
             .detach();
             cx.subscribe(&project, |this, project, event, cx| match event {
@@ -332,6 +338,7 @@ impl ProjectPanel {
                 horizontal_scrollbar_state: ScrollbarState::new(scroll_handle.clone())
                     .parent_view(cx.view()),
                 max_width_item_index: None,
+                pending_scroll_anchor: None,
                 scroll_handle,
             };
             this.update_visible_entries(None, cx);
@@ -452,6 +459,8 @@ impl ProjectPanel {
             if let Some(serialized_panel) = serialized_panel {
                 panel.update(cx, |panel, cx| {
                     panel.width = serialized_panel.width.map(|px| px.round());
+                    panel.pending_scroll_anchor = serialized_panel.scroll_anchor;
+                    panel.restore_scroll_anchor(cx);
                     cx.notify();
                 });
             }
@@ -461,12 +470,18 @@ impl ProjectPanel {
 
     fn serialize(&mut self, cx: &mut ViewContext<Self>) {
         let width = self.width;
+        let scroll_anchor = self.pending_scroll_anchor.clone().or_else(|| {
+            ScrollAnchor::capture(&self.scroll_handle, |ix| self.scroll_key_for_index(ix, cx))
+        });
         self.pending_serialization = cx.background_executor().spawn(
             async move {
                 KEY_VALUE_STORE
                     .write_kvp(
                         PROJECT_PANEL_KEY.into(),
-                        serde_json::to_string(&SerializedProjectPanel { width })?,
+                        serde_json::to_string(&SerializedProjectPanel {
+                            width,
+                            scroll_anchor,
+                        })?,
                     )
                     .await?;
                 anyhow::Ok(())
@@ -1340,6 +1355,7 @@ impl ProjectPanel {
 
     fn autoscroll(&mut self, cx: &mut ViewContext<Self>) {
         if let Some((_, _, index)) = self.selection.and_then(|s| self.index_for_selection(s)) {
+            self.pending_scroll_anchor = None;
             self.scroll_handle.scroll_to_item(index);
             cx.notify();
         }
@@ -2023,6 +2039,7 @@ impl ProjectPanel {
                 self.max_width_item_index = Some(visited_worktrees_length + index);
             }
         }
+        self.restore_scroll_anchor(cx);
         if let Some((worktree_id, entry_id)) = new_selected_entry {
             self.selection = Some(SelectedEntry {
                 worktree_id,
@@ -2217,6 +2234,80 @@ impl ProjectPanel {
         None
     }
 
+    /// Returns the key under which the scroll position is stored when the entry at
+    /// `index` is the topmost visible one: its absolute path, which stays the same
+    /// across sessions, unlike entry and worktree ids.
+    fn scroll_key_for_index(&self, index: usize, cx: &AppContext) -> Option<String> {
+        let (worktree_id, entry) = self.entry_at_index(index)?;
+        let worktree = self.project.read(cx).worktree_for_id(worktree_id, cx)?;
+        let path = worktree.read(cx).abs_path().join(&entry.path);
+        Some(path.to_string_lossy().into_owned())
+    }
+
+    fn index_for_scroll_key(&self, key: &str, cx: &AppContext) -> Option<usize> {
+        let mut offset = 0;
+        for (worktree_id, visible_worktree_entries, _) in &self.visible_entries {
+            let worktree = self.project.read(cx).worktree_for_id(*worktree_id, cx);
+            let path = worktree.and_then(|worktree| {
+                Path::new(key)
+                    .strip_prefix(worktree.read(cx).abs_path())
+                    .ok()
+            });
+            if let Some(path) = path {
+                if let Some(ix) = visible_worktree_entries
+                    .iter()
+                    .position(|entry| entry.path.as_ref() == path)
+                {
+                    return Some(offset + ix);
+                }
+            }
+            offset += visible_worktree_entries.len();
+        }
+        None
+    }
+
+    /// Scrolls back to the position of the last session once its entry is shown,
+    /// e.g. after the worktree was scanned.
+    fn restore_scroll_anchor(&mut self, cx: &AppContext) {
+        let Some(anchor) = self.pending_scroll_anchor.as_ref() else {
+            return;
+        };
+        if anchor.restore(&self.scroll_handle, |key| {
+            self.index_for_scroll_key(key, cx)
+        }) {
+            self.pending_scroll_anchor = None;
+        }
+    }
+
+    /// Returns the indices of the visible directories that contain the entry at
+    /// `index`, from its worktree root down to its parent.
+    fn ancestor_indices(&self, index: usize) -> SmallVec<[usize; 8]> {
+        let mut ancestors = SmallVec::new();
+        let mut offset = 0;
+        for (_, visible_worktree_entries, _) in &self.visible_entries {
+            if offset + visible_worktree_entries.len() <= index {
+                offset += visible_worktree_entries.len();
+                continue;
+            }
+
+            let entry_ix = index - offset;
+            let mut path = visible_worktree_entries[entry_ix].path.as_ref();
+            for (ix, entry) in visible_worktree_entries[..entry_ix]
+                .iter()
+                .enumerate()
+                .rev()
+            {
+                if entry.path.as_ref() != path && path.starts_with(&entry.path) {
+                    ancestors.push(offset + ix);
+                    path = entry.path.as_ref();
+                }
+            }
+            break;
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
     fn iter_visible_entries(
         &self,
         range: Range<usize>,
@@ -2451,6 +2542,36 @@ impl ProjectPanel {
         (depth, difference)
     }
 
+    /// Renders the entry, reusing its layout and paint from the previous frame while none
+    /// of the inputs of `render_entry` changed, as most entries don't between frames.
+    fn render_cached_entry(
+        &self,
+        entry_id: ProjectEntryId,
+        details: EntryDetails,
+        cx: &mut ViewContext<Self>,
+    ) -> Cached {
+        let settings = ProjectPanelSettings::get_global(cx);
+        let key = (
+            details.clone(),
+            &self.marked_entries,
+            self.selection.map(|selection| selection.entry_id),
+            self.focus_handle.contains_focused(cx),
+            self.ancestors
+                .get(&entry_id)
+                .map(|ancestors| ancestors.current_ancestor_depth),
+            self.size(cx).0.to_bits(),
+            settings.file_icons,
+            settings.indent_size.to_bits(),
+            self.project.read(cx).is_local(),
+        );
+        let view = cx.view().clone();
+        cached(("cached-entry", entry_id.to_usize()), key, move |cx| {
+            view.update(cx, |this, cx| this.render_entry(entry_id, details, cx))
+        })
+        .w_full()
+        .h_6()
+    }
+
     fn render_entry(
         &self,
         entry_id: ProjectEntryId,
@@ -2569,6 +2690,7 @@ impl ProjectPanel {
                     .indent_level(depth)
                     .indent_step_size(px(settings.indent_size))
                     .selected(is_marked || is_active)
+                    .focused(self.focus_handle.contains_focused(cx))
                     .when_some(canonical_path, |this, path| {
                         this.end_slot::<AnyElement>(
                             div()
@@ -3033,6 +3155,7 @@ impl Render for ProjectPanel {
         let project = self.project.read(cx);
         let indent_size = ProjectPanelSettings::get_global(cx).indent_size;
         let indent_guides = ProjectPanelSettings::get_global(cx).indent_guides;
+        let sticky_scroll = ProjectPanelSettings::get_global(cx).sticky_scroll;
         let is_local = project.is_local();
 
         if has_worktree {
@@ -3131,7 +3254,7 @@ impl Render for ProjectPanel {
                         |this, range, cx| {
                             let mut items = Vec::with_capacity(range.end - range.start);
                             this.for_each_visible_entry(range, cx, |id, details, cx| {
-                                items.push(this.render_entry(id, details, cx));
+                                items.push(this.render_cached_entry(id, details, cx));
                             });
                             items
                         }
@@ -3233,6 +3356,29 @@ impl Render for ProjectPanel {
                             ),
                         )
                     })
+                    .when(sticky_scroll, |list| {
+                        list.with_decoration(ui::sticky_ancestors(
+                            cx.view().clone(),
+                            self.scroll_handle.clone(),
+                            |this, ix, _| this.ancestor_indices(ix),
+                            |this, rows, cx| {
+                                let mut items = SmallVec::with_capacity(rows.len());
+                                for ix in rows {
+                                    this.for_each_visible_entry(
+                                        ix..ix + 1,
+                                        cx,
+                                        |id, details, cx| {
+                                            items.push(
+                                                this.render_entry(id, details, cx)
+                                                    .into_any_element(),
+                                            );
+                                        },
+                                    );
+                                }
+                                items
+                            },
+                        ))
+                    })
                     .size_full()
                     .with_sizing_behavior(ListSizingBehavior::Infer)
                     .with_horizontal_sizing_behavior(ListHorizontalSizingBehavior::Unconstrained)
@@ -3250,7 +3396,7 @@ impl Render for ProjectPanel {
                             .anchor(gpui::AnchorCorner::TopLeft)
                             .child(menu.clone()),
                     )
-                    .with_priority(1)
+                    .with_priority(OverlayLayer::Popover.priority())
                 }))
         } else {
             v_flex()
@@ -3517,6 +3663,118 @@ mod tests {
         );
     }
 
+    #[gpui::test]
+    async fn test_sticky_scroll_ancestors(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor().clone());
+        fs.insert_tree(
+            "/root1",
+            json!({
+                "a": { "0": { "q": "" } },
+                "b": {
+                    "3": { "Q": "" },
+                    "4": { "R": "", "S": "" },
+                },
+                ".dockerignore": "",
+            }),
+        )
+        .await;
+        fs.insert_tree("/root2", json!({ "d": { "9": "" } })).await;
+
+        let project = Project::test(fs.clone(), ["/root1".as_ref(), "/root2".as_ref()], cx).await;
+        let workspace = cx.add_window(|cx| Workspace::test_new(project.clone(), cx));
+        let cx = &mut VisualTestContext::from_window(*workspace, cx);
+        let panel = workspace.update(cx, ProjectPanel::new).unwrap();
+
+        toggle_expand_dir(&panel, "root1/b", cx);
+        toggle_expand_dir(&panel, "root1/b/4", cx);
+        assert_eq!(
+            visible_entries_as_strings(&panel, 0..50, cx),
+            &[
+                "v root1",
+                "    > a",
+                "    v b",
+                "        > 3",
+                "        v 4  <== selected",
+                "              R",
+                "              S",
+                "      .dockerignore",
+                "v root2",
+                "    > d",
+            ]
+        );
+
+        panel.update(cx, |panel, _| {
+            assert!(panel.ancestor_indices(0).is_empty());
+            assert_eq!(panel.ancestor_indices(3).as_slice(), &[0, 2]);
+            assert_eq!(panel.ancestor_indices(6).as_slice(), &[0, 2, 4]);
+            assert_eq!(panel.ancestor_indices(7).as_slice(), &[0]);
+            assert!(panel.ancestor_indices(8).is_empty());
+            assert_eq!(panel.ancestor_indices(9).as_slice(), &[8]);
+        });
+    }
+
+    #[gpui::test]
+    async fn test_restore_scroll_anchor(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor().clone());
+        fs.insert_tree(
+            "/root1",
+            json!({
+                "a": { "0": "" },
+                "b": { "1": "" },
+                "c": { "2": "" },
+            }),
+        )
+        .await;
+
+        let project = Project::test(fs.clone(), ["/root1".as_ref()], cx).await;
+        let workspace = cx.add_window(|cx| Workspace::test_new(project.clone(), cx));
+        let cx = &mut VisualTestContext::from_window(*workspace, cx);
+        let panel = workspace.update(cx, ProjectPanel::new).unwrap();
+        assert_eq!(
+            visible_entries_as_strings(&panel, 0..50, cx),
+            &["v root1", "    > a", "    > b", "    > c"]
+        );
+
+        panel.update(cx, |panel, cx| {
+            assert_eq!(
+                panel.scroll_key_for_index(2, cx).as_deref(),
+                Some("/root1/b")
+            );
+
+            // An entry that isn't shown yet keeps the position pending.
+            panel.pending_scroll_anchor = Some(ScrollAnchor {
+                item_key: "/root1/b/1".into(),
+                offset_in_item: 3.,
+            });
+            panel.update_visible_entries(None, cx);
+            assert!(panel.pending_scroll_anchor.is_some());
+            assert_eq!(panel.scroll_handle.logical_scroll_top(), (0, px(0.)));
+
+            panel.pending_scroll_anchor = Some(ScrollAnchor {
+                item_key: "/root1/b".into(),
+                offset_in_item: 3.,
+            });
+            panel.update_visible_entries(None, cx);
+            assert!(panel.pending_scroll_anchor.is_none());
+            assert_eq!(panel.scroll_handle.logical_scroll_top(), (2, px(3.)));
+
+            let anchor = ScrollAnchor::capture(&panel.scroll_handle, |ix| {
+                panel.scroll_key_for_index(ix, cx)
+            });
+            assert_eq!(
+                anchor,
+                Some(ScrollAnchor {
+                    item_key: "/root1/b".into(),
+                    offset_in_item: 3.,
+                })
+            );
+        });
+    }
+
     #[gpui::test]
     async fn test_exclusions_in_visible_list(cx: &mut gpui::TestAppContext) {
         init_test(cx);
@@ -21,6 +21,7 @@ pub struct ProjectPanelSettings {
     pub git_status: bool,
     pub indent_size: f32,
     pub indent_guides: bool,
+    pub sticky_scroll: bool,
     pub auto_reveal_entries: bool,
     pub auto_fold_dirs: bool,
     pub scrollbar: ScrollbarSettings,
@@ -76,6 +77,11 @@ pub struct ProjectPanelSettingsContent {
     ///
     /// Default: true
     pub indent_guides: Option<bool>,
+    /// Whether to pin the parent directories of the topmost visible entry to the
+    /// top of the project panel.
+    ///
+    /// Default: true
+    pub sticky_scroll: Option<bool>,
     /// Whether to reveal it in the project panel automatically,
     /// when a corresponding project entry becomes active.
     /// Gitignored entries are never auto revealed.
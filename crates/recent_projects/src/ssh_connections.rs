@@ -1,13 +1,13 @@
-use std::{path::PathBuf, sync::Arc, time::Duration};
+use std::{path::PathBuf, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use auto_update::AutoUpdater;
 use editor::Editor;
 use futures::channel::oneshot;
 use gpui::{
-    percentage, Animation, AnimationExt, AnyWindowHandle, AsyncAppContext, DismissEvent,
-    EventEmitter, FocusableView, ParentElement as _, PromptLevel, Render, SemanticVersion,
-    SharedString, Task, TextStyleRefinement, Transformation, View, WeakView,
+    AnyWindowHandle, AsyncAppContext, DismissEvent, EventEmitter, FocusableView,
+    ParentElement as _, PromptLevel, Render, SemanticVersion, SharedString, Task,
+    TextStyleRefinement, View, WeakView,
 };
 use gpui::{AppContext, Model};
 
@@ -22,7 +22,7 @@ use settings::{Settings, SettingsSources};
 use theme::ThemeSettings;
 use ui::{
     prelude::*, ActiveTheme, Color, Icon, IconName, IconSize, InteractiveElement, IntoElement,
-    Label, LabelCommon, Styled, ViewContext, VisualContext, WindowContext,
+    Label, LabelCommon, LoadingLabel, Styled, ViewContext, VisualContext, WindowContext,
 };
 use workspace::{AppState, ModalView, Workspace};
 
@@ -232,27 +232,10 @@ impl Render for SshPrompt {
             .size_full()
             .text_buffer(cx)
             .when_some(self.status_message.clone(), |el, status_message| {
-                el.child(
-                    h_flex()
-                        .gap_1()
-                        .child(
-                            Icon::new(IconName::ArrowCircle)
-                                .size(IconSize::Medium)
-                                .with_animation(
-                                    "arrow-circle",
-                                    Animation::new(Duration::from_secs(2)).repeat(),
-                                    |icon, delta| {
-                                        icon.transform(Transformation::rotate(percentage(delta)))
-                                    },
-                                ),
-                        )
-                        .child(
-                            div()
-                                .text_ellipsis()
-                                .overflow_x_hidden()
-                                .child(format!("{}…", status_message)),
-                        ),
-                )
+                el.child(LoadingLabel::new(
+                    "ssh-connection-status",
+                    format!("{}…", status_message),
+                ))
             })
             .when_some(self.prompt.as_ref(), |el, prompt| {
                 el.child(
@@ -81,6 +81,7 @@ pub fn text_style(cx: &mut WindowContext) -> TextStyle {
         // These are going to be overridden per-cell
         underline: None,
         strikethrough: None,
+        overline: None,
         color: theme.colors().terminal_foreground,
     };
 
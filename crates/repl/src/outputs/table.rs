@@ -101,6 +101,7 @@ impl TableView {
             background_color: None,
             underline: None,
             strikethrough: None,
+            overline: None,
         }];
 
         for field in table.schema.fields.iter() {
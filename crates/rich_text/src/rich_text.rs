@@ -1,12 +1,13 @@
 use futures::FutureExt;
 use gpui::{
-    AnyElement, AnyView, ElementId, FontStyle, FontWeight, HighlightStyle, InteractiveText,
-    IntoElement, SharedString, StrikethroughStyle, StyledText, UnderlineStyle, WindowContext,
+    AnyElement, AnyView, ClipboardItem, ElementId, FontStyle, FontWeight, HighlightStyle,
+    InteractiveText, IntoElement, SharedString, StrikethroughStyle, StyledText, UnderlineStyle,
+    WindowContext,
 };
 use language::{HighlightId, Language, LanguageRegistry};
 use std::{ops::Range, sync::Arc};
 use theme::ActiveTheme;
-use ui::LinkPreview;
+use ui::{utils::formatted_clipboard_item, LinkPreview, SelectableText};
 use util::RangeExt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -41,6 +42,7 @@ pub struct RichText {
     pub custom_ranges: Vec<Range<usize>>,
     custom_ranges_tooltip_fn:
         Option<Arc<dyn Fn(usize, Range<usize>, &mut WindowContext) -> Option<AnyView>>>,
+    selectable: bool,
 }
 
 /// Allows one to specify extra links to the rendered markdown, which can be used
@@ -80,9 +82,16 @@ impl RichText {
             highlights,
             custom_ranges: Vec::new(),
             custom_ranges_tooltip_fn: None,
+            selectable: false,
         }
     }
 
+    /// Lets the rendered text be selected and copied. Links can still be clicked.
+    pub fn selectable(mut self) -> Self {
+        self.selectable = true;
+        self
+    }
+
     pub fn set_tooltip_builder_for_custom_ranges(
         &mut self,
         f: impl Fn(usize, Range<usize>, &mut WindowContext) -> Option<AnyView> + 'static,
@@ -90,88 +99,104 @@ impl RichText {
         self.custom_ranges_tooltip_fn = Some(Arc::new(f));
     }
 
-    pub fn element(&self, id: ElementId, cx: &mut WindowContext) -> AnyElement {
+    /// Returns the style of each highlighted range of the text with the current theme.
+    fn highlight_styles(&self, cx: &WindowContext) -> Vec<(Range<usize>, HighlightStyle)> {
         let theme = cx.theme();
         let code_background = theme.colors().surface_background;
-
-        InteractiveText::new(
-            id,
-            StyledText::new(self.text.clone()).with_highlights(
-                &cx.text_style(),
-                self.highlights.iter().map(|(range, highlight)| {
-                    (
-                        range.clone(),
-                        match highlight {
-                            Highlight::Code => HighlightStyle {
-                                background_color: Some(code_background),
-                                ..Default::default()
-                            },
-                            Highlight::Id(id) => HighlightStyle {
-                                background_color: Some(code_background),
-                                ..id.style(theme.syntax()).unwrap_or_default()
-                            },
-                            Highlight::InlineCode(link) => {
-                                if *link {
-                                    HighlightStyle {
-                                        background_color: Some(code_background),
-                                        underline: Some(UnderlineStyle {
-                                            thickness: 1.0.into(),
-                                            ..Default::default()
-                                        }),
-                                        ..Default::default()
-                                    }
-                                } else {
-                                    HighlightStyle {
-                                        background_color: Some(code_background),
+        self.highlights
+            .iter()
+            .map(|(range, highlight)| {
+                (
+                    range.clone(),
+                    match highlight {
+                        Highlight::Code => HighlightStyle {
+                            background_color: Some(code_background),
+                            ..Default::default()
+                        },
+                        Highlight::Id(id) => HighlightStyle {
+                            background_color: Some(code_background),
+                            ..id.style(theme.syntax()).unwrap_or_default()
+                        },
+                        Highlight::InlineCode(link) => {
+                            if *link {
+                                HighlightStyle {
+                                    background_color: Some(code_background),
+                                    underline: Some(UnderlineStyle {
+                                        thickness: 1.0.into(),
                                         ..Default::default()
-                                    }
+                                    }),
+                                    ..Default::default()
+                                }
+                            } else {
+                                HighlightStyle {
+                                    background_color: Some(code_background),
+                                    ..Default::default()
                                 }
                             }
-                            Highlight::Highlight(highlight) => *highlight,
-                            Highlight::Mention => HighlightStyle {
-                                font_weight: Some(FontWeight::BOLD),
-                                ..Default::default()
-                            },
-                            Highlight::SelfMention => HighlightStyle {
-                                font_weight: Some(FontWeight::BOLD),
-                                ..Default::default()
-                            },
+                        }
+                        Highlight::Highlight(highlight) => *highlight,
+                        Highlight::Mention => HighlightStyle {
+                            font_weight: Some(FontWeight::BOLD),
+                            ..Default::default()
                         },
-                    )
-                }),
-            ),
-        )
-        .on_click(self.link_ranges.clone(), {
-            let link_urls = self.link_urls.clone();
-            move |ix, cx| {
-                let url = &link_urls[ix];
-                if url.starts_with("http") {
-                    cx.open_url(url);
-                }
-            }
-        })
-        .tooltip({
-            let link_ranges = self.link_ranges.clone();
-            let link_urls = self.link_urls.clone();
-            let custom_tooltip_ranges = self.custom_ranges.clone();
-            let custom_tooltip_fn = self.custom_ranges_tooltip_fn.clone();
-            move |idx, cx| {
-                for (ix, range) in link_ranges.iter().enumerate() {
-                    if range.contains(&idx) {
-                        return Some(LinkPreview::new(&link_urls[ix], cx));
+                        Highlight::SelfMention => HighlightStyle {
+                            font_weight: Some(FontWeight::BOLD),
+                            ..Default::default()
+                        },
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns a clipboard item with the text, along with versions of it formatted
+    /// with its highlights, for pasting into applications that accept formatted text.
+    pub fn clipboard_item(&self, cx: &WindowContext) -> ClipboardItem {
+        formatted_clipboard_item(&self.text, &cx.text_style(), &self.highlight_styles(cx))
+    }
+
+    pub fn element(&self, id: ElementId, cx: &mut WindowContext) -> AnyElement {
+        let text = StyledText::new(self.text.clone())
+            .with_highlights(&cx.text_style(), self.highlight_styles(cx));
+        let layout = text.layout().clone();
+
+        let text = InteractiveText::new(id.clone(), text)
+            .on_click(self.link_ranges.clone(), {
+                let link_urls = self.link_urls.clone();
+                move |ix, cx| {
+                    let url = &link_urls[ix];
+                    if url.starts_with("http") {
+                        cx.open_url(url);
                     }
                 }
-                for range in &custom_tooltip_ranges {
-                    if range.contains(&idx) {
-                        if let Some(f) = &custom_tooltip_fn {
-                            return f(idx, range.clone(), cx);
+            })
+            .tooltip({
+                let link_ranges = self.link_ranges.clone();
+                let link_urls = self.link_urls.clone();
+                let custom_tooltip_ranges = self.custom_ranges.clone();
+                let custom_tooltip_fn = self.custom_ranges_tooltip_fn.clone();
+                move |idx, cx| {
+                    for (ix, range) in link_ranges.iter().enumerate() {
+                        if range.contains(&idx) {
+                            return Some(LinkPreview::new(&link_urls[ix], cx));
+                        }
+                    }
+                    for range in &custom_tooltip_ranges {
+                        if range.contains(&idx) {
+                            if let Some(f) = &custom_tooltip_fn {
+                                return f(idx, range.clone(), cx);
+                            }
                         }
                     }
+                    None
                 }
-                None
-            }
-        })
-        .into_any_element()
+            });
+
+        if self.selectable {
+            SelectableText::with_layout(id, layout, text).into_any_element()
+        } else {
+            text.into_any_element()
+        }
     }
 }
 
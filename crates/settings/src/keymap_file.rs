@@ -1,4 +1,7 @@
-use crate::{settings_store::parse_json_with_comments, SettingsAssets};
+use crate::{
+    settings_store::{parse_json_with_comments, replace_value_in_json_text, to_pretty_json},
+    SettingsAssets,
+};
 use anyhow::{anyhow, Context, Result};
 use collections::BTreeMap;
 use gpui::{Action, AppContext, KeyBinding, SharedString};
@@ -9,6 +12,7 @@ use schemars::{
 };
 use serde::Deserialize;
 use serde_json::Value;
+use std::ops::Range;
 use util::{asset_str, ResultExt};
 
 #[derive(Debug, Deserialize, Default, Clone, JsonSchema)]
@@ -164,13 +168,322 @@ impl KeymapFile {
     }
 }
 
+/// A change to the bindings of an action, applied to the text of the user keymap
+/// with [`KeymapFile::update_text`].
+#[derive(Debug, Clone, Copy)]
+pub struct KeymapEdit<'a> {
+    pub action_name: &'a str,
+    pub context: Option<&'a str>,
+    /// The keystrokes to unbind from the action, e.g. to replace them.
+    pub old_keystrokes: Option<&'a str>,
+    /// The keystrokes to bind the action to.
+    pub new_keystrokes: Option<&'a str>,
+}
+
+/// The indentation of keymap files, like the default keymaps.
+const KEYMAP_TAB_SIZE: usize = 2;
+
+/// A change to one binding of a block, applied to the text of a keymap.
+enum BindingChange<'a> {
+    Set(&'a str, Value),
+    Rename(&'a str, &'a str),
+    Remove(&'a str),
+}
+
+impl KeymapFile {
+    /// Applies an edit to the text of a keymap file, returning the new text.
+    ///
+    /// The bindings go in the block with the same context, which is added when
+    /// there's none. Old keystrokes bound in that block are removed, keeping the
+    /// action's arguments for the new keystrokes, and other old keystrokes, e.g.
+    /// from the default keymap, are bound to `null` to unbind them. The text is
+    /// edited in place, so the comments and formatting of the rest of the file
+    /// are kept.
+    pub fn update_text(content: &str, edit: KeymapEdit) -> Result<String> {
+        let blocks = if content.trim().is_empty() {
+            Vec::new()
+        } else {
+            parse_json_with_comments::<Vec<Value>>(content)?
+        };
+        let Some(block_ix) = blocks
+            .iter()
+            .position(|block| block.get("context").and_then(Value::as_str) == edit.context)
+        else {
+            return append_block(content, &new_block(edit));
+        };
+        // The blocks of the syntax tree are matched with the parsed blocks by index.
+        let tree = parse_keymap_syntax(content)?;
+        if block_nodes(&tree)?.1.len() != blocks.len() {
+            return Err(anyhow!(
+                "Failed to find the blocks in the text of the keymap"
+            ));
+        }
+
+        let bindings = blocks[block_ix]
+            .get("bindings")
+            .map(|bindings| {
+                bindings
+                    .as_object()
+                    .ok_or_else(|| anyhow!("Expected keymap bindings to be an object"))
+            })
+            .transpose()?;
+
+        let mut changes = Vec::new();
+        let mut rebound = false;
+        if let Some(old_keystrokes) = edit.old_keystrokes {
+            let old_action = bindings.and_then(|bindings| bindings.get(old_keystrokes));
+            let names_action = old_action.map_or(false, |value| {
+                let name = match value {
+                    Value::Array(items) => items.first(),
+                    value => Some(value),
+                };
+                name.and_then(Value::as_str) == Some(edit.action_name)
+            });
+            if let (true, Some(old_action)) = (names_action, old_action) {
+                rebound = true;
+                match edit.new_keystrokes {
+                    Some(new_keystrokes) if new_keystrokes == old_keystrokes => {}
+                    Some(new_keystrokes)
+                        if bindings
+                            .map_or(false, |bindings| bindings.contains_key(new_keystrokes)) =>
+                    {
+                        changes.push(BindingChange::Remove(old_keystrokes));
+                        changes.push(BindingChange::Set(new_keystrokes, old_action.clone()));
+                    }
+                    Some(new_keystrokes) => {
+                        changes.push(BindingChange::Rename(old_keystrokes, new_keystrokes))
+                    }
+                    None => changes.push(BindingChange::Remove(old_keystrokes)),
+                }
+            } else if edit.new_keystrokes != Some(old_keystrokes) {
+                changes.push(BindingChange::Set(old_keystrokes, Value::Null));
+            }
+        }
+        if let (false, Some(new_keystrokes)) = (rebound, edit.new_keystrokes) {
+            changes.push(BindingChange::Set(
+                new_keystrokes,
+                Value::String(edit.action_name.into()),
+            ));
+        }
+
+        let mut text = content.to_string();
+        for change in changes {
+            apply_binding_change(&mut text, block_ix, change)?;
+        }
+        Ok(text)
+    }
+}
+
+/// The block that an edit adds when there's no block with its context.
+fn new_block(edit: KeymapEdit) -> Value {
+    let mut bindings = serde_json::Map::new();
+    if let Some(old_keystrokes) = edit.old_keystrokes {
+        if edit.new_keystrokes != Some(old_keystrokes) {
+            bindings.insert(old_keystrokes.into(), Value::Null);
+        }
+    }
+    if let Some(new_keystrokes) = edit.new_keystrokes {
+        bindings.insert(new_keystrokes.into(), edit.action_name.into());
+    }
+    let mut block = serde_json::Map::new();
+    if let Some(context) = edit.context {
+        block.insert("context".into(), context.into());
+    }
+    block.insert("bindings".into(), Value::Object(bindings));
+    Value::Object(block)
+}
+
+fn parse_keymap_syntax(text: &str) -> Result<tree_sitter::Tree> {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&tree_sitter_json::LANGUAGE.into())?;
+    parser
+        .parse(text, None)
+        .ok_or_else(|| anyhow!("Failed to parse the keymap"))
+}
+
+/// Returns the top-level array of a keymap and the objects of its blocks.
+fn block_nodes(
+    tree: &tree_sitter::Tree,
+) -> Result<(tree_sitter::Node<'_>, Vec<tree_sitter::Node<'_>>)> {
+    let root = tree.root_node();
+    let array = (0..root.named_child_count())
+        .filter_map(|ix| root.named_child(ix))
+        .find(|node| node.kind() == "array")
+        .ok_or_else(|| anyhow!("Expected the keymap to be an array"))?;
+    let blocks = (0..array.named_child_count())
+        .filter_map(|ix| array.named_child(ix))
+        .filter(|node| !node.is_extra() && !node.is_error())
+        .collect();
+    Ok((array, blocks))
+}
+
+/// Returns the pair of an object with the given key.
+fn object_pair<'a>(
+    object: tree_sitter::Node<'a>,
+    text: &str,
+    key: &str,
+) -> Option<tree_sitter::Node<'a>> {
+    (0..object.named_child_count())
+        .filter_map(|ix| object.named_child(ix))
+        .find(|pair| {
+            pair.kind() == "pair"
+                && pair.child_by_field_name("key").map_or(false, |key_node| {
+                    text[key_node.byte_range()] == format!("\"{key}\"")
+                })
+        })
+}
+
+fn binding_pair<'a>(
+    block: tree_sitter::Node<'a>,
+    text: &str,
+    keystrokes: &str,
+) -> Option<tree_sitter::Node<'a>> {
+    let bindings = object_pair(block, text, "bindings")?.child_by_field_name("value")?;
+    object_pair(bindings, text, keystrokes)
+}
+
+fn apply_binding_change(text: &mut String, block_ix: usize, change: BindingChange) -> Result<()> {
+    let tree = parse_keymap_syntax(text)?;
+    let (_, blocks) = block_nodes(&tree)?;
+    let block = *blocks
+        .get(block_ix)
+        .ok_or_else(|| anyhow!("Failed to find keymap block {block_ix}"))?;
+    let missing_binding = || anyhow!("Failed to find the binding in the keymap block");
+
+    let (range, replacement) = match change {
+        BindingChange::Set(keystrokes, value) => {
+            let bindings = object_pair(block, text, "bindings")
+                .and_then(|pair| Some((pair, pair.child_by_field_name("value")?)))
+                .filter(|(_, bindings)| bindings.kind() == "object");
+            match bindings {
+                // The first binding is inserted before the comments of the bindings,
+                // e.g. the examples of the initial keymap, rather than replacing them.
+                Some((pair, bindings)) if !has_pairs(bindings) => {
+                    let indent = line_indent(text, pair.start_byte());
+                    let binding_indent = indent + KEYMAP_TAB_SIZE;
+                    let mut binding = format!(
+                        "\n{:binding_indent$}{}: {}",
+                        "",
+                        serde_json::to_string(keystrokes)?,
+                        to_pretty_json(&value, KEYMAP_TAB_SIZE, binding_indent),
+                    );
+                    if bindings.named_child_count() == 0 {
+                        binding.push_str(&format!("\n{:indent$}", ""));
+                    }
+                    let start = bindings.start_byte() + 1;
+                    (start..start, binding)
+                }
+                _ => {
+                    let block_range = block.byte_range();
+                    let (range, replacement) = replace_value_in_json_text(
+                        &text[block_range.clone()],
+                        &["bindings", keystrokes],
+                        KEYMAP_TAB_SIZE,
+                        &value,
+                    );
+                    (
+                        block_range.start + range.start..block_range.start + range.end,
+                        replacement,
+                    )
+                }
+            }
+        }
+        BindingChange::Rename(old_keystrokes, new_keystrokes) => {
+            let key = binding_pair(block, text, old_keystrokes)
+                .and_then(|pair| pair.child_by_field_name("key"))
+                .ok_or_else(missing_binding)?;
+            (key.byte_range(), serde_json::to_string(new_keystrokes)?)
+        }
+        BindingChange::Remove(keystrokes) => {
+            let pair = binding_pair(block, text, keystrokes).ok_or_else(missing_binding)?;
+            (removal_range(text, pair), String::new())
+        }
+    };
+    text.replace_range(range, &replacement);
+    Ok(())
+}
+
+fn has_pairs(object: tree_sitter::Node<'_>) -> bool {
+    (0..object.named_child_count())
+        .filter_map(|ix| object.named_child(ix))
+        .any(|node| node.kind() == "pair")
+}
+
+/// The indentation of the line at the offset, if it's only preceded by whitespace.
+fn line_indent(text: &str, offset: usize) -> usize {
+    let line_start = text[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+    let prefix = &text[line_start..offset];
+    if prefix.trim().is_empty() {
+        prefix.len()
+    } else {
+        0
+    }
+}
+
+/// The range to remove a pair of an object with, along with its comma and, when it
+/// is on its own line, the line.
+fn removal_range(text: &str, pair: tree_sitter::Node<'_>) -> Range<usize> {
+    let is_blank = |c: char| c == ' ' || c == '\t';
+    let mut start = pair.start_byte();
+    let mut end = pair.end_byte();
+
+    let after = &text[end..];
+    let comma_offset = after.len() - after.trim_start_matches(is_blank).len();
+    if after[comma_offset..].starts_with(',') {
+        end += comma_offset + 1;
+    } else if let Some(previous) =
+        std::iter::successors(pair.prev_named_sibling(), |node| node.prev_named_sibling())
+            .find(|node| !node.is_extra())
+            .filter(|node| node.kind() == "pair")
+    {
+        // The last pair takes the comma after the previous pair with it instead.
+        if let Some(comma) = text[previous.end_byte()..start].find(',') {
+            start = previous.end_byte() + comma;
+        }
+    }
+
+    let line_start = text[..start].trim_end_matches(is_blank);
+    let rest = &text[end..];
+    let rest_trimmed = rest.trim_start_matches(is_blank);
+    if (line_start.is_empty() || line_start.ends_with('\n')) && rest_trimmed.starts_with('\n') {
+        start = line_start.len();
+        end += rest.len() - rest_trimmed.len() + 1;
+    }
+    start..end
+}
+
+/// Adds a block at the end of the top-level array of a keymap.
+fn append_block(content: &str, block: &Value) -> Result<String> {
+    let block_text = to_pretty_json(block, KEYMAP_TAB_SIZE, KEYMAP_TAB_SIZE);
+    if content.trim().is_empty() {
+        return Ok(format!("[\n  {block_text}\n]\n"));
+    }
+
+    let tree = parse_keymap_syntax(content)?;
+    let (array, blocks) = block_nodes(&tree)?;
+    let mut text = content.to_string();
+    match blocks.last() {
+        Some(last) => {
+            let after = &content[last.end_byte()..array.end_byte()];
+            if after.trim_start().starts_with(',') {
+                let comma = last.end_byte() + after.find(',').unwrap_or_default();
+                text.insert_str(comma + 1, &format!("\n  {block_text},"));
+            } else {
+                text.insert_str(last.end_byte(), &format!(",\n  {block_text}"));
+            }
+        }
+        None => text.insert_str(array.start_byte() + 1, &format!("\n  {block_text}\n")),
+    }
+    Ok(text)
+}
+
 fn no_action() -> Box<dyn gpui::Action> {
     gpui::NoAction.boxed_clone()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::KeymapFile;
+    use crate::{parse_json_with_comments, KeymapEdit, KeymapFile};
 
     #[test]
     fn can_deserialize_keymap_with_trailing_comma() {
@@ -187,4 +500,224 @@ mod tests {
         };
         KeymapFile::parse(json).unwrap();
     }
+
+    #[test]
+    fn test_update_text() {
+        let json = indoc::indoc! {r#"[
+              {
+                "bindings": { "ctrl-s": "workspace::Save" },
+              },
+              {
+                "context": "Editor",
+                "bindings": { "ctrl-d": ["editor::SelectNext", { "replace_newest": false }] },
+              },
+            ]"#
+        };
+
+        let text = KeymapFile::update_text(
+            json,
+            KeymapEdit {
+                action_name: "editor::SelectNext",
+                context: Some("Editor"),
+                old_keystrokes: Some("ctrl-d"),
+                new_keystrokes: Some("alt-d"),
+            },
+        )
+        .unwrap();
+        // The arguments of the action are kept, and the default binding of the
+        // new block is unbound.
+        let text = KeymapFile::update_text(
+            &text,
+            KeymapEdit {
+                action_name: "pane::CloseActiveItem",
+                context: Some("Pane"),
+                old_keystrokes: Some("ctrl-w"),
+                new_keystrokes: Some("ctrl-shift-w"),
+            },
+        )
+        .unwrap();
+        let blocks: serde_json::Value = parse_json_with_comments(&text).unwrap();
+
+        assert_eq!(
+            blocks,
+            serde_json::json!([
+                { "bindings": { "ctrl-s": "workspace::Save" } },
+                {
+                    "context": "Editor",
+                    "bindings": { "alt-d": ["editor::SelectNext", { "replace_newest": false }] },
+                },
+                {
+                    "context": "Pane",
+                    "bindings": { "ctrl-w": null, "ctrl-shift-w": "pane::CloseActiveItem" },
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_update_text_keeps_comments() {
+        let json = indoc::indoc! {r#"
+            // Zed keymap
+            [
+              {
+                "context": "Workspace",
+                "bindings": {
+                  // "shift shift": "file_finder::Toggle"
+                }
+              }
+            ]
+        "#};
+
+        let text = KeymapFile::update_text(
+            json,
+            KeymapEdit {
+                action_name: "file_finder::Toggle",
+                context: Some("Workspace"),
+                old_keystrokes: None,
+                new_keystrokes: Some("ctrl-p"),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            text,
+            indoc::indoc! {r#"
+                // Zed keymap
+                [
+                  {
+                    "context": "Workspace",
+                    "bindings": {
+                      "ctrl-p": "file_finder::Toggle"
+                      // "shift shift": "file_finder::Toggle"
+                    }
+                  }
+                ]
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_update_text_edits_bindings_in_place() {
+        let json = indoc::indoc! {r#"
+            [
+              // Navigation
+              {
+                "context": "Editor",
+                "bindings": {
+                  // Select the next occurrence
+                  "ctrl-d": ["editor::SelectNext", { "replace_newest": false }],
+                  "ctrl-s": "workspace::Save",
+                  "ctrl-w": "pane::CloseActiveItem"
+                }
+              }
+            ]
+        "#};
+        let edit = |action_name, old_keystrokes, new_keystrokes| KeymapEdit {
+            action_name,
+            context: Some("Editor"),
+            old_keystrokes: Some(old_keystrokes),
+            new_keystrokes,
+        };
+
+        let text =
+            KeymapFile::update_text(json, edit("editor::SelectNext", "ctrl-d", Some("alt-d")))
+                .unwrap();
+        let text = KeymapFile::update_text(&text, edit("workspace::Save", "ctrl-s", None)).unwrap();
+        assert_eq!(
+            text,
+            indoc::indoc! {r#"
+                [
+                  // Navigation
+                  {
+                    "context": "Editor",
+                    "bindings": {
+                      // Select the next occurrence
+                      "alt-d": ["editor::SelectNext", { "replace_newest": false }],
+                      "ctrl-w": "pane::CloseActiveItem"
+                    }
+                  }
+                ]
+            "#}
+        );
+
+        let text =
+            KeymapFile::update_text(&text, edit("pane::CloseActiveItem", "ctrl-w", None)).unwrap();
+        assert_eq!(
+            text,
+            indoc::indoc! {r#"
+                [
+                  // Navigation
+                  {
+                    "context": "Editor",
+                    "bindings": {
+                      // Select the next occurrence
+                      "alt-d": ["editor::SelectNext", { "replace_newest": false }]
+                    }
+                  }
+                ]
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_update_text_keeps_comments_and_trailing_commas() {
+        let json = indoc::indoc! {r#"
+            [
+              {
+                "context": "Editor",
+                "bindings": {
+                  "ctrl-d": "editor::SelectNext", // Select the next occurrence
+                  // Save the file
+                  "ctrl-s": "workspace::Save",
+                  "ctrl-w": "pane::CloseActiveItem",
+                },
+              },
+            ]
+        "#};
+        let edit = |action_name, old_keystrokes, new_keystrokes| KeymapEdit {
+            action_name,
+            context: Some("Editor"),
+            old_keystrokes: Some(old_keystrokes),
+            new_keystrokes,
+        };
+
+        // Renaming keeps the comment after the binding, and removing the last
+        // binding keeps the trailing comma of the one before it.
+        let text =
+            KeymapFile::update_text(json, edit("editor::SelectNext", "ctrl-d", Some("alt-d")))
+                .unwrap();
+        let text =
+            KeymapFile::update_text(&text, edit("pane::CloseActiveItem", "ctrl-w", None)).unwrap();
+        assert_eq!(
+            text,
+            indoc::indoc! {r#"
+                [
+                  {
+                    "context": "Editor",
+                    "bindings": {
+                      "alt-d": "editor::SelectNext", // Select the next occurrence
+                      // Save the file
+                      "ctrl-s": "workspace::Save",
+                    },
+                  },
+                ]
+            "#}
+        );
+
+        // Removing a binding keeps the comment on the line above it.
+        let text = KeymapFile::update_text(&text, edit("workspace::Save", "ctrl-s", None)).unwrap();
+        assert_eq!(
+            text,
+            indoc::indoc! {r#"
+                [
+                  {
+                    "context": "Editor",
+                    "bindings": {
+                      "alt-d": "editor::SelectNext", // Select the next occurrence
+                      // Save the file
+                    },
+                  },
+                ]
+            "#}
+        );
+    }
 }
@@ -11,7 +11,7 @@ use util::asset_str;
 
 pub use editable_setting_control::*;
 pub use json_schema::*;
-pub use keymap_file::KeymapFile;
+pub use keymap_file::{KeymapEdit, KeymapFile};
 pub use settings_file::*;
 pub use settings_store::{
     parse_json_with_comments, InvalidSettingsError, LocalSettingsKind, Settings, SettingsLocation,
@@ -360,6 +360,11 @@ impl SettingsStore {
         &self.raw_user_settings
     }
 
+    /// Get the default settings as a raw JSON value.
+    pub fn raw_default_settings(&self) -> &serde_json::Value {
+        &self.raw_default_settings
+    }
+
     #[cfg(any(test, feature = "test-support"))]
     pub fn test(cx: &mut AppContext) -> Self {
         let mut this = Self::new(cx);
@@ -410,33 +415,71 @@ impl SettingsStore {
                     let new_text = cx.read_global(|store: &SettingsStore, cx| {
                         store.new_text_for_update::<T>(old_text, |content| update(content, cx))
                     })?;
-                    let initial_path = paths::settings_file().as_path();
-                    if fs.is_file(initial_path).await {
-                        let resolved_path =
-                            fs.canonicalize(initial_path).await.with_context(|| {
-                                format!("Failed to canonicalize settings path {:?}", initial_path)
-                            })?;
-
-                        fs.atomic_write(resolved_path.clone(), new_text)
-                            .await
-                            .with_context(|| {
-                                format!("Failed to write settings to file {:?}", resolved_path)
-                            })?;
-                    } else {
-                        fs.atomic_write(initial_path.to_path_buf(), new_text)
-                            .await
-                            .with_context(|| {
-                                format!("Failed to write settings to file {:?}", initial_path)
-                            })?;
-                    }
+                    Self::write_settings(&fs, new_text).await
+                }
+                .boxed_local()
+            }))
+            .ok();
+    }
 
-                    anyhow::Ok(())
+    /// Sets the value at a key path of the user's settings file, e.g.
+    /// `["terminal", "font_size"]`, for settings edited without knowing their
+    /// type. A `null` value resets the setting to its default.
+    pub fn update_settings_file_at_path(
+        &self,
+        fs: Arc<dyn Fs>,
+        key_path: Vec<String>,
+        value: serde_json::Value,
+    ) {
+        self.setting_file_updates_tx
+            .unbounded_send(Box::new(move |cx: AsyncAppContext| {
+                async move {
+                    let old_text = Self::load_settings(&fs).await?;
+                    let new_text = cx.read_global(|store: &SettingsStore, _| {
+                        let key_path = key_path.iter().map(String::as_str).collect::<Vec<_>>();
+                        store.new_text_for_path_update(old_text, &key_path, &value)
+                    })?;
+                    Self::write_settings(&fs, new_text).await
                 }
                 .boxed_local()
             }))
             .ok();
     }
 
+    async fn write_settings(fs: &Arc<dyn Fs>, new_text: String) -> Result<()> {
+        let initial_path = paths::settings_file().as_path();
+        if fs.is_file(initial_path).await {
+            let resolved_path = fs.canonicalize(initial_path).await.with_context(|| {
+                format!("Failed to canonicalize settings path {:?}", initial_path)
+            })?;
+
+            fs.atomic_write(resolved_path.clone(), new_text)
+                .await
+                .with_context(|| format!("Failed to write settings to file {:?}", resolved_path))?;
+        } else {
+            fs.atomic_write(initial_path.to_path_buf(), new_text)
+                .await
+                .with_context(|| format!("Failed to write settings to file {:?}", initial_path))?;
+        }
+
+        anyhow::Ok(())
+    }
+
+    /// Sets the value at a key path of a JSON file, returning the new text for that
+    /// JSON file. The comments and formatting of the rest of the file are kept.
+    pub fn new_text_for_path_update(
+        &self,
+        old_text: String,
+        key_path: &[&str],
+        value: &serde_json::Value,
+    ) -> String {
+        let (range, replacement) =
+            replace_value_in_json_text(&old_text, key_path, self.json_tab_size(), value);
+        let mut new_text = old_text;
+        new_text.replace_range(range, &replacement);
+        new_text
+    }
+
     /// Updates the value of a setting in a JSON file, returning the new text
     /// for that JSON file.
     pub fn new_text_for_update<T: Settings>(
@@ -1190,7 +1233,7 @@ fn update_value_in_json_text<'a>(
     }
 }
 
-fn replace_value_in_json_text(
+pub(crate) fn replace_value_in_json_text(
     text: &str,
     key_path: &[&str],
     tab_size: usize,
@@ -1312,7 +1355,11 @@ fn replace_value_in_json_text(
     }
 }
 
-fn to_pretty_json(value: &impl Serialize, indent_size: usize, indent_prefix_len: usize) -> String {
+pub(crate) fn to_pretty_json(
+    value: &impl Serialize,
+    indent_size: usize,
+    indent_prefix_len: usize,
+) -> String {
     const SPACES: [u8; 32] = [b' '; 32];
 
     debug_assert!(indent_size <= SPACES.len());
@@ -1644,6 +1691,34 @@ mod tests {
         );
     }
 
+    #[gpui::test]
+    fn test_setting_store_update_at_path(cx: &mut AppContext) {
+        let store = SettingsStore::new(cx);
+        let old_json = r#"{
+                // The user
+                "user": {
+                    "age": 36
+                }
+            }"#
+        .unindent();
+
+        let new_json =
+            store.new_text_for_path_update(old_json, &["user", "age"], &serde_json::json!(37));
+        let new_json =
+            store.new_text_for_path_update(new_json, &["user", "name"], &serde_json::json!("Max"));
+        pretty_assertions::assert_eq!(
+            new_json,
+            r#"{
+                // The user
+                "user": {
+                    "name": "Max",
+                    "age": 37
+                }
+            }"#
+            .unindent()
+        );
+    }
+
     fn check_settings_update<T: Settings>(
         store: &mut SettingsStore,
         old_json: String,
@@ -0,0 +1,563 @@
+use std::sync::Arc;
+
+use collections::{HashMap, HashSet};
+use fs::Fs;
+use gpui::{
+    AppContext, EventEmitter, FocusHandle, FocusableView, KeymapVersion, Keystroke, NoAction,
+    Subscription, View,
+};
+use settings::{KeymapEdit, KeymapFile};
+use ui::{prelude::*, KeyBinding, KeystrokeRecorder, KeystrokeRecorderEvent, Table, TableCell};
+use ui::{TableColumn, Tooltip};
+use ui_input::{SearchInput, SearchInputEvent};
+use workspace::item::{Item, ItemEvent};
+use workspace::Workspace;
+
+/// The most rows shown at once, as the table isn't virtualized.
+const MAX_VISIBLE_ROWS: usize = 250;
+
+/// A binding of the keymap, or an action without any binding.
+struct KeymapRow {
+    action_name: SharedString,
+    context: Option<SharedString>,
+    /// The keystrokes in the syntax of keymap files, e.g. `ctrl-k ctrl-s`.
+    keystrokes: Option<String>,
+    binding: Option<gpui::KeyBinding>,
+    /// Whether the binding passes arguments to the action. Those can't be
+    /// written from the editor, so such bindings are edited in the keymap file.
+    has_arguments: bool,
+    /// Whether other actions are bound to the same keystrokes in the same context.
+    conflicting: bool,
+}
+
+impl KeymapRow {
+    fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || self.action_name.to_lowercase().contains(query)
+            || self
+                .context
+                .as_ref()
+                .map_or(false, |context| context.to_lowercase().contains(query))
+            || self
+                .keystrokes
+                .as_ref()
+                .map_or(false, |keystrokes| keystrokes.contains(query))
+    }
+}
+
+/// The binding being edited, until its new keystrokes are saved.
+struct PendingEdit {
+    action_name: SharedString,
+    context: Option<SharedString>,
+    old_keystrokes: Option<String>,
+    new_keystrokes: Option<String>,
+}
+
+fn keystrokes_text(keystrokes: &[Keystroke]) -> String {
+    keystrokes
+        .iter()
+        .map(Keystroke::unparse)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Loads the user keymap, which is empty until the first binding is written to it.
+/// Other errors are returned, so that a keymap that can't be read isn't overwritten.
+async fn load_keymap(fs: &Arc<dyn Fs>) -> anyhow::Result<String> {
+    match fs.load(paths::keymap_file()).await {
+        result @ Ok(_) => result,
+        Err(err) => {
+            if let Some(e) = err.downcast_ref::<std::io::Error>() {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    return Ok(String::new());
+                }
+            }
+            Err(err)
+        }
+    }
+}
+
+/// Lists the bindings of the keymap by action and context, to edit them and write
+/// the changes to the user keymap.
+pub struct KeymapEditor {
+    focus_handle: FocusHandle,
+    fs: Arc<dyn Fs>,
+    search: View<SearchInput>,
+    recorder: View<KeystrokeRecorder>,
+    query: String,
+    rows: Vec<KeymapRow>,
+    keymap_version: Option<KeymapVersion>,
+    pending_edit: Option<PendingEdit>,
+    error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl KeymapEditor {
+    pub fn new(workspace: &Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
+        let fs = workspace.app_state().fs.clone();
+        cx.new_view(|cx| {
+            let search = cx.new_view(|cx| SearchInput::new("Search actions and keystrokes…", cx));
+            let recorder = cx.new_view(KeystrokeRecorder::new);
+            let subscriptions = vec![
+                cx.subscribe(&search, |this, _, event: &SearchInputEvent, cx| {
+                    let SearchInputEvent::QueryChanged(query) = event;
+                    this.query = query.to_lowercase();
+                    cx.notify();
+                }),
+                cx.subscribe(&recorder, |this, _, event: &KeystrokeRecorderEvent, cx| {
+                    match event {
+                        KeystrokeRecorderEvent::Recorded(keystrokes) => {
+                            if let Some(edit) = this.pending_edit.as_mut() {
+                                edit.new_keystrokes = Some(keystrokes_text(keystrokes));
+                            }
+                        }
+                        KeystrokeRecorderEvent::Cancelled => this.pending_edit = None,
+                    }
+                    cx.notify();
+                }),
+            ];
+
+            Self {
+                focus_handle: cx.focus_handle(),
+                fs,
+                search,
+                recorder,
+                query: String::new(),
+                rows: Vec::new(),
+                keymap_version: None,
+                pending_edit: None,
+                error: None,
+                _subscriptions: subscriptions,
+            }
+        })
+    }
+
+    /// Rebuilds the rows when the keymap changed, e.g. after the user keymap was
+    /// saved and reloaded.
+    fn update_rows(&mut self, cx: &AppContext) {
+        let keymap = cx.key_bindings();
+        let keymap = keymap.borrow();
+        if self.keymap_version == Some(keymap.version()) {
+            return;
+        }
+        self.keymap_version = Some(keymap.version());
+
+        // Later bindings take precedence, and bindings to `NoAction` remove the
+        // earlier bindings of their keystrokes.
+        let mut unbound = HashSet::default();
+        let mut rows = Vec::new();
+        for binding in keymap.bindings().rev() {
+            let context = binding
+                .predicate()
+                .map(|predicate| SharedString::from(predicate.to_string()));
+            let keystrokes = keystrokes_text(binding.keystrokes());
+            if binding.action().as_any().is::<NoAction>() {
+                unbound.insert((context, keystrokes));
+                continue;
+            }
+            if unbound.contains(&(context.clone(), keystrokes.clone())) {
+                continue;
+            }
+            let action_name = SharedString::from(binding.action().name().to_string());
+            let has_arguments = cx
+                .build_action(&action_name, None)
+                .map_or(true, |action| !action.partial_eq(binding.action()));
+            rows.push(KeymapRow {
+                action_name,
+                context,
+                keystrokes: Some(keystrokes),
+                binding: Some(binding.clone()),
+                has_arguments,
+                conflicting: false,
+            });
+        }
+        rows.reverse();
+
+        let mut actions_by_keystrokes = HashMap::<_, HashSet<_>>::default();
+        for row in &rows {
+            actions_by_keystrokes
+                .entry((row.context.clone(), row.keystrokes.clone()))
+                .or_default()
+                .insert(row.action_name.clone());
+        }
+        for row in &mut rows {
+            row.conflicting = actions_by_keystrokes
+                .get(&(row.context.clone(), row.keystrokes.clone()))
+                .map_or(false, |actions| actions.len() > 1);
+        }
+
+        let bound_actions = rows
+            .iter()
+            .map(|row| row.action_name.clone())
+            .collect::<HashSet<_>>();
+        for action_name in cx.all_action_names() {
+            if !bound_actions.contains(action_name) {
+                rows.push(KeymapRow {
+                    action_name: action_name.clone(),
+                    context: None,
+                    keystrokes: None,
+                    binding: None,
+                    has_arguments: false,
+                    conflicting: false,
+                });
+            }
+        }
+        rows.sort_by(|a, b| a.action_name.cmp(&b.action_name));
+        self.rows = rows;
+    }
+
+    fn start_edit(&mut self, row_ix: usize, replace: bool, cx: &mut ViewContext<Self>) {
+        let Some(row) = self.rows.get(row_ix) else {
+            return;
+        };
+        self.pending_edit = Some(PendingEdit {
+            action_name: row.action_name.clone(),
+            context: row.context.clone(),
+            old_keystrokes: row.keystrokes.clone().filter(|_| replace),
+            new_keystrokes: None,
+        });
+        self.error = None;
+        self.recorder
+            .update(cx, |recorder, cx| recorder.set_keystrokes(Vec::new(), cx));
+        cx.focus_view(&self.recorder);
+        cx.notify();
+    }
+
+    fn remove_binding(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(row) = self.rows.get(row_ix) else {
+            return;
+        };
+        self.pending_edit = Some(PendingEdit {
+            action_name: row.action_name.clone(),
+            context: row.context.clone(),
+            old_keystrokes: row.keystrokes.clone(),
+            new_keystrokes: None,
+        });
+        self.save_edit(cx);
+    }
+
+    fn cancel_edit(&mut self, cx: &mut ViewContext<Self>) {
+        self.pending_edit = None;
+        cx.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    /// Writes the pending edit to the user keymap, which is then reloaded.
+    fn save_edit(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(edit) = self.pending_edit.take() else {
+            return;
+        };
+        let fs = self.fs.clone();
+        cx.focus(&self.focus_handle);
+        cx.spawn(|this, mut cx| async move {
+            let path = paths::keymap_file();
+            let result = async {
+                let content = load_keymap(&fs).await?;
+                let text = KeymapFile::update_text(
+                    &content,
+                    KeymapEdit {
+                        action_name: &edit.action_name,
+                        context: edit.context.as_deref(),
+                        old_keystrokes: edit.old_keystrokes.as_deref(),
+                        new_keystrokes: edit.new_keystrokes.as_deref(),
+                    },
+                )?;
+                fs.atomic_write(path.clone(), text).await
+            }
+            .await;
+            this.update(&mut cx, |this, cx| {
+                this.error = result
+                    .err()
+                    .map(|error| format!("Failed to update the keymap: {error}").into());
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+        cx.notify();
+    }
+
+    fn render_pending_edit(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let edit = self.pending_edit.as_ref()?;
+        let title = match &edit.context {
+            Some(context) => format!("{} in {}", edit.action_name, context),
+            None => edit.action_name.to_string(),
+        };
+        let can_save = edit.new_keystrokes.is_some();
+
+        Some(
+            h_flex()
+                .gap_2()
+                .p_2()
+                .rounded_md()
+                .border_1()
+                .border_color(cx.theme().colors().border)
+                .bg(cx.theme().colors().elevated_surface_background)
+                .child(Label::new(title).color(Color::Muted))
+                .child(div().flex_1().child(self.recorder.clone()))
+                .child(
+                    Button::new("save-binding", "Save")
+                        .style(ButtonStyle::Filled)
+                        .disabled(!can_save)
+                        .on_click(cx.listener(|this, _, cx| this.save_edit(cx))),
+                )
+                .child(
+                    Button::new("cancel-binding", "Cancel")
+                        .on_click(cx.listener(|this, _, cx| this.cancel_edit(cx))),
+                ),
+        )
+    }
+
+    fn render_row(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> Vec<TableCell> {
+        let row = &self.rows[row_ix];
+        let keystrokes = match (&row.binding, row.conflicting) {
+            (Some(binding), true) => TableCell::element(
+                h_flex()
+                    .gap_1()
+                    .child(KeyBinding::new(binding.clone()))
+                    .child(
+                        Label::new("Conflict")
+                            .size(LabelSize::Small)
+                            .color(Color::Deleted),
+                    ),
+            ),
+            (Some(binding), false) => TableCell::element(KeyBinding::new(binding.clone())),
+            (None, _) => TableCell::element(Label::new("Unbound").color(Color::Placeholder)),
+        };
+        let action_name = Label::new(row.action_name.clone()).color(if row.conflicting {
+            Color::Deleted
+        } else {
+            Color::Default
+        });
+        let context = row.context.clone().unwrap_or_default();
+
+        let actions = h_flex().gap_1().map(|this| {
+            if row.has_arguments {
+                this.child(
+                    IconButton::new(("edit-binding", row_ix), IconName::Pencil)
+                        .icon_size(IconSize::Small)
+                        .disabled(true)
+                        .tooltip(|cx| {
+                            Tooltip::text(
+                                "Bindings with arguments are edited in the keymap file",
+                                cx,
+                            )
+                        }),
+                )
+            } else {
+                this.when(row.binding.is_some(), |this| {
+                    this.child(
+                        IconButton::new(("edit-binding", row_ix), IconName::Pencil)
+                            .icon_size(IconSize::Small)
+                            .tooltip(|cx| Tooltip::text("Edit Binding", cx))
+                            .on_click(
+                                cx.listener(move |this, _, cx| this.start_edit(row_ix, true, cx)),
+                            ),
+                    )
+                    .child(
+                        IconButton::new(("remove-binding", row_ix), IconName::Trash)
+                            .icon_size(IconSize::Small)
+                            .tooltip(|cx| Tooltip::text("Remove Binding", cx))
+                            .on_click(
+                                cx.listener(move |this, _, cx| this.remove_binding(row_ix, cx)),
+                            ),
+                    )
+                })
+                .child(
+                    IconButton::new(("add-binding", row_ix), IconName::Plus)
+                        .icon_size(IconSize::Small)
+                        .tooltip(|cx| Tooltip::text("Add Binding", cx))
+                        .on_click(
+                            cx.listener(move |this, _, cx| this.start_edit(row_ix, false, cx)),
+                        ),
+                )
+            }
+        });
+
+        vec![
+            TableCell::element(action_name),
+            context.into(),
+            keystrokes,
+            TableCell::element(actions),
+        ]
+    }
+}
+
+impl EventEmitter<ItemEvent> for KeymapEditor {}
+
+impl FocusableView for KeymapEditor {
+    fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Item for KeymapEditor {
+    type Event = ItemEvent;
+
+    fn tab_icon(&self, _cx: &WindowContext) -> Option<Icon> {
+        Some(Icon::new(IconName::Command))
+    }
+
+    fn tab_content_text(&self, _cx: &WindowContext) -> Option<SharedString> {
+        Some("Keymap".into())
+    }
+
+    fn show_toolbar(&self) -> bool {
+        false
+    }
+
+    fn to_item_events(event: &Self::Event, mut f: impl FnMut(ItemEvent)) {
+        f(*event)
+    }
+}
+
+impl Render for KeymapEditor {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.update_rows(cx);
+        let matches = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.matches(&self.query))
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        let hidden_count = matches.len().saturating_sub(MAX_VISIBLE_ROWS);
+
+        let mut table = Table::new("keymap")
+            .striped(true)
+            .column(TableColumn::new("Action").width(relative(0.35)))
+            .column(TableColumn::new("Context").width(relative(0.25)))
+            .column(TableColumn::new("Keystrokes").width(relative(0.25)))
+            .column(TableColumn::new("").width(relative(0.15)));
+        for row_ix in matches.into_iter().take(MAX_VISIBLE_ROWS) {
+            table = table.row(self.render_row(row_ix, cx));
+        }
+
+        v_flex()
+            .id("keymap-editor")
+            .track_focus(&self.focus_handle)
+            .p_4()
+            .size_full()
+            .gap_4()
+            .overflow_y_scroll()
+            .child(Label::new("Keymap").size(LabelSize::Large))
+            .child(self.search.clone())
+            .children(self.render_pending_edit(cx))
+            .children(
+                self.error
+                    .clone()
+                    .map(|error| Label::new(error).color(Color::Error)),
+            )
+            .child(table)
+            .when(hidden_count > 0, |this| {
+                this.child(
+                    Label::new(format!(
+                        "{} more actions, refine the search to show them",
+                        hidden_count
+                    ))
+                    .color(Color::Muted),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use fs::FakeFs;
+    use gpui::{actions, KeyBinding as GpuiKeyBinding, TestAppContext};
+    use project::Project;
+    use serde_json::json;
+    use settings::SettingsStore;
+
+    use super::*;
+
+    actions!(keymap_editor_test, [Rebind]);
+
+    fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            editor::init(cx);
+            workspace::init_settings(cx);
+            Project::init_settings(cx);
+        });
+    }
+
+    #[gpui::test]
+    async fn test_rebinding_writes_user_keymap(cx: &mut TestAppContext) {
+        init_test(cx);
+        cx.update(|cx| cx.bind_keys([GpuiKeyBinding::new("ctrl-d", Rebind, Some("Editor"))]));
+
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            paths::config_dir(),
+            json!({
+                "keymap.json": r#"[
+  {
+    "context": "Editor",
+    "bindings": {
+      // Rebound from the keymap editor
+      "ctrl-d": "keymap_editor_test::Rebind",
+    }
+  }
+]
+"#
+            }),
+        )
+        .await;
+        let project = Project::test(fs.clone(), [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project.clone(), cx));
+        let keymap_editor = workspace.update(cx, |workspace, cx| {
+            let keymap_editor = KeymapEditor::new(workspace, cx);
+            workspace.add_item_to_active_pane(Box::new(keymap_editor.clone()), None, true, cx);
+            keymap_editor
+        });
+        cx.run_until_parked();
+
+        keymap_editor.update(cx, |keymap_editor, cx| {
+            let row_ix = keymap_editor
+                .rows
+                .iter()
+                .position(|row| {
+                    row.action_name.as_ref() == "keymap_editor_test::Rebind"
+                        && row.keystrokes.as_deref() == Some("ctrl-d")
+                })
+                .unwrap();
+            keymap_editor.start_edit(row_ix, true, cx);
+        });
+        cx.run_until_parked();
+
+        cx.simulate_keystrokes("alt-d");
+        // A chord is recorded once it times out waiting for its next keystroke.
+        cx.executor().advance_clock(Duration::from_secs(1));
+        cx.run_until_parked();
+        keymap_editor.update(cx, |keymap_editor, cx| {
+            let edit = keymap_editor.pending_edit.as_ref().unwrap();
+            assert_eq!(edit.new_keystrokes.as_deref(), Some("alt-d"));
+            keymap_editor.save_edit(cx);
+        });
+        cx.run_until_parked();
+
+        assert_eq!(
+            fs.load(paths::keymap_file()).await.unwrap(),
+            r#"[
+  {
+    "context": "Editor",
+    "bindings": {
+      // Rebound from the keymap editor
+      "alt-d": "keymap_editor_test::Rebind",
+    }
+  }
+]
+"#
+        );
+        keymap_editor.update(cx, |keymap_editor, _| {
+            assert!(keymap_editor.pending_edit.is_none());
+            assert!(keymap_editor.error.is_none());
+        });
+    }
+}
@@ -0,0 +1,342 @@
+use serde_json::Value;
+use ui::SharedString;
+
+/// How deep settings are looked for in nested objects, which also bounds
+/// recursive definitions.
+const MAX_DEPTH: usize = 4;
+
+/// The control editing a setting, chosen from the type of its schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingControl {
+    Switch,
+    Select(Vec<SharedString>),
+    Number {
+        integer: bool,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Text,
+}
+
+/// A setting that can be edited with a single control, found in the settings
+/// schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaSetting {
+    /// The keys leading to the setting in the settings file, e.g.
+    /// `["terminal", "font_size"]`.
+    pub key_path: Vec<String>,
+    /// The humanized keys below the section, e.g. `Scrollbar › Show`.
+    pub title: SharedString,
+    pub description: Option<SharedString>,
+    pub control: SettingControl,
+}
+
+impl SchemaSetting {
+    pub fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || self.title.to_lowercase().contains(query)
+            || self.key_path.join(".").contains(query)
+            || self.description.as_ref().map_or(false, |description| {
+                description.to_lowercase().contains(query)
+            })
+    }
+}
+
+/// The settings of a top-level key of the settings file, or the settings at the
+/// top level under `General`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaSection {
+    pub title: SharedString,
+    pub description: Option<SharedString>,
+    pub settings: Vec<SchemaSetting>,
+}
+
+/// Turns `font_size` into `Font size`.
+fn humanize_key(key: &str) -> String {
+    let mut title = key.replace('_', " ");
+    if let Some(first) = title.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    title
+}
+
+/// Follows references to definitions, and unwraps the schemas that only wrap
+/// another, like `allOf` with a single schema or an `anyOf` with `null`.
+fn resolve<'a>(root: &'a Value, mut schema: &'a Value) -> &'a Value {
+    for _ in 0..MAX_DEPTH {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let Some(definition) = reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| root.get("definitions")?.get(name))
+            else {
+                return schema;
+            };
+            schema = definition;
+            continue;
+        }
+        let wrapped = ["allOf", "anyOf", "oneOf"].iter().find_map(|key| {
+            let variants = schema.get(*key)?.as_array()?;
+            let mut non_null = variants
+                .iter()
+                .filter(|variant| variant.get("type").and_then(Value::as_str) != Some("null"));
+            let variant = non_null.next()?;
+            non_null.next().is_none().then_some(variant)
+        });
+        match wrapped {
+            Some(variant) => schema = variant,
+            None => return schema,
+        }
+    }
+    schema
+}
+
+/// The type of a schema, ignoring `null` in a list of types.
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(schema_type) => Some(schema_type),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|schema_type| *schema_type != "null"),
+        _ => None,
+    }
+}
+
+/// The string values of an enum, which schemars writes either as an `enum`, or as
+/// one schema per variant when the variants are documented.
+fn enum_values(root: &Value, schema: &Value) -> Option<Vec<SharedString>> {
+    let string_values = |values: &Vec<Value>| {
+        values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(|value| SharedString::from(value.to_string()))
+            })
+            .collect::<Option<Vec<_>>>()
+    };
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return string_values(values).filter(|values| !values.is_empty());
+    }
+    let variants = ["oneOf", "anyOf"]
+        .iter()
+        .find_map(|key| schema.get(*key)?.as_array())?;
+    let mut values = Vec::new();
+    for variant in variants {
+        let variant = resolve(root, variant);
+        if schema_type(variant) == Some("null") {
+            continue;
+        }
+        if let Some(value) = variant.get("const").and_then(Value::as_str) {
+            values.push(value.to_string().into());
+        } else {
+            values.extend(string_values(variant.get("enum")?.as_array()?)?);
+        }
+    }
+    (!values.is_empty()).then_some(values)
+}
+
+fn control_for_schema(root: &Value, schema: &Value) -> Option<SettingControl> {
+    if let Some(values) = enum_values(root, schema) {
+        return Some(SettingControl::Select(values));
+    }
+    match schema_type(schema)? {
+        "boolean" => Some(SettingControl::Switch),
+        schema_type @ ("integer" | "number") => Some(SettingControl::Number {
+            integer: schema_type == "integer",
+            min: schema.get("minimum").and_then(Value::as_f64),
+            max: schema.get("maximum").and_then(Value::as_f64),
+        }),
+        "string" => Some(SettingControl::Text),
+        _ => None,
+    }
+}
+
+fn description(schema: &Value, resolved: &Value) -> Option<SharedString> {
+    schema
+        .get("description")
+        .or_else(|| resolved.get("description"))
+        .and_then(Value::as_str)
+        .map(|description| description.to_string().into())
+}
+
+fn collect_settings(
+    root: &Value,
+    properties: &serde_json::Map<String, Value>,
+    key_path: &mut Vec<String>,
+    titles: &mut Vec<String>,
+    settings: &mut Vec<SchemaSetting>,
+) {
+    for (key, schema) in properties {
+        let resolved = resolve(root, schema);
+        key_path.push(key.clone());
+        titles.push(humanize_key(key));
+        if let Some(control) = control_for_schema(root, resolved) {
+            settings.push(SchemaSetting {
+                key_path: key_path.clone(),
+                title: titles.join(" › ").into(),
+                description: description(schema, resolved),
+                control,
+            });
+        } else if let Some(nested) = resolved.get("properties").and_then(Value::as_object) {
+            if key_path.len() < MAX_DEPTH {
+                collect_settings(root, nested, key_path, titles, settings);
+            }
+        }
+        key_path.pop();
+        titles.pop();
+    }
+}
+
+/// Finds the settings that can be edited with a single control in the JSON schema
+/// of the settings file, grouped by their top-level key. Settings that don't fit a
+/// single control, like lists and maps, are left out.
+pub fn sections_from_schema(schema: &Value) -> Vec<SchemaSection> {
+    let Some(properties) = resolve(schema, schema)
+        .get("properties")
+        .and_then(Value::as_object)
+    else {
+        return Vec::new();
+    };
+
+    let mut general = SchemaSection {
+        title: "General".into(),
+        description: None,
+        settings: Vec::new(),
+    };
+    let mut sections = Vec::new();
+    for (key, property) in properties {
+        let resolved = resolve(schema, property);
+        if let Some(control) = control_for_schema(schema, resolved) {
+            general.settings.push(SchemaSetting {
+                key_path: vec![key.clone()],
+                title: humanize_key(key).into(),
+                description: description(property, resolved),
+                control,
+            });
+        } else if let Some(nested) = resolved.get("properties").and_then(Value::as_object) {
+            let mut settings = Vec::new();
+            collect_settings(
+                schema,
+                nested,
+                &mut vec![key.clone()],
+                &mut Vec::new(),
+                &mut settings,
+            );
+            if !settings.is_empty() {
+                sections.push(SchemaSection {
+                    title: humanize_key(key).into(),
+                    description: description(property, resolved),
+                    settings,
+                });
+            }
+        }
+    }
+
+    sections.sort_by(|a, b| a.title.cmp(&b.title));
+    if !general.settings.is_empty() {
+        sections.insert(0, general);
+    }
+    sections
+}
+
+/// Returns the value at a key path of a settings file, if it's set.
+pub fn value_at_path<'a>(value: &'a Value, key_path: &[String]) -> Option<&'a Value> {
+    key_path
+        .iter()
+        .try_fold(value, |value, key| value.get(key))
+        .filter(|value| !value.is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sections_from_schema() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "vim_mode": { "type": "boolean", "description": "Whether to enable vim." },
+                "tab_size": { "type": ["integer", "null"], "minimum": 1.0 },
+                "base_keymap": { "$ref": "#/definitions/BaseKeymap" },
+                "languages": { "type": "object", "additionalProperties": {} },
+                "terminal": {
+                    "type": "object",
+                    "properties": {
+                        "font_family": { "type": "string" },
+                        "scrollbar": {
+                            "allOf": [{ "$ref": "#/definitions/Scrollbar" }],
+                        },
+                    },
+                },
+            },
+            "definitions": {
+                "BaseKeymap": {
+                    "oneOf": [
+                        { "type": "string", "enum": ["VSCode"] },
+                        { "type": "string", "enum": ["JetBrains"] },
+                    ],
+                },
+                "Scrollbar": {
+                    "type": "object",
+                    "properties": {
+                        "show": { "type": "string", "enum": ["auto", "always", "never", null] },
+                    },
+                },
+            },
+        });
+
+        let sections = sections_from_schema(&schema);
+        assert_eq!(
+            sections
+                .iter()
+                .map(|section| {
+                    let settings = section
+                        .settings
+                        .iter()
+                        .map(|setting| setting.title.to_string())
+                        .collect::<Vec<_>>();
+                    (section.title.to_string(), settings)
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "General".to_string(),
+                    vec![
+                        "Vim mode".to_string(),
+                        "Tab size".to_string(),
+                        "Base keymap".to_string()
+                    ]
+                ),
+                (
+                    "Terminal".to_string(),
+                    vec!["Font family".to_string(), "Scrollbar › Show".to_string()]
+                ),
+            ]
+        );
+
+        let general = &sections[0].settings;
+        assert_eq!(general[0].control, SettingControl::Switch);
+        assert_eq!(
+            general[1].control,
+            SettingControl::Number {
+                integer: true,
+                min: Some(1.),
+                max: None
+            }
+        );
+        assert_eq!(
+            general[2].control,
+            SettingControl::Select(vec!["VSCode".into(), "JetBrains".into()])
+        );
+        let scrollbar = &sections[1].settings[1];
+        assert_eq!(scrollbar.key_path, vec!["terminal", "scrollbar", "show"]);
+        assert_eq!(
+            scrollbar.control,
+            SettingControl::Select(vec!["auto".into(), "always".into(), "never".into()])
+        );
+    }
+}
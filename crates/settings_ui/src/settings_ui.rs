@@ -1,16 +1,28 @@
 mod appearance_settings_controls;
+mod keymap_editor;
+mod schema_settings;
 
 use std::any::TypeId;
+use std::sync::Arc;
 
+use collections::HashMap;
 use command_palette_hooks::CommandPaletteFilter;
 use editor::EditorSettingsControls;
 use feature_flags::{FeatureFlag, FeatureFlagViewExt};
-use gpui::{actions, AppContext, EventEmitter, FocusHandle, FocusableView, View};
-use ui::prelude::*;
+use fs::Fs;
+use gpui::{actions, AppContext, EventEmitter, FocusHandle, FocusableView, ReadGlobal, View};
+use serde_json::Value;
+use settings::{SettingsJsonSchemaParams, SettingsStore};
+use ui::{prelude::*, ContextMenu, DropdownMenu, SettingsRow, SettingsSection, Switch, Tooltip};
+use ui_input::{NumberInput, NumberInputEvent, SearchInput, SearchInputEvent, TextField};
 use workspace::item::{Item, ItemEvent};
 use workspace::Workspace;
 
 use crate::appearance_settings_controls::AppearanceSettingsControls;
+pub use crate::keymap_editor::KeymapEditor;
+use crate::schema_settings::{
+    sections_from_schema, value_at_path, SchemaSection, SchemaSetting, SettingControl,
+};
 
 pub struct SettingsUiFeatureFlag;
 
@@ -18,7 +30,7 @@ impl FeatureFlag for SettingsUiFeatureFlag {
     const NAME: &'static str = "settings-ui";
 }
 
-actions!(zed, [OpenSettingsEditor]);
+actions!(zed, [OpenSettingsEditor, OpenKeymapEditor]);
 
 pub fn init(cx: &mut AppContext) {
     cx.observe_new_views(|workspace: &mut Workspace, cx| {
@@ -36,8 +48,25 @@ pub fn init(cx: &mut AppContext) {
                 workspace.add_item_to_active_pane(Box::new(settings_page), None, true, cx)
             }
         });
+        workspace.register_action(|workspace, _: &OpenKeymapEditor, cx| {
+            let existing = workspace
+                .active_pane()
+                .read(cx)
+                .items()
+                .find_map(|item| item.downcast::<KeymapEditor>());
 
-        let settings_ui_actions = [TypeId::of::<OpenSettingsEditor>()];
+            if let Some(existing) = existing {
+                workspace.activate_item(&existing, true, true, cx);
+            } else {
+                let keymap_editor = KeymapEditor::new(workspace, cx);
+                workspace.add_item_to_active_pane(Box::new(keymap_editor), None, true, cx)
+            }
+        });
+
+        let settings_ui_actions = [
+            TypeId::of::<OpenSettingsEditor>(),
+            TypeId::of::<OpenKeymapEditor>(),
+        ];
 
         CommandPaletteFilter::update_global(cx, |filter, _cx| {
             filter.hide_action_types(&settings_ui_actions);
@@ -59,15 +88,279 @@ pub fn init(cx: &mut AppContext) {
     .detach();
 }
 
+/// The input of a setting edited as text, kept across frames.
+enum SettingInput {
+    Number(View<NumberInput>),
+    Text(View<TextField>),
+}
+
 pub struct SettingsPage {
     focus_handle: FocusHandle,
+    fs: Arc<dyn Fs>,
+    search: View<SearchInput>,
+    query: String,
+    /// The settings found in the settings schema.
+    sections: Vec<SchemaSection>,
+    inputs: HashMap<String, SettingInput>,
+    _subscriptions: Vec<gpui::Subscription>,
+}
+
+/// Writes a setting to the user's settings file. A `null` value resets it.
+fn write_setting(fs: &Arc<dyn Fs>, key_path: &[String], value: Value, cx: &AppContext) {
+    SettingsStore::global(cx).update_settings_file_at_path(fs.clone(), key_path.to_vec(), value);
+}
+
+/// The value of a setting, from the user's settings or the defaults.
+fn setting_value(key_path: &[String], cx: &AppContext) -> Option<Value> {
+    let store = SettingsStore::global(cx);
+    value_at_path(store.raw_user_settings(), key_path)
+        .or_else(|| value_at_path(store.raw_default_settings(), key_path))
+        .cloned()
+}
+
+fn is_customized(key_path: &[String], cx: &AppContext) -> bool {
+    value_at_path(SettingsStore::global(cx).raw_user_settings(), key_path).is_some()
 }
 
 impl SettingsPage {
-    pub fn new(_workspace: &Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
-        cx.new_view(|cx| Self {
-            focus_handle: cx.focus_handle(),
+    pub fn new(workspace: &Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
+        let fs = workspace.app_state().fs.clone();
+        cx.new_view(|cx| {
+            let search = cx.new_view(|cx| SearchInput::new("Search settings…", cx));
+            let schema = SettingsStore::global(cx).json_schema(
+                &SettingsJsonSchemaParams {
+                    staff_mode: false,
+                    language_names: &[],
+                    font_names: &[],
+                },
+                cx,
+            );
+            let subscriptions = vec![
+                cx.subscribe(&search, |this, _, event: &SearchInputEvent, cx| {
+                    let SearchInputEvent::QueryChanged(query) = event;
+                    this.query = query.to_lowercase();
+                    cx.notify();
+                }),
+                cx.observe_global::<SettingsStore>(|this, cx| this.sync_inputs(cx)),
+            ];
+
+            Self {
+                focus_handle: cx.focus_handle(),
+                fs,
+                search,
+                query: String::new(),
+                sections: sections_from_schema(&schema),
+                inputs: HashMap::default(),
+                _subscriptions: subscriptions,
+            }
+        })
+    }
+
+    /// Shows the values of settings changed elsewhere, e.g. in the settings file,
+    /// in the inputs that aren't being edited.
+    fn sync_inputs(&mut self, cx: &mut ViewContext<Self>) {
+        for (key, input) in &self.inputs {
+            let key_path = key.split('.').map(String::from).collect::<Vec<_>>();
+            let value = setting_value(&key_path, cx);
+            match input {
+                SettingInput::Number(input) => {
+                    let Some(value) = value.as_ref().and_then(Value::as_f64) else {
+                        continue;
+                    };
+                    if !input.read(cx).focus_handle(cx).is_focused(cx)
+                        && input.read(cx).value() != value
+                    {
+                        input.update(cx, |input, cx| input.set_value(value, cx));
+                    }
+                }
+                SettingInput::Text(input) => {
+                    let editor = input.read(cx).editor().clone();
+                    let text = value
+                        .as_ref()
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string();
+                    if !editor.focus_handle(cx).is_focused(cx) && editor.read(cx).text(cx) != text {
+                        editor.update(cx, |editor, cx| editor.set_text(text, cx));
+                    }
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    fn number_input(
+        &mut self,
+        setting: &SchemaSetting,
+        integer: bool,
+        min: Option<f64>,
+        max: Option<f64>,
+        cx: &mut ViewContext<Self>,
+    ) -> View<NumberInput> {
+        let key = setting.key_path.join(".");
+        if let Some(SettingInput::Number(input)) = self.inputs.get(&key) {
+            return input.clone();
+        }
+        let value = setting_value(&setting.key_path, cx)
+            .and_then(|value| value.as_f64())
+            .unwrap_or_default();
+        let input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(value, cx);
+            input.set_range(min, max);
+            if integer {
+                input.set_precision(0, cx);
+            }
+            input
+        });
+        let fs = self.fs.clone();
+        let key_path = setting.key_path.clone();
+        cx.subscribe(&input, move |_, _, event: &NumberInputEvent, cx| {
+            let NumberInputEvent::Changed(value) = event;
+            let current = setting_value(&key_path, cx).and_then(|value| value.as_f64());
+            if current != Some(*value) {
+                let value = if integer {
+                    Value::from(value.round() as i64)
+                } else {
+                    Value::from(*value)
+                };
+                write_setting(&fs, &key_path, value, cx);
+            }
         })
+        .detach();
+        self.inputs.insert(key, SettingInput::Number(input.clone()));
+        input
+    }
+
+    fn text_input(
+        &mut self,
+        setting: &SchemaSetting,
+        cx: &mut ViewContext<Self>,
+    ) -> View<TextField> {
+        let key = setting.key_path.join(".");
+        if let Some(SettingInput::Text(input)) = self.inputs.get(&key) {
+            return input.clone();
+        }
+        let value = setting_value(&setting.key_path, cx)
+            .and_then(|value| value.as_str().map(String::from))
+            .unwrap_or_default();
+        let input = cx.new_view(|cx| TextField::new(cx, setting.title.clone(), ""));
+        let editor = input.read(cx).editor().clone();
+        editor.update(cx, |editor, cx| editor.set_text(value, cx));
+
+        // The text is written once it's confirmed by leaving the field, rather than
+        // on every keystroke.
+        let fs = self.fs.clone();
+        let key_path = setting.key_path.clone();
+        let editor_handle = editor.clone();
+        cx.on_blur(&editor.focus_handle(cx), move |_, cx| {
+            let text = editor_handle.read(cx).text(cx);
+            let current = setting_value(&key_path, cx);
+            if current.as_ref().and_then(Value::as_str) != Some(text.as_str()) {
+                write_setting(&fs, &key_path, Value::String(text), cx);
+            }
+        })
+        .detach();
+        self.inputs.insert(key, SettingInput::Text(input.clone()));
+        input
+    }
+
+    fn render_setting(
+        &mut self,
+        setting: &SchemaSetting,
+        cx: &mut ViewContext<Self>,
+    ) -> SettingsRow {
+        let key = setting.key_path.join(".");
+        let value = setting_value(&setting.key_path, cx);
+        let fs = self.fs.clone();
+        let key_path = setting.key_path.clone();
+
+        let control = match &setting.control {
+            SettingControl::Switch => {
+                let on = value.as_ref().and_then(Value::as_bool).unwrap_or(false);
+                Switch::new(SharedString::from(format!("{key}-switch")), on)
+                    .on_click(move |on, cx| write_setting(&fs, &key_path, Value::Bool(*on), cx))
+                    .into_any_element()
+            }
+            SettingControl::Select(options) => {
+                let selected = value
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                let options = options.clone();
+                DropdownMenu::new(
+                    SharedString::from(format!("{key}-select")),
+                    selected,
+                    ContextMenu::build(cx, move |mut menu, _| {
+                        for option in options {
+                            let fs = fs.clone();
+                            let key_path = key_path.clone();
+                            let value = Value::String(option.to_string());
+                            menu = menu.entry(option, None, move |cx| {
+                                write_setting(&fs, &key_path, value.clone(), cx)
+                            });
+                        }
+                        menu
+                    }),
+                )
+                .into_any_element()
+            }
+            SettingControl::Number { integer, min, max } => {
+                let input = self.number_input(setting, *integer, *min, *max, cx);
+                div().w(rems(8.)).child(input).into_any_element()
+            }
+            SettingControl::Text => {
+                let input = self.text_input(setting, cx);
+                div().w(rems(16.)).child(input).into_any_element()
+            }
+        };
+
+        let reset = is_customized(&setting.key_path, cx).then(|| {
+            let fs = self.fs.clone();
+            let key_path = setting.key_path.clone();
+            IconButton::new(
+                SharedString::from(format!("{key}-reset")),
+                IconName::RotateCcw,
+            )
+            .icon_size(IconSize::Small)
+            .icon_color(Color::Muted)
+            .tooltip(|cx| Tooltip::text("Reset to Default", cx))
+            .on_click(move |_, cx| write_setting(&fs, &key_path, Value::Null, cx))
+        });
+
+        SettingsRow::new(setting.title.clone())
+            .when_some(setting.description.clone(), |this, description| {
+                this.description(description)
+            })
+            .control(h_flex().gap_1().children(reset).child(control))
+    }
+
+    fn render_sections(&mut self, cx: &mut ViewContext<Self>) -> Vec<SettingsSection> {
+        let sections = std::mem::take(&mut self.sections);
+        let query = self.query.clone();
+        let rendered = sections
+            .iter()
+            .filter_map(|section| {
+                let rows = section
+                    .settings
+                    .iter()
+                    .filter(|setting| setting.matches(&query))
+                    .map(|setting| self.render_setting(setting, cx))
+                    .collect::<Vec<_>>();
+                if rows.is_empty() {
+                    return None;
+                }
+                Some(
+                    SettingsSection::new(section.title.clone())
+                        .when_some(section.description.clone(), |this, description| {
+                            this.description(description)
+                        })
+                        .children(rows),
+                )
+            })
+            .collect();
+        self.sections = sections;
+        rendered
     }
 }
 
@@ -101,24 +394,37 @@ impl Item for SettingsPage {
 
 impl Render for SettingsPage {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let sections = self.render_sections(cx);
+        let is_searching = !self.query.is_empty();
+
         v_flex()
+            .id("settings-page")
+            .track_focus(&self.focus_handle)
             .p_4()
             .size_full()
             .gap_4()
+            .overflow_y_scroll()
             .child(Label::new("Settings").size(LabelSize::Large))
-            .child(
-                v_flex().gap_1().child(Label::new("Appearance")).child(
-                    v_flex()
-                        .elevation_2(cx)
-                        .child(AppearanceSettingsControls::new()),
-                ),
-            )
-            .child(
-                v_flex().gap_1().child(Label::new("Editor")).child(
-                    v_flex()
-                        .elevation_2(cx)
-                        .child(EditorSettingsControls::new()),
-                ),
-            )
+            .child(self.search.clone())
+            .when(!is_searching, |this| {
+                this.child(
+                    v_flex().gap_1().child(Label::new("Appearance")).child(
+                        v_flex()
+                            .elevation_2(cx)
+                            .child(AppearanceSettingsControls::new()),
+                    ),
+                )
+                .child(
+                    v_flex().gap_1().child(Label::new("Editor")).child(
+                        v_flex()
+                            .elevation_2(cx)
+                            .child(EditorSettingsControls::new()),
+                    ),
+                )
+            })
+            .when(is_searching && sections.is_empty(), |this| {
+                this.child(Label::new("No matching settings").color(Color::Muted))
+            })
+            .children(sections)
     }
 }
@@ -0,0 +1,115 @@
+use gpui::{AppContext, Global, SharedString};
+
+/// The value of a [`StoryControl`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoryControlValue {
+    Toggle(bool),
+    Choice {
+        options: Vec<SharedString>,
+        selected: usize,
+    },
+}
+
+/// A prop of the components shown by a story that can be changed while the story
+/// is running, e.g. from the controls panel of the storybook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoryControl {
+    pub name: SharedString,
+    pub value: StoryControlValue,
+}
+
+/// The controls registered by the stories rendered in the app.
+///
+/// Stories read a control while rendering, which registers it with its default
+/// value the first time:
+///
+/// ```ignore
+/// let strikethrough = StoryControls::toggle("Strikethrough", false, cx);
+/// let color = StoryControls::choice("Color", &["Default", "Muted", "Error"], 0, cx);
+/// ```
+#[derive(Default)]
+pub struct StoryControls {
+    controls: Vec<StoryControl>,
+}
+
+impl Global for StoryControls {}
+
+impl StoryControls {
+    fn control_mut<'a>(
+        name: &str,
+        default: impl FnOnce() -> StoryControlValue,
+        cx: &'a mut AppContext,
+    ) -> &'a mut StoryControl {
+        let existing_ix = cx
+            .default_global::<Self>()
+            .controls
+            .iter()
+            .position(|control| control.name.as_ref() == name);
+        let ix = match existing_ix {
+            Some(ix) => ix,
+            None => {
+                // Controls are registered while rendering a story, so redraw to show
+                // them in the panels that were already rendered this frame.
+                cx.refresh();
+                let controls = &mut cx.global_mut::<Self>().controls;
+                controls.push(StoryControl {
+                    name: SharedString::from(name.to_string()),
+                    value: default(),
+                });
+                controls.len() - 1
+            }
+        };
+        &mut cx.global_mut::<Self>().controls[ix]
+    }
+
+    /// Returns the value of a toggle control, registering it if needed.
+    pub fn toggle(name: &str, default: bool, cx: &mut AppContext) -> bool {
+        match Self::control_mut(name, || StoryControlValue::Toggle(default), cx).value {
+            StoryControlValue::Toggle(value) => value,
+            StoryControlValue::Choice { .. } => default,
+        }
+    }
+
+    /// Returns the index of the selected option of a choice control, registering it
+    /// if needed.
+    pub fn choice(name: &str, options: &[&str], default: usize, cx: &mut AppContext) -> usize {
+        let default_options = || {
+            options
+                .iter()
+                .map(|option| SharedString::from(option.to_string()))
+                .collect()
+        };
+        match &Self::control_mut(
+            name,
+            || StoryControlValue::Choice {
+                options: default_options(),
+                selected: default,
+            },
+            cx,
+        )
+        .value
+        {
+            StoryControlValue::Choice { selected, .. } => *selected,
+            StoryControlValue::Toggle(_) => default,
+        }
+    }
+
+    /// Returns the registered controls, in the order they were registered.
+    pub fn controls(cx: &AppContext) -> &[StoryControl] {
+        cx.try_global::<Self>()
+            .map(|controls| controls.controls.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Changes the value of a control and redraws the stories reading it.
+    pub fn set(name: &str, value: StoryControlValue, cx: &mut AppContext) {
+        let control = Self::control_mut(name, || value.clone(), cx);
+        control.value = value;
+        cx.refresh();
+    }
+
+    /// Removes all controls, e.g. when switching to another story.
+    pub fn clear(cx: &mut AppContext) {
+        cx.default_global::<Self>().controls.clear();
+    }
+}
@@ -0,0 +1,66 @@
+use gpui::{AnyView, WindowContext};
+
+/// A story registered with [`register_story!`], discovered by the storybook and
+/// by tests rendering every story, without wiring it up in either.
+#[derive(Debug)]
+pub struct StoryRegistration {
+    /// The name of the story, in `snake_case`, like `"icon_button"`.
+    pub name: &'static str,
+    /// Opens the story in a window.
+    pub build: fn(&mut WindowContext) -> AnyView,
+}
+
+impl PartialEq for StoryRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for StoryRegistration {}
+
+/// This constant must be public to be accessible from [`register_story!`].
+/// But its existence is an implementation detail and should not be used directly.
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static __STORIES: [StoryRegistration];
+
+/// Returns the stories registered by all the crates linked into the binary,
+/// sorted by name.
+pub fn registered_stories() -> Vec<&'static StoryRegistration> {
+    let mut stories = __STORIES.iter().collect::<Vec<_>>();
+    stories.sort_by_key(|story| story.name);
+    stories
+}
+
+/// Returns the registered story with the given name.
+pub fn registered_story(name: &str) -> Option<&'static StoryRegistration> {
+    __STORIES.iter().find(|story| story.name == name)
+}
+
+/// Registers a story, so it's discovered by the storybook. Takes the name of the
+/// story and either the type of a story view without fields, or a function opening
+/// the story:
+///
+/// ```ignore
+/// register_story!("checkbox", CheckboxStory);
+/// register_story!("picker", |cx| PickerStory::new(cx).into());
+/// ```
+#[macro_export]
+macro_rules! register_story {
+    ($name:literal, $story:ident) => {
+        $crate::register_story!($name, |cx| {
+            use ::gpui::VisualContext as _;
+            cx.new_view(|_| $story).into()
+        });
+    };
+    ($name:literal, $build:expr) => {
+        const _: () = {
+            #[$crate::private::linkme::distributed_slice($crate::__STORIES)]
+            #[linkme(crate = $crate::private::linkme)]
+            static STORY: $crate::StoryRegistration = $crate::StoryRegistration {
+                name: $name,
+                build: $build,
+            };
+        };
+    };
+}
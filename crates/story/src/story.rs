@@ -1,3 +1,17 @@
+mod controls;
+mod registry;
+mod variants;
+
+pub use controls::*;
+pub use registry::*;
+pub use variants::*;
+
+/// Do not touch, here be dragons for use by [`register_story!`].
+#[doc(hidden)]
+pub mod private {
+    pub use linkme;
+}
+
 use gpui::{
     div, prelude::*, px, rems, AnyElement, DefaultColor, DefaultColors, Div, SharedString,
     WindowContext,
@@ -0,0 +1,172 @@
+use gpui::AppContext;
+use itertools::Itertools;
+
+use crate::{
+    registered_stories, StoryControl, StoryControlValue, StoryControls, StoryRegistration,
+};
+
+/// A story rendered with one combination of the values of its controls, e.g. to
+/// snapshot it in a visual test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoryVariant {
+    pub story: &'static StoryRegistration,
+    /// The value of each control of the story, in the order they're registered.
+    pub controls: Vec<StoryControl>,
+}
+
+impl StoryVariant {
+    /// Returns a name identifying the variant, usable as a file name, like
+    /// `"label-color_muted-italic_on"`.
+    pub fn name(&self) -> String {
+        let mut name = self.story.name.to_string();
+        for control in &self.controls {
+            let value = match &control.value {
+                StoryControlValue::Toggle(true) => "on".into(),
+                StoryControlValue::Toggle(false) => "off".into(),
+                StoryControlValue::Choice { options, selected } => options
+                    .get(*selected)
+                    .map(|option| slug(option))
+                    .unwrap_or_else(|| selected.to_string()),
+            };
+            name.push('-');
+            name.push_str(&slug(&control.name));
+            name.push('_');
+            name.push_str(&value);
+        }
+        name
+    }
+
+    /// Sets the controls of the story to the values of this variant. The story
+    /// must be rendered again to show them.
+    pub fn apply(&self, cx: &mut AppContext) {
+        StoryControls::clear(cx);
+        for control in &self.controls {
+            StoryControls::set(&control.name, control.value.clone(), cx);
+        }
+    }
+}
+
+fn slug(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .join("_")
+}
+
+/// Returns every combination of the values of the given controls: both values of
+/// each toggle, and each option of each choice.
+pub fn variant_matrix(controls: &[StoryControl]) -> Vec<Vec<StoryControl>> {
+    if controls.is_empty() {
+        return vec![Vec::new()];
+    }
+    controls
+        .iter()
+        .map(|control| {
+            let values = match &control.value {
+                StoryControlValue::Toggle(_) => vec![
+                    StoryControlValue::Toggle(false),
+                    StoryControlValue::Toggle(true),
+                ],
+                StoryControlValue::Choice { options, .. } => (0..options.len().max(1))
+                    .map(|selected| StoryControlValue::Choice {
+                        options: options.clone(),
+                        selected,
+                    })
+                    .collect(),
+            };
+            values.into_iter().map(|value| StoryControl {
+                name: control.name.clone(),
+                value,
+            })
+        })
+        .multi_cartesian_product()
+        .collect()
+}
+
+/// Returns every variant of every registered story, sorted by story name, without
+/// opening the storybook.
+///
+/// Stories register their controls while rendering, so `discover_controls` must
+/// render the given story once, and return the controls it registered:
+///
+/// ```ignore
+/// let variants = story::registered_story_variants(|story| {
+///     cx.update(|cx| StoryControls::clear(cx));
+///     cx.draw(point(px(0.), px(0.)), size, |cx| (story.build)(cx));
+///     cx.update(|cx| StoryControls::controls(cx).to_vec())
+/// });
+/// for variant in variants {
+///     cx.update(|cx| variant.apply(cx));
+///     cx.draw(point(px(0.), px(0.)), size, |cx| (variant.story.build)(cx));
+///     // Snapshot the window as `variant.name()`.
+/// }
+/// ```
+pub fn registered_story_variants(
+    mut discover_controls: impl FnMut(&'static StoryRegistration) -> Vec<StoryControl>,
+) -> Vec<StoryVariant> {
+    registered_stories()
+        .into_iter()
+        .flat_map(|story| {
+            variant_matrix(&discover_controls(story))
+                .into_iter()
+                .map(move |controls| StoryVariant { story, controls })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static STORY: StoryRegistration = StoryRegistration {
+        name: "label",
+        build: |_| unreachable!(),
+    };
+
+    fn toggle(name: &'static str) -> StoryControl {
+        StoryControl {
+            name: name.into(),
+            value: StoryControlValue::Toggle(false),
+        }
+    }
+
+    fn choice(name: &'static str, options: &[&'static str]) -> StoryControl {
+        StoryControl {
+            name: name.into(),
+            value: StoryControlValue::Choice {
+                options: options.iter().map(|option| (*option).into()).collect(),
+                selected: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_variant_matrix() {
+        assert_eq!(variant_matrix(&[]), vec![Vec::<StoryControl>::new()]);
+
+        let names = variant_matrix(&[
+            choice("Color", &["Default", "Muted", "Error"]),
+            toggle("Strike Through"),
+        ])
+        .into_iter()
+        .map(|controls| {
+            StoryVariant {
+                story: &STORY,
+                controls,
+            }
+            .name()
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "label-color_default-strike_through_off",
+                "label-color_default-strike_through_on",
+                "label-color_muted-strike_through_off",
+                "label-color_muted-strike_through_on",
+                "label-color_error-strike_through_off",
+                "label-color_error-strike_through_on",
+            ]
+        );
+    }
+}
@@ -8,6 +8,9 @@ pub struct AutoHeightEditorStory {
     editor: View<Editor>,
 }
 
+story::register_story!("auto_height_editor", |cx| AutoHeightEditorStory::new(cx)
+    .into());
+
 impl AutoHeightEditorStory {
     pub fn new(cx: &mut WindowContext) -> View<Self> {
         cx.bind_keys([KeyBinding::new(
@@ -4,6 +4,8 @@ use ui::prelude::*;
 
 pub struct CursorStory;
 
+story::register_story!("cursor", CursorStory);
+
 impl Render for CursorStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         let all_cursors: [(&str, Box<dyn Fn(Stateful<Div>) -> Stateful<Div>>); 19] = [
@@ -8,6 +8,8 @@ use ui::{h_flex, ActiveTheme};
 
 pub struct DefaultColorsStory;
 
+story::register_story!("default_colors", |cx| DefaultColorsStory::view(cx).into());
+
 impl DefaultColorsStory {
     pub fn view(cx: &mut WindowContext) -> View<Self> {
         cx.new_view(|_cx| Self)
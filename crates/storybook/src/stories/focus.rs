@@ -12,6 +12,8 @@ pub struct FocusStory {
     _focus_subscriptions: Vec<Subscription>,
 }
 
+story::register_story!("focus", |cx| FocusStory::view(cx).into());
+
 impl FocusStory {
     pub fn view(cx: &mut WindowContext) -> View<Self> {
         cx.bind_keys([
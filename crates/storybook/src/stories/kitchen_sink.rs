@@ -1,6 +1,5 @@
 use gpui::{prelude::*, Render, View};
 use story::Story;
-use strum::IntoEnumIterator;
 use ui::prelude::*;
 
 use crate::story_selector::ComponentStory;
@@ -15,7 +14,8 @@ impl KitchenSinkStory {
 
 impl Render for KitchenSinkStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let component_stories = ComponentStory::iter()
+        let component_stories = ComponentStory::all()
+            .iter()
             .map(|selector| selector.story(cx))
             .collect::<Vec<_>>();
 
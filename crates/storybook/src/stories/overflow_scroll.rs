@@ -5,6 +5,8 @@ use ui::prelude::*;
 
 pub struct OverflowScrollStory;
 
+story::register_story!("overflow_scroll", OverflowScrollStory);
+
 impl Render for OverflowScrollStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -112,6 +112,8 @@ impl PickerDelegate for Delegate {
     }
 }
 
+story::register_story!("picker", |cx| PickerStory::new(cx).into());
+
 impl PickerStory {
     pub fn new(cx: &mut WindowContext) -> View<Self> {
         cx.new_view(|cx| {
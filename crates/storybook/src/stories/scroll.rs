@@ -4,6 +4,8 @@ use ui::Tooltip;
 
 pub struct ScrollStory;
 
+story::register_story!("scroll", |cx| ScrollStory::view(cx).into());
+
 impl ScrollStory {
     pub fn view(cx: &mut WindowContext) -> View<ScrollStory> {
         cx.new_view(|_cx| ScrollStory)
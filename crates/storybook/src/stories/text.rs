@@ -7,6 +7,8 @@ use story::*;
 
 pub struct TextStory;
 
+story::register_story!("text", |cx| TextStory::view(cx).into());
+
 impl TextStory {
     pub fn view(cx: &mut WindowContext) -> View<Self> {
         cx.new_view(|_cx| Self)
@@ -5,6 +5,8 @@ use ui::prelude::*;
 
 pub struct ViewportUnitsStory;
 
+story::register_story!("viewport_units", ViewportUnitsStory);
+
 impl Render for ViewportUnitsStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container().child(
@@ -5,6 +5,8 @@ use ui::{prelude::*, utils::WithRemSize};
 
 pub struct WithRemSizeStory;
 
+story::register_story!("with_rem_size", WithRemSizeStory);
+
 impl Render for WithRemSizeStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container().child(
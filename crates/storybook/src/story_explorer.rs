@@ -0,0 +1,201 @@
+use editor::{Editor, EditorEvent};
+use gpui::{AnyView, Render, Subscription, View, ViewContext, VisualContext, WindowContext};
+use story::{StoryControlValue, StoryControls};
+use ui::{prelude::*, Checkbox, Divider, ListItem, Selection};
+
+use crate::story_selector::ComponentStory;
+
+/// A workbench for components: a searchable sidebar of all the stories, the
+/// selected story, and a panel of the controls it registered with
+/// [`StoryControls`] to change its components' props while it runs.
+pub struct StoryExplorer {
+    query_editor: View<Editor>,
+    selected_story: Option<ComponentStory>,
+    story: Option<AnyView>,
+    _query_subscription: Subscription,
+}
+
+impl StoryExplorer {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|cx| {
+            let query_editor = cx.new_view(|cx| {
+                let mut editor = Editor::single_line(cx);
+                editor.set_placeholder_text("Search stories…", cx);
+                editor
+            });
+            let query_subscription =
+                cx.subscribe(&query_editor, |_, _, event: &EditorEvent, cx| {
+                    if let EditorEvent::BufferEdited = event {
+                        cx.notify();
+                    }
+                });
+
+            Self {
+                query_editor,
+                selected_story: None,
+                story: None,
+                _query_subscription: query_subscription,
+            }
+        })
+    }
+
+    fn select_story(&mut self, story: ComponentStory, cx: &mut ViewContext<Self>) {
+        if self.selected_story == Some(story) {
+            return;
+        }
+        StoryControls::clear(cx);
+        self.selected_story = Some(story);
+        self.story = Some(story.story(cx));
+        cx.notify();
+    }
+
+    fn matching_stories(&self, cx: &WindowContext) -> Vec<ComponentStory> {
+        let query = self.query_editor.read(cx).text(cx).to_lowercase();
+        let query = query.trim();
+        ComponentStory::all()
+            .iter()
+            .copied()
+            .filter(|story| story.name().replace('_', " ").contains(query))
+            .collect()
+    }
+
+    fn render_sidebar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let stories = self.matching_stories(cx);
+
+        v_flex()
+            .flex_none()
+            .w(px(240.))
+            .h_full()
+            .border_r_1()
+            .border_color(cx.theme().colors().border)
+            .bg(cx.theme().colors().panel_background)
+            .child(
+                div()
+                    .p_2()
+                    .border_b_1()
+                    .border_color(cx.theme().colors().border_variant)
+                    .child(self.query_editor.clone()),
+            )
+            .child(
+                v_flex()
+                    .id("stories")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .p_1()
+                    .when(stories.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .p_2()
+                                .child(Label::new("No matching stories").color(Color::Muted)),
+                        )
+                    })
+                    .children(stories.into_iter().map(|story| {
+                        ListItem::new(story.name())
+                            .inset(true)
+                            .selected(self.selected_story == Some(story))
+                            .on_click(cx.listener(move |this, _, cx| this.select_story(story, cx)))
+                            .child(Label::new(story.name().replace('_', " ")))
+                    })),
+            )
+    }
+
+    fn render_controls(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let controls = StoryControls::controls(cx).to_vec();
+
+        v_flex()
+            .id("story-controls")
+            .flex_none()
+            .w(px(240.))
+            .h_full()
+            .overflow_y_scroll()
+            .p_2()
+            .gap_2()
+            .border_l_1()
+            .border_color(cx.theme().colors().border)
+            .bg(cx.theme().colors().panel_background)
+            .child(
+                Label::new("Controls")
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(Divider::horizontal())
+            .when(controls.is_empty(), |this| {
+                this.child(Label::new("This story has no controls.").color(Color::Muted))
+            })
+            .children(controls.into_iter().enumerate().map(|(ix, control)| {
+                let name = control.name.clone();
+                match control.value {
+                    StoryControlValue::Toggle(value) => h_flex()
+                        .gap_2()
+                        .child(Checkbox::new(("control", ix), value.into()).on_click(
+                            move |selection, cx| {
+                                StoryControls::set(
+                                    &name,
+                                    StoryControlValue::Toggle(*selection == Selection::Selected),
+                                    cx,
+                                )
+                            },
+                        ))
+                        .child(Label::new(control.name))
+                        .into_any_element(),
+                    StoryControlValue::Choice { options, selected } => v_flex()
+                        .gap_1()
+                        .child(Label::new(control.name))
+                        .child(render_choice_options(ix, name, options, selected))
+                        .into_any_element(),
+                }
+            }))
+    }
+}
+
+fn render_choice_options(
+    control_ix: usize,
+    name: SharedString,
+    options: Vec<SharedString>,
+    selected: usize,
+) -> impl IntoElement {
+    h_flex()
+        .flex_wrap()
+        .gap_1()
+        .children(options.iter().enumerate().map(|(option_ix, option)| {
+            let name = name.clone();
+            let options = options.clone();
+            Button::new(
+                SharedString::from(format!("control-{control_ix}-{option_ix}")),
+                option.clone(),
+            )
+            .style(ButtonStyle::Filled)
+            .selected(option_ix == selected)
+            .on_click(move |_, cx| {
+                StoryControls::set(
+                    &name,
+                    StoryControlValue::Choice {
+                        options: options.clone(),
+                        selected: option_ix,
+                    },
+                    cx,
+                )
+            })
+        }))
+}
+
+impl Render for StoryExplorer {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .size_full()
+            .bg(cx.theme().colors().background)
+            .text_color(cx.theme().colors().text)
+            .child(self.render_sidebar(cx))
+            .child(div().flex_1().h_full().overflow_hidden().map(|this| {
+                match &self.story {
+                    Some(story) => this.child(story.clone()),
+                    None => {
+                        this.flex().items_center().justify_center().child(
+                            Label::new("Select a story from the sidebar").color(Color::Muted),
+                        )
+                    }
+                }
+            }))
+            .child(self.render_controls(cx))
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::str::FromStr;
 use std::sync::OnceLock;
 
@@ -5,81 +6,49 @@ use crate::stories::*;
 use anyhow::anyhow;
 use clap::builder::PossibleValue;
 use clap::ValueEnum;
-use gpui::{AnyView, VisualContext};
-use strum::{EnumIter, EnumString, IntoEnumIterator};
+use gpui::AnyView;
+use story::StoryRegistration;
 use ui::prelude::*;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, strum::Display, EnumString, EnumIter)]
-#[strum(serialize_all = "snake_case")]
-pub enum ComponentStory {
-    ApplicationMenu,
-    AutoHeightEditor,
-    Avatar,
-    Button,
-    Checkbox,
-    CollabNotification,
-    ContextMenu,
-    Cursor,
-    DefaultColors,
-    Disclosure,
-    Focus,
-    Icon,
-    IconButton,
-    Keybinding,
-    Label,
-    List,
-    ListHeader,
-    ListItem,
-    OverflowScroll,
-    Picker,
-    Scroll,
-    Tab,
-    TabBar,
-    Text,
-    ToggleButton,
-    ToolStrip,
-    ViewportUnits,
-    WithRemSize,
-    Vector,
-}
+/// A story registered with [`story::register_story!`] by any of the crates linked
+/// into the storybook.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ComponentStory(&'static StoryRegistration);
 
 impl ComponentStory {
+    /// Returns all the registered stories, sorted by name.
+    pub fn all() -> &'static [ComponentStory] {
+        static ALL_COMPONENT_STORIES: OnceLock<Vec<ComponentStory>> = OnceLock::new();
+        ALL_COMPONENT_STORIES.get_or_init(|| {
+            story::registered_stories()
+                .into_iter()
+                .map(ComponentStory)
+                .collect()
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0.name
+    }
+
     pub fn story(&self, cx: &mut WindowContext) -> AnyView {
-        match self {
-            Self::ApplicationMenu => cx
-                .new_view(|cx| title_bar::ApplicationMenuStory::new(cx))
-                .into(),
-            Self::AutoHeightEditor => AutoHeightEditorStory::new(cx).into(),
-            Self::Avatar => cx.new_view(|_| ui::AvatarStory).into(),
-            Self::Button => cx.new_view(|_| ui::ButtonStory).into(),
-            Self::Checkbox => cx.new_view(|_| ui::CheckboxStory).into(),
-            Self::CollabNotification => cx
-                .new_view(|_| collab_ui::notifications::CollabNotificationStory)
-                .into(),
-            Self::ContextMenu => cx.new_view(|_| ui::ContextMenuStory).into(),
-            Self::Cursor => cx.new_view(|_| crate::stories::CursorStory).into(),
-            Self::DefaultColors => DefaultColorsStory::view(cx).into(),
-            Self::Disclosure => cx.new_view(|_| ui::DisclosureStory).into(),
-            Self::Focus => FocusStory::view(cx).into(),
-            Self::Icon => cx.new_view(|_| ui::IconStory).into(),
-            Self::IconButton => cx.new_view(|_| ui::IconButtonStory).into(),
-            Self::Keybinding => cx.new_view(|_| ui::KeybindingStory).into(),
-            Self::Label => cx.new_view(|_| ui::LabelStory).into(),
-            Self::List => cx.new_view(|_| ui::ListStory).into(),
-            Self::ListHeader => cx.new_view(|_| ui::ListHeaderStory).into(),
-            Self::ListItem => cx.new_view(|_| ui::ListItemStory).into(),
-            Self::OverflowScroll => cx.new_view(|_| crate::stories::OverflowScrollStory).into(),
-            Self::Picker => PickerStory::new(cx).into(),
-            Self::Scroll => ScrollStory::view(cx).into(),
-            Self::Tab => cx.new_view(|_| ui::TabStory).into(),
-            Self::TabBar => cx.new_view(|_| ui::TabBarStory).into(),
-            Self::Text => TextStory::view(cx).into(),
-            Self::ToggleButton => cx.new_view(|_| ui::ToggleButtonStory).into(),
-            Self::ToolStrip => cx.new_view(|_| ui::ToolStripStory).into(),
-            Self::ViewportUnits => cx.new_view(|_| crate::stories::ViewportUnitsStory).into(),
-            Self::WithRemSize => cx.new_view(|_| crate::stories::WithRemSizeStory).into(),
-            Self::Vector => cx.new_view(|_| ui::VectorStory).into(),
-        }
+        (self.0.build)(cx)
+    }
+}
+
+impl fmt::Display for ComponentStory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ComponentStory {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+        story::registered_story(name)
+            .map(ComponentStory)
+            .ok_or_else(|| anyhow!("story not found for '{name}'"))
     }
 }
 
@@ -127,7 +96,10 @@ static ALL_STORY_SELECTORS: OnceLock<Vec<StorySelector>> = OnceLock::new();
 impl ValueEnum for StorySelector {
     fn value_variants<'a>() -> &'a [Self] {
         let stories = ALL_STORY_SELECTORS.get_or_init(|| {
-            let component_stories = ComponentStory::iter().map(StorySelector::Component);
+            let component_stories = ComponentStory::all()
+                .iter()
+                .copied()
+                .map(StorySelector::Component);
 
             component_stories
                 .chain(std::iter::once(StorySelector::KitchenSink))
@@ -2,6 +2,7 @@ mod actions;
 mod app_menus;
 mod assets;
 mod stories;
+mod story_explorer;
 mod story_selector;
 
 use std::sync::Arc;
@@ -17,12 +18,12 @@ use project::Project;
 use reqwest_client::ReqwestClient;
 use settings::{KeymapFile, Settings};
 use simplelog::SimpleLogger;
-use strum::IntoEnumIterator;
 use theme::{ThemeRegistry, ThemeSettings};
 use ui::prelude::*;
 
 use crate::app_menus::app_menus;
 use crate::assets::Assets;
+use crate::story_explorer::StoryExplorer;
 use crate::story_selector::{ComponentStory, StorySelector};
 use actions::Quit;
 pub use indoc::indoc;
@@ -38,6 +39,11 @@ struct Args {
     /// If not provided, the default theme will be used.
     #[arg(long)]
     theme: Option<String>,
+
+    /// Choose the story to run in the terminal, instead of opening the story
+    /// explorer when no story is provided.
+    #[arg(long)]
+    pick: bool,
 }
 
 fn main() {
@@ -46,14 +52,18 @@ fn main() {
     menu::init();
     let args = Args::parse();
 
-    let story_selector = args.story.unwrap_or_else(|| {
-        let stories = ComponentStory::iter().collect::<Vec<_>>();
+    let story_selector = args.story.or_else(|| {
+        if !args.pick {
+            return None;
+        }
+
+        let stories = ComponentStory::all();
 
         ctrlc::set_handler(move || {}).unwrap();
 
         let result = FuzzySelect::new()
             .with_prompt("Choose a story to run:")
-            .items(&stories)
+            .items(stories)
             .interact();
 
         let Ok(selection) = result else {
@@ -61,7 +71,7 @@ fn main() {
             std::process::exit(0);
         };
 
-        StorySelector::Component(stories[selection])
+        Some(StorySelector::Component(stories[selection]))
     });
     let theme_name = args.theme.unwrap_or("One Dark".to_string());
 
@@ -98,7 +108,11 @@ fn main() {
             move |cx| {
                 theme::setup_ui_font(cx);
 
-                cx.new_view(|cx| StoryWrapper::new(selector.story(cx)))
+                let story = match selector {
+                    Some(selector) => selector.story(cx),
+                    None => StoryExplorer::view(cx).into(),
+                };
+                cx.new_view(|_| StoryWrapper::new(story))
             },
         );
 
@@ -47,6 +47,7 @@ pub struct TerminalSettings {
     pub detect_venv: VenvSettings,
     pub max_scroll_history_lines: Option<usize>,
     pub toolbar: Toolbar,
+    pub theme: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
@@ -187,6 +188,11 @@ pub struct TerminalSettingsContent {
     pub max_scroll_history_lines: Option<usize>,
     /// Toolbar related settings
     pub toolbar: Option<ToolbarContent>,
+    /// The name of a theme to draw the terminal panel with, instead of the
+    /// theme of the rest of the window.
+    ///
+    /// Default: null
+    pub theme: Option<String>,
 }
 
 impl settings::Settings for TerminalSettings {
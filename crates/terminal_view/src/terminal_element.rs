@@ -390,6 +390,7 @@ impl TerminalElement {
             },
             underline,
             strikethrough,
+            overline: None,
         };
 
         if let Some((style, range)) = hyperlink {
@@ -651,6 +652,7 @@ impl Element for TerminalElement {
                         wavy: false,
                     }),
                     strikethrough: None,
+                    overline: None,
                     fade_out: None,
                 };
 
@@ -668,6 +670,7 @@ impl Element for TerminalElement {
                     // These are going to be overridden per-cell
                     underline: None,
                     strikethrough: None,
+                    overline: None,
                     color: theme.colors().terminal_foreground,
                 };
 
@@ -782,6 +785,7 @@ impl Element for TerminalElement {
                                     background_color: None,
                                     underline: Default::default(),
                                     strikethrough: None,
+                                    overline: None,
                                 }],
                             )
                             .unwrap()
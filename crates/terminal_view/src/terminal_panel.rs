@@ -13,12 +13,13 @@ use itertools::Itertools;
 use project::{terminals::TerminalKind, Fs, ProjectEntryId};
 use search::{buffer_search::DivRegistrar, BufferSearchBar};
 use serde::{Deserialize, Serialize};
-use settings::Settings;
+use settings::{Settings, SettingsStore};
 use task::{RevealStrategy, Shell, SpawnInTerminal, TaskId};
 use terminal::{
     terminal_settings::{TerminalDockPosition, TerminalSettings},
     Terminal,
 };
+use theme::{with_theme, ThemeRegistry};
 use ui::{
     h_flex, ButtonCommon, Clickable, ContextMenu, IconButton, IconSize, PopoverMenu, Selectable,
     Tooltip,
@@ -29,7 +30,7 @@ use workspace::{
     item::SerializableItem,
     pane,
     ui::IconName,
-    DraggedTab, ItemId, NewTerminal, Pane, ToggleZoom, Workspace,
+    DraggedTab, ItemId, NewTerminal, Pane, PanelRegistry, ToggleZoom, Workspace,
 };
 
 use anyhow::Result;
@@ -40,6 +41,7 @@ const TERMINAL_PANEL_KEY: &str = "TerminalPanel";
 actions!(terminal_panel, [ToggleFocus]);
 
 pub fn init(cx: &mut AppContext) {
+    PanelRegistry::register(cx, TerminalPanel::load);
     cx.observe_new_views(
         |workspace: &mut Workspace, _: &mut ViewContext<Workspace>| {
             workspace.register_action(TerminalPanel::new_terminal);
@@ -145,6 +147,7 @@ impl TerminalPanel {
         let subscriptions = vec![
             cx.observe(&pane, |_, _, cx| cx.notify()),
             cx.subscribe(&pane, Self::handle_pane_event),
+            cx.observe_global::<SettingsStore>(|_, cx| cx.notify()),
         ];
         let project = workspace.project().read(cx);
         let enabled = project.supports_terminal(cx);
@@ -770,7 +773,14 @@ impl Render for TerminalPanel {
             cx,
         );
         BufferSearchBar::register(&mut registrar);
-        registrar.into_div().size_full().child(self.pane.clone())
+        let theme = TerminalSettings::get_global(cx)
+            .theme
+            .as_ref()
+            .and_then(|name| ThemeRegistry::global(cx).get(name).ok());
+        registrar.into_div().size_full().child(match theme {
+            Some(theme) => with_theme(theme, self.pane.clone()).into_any_element(),
+            None => self.pane.clone().into_any_element(),
+        })
     }
 }
 
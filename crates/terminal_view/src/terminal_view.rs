@@ -26,7 +26,7 @@ use terminal::{
 };
 use terminal_element::{is_blank, TerminalElement};
 use terminal_panel::TerminalPanel;
-use ui::{h_flex, prelude::*, ContextMenu, Icon, IconName, Label, Tooltip};
+use ui::{h_flex, prelude::*, ContextMenu, Icon, IconName, Label, OverlayLayer, Tooltip};
 use util::{paths::PathWithPosition, ResultExt};
 use workspace::{
     item::{BreadcrumbText, Item, ItemEvent, SerializableItem, TabContentParams},
@@ -437,9 +437,11 @@ impl TerminalView {
     }
 
     pub fn should_show_cursor(&self, focused: bool, cx: &mut gpui::ViewContext<Self>) -> bool {
-        //Don't blink the cursor when not focused, blinking is disabled, or paused
+        //Don't blink the cursor when not focused, blinking is disabled, or paused,
+        //or when motion is reduced
         if !focused
             || self.blinking_paused
+            || cx.prefers_reduced_motion()
             || self
                 .terminal
                 .read(cx)
@@ -1021,7 +1023,7 @@ impl Render for TerminalView {
                         .anchor(gpui::AnchorCorner::TopLeft)
                         .child(menu.clone()),
                 )
-                .with_priority(1)
+                .with_priority(OverlayLayer::Popover.priority())
             }))
     }
 }
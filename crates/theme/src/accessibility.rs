@@ -0,0 +1,211 @@
+use gpui::{rgb, Hsla};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{raise_contrast, ThemeColors, ThemeStyles, MIN_NON_TEXT_CONTRAST, MIN_TEXT_CONTRAST};
+
+/// A color vision deficiency to adapt the status colors of the theme to.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorVision {
+    /// Use the status colors of the theme.
+    #[default]
+    Default,
+    /// Tell apart red and green, for reduced sensitivity to green light.
+    Deuteranopia,
+    /// Tell apart red and green, for reduced sensitivity to red light.
+    Protanopia,
+}
+
+impl ColorVision {
+    /// Returns the colors to use for created, modified and deleted things, from the
+    /// Okabe-Ito palette, or `None` to keep the colors of the theme.
+    fn created_modified_deleted(self) -> Option<[Hsla; 3]> {
+        match self {
+            ColorVision::Default => None,
+            ColorVision::Deuteranopia => Some([
+                rgb(0x0072b2).into(),
+                rgb(0xe69f00).into(),
+                rgb(0xd55e00).into(),
+            ]),
+            ColorVision::Protanopia => Some([
+                rgb(0x0072b2).into(),
+                rgb(0xf0e442).into(),
+                rgb(0xcc79a7).into(),
+            ]),
+        }
+    }
+}
+
+/// Replaces the hues of the created, modified and deleted status colors with ones
+/// that can be told apart with the given color vision.
+///
+/// The lightness and alpha of the theme's colors are kept, so that e.g. subtle
+/// backgrounds stay subtle.
+pub fn apply_color_vision(styles: &mut ThemeStyles, color_vision: ColorVision) {
+    let Some([created, modified, deleted]) = color_vision.created_modified_deleted() else {
+        return;
+    };
+
+    let status = &mut styles.status;
+    for (hue, colors) in [
+        (
+            created,
+            [
+                &mut status.created,
+                &mut status.created_background,
+                &mut status.created_border,
+            ],
+        ),
+        (
+            modified,
+            [
+                &mut status.modified,
+                &mut status.modified_background,
+                &mut status.modified_border,
+            ],
+        ),
+        (
+            deleted,
+            [
+                &mut status.deleted,
+                &mut status.deleted_background,
+                &mut status.deleted_border,
+            ],
+        ),
+    ] {
+        for color in colors {
+            color.h = hue.h;
+            color.s = hue.s;
+        }
+    }
+}
+
+/// Raises the contrast of muted and disabled text, borders and status colors with
+/// the backgrounds of the theme past WCAG AA.
+pub fn apply_high_contrast(styles: &mut ThemeStyles) {
+    let backgrounds = backgrounds(&styles.colors);
+    let raise = |color: &mut Hsla, min_ratio: f32| {
+        for background in backgrounds {
+            *color = raise_contrast(*color, background, min_ratio);
+        }
+    };
+
+    let colors = &mut styles.colors;
+    for color in [
+        &mut colors.text_muted,
+        &mut colors.text_placeholder,
+        &mut colors.text_disabled,
+    ] {
+        raise(color, MIN_TEXT_CONTRAST);
+    }
+    for color in [
+        &mut colors.icon_muted,
+        &mut colors.icon_disabled,
+        &mut colors.icon_placeholder,
+        &mut colors.border,
+        &mut colors.border_variant,
+        &mut colors.border_focused,
+        &mut colors.border_selected,
+        &mut colors.border_disabled,
+    ] {
+        color.a = 1.;
+        raise(color, MIN_NON_TEXT_CONTRAST);
+    }
+
+    let status = &mut styles.status;
+    for color in [
+        &mut status.conflict,
+        &mut status.created,
+        &mut status.deleted,
+        &mut status.error,
+        &mut status.hint,
+        &mut status.info,
+        &mut status.modified,
+        &mut status.renamed,
+        &mut status.success,
+        &mut status.warning,
+    ] {
+        raise(color, MIN_TEXT_CONTRAST);
+    }
+}
+
+/// The backgrounds text and borders are commonly drawn on.
+fn backgrounds(colors: &ThemeColors) -> [Hsla; 5] {
+    [
+        colors.background,
+        colors.surface_background,
+        colors.elevated_surface_background,
+        colors.panel_background,
+        colors.editor_background,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{contrast_ratio, fallback_themes::zed_default_dark};
+
+    use super::*;
+
+    #[test]
+    fn test_apply_high_contrast() {
+        let mut styles = zed_default_dark().styles;
+        apply_high_contrast(&mut styles);
+
+        for background in backgrounds(&styles.colors) {
+            for color in [
+                styles.colors.text_muted,
+                styles.colors.text_disabled,
+                styles.status.error,
+                styles.status.modified,
+            ] {
+                assert!(contrast_ratio(color, background) >= MIN_TEXT_CONTRAST);
+            }
+            assert!(contrast_ratio(styles.colors.border, background) >= MIN_NON_TEXT_CONTRAST);
+        }
+        // Status colors keep their hues.
+        let original = zed_default_dark().styles;
+        assert_eq!(styles.status.error.h, original.status.error.h);
+        assert_eq!(styles.status.created.h, original.status.created.h);
+    }
+
+    #[test]
+    fn test_apply_color_vision() {
+        let original = zed_default_dark().styles;
+
+        let mut styles = original.clone();
+        apply_color_vision(&mut styles, ColorVision::Default);
+        assert!(styles == original);
+
+        for color_vision in [ColorVision::Deuteranopia, ColorVision::Protanopia] {
+            let mut styles = original.clone();
+            apply_color_vision(&mut styles, color_vision);
+            let [created, _, deleted] = color_vision.created_modified_deleted().unwrap();
+
+            assert_eq!(
+                (styles.status.created.h, styles.status.created.s),
+                (created.h, created.s)
+            );
+            assert_eq!(
+                (
+                    styles.status.deleted_border.h,
+                    styles.status.deleted_border.s
+                ),
+                (deleted.h, deleted.s)
+            );
+            // Lightness and alpha are kept, so backgrounds stay subtle.
+            assert_eq!(
+                (
+                    styles.status.created_background.l,
+                    styles.status.created_background.a
+                ),
+                (
+                    original.status.created_background.l,
+                    original.status.created_background.a
+                )
+            );
+            // Unrelated status colors are left alone.
+            assert_eq!(styles.status.error, original.status.error);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use gpui::{black, white, Hsla, Rgba};
+
+/// The minimum contrast ratio for icons and other graphical UI elements,
+/// as recommended by WCAG 2.1 for non-text content.
+pub const MIN_NON_TEXT_CONTRAST: f32 = 3.0;
+
+/// The minimum contrast ratio for text, as required by WCAG 2.1 level AA.
+pub const MIN_TEXT_CONTRAST: f32 = 4.5;
+
+/// Returns the relative luminance of a color, as defined by WCAG 2.1.
+///
+/// The alpha channel is ignored.
+pub fn relative_luminance(color: Hsla) -> f32 {
+    fn linearize(channel: f32) -> f32 {
+        if channel <= 0.03928 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    let rgba = Rgba::from(color);
+    0.2126 * linearize(rgba.r) + 0.7152 * linearize(rgba.g) + 0.0722 * linearize(rgba.b)
+}
+
+/// Returns the contrast ratio between two colors, from `1.0` (no contrast) to `21.0`.
+pub fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+    let a = relative_luminance(a);
+    let b = relative_luminance(b);
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Returns `foreground` if it contrasts enough with `background`, and otherwise
+/// whichever of white or black contrasts the most with it.
+///
+/// The alpha of `foreground` is preserved.
+pub fn ensure_contrast(foreground: Hsla, background: Hsla, min_ratio: f32) -> Hsla {
+    if contrast_ratio(foreground, background) >= min_ratio {
+        return foreground;
+    }
+
+    let light = white();
+    let dark = black();
+    let tint = if contrast_ratio(light, background) >= contrast_ratio(dark, background) {
+        light
+    } else {
+        dark
+    };
+
+    Hsla {
+        a: foreground.a,
+        ..tint
+    }
+}
+
+/// Returns `foreground`, lightened or darkened until it contrasts enough with
+/// `background`.
+///
+/// Unlike [`ensure_contrast`], the hue and saturation of `foreground` are kept, so
+/// e.g. an error color stays recognizably red.
+pub fn raise_contrast(foreground: Hsla, background: Hsla, min_ratio: f32) -> Hsla {
+    const STEP: f32 = 0.02;
+
+    let lighten = contrast_ratio(white(), background) >= contrast_ratio(black(), background);
+    let mut color = foreground;
+    while contrast_ratio(color, background) < min_ratio {
+        let lightness = if lighten {
+            (color.l + STEP).min(1.)
+        } else {
+            (color.l - STEP).max(0.)
+        };
+        if lightness == color.l {
+            break;
+        }
+        color.l = lightness;
+    }
+    color
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::hsla;
+
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio(white(), black()) - 21.).abs() < 0.01);
+        assert_eq!(contrast_ratio(black(), black()), 1.);
+        assert_eq!(
+            contrast_ratio(white(), black()),
+            contrast_ratio(black(), white())
+        );
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let light_gray = hsla(0., 0., 0.9, 1.);
+        let dark_gray = hsla(0., 0., 0.1, 1.);
+
+        // A light icon on a light background switches to a dark tint.
+        assert_eq!(
+            ensure_contrast(light_gray, white(), MIN_NON_TEXT_CONTRAST),
+            black()
+        );
+        // A dark icon on a dark background switches to a light tint.
+        assert_eq!(
+            ensure_contrast(dark_gray, black(), MIN_NON_TEXT_CONTRAST),
+            white()
+        );
+        // Colors that contrast enough are kept.
+        assert_eq!(
+            ensure_contrast(dark_gray, light_gray, MIN_NON_TEXT_CONTRAST),
+            dark_gray
+        );
+    }
+
+    #[test]
+    fn test_raise_contrast() {
+        let muted = hsla(0.6, 0.2, 0.4, 1.);
+        let dark_background = hsla(0.6, 0.1, 0.15, 1.);
+
+        let raised = raise_contrast(muted, dark_background, MIN_TEXT_CONTRAST);
+        assert!(contrast_ratio(raised, dark_background) >= MIN_TEXT_CONTRAST);
+        assert!(raised.l > muted.l);
+        assert_eq!((raised.h, raised.s), (muted.h, muted.s));
+
+        let readable = hsla(0., 0., 0.9, 1.);
+        assert_eq!(
+            raise_contrast(readable, dark_background, MIN_TEXT_CONTRAST),
+            readable
+        );
+    }
+}
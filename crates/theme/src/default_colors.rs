@@ -39,6 +39,9 @@ impl ThemeColors {
             ghost_element_active: neutral().light_alpha().step_4(),
             ghost_element_selected: neutral().light_alpha().step_5(),
             ghost_element_disabled: neutral().light_alpha().step_3(),
+            row_hover: neutral().light_alpha().step_3(),
+            row_selected: neutral().light_alpha().step_5(),
+            row_selected_unfocused: neutral().light_alpha().step_4(),
             text: neutral().light().step_12(),
             text_muted: neutral().light().step_10(),
             text_placeholder: neutral().light().step_10(),
@@ -145,6 +148,9 @@ impl ThemeColors {
             ghost_element_active: neutral().dark_alpha().step_5(),
             ghost_element_selected: neutral().dark_alpha().step_5(),
             ghost_element_disabled: neutral().dark_alpha().step_3(),
+            row_hover: neutral().dark_alpha().step_4(),
+            row_selected: neutral().dark_alpha().step_5(),
+            row_selected_unfocused: neutral().dark_alpha().step_3(),
             text: neutral().dark().step_12(),
             text_muted: neutral().dark().step_11(),
             text_placeholder: neutral().dark().step_10(),
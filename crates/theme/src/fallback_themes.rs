@@ -64,6 +64,9 @@ pub(crate) fn zed_default_dark() -> Theme {
                 ghost_element_active: hsla(220.0 / 360., 11.8 / 100., 20.0 / 100., 1.0),
                 ghost_element_selected: hsla(224.0 / 360., 11.3 / 100., 26.1 / 100., 1.0),
                 ghost_element_disabled: SystemColors::default().transparent,
+                row_hover: hsla(225.0 / 360., 11.8 / 100., 26.7 / 100., 1.0),
+                row_selected: hsla(224.0 / 360., 11.3 / 100., 26.1 / 100., 1.0),
+                row_selected_unfocused: hsla(224.0 / 360., 11.3 / 100., 26.1 / 100., 0.5),
                 text: hsla(221. / 360., 11. / 100., 86. / 100., 1.0),
                 text_muted: hsla(218.0 / 360., 7. / 100., 46. / 100., 1.0),
                 text_placeholder: hsla(220.0 / 360., 6.6 / 100., 44.5 / 100., 1.0),
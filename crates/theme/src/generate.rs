@@ -0,0 +1,435 @@
+use std::sync::Arc;
+
+use gpui::{hsla, FontStyle, FontWeight, HighlightStyle, Hsla, SharedString};
+
+use crate::{
+    contrast_ratio, raise_contrast, AccentColors, Appearance, PlayerColor, PlayerColors,
+    StatusColors, SyntaxTheme, SystemColors, Theme, ThemeColors, ThemeStyles,
+    MIN_NON_TEXT_CONTRAST, MIN_TEXT_CONTRAST,
+};
+
+/// The handful of colors a whole [`Theme`] can be derived from with
+/// [`ThemeSeed::generate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSeed {
+    /// The name of the generated theme.
+    pub name: SharedString,
+    /// The background of the editor, which the other surfaces are shades of. Whether
+    /// the theme is light or dark follows from it.
+    pub background: Hsla,
+    /// The color of text.
+    pub foreground: Hsla,
+    /// The color of focused borders, links, selections and the local cursor.
+    pub accent: Hsla,
+    /// The colors of keywords, functions, strings and types, in that order. Types
+    /// use the accent when only three colors are given.
+    pub syntax: Vec<Hsla>,
+}
+
+impl ThemeSeed {
+    /// Returns whether the theme is light or dark, from the lightness of its
+    /// background.
+    pub fn appearance(&self) -> Appearance {
+        if contrast_ratio(self.background, gpui::black())
+            > contrast_ratio(self.background, gpui::white())
+        {
+            Appearance::Light
+        } else {
+            Appearance::Dark
+        }
+    }
+
+    /// Derives a complete theme from the seed colors.
+    ///
+    /// Surfaces, elements and borders are shades of the background, stepping
+    /// towards the foreground. Text, icons, status and syntax colors are then
+    /// lightened or darkened until they contrast with the background as much as
+    /// WCAG 2.1 level AA requires.
+    pub fn generate(&self) -> Theme {
+        let appearance = self.appearance();
+        let background = Hsla {
+            a: 1.,
+            ..self.background
+        };
+        let foreground = raise_contrast(
+            Hsla {
+                a: 1.,
+                ..self.foreground
+            },
+            background,
+            MIN_TEXT_CONTRAST,
+        );
+        let shade = |amount: f32| mix(background, foreground, amount);
+        let text = |color: Hsla| raise_contrast(color, background, MIN_TEXT_CONTRAST);
+        let non_text = |color: Hsla| raise_contrast(color, background, MIN_NON_TEXT_CONTRAST);
+
+        let accent = non_text(self.accent);
+        let text_accent = text(self.accent);
+        let text_muted = text(shade(0.6));
+        let text_disabled = non_text(shade(0.4));
+
+        let (base_colors, base_status, base_players) = match appearance {
+            Appearance::Light => (
+                ThemeColors::light(),
+                StatusColors::light(),
+                PlayerColors::light(),
+            ),
+            Appearance::Dark => (
+                ThemeColors::dark(),
+                StatusColors::dark(),
+                PlayerColors::dark(),
+            ),
+        };
+        let transparent = SystemColors::default().transparent;
+
+        let chrome = shade(0.03);
+        let surface = shade(0.05);
+        let elevated_surface = shade(0.07);
+        let element = shade(0.08);
+        let element_hover = shade(0.12);
+        let element_active = shade(0.16);
+        let element_selected = mix(shade(0.12), accent, 0.15);
+        let border = shade(0.18);
+        let border_variant = shade(0.12);
+        let line_number = non_text(shade(0.35));
+
+        let colors = ThemeColors {
+            border,
+            border_variant,
+            border_focused: accent,
+            border_selected: accent,
+            border_transparent: transparent,
+            border_disabled: shade(0.08),
+            elevated_surface_background: elevated_surface,
+            surface_background: surface,
+            background: chrome,
+            element_background: element,
+            element_hover,
+            element_active,
+            element_selected,
+            element_disabled: shade(0.04),
+            drop_target_background: accent.opacity(0.2),
+            ghost_element_background: transparent,
+            ghost_element_hover: element_hover,
+            ghost_element_active: element_active,
+            ghost_element_selected: element_selected,
+            ghost_element_disabled: transparent,
+            text: foreground,
+            text_muted,
+            text_placeholder: text_disabled,
+            text_disabled,
+            text_accent,
+            icon: foreground,
+            icon_muted: text_muted,
+            icon_disabled: text_disabled,
+            icon_placeholder: text_disabled,
+            icon_accent: accent,
+            status_bar_background: chrome,
+            title_bar_background: chrome,
+            title_bar_inactive_background: shade(0.02),
+            toolbar_background: background,
+            tab_bar_background: surface,
+            tab_inactive_background: surface,
+            tab_active_background: background,
+            search_match_background: accent.opacity(0.3),
+            panel_background: surface,
+            panel_focused_border: accent,
+            panel_indent_guide: border_variant,
+            panel_indent_guide_hover: border,
+            panel_indent_guide_active: border,
+            pane_focused_border: accent,
+            pane_group_border: border,
+            scrollbar_thumb_background: foreground.opacity(0.15),
+            scrollbar_thumb_hover_background: foreground.opacity(0.3),
+            scrollbar_thumb_border: transparent,
+            scrollbar_track_background: transparent,
+            scrollbar_track_border: border_variant,
+            editor_foreground: foreground,
+            editor_background: background,
+            editor_gutter_background: background,
+            editor_subheader_background: surface,
+            editor_active_line_background: shade(0.04),
+            editor_highlighted_line_background: accent.opacity(0.1),
+            editor_line_number: line_number,
+            editor_active_line_number: foreground,
+            editor_invisible: line_number,
+            editor_wrap_guide: border_variant,
+            editor_active_wrap_guide: border,
+            editor_indent_guide: border_variant,
+            editor_indent_guide_active: border,
+            editor_document_highlight_read_background: accent.opacity(0.15),
+            editor_document_highlight_write_background: accent.opacity(0.25),
+            editor_document_highlight_bracket_background: accent.opacity(0.2),
+            terminal_background: background,
+            terminal_foreground: foreground,
+            terminal_bright_foreground: foreground,
+            terminal_dim_foreground: text_muted,
+            link_text_hover: text_accent,
+            ..base_colors
+        };
+
+        let status = self.status_colors(&colors, base_status);
+        let syntax = self.syntax_theme(&colors);
+
+        let mut player = base_players;
+        if let Some(local) = player.0.first_mut() {
+            *local = PlayerColor {
+                cursor: accent,
+                background: accent,
+                selection: accent.opacity(0.25),
+            };
+        }
+
+        Theme {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.name.clone(),
+            appearance,
+            styles: ThemeStyles {
+                window_background_appearance: Default::default(),
+                system: SystemColors::default(),
+                accents: match appearance {
+                    Appearance::Light => AccentColors::light(),
+                    Appearance::Dark => AccentColors::dark(),
+                },
+                colors,
+                status,
+                player,
+                syntax: Arc::new(syntax),
+            },
+        }
+    }
+
+    /// Returns the status colors, with fixed hues so that e.g. errors stay red, and
+    /// the saturation of the accent so that they sit well with the rest of the theme.
+    fn status_colors(&self, colors: &ThemeColors, base: StatusColors) -> StatusColors {
+        let saturation = self.accent.s.clamp(0.45, 0.8);
+        let lightness = match self.appearance() {
+            Appearance::Light => 0.4,
+            Appearance::Dark => 0.65,
+        };
+        let status = |hue: f32| {
+            let color = raise_contrast(
+                hsla(hue / 360., saturation, lightness, 1.),
+                colors.editor_background,
+                MIN_TEXT_CONTRAST,
+            );
+            (
+                color,
+                mix(colors.editor_background, color, 0.15),
+                mix(colors.editor_background, color, 0.4),
+            )
+        };
+
+        let (error, error_background, error_border) = status(355.);
+        let (warning, warning_background, warning_border) = status(40.);
+        let (success, success_background, success_border) = status(120.);
+        let (info, info_background, info_border) = status(self.accent.h * 360.);
+        let muted = (
+            colors.text_muted,
+            colors.element_background,
+            colors.border_variant,
+        );
+
+        StatusColors {
+            conflict: warning,
+            conflict_background: warning_background,
+            conflict_border: warning_border,
+            created: success,
+            created_background: success_background,
+            created_border: success_border,
+            deleted: error,
+            deleted_background: error_background,
+            deleted_border: error_border,
+            error,
+            error_background,
+            error_border,
+            hidden: muted.0,
+            hidden_background: muted.1,
+            hidden_border: muted.2,
+            hint: info,
+            hint_background: info_background,
+            hint_border: info_border,
+            ignored: muted.0,
+            ignored_background: muted.1,
+            ignored_border: muted.2,
+            info,
+            info_background,
+            info_border,
+            modified: warning,
+            modified_background: warning_background,
+            modified_border: warning_border,
+            predictive: colors.text_placeholder,
+            renamed: info,
+            renamed_background: info_background,
+            renamed_border: info_border,
+            success,
+            success_background,
+            success_border,
+            unreachable: muted.0,
+            unreachable_background: muted.1,
+            unreachable_border: muted.2,
+            warning,
+            warning_background,
+            warning_border,
+            ..base
+        }
+    }
+
+    fn syntax_theme(&self, colors: &ThemeColors) -> SyntaxTheme {
+        let role = |index: usize| {
+            let color = self.syntax.get(index).copied().unwrap_or(self.accent);
+            raise_contrast(color, colors.editor_background, MIN_TEXT_CONTRAST)
+        };
+        let keyword = role(0);
+        let function = role(1);
+        let string = role(2);
+        let ty = role(3);
+        let punctuation = colors.text_muted;
+
+        let styled =
+            |color: Hsla, font_style: Option<FontStyle>, font_weight: Option<FontWeight>| {
+                HighlightStyle {
+                    color: Some(color),
+                    font_style,
+                    font_weight,
+                    ..HighlightStyle::default()
+                }
+            };
+
+        SyntaxTheme {
+            highlights: vec![
+                ("attribute".into(), keyword.into()),
+                ("boolean".into(), ty.into()),
+                (
+                    "comment".into(),
+                    styled(colors.text_muted, Some(FontStyle::Italic), None),
+                ),
+                (
+                    "comment.doc".into(),
+                    styled(colors.text_muted, Some(FontStyle::Italic), None),
+                ),
+                ("constant".into(), ty.into()),
+                ("constructor".into(), function.into()),
+                (
+                    "emphasis".into(),
+                    HighlightStyle {
+                        font_style: Some(FontStyle::Italic),
+                        ..HighlightStyle::default()
+                    },
+                ),
+                (
+                    "emphasis.strong".into(),
+                    HighlightStyle {
+                        font_weight: Some(FontWeight::BOLD),
+                        ..HighlightStyle::default()
+                    },
+                ),
+                ("enum".into(), ty.into()),
+                ("function".into(), function.into()),
+                ("function.method".into(), function.into()),
+                ("function.definition".into(), function.into()),
+                ("hint".into(), colors.text_muted.into()),
+                ("keyword".into(), keyword.into()),
+                ("label".into(), keyword.into()),
+                ("link_text".into(), function.into()),
+                (
+                    "link_uri".into(),
+                    styled(string, Some(FontStyle::Italic), None),
+                ),
+                ("number".into(), ty.into()),
+                ("operator".into(), punctuation.into()),
+                ("predictive".into(), colors.text_placeholder.into()),
+                ("preproc".into(), keyword.into()),
+                ("punctuation".into(), punctuation.into()),
+                ("punctuation.bracket".into(), punctuation.into()),
+                ("punctuation.delimiter".into(), punctuation.into()),
+                ("punctuation.list_marker".into(), keyword.into()),
+                ("punctuation.special".into(), keyword.into()),
+                ("string".into(), string.into()),
+                ("string.escape".into(), keyword.into()),
+                ("string.regex".into(), string.into()),
+                ("string.special".into(), string.into()),
+                ("string.special.symbol".into(), ty.into()),
+                ("tag".into(), keyword.into()),
+                ("text.literal".into(), string.into()),
+                (
+                    "title".into(),
+                    styled(keyword, None, Some(FontWeight::BOLD)),
+                ),
+                ("type".into(), ty.into()),
+                ("variant".into(), ty.into()),
+            ],
+        }
+    }
+}
+
+/// Returns `base` with `amount` of `other` painted over it.
+pub(crate) fn mix(base: Hsla, other: Hsla, amount: f32) -> Hsla {
+    base.blend(other.opacity(amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed(background: Hsla, foreground: Hsla) -> ThemeSeed {
+        ThemeSeed {
+            name: "Seeded".into(),
+            background,
+            foreground,
+            accent: hsla(0.58, 0.7, 0.55, 1.),
+            syntax: vec![
+                hsla(0.78, 0.5, 0.6, 1.),
+                hsla(0.6, 0.6, 0.6, 1.),
+                hsla(0.27, 0.4, 0.55, 1.),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_generated_themes_contrast() {
+        let dark = seed(hsla(0.6, 0.1, 0.12, 1.), hsla(0.6, 0.1, 0.85, 1.));
+        // A foreground barely lighter than the background is still made readable.
+        let light = seed(hsla(0.1, 0.2, 0.97, 1.), hsla(0.1, 0.2, 0.8, 1.));
+
+        for (seed, appearance) in [(dark, Appearance::Dark), (light, Appearance::Light)] {
+            let theme = seed.generate();
+            assert_eq!(theme.appearance, appearance);
+
+            let colors = theme.colors();
+            let background = colors.editor_background;
+            for color in [colors.text, colors.text_muted, colors.text_accent] {
+                assert!(contrast_ratio(color, background) >= MIN_TEXT_CONTRAST);
+            }
+            assert!(contrast_ratio(colors.text_disabled, background) >= MIN_NON_TEXT_CONTRAST);
+
+            let status = theme.status();
+            for color in [status.error, status.warning, status.success, status.info] {
+                assert!(contrast_ratio(color, background) >= MIN_TEXT_CONTRAST);
+            }
+            for name in ["keyword", "function", "string", "type"] {
+                assert!(
+                    contrast_ratio(theme.syntax().color(name), background) >= MIN_TEXT_CONTRAST
+                );
+            }
+
+            // Each element state is a different shade, stepping away from the background.
+            let background_contrast = |color| contrast_ratio(color, background);
+            assert!(background_contrast(colors.element_background) > 1.);
+            assert!(
+                background_contrast(colors.element_hover)
+                    > background_contrast(colors.element_background)
+            );
+            assert!(
+                background_contrast(colors.element_active)
+                    > background_contrast(colors.element_hover)
+            );
+        }
+    }
+
+    #[test]
+    fn test_types_fall_back_to_accent() {
+        let theme = seed(hsla(0.6, 0.1, 0.12, 1.), hsla(0.6, 0.1, 0.85, 1.)).generate();
+        assert_eq!(theme.syntax().color("type"), theme.colors().text_accent);
+    }
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use gpui::{ease_in_out, ease_out, linear, quadratic, Animation, AppContext};
+
+/// How long an animation runs, so that motion is consistent across components.
+///
+/// All durations are zero when motion is reduced, which makes animations jump
+/// straight to their end.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum MotionDuration {
+    /// For small changes in place, like hover and focus feedback.
+    Fast,
+    /// For elements appearing, disappearing or changing size.
+    #[default]
+    Normal,
+    /// For large movements, like panels sliding in.
+    Slow,
+}
+
+impl MotionDuration {
+    /// Returns the duration, without taking reduced motion into account.
+    pub fn base(self) -> Duration {
+        match self {
+            MotionDuration::Fast => Duration::from_millis(100),
+            MotionDuration::Normal => Duration::from_millis(200),
+            MotionDuration::Slow => Duration::from_millis(400),
+        }
+    }
+
+    /// Returns the duration, or zero when motion is reduced.
+    pub fn duration(self, cx: &AppContext) -> Duration {
+        animation_duration(self.base(), cx)
+    }
+}
+
+/// Returns the given duration, or zero when motion is reduced.
+///
+/// Animations already jump to their end when motion is reduced; use this for
+/// durations driving other things, like timers, so that they follow the reduced
+/// motion setting too.
+pub fn animation_duration(duration: Duration, cx: &AppContext) -> Duration {
+    if cx.prefers_reduced_motion() {
+        Duration::ZERO
+    } else {
+        duration
+    }
+}
+
+/// How an animation progresses over its duration.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum MotionEasing {
+    /// Starts and ends slowly, for elements moving or changing size in place.
+    #[default]
+    Standard,
+    /// Starts fast and slows down, for elements appearing.
+    Decelerate,
+    /// Starts slowly and speeds up, for elements disappearing.
+    Accelerate,
+    /// Progresses evenly, for looping animations like spinners.
+    Linear,
+}
+
+impl MotionEasing {
+    /// Returns the easing function, mapping a delta between 0 and 1 to a new delta
+    /// between 0 and 1.
+    pub fn curve(self) -> fn(f32) -> f32 {
+        match self {
+            MotionEasing::Standard => ease_in_out,
+            MotionEasing::Decelerate => ease_out,
+            MotionEasing::Accelerate => quadratic,
+            MotionEasing::Linear => linear,
+        }
+    }
+
+    /// Applies the easing to a delta between 0 and 1.
+    pub fn apply(self, delta: f32) -> f32 {
+        self.curve()(delta)
+    }
+}
+
+/// Builds an [`Animation`] from the motion tokens, to be used with
+/// [`gpui::AnimationExt::with_animation`].
+pub fn transition(duration: MotionDuration, easing: MotionEasing, cx: &AppContext) -> Animation {
+    Animation::new(duration.duration(cx)).with_easing(easing.curve())
+}
@@ -14,7 +14,7 @@ use util::ResultExt;
 use crate::{
     try_parse_color, AccentColors, Appearance, AppearanceContent, PlayerColors, StatusColors,
     SyntaxTheme, SystemColors, Theme, ThemeColors, ThemeContent, ThemeFamily, ThemeFamilyContent,
-    ThemeStyles,
+    ThemeStyles, VsCodeThemeContent,
 };
 
 /// The metadata for a theme.
@@ -291,7 +291,22 @@ impl ThemeRegistry {
     }
 
     /// Loads the user theme from the specified path and adds it to the registry.
+    ///
+    /// VS Code color themes are converted into Zed themes, named after the file
+    /// when they have no name.
     pub async fn load_user_theme(&self, theme_path: &Path, fs: Arc<dyn Fs>) -> Result<()> {
+        let content = fs.load(theme_path).await?;
+        if let Some(vscode_theme) = VsCodeThemeContent::parse(&content)
+            .with_context(|| format!("parsing theme at {theme_path:?}"))?
+        {
+            let file_name = theme_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.insert_themes([vscode_theme.into_theme(&file_name)]);
+            return Ok(());
+        }
+
         let theme = Self::read_user_theme(theme_path, fs).await?;
 
         self.insert_user_theme_families([theme]);
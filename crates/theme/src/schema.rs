@@ -244,6 +244,24 @@ pub struct ThemeColorsContent {
     #[serde(rename = "ghost_element.disabled")]
     pub ghost_element_disabled: Option<String>,
 
+    /// Background Color. Used for the hover state of a row in a list, tree or table.
+    ///
+    /// Falls back to `ghost_element.hover`.
+    #[serde(rename = "row.hover")]
+    pub row_hover: Option<String>,
+
+    /// Background Color. Used for the selected rows of a list, tree or table that has focus.
+    ///
+    /// Falls back to `ghost_element.selected`.
+    #[serde(rename = "row.selected")]
+    pub row_selected: Option<String>,
+
+    /// Background Color. Used for the selected rows of a list, tree or table that doesn't have focus.
+    ///
+    /// Falls back to a faded `row.selected`.
+    #[serde(rename = "row.selected_unfocused")]
+    pub row_selected_unfocused: Option<String>,
+
     /// Text Color. Default text color used for most text.
     #[serde(rename = "text")]
     pub text: Option<String>,
@@ -557,6 +575,19 @@ impl ThemeColorsContent {
             .border
             .as_ref()
             .and_then(|color| try_parse_color(color).ok());
+        let ghost_element_hover = self
+            .ghost_element_hover
+            .as_ref()
+            .and_then(|color| try_parse_color(color).ok());
+        let ghost_element_selected = self
+            .ghost_element_selected
+            .as_ref()
+            .and_then(|color| try_parse_color(color).ok());
+        let row_selected = self
+            .row_selected
+            .as_ref()
+            .and_then(|color| try_parse_color(color).ok())
+            .or(ghost_element_selected);
         let editor_document_highlight_read_background = self
             .editor_document_highlight_read_background
             .as_ref()
@@ -623,22 +654,27 @@ impl ThemeColorsContent {
                 .ghost_element_background
                 .as_ref()
                 .and_then(|color| try_parse_color(color).ok()),
-            ghost_element_hover: self
-                .ghost_element_hover
-                .as_ref()
-                .and_then(|color| try_parse_color(color).ok()),
+            ghost_element_hover,
             ghost_element_active: self
                 .ghost_element_active
                 .as_ref()
                 .and_then(|color| try_parse_color(color).ok()),
-            ghost_element_selected: self
-                .ghost_element_selected
-                .as_ref()
-                .and_then(|color| try_parse_color(color).ok()),
+            ghost_element_selected,
             ghost_element_disabled: self
                 .ghost_element_disabled
                 .as_ref()
                 .and_then(|color| try_parse_color(color).ok()),
+            row_hover: self
+                .row_hover
+                .as_ref()
+                .and_then(|color| try_parse_color(color).ok())
+                .or(ghost_element_hover),
+            row_selected,
+            row_selected_unfocused: self
+                .row_selected_unfocused
+                .as_ref()
+                .and_then(|color| try_parse_color(color).ok())
+                .or(row_selected.map(|color| color.opacity(0.5))),
             text: self
                 .text
                 .as_ref()
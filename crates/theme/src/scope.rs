@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use gpui::{
+    AnyElement, AppContext, Bounds, Element, ElementId, Global, GlobalElementId, IntoElement,
+    LayoutId, Pixels, WindowContext,
+};
+
+use crate::Theme;
+
+/// The themes overriding the active theme while an element tree is drawn, from the
+/// outermost to the innermost [`ThemeScope`].
+#[derive(Default)]
+pub(crate) struct ThemeOverrides(Vec<Arc<Theme>>);
+
+impl Global for ThemeOverrides {}
+
+impl ThemeOverrides {
+    /// Returns the theme of the innermost [`ThemeScope`] being drawn, if any.
+    pub(crate) fn current(cx: &AppContext) -> Option<&Arc<Theme>> {
+        cx.try_global::<Self>()?.0.last()
+    }
+}
+
+/// Draws `child` with `theme` instead of the active theme.
+pub fn with_theme(theme: Arc<Theme>, child: impl IntoElement) -> ThemeScope {
+    ThemeScope {
+        theme,
+        child: child.into_any_element(),
+    }
+}
+
+/// Returns a copy of the active theme with some of its styles changed, e.g. to draw
+/// a part of the window with a different background using [`with_theme`].
+///
+/// The copy is made on each call, so views drawing with it should keep it around.
+pub fn modified_theme(cx: &AppContext, modify: impl FnOnce(&mut Theme)) -> Arc<Theme> {
+    let mut theme = Theme::clone(crate::ActiveTheme::theme(cx));
+    modify(&mut theme);
+    Arc::new(theme)
+}
+
+/// An element whose subtree is drawn with another theme: `cx.theme()` returns it
+/// while the subtree renders, lays out, prepaints and paints. Scopes nest, the
+/// innermost one winning.
+///
+/// Colors looked up outside of drawing, like in event handlers, or by elements
+/// deferred past the scope, are still the ones of the active theme.
+pub struct ThemeScope {
+    theme: Arc<Theme>,
+    child: AnyElement,
+}
+
+impl ThemeScope {
+    fn scoped<R>(
+        theme: &Arc<Theme>,
+        cx: &mut WindowContext,
+        f: impl FnOnce(&mut WindowContext) -> R,
+    ) -> R {
+        cx.default_global::<ThemeOverrides>().0.push(theme.clone());
+        let result = f(cx);
+        cx.default_global::<ThemeOverrides>().0.pop();
+        result
+    }
+}
+
+impl Element for ThemeScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, ()) {
+        let child = &mut self.child;
+        let layout_id = Self::scoped(&self.theme, cx, |cx| child.request_layout(cx));
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        let child = &mut self.child;
+        Self::scoped(&self.theme, cx, |cx| child.prepaint(cx));
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let child = &mut self.child;
+        Self::scoped(&self.theme, cx, |cx| child.paint(cx));
+    }
+}
+
+impl IntoElement for ThemeScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use settings::SettingsStore;
+
+    use super::*;
+    use crate::{ActiveTheme, LoadThemes};
+
+    #[gpui::test]
+    fn test_theme_overrides(cx: &mut AppContext) {
+        let settings = SettingsStore::test(cx);
+        cx.set_global(settings);
+        crate::init(LoadThemes::JustBase, cx);
+
+        let active = cx.theme().clone();
+        let scoped = modified_theme(cx, |theme| theme.name = "Scoped".into());
+        let innermost = modified_theme(cx, |theme| theme.name = "Innermost".into());
+        assert_eq!(scoped.appearance, active.appearance);
+
+        cx.default_global::<ThemeOverrides>().0.push(scoped);
+        assert_eq!(cx.theme().name.as_ref(), "Scoped");
+        cx.default_global::<ThemeOverrides>().0.push(innermost);
+        assert_eq!(cx.theme().name.as_ref(), "Innermost");
+        cx.default_global::<ThemeOverrides>().0.pop();
+        assert_eq!(cx.theme().name.as_ref(), "Scoped");
+        cx.default_global::<ThemeOverrides>().0.pop();
+        assert_eq!(cx.theme().name, active.name);
+    }
+}
@@ -1,10 +1,13 @@
 use crate::fallback_themes::zed_default_dark;
-use crate::{Appearance, SyntaxTheme, Theme, ThemeRegistry, ThemeStyleContent};
+use crate::{
+    apply_color_vision, apply_high_contrast, Appearance, ColorVision, SyntaxTheme, Theme,
+    ThemeRegistry, ThemeStyleContent,
+};
 use anyhow::Result;
 use derive_more::{Deref, DerefMut};
 use gpui::{
     px, AppContext, Font, FontFallbacks, FontFeatures, FontStyle, FontWeight, Global, Pixels,
-    Subscription, ViewContext, WindowContext,
+    Subscription, ViewContext, WindowBackgroundAppearance, WindowContext,
 };
 use refineable::Refineable;
 use schemars::{
@@ -62,6 +65,36 @@ impl UiDensity {
             UiDensity::Comfortable => 1.25,
         }
     }
+
+    /// The ratio by which the heights of fixed-size elements, like buttons, are
+    /// scaled in a given density.
+    pub fn size_ratio(self) -> f32 {
+        match self {
+            UiDensity::Compact => 0.875,
+            UiDensity::Default => 1.0,
+            UiDensity::Comfortable => 1.125,
+        }
+    }
+
+    /// The ratio by which the UI type scale is scaled in a given density.
+    ///
+    /// Text sized by the user's `ui_font_size` and `buffer_font_size` is not scaled.
+    pub fn text_ratio(self) -> f32 {
+        match self {
+            UiDensity::Compact => 13. / 14.,
+            UiDensity::Default => 1.0,
+            UiDensity::Comfortable => 15. / 14.,
+        }
+    }
+
+    /// Returns the next density, from compact to comfortable and back.
+    pub fn next(self) -> Self {
+        match self {
+            UiDensity::Compact => UiDensity::Default,
+            UiDensity::Default => UiDensity::Comfortable,
+            UiDensity::Comfortable => UiDensity::Compact,
+        }
+    }
 }
 
 impl From<String> for UiDensity {
@@ -85,6 +118,56 @@ impl From<UiDensity> for String {
     }
 }
 
+/// Whether translucent surfaces should be rendered fully opaque.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReduceTransparency {
+    /// Follow the accessibility preference of the operating system.
+    #[default]
+    System,
+    /// Always render surfaces fully opaque.
+    Always,
+    /// Never reduce transparency, even if the operating system asks to.
+    Never,
+}
+
+impl ReduceTransparency {
+    /// Returns whether transparency should be reduced, given the preference of the
+    /// operating system.
+    pub fn should_reduce(self, system_preference: bool) -> bool {
+        match self {
+            ReduceTransparency::System => system_preference,
+            ReduceTransparency::Always => true,
+            ReduceTransparency::Never => false,
+        }
+    }
+}
+
+/// Whether animations should be skipped.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReduceMotion {
+    /// Follow the accessibility preference of the operating system.
+    #[default]
+    System,
+    /// Always skip animations.
+    Always,
+    /// Never reduce motion, even if the operating system asks to.
+    Never,
+}
+
+impl ReduceMotion {
+    /// Returns whether motion should be reduced, given the preference of the
+    /// operating system.
+    pub fn should_reduce(self, system_preference: bool) -> bool {
+        match self {
+            ReduceMotion::System => system_preference,
+            ReduceMotion::Always => true,
+            ReduceMotion::Never => false,
+        }
+    }
+}
+
 /// Customizable settings for the UI and theme system.
 #[derive(Clone, PartialEq)]
 pub struct ThemeSettings {
@@ -123,6 +206,14 @@ pub struct ThemeSettings {
     pub ui_density: UiDensity,
     /// The amount of fading applied to unnecessary code.
     pub unnecessary_code_fade: f32,
+    /// Whether blurred and translucent surfaces are rendered fully opaque.
+    pub reduce_transparency: bool,
+    /// Whether animations are skipped, making the motion tokens zero.
+    pub reduce_motion: bool,
+    /// Whether the contrast of muted text, borders and status colors is raised.
+    pub high_contrast: bool,
+    /// The color vision the status colors are adapted to.
+    pub color_vision: ColorVision,
 }
 
 impl ThemeSettings {
@@ -335,6 +426,34 @@ pub struct ThemeSettingsContent {
     #[serde(default)]
     pub unnecessary_code_fade: Option<f32>,
 
+    /// Whether to render blurred and translucent surfaces, like popovers and the
+    /// title bar, fully opaque.
+    ///
+    /// Default: system
+    #[serde(default)]
+    pub reduce_transparency: Option<ReduceTransparency>,
+
+    /// Whether to skip animations, like expanding accordions and spinners, and to
+    /// stop cursors from blinking.
+    ///
+    /// Default: system
+    #[serde(default)]
+    pub reduce_motion: Option<ReduceMotion>,
+
+    /// Whether to raise the contrast of muted text, borders and status colors
+    /// past WCAG AA, on top of the current theme.
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub high_contrast: Option<bool>,
+
+    /// The color vision to adapt the created, modified and deleted status colors
+    /// of the current theme to.
+    ///
+    /// Default: default
+    #[serde(default)]
+    pub color_vision: Option<ColorVision>,
+
     /// EXPERIMENTAL: Overrides for the current theme.
     ///
     /// These values will override the ones on the current theme specified in `theme`.
@@ -486,6 +605,60 @@ impl ThemeSettings {
 
             self.active_theme = Arc::new(base_theme);
         }
+
+        self.apply_reduced_transparency();
+        self.apply_accessibility_colors();
+    }
+
+    /// Makes the translucent surfaces of the current theme opaque, if transparency
+    /// should be reduced.
+    fn apply_reduced_transparency(&mut self) {
+        if !self.reduce_transparency {
+            return;
+        }
+
+        let mut base_theme = (*self.active_theme).clone();
+        base_theme.styles.window_background_appearance = WindowBackgroundAppearance::Opaque;
+
+        let backdrop = match base_theme.appearance {
+            Appearance::Light => gpui::white(),
+            Appearance::Dark => gpui::black(),
+        };
+        let colors = &mut base_theme.styles.colors;
+        colors.background = backdrop.blend(colors.background);
+        let background = colors.background;
+        for color in [
+            &mut colors.surface_background,
+            &mut colors.elevated_surface_background,
+            &mut colors.panel_background,
+            &mut colors.title_bar_background,
+            &mut colors.title_bar_inactive_background,
+            &mut colors.status_bar_background,
+            &mut colors.toolbar_background,
+            &mut colors.tab_bar_background,
+            &mut colors.editor_background,
+            &mut colors.terminal_background,
+        ] {
+            *color = background.blend(*color);
+        }
+
+        self.active_theme = Arc::new(base_theme);
+    }
+
+    /// Adapts the colors of the current theme to the high contrast and color vision
+    /// settings.
+    fn apply_accessibility_colors(&mut self) {
+        if !self.high_contrast && self.color_vision == ColorVision::Default {
+            return;
+        }
+
+        let mut base_theme = (*self.active_theme).clone();
+        apply_color_vision(&mut base_theme.styles, self.color_vision);
+        if self.high_contrast {
+            apply_high_contrast(&mut base_theme.styles);
+        }
+
+        self.active_theme = Arc::new(base_theme);
     }
 }
 
@@ -634,8 +807,46 @@ impl settings::Settings for ThemeSettings {
             theme_overrides: None,
             ui_density: defaults.ui_density.unwrap_or(UiDensity::Default),
             unnecessary_code_fade: defaults.unnecessary_code_fade.unwrap_or(0.0),
+            reduce_transparency: false,
+            reduce_motion: false,
+            high_contrast: defaults.high_contrast.unwrap_or(false),
+            color_vision: defaults.color_vision.unwrap_or_default(),
         };
 
+        // Resolve this first, as it applies to the theme loaded from each source.
+        let reduce_transparency = sources
+            .user
+            .into_iter()
+            .chain(sources.release_channel)
+            .chain(sources.server)
+            .filter_map(|value| value.reduce_transparency)
+            .last()
+            .or(defaults.reduce_transparency)
+            .unwrap_or_default();
+        this.reduce_transparency =
+            reduce_transparency.should_reduce(cx.should_reduce_transparency());
+        let reduce_motion = sources
+            .user
+            .into_iter()
+            .chain(sources.release_channel)
+            .chain(sources.server)
+            .filter_map(|value| value.reduce_motion)
+            .last()
+            .or(defaults.reduce_motion)
+            .unwrap_or_default();
+        this.reduce_motion = reduce_motion.should_reduce(cx.should_reduce_motion());
+        for value in sources
+            .user
+            .into_iter()
+            .chain(sources.release_channel)
+            .chain(sources.server)
+        {
+            merge(&mut this.high_contrast, value.high_contrast);
+            merge(&mut this.color_vision, value.color_vision);
+        }
+        this.apply_reduced_transparency();
+        this.apply_accessibility_colors();
+
         for value in sources
             .user
             .into_iter()
@@ -1,5 +1,6 @@
 mod accents;
 mod colors;
+mod elevation;
 mod players;
 mod status;
 mod syntax;
@@ -7,6 +8,7 @@ mod system;
 
 pub use accents::*;
 pub use colors::*;
+pub use elevation::*;
 pub use players::*;
 pub use status::*;
 pub use syntax::*;
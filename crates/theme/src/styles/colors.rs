@@ -81,6 +81,12 @@ pub struct ThemeColors {
     ///
     /// Disabled states are shown when a user cannot interact with an element, like a disabled button or input.
     pub ghost_element_disabled: Hsla,
+    /// Background Color. Used for the hover state of a row in a list, tree or table.
+    pub row_hover: Hsla,
+    /// Background Color. Used for the selected rows of a list, tree or table that has focus.
+    pub row_selected: Hsla,
+    /// Background Color. Used for the selected rows of a list, tree or table that doesn't have focus.
+    pub row_selected_unfocused: Hsla,
     /// Text Color. Default text color used for most text.
     pub text: Hsla,
     /// Text Color. Color of muted or deemphasized text. It is a subdued version of the standard text color.
@@ -0,0 +1,108 @@
+use gpui::{hsla, point, px, BoxShadow, Hsla, Pixels};
+use smallvec::{smallvec, SmallVec};
+
+use crate::{Appearance, Theme};
+
+/// How far above the window a surface sits, which sets its background, border and
+/// shadow.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Elevation {
+    /// Flush with the window, like cards and sections.
+    #[default]
+    Surface,
+    /// Slightly above the window, like toasts and floating toolbars.
+    Raised,
+    /// Above the content it was opened from, like popovers and menus.
+    Overlay,
+    /// Above everything else, behind which the window is dimmed, like dialogs.
+    Modal,
+}
+
+/// The background, border and shadow of a surface at an [`Elevation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevationStyle {
+    /// The color of the surface.
+    pub background: Hsla,
+    /// The color of the border around the surface.
+    pub border: Hsla,
+    /// The shadows cast by the surface, none when it's flush with the window.
+    pub shadow: SmallVec<[BoxShadow; 2]>,
+}
+
+fn shadow(alpha: f32, offset_y: f32, blur_radius: f32) -> BoxShadow {
+    BoxShadow {
+        color: hsla(0., 0., 0., alpha),
+        offset: point(px(0.), px(offset_y)),
+        blur_radius: px(blur_radius),
+        spread_radius: Pixels::ZERO,
+    }
+}
+
+impl Theme {
+    /// Returns the background, border and shadow of surfaces at `elevation`.
+    ///
+    /// Shadows are darker in dark themes, so that they're as visible over dark
+    /// backgrounds as over light ones.
+    pub fn elevation(&self, elevation: Elevation) -> ElevationStyle {
+        let colors = self.colors();
+        let strength = match self.appearance {
+            Appearance::Light => 1.,
+            Appearance::Dark => 2.5,
+        };
+
+        match elevation {
+            Elevation::Surface => ElevationStyle {
+                background: colors.surface_background,
+                border: colors.border_variant,
+                shadow: SmallVec::new(),
+            },
+            Elevation::Raised => ElevationStyle {
+                background: colors.elevated_surface_background,
+                border: colors.border_variant,
+                shadow: smallvec![shadow(0.06 * strength, 1., 2.)],
+            },
+            Elevation::Overlay => ElevationStyle {
+                background: colors.elevated_surface_background,
+                border: colors.border_variant,
+                shadow: smallvec![shadow(0.12 * strength, 2., 3.)],
+            },
+            Elevation::Modal => ElevationStyle {
+                background: colors.elevated_surface_background,
+                border: colors.border,
+                shadow: smallvec![
+                    shadow(0.12 * strength, 2., 3.),
+                    shadow(0.08 * strength, 3., 6.),
+                    shadow(0.04 * strength, 6., 12.),
+                ],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shadows_grow_with_elevation() {
+        let dark = crate::fallback_themes::zed_default_dark();
+        let mut light = dark.clone();
+        light.appearance = Appearance::Light;
+        for theme in [light, dark] {
+            let shadows = [
+                Elevation::Surface,
+                Elevation::Raised,
+                Elevation::Overlay,
+                Elevation::Modal,
+            ]
+            .map(|elevation| theme.elevation(elevation).shadow);
+            assert!(shadows[0].is_empty());
+            for pair in shadows.windows(2) {
+                let strength = |shadows: &SmallVec<[BoxShadow; 2]>| {
+                    shadows.iter().map(|shadow| shadow.color.a).sum::<f32>()
+                };
+                assert!(strength(&pair[0]) < strength(&pair[1]));
+            }
+        }
+    }
+}
@@ -75,6 +75,7 @@ impl SyntaxTheme {
                 existing_highlight.underline = highlight.underline.or(existing_highlight.underline);
                 existing_highlight.strikethrough =
                     highlight.strikethrough.or(existing_highlight.strikethrough);
+                existing_highlight.overline = highlight.overline.or(existing_highlight.overline);
                 existing_highlight.fade_out = highlight.fade_out.or(existing_highlight.fade_out);
             } else {
                 merged_highlights.push((name, highlight));
@@ -8,25 +8,38 @@
 //!
 //! A theme is a collection of colors used to build a consistent appearance for UI components across the application.
 
+mod accessibility;
+mod contrast;
 mod default_colors;
 mod fallback_themes;
 mod font_family_cache;
+mod generate;
+mod motion;
 mod registry;
 mod scale;
 mod schema;
+mod scope;
 mod settings;
 mod styles;
+mod vscode;
 
 use std::sync::Arc;
 
 use ::settings::{Settings, SettingsStore};
+pub use accessibility::*;
+pub use contrast::*;
 pub use default_colors::*;
 pub use font_family_cache::*;
+pub use generate::*;
+pub use motion::*;
 pub use registry::*;
 pub use scale::*;
 pub use schema::*;
+use scope::ThemeOverrides;
+pub use scope::*;
 pub use settings::*;
 pub use styles::*;
+pub use vscode::*;
 
 use gpui::{
     px, AppContext, AssetSource, Hsla, Pixels, SharedString, WindowAppearance,
@@ -92,6 +105,7 @@ pub fn init(themes_to_load: LoadThemes, cx: &mut AppContext) {
 
     ThemeSettings::register(cx);
     FontFamilyCache::init_global(cx);
+    cx.set_prefers_reduced_motion(ThemeSettings::get_global(cx).reduce_motion);
 
     let mut prev_buffer_font_size = ThemeSettings::get_global(cx).buffer_font_size;
     cx.observe_global::<SettingsStore>(move |cx| {
@@ -100,6 +114,7 @@ pub fn init(themes_to_load: LoadThemes, cx: &mut AppContext) {
             prev_buffer_font_size = buffer_font_size;
             reset_buffer_font_size(cx);
         }
+        cx.set_prefers_reduced_motion(ThemeSettings::get_global(cx).reduce_motion);
     })
     .detach();
 }
@@ -112,7 +127,7 @@ pub trait ActiveTheme {
 
 impl ActiveTheme for AppContext {
     fn theme(&self) -> &Arc<Theme> {
-        &ThemeSettings::get_global(self).active_theme
+        ThemeOverrides::current(self).unwrap_or(&ThemeSettings::get_global(self).active_theme)
     }
 }
 
@@ -0,0 +1,822 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use collections::HashMap;
+use gpui::{hsla, FontStyle, FontWeight, HighlightStyle, Hsla};
+use serde::Deserialize;
+
+use crate::generate::mix;
+use crate::{try_parse_color, PlayerColor, SyntaxTheme, Theme, ThemeSeed};
+
+/// The Zed syntax tokens and the TextMate scopes they're colored like, from the most
+/// to the least preferred.
+///
+/// Parents come before the tokens refining them, so that e.g. `comment.doc` can
+/// fall back to the color of `comment`.
+const SYNTAX_SCOPES: &[(&str, &[&str])] = &[
+    ("attribute", &["entity.other.attribute-name"]),
+    (
+        "boolean",
+        &["constant.language.boolean", "constant.language"],
+    ),
+    ("comment", &["comment"]),
+    ("comment.doc", &["comment.block.documentation"]),
+    ("constant", &["variable.other.constant", "constant"]),
+    (
+        "constructor",
+        &["entity.name.function.constructor", "entity.name.class"],
+    ),
+    ("embedded", &["meta.embedded"]),
+    ("emphasis", &["markup.italic"]),
+    ("emphasis.strong", &["markup.bold"]),
+    ("enum", &["entity.name.type.enum", "entity.name.type"]),
+    (
+        "function",
+        &[
+            "entity.name.function",
+            "support.function",
+            "meta.function-call",
+        ],
+    ),
+    (
+        "function.method",
+        &["entity.name.function.member", "entity.name.function"],
+    ),
+    ("function.definition", &["entity.name.function"]),
+    (
+        "keyword",
+        &[
+            "keyword.control",
+            "keyword",
+            "storage.type",
+            "storage.modifier",
+        ],
+    ),
+    ("label", &["entity.name.label"]),
+    ("link_text", &["string.other.link", "markup.underline.link"]),
+    ("link_uri", &["markup.underline.link"]),
+    ("number", &["constant.numeric"]),
+    ("operator", &["keyword.operator"]),
+    (
+        "preproc",
+        &["meta.preprocessor", "keyword.control.directive"],
+    ),
+    (
+        "property",
+        &[
+            "variable.other.property",
+            "support.type.property-name",
+            "variable.other.object.property",
+        ],
+    ),
+    ("punctuation", &["punctuation"]),
+    (
+        "punctuation.bracket",
+        &["punctuation.bracket", "punctuation.section", "meta.brace"],
+    ),
+    (
+        "punctuation.delimiter",
+        &["punctuation.separator", "punctuation.terminator"],
+    ),
+    (
+        "punctuation.list_marker",
+        &["punctuation.definition.list.begin", "markup.list"],
+    ),
+    (
+        "punctuation.special",
+        &[
+            "punctuation.definition.template-expression",
+            "punctuation.special",
+        ],
+    ),
+    ("string", &["string"]),
+    ("string.escape", &["constant.character.escape"]),
+    ("string.regex", &["string.regexp"]),
+    ("string.special", &["string.other", "constant.other.symbol"]),
+    ("string.special.symbol", &["constant.other.symbol"]),
+    ("tag", &["entity.name.tag"]),
+    ("text.literal", &["markup.inline.raw", "markup.raw"]),
+    ("title", &["markup.heading", "entity.name.section"]),
+    (
+        "type",
+        &[
+            "entity.name.type",
+            "support.type",
+            "entity.name.class",
+            "support.class",
+        ],
+    ),
+    ("variable", &["variable.other", "variable"]),
+    ("variable.special", &["variable.language"]),
+    ("variant", &["variable.other.enummember"]),
+];
+
+/// A rule of a VS Code theme, coloring the tokens whose TextMate scope matches one
+/// of its scope selectors.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VsCodeTokenColor {
+    /// A selector, a list of them separated by commas, or a list of selectors. No
+    /// scope makes a rule setting the default colors of the editor.
+    #[serde(default)]
+    pub scope: Option<VsCodeTokenScope>,
+    /// The style given to the matching tokens.
+    pub settings: VsCodeTokenSettings,
+}
+
+/// The scope selectors of a [`VsCodeTokenColor`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum VsCodeTokenScope {
+    /// One selector, or several separated by commas.
+    One(String),
+    /// Several selectors.
+    Many(Vec<String>),
+}
+
+impl VsCodeTokenScope {
+    fn selectors(&self) -> impl Iterator<Item = &str> {
+        let selectors: &[String] = match self {
+            Self::One(selector) => std::slice::from_ref(selector),
+            Self::Many(selectors) => selectors,
+        };
+        selectors
+            .iter()
+            .flat_map(|selectors| selectors.split(','))
+            .map(str::trim)
+            .filter(|selector| !selector.is_empty())
+    }
+}
+
+/// The style of the tokens a [`VsCodeTokenColor`] matches.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VsCodeTokenSettings {
+    /// The color of the text.
+    pub foreground: Option<String>,
+    /// The color behind the text.
+    pub background: Option<String>,
+    /// `italic`, `bold`, `underline` and `strikethrough`, separated by spaces.
+    #[serde(rename = "fontStyle")]
+    pub font_style: Option<String>,
+}
+
+/// The token colors of a VS Code theme: either the rules themselves, or the path of
+/// a TextMate theme holding them.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum VsCodeTokenColors {
+    /// The rules of the theme.
+    Rules(Vec<VsCodeTokenColor>),
+    /// A path to a `.tmTheme` file, which isn't supported.
+    Path(String),
+}
+
+impl Default for VsCodeTokenColors {
+    fn default() -> Self {
+        Self::Rules(Vec::new())
+    }
+}
+
+/// A color theme in the format of VS Code, as shipped in its extensions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VsCodeThemeContent {
+    /// The name of the theme, if it has one; themes of extensions are usually named
+    /// in the extension's manifest instead.
+    pub name: Option<String>,
+    /// `dark`, `light`, `hc-black` or `hc-light`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// The colors of the workbench, by their VS Code key, e.g. `editor.background`.
+    #[serde(default)]
+    pub colors: HashMap<String, Option<String>>,
+    /// The colors of syntax tokens.
+    #[serde(default, rename = "tokenColors")]
+    pub token_colors: VsCodeTokenColors,
+}
+
+impl VsCodeThemeContent {
+    /// Parses a VS Code color theme, returning `None` when `content` is in another
+    /// format, like a Zed theme family.
+    pub fn parse(content: &str) -> Result<Option<Self>> {
+        let value: serde_json_lenient::Value = serde_json_lenient::from_str(content)?;
+        let is_vscode_theme = value.get("themes").is_none()
+            && (value.get("colors").is_some() || value.get("tokenColors").is_some());
+        if !is_vscode_theme {
+            return Ok(None);
+        }
+        Ok(Some(serde_json_lenient::from_value(value)?))
+    }
+
+    /// Converts the theme into a Zed theme, named `fallback_name` if it has no name of
+    /// its own.
+    ///
+    /// Only a small share of Zed's colors have a VS Code counterpart, so the theme is
+    /// first generated from its background, foreground, accent and main syntax
+    /// colors, and then given every color the VS Code theme defines.
+    pub fn into_theme(self, fallback_name: &str) -> Theme {
+        let rules = match &self.token_colors {
+            VsCodeTokenColors::Rules(rules) => rules.as_slice(),
+            VsCodeTokenColors::Path(path) => {
+                log::warn!("token colors of VS Code themes can't be read from {path:?}");
+                &[]
+            }
+        };
+        // Old themes set the colors of the editor in a rule without a scope.
+        let default_settings = rules
+            .iter()
+            .find(|rule| rule.scope.is_none())
+            .map(|rule| &rule.settings);
+        let default_color = |color: Option<&String>| color.and_then(|color| parse_color(color));
+
+        let is_light = self
+            .kind
+            .as_deref()
+            .map_or(false, |kind| kind.contains("light"));
+        let background = self
+            .color(&["editor.background"])
+            .or_else(|| default_color(default_settings.and_then(|s| s.background.as_ref())))
+            .unwrap_or(if is_light {
+                hsla(0., 0., 1., 1.)
+            } else {
+                hsla(0., 0., 0.12, 1.)
+            });
+        let foreground = self
+            .color(&["editor.foreground", "foreground"])
+            .or_else(|| default_color(default_settings.and_then(|s| s.foreground.as_ref())))
+            .unwrap_or(if is_light {
+                hsla(0., 0., 0.2, 1.)
+            } else {
+                hsla(0., 0., 0.83, 1.)
+            });
+        let accent = self
+            .color(&[
+                "focusBorder",
+                "button.background",
+                "textLink.foreground",
+                "activityBarBadge.background",
+            ])
+            .unwrap_or(hsla(0.58, 0.7, 0.55, 1.));
+        let syntax = ["keyword", "function", "string", "type"]
+            .iter()
+            .filter_map(|token| syntax_style(rules, token)?.color)
+            .collect();
+
+        let mut theme = ThemeSeed {
+            name: self
+                .name
+                .clone()
+                .unwrap_or_else(|| fallback_name.to_string())
+                .into(),
+            background,
+            foreground,
+            accent,
+            syntax,
+        }
+        .generate();
+        self.apply_colors(&mut theme);
+        self.apply_status_colors(&mut theme);
+        apply_syntax(rules, &mut theme);
+        theme
+    }
+
+    /// Returns the first of the given colors the theme defines.
+    fn color(&self, keys: &[&str]) -> Option<Hsla> {
+        keys.iter().find_map(|key| {
+            let color = self.colors.get(*key)?.as_ref()?;
+            parse_color(color)
+        })
+    }
+
+    fn apply_colors(&self, theme: &mut Theme) {
+        let colors = &mut theme.styles.colors;
+        for (color, keys) in [
+            (
+                &mut colors.border,
+                &[
+                    "panel.border",
+                    "editorGroup.border",
+                    "sideBar.border",
+                    "contrastBorder",
+                ][..],
+            ),
+            (
+                &mut colors.border_variant,
+                &[
+                    "editorGroup.border",
+                    "sideBarSectionHeader.border",
+                    "panel.border",
+                ],
+            ),
+            (&mut colors.border_focused, &["focusBorder"]),
+            (&mut colors.border_selected, &["focusBorder"]),
+            (
+                &mut colors.elevated_surface_background,
+                &[
+                    "editorWidget.background",
+                    "dropdown.background",
+                    "menu.background",
+                ],
+            ),
+            (
+                &mut colors.surface_background,
+                &["sideBar.background", "panel.background"],
+            ),
+            (
+                &mut colors.background,
+                &["titleBar.activeBackground", "sideBar.background"],
+            ),
+            (
+                &mut colors.element_background,
+                &["button.secondaryBackground", "input.background"],
+            ),
+            (
+                &mut colors.element_hover,
+                &["list.hoverBackground", "toolbar.hoverBackground"],
+            ),
+            (
+                &mut colors.element_active,
+                &["toolbar.activeBackground", "list.activeSelectionBackground"],
+            ),
+            (
+                &mut colors.element_selected,
+                &["list.activeSelectionBackground"],
+            ),
+            (
+                &mut colors.drop_target_background,
+                &["list.dropBackground", "editorGroup.dropBackground"],
+            ),
+            (
+                &mut colors.ghost_element_hover,
+                &["list.hoverBackground", "toolbar.hoverBackground"],
+            ),
+            (
+                &mut colors.ghost_element_active,
+                &["toolbar.activeBackground", "list.activeSelectionBackground"],
+            ),
+            (
+                &mut colors.ghost_element_selected,
+                &[
+                    "list.inactiveSelectionBackground",
+                    "list.activeSelectionBackground",
+                ],
+            ),
+            (&mut colors.row_hover, &["list.hoverBackground"]),
+            (
+                &mut colors.row_selected,
+                &["list.activeSelectionBackground"],
+            ),
+            (
+                &mut colors.row_selected_unfocused,
+                &["list.inactiveSelectionBackground"],
+            ),
+            (&mut colors.text, &["foreground", "editor.foreground"]),
+            (
+                &mut colors.text_muted,
+                &["descriptionForeground", "tab.inactiveForeground"],
+            ),
+            (
+                &mut colors.text_placeholder,
+                &["input.placeholderForeground"],
+            ),
+            (&mut colors.text_disabled, &["disabledForeground"]),
+            (&mut colors.text_accent, &["textLink.foreground"]),
+            (&mut colors.icon, &["icon.foreground", "foreground"]),
+            (&mut colors.icon_muted, &["tab.inactiveForeground"]),
+            (&mut colors.icon_disabled, &["disabledForeground"]),
+            (&mut colors.icon_accent, &["focusBorder"]),
+            (&mut colors.status_bar_background, &["statusBar.background"]),
+            (
+                &mut colors.title_bar_background,
+                &["titleBar.activeBackground"],
+            ),
+            (
+                &mut colors.title_bar_inactive_background,
+                &["titleBar.inactiveBackground", "titleBar.activeBackground"],
+            ),
+            (
+                &mut colors.toolbar_background,
+                &["breadcrumb.background", "editor.background"],
+            ),
+            (
+                &mut colors.tab_bar_background,
+                &["editorGroupHeader.tabsBackground"],
+            ),
+            (
+                &mut colors.tab_inactive_background,
+                &["tab.inactiveBackground"],
+            ),
+            (&mut colors.tab_active_background, &["tab.activeBackground"]),
+            (
+                &mut colors.search_match_background,
+                &["editor.findMatchHighlightBackground"],
+            ),
+            (
+                &mut colors.panel_background,
+                &["sideBar.background", "panel.background"],
+            ),
+            (
+                &mut colors.panel_focused_border,
+                &["list.focusOutline", "focusBorder"],
+            ),
+            (&mut colors.pane_focused_border, &["focusBorder"]),
+            (&mut colors.pane_group_border, &["editorGroup.border"]),
+            (
+                &mut colors.scrollbar_thumb_background,
+                &["scrollbarSlider.background"],
+            ),
+            (
+                &mut colors.scrollbar_thumb_hover_background,
+                &["scrollbarSlider.hoverBackground"],
+            ),
+            (
+                &mut colors.scrollbar_track_border,
+                &["editorOverviewRuler.border"],
+            ),
+            (&mut colors.editor_foreground, &["editor.foreground"]),
+            (&mut colors.editor_background, &["editor.background"]),
+            (
+                &mut colors.editor_gutter_background,
+                &["editorGutter.background", "editor.background"],
+            ),
+            (
+                &mut colors.editor_active_line_background,
+                &["editor.lineHighlightBackground"],
+            ),
+            (
+                &mut colors.editor_line_number,
+                &["editorLineNumber.foreground"],
+            ),
+            (
+                &mut colors.editor_active_line_number,
+                &["editorLineNumber.activeForeground"],
+            ),
+            (
+                &mut colors.editor_invisible,
+                &["editorWhitespace.foreground"],
+            ),
+            (&mut colors.editor_wrap_guide, &["editorRuler.foreground"]),
+            (
+                &mut colors.editor_active_wrap_guide,
+                &["editorRuler.foreground"],
+            ),
+            (
+                &mut colors.editor_indent_guide,
+                &["editorIndentGuide.background"],
+            ),
+            (
+                &mut colors.editor_indent_guide_active,
+                &["editorIndentGuide.activeBackground"],
+            ),
+            (
+                &mut colors.editor_document_highlight_read_background,
+                &["editor.wordHighlightBackground"],
+            ),
+            (
+                &mut colors.editor_document_highlight_write_background,
+                &["editor.wordHighlightStrongBackground"],
+            ),
+            (
+                &mut colors.editor_document_highlight_bracket_background,
+                &["editorBracketMatch.background"],
+            ),
+            (
+                &mut colors.terminal_background,
+                &["terminal.background", "panel.background"],
+            ),
+            (&mut colors.terminal_foreground, &["terminal.foreground"]),
+            (&mut colors.terminal_ansi_black, &["terminal.ansiBlack"]),
+            (&mut colors.terminal_ansi_red, &["terminal.ansiRed"]),
+            (&mut colors.terminal_ansi_green, &["terminal.ansiGreen"]),
+            (&mut colors.terminal_ansi_yellow, &["terminal.ansiYellow"]),
+            (&mut colors.terminal_ansi_blue, &["terminal.ansiBlue"]),
+            (&mut colors.terminal_ansi_magenta, &["terminal.ansiMagenta"]),
+            (&mut colors.terminal_ansi_cyan, &["terminal.ansiCyan"]),
+            (&mut colors.terminal_ansi_white, &["terminal.ansiWhite"]),
+            (
+                &mut colors.terminal_ansi_bright_black,
+                &["terminal.ansiBrightBlack"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_red,
+                &["terminal.ansiBrightRed"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_green,
+                &["terminal.ansiBrightGreen"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_yellow,
+                &["terminal.ansiBrightYellow"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_blue,
+                &["terminal.ansiBrightBlue"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_magenta,
+                &["terminal.ansiBrightMagenta"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_cyan,
+                &["terminal.ansiBrightCyan"],
+            ),
+            (
+                &mut colors.terminal_ansi_bright_white,
+                &["terminal.ansiBrightWhite"],
+            ),
+            (&mut colors.link_text_hover, &["textLink.activeForeground"]),
+        ] {
+            if let Some(value) = self.color(keys) {
+                *color = value;
+            }
+        }
+
+        if let Some(local) = theme.styles.player.0.first_mut() {
+            let cursor = self.color(&["editorCursor.foreground"]);
+            let selection = self.color(&["editor.selectionBackground"]);
+            *local = PlayerColor {
+                cursor: cursor.unwrap_or(local.cursor),
+                background: cursor.unwrap_or(local.background),
+                selection: selection.unwrap_or(local.selection),
+            };
+        }
+    }
+
+    /// Sets the status colors the theme defines. VS Code themes rarely define the
+    /// background and border of a status, so those are derived from its color when
+    /// missing, rather than kept from the generated theme, whose hue may differ.
+    fn apply_status_colors(&self, theme: &mut Theme) {
+        let background = theme.styles.colors.editor_background;
+        let status = &mut theme.styles.status;
+        for ([color, status_background, status_border], keys, background_keys) in [
+            (
+                [
+                    &mut status.error,
+                    &mut status.error_background,
+                    &mut status.error_border,
+                ],
+                &["editorError.foreground", "errorForeground"][..],
+                &["editorError.background"][..],
+            ),
+            (
+                [
+                    &mut status.warning,
+                    &mut status.warning_background,
+                    &mut status.warning_border,
+                ],
+                &["editorWarning.foreground"],
+                &["editorWarning.background"],
+            ),
+            (
+                [
+                    &mut status.info,
+                    &mut status.info_background,
+                    &mut status.info_border,
+                ],
+                &["editorInfo.foreground"],
+                &["editorInfo.background"],
+            ),
+            (
+                [
+                    &mut status.hint,
+                    &mut status.hint_background,
+                    &mut status.hint_border,
+                ],
+                &["editorInlayHint.foreground", "editorHint.foreground"],
+                &["editorInlayHint.background"],
+            ),
+            (
+                [
+                    &mut status.created,
+                    &mut status.created_background,
+                    &mut status.created_border,
+                ],
+                &[
+                    "gitDecoration.addedResourceForeground",
+                    "editorGutter.addedBackground",
+                ],
+                &["diffEditor.insertedLineBackground"],
+            ),
+            (
+                [
+                    &mut status.modified,
+                    &mut status.modified_background,
+                    &mut status.modified_border,
+                ],
+                &[
+                    "gitDecoration.modifiedResourceForeground",
+                    "editorGutter.modifiedBackground",
+                ],
+                &[],
+            ),
+            (
+                [
+                    &mut status.deleted,
+                    &mut status.deleted_background,
+                    &mut status.deleted_border,
+                ],
+                &[
+                    "gitDecoration.deletedResourceForeground",
+                    "editorGutter.deletedBackground",
+                ],
+                &["diffEditor.removedLineBackground"],
+            ),
+            (
+                [
+                    &mut status.conflict,
+                    &mut status.conflict_background,
+                    &mut status.conflict_border,
+                ],
+                &["gitDecoration.conflictingResourceForeground"],
+                &[],
+            ),
+            (
+                [
+                    &mut status.ignored,
+                    &mut status.ignored_background,
+                    &mut status.ignored_border,
+                ],
+                &["gitDecoration.ignoredResourceForeground"],
+                &[],
+            ),
+        ] {
+            let Some(value) = self.color(keys) else {
+                continue;
+            };
+            *color = value;
+            *status_background = self
+                .color(background_keys)
+                .unwrap_or_else(|| mix(background, value, 0.15));
+            *status_border = mix(background, value, 0.4);
+        }
+    }
+}
+
+fn parse_color(color: &str) -> Option<Hsla> {
+    try_parse_color(color)
+        .map_err(|error| log::warn!("invalid color in VS Code theme: {error}"))
+        .ok()
+}
+
+/// Returns how specific `selector` is when it matches `scope`, i.e. its number of
+/// segments, like TextMate does. Only the last part of a descendant selector like
+/// `meta.tag string` is taken into account.
+fn selector_specificity(selector: &str, scope: &str) -> Option<usize> {
+    let selector = selector.split_whitespace().last()?;
+    let matches = scope == selector
+        || scope
+            .strip_prefix(selector)
+            .map_or(false, |rest| rest.starts_with('.'));
+    matches.then(|| selector.split('.').count())
+}
+
+/// Returns the style of the rule matching one of the scopes of `token` the most
+/// specifically, preferring earlier scopes of the token, and then later rules, like
+/// VS Code does.
+fn syntax_style(rules: &[VsCodeTokenColor], token: &str) -> Option<HighlightStyle> {
+    let (_, scopes) = SYNTAX_SCOPES.iter().find(|(name, _)| *name == token)?;
+    let mut best_match = None;
+    for (rule_ix, rule) in rules.iter().enumerate() {
+        let Some(scope) = &rule.scope else {
+            continue;
+        };
+        for selector in scope.selectors() {
+            for (scope_ix, scope) in scopes.iter().enumerate() {
+                let Some(specificity) = selector_specificity(selector, scope) else {
+                    continue;
+                };
+                let rank = (specificity, std::cmp::Reverse(scope_ix), rule_ix);
+                if best_match
+                    .as_ref()
+                    .map_or(true, |(best_rank, _)| rank > *best_rank)
+                {
+                    best_match = Some((rank, &rule.settings));
+                }
+            }
+        }
+    }
+
+    let (_, settings) = best_match?;
+    let font_style = settings.font_style.as_deref().unwrap_or_default();
+    let style = HighlightStyle {
+        color: settings.foreground.as_deref().and_then(parse_color),
+        background_color: settings.background.as_deref().and_then(parse_color),
+        font_style: font_style.contains("italic").then_some(FontStyle::Italic),
+        font_weight: font_style.contains("bold").then_some(FontWeight::BOLD),
+        ..HighlightStyle::default()
+    };
+    (style != HighlightStyle::default()).then_some(style)
+}
+
+/// Replaces the generated syntax styles with the styles of the VS Code rules
+/// matching them. Tokens without a matching rule take the style of their parent,
+/// e.g. `comment.doc` the one of `comment`, and otherwise keep the generated style.
+fn apply_syntax(rules: &[VsCodeTokenColor], theme: &mut Theme) {
+    let mut highlights = theme.styles.syntax.highlights.clone();
+    let mut matched = HashMap::<&str, HighlightStyle>::default();
+    for (token, _) in SYNTAX_SCOPES {
+        let parent_style = || {
+            let (parent, _) = token.rsplit_once('.')?;
+            matched.get(parent).copied()
+        };
+        let Some(style) = syntax_style(rules, token).or_else(parent_style) else {
+            continue;
+        };
+        matched.insert(*token, style);
+        match highlights
+            .iter_mut()
+            .find(|(name, _)| name.as_str() == *token)
+        {
+            Some((_, highlight)) => *highlight = style,
+            None => highlights.push((token.to_string(), style)),
+        }
+    }
+    theme.styles.syntax = Arc::new(SyntaxTheme { highlights });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Appearance;
+
+    const THEME: &str = r##"{
+        // VS Code themes may have comments.
+        "name": "Test Dark",
+        "type": "dark",
+        "colors": {
+            "editor.background": "#1e1e2e",
+            "editor.foreground": "#cdd6f4",
+            "focusBorder": "#89b4fa",
+            "list.hoverBackground": "#313244",
+            "editorError.foreground": "#f38ba8",
+            "tab.activeBackground": null,
+        },
+        "tokenColors": [
+            { "scope": ["comment"], "settings": { "foreground": "#6c7086", "fontStyle": "italic" } },
+            { "scope": "keyword, storage.type", "settings": { "foreground": "#cba6f7" } },
+            { "scope": "keyword.control", "settings": { "foreground": "#f5c2e7", "fontStyle": "bold" } },
+            { "scope": "string", "settings": { "foreground": "#a6e3a1" } },
+        ],
+    }"##;
+
+    #[test]
+    fn test_vscode_theme_into_theme() {
+        let theme = VsCodeThemeContent::parse(THEME)
+            .unwrap()
+            .unwrap()
+            .into_theme("fallback");
+        assert_eq!(theme.name.as_ref(), "Test Dark");
+        assert_eq!(theme.appearance, Appearance::Dark);
+
+        let color = |hex: &str| try_parse_color(hex).unwrap();
+        let colors = theme.colors();
+        assert_eq!(colors.editor_background, color("#1e1e2e"));
+        assert_eq!(colors.editor_foreground, color("#cdd6f4"));
+        assert_eq!(colors.element_hover, color("#313244"));
+        assert_eq!(colors.border_focused, color("#89b4fa"));
+
+        // Statuses defined by the theme get a matching background.
+        let status = theme.status();
+        assert_eq!(status.error, color("#f38ba8"));
+        assert_eq!(
+            status.error_background,
+            mix(colors.editor_background, status.error, 0.15)
+        );
+
+        let syntax = theme.syntax();
+        // The most specific rule wins.
+        assert_eq!(
+            syntax.get("keyword"),
+            HighlightStyle {
+                color: Some(color("#f5c2e7")),
+                font_weight: Some(FontWeight::BOLD),
+                ..HighlightStyle::default()
+            }
+        );
+        // Tokens without a rule fall back to their parent's.
+        assert_eq!(syntax.get("comment.doc"), syntax.get("comment"));
+        assert_eq!(syntax.get("comment").font_style, Some(FontStyle::Italic));
+        // And otherwise to the generated theme.
+        assert!(syntax.get("type").color.is_some());
+    }
+
+    #[test]
+    fn test_parse_zed_theme_family() {
+        let family = r#"{ "name": "Family", "author": "", "themes": [] }"#;
+        assert!(VsCodeThemeContent::parse(family).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_selector_specificity() {
+        assert_eq!(selector_specificity("keyword", "keyword.control"), Some(1));
+        assert_eq!(
+            selector_specificity("keyword.control", "keyword.control"),
+            Some(2)
+        );
+        assert_eq!(selector_specificity("meta.tag string", "string"), Some(1));
+        assert_eq!(selector_specificity("key", "keyword"), None);
+        assert_eq!(
+            selector_specificity("keyword.control.rust", "keyword.control"),
+            None
+        );
+    }
+}
@@ -140,6 +140,8 @@ impl VsCodeThemeConverter {
             drop_target_background: vscode_colors.list.drop_background.clone(),
             ghost_element_hover: vscode_colors.list.hover_background.clone(),
             ghost_element_selected: vscode_colors.list.active_selection_background.clone(),
+            row_hover: vscode_colors.list.hover_background.clone(),
+            row_selected: vscode_colors.list.active_selection_background.clone(),
             text: vscode_colors
                 .foreground
                 .clone()
@@ -0,0 +1,155 @@
+use gpui::Hsla;
+use ui::{prelude::*, Checkbox, Indicator, Selection, Switch};
+
+/// A few lines of code, as tokens with the syntax highlight they are shown with.
+const SAMPLE_CODE: &[&[(&str, &str)]] = &[
+    &[
+        ("fn ", "keyword"),
+        ("greet", "function"),
+        ("(", "punctuation.bracket"),
+        ("name", "variable"),
+        (": &", "punctuation"),
+        ("str", "type"),
+        (") -> ", "punctuation"),
+        ("String", "type"),
+        (" {", "punctuation.bracket"),
+    ],
+    &[("    // Greet them a few times", "comment")],
+    &[
+        ("    let ", "keyword"),
+        ("count", "variable"),
+        (" = ", "operator"),
+        ("3", "number"),
+        (";", "punctuation"),
+    ],
+    &[
+        ("    format!", "function"),
+        ("(", "punctuation.bracket"),
+        ("\"Hello, {name}!\"", "string"),
+        (")", "punctuation.bracket"),
+    ],
+    &[("}", "punctuation.bracket")],
+];
+
+/// A card showing the colors of the active theme and a few components drawn with
+/// it, shown next to the theme selector while its themes are previewed.
+#[derive(IntoElement)]
+pub struct ThemePreview;
+
+impl ThemePreview {
+    fn render_code(cx: &WindowContext) -> impl IntoElement {
+        let syntax = cx.theme().syntax();
+        v_flex()
+            .p_2()
+            .rounded_md()
+            .bg(cx.theme().colors().editor_background)
+            .children(SAMPLE_CODE.iter().map(|line| {
+                h_flex().children(line.iter().map(|(text, highlight)| {
+                    Label::new(*text)
+                        .font_family(LabelFont::Buffer)
+                        .size(LabelSize::Small)
+                        .color(Color::Custom(syntax.color(highlight)))
+                }))
+            }))
+    }
+
+    fn render_swatch(label: &'static str, color: Hsla, background: Hsla) -> impl IntoElement {
+        h_flex()
+            .gap_1()
+            .px_1p5()
+            .py_0p5()
+            .rounded_sm()
+            .bg(background)
+            .child(div().size_2().rounded_full().bg(color))
+            .child(
+                Label::new(label)
+                    .size(LabelSize::XSmall)
+                    .color(Color::Custom(color)),
+            )
+    }
+}
+
+impl RenderOnce for ThemePreview {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let status = theme.status();
+
+        v_flex()
+            .w(rems(20.))
+            .p_3()
+            .gap_3()
+            .elevation_3(cx)
+            .child(
+                v_flex().child(Label::new(theme.name.clone())).child(
+                    Label::new(if theme.appearance.is_light() {
+                        "Light theme"
+                    } else {
+                        "Dark theme"
+                    })
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+                ),
+            )
+            .child(Self::render_code(cx))
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .gap_1()
+                    .child(Self::render_swatch(
+                        "Error",
+                        status.error,
+                        status.error_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Warning",
+                        status.warning,
+                        status.warning_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Success",
+                        status.success,
+                        status.success_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Info",
+                        status.info,
+                        status.info_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Created",
+                        status.created,
+                        status.created_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Modified",
+                        status.modified,
+                        status.modified_background,
+                    ))
+                    .child(Self::render_swatch(
+                        "Deleted",
+                        status.deleted,
+                        status.deleted_background,
+                    )),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Button::new("preview-filled", "Filled").style(ButtonStyle::Filled))
+                    .child(Button::new("preview-subtle", "Subtle"))
+                    .child(
+                        Button::new("preview-selected", "Selected")
+                            .selected(true)
+                            .style(ButtonStyle::Subtle),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Checkbox::new("preview-checkbox", Selection::Selected))
+                    .child(Switch::new("preview-switch", true))
+                    .child(Indicator::dot().color(Color::Accent))
+                    .child(Label::new("Accent").color(Color::Accent))
+                    .child(Label::new("Muted").color(Color::Muted)),
+            )
+    }
+}
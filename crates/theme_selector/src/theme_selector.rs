@@ -1,3 +1,5 @@
+mod theme_preview;
+
 use client::telemetry::Telemetry;
 use feature_flags::FeatureFlagAppExt;
 use fs::Fs;
@@ -11,10 +13,12 @@ use serde::Deserialize;
 use settings::{update_settings_file, SettingsStore};
 use std::sync::Arc;
 use theme::{Appearance, Theme, ThemeMeta, ThemeRegistry, ThemeSettings};
-use ui::{prelude::*, v_flex, ListItem, ListItemSpacing};
+use ui::{h_flex, prelude::*, v_flex, ListItem, ListItemSpacing};
 use util::ResultExt;
 use workspace::{ui::HighlightedLabel, ModalView, Workspace};
 
+use crate::theme_preview::ThemePreview;
+
 #[derive(PartialEq, Clone, Default, Debug, Deserialize)]
 pub struct Toggle {
     /// A list of theme names to filter the theme selector down to.
@@ -64,7 +68,13 @@ impl FocusableView for ThemeSelector {
 
 impl Render for ThemeSelector {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
-        v_flex().w(rems(34.)).child(self.picker.clone())
+        // The selected theme is applied to the whole window while it's previewed,
+        // so the preview shows it as well.
+        h_flex()
+            .items_start()
+            .gap_2()
+            .child(v_flex().w(rems(34.)).child(self.picker.clone()))
+            .child(ThemePreview)
     }
 }
 
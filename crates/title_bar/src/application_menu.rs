@@ -1,19 +1,35 @@
-use ui::{prelude::*, ContextMenu, NumericStepper, PopoverMenu, PopoverMenuHandle, Tooltip};
+use gpui::impl_actions;
+use serde::Deserialize;
+use ui::{
+    prelude::*, ContextMenu, Menu, MenuBar, MenuBarState, NumericStepper, PopoverMenu,
+    PopoverMenuHandle, Tooltip,
+};
+
+/// Opens the menu of the application menu bar with the given name or mnemonic,
+/// e.g. `["app_menu::OpenApplicationMenu", "f"]` bound to `alt-f` opens the File menu.
+#[derive(Clone, Deserialize, PartialEq)]
+pub struct OpenApplicationMenu(pub String);
+
+impl_actions!(app_menu, [OpenApplicationMenu]);
 
 pub struct ApplicationMenu {
     context_menu_handle: PopoverMenuHandle<ContextMenu>,
+    menu_bar_state: MenuBarState,
 }
 
 impl ApplicationMenu {
     pub fn new(_: &mut ViewContext<Self>) -> Self {
         Self {
             context_menu_handle: PopoverMenuHandle::default(),
+            menu_bar_state: MenuBarState::new(),
         }
     }
-}
 
-impl Render for ApplicationMenu {
-    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+    pub fn open_menu(&mut self, action: &OpenApplicationMenu, cx: &mut ViewContext<Self>) {
+        self.menu_bar_state.open_menu(&action.0, cx);
+    }
+
+    fn render_workspace_menu(&self) -> impl IntoElement {
         PopoverMenu::new("application-menu")
             .menu(move |cx| {
                 ContextMenu::build(cx, move |menu, cx| {
@@ -134,6 +150,23 @@ impl Render for ApplicationMenu {
                     }),
             )
             .with_handle(self.context_menu_handle.clone())
-            .into_any_element()
+    }
+}
+
+impl Render for ApplicationMenu {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // The menus of the app are shown in the window when the platform keeps them
+        // for the app rather than showing them itself.
+        let menus = cx.get_menus().filter(|menus| !menus.is_empty());
+
+        h_flex()
+            .gap_1()
+            .child(self.render_workspace_menu())
+            .when_some(menus, |this, menus| {
+                this.child(
+                    MenuBar::new("application-menu-bar", &self.menu_bar_state)
+                        .menus(menus.into_iter().map(Menu::from)),
+                )
+            })
     }
 }
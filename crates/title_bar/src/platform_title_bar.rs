@@ -0,0 +1,169 @@
+use gpui::{
+    div, px, Action, AnyElement, Decorations, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, Pixels, Render, StatefulInteractiveElement, Styled, ViewContext, WindowContext,
+};
+use smallvec::SmallVec;
+use std::mem;
+use theme::ActiveTheme;
+use ui::{h_flex, prelude::*};
+
+use crate::platforms::{platform_linux, platform_mac, platform_windows};
+
+/// The chrome at the top of a window drawing its own title bar, hosting the
+/// window's content for it, like project and branch labels or call controls.
+///
+/// The title bar reserves room for the window controls of the platform: the
+/// traffic lights on macOS, and the caption buttons on Windows and on Linux
+/// when the window has client-side decorations. Empty parts of the title bar
+/// move the window when dragged and zoom it when double-clicked, so interactive
+/// content should stop the propagation of left mouse down events. In fullscreen,
+/// the window controls are hidden and the title bar no longer reserves room for
+/// them.
+pub struct PlatformTitleBar {
+    id: ElementId,
+    platform_style: PlatformStyle,
+    close_window_action: Box<dyn Action>,
+    children: SmallVec<[AnyElement; 2]>,
+    should_move: bool,
+}
+
+impl PlatformTitleBar {
+    /// Creates a title bar whose close button, drawn on Linux, dispatches the given
+    /// action.
+    pub fn new(id: impl Into<ElementId>, close_window_action: Box<dyn Action>) -> Self {
+        Self {
+            id: id.into(),
+            platform_style: PlatformStyle::platform(),
+            close_window_action,
+            children: SmallVec::new(),
+            should_move: false,
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn height(cx: &mut WindowContext) -> Pixels {
+        (1.75 * cx.rem_size()).max(px(34.))
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn height(_cx: &mut WindowContext) -> Pixels {
+        // todo(windows) instead of hard coded size report the actual size to the Windows platform API
+        px(32.)
+    }
+
+    /// Sets the platform style.
+    pub fn set_platform_style(&mut self, style: PlatformStyle) {
+        self.platform_style = style;
+    }
+
+    /// Replaces the content shown in the title bar the next time it's rendered,
+    /// laid out in a row with space between its elements.
+    pub fn set_children(&mut self, children: impl IntoIterator<Item = AnyElement>) {
+        self.children = children.into_iter().collect();
+    }
+}
+
+impl Render for PlatformTitleBar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let height = Self::height(cx);
+        let supported_controls = cx.window_controls();
+        let decorations = cx.window_decorations();
+        let is_fullscreen = cx.is_fullscreen();
+        let titlebar_color = if cfg!(target_os = "linux") {
+            if cx.is_window_active() && !self.should_move {
+                cx.theme().colors().title_bar_background
+            } else {
+                cx.theme().colors().title_bar_inactive_background
+            }
+        } else {
+            cx.theme().colors().title_bar_background
+        };
+
+        h_flex()
+            .id(self.id.clone())
+            .w_full()
+            .h(height)
+            .map(|this| {
+                if !is_fullscreen && self.platform_style == PlatformStyle::Mac {
+                    this.pl(px(platform_mac::TRAFFIC_LIGHT_PADDING))
+                } else {
+                    this.pl_2()
+                }
+            })
+            .map(|el| match decorations {
+                Decorations::Server => el,
+                Decorations::Client { tiling, .. } => el
+                    .when(!(tiling.top || tiling.right), |el| {
+                        el.rounded_tr(theme::CLIENT_SIDE_DECORATION_ROUNDING)
+                    })
+                    .when(!(tiling.top || tiling.left), |el| {
+                        el.rounded_tl(theme::CLIENT_SIDE_DECORATION_ROUNDING)
+                    })
+                    // this border is to avoid a transparent gap in the rounded corners
+                    .mt(px(-1.))
+                    .border(px(1.))
+                    .border_color(titlebar_color),
+            })
+            .bg(titlebar_color)
+            .content_stretch()
+            .child(
+                div()
+                    .id("titlebar-content")
+                    .flex()
+                    .flex_row()
+                    .justify_between()
+                    .w_full()
+                    // Note: On Windows the title bar behavior is handled by the platform implementation.
+                    .when(self.platform_style != PlatformStyle::Windows, |this| {
+                        this.on_click(|event, cx| {
+                            if event.up.click_count == 2 {
+                                cx.zoom_window();
+                            }
+                        })
+                    })
+                    .children(mem::take(&mut self.children)),
+            )
+            .when(!is_fullscreen, |title_bar| match self.platform_style {
+                PlatformStyle::Mac => title_bar,
+                PlatformStyle::Linux => {
+                    if matches!(decorations, Decorations::Client { .. }) {
+                        title_bar
+                            .child(platform_linux::LinuxWindowControls::new(
+                                self.close_window_action.boxed_clone(),
+                            ))
+                            .when(supported_controls.window_menu, |titlebar| {
+                                titlebar.on_mouse_down(MouseButton::Right, move |ev, cx| {
+                                    cx.show_window_menu(ev.position)
+                                })
+                            })
+                            .on_mouse_move(cx.listener(move |this, _ev, cx| {
+                                if this.should_move {
+                                    this.should_move = false;
+                                    cx.start_window_move();
+                                }
+                            }))
+                            .on_mouse_down_out(cx.listener(move |this, _ev, _cx| {
+                                this.should_move = false;
+                            }))
+                            .on_mouse_up(
+                                MouseButton::Left,
+                                cx.listener(move |this, _ev, _cx| {
+                                    this.should_move = false;
+                                }),
+                            )
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |this, _ev, _cx| {
+                                    this.should_move = true;
+                                }),
+                            )
+                    } else {
+                        title_bar
+                    }
+                }
+                PlatformStyle::Windows => {
+                    title_bar.child(platform_windows::WindowsWindowControls::new(height))
+                }
+            })
+    }
+}
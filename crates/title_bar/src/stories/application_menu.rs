@@ -9,6 +9,10 @@ pub struct ApplicationMenuStory {
     menu: View<ApplicationMenu>,
 }
 
+story::register_story!("application_menu", |cx| {
+    cx.new_view(|cx| ApplicationMenuStory::new(cx)).into()
+});
+
 impl ApplicationMenuStory {
     pub fn new(cx: &mut WindowContext) -> Self {
         Self {
@@ -1,5 +1,6 @@
 mod application_menu;
 mod collab;
+mod platform_title_bar;
 mod platforms;
 mod window_controls;
 
@@ -7,15 +8,16 @@ mod window_controls;
 mod stories;
 
 use crate::application_menu::ApplicationMenu;
-use crate::platforms::{platform_linux, platform_mac, platform_windows};
+pub use crate::application_menu::OpenApplicationMenu;
+pub use crate::platform_title_bar::PlatformTitleBar;
 use auto_update::AutoUpdateStatus;
 use call::ActiveCall;
 use client::{Client, UserStore};
 use feature_flags::{FeatureFlagAppExt, ZedPro};
 use gpui::{
-    actions, div, px, Action, AnyElement, AppContext, Decorations, Element, InteractiveElement,
-    Interactivity, IntoElement, Model, MouseButton, ParentElement, Render, Stateful,
-    StatefulInteractiveElement, Styled, Subscription, View, ViewContext, VisualContext, WeakView,
+    actions, div, Action, AnyElement, AppContext, Element, InteractiveElement, Interactivity,
+    IntoElement, Model, MouseButton, ParentElement, Render, Stateful, StatefulInteractiveElement,
+    Styled, Subscription, View, ViewContext, VisualContext, WeakView,
 };
 use project::{Project, RepositoryEntry};
 use recent_projects::{OpenRemote, RecentProjects};
@@ -51,7 +53,18 @@ actions!(
 pub fn init(cx: &mut AppContext) {
     cx.observe_new_views(|workspace: &mut Workspace, cx| {
         let item = cx.new_view(|cx| TitleBar::new("title-bar", workspace, cx));
-        workspace.set_titlebar_item(item.into(), cx)
+        workspace.set_titlebar_item(item.into(), cx);
+        workspace.register_action(|workspace, action: &OpenApplicationMenu, cx| {
+            let Some(title_bar) = workspace
+                .titlebar_item()
+                .and_then(|item| item.downcast::<TitleBar>().ok())
+            else {
+                return;
+            };
+            if let Some(menu) = title_bar.read(cx).application_menu.clone() {
+                menu.update(cx, |menu, cx| menu.open_menu(action, cx));
+            }
+        });
     })
     .detach();
 }
@@ -64,140 +77,47 @@ pub struct TitleBar {
     user_store: Model<UserStore>,
     client: Arc<Client>,
     workspace: WeakView<Workspace>,
-    should_move: bool,
+    platform_title_bar: View<PlatformTitleBar>,
     application_menu: Option<View<ApplicationMenu>>,
     _subscriptions: Vec<Subscription>,
 }
 
 impl Render for TitleBar {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let close_action = Box::new(workspace::CloseWindow);
-        let height = Self::height(cx);
-        let supported_controls = cx.window_controls();
-        let decorations = cx.window_decorations();
-        let titlebar_color = if cfg!(target_os = "linux") {
-            if cx.is_window_active() && !self.should_move {
-                cx.theme().colors().title_bar_background
-            } else {
-                cx.theme().colors().title_bar_inactive_background
-            }
-        } else {
-            cx.theme().colors().title_bar_background
-        };
-
-        h_flex()
-            .id("titlebar")
-            .w_full()
-            .h(height)
-            .map(|this| {
-                if cx.is_fullscreen() {
-                    this.pl_2()
-                } else if self.platform_style == PlatformStyle::Mac {
-                    this.pl(px(platform_mac::TRAFFIC_LIGHT_PADDING))
-                } else {
-                    this.pl_2()
-                }
-            })
-            .map(|el| match decorations {
-                Decorations::Server => el,
-                Decorations::Client { tiling, .. } => el
-                    .when(!(tiling.top || tiling.right), |el| {
-                        el.rounded_tr(theme::CLIENT_SIDE_DECORATION_ROUNDING)
-                    })
-                    .when(!(tiling.top || tiling.left), |el| {
-                        el.rounded_tl(theme::CLIENT_SIDE_DECORATION_ROUNDING)
-                    })
-                    // this border is to avoid a transparent gap in the rounded corners
-                    .mt(px(-1.))
-                    .border(px(1.))
-                    .border_color(titlebar_color),
-            })
-            .bg(titlebar_color)
-            .content_stretch()
-            .child(
-                div()
-                    .id("titlebar-content")
-                    .flex()
-                    .flex_row()
-                    .justify_between()
-                    .w_full()
-                    // Note: On Windows the title bar behavior is handled by the platform implementation.
-                    .when(self.platform_style != PlatformStyle::Windows, |this| {
-                        this.on_click(|event, cx| {
-                            if event.up.click_count == 2 {
-                                cx.zoom_window();
-                            }
-                        })
-                    })
-                    .child(
-                        h_flex()
-                            .gap_1()
-                            .when_some(self.application_menu.clone(), |this, menu| this.child(menu))
-                            .children(self.render_project_host(cx))
-                            .child(self.render_project_name(cx))
-                            .children(self.render_project_branch(cx))
-                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation()),
-                    )
-                    .child(self.render_collaborator_list(cx))
-                    .child(
-                        h_flex()
-                            .gap_1()
-                            .pr_1()
-                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
-                            .children(self.render_call_controls(cx))
-                            .map(|el| {
-                                let status = self.client.status();
-                                let status = &*status.borrow();
-                                if matches!(status, client::Status::Connected { .. }) {
-                                    el.child(self.render_user_menu_button(cx))
-                                } else {
-                                    el.children(self.render_connection_status(status, cx))
-                                        .child(self.render_sign_in_button(cx))
-                                        .child(self.render_user_menu_button(cx))
-                                }
-                            }),
-                    ),
-            )
-            .when(!cx.is_fullscreen(), |title_bar| match self.platform_style {
-                PlatformStyle::Mac => title_bar,
-                PlatformStyle::Linux => {
-                    if matches!(decorations, Decorations::Client { .. }) {
-                        title_bar
-                            .child(platform_linux::LinuxWindowControls::new(close_action))
-                            .when(supported_controls.window_menu, |titlebar| {
-                                titlebar.on_mouse_down(gpui::MouseButton::Right, move |ev, cx| {
-                                    cx.show_window_menu(ev.position)
-                                })
-                            })
-                            .on_mouse_move(cx.listener(move |this, _ev, cx| {
-                                if this.should_move {
-                                    this.should_move = false;
-                                    cx.start_window_move();
-                                }
-                            }))
-                            .on_mouse_down_out(cx.listener(move |this, _ev, _cx| {
-                                this.should_move = false;
-                            }))
-                            .on_mouse_up(
-                                gpui::MouseButton::Left,
-                                cx.listener(move |this, _ev, _cx| {
-                                    this.should_move = false;
-                                }),
-                            )
-                            .on_mouse_down(
-                                gpui::MouseButton::Left,
-                                cx.listener(move |this, _ev, _cx| {
-                                    this.should_move = true;
-                                }),
-                            )
+        let children = [
+            h_flex()
+                .gap_1()
+                .when_some(self.application_menu.clone(), |this, menu| this.child(menu))
+                .children(self.render_project_host(cx))
+                .child(self.render_project_name(cx))
+                .children(self.render_project_branch(cx))
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                .into_any_element(),
+            self.render_collaborator_list(cx).into_any_element(),
+            h_flex()
+                .gap_1()
+                .pr_1()
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                .children(self.render_call_controls(cx))
+                .map(|el| {
+                    let status = self.client.status();
+                    let status = &*status.borrow();
+                    if matches!(status, client::Status::Connected { .. }) {
+                        el.child(self.render_user_menu_button(cx))
                     } else {
-                        title_bar
+                        el.children(self.render_connection_status(status, cx))
+                            .child(self.render_sign_in_button(cx))
+                            .child(self.render_user_menu_button(cx))
                     }
-                }
-                PlatformStyle::Windows => {
-                    title_bar.child(platform_windows::WindowsWindowControls::new(height))
-                }
-            })
+                })
+                .into_any_element(),
+        ];
+        let platform_style = self.platform_style;
+        self.platform_title_bar.update(cx, |title_bar, _| {
+            title_bar.set_platform_style(platform_style);
+            title_bar.set_children(children);
+        });
+        self.platform_title_bar.clone()
     }
 }
 
@@ -213,6 +133,8 @@ impl TitleBar {
         let active_call = ActiveCall::global(cx);
 
         let platform_style = PlatformStyle::platform();
+        let platform_title_bar =
+            cx.new_view(|_| PlatformTitleBar::new("titlebar", Box::new(workspace::CloseWindow)));
         let application_menu = match platform_style {
             PlatformStyle::Mac => None,
             PlatformStyle::Linux | PlatformStyle::Windows => {
@@ -237,7 +159,7 @@ impl TitleBar {
             children: SmallVec::new(),
             application_menu,
             workspace: workspace.weak_handle(),
-            should_move: false,
+            platform_title_bar,
             project,
             user_store,
             client,
@@ -245,15 +167,8 @@ impl TitleBar {
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
     pub fn height(cx: &mut WindowContext) -> Pixels {
-        (1.75 * cx.rem_size()).max(px(34.))
-    }
-
-    #[cfg(target_os = "windows")]
-    pub fn height(_cx: &mut WindowContext) -> Pixels {
-        // todo(windows) instead of hard coded size report the actual size to the Windows platform API
-        px(32.)
+        PlatformTitleBar::height(cx)
     }
 
     /// Sets the platform style.
@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gpui::{black, font, hsla, HighlightStyle, TextRun};
+use ui::{build_runs, highlight_ranges, HighlightedLabelRuns};
+
+/// Labels like the rows of a file finder, each with a few matched characters.
+fn generate_labels(count: usize) -> Vec<(String, Vec<usize>)> {
+    (0..count)
+        .map(|ix| {
+            let label = format!("crates/component_{ix}/src/module_{}.rs", ix % 17);
+            let highlight_indices = vec![0, 1, 7, 8, 9, label.len() - 4, label.len() - 3];
+            (label, highlight_indices)
+        })
+        .collect()
+}
+
+fn highlighted_label_benchmarks(c: &mut Criterion) {
+    let labels = generate_labels(200);
+    let default_run = TextRun {
+        len: 0,
+        font: font("Zed Plex Sans"),
+        color: black(),
+        background_color: None,
+        underline: None,
+        strikethrough: None,
+        overline: None,
+    };
+    let highlight_color = hsla(0.6, 0.8, 0.5, 1.);
+
+    let mut group = c.benchmark_group("highlighted_label_runs");
+    group.bench_function("derive", |b| {
+        b.iter(|| {
+            for (label, highlight_indices) in &labels {
+                let highlights = highlight_ranges(
+                    label,
+                    highlight_indices,
+                    HighlightStyle {
+                        color: Some(highlight_color),
+                        ..Default::default()
+                    },
+                );
+                black_box(build_runs(label, highlights, &default_run, highlight_color));
+            }
+        })
+    });
+    group.bench_function("cached", |b| {
+        let mut cache = HighlightedLabelRuns::default();
+        b.iter(|| {
+            for (label, highlight_indices) in &labels {
+                black_box(cache.get_or_insert(
+                    label,
+                    highlight_indices,
+                    &default_run,
+                    highlight_color,
+                ));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, highlighted_label_benchmarks);
+criterion_main!(benches);
@@ -1,65 +1,161 @@
+mod accordion;
+mod activity_indicator_item;
+mod adaptive_toolbar;
+mod anchored_popover;
+mod ansi_text;
+mod async_component;
 mod avatar;
+mod banner;
+mod bar;
+mod breadcrumbs;
 mod button;
+mod calendar;
+mod chart;
+mod cheat_sheet;
 mod checkbox;
+mod code_block;
 mod context_menu;
+mod date_picker;
+mod deferred_panel;
+mod diagnostics_indicator;
+mod diff_view;
+mod diff_wrap;
 mod disclosure;
 mod divider;
 mod dropdown_menu;
+mod empty_state;
+mod error_boundary;
 mod facepile;
+mod form;
+mod grid;
+mod hover_card;
 mod icon;
 mod image;
 mod indent_guides;
 mod indicator;
+mod json_diff_preview;
 mod keybinding;
+mod keystroke_recorder;
 mod label;
 mod list;
+mod menu_bar;
+mod minimap;
 mod modal;
 mod numeric_stepper;
+mod overlay;
+mod palette_footer;
+mod peek;
 mod popover;
 mod popover_menu;
+mod progress_bar;
+mod progress_ring;
 mod radio;
 mod right_click_menu;
+mod scroll_anchor;
+mod scroll_view;
 mod scrollbar;
+mod segmented_control;
 mod settings_container;
 mod settings_group;
+mod settings_section;
+mod sidebar;
+mod skeleton;
+mod split_pane;
 mod stack;
+mod stepper;
+mod sticky_ancestors;
+mod surface;
+mod switch;
 mod tab;
 mod tab_bar;
+mod table;
+mod thumbnail;
 mod tool_strip;
+mod toolbar;
 mod tooltip;
+mod viewport;
+mod zoom_toggle;
 
 #[cfg(feature = "stories")]
 mod stories;
 
+pub use accordion::*;
+pub use activity_indicator_item::*;
+pub use adaptive_toolbar::*;
+pub use anchored_popover::*;
+pub use ansi_text::*;
+pub use async_component::*;
 pub use avatar::*;
+pub use banner::*;
+pub use bar::*;
+pub use breadcrumbs::*;
 pub use button::*;
+pub use calendar::*;
+pub use chart::*;
+pub use cheat_sheet::*;
 pub use checkbox::*;
+pub use code_block::*;
 pub use context_menu::*;
+pub use date_picker::*;
+pub use deferred_panel::*;
+pub use diagnostics_indicator::*;
+pub use diff_view::*;
+pub use diff_wrap::*;
 pub use disclosure::*;
 pub use divider::*;
 pub use dropdown_menu::*;
+pub use empty_state::*;
+pub use error_boundary::*;
 pub use facepile::*;
+pub use form::*;
+pub use grid::*;
+pub use hover_card::*;
 pub use icon::*;
 pub use image::*;
 pub use indent_guides::*;
 pub use indicator::*;
+pub use json_diff_preview::*;
 pub use keybinding::*;
+pub use keystroke_recorder::*;
 pub use label::*;
 pub use list::*;
+pub use menu_bar::*;
+pub use minimap::*;
 pub use modal::*;
 pub use numeric_stepper::*;
+pub use overlay::*;
+pub use palette_footer::*;
+pub use peek::*;
 pub use popover::*;
 pub use popover_menu::*;
+pub use progress_bar::*;
+pub use progress_ring::*;
 pub use radio::*;
 pub use right_click_menu::*;
+pub use scroll_anchor::*;
+pub use scroll_view::*;
 pub use scrollbar::*;
+pub use segmented_control::*;
 pub use settings_container::*;
 pub use settings_group::*;
+pub use settings_section::*;
+pub use sidebar::*;
+pub use skeleton::*;
+pub use split_pane::*;
 pub use stack::*;
+pub use stepper::*;
+pub use sticky_ancestors::*;
+pub use surface::*;
+pub use switch::*;
 pub use tab::*;
 pub use tab_bar::*;
+pub use table::*;
+pub use thumbnail::*;
 pub use tool_strip::*;
+pub use toolbar::*;
 pub use tooltip::*;
+pub use viewport::*;
+pub use zoom_toggle::*;
 
 #[cfg(feature = "stories")]
 pub use image::story::*;
@@ -0,0 +1,198 @@
+#![allow(missing_docs)]
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use gpui::{AnimationExt, AnyElement, ClickEvent, MouseButton};
+use smallvec::SmallVec;
+use theme::{transition, MotionDuration, MotionEasing};
+
+use crate::{prelude::*, Disclosure};
+
+const ANIMATION_DURATION: MotionDuration = MotionDuration::Normal;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct AccordionStateInner {
+    is_open: bool,
+    /// Incremented on every toggle, so that each toggle restarts the animation.
+    generation: usize,
+    toggled_at: Option<Instant>,
+    refresh_scheduled_for: Option<usize>,
+}
+
+/// The open state of an [`Accordion`].
+///
+/// Keep this on the view that renders the accordion, so that the section stays
+/// open or closed across renders.
+#[derive(Debug, Clone, Default)]
+pub struct AccordionState(Rc<Cell<AccordionStateInner>>);
+
+impl AccordionState {
+    pub fn new(is_open: bool) -> Self {
+        Self(Rc::new(Cell::new(AccordionStateInner {
+            is_open,
+            ..Default::default()
+        })))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.0.get().is_open
+    }
+
+    pub fn set_open(&self, is_open: bool) {
+        let mut state = self.0.get();
+        if state.is_open != is_open {
+            state.is_open = is_open;
+            state.generation += 1;
+            state.toggled_at = Some(Instant::now());
+            self.0.set(state);
+        }
+    }
+
+    pub fn toggle(&self) {
+        self.set_open(!self.is_open());
+    }
+
+    fn is_animating(&self, duration: Duration) -> bool {
+        self.0
+            .get()
+            .toggled_at
+            .map_or(false, |toggled_at| toggled_at.elapsed() < duration)
+    }
+}
+
+/// A collapsible section: a header row with a disclosure chevron, a label and
+/// optional trailing actions, above a body that expands and collapses with an animation.
+#[derive(IntoElement)]
+pub struct Accordion {
+    id: ElementId,
+    state: AccordionState,
+    label: SharedString,
+    start_slot: Option<AnyElement>,
+    end_slot: Option<AnyElement>,
+    on_toggle: Option<Rc<dyn Fn(bool, &mut WindowContext) + 'static>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Accordion {
+    pub fn new(
+        id: impl Into<ElementId>,
+        label: impl Into<SharedString>,
+        state: &AccordionState,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            label: label.into(),
+            start_slot: None,
+            end_slot: None,
+            on_toggle: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets an element shown between the chevron and the label, such as an icon.
+    pub fn start_slot(mut self, start_slot: impl IntoElement) -> Self {
+        self.start_slot = Some(start_slot.into_any_element());
+        self
+    }
+
+    /// Sets the trailing actions of the header. Clicking them does not toggle the section.
+    pub fn end_slot(mut self, end_slot: impl IntoElement) -> Self {
+        self.end_slot = Some(end_slot.into_any_element());
+        self
+    }
+
+    /// Called with the new open state whenever the section is toggled, e.g. to
+    /// persist it across sessions.
+    pub fn on_toggle(mut self, handler: impl Fn(bool, &mut WindowContext) + 'static) -> Self {
+        self.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Schedules a refresh for when the collapse animation ends, so that the body
+    /// is removed once it has faded out.
+    fn schedule_refresh(&self, duration: Duration, cx: &mut WindowContext) {
+        let mut state = self.state.0.get();
+        if state.refresh_scheduled_for == Some(state.generation) {
+            return;
+        }
+        state.refresh_scheduled_for = Some(state.generation);
+        self.state.0.set(state);
+
+        cx.spawn(|mut cx| async move {
+            cx.background_executor().timer(duration).await;
+            cx.update(|cx| cx.refresh()).ok();
+        })
+        .detach();
+    }
+}
+
+impl ParentElement for Accordion {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Accordion {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let is_open = self.state.is_open();
+        let duration = ANIMATION_DURATION.duration(cx);
+        let is_animating = self.state.is_animating(duration);
+        let generation = self.state.0.get().generation;
+        if !is_open && is_animating {
+            self.schedule_refresh(duration, cx);
+        }
+
+        let toggle = {
+            let state = self.state.clone();
+            let on_toggle = self.on_toggle.clone();
+            move |_: &ClickEvent, cx: &mut WindowContext| {
+                state.toggle();
+                if let Some(on_toggle) = on_toggle.as_ref() {
+                    on_toggle(state.is_open(), cx);
+                }
+                cx.refresh();
+            }
+        };
+
+        let header = h_flex()
+            .id("accordion-header")
+            .w_full()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .rounded_md()
+            .cursor_pointer()
+            .hover(|style| style.bg(cx.theme().colors().ghost_element_hover))
+            .on_click(move |event, cx| toggle(event, cx))
+            .child(Disclosure::new("accordion-disclosure", is_open))
+            .children(self.start_slot)
+            .child(Label::new(self.label).size(LabelSize::Small))
+            .child(div().flex_1())
+            .children(self.end_slot.map(|end_slot| {
+                h_flex()
+                    .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                    .child(end_slot)
+            }));
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .child(header)
+            .when(is_open || is_animating, |this| {
+                let body = v_flex().w_full().pl_5().children(self.children);
+                if !is_animating {
+                    return this.child(body);
+                }
+                this.child(body.with_animation(
+                    ("accordion-body", generation),
+                    transition(ANIMATION_DURATION, MotionEasing::Standard, cx),
+                    move |this, delta| {
+                        let delta = if is_open { delta } else { 1. - delta };
+                        this.opacity(delta).mt(px(-8. * (1. - delta)))
+                    },
+                ))
+            })
+    }
+}
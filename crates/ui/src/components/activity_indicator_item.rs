@@ -0,0 +1,192 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{percentage, AnchorCorner, Animation, AnimationExt, AnyElement, Transformation};
+use theme::animation_duration;
+
+use crate::{prelude::*, ButtonLike, ContextMenu, PopoverMenu, ProgressBar, Tooltip};
+
+/// A task running in the background, like a language server download or indexing.
+#[derive(Clone)]
+pub struct BackgroundActivity {
+    label: SharedString,
+    detail: Option<SharedString>,
+    progress: Option<f32>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext) + 'static>>,
+}
+
+impl BackgroundActivity {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            detail: None,
+            progress: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Sets a secondary message, e.g. the file being indexed.
+    pub fn detail(mut self, detail: impl Into<SharedString>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Sets the progress of the activity, from `0.` to `1.`. Activities without
+    /// progress are shown as indeterminate.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress.clamp(0., 1.));
+        self
+    }
+
+    /// Shows a cancel button for the activity in the popover.
+    pub fn on_cancel(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Summarizes the given activities for the status bar, e.g. "Indexing (40%) + 2 more".
+pub fn activity_summary(activities: &[BackgroundActivity]) -> Option<SharedString> {
+    let (first, rest) = activities.split_first()?;
+    let mut summary = first.label.to_string();
+    if let Some(progress) = first.progress {
+        summary.push_str(&format!(" ({:.0}%)", progress * 100.));
+    }
+    if !rest.is_empty() {
+        summary.push_str(&format!(" + {} more", rest.len()));
+    }
+    Some(summary.into())
+}
+
+/// A status bar entry that aggregates concurrent background activities into a
+/// spinner and a summary, with a popover listing each activity with its progress
+/// and a cancel button.
+///
+/// Renders nothing while there are no activities.
+#[derive(IntoElement)]
+pub struct ActivityIndicatorItem {
+    id: ElementId,
+    activities: Vec<BackgroundActivity>,
+}
+
+impl ActivityIndicatorItem {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            activities: Vec::new(),
+        }
+    }
+
+    pub fn activity(mut self, activity: BackgroundActivity) -> Self {
+        self.activities.push(activity);
+        self
+    }
+
+    pub fn activities(mut self, activities: impl IntoIterator<Item = BackgroundActivity>) -> Self {
+        self.activities.extend(activities);
+        self
+    }
+}
+
+fn render_activity(activity: &BackgroundActivity, ix: usize) -> AnyElement {
+    v_flex()
+        .w_64()
+        .gap_1()
+        .py_1()
+        .child(
+            h_flex()
+                .gap_1()
+                .child(
+                    v_flex()
+                        .flex_1()
+                        .overflow_hidden()
+                        .child(Label::new(activity.label.clone()).size(LabelSize::Small))
+                        .children(activity.detail.clone().map(|detail| {
+                            Label::new(detail)
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted)
+                                .single_line()
+                        })),
+                )
+                .children(activity.on_cancel.clone().map(|on_cancel| {
+                    IconButton::new(("cancel-activity", ix), IconName::Close)
+                        .icon_size(IconSize::Small)
+                        .icon_color(Color::Muted)
+                        .tooltip(|cx| Tooltip::text(crate::t!(cx, "common.cancel"), cx))
+                        .on_click(move |_, cx| on_cancel(cx))
+                })),
+        )
+        .children(
+            activity
+                .progress
+                .map(|progress| ProgressBar::new(progress).show_label(false)),
+        )
+        .into_any_element()
+}
+
+impl RenderOnce for ActivityIndicatorItem {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let Some(summary) = activity_summary(&self.activities) else {
+            return div().into_any_element();
+        };
+        let activities: Rc<[BackgroundActivity]> = self.activities.into();
+
+        PopoverMenu::new(self.id)
+            .anchor(AnchorCorner::BottomLeft)
+            .trigger(
+                ButtonLike::new("activity-indicator-trigger").child(
+                    h_flex()
+                        .gap_1()
+                        .child(
+                            Icon::new(IconName::ArrowCircle)
+                                .size(IconSize::Small)
+                                .with_animation(
+                                    "activity-indicator-spinner",
+                                    Animation::new(animation_duration(Duration::from_secs(2), cx))
+                                        .repeat(),
+                                    |icon, delta| {
+                                        icon.transform(Transformation::rotate(percentage(delta)))
+                                    },
+                                ),
+                        )
+                        .child(Label::new(summary).size(LabelSize::Small)),
+                ),
+            )
+            .menu(move |cx| {
+                let activities = activities.clone();
+                Some(ContextMenu::build(cx, move |menu, _| {
+                    activities.iter().enumerate().fold(
+                        menu.header("Background Activity"),
+                        |menu, (ix, activity)| {
+                            let activity = activity.clone();
+                            menu.custom_row(move |_| render_activity(&activity, ix))
+                        },
+                    )
+                }))
+            })
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_summary() {
+        assert_eq!(activity_summary(&[]), None);
+        assert_eq!(
+            activity_summary(&[BackgroundActivity::new("Indexing").progress(0.4)]),
+            Some("Indexing (40%)".into())
+        );
+        assert_eq!(
+            activity_summary(&[
+                BackgroundActivity::new("Downloading rust-analyzer"),
+                BackgroundActivity::new("Indexing"),
+                BackgroundActivity::new("Formatting"),
+            ]),
+            Some("Downloading rust-analyzer + 2 more".into())
+        );
+    }
+}
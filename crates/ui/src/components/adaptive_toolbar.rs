@@ -0,0 +1,114 @@
+#![allow(missing_docs)]
+use gpui::{
+    point, size, AnyElement, AvailableSpace, Bounds, ContentMask, GlobalElementId, LayoutId, Style,
+};
+
+use crate::prelude::*;
+
+/// A row of buttons that switches all of them to icon-only mode at once when they
+/// don't fit in the available width.
+///
+/// The content is rendered by a function that receives whether the buttons should be
+/// collapsed, and is expected to pass it to [`Button::icon_only`] on each of them, so
+/// that the whole toolbar stays consistent instead of buttons collapsing one by one.
+pub struct AdaptiveToolbar {
+    id: ElementId,
+    render_content: Box<dyn Fn(bool, &mut WindowContext) -> AnyElement>,
+    align_end: bool,
+}
+
+impl AdaptiveToolbar {
+    pub fn new<E: IntoElement>(
+        id: impl Into<ElementId>,
+        render_content: impl Fn(bool, &mut WindowContext) -> E + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            render_content: Box::new(move |collapsed, cx| {
+                render_content(collapsed, cx).into_any_element()
+            }),
+            align_end: false,
+        }
+    }
+
+    /// Aligns the content with the end of the toolbar rather than its start.
+    pub fn align_end(mut self, align_end: bool) -> Self {
+        self.align_end = align_end;
+        self
+    }
+}
+
+impl IntoElement for AdaptiveToolbar {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for AdaptiveToolbar {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<AnyElement>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.flex_grow = 1.;
+        style.flex_shrink = 1.;
+        style.size.width = relative(1.).into();
+        style.size.height = ButtonSize::Default.rems().into();
+        style.min_size.width = px(0.).into();
+
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let available_space = size(
+            AvailableSpace::MinContent,
+            AvailableSpace::Definite(bounds.size.height),
+        );
+
+        let mut content = (self.render_content)(false, cx);
+        let mut content_size = content.layout_as_root(available_space, cx);
+        if content_size.width > bounds.size.width {
+            content = (self.render_content)(true, cx);
+            content_size = content.layout_as_root(available_space, cx);
+        }
+
+        let origin = if self.align_end {
+            bounds.origin + point((bounds.size.width - content_size.width).max(px(0.)), px(0.))
+        } else {
+            bounds.origin
+        };
+        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            content.prepaint_at(origin, cx);
+        });
+        Some(content)
+    }
+
+    fn paint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        content: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(content) = content.as_mut() {
+            cx.with_content_mask(Some(ContentMask { bounds }), |cx| content.paint(cx));
+        }
+    }
+}
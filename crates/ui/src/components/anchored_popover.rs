@@ -0,0 +1,746 @@
+#![allow(missing_docs)]
+
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    deferred, point, size, AnyElement, Bounds, DispatchPhase, Display, GlobalElementId, Hsla,
+    LayoutId, MouseDownEvent, Path, Point, Position, Size, Style,
+};
+use smallvec::{smallvec, SmallVec};
+
+use crate::{prelude::*, OverlayLayer};
+
+/// The size of the arrow that points from a popover to its anchor.
+const ARROW_SIZE: Pixels = px(6.);
+
+/// The side of the anchor on which a popover is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverSide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl PopoverSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            PopoverSide::Top => PopoverSide::Bottom,
+            PopoverSide::Bottom => PopoverSide::Top,
+            PopoverSide::Left => PopoverSide::Right,
+            PopoverSide::Right => PopoverSide::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, PopoverSide::Top | PopoverSide::Bottom)
+    }
+}
+
+/// How a popover is aligned with its anchor, along the side it is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverAlign {
+    Start,
+    Center,
+    End,
+}
+
+/// Where a popover is placed relative to its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PopoverPlacement {
+    pub side: PopoverSide,
+    pub align: PopoverAlign,
+}
+
+impl PopoverPlacement {
+    pub const fn new(side: PopoverSide, align: PopoverAlign) -> Self {
+        Self { side, align }
+    }
+
+    pub const fn bottom_start() -> Self {
+        Self::new(PopoverSide::Bottom, PopoverAlign::Start)
+    }
+
+    pub const fn bottom_end() -> Self {
+        Self::new(PopoverSide::Bottom, PopoverAlign::End)
+    }
+
+    pub const fn top_start() -> Self {
+        Self::new(PopoverSide::Top, PopoverAlign::Start)
+    }
+
+    pub const fn right_start() -> Self {
+        Self::new(PopoverSide::Right, PopoverAlign::Start)
+    }
+
+    /// Returns the same placement on the opposite side of the anchor.
+    pub fn flipped(self) -> Self {
+        Self::new(self.side.opposite(), self.align)
+    }
+}
+
+/// The outcome of positioning a popover relative to its anchor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedPopover {
+    /// The bounds of the popover, in the same coordinate space as the anchor.
+    pub bounds: Bounds<Pixels>,
+    /// The placement that was picked.
+    pub placement: PopoverPlacement,
+    /// The distance between the start of the popover's edge facing the anchor
+    /// and the center of the anchor, used to position an arrow.
+    pub arrow_offset: Pixels,
+}
+
+/// Positions a popover of the given size relative to `anchor`, so that it stays within `limits`.
+///
+/// The placements are tried in order, each one followed by its flipped counterpart, and the
+/// first one that fits on its side of the anchor wins. When none of them fits, the popover is
+/// placed on the side of the first placement with the most room. The popover is then shifted
+/// along the anchor so that it doesn't overflow `limits`.
+pub fn resolve_popover_position(
+    anchor: Bounds<Pixels>,
+    popover_size: Size<Pixels>,
+    limits: Bounds<Pixels>,
+    placements: &[PopoverPlacement],
+    offset: Pixels,
+) -> ResolvedPopover {
+    let preferred = placements
+        .first()
+        .copied()
+        .unwrap_or(PopoverPlacement::bottom_start());
+
+    let mut candidates: SmallVec<[PopoverPlacement; 8]> = SmallVec::new();
+    for placement in placements.iter().copied() {
+        for candidate in [placement, placement.flipped()] {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    let placement = candidates
+        .iter()
+        .copied()
+        .find(|placement| {
+            let bounds = popover_bounds(anchor, popover_size, *placement, offset);
+            fits_on_side(bounds, placement.side, limits)
+        })
+        .unwrap_or_else(|| {
+            if available_space(anchor, preferred.side.opposite(), limits)
+                > available_space(anchor, preferred.side, limits)
+            {
+                preferred.flipped()
+            } else {
+                preferred
+            }
+        });
+
+    let mut bounds = popover_bounds(anchor, popover_size, placement, offset);
+    bounds.origin.x = clamp_start(
+        bounds.origin.x,
+        bounds.size.width,
+        limits.left(),
+        limits.right(),
+    );
+    bounds.origin.y = clamp_start(
+        bounds.origin.y,
+        bounds.size.height,
+        limits.top(),
+        limits.bottom(),
+    );
+
+    let arrow_offset = if placement.side.is_vertical() {
+        (anchor.center().x - bounds.left()).clamp(px(0.), bounds.size.width)
+    } else {
+        (anchor.center().y - bounds.top()).clamp(px(0.), bounds.size.height)
+    };
+
+    ResolvedPopover {
+        bounds,
+        placement,
+        arrow_offset,
+    }
+}
+
+fn popover_bounds(
+    anchor: Bounds<Pixels>,
+    size: Size<Pixels>,
+    placement: PopoverPlacement,
+    offset: Pixels,
+) -> Bounds<Pixels> {
+    let align = |start: Pixels, anchor_len: Pixels, len: Pixels| match placement.align {
+        PopoverAlign::Start => start,
+        PopoverAlign::Center => start + (anchor_len - len) / 2.,
+        PopoverAlign::End => start + anchor_len - len,
+    };
+
+    let origin = match placement.side {
+        PopoverSide::Top => point(
+            align(anchor.left(), anchor.size.width, size.width),
+            anchor.top() - offset - size.height,
+        ),
+        PopoverSide::Bottom => point(
+            align(anchor.left(), anchor.size.width, size.width),
+            anchor.bottom() + offset,
+        ),
+        PopoverSide::Left => point(
+            anchor.left() - offset - size.width,
+            align(anchor.top(), anchor.size.height, size.height),
+        ),
+        PopoverSide::Right => point(
+            anchor.right() + offset,
+            align(anchor.top(), anchor.size.height, size.height),
+        ),
+    };
+
+    Bounds { origin, size }
+}
+
+fn fits_on_side(bounds: Bounds<Pixels>, side: PopoverSide, limits: Bounds<Pixels>) -> bool {
+    if side.is_vertical() {
+        bounds.top() >= limits.top() && bounds.bottom() <= limits.bottom()
+    } else {
+        bounds.left() >= limits.left() && bounds.right() <= limits.right()
+    }
+}
+
+fn available_space(anchor: Bounds<Pixels>, side: PopoverSide, limits: Bounds<Pixels>) -> Pixels {
+    match side {
+        PopoverSide::Top => anchor.top() - limits.top(),
+        PopoverSide::Bottom => limits.bottom() - anchor.bottom(),
+        PopoverSide::Left => anchor.left() - limits.left(),
+        PopoverSide::Right => limits.right() - anchor.right(),
+    }
+}
+
+/// Shifts a segment starting at `start` so that it stays within `min..max`,
+/// preferring to keep its start visible when it's longer than the range.
+fn clamp_start(start: Pixels, len: Pixels, min: Pixels, max: Pixels) -> Pixels {
+    let end = start + len;
+    let start = if end > max {
+        start - (end - max)
+    } else {
+        start
+    };
+    start.max(min)
+}
+
+/// Renders arbitrary content next to a trigger element, or next to explicit anchor bounds.
+///
+/// The content is positioned by [`resolve_popover_position`], so it moves to the other side
+/// of its anchor and along it instead of overflowing the window. Popovers can be nested:
+/// clicks inside a nested popover don't dismiss the popovers it was opened from, as long
+/// as the content of the outer popover doesn't clip its overflow.
+pub struct AnchoredPopover {
+    trigger: Option<AnyElement>,
+    content: Option<AnyElement>,
+    anchor_bounds: Option<Bounds<Pixels>>,
+    placements: SmallVec<[PopoverPlacement; 4]>,
+    offset: Pixels,
+    margin: Pixels,
+    arrow: bool,
+    priority: usize,
+    on_dismiss: Option<Rc<dyn Fn(&mut WindowContext) + 'static>>,
+    on_reposition: Option<Rc<dyn Fn(&ResolvedPopover, &mut WindowContext) + 'static>>,
+}
+
+impl AnchoredPopover {
+    pub fn new() -> Self {
+        Self {
+            trigger: None,
+            content: None,
+            anchor_bounds: None,
+            placements: smallvec![PopoverPlacement::bottom_start()],
+            offset: px(4.),
+            margin: px(8.),
+            arrow: false,
+            priority: OverlayLayer::Popover.priority(),
+            on_dismiss: None,
+            on_reposition: None,
+        }
+    }
+
+    /// Sets the element the popover is anchored to. It is rendered in place.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// Anchors the popover to the given window bounds instead of its trigger,
+    /// e.g. to the mouse position for context menus.
+    pub fn anchor_bounds(mut self, bounds: Bounds<Pixels>) -> Self {
+        self.anchor_bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the content of the popover. The popover is only shown when it has content.
+    pub fn content<E: IntoElement>(mut self, content: impl Into<Option<E>>) -> Self {
+        self.content = content.into().map(IntoElement::into_any_element);
+        self
+    }
+
+    /// Sets the preferred placement, replacing the default one.
+    pub fn placement(mut self, placement: PopoverPlacement) -> Self {
+        self.placements = smallvec![placement];
+        self
+    }
+
+    /// Adds a placement to try when the previous ones don't fit.
+    pub fn fallback_placement(mut self, placement: PopoverPlacement) -> Self {
+        self.placements.push(placement);
+        self
+    }
+
+    /// Sets the gap between the anchor and the popover.
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the minimum distance between the popover and the edges of the window.
+    pub fn margin(mut self, margin: Pixels) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Draws an arrow from the popover to the center of its anchor.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Sets the priority with which the popover is drawn above the rest of the window.
+    /// Defaults to the priority of [`OverlayLayer::Popover`].
+    ///
+    /// Nested popovers are drawn along with the popover they were opened from.
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Called when the user clicks outside of the popover or presses escape within it.
+    pub fn on_dismiss(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called whenever the popover is positioned, with where it ended up, e.g. to
+    /// align an arrow drawn by the content. The popover is positioned on every
+    /// frame, so it follows its anchor as the window resizes or the anchor scrolls.
+    pub fn on_reposition(
+        mut self,
+        handler: impl Fn(&ResolvedPopover, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_reposition = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Default for AnchoredPopover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct AnchoredPopoverLayoutState {
+    trigger_layout_id: Option<LayoutId>,
+    anchor: Rc<Cell<Option<Bounds<Pixels>>>>,
+    anchor_visible: Rc<Cell<bool>>,
+    popover: Option<AnyElement>,
+}
+
+thread_local! {
+    /// How many popovers are currently being laid out. Popovers opened from within another
+    /// popover are already drawn above the rest of the window, and can't be deferred again.
+    static POPOVER_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+impl Element for AnchoredPopover {
+    type RequestLayoutState = AnchoredPopoverLayoutState;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let anchor = Rc::new(Cell::new(self.anchor_bounds));
+        let anchor_visible = Rc::new(Cell::new(true));
+        let trigger_layout_id = self
+            .trigger
+            .as_mut()
+            .map(|trigger| trigger.request_layout(cx));
+
+        let is_nested = POPOVER_DEPTH.get() > 0;
+
+        let mut popover = self.content.take().map(|content| {
+            let on_dismiss = self.on_dismiss.clone();
+            let content = div()
+                .occlude()
+                .on_key_down(move |event, cx| {
+                    if event.keystroke.key == "escape" {
+                        if let Some(on_dismiss) = on_dismiss.as_ref() {
+                            cx.stop_propagation();
+                            on_dismiss(cx);
+                        }
+                    }
+                })
+                .child(content)
+                .into_any_element();
+
+            let positioner = PopoverPositioner {
+                child: content,
+                anchor: anchor.clone(),
+                anchor_visible: anchor_visible.clone(),
+                placements: self.placements.clone(),
+                offset: if self.arrow {
+                    self.offset + ARROW_SIZE
+                } else {
+                    self.offset
+                },
+                margin: self.margin,
+                arrow: self.arrow,
+                on_dismiss: self.on_dismiss.clone(),
+                on_reposition: self.on_reposition.clone(),
+            };
+
+            if is_nested {
+                positioner.into_any_element()
+            } else {
+                deferred(positioner).with_priority(self.priority).into_any()
+            }
+        });
+
+        let popover_layout_id = popover.as_mut().map(|popover| popover.request_layout(cx));
+        let layout_id = cx.request_layout(
+            Style::default(),
+            trigger_layout_id.into_iter().chain(popover_layout_id),
+        );
+
+        (
+            layout_id,
+            AnchoredPopoverLayoutState {
+                trigger_layout_id,
+                anchor,
+                anchor_visible,
+                popover,
+            },
+        )
+    }
+
+    fn prepaint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(trigger) = self.trigger.as_mut() {
+            trigger.prepaint(cx);
+        }
+
+        if self.anchor_bounds.is_none() {
+            if let Some(trigger_layout_id) = request_layout.trigger_layout_id {
+                let anchor = cx.layout_bounds(trigger_layout_id);
+                request_layout.anchor.set(Some(anchor));
+                // The trigger is clipped by the scrollable containers around it, so
+                // it's hidden once it has scrolled out of all of them.
+                request_layout
+                    .anchor_visible
+                    .set(cx.content_mask().bounds.intersects(&anchor));
+            }
+        }
+
+        if let Some(popover) = request_layout.popover.as_mut() {
+            popover.prepaint(cx);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(trigger) = self.trigger.as_mut() {
+            trigger.paint(cx);
+        }
+
+        if let Some(popover) = request_layout.popover.as_mut() {
+            popover.paint(cx);
+        }
+    }
+}
+
+impl IntoElement for AnchoredPopover {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+/// Positions the popover content once the bounds of its anchor are known.
+struct PopoverPositioner {
+    child: AnyElement,
+    anchor: Rc<Cell<Option<Bounds<Pixels>>>>,
+    anchor_visible: Rc<Cell<bool>>,
+    placements: SmallVec<[PopoverPlacement; 4]>,
+    offset: Pixels,
+    margin: Pixels,
+    arrow: bool,
+    on_dismiss: Option<Rc<dyn Fn(&mut WindowContext) + 'static>>,
+    on_reposition: Option<Rc<dyn Fn(&ResolvedPopover, &mut WindowContext) + 'static>>,
+}
+
+impl Element for PopoverPositioner {
+    type RequestLayoutState = LayoutId;
+    type PrepaintState = Option<ResolvedPopover>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        POPOVER_DEPTH.set(POPOVER_DEPTH.get() + 1);
+        let child_layout_id = self.child.request_layout(cx);
+        POPOVER_DEPTH.set(POPOVER_DEPTH.get() - 1);
+
+        let style = Style {
+            position: Position::Absolute,
+            display: Display::Flex,
+            ..Style::default()
+        };
+
+        (
+            cx.request_layout(style, Some(child_layout_id)),
+            child_layout_id,
+        )
+    }
+
+    fn prepaint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        child_layout_id: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let anchor = self.anchor.get()?;
+        let viewport = Bounds {
+            origin: Point::default(),
+            size: cx.viewport_size(),
+        };
+        // Dismiss the popover instead of pointing at nothing once its anchor has
+        // scrolled away or the window was resized past it.
+        if !self.anchor_visible.get() || !viewport.intersects(&anchor) {
+            if let Some(on_dismiss) = self.on_dismiss.clone() {
+                cx.defer(move |cx| on_dismiss(cx));
+            }
+            return None;
+        }
+
+        let child_size = cx.layout_bounds(*child_layout_id).size;
+        let limits = Bounds {
+            origin: viewport.origin + point(self.margin, self.margin),
+            size: size(
+                (viewport.size.width - self.margin * 2.).max(px(0.)),
+                (viewport.size.height - self.margin * 2.).max(px(0.)),
+            ),
+        };
+
+        let resolved =
+            resolve_popover_position(anchor, child_size, limits, &self.placements, self.offset);
+        let offset = resolved.bounds.origin - bounds.origin;
+        cx.with_element_offset(point(offset.x.round(), offset.y.round()), |cx| {
+            self.child.prepaint(cx)
+        });
+        if let Some(on_reposition) = self.on_reposition.as_ref() {
+            on_reposition(&resolved, cx);
+        }
+
+        Some(resolved)
+    }
+
+    fn paint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _child_layout_id: &mut Self::RequestLayoutState,
+        resolved: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let Some(resolved) = *resolved else {
+            return;
+        };
+
+        // Registered before the content is painted, so that the content handles mouse
+        // events first. Stopping the propagation for clicks within this popover keeps
+        // the popovers below it open.
+        let anchor = self.anchor.get();
+        let on_dismiss = self.on_dismiss.clone();
+        cx.on_mouse_event(move |event: &MouseDownEvent, phase, cx| {
+            if phase != DispatchPhase::Bubble {
+                return;
+            }
+            if resolved.bounds.contains(&event.position) {
+                cx.stop_propagation();
+            } else if !anchor.map_or(false, |anchor| anchor.contains(&event.position)) {
+                if let Some(on_dismiss) = on_dismiss.as_ref() {
+                    on_dismiss(cx);
+                }
+            }
+        });
+
+        self.child.paint(cx);
+
+        if self.arrow {
+            let color = cx.theme().colors().elevated_surface_background;
+            paint_arrow(resolved, color, cx);
+        }
+    }
+}
+
+impl IntoElement for PopoverPositioner {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+fn paint_arrow(resolved: ResolvedPopover, color: Hsla, cx: &mut WindowContext) {
+    let bounds = resolved.bounds;
+    let min_offset = ARROW_SIZE * 2.;
+    let (tip, base_start, base_end) = match resolved.placement.side {
+        PopoverSide::Bottom | PopoverSide::Top => {
+            let x = bounds.left()
+                + resolved
+                    .arrow_offset
+                    .clamp(min_offset, (bounds.size.width - min_offset).max(min_offset));
+            let (edge, direction) = if resolved.placement.side == PopoverSide::Bottom {
+                (bounds.top(), -1.)
+            } else {
+                (bounds.bottom(), 1.)
+            };
+            (
+                point(x, edge + ARROW_SIZE * direction),
+                point(x - ARROW_SIZE, edge),
+                point(x + ARROW_SIZE, edge),
+            )
+        }
+        PopoverSide::Right | PopoverSide::Left => {
+            let y = bounds.top()
+                + resolved.arrow_offset.clamp(
+                    min_offset,
+                    (bounds.size.height - min_offset).max(min_offset),
+                );
+            let (edge, direction) = if resolved.placement.side == PopoverSide::Right {
+                (bounds.left(), -1.)
+            } else {
+                (bounds.right(), 1.)
+            };
+            (
+                point(edge + ARROW_SIZE * direction, y),
+                point(edge, y - ARROW_SIZE),
+                point(edge, y + ARROW_SIZE),
+            )
+        }
+    };
+
+    let mut path = Path::new(base_start);
+    path.line_to(tip);
+    path.line_to(base_end);
+    path.line_to(base_start);
+    cx.paint_path(path, color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x: f32, y: f32, width: f32, height: f32) -> Bounds<Pixels> {
+        Bounds {
+            origin: point(px(x), px(y)),
+            size: size(px(width), px(height)),
+        }
+    }
+
+    #[test]
+    fn test_preferred_placement_fits() {
+        let resolved = resolve_popover_position(
+            bounds(100., 100., 50., 20.),
+            size(px(80.), px(40.)),
+            bounds(0., 0., 500., 500.),
+            &[PopoverPlacement::bottom_start()],
+            px(4.),
+        );
+        assert_eq!(resolved.placement, PopoverPlacement::bottom_start());
+        assert_eq!(resolved.bounds, bounds(100., 124., 80., 40.));
+        assert_eq!(resolved.arrow_offset, px(25.));
+    }
+
+    #[test]
+    fn test_flips_to_opposite_side() {
+        let resolved = resolve_popover_position(
+            bounds(100., 460., 50., 20.),
+            size(px(80.), px(40.)),
+            bounds(0., 0., 500., 500.),
+            &[PopoverPlacement::bottom_start()],
+            px(4.),
+        );
+        assert_eq!(resolved.placement, PopoverPlacement::top_start());
+        assert_eq!(resolved.bounds, bounds(100., 416., 80., 40.));
+    }
+
+    #[test]
+    fn test_shifts_along_anchor() {
+        let resolved = resolve_popover_position(
+            bounds(450., 100., 50., 20.),
+            size(px(80.), px(40.)),
+            bounds(0., 0., 500., 500.),
+            &[PopoverPlacement::bottom_start()],
+            px(0.),
+        );
+        assert_eq!(resolved.placement, PopoverPlacement::bottom_start());
+        assert_eq!(resolved.bounds, bounds(420., 120., 80., 40.));
+        assert_eq!(resolved.arrow_offset, px(55.));
+    }
+
+    #[test]
+    fn test_fallback_placements() {
+        // Neither below nor above the anchor has room, but the right side does.
+        let resolved = resolve_popover_position(
+            bounds(10., 40., 50., 20.),
+            size(px(80.), px(60.)),
+            bounds(0., 0., 500., 100.),
+            &[
+                PopoverPlacement::bottom_start(),
+                PopoverPlacement::right_start(),
+            ],
+            px(0.),
+        );
+        assert_eq!(resolved.placement, PopoverPlacement::right_start());
+        assert_eq!(resolved.bounds, bounds(60., 40., 80., 60.));
+
+        // Nothing fits, so the side of the preferred placement with the most room is used.
+        let resolved = resolve_popover_position(
+            bounds(10., 30., 50., 20.),
+            size(px(80.), px(60.)),
+            bounds(0., 0., 100., 100.),
+            &[PopoverPlacement::top_start()],
+            px(0.),
+        );
+        assert_eq!(resolved.placement, PopoverPlacement::bottom_start());
+        assert_eq!(resolved.bounds, bounds(10., 40., 80., 60.));
+    }
+}
@@ -0,0 +1,296 @@
+#![allow(missing_docs)]
+use std::ops::Range;
+
+use gpui::{rgb, FontStyle, FontWeight, HighlightStyle, Hsla, StyledText, UnderlineStyle};
+use settings::Settings;
+use theme::{ThemeColors, ThemeSettings};
+
+use crate::prelude::*;
+
+/// A color set by an ANSI escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// One of the 256 colors of the xterm palette, the first 16 of which are
+    /// the named colors of the theme's terminal palette.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    pub fn to_hsla(self, colors: &ThemeColors) -> Hsla {
+        match self {
+            Self::Indexed(index @ 0..=15) => [
+                colors.terminal_ansi_black,
+                colors.terminal_ansi_red,
+                colors.terminal_ansi_green,
+                colors.terminal_ansi_yellow,
+                colors.terminal_ansi_blue,
+                colors.terminal_ansi_magenta,
+                colors.terminal_ansi_cyan,
+                colors.terminal_ansi_white,
+                colors.terminal_ansi_bright_black,
+                colors.terminal_ansi_bright_red,
+                colors.terminal_ansi_bright_green,
+                colors.terminal_ansi_bright_yellow,
+                colors.terminal_ansi_bright_blue,
+                colors.terminal_ansi_bright_magenta,
+                colors.terminal_ansi_bright_cyan,
+                colors.terminal_ansi_bright_white,
+            ][index as usize],
+            Self::Indexed(index) => {
+                let (r, g, b) = xterm_palette_rgb(index);
+                Self::Rgb(r, g, b).to_hsla(colors)
+            }
+            Self::Rgb(r, g, b) => rgb((r as u32) << 16 | (g as u32) << 8 | b as u32).into(),
+        }
+    }
+}
+
+/// Returns the color of the given index of the xterm palette past the 16 named
+/// colors: a 6x6x6 color cube, then a grayscale ramp.
+fn xterm_palette_rgb(index: u8) -> (u8, u8, u8) {
+    if let Some(index) = index.checked_sub(232) {
+        let level = 8 + index * 10;
+        return (level, level, level);
+    }
+    let index = index.saturating_sub(16);
+    let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+    (level(index / 36), level(index / 6 % 6), level(index % 6))
+}
+
+/// The style of a run of text set by ANSI SGR ("select graphic rendition")
+/// sequences.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiStyle {
+    pub foreground: Option<AnsiColor>,
+    pub background: Option<AnsiColor>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl AnsiStyle {
+    pub fn to_highlight_style(self, colors: &ThemeColors) -> HighlightStyle {
+        HighlightStyle {
+            color: self.foreground.map(|color| color.to_hsla(colors)),
+            background_color: self.background.map(|color| color.to_hsla(colors)),
+            font_weight: self.bold.then_some(FontWeight::BOLD),
+            font_style: self.italic.then_some(FontStyle::Italic),
+            underline: self.underline.then(|| UnderlineStyle {
+                thickness: px(1.),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn apply_sgr(&mut self, params: &str) {
+        let mut params = params
+            .split([';', ':'])
+            .map(|param| param.parse::<u16>().unwrap_or(0));
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.foreground = Some(AnsiColor::Indexed(param as u8 - 30)),
+                38 => self.foreground = parse_extended_color(&mut params),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(AnsiColor::Indexed(param as u8 - 40)),
+                48 => self.background = parse_extended_color(&mut params),
+                49 => self.background = None,
+                90..=97 => self.foreground = Some(AnsiColor::Indexed(param as u8 - 90 + 8)),
+                100..=107 => self.background = Some(AnsiColor::Indexed(param as u8 - 100 + 8)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parses the color of a `38` or `48` SGR parameter: `5;n` for the 256 color
+/// palette or `2;r;g;b` for true color.
+fn parse_extended_color(params: &mut impl Iterator<Item = u16>) -> Option<AnsiColor> {
+    let byte = |value: Option<u16>| value.map(|value| value.min(255) as u8);
+    match params.next()? {
+        5 => Some(AnsiColor::Indexed(byte(params.next())?)),
+        2 => Some(AnsiColor::Rgb(
+            byte(params.next())?,
+            byte(params.next())?,
+            byte(params.next())?,
+        )),
+        _ => None,
+    }
+}
+
+/// Strips the escape sequences from the given string, returning the plain text
+/// and the byte ranges of it styled by SGR sequences.
+///
+/// Other control sequences, like cursor movements and window titles, are
+/// dropped since the text isn't laid out on a terminal grid.
+pub fn parse_ansi(input: &str) -> (String, Vec<(Range<usize>, AnsiStyle)>) {
+    let mut text = String::with_capacity(input.len());
+    let mut runs = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut run_start = 0;
+
+    let mut rest = input;
+    while let Some(escape_ix) = rest.find('\x1b') {
+        text.push_str(&rest[..escape_ix]);
+        rest = &rest[escape_ix + 1..];
+
+        let new_style = match rest.as_bytes().first() {
+            // CSI: parameters, then a final byte in `@`..=`~`.
+            Some(b'[') => {
+                let Some(end) = rest[1..].find(|c| ('@'..='~').contains(&c)) else {
+                    rest = "";
+                    break;
+                };
+                let (params, command) = (&rest[1..end + 1], &rest[end + 1..end + 2]);
+                rest = &rest[end + 2..];
+                let mut new_style = style;
+                if command == "m" {
+                    new_style.apply_sgr(params);
+                }
+                new_style
+            }
+            // OSC: terminated by BEL or ST (`ESC \`).
+            Some(b']') => {
+                let end = rest.find(['\x07', '\x1b']).unwrap_or(rest.len());
+                rest = &rest[end..];
+                rest = rest
+                    .strip_prefix('\x07')
+                    .or_else(|| rest.strip_prefix("\x1b\\"))
+                    .unwrap_or(rest);
+                style
+            }
+            Some(_) => {
+                let next = rest.chars().next().map_or(0, char::len_utf8);
+                rest = &rest[next..];
+                style
+            }
+            None => style,
+        };
+
+        if new_style != style {
+            if style != AnsiStyle::default() && run_start < text.len() {
+                runs.push((run_start..text.len(), style));
+            }
+            style = new_style;
+            run_start = text.len();
+        }
+    }
+    text.push_str(rest);
+    if style != AnsiStyle::default() && run_start < text.len() {
+        runs.push((run_start..text.len(), style));
+    }
+
+    (text, runs)
+}
+
+/// Text with ANSI escape sequences, like the output of a task or a build, rendered
+/// in the buffer font with the colors of the theme's terminal palette.
+#[derive(IntoElement)]
+pub struct AnsiText {
+    text: String,
+    runs: Vec<(Range<usize>, AnsiStyle)>,
+}
+
+impl AnsiText {
+    pub fn new(input: impl AsRef<str>) -> Self {
+        let (text, runs) = parse_ansi(input.as_ref());
+        Self { text, runs }
+    }
+}
+
+impl RenderOnce for AnsiText {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.clone();
+        let mut text_style = cx.text_style();
+        text_style.font_family = buffer_font.family;
+        text_style.font_features = buffer_font.features;
+        text_style.font_fallbacks = buffer_font.fallbacks;
+        text_style.font_weight = buffer_font.weight;
+
+        let colors = cx.theme().colors();
+        let highlights = self
+            .runs
+            .into_iter()
+            .map(|(range, style)| (range, style.to_highlight_style(colors)))
+            .collect::<Vec<_>>();
+
+        div().child(StyledText::new(self.text).with_highlights(&text_style, highlights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ansi() {
+        assert_eq!(parse_ansi("plain text"), ("plain text".into(), vec![]));
+
+        let red = AnsiStyle {
+            foreground: Some(AnsiColor::Indexed(1)),
+            ..Default::default()
+        };
+        let bold_red = AnsiStyle { bold: true, ..red };
+        assert_eq!(
+            parse_ansi("\x1b[31merror\x1b[1m:\x1b[0m mismatched types"),
+            (
+                "error: mismatched types".into(),
+                vec![(0..5, red), (5..6, bold_red)]
+            )
+        );
+
+        assert_eq!(
+            parse_ansi("\x1b[38;5;208;48;2;10;20;30;4mwarn\x1b[24;39;49m ok"),
+            (
+                "warn ok".into(),
+                vec![(
+                    0..4,
+                    AnsiStyle {
+                        foreground: Some(AnsiColor::Indexed(208)),
+                        background: Some(AnsiColor::Rgb(10, 20, 30)),
+                        underline: true,
+                        ..Default::default()
+                    }
+                )]
+            )
+        );
+
+        // Bright colors, and `ESC[m` as a reset.
+        assert_eq!(
+            parse_ansi("\x1b[92mok\x1b[m"),
+            (
+                "ok".into(),
+                vec![(
+                    0..2,
+                    AnsiStyle {
+                        foreground: Some(AnsiColor::Indexed(10)),
+                        ..Default::default()
+                    }
+                )]
+            )
+        );
+
+        // Other sequences are dropped, as are unterminated ones.
+        assert_eq!(
+            parse_ansi("\x1b]0;title\x07\x1b[2Kdone\x1b[1"),
+            ("done".into(), vec![])
+        );
+    }
+
+    #[test]
+    fn test_xterm_palette() {
+        assert_eq!(xterm_palette_rgb(16), (0, 0, 0));
+        assert_eq!(xterm_palette_rgb(196), (255, 0, 0));
+        assert_eq!(xterm_palette_rgb(110), (135, 175, 215));
+        assert_eq!(xterm_palette_rgb(244), (128, 128, 128));
+        assert_eq!(xterm_palette_rgb(255), (238, 238, 238));
+    }
+}
@@ -0,0 +1,239 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    percentage, Animation, AnimationExt, AnyElement, Bounds, GlobalElementId, LayoutId, Pixels,
+    Task, Transformation,
+};
+use theme::animation_duration;
+
+use crate::{prelude::*, Banner, BannerSeverity};
+
+type Load<T> = Rc<dyn Fn(&mut WindowContext) -> Task<anyhow::Result<T>>>;
+type RenderReady<T> = Box<dyn FnOnce(&T, &mut WindowContext) -> AnyElement>;
+
+enum AsyncStatus<T> {
+    /// Nothing is loading, either because the element was just created or because
+    /// loading is being retried.
+    Idle,
+    Pending,
+    Ready(Rc<T>),
+    Failed(SharedString),
+}
+
+struct AsyncElementState<T> {
+    status: Rc<RefCell<AsyncStatus<T>>>,
+    /// Dropped with the element state when the element stops being rendered,
+    /// which cancels the load.
+    _load_task: Option<Task<()>>,
+}
+
+/// Renders data loaded by a future, standardizing how panels and other views
+/// show async data: a spinner while it loads, an error banner with a retry
+/// button if it fails, and the rendered data once it's ready.
+///
+/// The data is loaded the first time the element is rendered and kept in its
+/// element state, so the future is only created again when retrying. Loading
+/// is cancelled when the element stops being rendered, e.g. when its view is
+/// dropped.
+pub struct Async<T: 'static> {
+    id: ElementId,
+    load: Load<T>,
+    render_ready: Option<RenderReady<T>>,
+    pending: Option<AnyElement>,
+    error_message: SharedString,
+}
+
+impl<T: 'static> Async<T> {
+    /// Creates an element loading its data with the task returned by `load`,
+    /// then rendering it with `render`.
+    pub fn new<E: IntoElement>(
+        id: impl Into<ElementId>,
+        load: impl Fn(&mut WindowContext) -> Task<anyhow::Result<T>> + 'static,
+        render: impl FnOnce(&T, &mut WindowContext) -> E + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            load: Rc::new(load),
+            render_ready: Some(Box::new(move |data, cx| {
+                render(data, cx).into_any_element()
+            })),
+            pending: None,
+            error_message: "Failed to load this content.".into(),
+        }
+    }
+
+    /// Replaces the spinner shown while the data loads, e.g. with a [`Skeleton`](crate::Skeleton)
+    /// of the content.
+    pub fn pending(mut self, pending: impl IntoElement) -> Self {
+        self.pending = Some(pending.into_any_element());
+        self
+    }
+
+    /// Replaces the message shown above the error details if loading fails.
+    pub fn error_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.error_message = message.into();
+        self
+    }
+}
+
+impl<T: IntoElement + Clone + 'static> Async<T> {
+    /// Creates an element rendering the component produced by the task returned
+    /// by `load`.
+    pub fn component(
+        id: impl Into<ElementId>,
+        load: impl Fn(&mut WindowContext) -> Task<anyhow::Result<T>> + 'static,
+    ) -> Self {
+        Self::new(id, load, |component: &T, _| component.clone())
+    }
+}
+
+impl<T: 'static> Async<T> {
+    fn start_loading(
+        &self,
+        status: &Rc<RefCell<AsyncStatus<T>>>,
+        cx: &mut WindowContext,
+    ) -> Task<()> {
+        *status.borrow_mut() = AsyncStatus::Pending;
+        let load = (self.load)(cx);
+        let status = status.clone();
+        let view_id = cx.parent_view_id();
+        cx.spawn(|mut cx| async move {
+            let result = load.await;
+            *status.borrow_mut() = match result {
+                Ok(data) => AsyncStatus::Ready(Rc::new(data)),
+                Err(error) => AsyncStatus::Failed(format!("{error:#}").into()),
+            };
+            cx.update(|cx| match view_id {
+                Some(view_id) => cx.notify(view_id),
+                None => cx.refresh(),
+            })
+            .ok();
+        })
+    }
+
+    fn render_pending(&mut self, cx: &WindowContext) -> AnyElement {
+        if let Some(pending) = self.pending.take() {
+            return pending;
+        }
+        h_flex()
+            .w_full()
+            .py_2()
+            .justify_center()
+            .child(
+                Icon::new(IconName::ArrowCircle)
+                    .size(IconSize::Small)
+                    .color(Color::Muted)
+                    .with_animation(
+                        "async-spinner",
+                        Animation::new(animation_duration(Duration::from_secs(2), cx)).repeat(),
+                        |icon, delta| icon.transform(Transformation::rotate(percentage(delta))),
+                    ),
+            )
+            .into_any_element()
+    }
+
+    fn render_failed(
+        &self,
+        error: SharedString,
+        status: Rc<RefCell<AsyncStatus<T>>>,
+        view_id: Option<gpui::EntityId>,
+    ) -> AnyElement {
+        v_flex()
+            .w_full()
+            .gap_1()
+            .child(
+                Banner::new("async-error-banner", self.error_message.clone())
+                    .severity(BannerSeverity::Error)
+                    .action("Retry", move |_, cx| {
+                        *status.borrow_mut() = AsyncStatus::Idle;
+                        match view_id {
+                            Some(view_id) => cx.notify(view_id),
+                            None => cx.refresh(),
+                        }
+                    }),
+            )
+            .child(
+                div().px_2().child(
+                    Label::new(error)
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                ),
+            )
+            .into_any_element()
+    }
+}
+
+impl<T: 'static> Element for Async<T> {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state::<AsyncElementState<T>, _>(id.unwrap(), |state, cx| {
+            let mut state = state.unwrap_or_else(|| AsyncElementState {
+                status: Rc::new(RefCell::new(AsyncStatus::Idle)),
+                _load_task: None,
+            });
+            if matches!(*state.status.borrow(), AsyncStatus::Idle) {
+                state._load_task = Some(self.start_loading(&state.status, cx));
+            }
+
+            let status = match &*state.status.borrow() {
+                AsyncStatus::Idle | AsyncStatus::Pending => None,
+                AsyncStatus::Ready(data) => Some(Ok(data.clone())),
+                AsyncStatus::Failed(error) => Some(Err(error.clone())),
+            };
+            let mut element = match status {
+                None => self.render_pending(cx),
+                Some(Ok(data)) => {
+                    let render_ready = self.render_ready.take().unwrap();
+                    render_ready(&data, cx)
+                }
+                Some(Err(error)) => {
+                    self.render_failed(error, state.status.clone(), cx.parent_view_id())
+                }
+            };
+            let layout_id = element.request_layout(cx);
+            ((layout_id, element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx);
+    }
+}
+
+impl<T: 'static> IntoElement for Async<T> {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
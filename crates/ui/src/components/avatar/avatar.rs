@@ -1,6 +1,6 @@
-use crate::prelude::*;
+use crate::{prelude::*, Image, ImageFit};
 
-use gpui::{img, AnyElement, Hsla, ImageSource, Img, IntoElement, Styled};
+use gpui::{AnyElement, Hsla, ImageSource, IntoElement, Styled};
 
 /// An element that renders a user avatar with customizable appearance options.
 ///
@@ -16,7 +16,7 @@ use gpui::{img, AnyElement, Hsla, ImageSource, Img, IntoElement, Styled};
 /// ```
 #[derive(IntoElement)]
 pub struct Avatar {
-    image: Img,
+    image: Image,
     size: Option<AbsoluteLength>,
     border_color: Option<Hsla>,
     indicator: Option<AnyElement>,
@@ -26,7 +26,7 @@ impl Avatar {
     /// Creates a new avatar element with the specified image source.
     pub fn new(src: impl Into<ImageSource>) -> Self {
         Avatar {
-            image: img(src),
+            image: Image::new(src).fit(ImageFit::Cover).fallback(div()),
             size: None,
             border_color: None,
             indicator: None,
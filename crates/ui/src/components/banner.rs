@@ -0,0 +1,162 @@
+#![allow(missing_docs)]
+use gpui::{ClickEvent, Hsla};
+
+use crate::{prelude::*, Tooltip};
+
+type ClickHandler = Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum BannerSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl BannerSeverity {
+    fn icon(self) -> IconName {
+        match self {
+            BannerSeverity::Info => IconName::Bell,
+            BannerSeverity::Success => IconName::Check,
+            BannerSeverity::Warning => IconName::Warning,
+            BannerSeverity::Error => IconName::XCircle,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            BannerSeverity::Info => Color::Info,
+            BannerSeverity::Success => Color::Success,
+            BannerSeverity::Warning => Color::Warning,
+            BannerSeverity::Error => Color::Error,
+        }
+    }
+
+    fn background_and_border(self, cx: &WindowContext) -> (Hsla, Hsla) {
+        let status = cx.theme().status();
+        match self {
+            BannerSeverity::Info => (status.info_background, status.info_border),
+            BannerSeverity::Success => (status.success_background, status.success_border),
+            BannerSeverity::Warning => (status.warning_background, status.warning_border),
+            BannerSeverity::Error => (status.error_background, status.error_border),
+        }
+    }
+}
+
+/// A persistent message shown inline, e.g. at the top of a panel, as opposed to a
+/// transient notification.
+///
+/// To keep a dismissed banner hidden, e.g. across restarts, record the dismissal in
+/// the handler passed to [`Banner::on_dismiss`] and stop rendering the banner.
+#[derive(IntoElement)]
+pub struct Banner {
+    id: ElementId,
+    severity: BannerSeverity,
+    message: SharedString,
+    icon: Option<IconName>,
+    show_icon: bool,
+    actions: Vec<(SharedString, ClickHandler)>,
+    on_dismiss: Option<ClickHandler>,
+}
+
+impl Banner {
+    pub fn new(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            severity: BannerSeverity::default(),
+            message: message.into(),
+            icon: None,
+            show_icon: true,
+            actions: Vec::new(),
+            on_dismiss: None,
+        }
+    }
+
+    pub fn severity(mut self, severity: BannerSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Replaces the icon of the severity.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn show_icon(mut self, show_icon: bool) -> Self {
+        self.show_icon = show_icon;
+        self
+    }
+
+    /// Adds a link-styled action after the message.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.actions.push((label.into(), Box::new(handler)));
+        self
+    }
+
+    /// Shows a dismiss button, calling the handler when it is clicked.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Box::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Banner {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (background, border) = self.severity.background_and_border(cx);
+        let icon = self.icon.unwrap_or(self.severity.icon());
+        let color = self.severity.color();
+
+        h_flex()
+            .id(self.id)
+            .w_full()
+            .items_start()
+            .gap_2()
+            .px_2()
+            .py_1p5()
+            .rounded_md()
+            .border_1()
+            .border_color(border)
+            .bg(background)
+            .when(self.show_icon, |this| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .pt_0p5()
+                        .child(Icon::new(icon).size(IconSize::Small).color(color)),
+                )
+            })
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .flex_wrap()
+                    .gap_x_2()
+                    .child(Label::new(self.message).size(LabelSize::Small))
+                    .children(self.actions.into_iter().enumerate().map(
+                        |(ix, (label, handler))| {
+                            Button::new(("banner-action", ix), label)
+                                .style(ButtonStyle::Transparent)
+                                .label_size(LabelSize::Small)
+                                .color(Color::Accent)
+                                .on_click(handler)
+                        },
+                    )),
+            )
+            .children(self.on_dismiss.map(|on_dismiss| {
+                IconButton::new("dismiss-banner", IconName::Close)
+                    .icon_size(IconSize::Small)
+                    .icon_color(Color::Muted)
+                    .tooltip(|cx| Tooltip::text(crate::t!(cx, "common.dismiss"), cx))
+                    .on_click(on_dismiss)
+            }))
+    }
+}
@@ -0,0 +1,119 @@
+#![allow(missing_docs)]
+use gpui::AnyElement;
+
+use crate::prelude::*;
+
+/// The slots shared by [`HeaderBar`] and [`FooterBar`].
+#[derive(Default)]
+struct BarSlots {
+    start_slot: Option<AnyElement>,
+    center_slot: Option<AnyElement>,
+    end_slot: Option<AnyElement>,
+    height: Option<Rems>,
+}
+
+impl BarSlots {
+    /// Lays out the slots in a fixed-height row. The start and end slots take up an
+    /// equal share of the remaining width, so that the center slot stays centered.
+    fn render(self, cx: &WindowContext) -> Div {
+        h_flex()
+            .flex_none()
+            .w_full()
+            .h(self.height.unwrap_or(rems_from_px(32.)))
+            .px(Spacing::Medium.rems(cx))
+            .gap(Spacing::Small.rems(cx))
+            .bg(cx.theme().colors().panel_background)
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .gap(Spacing::Small.rems(cx))
+                    .children(self.start_slot),
+            )
+            .children(
+                self.center_slot
+                    .map(|center_slot| h_flex().flex_none().child(center_slot)),
+            )
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .justify_end()
+                    .gap(Spacing::Small.rems(cx))
+                    .children(self.end_slot),
+            )
+    }
+}
+
+macro_rules! impl_bar_builders {
+    ($bar:ident) => {
+        impl $bar {
+            pub fn new() -> Self {
+                Self {
+                    slots: BarSlots::default(),
+                }
+            }
+
+            pub fn start_slot<E: IntoElement>(mut self, start_slot: impl Into<Option<E>>) -> Self {
+                self.slots.start_slot = start_slot.into().map(IntoElement::into_any_element);
+                self
+            }
+
+            pub fn center_slot<E: IntoElement>(
+                mut self,
+                center_slot: impl Into<Option<E>>,
+            ) -> Self {
+                self.slots.center_slot = center_slot.into().map(IntoElement::into_any_element);
+                self
+            }
+
+            pub fn end_slot<E: IntoElement>(mut self, end_slot: impl Into<Option<E>>) -> Self {
+                self.slots.end_slot = end_slot.into().map(IntoElement::into_any_element);
+                self
+            }
+
+            /// Overrides the height of the bar, which defaults to 32px.
+            pub fn height(mut self, height: Rems) -> Self {
+                self.slots.height = Some(height);
+                self
+            }
+        }
+
+        impl Default for $bar {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+/// A fixed-height bar with start, center and end slots and a bottom border, for the
+/// top of modals, panels and pickers.
+#[derive(IntoElement)]
+pub struct HeaderBar {
+    slots: BarSlots,
+}
+
+impl_bar_builders!(HeaderBar);
+
+impl RenderOnce for HeaderBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.slots.render(cx).border_b_1()
+    }
+}
+
+/// A fixed-height bar with start, center and end slots and a top border, for the
+/// bottom of modals, panels and pickers.
+#[derive(IntoElement)]
+pub struct FooterBar {
+    slots: BarSlots,
+}
+
+impl_bar_builders!(FooterBar);
+
+impl RenderOnce for FooterBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.slots.render(cx).border_t_1()
+    }
+}
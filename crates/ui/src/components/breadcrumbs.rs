@@ -0,0 +1,280 @@
+#![allow(missing_docs)]
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::{
+    size, AnyElement, AvailableSpace, Bounds, ContentMask, GlobalElementId, LayoutId, Style,
+};
+
+use crate::{prelude::*, ButtonLike, ContextMenu, PopoverMenu};
+
+/// A single segment of a [`Breadcrumbs`] trail.
+#[derive(Clone)]
+pub struct BreadcrumbSegment {
+    label: SharedString,
+    icon: Option<IconName>,
+    on_click: Option<Rc<dyn Fn(&mut WindowContext) + 'static>>,
+}
+
+impl BreadcrumbSegment {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Option<IconName>>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// A trail of segments, such as a file path followed by the enclosing symbols.
+///
+/// When the trail doesn't fit in the available width, the middle segments are
+/// collapsed into a "…" menu. The first and the last segments are always shown.
+pub struct Breadcrumbs {
+    id: ElementId,
+    segments: Vec<BreadcrumbSegment>,
+    separator: SharedString,
+}
+
+impl Breadcrumbs {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            segments: Vec::new(),
+            separator: "›".into(),
+        }
+    }
+
+    pub fn segment(mut self, segment: BreadcrumbSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    pub fn segments(mut self, segments: impl IntoIterator<Item = BreadcrumbSegment>) -> Self {
+        self.segments.extend(segments);
+        self
+    }
+
+    /// Sets the text rendered between two segments.
+    pub fn separator(mut self, separator: impl Into<SharedString>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    fn render_trail(&self, collapsed_count: usize) -> AnyElement {
+        let collapsed_range = collapsed_segment_range(self.segments.len(), collapsed_count);
+        let last_ix = self.segments.len().saturating_sub(1);
+
+        let mut items: Vec<AnyElement> = Vec::new();
+        for (ix, segment) in self.segments.iter().enumerate() {
+            if let Some(collapsed_range) = collapsed_range.as_ref() {
+                if collapsed_range.contains(&ix) {
+                    if ix == collapsed_range.start {
+                        items.push(self.render_separator());
+                        items.push(self.render_overflow_menu(collapsed_range.clone()));
+                    }
+                    continue;
+                }
+            }
+
+            if ix > 0 {
+                items.push(self.render_separator());
+            }
+            items.push(render_segment(ix, segment, ix == last_ix));
+        }
+
+        h_flex().gap_1().children(items).into_any_element()
+    }
+
+    fn render_separator(&self) -> AnyElement {
+        Label::new(self.separator.clone())
+            .color(Color::Placeholder)
+            .into_any_element()
+    }
+
+    fn render_overflow_menu(&self, collapsed_range: Range<usize>) -> AnyElement {
+        let segments: Rc<[BreadcrumbSegment]> = self.segments[collapsed_range].into();
+
+        PopoverMenu::new("breadcrumbs-overflow")
+            .trigger(
+                IconButton::new("breadcrumbs-overflow-trigger", IconName::Ellipsis)
+                    .icon_size(IconSize::Small)
+                    .icon_color(Color::Muted),
+            )
+            .menu(move |cx| {
+                let segments = segments.clone();
+                Some(ContextMenu::build(cx, move |mut menu, _| {
+                    for segment in segments.iter() {
+                        let label = segment.label.clone();
+                        let icon = segment.icon;
+                        let on_click = segment.on_click.clone();
+                        menu = menu.custom_entry(
+                            move |_| {
+                                h_flex()
+                                    .gap_1()
+                                    .children(icon.map(|icon| {
+                                        Icon::new(icon).size(IconSize::Small).color(Color::Muted)
+                                    }))
+                                    .child(Label::new(label.clone()))
+                                    .into_any_element()
+                            },
+                            move |cx| {
+                                if let Some(on_click) = on_click.as_ref() {
+                                    on_click(cx);
+                                }
+                            },
+                        );
+                    }
+                    menu
+                }))
+            })
+            .into_any_element()
+    }
+}
+
+fn render_segment(ix: usize, segment: &BreadcrumbSegment, is_last: bool) -> AnyElement {
+    let color = if is_last {
+        Color::Default
+    } else {
+        Color::Muted
+    };
+
+    ButtonLike::new(("breadcrumb", ix))
+        .style(ButtonStyle::Transparent)
+        .disabled(segment.on_click.is_none())
+        .child(
+            h_flex()
+                .gap_1()
+                .children(
+                    segment
+                        .icon
+                        .map(|icon| Icon::new(icon).size(IconSize::Small).color(color)),
+                )
+                .child(Label::new(segment.label.clone()).color(color).single_line()),
+        )
+        .when_some(segment.on_click.clone(), |this, on_click| {
+            this.on_click(move |_, cx| on_click(cx))
+        })
+        .into_any_element()
+}
+
+/// Returns the range of segments hidden behind the overflow menu when
+/// `collapsed_count` segments have to be collapsed.
+///
+/// Segments are collapsed starting right after the first one, so that the
+/// innermost segments stay visible the longest.
+pub fn collapsed_segment_range(
+    segment_count: usize,
+    collapsed_count: usize,
+) -> Option<Range<usize>> {
+    let collapsible_count = segment_count.saturating_sub(2);
+    let collapsed_count = collapsed_count.min(collapsible_count);
+    if collapsed_count == 0 {
+        return None;
+    }
+
+    Some(1..1 + collapsed_count)
+}
+
+impl Element for Breadcrumbs {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<AnyElement>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.flex_grow = 1.;
+        style.flex_shrink = 1.;
+        style.size.width = relative(1.).into();
+        style.size.height = ButtonSize::Default.rems().into();
+        style.min_size.width = px(0.).into();
+
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let available_space = size(
+            AvailableSpace::MinContent,
+            AvailableSpace::Definite(bounds.size.height),
+        );
+
+        // Collapse one more segment at a time until the trail fits.
+        let max_collapsed_count = self.segments.len().saturating_sub(2);
+        let mut trail = None;
+        for collapsed_count in 0..=max_collapsed_count {
+            let mut candidate = self.render_trail(collapsed_count);
+            let fits = candidate.layout_as_root(available_space, cx).width <= bounds.size.width;
+            trail = Some(candidate);
+            if fits {
+                break;
+            }
+        }
+
+        let mut trail = trail?;
+        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            trail.prepaint_at(bounds.origin, cx);
+        });
+        Some(trail)
+    }
+
+    fn paint(
+        &mut self,
+        _global_id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        trail: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(trail) = trail.as_mut() {
+            cx.with_content_mask(Some(ContentMask { bounds }), |cx| trail.paint(cx));
+        }
+    }
+}
+
+impl IntoElement for Breadcrumbs {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsed_segment_range() {
+        assert_eq!(collapsed_segment_range(5, 0), None);
+        assert_eq!(collapsed_segment_range(5, 1), Some(1..2));
+        assert_eq!(collapsed_segment_range(5, 3), Some(1..4));
+        // The first and the last segments are never collapsed.
+        assert_eq!(collapsed_segment_range(5, 10), Some(1..4));
+        assert_eq!(collapsed_segment_range(2, 1), None);
+        assert_eq!(collapsed_segment_range(0, 1), None);
+    }
+}
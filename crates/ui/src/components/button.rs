@@ -1,10 +1,12 @@
 mod button;
 mod button_icon;
 mod button_like;
+mod danger_button;
 mod icon_button;
 mod toggle_button;
 
 pub use button::*;
 pub use button_like::*;
+pub use danger_button::*;
 pub use icon_button::*;
 pub use toggle_button::*;
@@ -1,7 +1,13 @@
 #![allow(missing_docs)]
-use gpui::{AnyView, DefiniteLength};
+use std::time::Duration;
 
-use crate::{prelude::*, ElevationIndex, IconPosition, KeyBinding, Spacing};
+use gpui::{
+    percentage, Animation, AnimationExt, AnyView, DefiniteLength, FocusHandle, Transformation,
+};
+use theme::animation_duration;
+
+use crate::utils::GestureHandlers;
+use crate::{prelude::*, ElevationIndex, IconPosition, KeyBinding, Spacing, Tooltip};
 use crate::{
     ButtonCommon, ButtonLike, ButtonSize, ButtonStyle, IconName, IconSize, Label, LineHeightStyle,
 };
@@ -75,6 +81,18 @@ use super::button_icon::ButtonIcon;
 ///     });
 /// ```
 ///
+/// **A loading button**, shown while the action it started is running. The label
+/// is replaced by a spinner, keeping the width of the button, and clicks are
+/// ignored until it's done.
+///
+/// ```
+/// use ui::prelude::*;
+///
+/// let button = Button::new("save", "Save")
+///     .style(ButtonStyle::Filled)
+///     .loading(true);
+/// ```
+///
 #[derive(IntoElement)]
 pub struct Button {
     base: ButtonLike,
@@ -87,10 +105,13 @@ pub struct Button {
     icon_position: Option<IconPosition>,
     icon_size: Option<IconSize>,
     icon_color: Option<Color>,
+    end_icon: Option<IconName>,
     selected_icon: Option<IconName>,
     selected_icon_color: Option<Color>,
     key_binding: Option<KeyBinding>,
     alpha: Option<f32>,
+    icon_only: bool,
+    loading: bool,
 }
 
 impl Button {
@@ -112,13 +133,23 @@ impl Button {
             icon_position: None,
             icon_size: None,
             icon_color: None,
+            end_icon: None,
             selected_icon: None,
             selected_icon_color: None,
             key_binding: None,
             alpha: None,
+            icon_only: false,
+            loading: false,
         }
     }
 
+    /// Attaches a stable identifier for tests to locate the button by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.base = self.base.test_id(id);
+        self
+    }
+
     /// Sets the color of the button's label.
     pub fn color(mut self, label_color: impl Into<Option<Color>>) -> Self {
         self.label_color = label_color.into();
@@ -167,6 +198,13 @@ impl Button {
         self
     }
 
+    /// Assigns an icon drawn after the label, alongside the [`icon`](Button::icon)
+    /// drawn before it, e.g. a chevron on a button opening a menu.
+    pub fn end_icon(mut self, icon: impl Into<Option<IconName>>) -> Self {
+        self.end_icon = icon.into();
+        self
+    }
+
     /// Chooses an icon to display when the button is in a selected state.
     pub fn selected_icon(mut self, icon: impl Into<Option<IconName>>) -> Self {
         self.selected_icon = icon.into();
@@ -190,6 +228,30 @@ impl Button {
         self.alpha = Some(alpha);
         self
     }
+
+    /// Drops the label and shows only the icon, with the label in a tooltip unless
+    /// the button already has one.
+    ///
+    /// This is meant for narrow containers, see [`AdaptiveToolbar`](crate::AdaptiveToolbar).
+    /// Has no effect on buttons without an icon.
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    /// Replaces the label with a spinner and ignores clicks, while the action the
+    /// button started is running.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Makes the button focusable with the given handle, so that it can be clicked
+    /// with Enter or Space. See [`ButtonLike::track_focus`].
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.base = self.base.track_focus(focus_handle);
+        self
+    }
 }
 
 impl Selectable for Button {
@@ -265,6 +327,12 @@ impl Disableable for Button {
     }
 }
 
+impl Gestures for Button {
+    fn gesture_handlers(&mut self) -> &mut GestureHandlers {
+        self.base.gesture_handlers()
+    }
+}
+
 impl Clickable for Button {
     /// Sets the click event handler for the button.
     fn on_click(
@@ -377,17 +445,30 @@ impl ButtonCommon for Button {
     }
 }
 
+impl StyleOverride for Button {
+    fn style_overrides(&mut self) -> &mut StyleOverrides {
+        self.base.style_overrides()
+    }
+}
+
 impl RenderOnce for Button {
     #[allow(refining_impl_trait)]
     fn render(self, cx: &mut WindowContext) -> ButtonLike {
         let is_disabled = self.base.disabled;
         let is_selected = self.base.selected;
+        let icon_background = self.base.icon_background(cx);
 
         let label = self
             .selected_label
             .filter(|_| is_selected)
             .unwrap_or(self.label);
 
+        let mut base = self.base;
+        base.loading = self.loading;
+        if base.accessibility.name.is_none() {
+            base.accessibility.name = Some(label.clone());
+        }
+
         let label_color = if is_disabled {
             Color::Disabled
         } else if is_selected {
@@ -396,8 +477,51 @@ impl RenderOnce for Button {
             self.label_color.unwrap_or_default()
         };
 
-        self.base.child(
+        // The spinner is drawn over the hidden content, so that the button keeps
+        // its width while loading.
+        let icon_size = self.icon_size;
+        let spinner = self.loading.then(|| {
+            div()
+                .absolute()
+                .inset_0()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    Icon::new(IconName::ArrowCircle)
+                        .size(icon_size.unwrap_or(IconSize::Small))
+                        .color(label_color)
+                        .with_animation(
+                            "button-loading",
+                            Animation::new(animation_duration(Duration::from_secs(2), cx)).repeat(),
+                            |icon, delta| icon.transform(Transformation::rotate(percentage(delta))),
+                        ),
+                )
+        });
+
+        if let Some(icon) = self.icon.filter(|_| self.icon_only) {
+            if base.tooltip.is_none() {
+                base = base.tooltip(move |cx| Tooltip::text(label.clone(), cx));
+            }
+            return base
+                .child(
+                    div().when(self.loading, |this| this.invisible()).child(
+                        ButtonIcon::new(icon)
+                            .disabled(is_disabled)
+                            .selected(is_selected)
+                            .selected_icon(self.selected_icon)
+                            .selected_icon_color(self.selected_icon_color)
+                            .size(self.icon_size)
+                            .color(self.icon_color)
+                            .parent_background(icon_background),
+                    ),
+                )
+                .children(spinner);
+        }
+
+        base.child(
             h_flex()
+                .when(self.loading, |this| this.invisible())
                 .gap(Spacing::Small.rems(cx))
                 .when(self.icon_position == Some(IconPosition::Start), |this| {
                     this.children(self.icon.map(|icon| {
@@ -408,6 +532,7 @@ impl RenderOnce for Button {
                             .selected_icon_color(self.selected_icon_color)
                             .size(self.icon_size)
                             .color(self.icon_color)
+                            .parent_background(icon_background)
                     }))
                 })
                 .child(
@@ -432,8 +557,17 @@ impl RenderOnce for Button {
                             .selected_icon_color(self.selected_icon_color)
                             .size(self.icon_size)
                             .color(self.icon_color)
+                            .parent_background(icon_background)
                     }))
-                }),
+                })
+                .children(self.end_icon.map(|icon| {
+                    ButtonIcon::new(icon)
+                        .disabled(is_disabled)
+                        .size(self.icon_size)
+                        .color(self.icon_color)
+                        .parent_background(icon_background)
+                })),
         )
+        .children(spinner)
     }
 }
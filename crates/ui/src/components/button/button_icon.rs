@@ -1,4 +1,6 @@
 #![allow(missing_docs)]
+use gpui::Hsla;
+
 use crate::{prelude::*, Icon, IconName, IconSize};
 
 /// An icon that appears within a button.
@@ -15,6 +17,7 @@ pub(super) struct ButtonIcon {
     selected_icon: Option<IconName>,
     selected_icon_color: Option<Color>,
     selected_style: Option<ButtonStyle>,
+    parent_background: Option<Hsla>,
 }
 
 impl ButtonIcon {
@@ -28,6 +31,7 @@ impl ButtonIcon {
             selected_icon: None,
             selected_icon_color: None,
             selected_style: None,
+            parent_background: None,
         }
     }
 
@@ -56,6 +60,11 @@ impl ButtonIcon {
         self.selected_icon_color = color.into();
         self
     }
+
+    pub fn parent_background(mut self, background: Option<Hsla>) -> Self {
+        self.parent_background = background;
+        self
+    }
 }
 
 impl Disableable for ButtonIcon {
@@ -96,6 +105,9 @@ impl RenderOnce for ButtonIcon {
             self.color
         };
 
-        Icon::new(icon).size(self.size).color(icon_color)
+        Icon::new(icon)
+            .size(self.size)
+            .color(icon_color)
+            .parent_background(self.parent_background)
     }
 }
@@ -1,9 +1,19 @@
 #![allow(missing_docs)]
-use gpui::{relative, CursorStyle, DefiniteLength, MouseButton};
+use std::rc::Rc;
+
+use gpui::{
+    relative, AccessibilityProperties, AccessibilityRole, CursorStyle, DefiniteLength, FocusHandle,
+    KeyDownEvent, MouseButton,
+};
 use gpui::{transparent_black, AnyElement, AnyView, ClickEvent, Hsla, Rems};
+use settings::Settings;
 use smallvec::SmallVec;
+use theme::ThemeSettings;
 
-use crate::{prelude::*, ElevationIndex, Spacing};
+use crate::{
+    prelude::*, utils::GestureHandlers, ElevationIndex, InteractiveColors, InteractiveState,
+    InteractiveStates, Spacing,
+};
 
 /// A trait for buttons that can be Selected. Enables setting the [`ButtonStyle`] of a button when it is selected.
 pub trait SelectableButton: Selectable {
@@ -107,6 +117,11 @@ impl From<ButtonStyle> for Color {
 }
 
 /// The visual appearance of a button.
+///
+/// Use [`ButtonStyle::Filled`] for the primary action of a form or dialog,
+/// [`ButtonStyle::Outline`] next to it for secondary actions,
+/// [`ButtonStyle::Subtle`] for ghost buttons in toolbars and lists, and
+/// `ButtonStyle::Tinted(TintColor::Negative)` for destructive actions.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
 pub enum ButtonStyle {
     /// A filled button with a solid background color. Provides emphasis versus
@@ -122,6 +137,10 @@ pub enum ButtonStyle {
     #[default]
     Subtle,
 
+    /// A button with a transparent background and a border, for secondary actions
+    /// next to a filled button.
+    Outline,
+
     /// Used for buttons that only change foreground color on hover and active states.
     ///
     /// TODO: Better docs for this.
@@ -176,6 +195,12 @@ impl ButtonStyle {
                 label_color: Color::Default.color(cx),
                 icon_color: Color::Default.color(cx),
             },
+            ButtonStyle::Outline => ButtonLikeStyles {
+                background: cx.theme().colors().ghost_element_background,
+                border_color: cx.theme().colors().border,
+                label_color: Color::Default.color(cx),
+                icon_color: Color::Default.color(cx),
+            },
             ButtonStyle::Transparent => ButtonLikeStyles {
                 background: transparent_black(),
                 border_color: transparent_black(),
@@ -209,6 +234,12 @@ impl ButtonStyle {
                 label_color: Color::Default.color(cx),
                 icon_color: Color::Default.color(cx),
             },
+            ButtonStyle::Outline => ButtonLikeStyles {
+                background: cx.theme().colors().ghost_element_hover,
+                border_color: cx.theme().colors().border,
+                label_color: Color::Default.color(cx),
+                icon_color: Color::Default.color(cx),
+            },
             ButtonStyle::Transparent => ButtonLikeStyles {
                 background: transparent_black(),
                 border_color: transparent_black(),
@@ -235,6 +266,12 @@ impl ButtonStyle {
                 label_color: Color::Default.color(cx),
                 icon_color: Color::Default.color(cx),
             },
+            ButtonStyle::Outline => ButtonLikeStyles {
+                background: cx.theme().colors().ghost_element_active,
+                border_color: cx.theme().colors().border,
+                label_color: Color::Default.color(cx),
+                icon_color: Color::Default.color(cx),
+            },
             ButtonStyle::Transparent => ButtonLikeStyles {
                 background: transparent_black(),
                 border_color: transparent_black(),
@@ -256,7 +293,7 @@ impl ButtonStyle {
                 icon_color: Color::Default.color(cx),
             },
             ButtonStyle::Tinted(tint) => tint.button_like_style(cx),
-            ButtonStyle::Subtle => ButtonLikeStyles {
+            ButtonStyle::Subtle | ButtonStyle::Outline => ButtonLikeStyles {
                 background: cx.theme().colors().ghost_element_background,
                 border_color: cx.theme().colors().border_focused,
                 label_color: Color::Default.color(cx),
@@ -285,7 +322,7 @@ impl ButtonStyle {
                 icon_color: Color::Disabled.color(cx),
             },
             ButtonStyle::Tinted(tint) => tint.button_like_style(cx),
-            ButtonStyle::Subtle => ButtonLikeStyles {
+            ButtonStyle::Subtle | ButtonStyle::Outline => ButtonLikeStyles {
                 background: cx.theme().colors().ghost_element_disabled,
                 border_color: cx.theme().colors().border_disabled,
                 label_color: Color::Disabled.color(cx),
@@ -301,6 +338,27 @@ impl ButtonStyle {
     }
 }
 
+impl ButtonStyle {
+    /// Returns the backgrounds of the style in each [`InteractiveState`].
+    pub(crate) fn interactive_colors(
+        self,
+        elevation: Option<ElevationIndex>,
+        cx: &mut WindowContext,
+    ) -> InteractiveColors {
+        let background = self.enabled(elevation, cx).background;
+        InteractiveColors {
+            background,
+            hover: self.hovered(elevation, cx).background,
+            active: self.active(cx).background,
+            // Selected buttons are drawn with their `selected_style` instead.
+            selected: background,
+            // Disabled buttons keep their background and only dim their labels.
+            disabled: background,
+            focus_ring: cx.theme().colors().border_focused,
+        }
+    }
+}
+
 /// The height of a button.
 ///
 /// Can also be used to size non-button elements to align with [`Button`]s.
@@ -322,6 +380,11 @@ impl ButtonSize {
             ButtonSize::None => rems_from_px(16.),
         }
     }
+
+    /// Returns the height of the button in the current [`UiDensity`](theme::UiDensity).
+    pub fn height(self, cx: &WindowContext) -> Rems {
+        rems(self.rems().0 * ThemeSettings::get_global(cx).ui_density.size_ratio())
+    }
 }
 
 /// A button-like element that can be used to create a custom button when
@@ -335,6 +398,7 @@ pub struct ButtonLike {
     id: ElementId,
     pub(super) style: ButtonStyle,
     pub(super) disabled: bool,
+    pub(super) loading: bool,
     pub(super) selected: bool,
     pub(super) selected_style: Option<ButtonStyle>,
     pub(super) width: Option<DefiniteLength>,
@@ -342,12 +406,27 @@ pub struct ButtonLike {
     pub(super) layer: Option<ElevationIndex>,
     size: ButtonSize,
     rounding: Option<ButtonLikeRounding>,
-    tooltip: Option<Box<dyn Fn(&mut WindowContext) -> AnyView>>,
+    pub(super) tooltip: Option<Box<dyn Fn(&mut WindowContext) -> AnyView>>,
+    pub(super) accessibility: AccessibilityProperties,
     cursor_style: CursorStyle,
-    on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    focus_handle: Option<FocusHandle>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    gestures: GestureHandlers,
+    style_overrides: StyleOverrides,
     children: SmallVec<[AnyElement; 2]>,
 }
 
+impl ButtonLike {
+    /// Returns the background that icons within the button are rendered on, when
+    /// it is colored enough to affect their legibility.
+    pub(super) fn icon_background(&self, cx: &mut WindowContext) -> Option<Hsla> {
+        match self.style {
+            ButtonStyle::Tinted(_) => Some(self.style.enabled(self.layer, cx).background),
+            _ => None,
+        }
+    }
+}
+
 impl ButtonLike {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
@@ -355,6 +434,7 @@ impl ButtonLike {
             id: id.into(),
             style: ButtonStyle::default(),
             disabled: false,
+            loading: false,
             selected: false,
             selected_style: None,
             width: None,
@@ -362,10 +442,14 @@ impl ButtonLike {
             size: ButtonSize::Default,
             rounding: Some(ButtonLikeRounding::All),
             tooltip: None,
+            accessibility: AccessibilityProperties::new(AccessibilityRole::Button),
             children: SmallVec::new(),
             cursor_style: CursorStyle::PointingHand,
+            focus_handle: None,
             on_click: None,
+            gestures: GestureHandlers::default(),
             layer: None,
+            style_overrides: StyleOverrides::default(),
         }
     }
 
@@ -377,6 +461,37 @@ impl ButtonLike {
         Self::new(id).rounding(ButtonLikeRounding::Right)
     }
 
+    /// Sets the name screen readers announce for the button, for buttons without a
+    /// text label.
+    pub fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessibility.name = Some(name.into());
+        self
+    }
+
+    /// Attaches a stable identifier for tests to locate the button by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.base = self.base.test_id(id);
+        self
+    }
+
+    /// Adjusts how the button is described to screen readers, for components built
+    /// on buttons with a more specific role, like disclosures.
+    pub(crate) fn map_accessibility(
+        mut self,
+        f: impl FnOnce(AccessibilityProperties) -> AccessibilityProperties,
+    ) -> Self {
+        self.accessibility = f(self.accessibility);
+        self
+    }
+
+    /// Makes the button focusable with the given handle. While focused, it's drawn
+    /// with a focus ring and Enter or Space clicks it.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub(crate) fn height(mut self, height: DefiniteLength) -> Self {
         self.height = Some(height);
         self
@@ -411,7 +526,7 @@ impl SelectableButton for ButtonLike {
 
 impl Clickable for ButtonLike {
     fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
         self
     }
 
@@ -421,6 +536,12 @@ impl Clickable for ButtonLike {
     }
 }
 
+impl Gestures for ButtonLike {
+    fn gesture_handlers(&mut self) -> &mut GestureHandlers {
+        &mut self.gestures
+    }
+}
+
 impl FixedWidth for ButtonLike {
     fn width(mut self, width: DefiniteLength) -> Self {
         self.width = Some(width);
@@ -459,6 +580,12 @@ impl ButtonCommon for ButtonLike {
     }
 }
 
+impl StyleOverride for ButtonLike {
+    fn style_overrides(&mut self) -> &mut StyleOverrides {
+        &mut self.style_overrides
+    }
+}
+
 impl VisibleOnHover for ButtonLike {
     fn visible_on_hover(mut self, group_name: impl Into<SharedString>) -> Self {
         self.base = self.base.visible_on_hover(group_name);
@@ -473,18 +600,44 @@ impl ParentElement for ButtonLike {
 }
 
 impl RenderOnce for ButtonLike {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
         let style = self
             .selected_style
             .filter(|_| self.selected)
             .unwrap_or(self.style);
 
-        self.base
+        // A loading button keeps its look, but can't be clicked until it's done.
+        let is_interactive = !self.disabled && !self.loading;
+        let accessibility = self
+            .accessibility
+            .disabled(!is_interactive)
+            .selected(self.selected);
+        let focused = self
+            .focus_handle
+            .as_ref()
+            .map_or(false, |focus_handle| focus_handle.is_focused(cx));
+        let on_click = self.on_click.filter(|_| is_interactive);
+        let gestures = if is_interactive {
+            std::mem::take(&mut self.gestures)
+        } else {
+            GestureHandlers::default()
+        };
+        let colors = style.interactive_colors(self.layer, cx);
+        let state = InteractiveState {
+            disabled: self.disabled,
+            selected: self.selected,
+            focused,
+        };
+
+        let button = self
+            .base
             .h_flex()
             .id(self.id.clone())
             .group("")
+            .accessibility(accessibility)
+            .when(self.loading, |this| this.relative())
             .flex_none()
-            .h(self.height.unwrap_or(self.size.rems().into()))
+            .h(self.height.unwrap_or(self.size.height(cx).into()))
             .when_some(self.width, |this, width| this.w(width).justify_center())
             .when_some(self.rounding, |this, rounding| match rounding {
                 ButtonLikeRounding::All => this.rounded_md(),
@@ -497,26 +650,48 @@ impl RenderOnce for ButtonLike {
                 ButtonSize::Default | ButtonSize::Compact => this.px(Spacing::Small.rems(cx)),
                 ButtonSize::None => this,
             })
-            .bg(style.enabled(self.layer, cx).background)
+            .bg(state.background(&colors, InteractiveStates::SELECTED))
+            .when(style == ButtonStyle::Outline, |this| {
+                this.border_1()
+                    .border_color(style.enabled(self.layer, cx).border_color)
+            })
+            .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                // Reserve the ring's border so that focusing doesn't move the content.
+                this.track_focus(focus_handle)
+                    .when(style != ButtonStyle::Outline, |this| {
+                        this.border_1().border_color(transparent_black())
+                    })
+                    .when(focused, |this| this.border_color(colors.focus_ring))
+            })
             .when(self.disabled, |this| this.cursor_not_allowed())
-            .when(!self.disabled, |this| {
+            .when(is_interactive, |this| {
                 this.cursor_pointer()
-                    .hover(|hover| hover.bg(style.hovered(self.layer, cx).background))
-                    .active(|active| active.bg(style.active(cx).background))
+                    .hover(|hover| self.style_overrides.refine_hover(hover.bg(colors.hover)))
+                    .active(|active| self.style_overrides.refine_active(active.bg(colors.active)))
             })
-            .when_some(
-                self.on_click.filter(|_| !self.disabled),
-                |this, on_click| {
-                    this.on_mouse_down(MouseButton::Left, |_, cx| cx.prevent_default())
-                        .on_click(move |event, cx| {
-                            cx.stop_propagation();
-                            (on_click)(event, cx)
+            .when_some(on_click, |this, on_click| {
+                let on_key_down = on_click.clone();
+                this.on_mouse_down(MouseButton::Left, |_, cx| cx.prevent_default())
+                    .on_click(move |event, cx| {
+                        cx.stop_propagation();
+                        (on_click)(event, cx)
+                    })
+                    .when(self.focus_handle.is_some(), |this| {
+                        this.on_key_down(move |event: &KeyDownEvent, cx| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space")
+                                && !event.keystroke.modifiers.modified()
+                            {
+                                cx.stop_propagation();
+                                (on_key_down)(&ClickEvent::default(), cx)
+                            }
                         })
-                },
-            )
+                    })
+            })
             .when_some(self.tooltip, |this, tooltip| {
                 this.tooltip(move |cx| tooltip(cx))
             })
-            .children(self.children)
+            .map(|this| self.style_overrides.refine(this))
+            .children(self.children);
+        gestures.wrap(self.id, button)
     }
 }
@@ -0,0 +1,186 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use gpui::ClickEvent;
+
+use crate::{prelude::*, TintColor};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone)]
+struct PendingConfirmation {
+    id: ElementId,
+    started_at: Instant,
+}
+
+/// Which [`DangerButton`] is awaiting confirmation, if any.
+///
+/// Keep this on the view that renders the buttons. Sharing one between the rows of a
+/// list ensures that at most one row asks for confirmation at a time.
+#[derive(Debug, Clone, Default)]
+pub struct DangerConfirmation(Rc<RefCell<Option<PendingConfirmation>>>);
+
+impl DangerConfirmation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the button with the given id is asking for confirmation.
+    pub fn is_confirming(&self, id: &ElementId) -> bool {
+        self.0
+            .borrow()
+            .as_ref()
+            .map_or(false, |pending| &pending.id == id)
+    }
+
+    /// Reverts the button awaiting confirmation to its initial state.
+    pub fn cancel(&self) {
+        self.0.borrow_mut().take();
+    }
+
+    fn start(&self, id: ElementId, timeout: Duration, cx: &mut WindowContext) {
+        let started_at = Instant::now();
+        *self.0.borrow_mut() = Some(PendingConfirmation { id, started_at });
+
+        let this = self.clone();
+        cx.spawn(|mut cx| async move {
+            cx.background_executor().timer(timeout).await;
+            let expired = this
+                .0
+                .borrow()
+                .as_ref()
+                .map_or(false, |pending| pending.started_at == started_at);
+            if expired {
+                this.cancel();
+                cx.update(|cx| cx.refresh()).ok();
+            }
+        })
+        .detach();
+    }
+}
+
+/// A button for quick destructive actions, e.g. deleting a row of a list, that asks
+/// for confirmation in place instead of opening a modal.
+///
+/// The first click switches the button into a "Confirm?" state with danger styling.
+/// Clicking it again within the timeout performs the action; otherwise the button
+/// reverts on its own.
+#[derive(IntoElement)]
+pub struct DangerButton {
+    id: ElementId,
+    label: SharedString,
+    confirm_label: SharedString,
+    icon: Option<IconName>,
+    icon_only: bool,
+    size: ButtonSize,
+    disabled: bool,
+    timeout: Duration,
+    confirmation: DangerConfirmation,
+    on_confirm: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+}
+
+impl DangerButton {
+    pub fn new(
+        id: impl Into<ElementId>,
+        label: impl Into<SharedString>,
+        confirmation: &DangerConfirmation,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            confirm_label: "Confirm?".into(),
+            icon: None,
+            icon_only: false,
+            size: ButtonSize::Default,
+            disabled: false,
+            timeout: DEFAULT_TIMEOUT,
+            confirmation: confirmation.clone(),
+            on_confirm: None,
+        }
+    }
+
+    /// Sets the label shown while awaiting confirmation, e.g. "Confirm delete?".
+    pub fn confirm_label(mut self, confirm_label: impl Into<SharedString>) -> Self {
+        self.confirm_label = confirm_label.into();
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<Option<IconName>>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Shows only the icon until the button is clicked. The confirmation label is
+    /// always shown, so that it is clear what the second click does.
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets how long the button waits for confirmation before reverting. Defaults to 3s.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn on_confirm(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_confirm = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for DangerButton {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl RenderOnce for DangerButton {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let id = self.id;
+        let is_confirming = !self.disabled && self.confirmation.is_confirming(&id);
+        let confirmation = self.confirmation;
+        let on_confirm = self.on_confirm;
+        let timeout = self.timeout;
+
+        let button = if is_confirming {
+            Button::new(id.clone(), self.confirm_label)
+                .style(ButtonStyle::Tinted(TintColor::Negative))
+                .color(Color::Error)
+                .icon(IconName::Warning)
+                .icon_color(Color::Error)
+        } else {
+            Button::new(id.clone(), self.label)
+                .icon(self.icon)
+                .icon_only(self.icon_only && self.icon.is_some())
+        };
+
+        button
+            .icon_size(IconSize::Small)
+            .icon_position(IconPosition::Start)
+            .size(self.size)
+            .disabled(self.disabled)
+            .on_click(move |event, cx| {
+                if confirmation.is_confirming(&id) {
+                    confirmation.cancel();
+                    if let Some(on_confirm) = on_confirm.as_ref() {
+                        on_confirm(event, cx);
+                    }
+                } else {
+                    confirmation.start(id.clone(), timeout, cx);
+                }
+                cx.refresh();
+            })
+    }
+}
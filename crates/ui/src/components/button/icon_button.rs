@@ -1,8 +1,8 @@
 #![allow(missing_docs)]
-use gpui::{AnyView, DefiniteLength};
+use gpui::{AccessibilityProperties, AnyView, DefiniteLength};
 
 use super::button_like::{ButtonCommon, ButtonLike, ButtonSize, ButtonStyle};
-use crate::{prelude::*, ElevationIndex, SelectableButton};
+use crate::{prelude::*, utils::GestureHandlers, ElevationIndex, SelectableButton};
 use crate::{IconName, IconSize};
 
 use super::button_icon::ButtonIcon;
@@ -53,6 +53,28 @@ impl IconButton {
         self
     }
 
+    /// Sets the name screen readers announce for the button, like the text of its
+    /// tooltip.
+    pub fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.base = self.base.accessible_name(name);
+        self
+    }
+
+    /// Attaches a stable identifier for tests to locate the button by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.base = self.base.test_id(id);
+        self
+    }
+
+    pub(crate) fn map_accessibility(
+        mut self,
+        f: impl FnOnce(AccessibilityProperties) -> AccessibilityProperties,
+    ) -> Self {
+        self.base = self.base.map_accessibility(f);
+        self
+    }
+
     pub fn selected_icon(mut self, icon: impl Into<Option<IconName>>) -> Self {
         self.selected_icon = icon.into();
         self
@@ -80,6 +102,12 @@ impl SelectableButton for IconButton {
     }
 }
 
+impl Gestures for IconButton {
+    fn gesture_handlers(&mut self) -> &mut GestureHandlers {
+        self.base.gesture_handlers()
+    }
+}
+
 impl Clickable for IconButton {
     fn on_click(
         mut self,
@@ -140,11 +168,18 @@ impl VisibleOnHover for IconButton {
     }
 }
 
+impl StyleOverride for IconButton {
+    fn style_overrides(&mut self) -> &mut StyleOverrides {
+        self.base.style_overrides()
+    }
+}
+
 impl RenderOnce for IconButton {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let is_disabled = self.base.disabled;
         let is_selected = self.base.selected;
         let selected_style = self.base.selected_style;
+        let icon_background = self.base.icon_background(cx);
 
         self.base
             .map(|this| match self.shape {
@@ -161,7 +196,8 @@ impl RenderOnce for IconButton {
                     .selected_icon(self.selected_icon)
                     .when_some(selected_style, |this, style| this.selected_style(style))
                     .size(self.icon_size)
-                    .color(self.icon_color),
+                    .color(self.icon_color)
+                    .parent_background(icon_background),
             )
     }
 }
@@ -116,6 +116,12 @@ impl ButtonCommon for ToggleButton {
     }
 }
 
+impl StyleOverride for ToggleButton {
+    fn style_overrides(&mut self) -> &mut StyleOverrides {
+        self.base.style_overrides()
+    }
+}
+
 impl RenderOnce for ToggleButton {
     fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
         let is_disabled = self.base.disabled;
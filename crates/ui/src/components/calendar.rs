@@ -0,0 +1,463 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
+use gpui::{FocusHandle, KeyDownEvent, MouseButton};
+
+use crate::prelude::*;
+
+/// The number of weeks shown by a [`Calendar`], so that it doesn't change height
+/// from one month to the next.
+const WEEKS_SHOWN: usize = 6;
+
+/// Returns the first day of the month of a date.
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Moves a date by a number of months, keeping its day where the month has it
+/// and using the last day of the month otherwise.
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let moved = if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    moved.unwrap_or(date)
+}
+
+/// Returns the days shown for a month, starting with the week of its first day.
+fn month_grid(month: NaiveDate, first_weekday: Weekday) -> Vec<NaiveDate> {
+    let first = first_of_month(month);
+    let leading_days =
+        (first.weekday().num_days_from_monday() + 7 - first_weekday.num_days_from_monday()) % 7;
+    let start = first - Duration::days(leading_days as i64);
+    start.iter_days().take(WEEKS_SHOWN * 7).collect()
+}
+
+/// Returns the week days in the order they're shown, starting with `first_weekday`.
+fn weekdays(first_weekday: Weekday) -> impl Iterator<Item = Weekday> {
+    std::iter::successors(Some(first_weekday), |day| Some(day.succ())).take(7)
+}
+
+/// Returns the range from one date to another, in either order.
+fn ordered_range(a: NaiveDate, b: NaiveDate) -> RangeInclusive<NaiveDate> {
+    if a <= b {
+        a..=b
+    } else {
+        b..=a
+    }
+}
+
+/// How dates are picked in a [`Calendar`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarSelectionMode {
+    /// Picking a date selects it.
+    #[default]
+    Single,
+    /// The first date picked starts a range, and the second one ends it.
+    Range,
+}
+
+struct CalendarModel {
+    /// The first day of the month shown.
+    month: NaiveDate,
+    /// The date moved with the keyboard, and picked with Enter.
+    cursor: NaiveDate,
+    selection: Option<RangeInclusive<NaiveDate>>,
+    /// The start of a range being picked, waiting for its end.
+    range_start: Option<NaiveDate>,
+}
+
+/// The shown month and selection of a [`Calendar`], held by the view rendering it
+/// so they're kept across frames.
+#[derive(Clone)]
+pub struct CalendarState {
+    focus_handle: FocusHandle,
+    model: Rc<RefCell<CalendarModel>>,
+}
+
+impl CalendarState {
+    /// Creates a state showing the current month, with nothing selected.
+    pub fn new(cx: &mut WindowContext) -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            focus_handle: cx.focus_handle(),
+            model: Rc::new(RefCell::new(CalendarModel {
+                month: first_of_month(today),
+                cursor: today,
+                selection: None,
+                range_start: None,
+            })),
+        }
+    }
+
+    /// The focus handle of the calendar, which has to be focused for it to be
+    /// navigated with the keyboard.
+    pub fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    /// The selected date, or the start of the selected range.
+    pub fn selected_date(&self) -> Option<NaiveDate> {
+        self.model
+            .borrow()
+            .selection
+            .as_ref()
+            .map(|range| *range.start())
+    }
+
+    /// The selected dates. A single selected date is a range ending on the same day.
+    pub fn selection(&self) -> Option<RangeInclusive<NaiveDate>> {
+        self.model.borrow().selection.clone()
+    }
+
+    /// Selects a date, showing its month.
+    pub fn select_date(&self, date: NaiveDate) {
+        self.select_range(date..=date);
+    }
+
+    /// Selects a range of dates, showing the month of its start.
+    pub fn select_range(&self, range: RangeInclusive<NaiveDate>) {
+        let mut model = self.model.borrow_mut();
+        model.month = first_of_month(*range.start());
+        model.cursor = *range.start();
+        model.selection = Some(range);
+        model.range_start = None;
+    }
+
+    pub fn clear_selection(&self) {
+        let mut model = self.model.borrow_mut();
+        model.selection = None;
+        model.range_start = None;
+    }
+
+    /// The first day of the month shown.
+    pub fn visible_month(&self) -> NaiveDate {
+        self.model.borrow().month
+    }
+
+    /// Shows the month of the given date.
+    pub fn show_month(&self, date: NaiveDate) {
+        let mut model = self.model.borrow_mut();
+        model.month = first_of_month(date);
+        model.cursor = model.month;
+    }
+
+    fn show_adjacent_month(&self, months: i32) {
+        let mut model = self.model.borrow_mut();
+        model.month = add_months(model.month, months);
+        model.cursor = add_months(model.cursor, months);
+    }
+
+    fn move_cursor(&self, cursor: NaiveDate) {
+        let mut model = self.model.borrow_mut();
+        model.cursor = cursor;
+        model.month = first_of_month(cursor);
+    }
+
+    /// Picks a date as the cursor does, returning the selection once it's complete.
+    fn pick(
+        &self,
+        date: NaiveDate,
+        mode: CalendarSelectionMode,
+    ) -> Option<RangeInclusive<NaiveDate>> {
+        let mut model = self.model.borrow_mut();
+        model.cursor = date;
+        match (mode, model.range_start.take()) {
+            (CalendarSelectionMode::Range, None) => {
+                model.range_start = Some(date);
+                model.selection = Some(date..=date);
+                None
+            }
+            (CalendarSelectionMode::Range, Some(start)) => {
+                let range = ordered_range(start, date);
+                model.selection = Some(range.clone());
+                Some(range)
+            }
+            (CalendarSelectionMode::Single, _) => {
+                model.selection = Some(date..=date);
+                Some(date..=date)
+            }
+        }
+    }
+}
+
+type OnSelect = Rc<dyn Fn(&RangeInclusive<NaiveDate>, &mut WindowContext)>;
+
+/// A month of days to pick a date or a range of dates from, e.g. to schedule
+/// something or to filter a list by date.
+///
+/// When focused, the arrow keys move between days and weeks, Page Up and Page Down
+/// between months, and Enter or Space picks the day.
+#[derive(IntoElement)]
+pub struct Calendar {
+    id: ElementId,
+    state: CalendarState,
+    mode: CalendarSelectionMode,
+    first_weekday: Weekday,
+    is_disabled: Option<Rc<dyn Fn(NaiveDate) -> bool>>,
+    on_select: Option<OnSelect>,
+}
+
+impl Calendar {
+    pub fn new(id: impl Into<ElementId>, state: &CalendarState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            mode: CalendarSelectionMode::default(),
+            first_weekday: Weekday::Mon,
+            is_disabled: None,
+            on_select: None,
+        }
+    }
+
+    pub fn selection_mode(mut self, mode: CalendarSelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The day weeks start with. Defaults to Monday.
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Disables the dates for which the given function returns `true`, so they
+    /// can't be picked.
+    pub fn disabled_dates(mut self, is_disabled: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.is_disabled = Some(Rc::new(is_disabled));
+        self
+    }
+
+    /// Called when a date is picked, or when the end of a range is picked in
+    /// [`CalendarSelectionMode::Range`]. A single date is a range ending on the
+    /// same day.
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&RangeInclusive<NaiveDate>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    fn render_header(&self, month: NaiveDate) -> impl IntoElement {
+        let previous_state = self.state.clone();
+        let next_state = self.state.clone();
+        h_flex()
+            .justify_between()
+            .child(
+                IconButton::new("calendar-previous-month", IconName::ChevronLeft)
+                    .icon_size(IconSize::Small)
+                    .on_click(move |_, cx| {
+                        previous_state.show_adjacent_month(-1);
+                        cx.refresh();
+                    }),
+            )
+            .child(Label::new(month.format("%B %Y").to_string()).size(LabelSize::Small))
+            .child(
+                IconButton::new("calendar-next-month", IconName::ChevronRight)
+                    .icon_size(IconSize::Small)
+                    .on_click(move |_, cx| {
+                        next_state.show_adjacent_month(1);
+                        cx.refresh();
+                    }),
+            )
+    }
+}
+
+/// Handles the key presses navigating a focused calendar.
+fn handle_key_down(
+    state: &CalendarState,
+    mode: CalendarSelectionMode,
+    is_disabled: &Option<Rc<dyn Fn(NaiveDate) -> bool>>,
+    on_select: &Option<OnSelect>,
+    event: &KeyDownEvent,
+    cx: &mut WindowContext,
+) {
+    let cursor = state.model.borrow().cursor;
+    let moved = match event.keystroke.key.as_str() {
+        "left" => Some(cursor - Duration::days(1)),
+        "right" => Some(cursor + Duration::days(1)),
+        "up" => Some(cursor - Duration::weeks(1)),
+        "down" => Some(cursor + Duration::weeks(1)),
+        "pageup" => Some(add_months(cursor, -1)),
+        "pagedown" => Some(add_months(cursor, 1)),
+        "enter" | "space" => {
+            if !is_disabled
+                .as_ref()
+                .map_or(false, |is_disabled| is_disabled(cursor))
+            {
+                if let Some((range, on_select)) = state.pick(cursor, mode).zip(on_select.as_ref()) {
+                    on_select(&range, cx);
+                }
+            }
+            None
+        }
+        _ => return,
+    };
+    if let Some(moved) = moved {
+        state.move_cursor(moved);
+    }
+    cx.stop_propagation();
+    cx.refresh();
+}
+
+impl RenderOnce for Calendar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (month, cursor, selection, range_start) = {
+            let model = self.state.model.borrow();
+            (
+                model.month,
+                model.cursor,
+                model.selection.clone(),
+                model.range_start,
+            )
+        };
+        let focused = self.state.focus_handle.is_focused(cx);
+        let today = Local::now().date_naive();
+        let colors = cx.theme().colors();
+        let (selected_background, in_range_background) =
+            (colors.element_selected, colors.ghost_element_selected);
+        let hover_background = colors.ghost_element_hover;
+        let (accent_border, focused_border) = (colors.text_accent, colors.border_focused);
+
+        let header = self.render_header(month);
+        let weekday_labels = h_flex().children(weekdays(self.first_weekday).map(|weekday| {
+            div().w(rems(1.75)).flex().justify_center().child(
+                Label::new(weekday.to_string()[..2].to_string())
+                    .size(LabelSize::XSmall)
+                    .color(Color::Muted),
+            )
+        }));
+
+        let days = month_grid(month, self.first_weekday);
+        let weeks = days.chunks(7).map(|week| {
+            h_flex().children(week.iter().map(|&date| {
+                let disabled = self
+                    .is_disabled
+                    .as_ref()
+                    .map_or(false, |is_disabled| is_disabled(date));
+                let in_month = date.month() == month.month();
+                let selection = selection.as_ref();
+                let is_endpoint = selection.map_or(false, |range| {
+                    *range.start() == date || *range.end() == date
+                });
+                let in_range = selection.map_or(false, |range| range.contains(&date));
+                let color = if disabled {
+                    Color::Disabled
+                } else if in_month || in_range {
+                    Color::Default
+                } else {
+                    Color::Muted
+                };
+
+                let state = self.state.clone();
+                let on_select = self.on_select.clone();
+                let mode = self.mode;
+                div()
+                    .id(("calendar-day", date.num_days_from_ce() as usize))
+                    .size(rems(1.75))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(gpui::transparent_black())
+                    .when(in_range && !is_endpoint, |this| {
+                        this.bg(in_range_background)
+                    })
+                    .when(is_endpoint, |this| this.bg(selected_background))
+                    .when(date == today, |this| this.border_color(accent_border))
+                    .when(focused && date == cursor, |this| {
+                        this.border_color(focused_border)
+                    })
+                    .when(range_start == Some(date), |this| {
+                        this.border_color(accent_border)
+                    })
+                    .when(!disabled, |this| {
+                        this.cursor_pointer()
+                            .hover(|style| style.bg(hover_background))
+                            .on_click(move |_, cx| {
+                                if let Some((range, on_select)) =
+                                    state.pick(date, mode).zip(on_select.as_ref())
+                                {
+                                    on_select(&range, cx);
+                                }
+                                cx.refresh();
+                            })
+                    })
+                    .child(
+                        Label::new(date.day().to_string())
+                            .size(LabelSize::Small)
+                            .color(color),
+                    )
+            }))
+        });
+
+        let state = self.state.clone();
+        let focus_handle = self.state.focus_handle.clone();
+        let mode = self.mode;
+        let is_disabled = self.is_disabled.clone();
+        let on_select = self.on_select.clone();
+        v_flex()
+            .id(self.id)
+            .track_focus(&self.state.focus_handle)
+            .on_mouse_down(MouseButton::Left, move |_, cx| cx.focus(&focus_handle))
+            .on_key_down(move |event, cx| {
+                handle_key_down(&state, mode, &is_disabled, &on_select, event, cx)
+            })
+            .p_1()
+            .gap_1()
+            .child(header)
+            .child(weekday_labels)
+            .children(weeks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(add_months(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(add_months(date(2024, 3, 15), -3), date(2023, 12, 15));
+        assert_eq!(add_months(date(2024, 12, 1), 1), date(2025, 1, 1));
+    }
+
+    #[test]
+    fn test_month_grid() {
+        // September 2024 starts on a Sunday.
+        let grid = month_grid(date(2024, 9, 17), Weekday::Mon);
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[0], date(2024, 8, 26));
+        assert_eq!(grid[6], date(2024, 9, 1));
+
+        let grid = month_grid(date(2024, 9, 17), Weekday::Sun);
+        assert_eq!(grid[0], date(2024, 9, 1));
+        assert_eq!(grid[41], date(2024, 10, 12));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(
+            weekdays(Weekday::Sat).collect::<Vec<_>>(),
+            vec![
+                Weekday::Sat,
+                Weekday::Sun,
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri
+            ]
+        );
+    }
+}
@@ -0,0 +1,609 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+
+use gpui::{canvas, point, Bounds, Hsla, Path, Pixels, Point};
+
+use crate::{prelude::*, Tooltip};
+
+/// The most labels shown along the x axis of a chart, so that they don't overlap.
+const MAX_X_AXIS_LABELS: usize = 6;
+
+/// The range of values a chart spans, from the lowest to the highest value. Bar
+/// charts also include zero, so that bars grow from a baseline.
+fn value_range(values: impl IntoIterator<Item = f64>, include_zero: bool) -> (f64, f64) {
+    let (mut min, mut max) = values
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if min > max {
+        return (0., 1.);
+    }
+    if include_zero {
+        min = min.min(0.);
+        max = max.max(0.);
+    }
+    if min == max {
+        // A flat series is drawn across the middle of the chart.
+        (min - 1., max + 1.)
+    } else {
+        (min, max)
+    }
+}
+
+/// Returns how far up the chart a value is, from `0.` at the bottom to `1.` at the top.
+fn fraction(value: f64, (min, max): (f64, f64)) -> f32 {
+    if value.is_finite() {
+        ((value - min) / (max - min)).clamp(0., 1.) as f32
+    } else {
+        0.
+    }
+}
+
+/// Returns the indices of the points labeled on the x axis: at most `max_labels`,
+/// evenly spaced, always including the first and last points.
+fn axis_label_indices(count: usize, max_labels: usize) -> Vec<usize> {
+    if count <= max_labels {
+        return (0..count).collect();
+    }
+    let steps = max_labels.max(2) - 1;
+    (0..=steps).map(|step| step * (count - 1) / steps).collect()
+}
+
+/// Formats a value for axis labels and tooltips, without trailing zeros.
+fn format_value(value: f64) -> String {
+    let formatted = if value.abs() >= 100. || value.fract() == 0. {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    };
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Returns the position of each value of a series in the bounds of a chart, with
+/// the values in the middle of `count` equal columns. Series shorter than the
+/// others end early rather than stretching across the chart.
+fn series_points(
+    values: &[f64],
+    count: usize,
+    range: (f64, f64),
+    bounds: Bounds<Pixels>,
+) -> Vec<Point<Pixels>> {
+    let column_width = bounds.size.width / count.max(1) as f32;
+    values
+        .iter()
+        .enumerate()
+        .map(|(ix, value)| {
+            point(
+                bounds.left() + column_width * (ix as f32 + 0.5),
+                bounds.bottom() - bounds.size.height * fraction(*value, range),
+            )
+        })
+        .collect()
+}
+
+fn paint_line(points: &[Point<Pixels>], width: Pixels, color: Hsla, cx: &mut WindowContext) {
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let dx = (end.x - start.x).0;
+        let dy = (end.y - start.y).0;
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0. {
+            continue;
+        }
+        let normal = point(px(-dy / length), px(dx / length)) * (width.0 / 2.);
+
+        let mut path = Path::new(start + normal);
+        path.line_to(end + normal);
+        path.line_to(end - normal);
+        path.line_to(start - normal);
+        path.line_to(start + normal);
+        cx.paint_path(path, color);
+    }
+}
+
+fn paint_area(points: &[Point<Pixels>], baseline: Pixels, color: Hsla, cx: &mut WindowContext) {
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let mut path = Path::new(point(start.x, baseline));
+        path.line_to(start);
+        path.line_to(end);
+        path.line_to(point(end.x, baseline));
+        path.line_to(point(start.x, baseline));
+        cx.paint_path(path, color);
+    }
+}
+
+/// A column over each point of a chart, showing a tooltip for the point on hover.
+fn render_hover_columns(
+    id: &ElementId,
+    count: usize,
+    tooltip: Rc<dyn Fn(usize, &mut WindowContext) -> AnyView>,
+    cx: &WindowContext,
+) -> impl IntoElement {
+    let hover_color = cx.theme().colors().ghost_element_hover.opacity(0.5);
+    h_flex()
+        .id(id.clone())
+        .absolute()
+        .inset_0()
+        .children((0..count).map(move |ix| {
+            let tooltip = tooltip.clone();
+            div()
+                .id(ix)
+                .flex_1()
+                .h_full()
+                .hover(move |style| style.bg(hover_color))
+                .tooltip(move |cx| tooltip(ix, cx))
+        }))
+}
+
+fn render_y_axis(range: (f64, f64)) -> impl IntoElement {
+    let (min, max) = range;
+    v_flex()
+        .flex_none()
+        .h_full()
+        .justify_between()
+        .items_end()
+        .pr_1()
+        .children([max, (min + max) / 2., min].map(|value| {
+            Label::new(format_value(value))
+                .size(LabelSize::XSmall)
+                .color(Color::Muted)
+        }))
+}
+
+fn render_x_axis(labels: &[SharedString]) -> impl IntoElement {
+    let count = labels.len();
+    let shown = axis_label_indices(count, MAX_X_AXIS_LABELS);
+    h_flex()
+        .w_full()
+        .children(labels.iter().enumerate().map(|(ix, label)| {
+            div()
+                .flex_1()
+                .flex()
+                .justify_center()
+                .overflow_hidden()
+                .when(shown.contains(&ix), |this| {
+                    this.child(
+                        Label::new(label.clone())
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted)
+                            .single_line(),
+                    )
+                })
+        }))
+}
+
+/// A small line of a series of values with no axes, e.g. the recent frame times
+/// next to a label.
+#[derive(IntoElement)]
+pub struct Sparkline {
+    values: Vec<f64>,
+    color: Color,
+    fill: bool,
+    width: Rems,
+    height: Rems,
+}
+
+impl Sparkline {
+    pub fn new(values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            color: Color::Accent,
+            fill: false,
+            width: rems(4.),
+            height: rems(1.),
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Fills the area under the line with a faded color.
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn size(mut self, width: Rems, height: Rems) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+}
+
+impl RenderOnce for Sparkline {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.color.color(cx);
+        let range = value_range(self.values.iter().copied(), false);
+        let values = self.values;
+        let fill = self.fill;
+
+        canvas(
+            |_, _| {},
+            move |bounds, _, cx| {
+                let points = series_points(&values, values.len(), range, bounds);
+                if fill {
+                    paint_area(&points, bounds.bottom(), color.opacity(0.2), cx);
+                }
+                paint_line(&points, px(1.5), color, cx);
+            },
+        )
+        .flex_none()
+        .w(self.width)
+        .h(self.height)
+    }
+}
+
+/// A chart of bars, one per labeled value, growing up from zero, with the values
+/// on the y axis and the labels on the x axis.
+#[derive(IntoElement)]
+pub struct BarChart {
+    id: ElementId,
+    bars: Vec<(SharedString, f64)>,
+    color: Color,
+    height: Rems,
+    axis_labels: bool,
+    tooltips: bool,
+}
+
+impl BarChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            bars: Vec::new(),
+            color: Color::Accent,
+            height: rems(8.),
+            axis_labels: true,
+            tooltips: true,
+        }
+    }
+
+    pub fn bar(mut self, label: impl Into<SharedString>, value: f64) -> Self {
+        self.bars.push((label.into(), value));
+        self
+    }
+
+    pub fn bars<L: Into<SharedString>>(mut self, bars: impl IntoIterator<Item = (L, f64)>) -> Self {
+        self.bars
+            .extend(bars.into_iter().map(|(label, value)| (label.into(), value)));
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// The height of the bars. Defaults to 8rem.
+    pub fn height(mut self, height: Rems) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Whether to show the values and labels along the axes. Defaults to `true`.
+    pub fn axis_labels(mut self, axis_labels: bool) -> Self {
+        self.axis_labels = axis_labels;
+        self
+    }
+
+    /// Whether to show the label and value of a bar on hover. Defaults to `true`.
+    pub fn tooltips(mut self, tooltips: bool) -> Self {
+        self.tooltips = tooltips;
+        self
+    }
+}
+
+impl RenderOnce for BarChart {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.color.color(cx);
+        let range = value_range(self.bars.iter().map(|(_, value)| *value), true);
+        let zero = fraction(0., range);
+        let labels = self
+            .bars
+            .iter()
+            .map(|(label, _)| label.clone())
+            .collect::<Vec<_>>();
+        let values = self
+            .bars
+            .iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>();
+        let bars = Rc::new(self.bars);
+
+        let plot = div()
+            .relative()
+            .flex_1()
+            .h_full()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                canvas(
+                    |_, _| {},
+                    move |bounds, _, cx| {
+                        let column_width = bounds.size.width / values.len().max(1) as f32;
+                        let bar_width = column_width * 0.7;
+                        let baseline = bounds.bottom() - bounds.size.height * zero;
+                        for (ix, value) in values.iter().enumerate() {
+                            let top =
+                                bounds.bottom() - bounds.size.height * fraction(*value, range);
+                            let left = bounds.left()
+                                + column_width * ix as f32
+                                + (column_width - bar_width) / 2.;
+                            let bar = Bounds::from_corners(
+                                point(left, top.min(baseline)),
+                                point(left + bar_width, top.max(baseline)),
+                            );
+                            cx.paint_quad(gpui::fill(bar, color));
+                        }
+                    },
+                )
+                .size_full(),
+            )
+            .when(self.tooltips, |this| {
+                this.child(render_hover_columns(
+                    &self.id,
+                    bars.len(),
+                    Rc::new(move |ix: usize, cx: &mut WindowContext| {
+                        let (label, value) = &bars[ix];
+                        Tooltip::with_meta(label.clone(), None, format_value(*value), cx)
+                    }),
+                    cx,
+                ))
+            });
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(self.height)
+                    .when(self.axis_labels, |this| this.child(render_y_axis(range)))
+                    .child(plot),
+            )
+            .when(self.axis_labels, |this| {
+                this.child(
+                    h_flex()
+                        .w_full()
+                        // Line the labels up with the bars, past the y axis.
+                        .pl(rems(2.5))
+                        .child(render_x_axis(&labels)),
+                )
+            })
+    }
+}
+
+struct LineSeries {
+    name: SharedString,
+    values: Vec<f64>,
+    color: Option<Color>,
+}
+
+/// A chart of one or more series of values over the same points, with the values
+/// on the y axis and the labels of the points on the x axis.
+#[derive(IntoElement)]
+pub struct LineChart {
+    id: ElementId,
+    series: Vec<LineSeries>,
+    x_labels: Vec<SharedString>,
+    height: Rems,
+    axis_labels: bool,
+    tooltips: bool,
+    fill: bool,
+}
+
+impl LineChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            series: Vec::new(),
+            x_labels: Vec::new(),
+            height: rems(8.),
+            axis_labels: true,
+            tooltips: true,
+            fill: false,
+        }
+    }
+
+    /// Adds a series, drawn in the next player color of the theme.
+    pub fn series(
+        mut self,
+        name: impl Into<SharedString>,
+        values: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        self.series.push(LineSeries {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+        });
+        self
+    }
+
+    /// Adds a series drawn in the given color.
+    pub fn colored_series(
+        mut self,
+        name: impl Into<SharedString>,
+        values: impl IntoIterator<Item = f64>,
+        color: Color,
+    ) -> Self {
+        self.series.push(LineSeries {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: Some(color),
+        });
+        self
+    }
+
+    /// Labels the points along the x axis, e.g. with their times.
+    pub fn x_labels<L: Into<SharedString>>(mut self, labels: impl IntoIterator<Item = L>) -> Self {
+        self.x_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The height of the plot. Defaults to 8rem.
+    pub fn height(mut self, height: Rems) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Whether to show the values and labels along the axes. Defaults to `true`.
+    pub fn axis_labels(mut self, axis_labels: bool) -> Self {
+        self.axis_labels = axis_labels;
+        self
+    }
+
+    /// Whether to show the values of the series at a point on hover. Defaults to
+    /// `true`.
+    pub fn tooltips(mut self, tooltips: bool) -> Self {
+        self.tooltips = tooltips;
+        self
+    }
+
+    /// Fills the area under each line with a faded color.
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+}
+
+impl RenderOnce for LineChart {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let point_count = self
+            .series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0);
+        let range = value_range(
+            self.series
+                .iter()
+                .flat_map(|series| series.values.iter().copied()),
+            false,
+        );
+        let players = cx.theme().players().clone();
+        let lines = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(ix, series)| {
+                let color = match series.color {
+                    Some(color) => color.color(cx),
+                    None => players.color_for_participant(ix as u32).cursor,
+                };
+                (series.values.clone(), color)
+            })
+            .collect::<Vec<_>>();
+        let fill = self.fill;
+        let x_labels = (0..point_count)
+            .map(|ix| {
+                self.x_labels
+                    .get(ix)
+                    .cloned()
+                    .unwrap_or_else(|| SharedString::from((ix + 1).to_string()))
+            })
+            .collect::<Vec<_>>();
+
+        let series = Rc::new(self.series);
+        let tooltip_labels = Rc::new(x_labels.clone());
+        let plot = div()
+            .relative()
+            .flex_1()
+            .h_full()
+            .border_b_1()
+            .border_l_1()
+            .border_color(cx.theme().colors().border_variant)
+            .child(
+                canvas(
+                    |_, _| {},
+                    move |bounds, _, cx| {
+                        for (values, color) in &lines {
+                            let points = series_points(values, point_count, range, bounds);
+                            if fill {
+                                paint_area(&points, bounds.bottom(), color.opacity(0.15), cx);
+                            }
+                            paint_line(&points, px(1.5), *color, cx);
+                        }
+                    },
+                )
+                .size_full(),
+            )
+            .when(self.tooltips && point_count > 0, |this| {
+                this.child(render_hover_columns(
+                    &self.id,
+                    point_count,
+                    Rc::new(move |ix: usize, cx: &mut WindowContext| {
+                        let meta = series
+                            .iter()
+                            .filter_map(|series| {
+                                let value = series.values.get(ix)?;
+                                Some(format!("{}: {}", series.name, format_value(*value)))
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        Tooltip::with_meta(tooltip_labels[ix].clone(), None, meta, cx)
+                    }),
+                    cx,
+                ))
+            });
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .child(
+                h_flex()
+                    .w_full()
+                    .h(self.height)
+                    .when(self.axis_labels, |this| this.child(render_y_axis(range)))
+                    .child(plot),
+            )
+            .when(self.axis_labels, |this| {
+                this.child(
+                    h_flex()
+                        .w_full()
+                        .pl(rems(2.5))
+                        .child(render_x_axis(&x_labels)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_range() {
+        assert_eq!(value_range([3., 1., 2.], false), (1., 3.));
+        assert_eq!(value_range([3., 1., 2.], true), (0., 3.));
+        assert_eq!(value_range([-2., f64::NAN, 4.], true), (-2., 4.));
+        assert_eq!(value_range([5.], false), (4., 6.));
+        assert_eq!(value_range([], false), (0., 1.));
+    }
+
+    #[test]
+    fn test_axis_label_indices() {
+        assert_eq!(axis_label_indices(3, 6), vec![0, 1, 2]);
+        assert_eq!(axis_label_indices(11, 6), vec![0, 2, 4, 6, 8, 10]);
+        assert_eq!(axis_label_indices(100, 3), vec![0, 49, 99]);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(3.), "3");
+        assert_eq!(format_value(2.5), "2.5");
+        assert_eq!(format_value(0.125), "0.13");
+        assert_eq!(format_value(1234.5), "1234");
+        assert_eq!(format_value(-1.10), "-1.1");
+    }
+}
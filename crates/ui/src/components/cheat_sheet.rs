@@ -0,0 +1,189 @@
+#![allow(missing_docs)]
+use gpui::{Action, FocusHandle};
+
+use crate::{prelude::*, KeyBinding};
+
+/// An action listed in a [`CheatSheet`].
+pub struct CheatSheetEntry {
+    label: SharedString,
+    action: Box<dyn Action>,
+}
+
+impl CheatSheetEntry {
+    pub fn new(label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        Self {
+            label: label.into(),
+            action,
+        }
+    }
+}
+
+impl Clone for CheatSheetEntry {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            action: self.action.boxed_clone(),
+        }
+    }
+}
+
+/// A titled group of entries in a [`CheatSheet`].
+#[derive(Clone)]
+pub struct CheatSheetCategory {
+    name: SharedString,
+    entries: Vec<CheatSheetEntry>,
+}
+
+impl CheatSheetCategory {
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn entry(mut self, entry: CheatSheetEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    pub fn entries(mut self, entries: impl IntoIterator<Item = CheatSheetEntry>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+}
+
+/// Returns whether every word of `query` appears, case-insensitively, in either
+/// the category name or the entry label.
+pub fn cheat_sheet_entry_matches(category: &str, label: &str, query: &str) -> bool {
+    let haystack = format!("{category} {label}").to_lowercase();
+    query
+        .split_whitespace()
+        .all(|word| haystack.contains(&word.to_lowercase()))
+}
+
+/// A categorized grid of actions and their current key bindings.
+///
+/// Entries whose action has no key binding are left out, so that the sheet only
+/// lists shortcuts.
+#[derive(IntoElement)]
+pub struct CheatSheet {
+    categories: Vec<CheatSheetCategory>,
+    query: SharedString,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl CheatSheet {
+    pub fn new(categories: impl IntoIterator<Item = CheatSheetCategory>) -> Self {
+        Self {
+            categories: categories.into_iter().collect(),
+            query: SharedString::default(),
+            focus_handle: None,
+        }
+    }
+
+    /// Only shows the entries matching the given query.
+    pub fn query(mut self, query: impl Into<SharedString>) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Looks up key bindings from the given focus handle, rather than from the
+    /// currently focused element.
+    pub fn key_context(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+}
+
+impl RenderOnce for CheatSheet {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let query = self.query;
+        let focus_handle = self.focus_handle;
+
+        let categories = self
+            .categories
+            .into_iter()
+            .filter_map(|category| {
+                let rows = category
+                    .entries
+                    .into_iter()
+                    .filter(|entry| cheat_sheet_entry_matches(&category.name, &entry.label, &query))
+                    .filter_map(|entry| {
+                        let key_binding = match focus_handle.as_ref() {
+                            Some(focus_handle) => {
+                                KeyBinding::for_action_in(entry.action.as_ref(), focus_handle, cx)
+                            }
+                            None => KeyBinding::for_action(entry.action.as_ref(), cx),
+                        }?;
+                        Some(
+                            h_flex()
+                                .w_full()
+                                .gap_2()
+                                .justify_between()
+                                .child(Label::new(entry.label).size(LabelSize::Small))
+                                .child(key_binding),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if rows.is_empty() {
+                    return None;
+                }
+
+                Some(
+                    v_flex()
+                        .w(rems(18.))
+                        .gap_1()
+                        .child(
+                            Label::new(category.name)
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        )
+                        .children(rows),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if categories.is_empty() {
+            return h_flex()
+                .w_full()
+                .p_4()
+                .justify_center()
+                .child(Label::new(crate::t!(cx, "cheat_sheet.no_matches")).color(Color::Muted))
+                .into_any_element();
+        }
+
+        h_flex()
+            .w_full()
+            .flex_wrap()
+            .items_start()
+            .gap_6()
+            .children(categories)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheat_sheet_entry_matches() {
+        assert!(cheat_sheet_entry_matches("editor", "go to definition", ""));
+        assert!(cheat_sheet_entry_matches(
+            "editor",
+            "go to definition",
+            "Definition"
+        ));
+        assert!(cheat_sheet_entry_matches(
+            "editor",
+            "go to definition",
+            "edit def"
+        ));
+        assert!(!cheat_sheet_entry_matches(
+            "editor",
+            "go to definition",
+            "workspace"
+        ));
+    }
+}
@@ -3,7 +3,10 @@ mod checkbox_with_label;
 
 pub use checkbox_with_label::*;
 
-use gpui::{div, prelude::*, ElementId, IntoElement, Styled, WindowContext};
+use gpui::{
+    div, prelude::*, AccessibilityProperties, AccessibilityRole, ElementId, IntoElement, Styled,
+    WindowContext,
+};
 
 use crate::prelude::*;
 use crate::{Color, Icon, IconName, Selection};
@@ -19,6 +22,7 @@ pub struct Checkbox {
     checked: Selection,
     disabled: bool,
     on_click: Option<Box<dyn Fn(&Selection, &mut WindowContext) + 'static>>,
+    test_id: Option<SharedString>,
 }
 
 impl Checkbox {
@@ -28,6 +32,7 @@ impl Checkbox {
             checked,
             disabled: false,
             on_click: None,
+            test_id: None,
         }
     }
 
@@ -40,6 +45,13 @@ impl Checkbox {
         self.on_click = Some(Box::new(handler));
         self
     }
+
+    /// Attaches a stable identifier for tests to locate the checkbox by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.test_id = Some(id.into());
+        self
+    }
 }
 
 impl RenderOnce for Checkbox {
@@ -84,8 +96,20 @@ impl RenderOnce for Checkbox {
             ),
         };
 
+        let checked = match self.checked {
+            Selection::Selected => Some(true),
+            Selection::Unselected => Some(false),
+            Selection::Indeterminate => None,
+        };
+
         h_flex()
             .id(self.id)
+            .accessibility(
+                AccessibilityProperties::new(AccessibilityRole::CheckBox)
+                    .checked(checked)
+                    .disabled(self.disabled),
+            )
+            .when_some(self.test_id, |this, id| this.test_id(id))
             .justify_center()
             .items_center()
             .size(crate::styles::custom_spacing(cx, 20.))
@@ -0,0 +1,296 @@
+#![allow(missing_docs)]
+use std::ops::Range;
+use std::rc::Rc;
+
+use collections::HashSet;
+use gpui::{HighlightStyle, Hsla, StyledText, TextStyle};
+use settings::Settings;
+use theme::ThemeSettings;
+
+use crate::{prelude::*, utils::formatted_clipboard_item, Tooltip};
+
+/// Returns the byte ranges of the lines of a snippet, without their line endings.
+/// A line ending at the end of the snippet doesn't start another line.
+fn line_ranges(code: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (ix, _) in code.match_indices('\n') {
+        let end = if code[..ix].ends_with('\r') {
+            ix - 1
+        } else {
+            ix
+        };
+        ranges.push(start..end);
+        start = ix + 1;
+    }
+    if start < code.len() || ranges.is_empty() {
+        ranges.push(start..code.len());
+    }
+    ranges
+}
+
+/// Returns the highlights overlapping a line, relative to the start of the line.
+fn line_highlights(
+    highlights: &[(Range<usize>, HighlightStyle)],
+    line: &Range<usize>,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    highlights
+        .iter()
+        .filter(|(range, _)| range.start < line.end && range.end > line.start)
+        .map(|(range, style)| {
+            let start = range.start.max(line.start) - line.start;
+            let end = range.end.min(line.end) - line.start;
+            (start..end, *style)
+        })
+        .collect()
+}
+
+/// A read-only snippet of code in the buffer font, with optional line numbers and
+/// highlighted lines, and a button to copy it shown on hover. The code is copied
+/// along with a version formatted with its syntax colors, for pasting into
+/// applications that accept formatted text.
+///
+/// Syntax highlighting is given as styled ranges, with [`CodeBlock::syntax_highlights`]
+/// resolving capture names like `"keyword"` with the syntax theme.
+#[derive(IntoElement)]
+pub struct CodeBlock {
+    id: ElementId,
+    code: SharedString,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+    line_numbers: bool,
+    first_line_number: usize,
+    highlighted_lines: HashSet<usize>,
+    soft_wrap: bool,
+    on_toggle_soft_wrap: Option<Rc<dyn Fn(bool, &mut WindowContext)>>,
+    copy_button: bool,
+    background: Option<Hsla>,
+}
+
+impl CodeBlock {
+    pub fn new(id: impl Into<ElementId>, code: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            code: code.into(),
+            highlights: Vec::new(),
+            line_numbers: false,
+            first_line_number: 1,
+            highlighted_lines: HashSet::default(),
+            soft_wrap: true,
+            on_toggle_soft_wrap: None,
+            copy_button: true,
+            background: None,
+        }
+    }
+
+    /// Styles byte ranges of the code, which must be sorted and not overlap.
+    pub fn highlights(
+        mut self,
+        highlights: impl IntoIterator<Item = (Range<usize>, HighlightStyle)>,
+    ) -> Self {
+        self.highlights = highlights.into_iter().collect();
+        self
+    }
+
+    /// Styles byte ranges of the code with the style of the given syntax highlight
+    /// captures in the theme.
+    pub fn syntax_highlights<N: AsRef<str>>(
+        mut self,
+        highlights: impl IntoIterator<Item = (Range<usize>, N)>,
+        cx: &WindowContext,
+    ) -> Self {
+        let syntax = cx.theme().syntax().clone();
+        self.highlights = highlights
+            .into_iter()
+            .map(|(range, name)| (range, syntax.get(name.as_ref())))
+            .collect();
+        self
+    }
+
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// The number shown for the first line, e.g. for an excerpt of a file.
+    /// Defaults to 1.
+    pub fn first_line_number(mut self, first_line_number: usize) -> Self {
+        self.first_line_number = first_line_number;
+        self
+    }
+
+    /// Highlights the lines with the given zero-based indices.
+    pub fn highlighted_lines(mut self, lines: impl IntoIterator<Item = usize>) -> Self {
+        self.highlighted_lines = lines.into_iter().collect();
+        self
+    }
+
+    /// Whether long lines wrap, rather than scroll horizontally. Defaults to `true`.
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    /// Shows a button on hover to toggle soft wrap, called with the new value.
+    pub fn on_toggle_soft_wrap(
+        mut self,
+        handler: impl Fn(bool, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_toggle_soft_wrap = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether to show the copy button on hover. Defaults to `true`.
+    pub fn copy_button(mut self, copy_button: bool) -> Self {
+        self.copy_button = copy_button;
+        self
+    }
+
+    /// Replaces the editor background the code is shown on.
+    pub fn background(mut self, background: Hsla) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    fn render_buttons(&self, text_style: &TextStyle) -> impl IntoElement {
+        let soft_wrap = self.soft_wrap;
+
+        h_flex()
+            .absolute()
+            .top_1()
+            .right_1()
+            .gap_0p5()
+            .visible_on_hover("code-block")
+            .when_some(self.on_toggle_soft_wrap.clone(), |this, on_toggle| {
+                this.child(
+                    IconButton::new("code-block-soft-wrap", IconName::Return)
+                        .icon_size(IconSize::Small)
+                        .icon_color(Color::Muted)
+                        .selected(soft_wrap)
+                        .tooltip(move |cx| {
+                            Tooltip::text(
+                                if soft_wrap {
+                                    "Disable Soft Wrap"
+                                } else {
+                                    "Enable Soft Wrap"
+                                },
+                                cx,
+                            )
+                        })
+                        .on_click(move |_, cx| on_toggle(!soft_wrap, cx)),
+                )
+            })
+            .when(self.copy_button, |this| {
+                let code = self.code.clone();
+                let highlights = self.highlights.clone();
+                let text_style = text_style.clone();
+                this.child(
+                    IconButton::new("code-block-copy", IconName::Copy)
+                        .icon_size(IconSize::Small)
+                        .icon_color(Color::Muted)
+                        .tooltip(|cx| Tooltip::text("Copy Code", cx))
+                        .on_click(move |_, cx| {
+                            cx.write_to_clipboard(formatted_clipboard_item(
+                                &code,
+                                &text_style,
+                                &highlights,
+                            ))
+                        }),
+                )
+            })
+    }
+}
+
+impl RenderOnce for CodeBlock {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.clone();
+        let mut text_style = cx.text_style();
+        text_style.font_family = buffer_font.family;
+        text_style.font_features = buffer_font.features;
+        text_style.font_fallbacks = buffer_font.fallbacks;
+        text_style.font_weight = buffer_font.weight;
+
+        let colors = cx.theme().colors();
+        let highlighted_line_background = colors.editor_highlighted_line_background;
+        let line_number_color = colors.editor_line_number;
+        let background = self.background.unwrap_or(colors.editor_background);
+
+        let lines = line_ranges(&self.code);
+        let last_line_number = self.first_line_number + lines.len() - 1;
+        let gutter_width = rems(0.6 * last_line_number.to_string().len() as f32 + 0.75);
+
+        let rows = lines.iter().enumerate().map(|(ix, line)| {
+            let text = StyledText::new(self.code[line.clone()].to_string())
+                .with_highlights(&text_style, line_highlights(&self.highlights, line));
+            h_flex()
+                .w_full()
+                .items_start()
+                .when(self.highlighted_lines.contains(&ix), |this| {
+                    this.bg(highlighted_line_background)
+                })
+                .when(self.line_numbers, |this| {
+                    this.child(
+                        div()
+                            .flex_none()
+                            .w(gutter_width)
+                            .pr_3()
+                            .flex()
+                            .justify_end()
+                            .text_color(line_number_color)
+                            .child((self.first_line_number + ix).to_string()),
+                    )
+                })
+                .child(
+                    div()
+                        .min_w_0()
+                        .map(|this| {
+                            if self.soft_wrap {
+                                this.flex_1()
+                            } else {
+                                this.flex_none().whitespace_nowrap()
+                            }
+                        })
+                        .child(text),
+                )
+        });
+
+        div()
+            .id(self.id.clone())
+            .group("code-block")
+            .relative()
+            .w_full()
+            .rounded_md()
+            .bg(background)
+            .font_buffer(cx)
+            .text_buffer(cx)
+            .child(
+                v_flex()
+                    .id("code-block-lines")
+                    .p_2()
+                    .when(!self.soft_wrap, |this| this.overflow_x_scroll())
+                    .children(rows),
+            )
+            .child(self.render_buttons(&TextStyle {
+                background_color: Some(background),
+                ..text_style.clone()
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_ranges() {
+        assert_eq!(line_ranges("a\r\nbc\n\nd"), vec![0..1, 3..5, 6..6, 7..8]);
+        assert_eq!(line_ranges("fn main() {}\n"), vec![0..12]);
+        assert_eq!(line_ranges(""), vec![0..0]);
+    }
+
+    #[test]
+    fn test_line_highlights() {
+        let style = HighlightStyle::default();
+        let highlights = [(0..2, style), (3..8, style), (9..10, style)];
+        assert_eq!(line_highlights(&highlights, &(4..9)), vec![(0..4, style)]);
+    }
+}
@@ -1,23 +1,40 @@
 #![allow(missing_docs)]
 use crate::{
-    h_flex, prelude::*, utils::WithRemSize, v_flex, Icon, IconName, KeyBinding, Label, List,
-    ListItem, ListSeparator, ListSubHeader,
+    h_flex,
+    prelude::*,
+    utils::{register_dismissible, DismissRegistration, WithRemSize},
+    v_flex, Icon, IconName, KeyBinding, Label, List, ListItem, ListSeparator, ListSubHeader,
+    LoadingLabel,
 };
 use gpui::{
     px, Action, AnyElement, AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView,
-    IntoElement, Render, Subscription, View, VisualContext,
+    IntoElement, Render, Subscription, Task, View, VisualContext,
 };
 use menu::{SelectFirst, SelectLast, SelectNext, SelectPrev};
 use settings::Settings;
 use std::{rc::Rc, time::Duration};
 use theme::ThemeSettings;
 
+/// The glyph shown for a toggleable [`ContextMenu`] entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ToggleKind {
+    /// A checkmark, for options that are on or off, like "Show Whitespace".
+    Check,
+    /// A dot, for the one selected option of a group, like a sort order.
+    Radio,
+}
+
 enum ContextMenuItem {
     Separator,
     Header(SharedString),
     Label(SharedString),
+    /// A section whose items are still being loaded.
+    Loading {
+        id: usize,
+        label: SharedString,
+    },
     Entry {
-        toggle: Option<(IconPosition, bool)>,
+        toggle: Option<(IconPosition, ToggleKind, bool)>,
         label: SharedString,
         icon: Option<IconName>,
         handler: Rc<dyn Fn(Option<&FocusHandle>, &mut WindowContext)>,
@@ -38,7 +55,13 @@ pub struct ContextMenu {
     selected_index: Option<usize>,
     delayed: bool,
     clicked: bool,
+    next_loading_id: usize,
+    loading_tasks: Vec<Task<()>>,
     _on_blur_subscription: Subscription,
+    _on_focus_subscription: Subscription,
+    /// Set while the menu is shown, which is when it has focus, since menus can be
+    /// built ahead of being shown, e.g. by a [`DropdownMenu`](crate::DropdownMenu).
+    dismiss_registration: Option<DismissRegistration>,
 }
 
 impl FocusableView for ContextMenu {
@@ -61,6 +84,15 @@ impl ContextMenu {
             let _on_blur_subscription = cx.on_blur(&focus_handle, |this: &mut ContextMenu, cx| {
                 this.cancel(&menu::Cancel, cx)
             });
+            let _on_focus_subscription = cx.on_focus_in(&focus_handle, |this, cx| {
+                if this.dismiss_registration.is_none() {
+                    let menu = cx.view().downgrade();
+                    this.dismiss_registration =
+                        Some(register_dismissible(&this.focus_handle, cx, move |cx| {
+                            menu.update(cx, |_, cx| cx.emit(DismissEvent)).ok();
+                        }));
+                }
+            });
             cx.refresh();
             f(
                 Self {
@@ -70,7 +102,11 @@ impl ContextMenu {
                     selected_index: None,
                     delayed: false,
                     clicked: false,
+                    next_loading_id: 0,
+                    loading_tasks: Vec::new(),
                     _on_blur_subscription,
+                    _on_focus_subscription,
+                    dismiss_registration: None,
                 },
                 cx,
             )
@@ -118,7 +154,39 @@ impl ContextMenu {
         handler: impl Fn(&mut WindowContext) + 'static,
     ) -> Self {
         self.items.push(ContextMenuItem::Entry {
-            toggle: Some((position, toggled)),
+            toggle: Some((position, ToggleKind::Check, toggled)),
+            label: label.into(),
+            handler: Rc::new(move |_, cx| handler(cx)),
+            icon: None,
+            action,
+            disabled: false,
+        });
+        self
+    }
+
+    /// Adds an entry with a checkmark in front of the label when `checked`.
+    pub fn checkable_entry(
+        self,
+        label: impl Into<SharedString>,
+        checked: bool,
+        action: Option<Box<dyn Action>>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        self.toggleable_entry(label, checked, IconPosition::Start, action, handler)
+    }
+
+    /// Adds an entry of a group of exclusive options, with a dot in front of the
+    /// label when it's the `selected` one. Consecutive radio entries form a group;
+    /// the handler is expected to select the entry and deselect the others.
+    pub fn radio_entry(
+        mut self,
+        label: impl Into<SharedString>,
+        selected: bool,
+        action: Option<Box<dyn Action>>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        self.items.push(ContextMenuItem::Entry {
+            toggle: Some((IconPosition::Start, ToggleKind::Radio, selected)),
             label: label.into(),
             handler: Rc::new(move |_, cx| handler(cx)),
             icon: None,
@@ -158,6 +226,79 @@ impl ContextMenu {
         self
     }
 
+    /// Adds a section whose items are loaded asynchronously, like the branches of
+    /// a repository. A loading row is shown in its place until `load` resolves,
+    /// and then replaced with the items `build` adds to the menu.
+    pub fn async_section<T: 'static>(
+        mut self,
+        loading_label: impl Into<SharedString>,
+        load: Task<anyhow::Result<T>>,
+        build: impl FnOnce(Self, T, &mut ViewContext<Self>) -> Self + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let id = self.next_loading_id;
+        self.next_loading_id += 1;
+        self.items.push(ContextMenuItem::Loading {
+            id,
+            label: loading_label.into(),
+        });
+        self.loading_tasks.push(cx.spawn(|this, mut cx| async move {
+            let result = load.await;
+            this.update(&mut cx, |this, cx| {
+                let items = match result {
+                    Ok(value) => build(Self::scratch(cx), value, cx).items,
+                    Err(error) => {
+                        log::error!("failed to load context menu section: {error:#}");
+                        vec![ContextMenuItem::Label("Failed to load".into())]
+                    }
+                };
+                this.replace_loading_section(id, items, cx);
+            })
+            .ok();
+        }));
+        self
+    }
+
+    /// Returns an empty menu, not backed by a view, to collect the items of an
+    /// asynchronous section into.
+    fn scratch(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            items: Default::default(),
+            focus_handle: cx.focus_handle(),
+            action_context: None,
+            selected_index: None,
+            delayed: false,
+            clicked: false,
+            next_loading_id: 0,
+            loading_tasks: Vec::new(),
+            _on_blur_subscription: Subscription::new(|| {}),
+            _on_focus_subscription: Subscription::new(|| {}),
+            dismiss_registration: None,
+        }
+    }
+
+    fn replace_loading_section(
+        &mut self,
+        id: usize,
+        items: Vec<ContextMenuItem>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ix) = self.items.iter().position(
+            |item| matches!(item, ContextMenuItem::Loading { id: item_id, .. } if *item_id == id),
+        ) else {
+            return;
+        };
+        let inserted = items.len();
+        self.items.splice(ix..=ix, items);
+        // Keep the same entry selected, as the section renders above it.
+        if let Some(selected_index) = self.selected_index.as_mut() {
+            if *selected_index > ix {
+                *selected_index = *selected_index + inserted - 1;
+            }
+        }
+        cx.notify();
+    }
+
     pub fn action(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
         self.items.push(ContextMenuItem::Entry {
             toggle: None,
@@ -225,10 +366,12 @@ impl ContextMenu {
             (handler)(context, cx)
         }
 
+        self.dismiss_registration.take();
         cx.emit(DismissEvent);
     }
 
     pub fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        self.dismiss_registration.take();
         cx.emit(DismissEvent);
         cx.emit(DismissEvent);
     }
@@ -330,7 +473,8 @@ impl ContextMenuItem {
         match self {
             ContextMenuItem::Header(_)
             | ContextMenuItem::Separator
-            | ContextMenuItem::Label { .. } => false,
+            | ContextMenuItem::Label { .. }
+            | ContextMenuItem::Loading { .. } => false,
             ContextMenuItem::Entry { disabled, .. } => !disabled,
             ContextMenuItem::CustomEntry { selectable, .. } => *selectable,
         }
@@ -340,6 +484,17 @@ impl ContextMenuItem {
 impl Render for ContextMenu {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let ui_font_size = ThemeSettings::get_global(cx).ui_font_size;
+        // Reserve the glyph column of leading toggles on every entry, so that all
+        // labels line up.
+        let has_leading_toggles = self.items.iter().any(|item| {
+            matches!(
+                item,
+                ContextMenuItem::Entry {
+                    toggle: Some((IconPosition::Start, _, _)),
+                    ..
+                }
+            )
+        });
 
         div().occlude().elevation_2(cx).flex().flex_row().child(
             WithRemSize::new(ui_font_size).flex().child(
@@ -383,6 +538,14 @@ impl Render for ContextMenu {
                                         .inset(true)
                                         .into_any_element()
                                 }
+                                ContextMenuItem::Loading { id, label } => ListItem::new(ix)
+                                    .inset(true)
+                                    .disabled(true)
+                                    .child(LoadingLabel::new(
+                                        ("context-menu-loading", *id),
+                                        label.clone(),
+                                    ))
+                                    .into_any_element(),
                                 ContextMenuItem::Label(label) => ListItem::new(ix)
                                     .inset(true)
                                     .disabled(true)
@@ -419,23 +582,38 @@ impl Render for ContextMenu {
                                         .inset(true)
                                         .disabled(*disabled)
                                         .selected(Some(ix) == self.selected_index)
-                                        .when_some(*toggle, |list_item, (position, toggled)| {
-                                            let contents = if toggled {
-                                                v_flex().flex_none().child(
-                                                    Icon::new(IconName::Check).color(Color::Accent),
-                                                )
-                                            } else {
-                                                v_flex()
-                                                    .flex_none()
-                                                    .size(IconSize::default().rems())
-                                            };
-                                            match position {
-                                                IconPosition::Start => {
-                                                    list_item.start_slot(contents)
+                                        .when_some(
+                                            *toggle,
+                                            |list_item, (position, kind, toggled)| {
+                                                let contents = if toggled {
+                                                    let icon = match kind {
+                                                        ToggleKind::Check => IconName::Check,
+                                                        ToggleKind::Radio => IconName::Indicator,
+                                                    };
+                                                    v_flex()
+                                                        .flex_none()
+                                                        .child(Icon::new(icon).color(Color::Accent))
+                                                } else {
+                                                    toggle_placeholder()
+                                                };
+                                                match position {
+                                                    IconPosition::Start => {
+                                                        list_item.start_slot(contents)
+                                                    }
+                                                    IconPosition::End => {
+                                                        list_item.end_slot(contents)
+                                                    }
                                                 }
-                                                IconPosition::End => list_item.end_slot(contents),
-                                            }
-                                        })
+                                            },
+                                        )
+                                        .when(
+                                            has_leading_toggles
+                                                && !matches!(
+                                                    toggle,
+                                                    Some((IconPosition::Start, _, _))
+                                                ),
+                                            |list_item| list_item.start_slot(toggle_placeholder()),
+                                        )
                                         .child(
                                             h_flex()
                                                 .w_full()
@@ -508,3 +686,8 @@ impl Render for ContextMenu {
         )
     }
 }
+
+/// The empty glyph column of an entry that isn't toggled.
+fn toggle_placeholder() -> Div {
+    v_flex().flex_none().size(IconSize::default().rems())
+}
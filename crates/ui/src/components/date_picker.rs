@@ -0,0 +1,186 @@
+#![allow(missing_docs)]
+use std::cell::Cell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    prelude::*, AnchoredPopover, ButtonLike, Calendar, CalendarSelectionMode, CalendarState,
+};
+
+/// The calendar of a [`DatePicker`] and whether it's open, held by the view
+/// rendering the picker.
+#[derive(Clone)]
+pub struct DatePickerState {
+    calendar: CalendarState,
+    open: Rc<Cell<bool>>,
+}
+
+impl DatePickerState {
+    pub fn new(cx: &mut WindowContext) -> Self {
+        Self {
+            calendar: CalendarState::new(cx),
+            open: Rc::default(),
+        }
+    }
+
+    /// The calendar shown in the popover, which holds the selection.
+    pub fn calendar(&self) -> &CalendarState {
+        &self.calendar
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.get()
+    }
+
+    pub fn open(&self, cx: &mut WindowContext) {
+        if let Some(date) = self.calendar.selected_date() {
+            self.calendar.show_month(date);
+        }
+        self.open.set(true);
+        cx.focus(self.calendar.focus_handle());
+        cx.refresh();
+    }
+
+    pub fn close(&self, cx: &mut WindowContext) {
+        self.open.set(false);
+        cx.refresh();
+    }
+}
+
+/// A button showing the selected date or range, opening a [`Calendar`] in a
+/// popover to pick another one. The popover closes once a selection is picked.
+#[derive(IntoElement)]
+pub struct DatePicker {
+    id: ElementId,
+    state: DatePickerState,
+    mode: CalendarSelectionMode,
+    placeholder: SharedString,
+    format: SharedString,
+    first_weekday: Weekday,
+    is_disabled: Option<Rc<dyn Fn(NaiveDate) -> bool>>,
+    on_select: Option<Rc<dyn Fn(&RangeInclusive<NaiveDate>, &mut WindowContext)>>,
+}
+
+impl DatePicker {
+    pub fn new(id: impl Into<ElementId>, state: &DatePickerState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            mode: CalendarSelectionMode::default(),
+            placeholder: "Pick a Date".into(),
+            format: "%b %-d, %Y".into(),
+            first_weekday: Weekday::Mon,
+            is_disabled: None,
+            on_select: None,
+        }
+    }
+
+    pub fn selection_mode(mut self, mode: CalendarSelectionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The text shown while nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// The [`chrono` format](chrono::format::strftime) of the dates shown. Defaults
+    /// to dates like "Oct 14, 2026".
+    pub fn format(mut self, format: impl Into<SharedString>) -> Self {
+        self.format = format.into();
+        self
+    }
+
+    /// The day weeks start with in the calendar. Defaults to Monday.
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
+    /// Disables the dates for which the given function returns `true`.
+    pub fn disabled_dates(mut self, is_disabled: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.is_disabled = Some(Rc::new(is_disabled));
+        self
+    }
+
+    /// Called when a date is picked, or the end of a range in
+    /// [`CalendarSelectionMode::Range`].
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&RangeInclusive<NaiveDate>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    fn label(&self) -> Option<String> {
+        let selection = self.state.calendar.selection()?;
+        let (start, end) = (*selection.start(), *selection.end());
+        let format = self.format.as_ref();
+        Some(if start == end {
+            start.format(format).to_string()
+        } else {
+            format!("{} – {}", start.format(format), end.format(format))
+        })
+    }
+}
+
+impl RenderOnce for DatePicker {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let label = self.label();
+        let is_open = self.state.is_open();
+
+        let toggle_state = self.state.clone();
+        let trigger = ButtonLike::new(self.id.clone())
+            .selected(is_open)
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(match label {
+                        Some(label) => Label::new(label),
+                        None => Label::new(self.placeholder.clone()).color(Color::Placeholder),
+                    })
+                    .child(
+                        Icon::new(IconName::ChevronDown)
+                            .size(IconSize::XSmall)
+                            .color(Color::Muted),
+                    ),
+            )
+            .on_click(move |_, cx| {
+                if toggle_state.is_open() {
+                    toggle_state.close(cx);
+                } else {
+                    toggle_state.open(cx);
+                }
+            });
+
+        let calendar = is_open.then(|| {
+            let state = self.state.clone();
+            let on_select = self.on_select.clone();
+            let calendar = Calendar::new("date-picker-calendar", &self.state.calendar)
+                .selection_mode(self.mode)
+                .first_weekday(self.first_weekday)
+                .on_select(move |range, cx| {
+                    state.close(cx);
+                    if let Some(on_select) = &on_select {
+                        on_select(range, cx);
+                    }
+                });
+            let calendar = match self.is_disabled.clone() {
+                Some(is_disabled) => calendar.disabled_dates(move |date| is_disabled(date)),
+                None => calendar,
+            };
+            v_flex().elevation_2(cx).child(calendar)
+        });
+
+        let dismiss_state = self.state.clone();
+        AnchoredPopover::new()
+            .trigger(trigger)
+            .content(calendar)
+            .on_dismiss(move |cx| dismiss_state.close(cx))
+    }
+}
@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+use gpui::AnyElement;
+
+use crate::{prelude::*, Skeleton};
+
+/// The structure of a panel, drawn by a [`DeferredPanel`] while the panel loads.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PanelSkeleton {
+    /// A flat list of rows, like the outline of a file or the notifications.
+    #[default]
+    List,
+    /// Rows indented by their depth, like the project panel.
+    Tree,
+}
+
+/// A panel whose content loads asynchronously, drawn as a skeleton of its structure
+/// until the content is ready, so that opening a dock doesn't show a blank panel
+/// that then pops in.
+#[derive(IntoElement)]
+pub struct DeferredPanel {
+    id: ElementId,
+    skeleton: PanelSkeleton,
+    toolbar: bool,
+    row_count: usize,
+    content: Option<AnyElement>,
+}
+
+impl DeferredPanel {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            skeleton: PanelSkeleton::default(),
+            toolbar: false,
+            row_count: 16,
+            content: None,
+        }
+    }
+
+    /// Sets the structure drawn while the panel loads. Defaults to a list.
+    pub fn skeleton(mut self, skeleton: PanelSkeleton) -> Self {
+        self.skeleton = skeleton;
+        self
+    }
+
+    /// Draws a toolbar above the rows while the panel loads.
+    pub fn toolbar(mut self, toolbar: bool) -> Self {
+        self.toolbar = toolbar;
+        self
+    }
+
+    /// Sets how many rows are drawn while the panel loads. Rows that don't fit in the
+    /// panel are clipped.
+    pub fn row_count(mut self, row_count: usize) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Sets the loaded content of the panel, which replaces the skeleton.
+    pub fn content<E: IntoElement>(mut self, content: impl Into<Option<E>>) -> Self {
+        self.content = content.into().map(IntoElement::into_any_element);
+        self
+    }
+
+    fn render_row(&self, ix: usize) -> impl IntoElement {
+        // Vary the depth and width of the rows, so that the placeholder reads as the
+        // entries of the panel.
+        const TREE_DEPTHS: [usize; 8] = [0, 1, 1, 2, 2, 1, 0, 0];
+        const ROW_WIDTHS: [f32; 5] = [0.5, 0.35, 0.6, 0.4, 0.3];
+
+        let depth = match self.skeleton {
+            PanelSkeleton::List => 0,
+            PanelSkeleton::Tree => TREE_DEPTHS[ix % TREE_DEPTHS.len()],
+        };
+        h_flex()
+            .h(rems(1.5))
+            .gap_2()
+            .pl(rems(0.5 + depth as f32))
+            .child(Skeleton::circle(("deferred-panel-icon", ix)).height(rems(0.875)))
+            .child(
+                div().flex_1().child(
+                    Skeleton::line(("deferred-panel-row", ix))
+                        .width(relative(ROW_WIDTHS[ix % ROW_WIDTHS.len()])),
+                ),
+            )
+    }
+}
+
+impl RenderOnce for DeferredPanel {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let base = div().id(self.id.clone()).size_full();
+        if let Some(content) = self.content {
+            return base.child(content);
+        }
+
+        base.flex()
+            .flex_col()
+            .overflow_hidden()
+            .bg(cx.theme().colors().panel_background)
+            .when(self.toolbar, |this| {
+                this.child(
+                    h_flex()
+                        .flex_none()
+                        .h(rems(2.))
+                        .px_2()
+                        .gap_2()
+                        .border_b_1()
+                        .border_color(cx.theme().colors().border_variant)
+                        .child(
+                            div().flex_1().child(
+                                Skeleton::line("deferred-panel-toolbar").width(relative(0.4)),
+                            ),
+                        )
+                        .child(Skeleton::circle("deferred-panel-toolbar-button").height(rems(1.))),
+                )
+            })
+            .child(
+                v_flex()
+                    .py_1()
+                    .children((0..self.row_count).map(|ix| self.render_row(ix))),
+            )
+    }
+}
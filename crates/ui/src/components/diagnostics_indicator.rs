@@ -0,0 +1,293 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{percentage, AnchorCorner, Animation, AnimationExt, AnyElement, Transformation};
+use theme::animation_duration;
+
+use crate::{prelude::*, ButtonLike, ContextMenu, PopoverMenu, Tooltip};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticItemSeverity {
+    Error,
+    Warning,
+}
+
+impl DiagnosticItemSeverity {
+    fn icon(self) -> (IconName, Color) {
+        match self {
+            Self::Error => (IconName::XCircle, Color::Error),
+            Self::Warning => (IconName::Warning, Color::Warning),
+        }
+    }
+}
+
+/// A diagnostic listed in the popover of a [`DiagnosticsIndicator`].
+#[derive(Clone)]
+pub struct DiagnosticItem {
+    path: SharedString,
+    severity: DiagnosticItemSeverity,
+    message: SharedString,
+    position: Option<(u32, u32)>,
+    on_jump: Option<Rc<dyn Fn(&mut WindowContext) + 'static>>,
+}
+
+impl DiagnosticItem {
+    pub fn new(
+        path: impl Into<SharedString>,
+        severity: DiagnosticItemSeverity,
+        message: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            severity,
+            message: message.into(),
+            position: None,
+            on_jump: None,
+        }
+    }
+
+    /// Sets the zero-based row and column of the diagnostic, shown one-based.
+    pub fn position(mut self, row: u32, column: u32) -> Self {
+        self.position = Some((row, column));
+        self
+    }
+
+    /// Sets the handler jumping to the diagnostic when its row is clicked.
+    pub fn on_jump(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_jump = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Groups diagnostics by file, keeping the files in the order they first appear
+/// and sorting the diagnostics of each file by severity, then position.
+pub fn group_diagnostics_by_file(
+    diagnostics: &[DiagnosticItem],
+) -> Vec<(SharedString, Vec<&DiagnosticItem>)> {
+    let mut groups: Vec<(SharedString, Vec<&DiagnosticItem>)> = Vec::new();
+    for diagnostic in diagnostics {
+        match groups.iter_mut().find(|(path, _)| *path == diagnostic.path) {
+            Some((_, group)) => group.push(diagnostic),
+            None => groups.push((diagnostic.path.clone(), vec![diagnostic])),
+        }
+    }
+    for (_, group) in &mut groups {
+        group.sort_by_key(|diagnostic| (diagnostic.severity, diagnostic.position));
+    }
+    groups
+}
+
+/// A status bar entry showing the error and warning counts of a project in
+/// their status colors, with a spinner while checks are running and a popover
+/// listing the diagnostics grouped by file.
+///
+/// The counts default to the number of diagnostics given, and can be set when
+/// only some of the diagnostics are listed.
+#[derive(IntoElement)]
+pub struct DiagnosticsIndicator {
+    id: ElementId,
+    counts: Option<(usize, usize)>,
+    checking: bool,
+    diagnostics: Vec<DiagnosticItem>,
+}
+
+impl DiagnosticsIndicator {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            counts: None,
+            checking: false,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn counts(mut self, error_count: usize, warning_count: usize) -> Self {
+        self.counts = Some((error_count, warning_count));
+        self
+    }
+
+    /// Shows a spinner while diagnostics are being computed, e.g. by `cargo check`.
+    pub fn checking(mut self, checking: bool) -> Self {
+        self.checking = checking;
+        self
+    }
+
+    pub fn diagnostic(mut self, diagnostic: DiagnosticItem) -> Self {
+        self.diagnostics.push(diagnostic);
+        self
+    }
+
+    pub fn diagnostics(mut self, diagnostics: impl IntoIterator<Item = DiagnosticItem>) -> Self {
+        self.diagnostics.extend(diagnostics);
+        self
+    }
+
+    fn counts_or_default(&self) -> (usize, usize) {
+        self.counts.unwrap_or_else(|| {
+            let error_count = self
+                .diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == DiagnosticItemSeverity::Error)
+                .count();
+            (error_count, self.diagnostics.len() - error_count)
+        })
+    }
+}
+
+fn render_count(severity: DiagnosticItemSeverity, count: usize) -> impl IntoElement {
+    let (icon, color) = severity.icon();
+    h_flex()
+        .gap_1()
+        .child(Icon::new(icon).size(IconSize::Small).color(color))
+        .child(Label::new(count.to_string()).size(LabelSize::Small))
+}
+
+fn render_diagnostic(diagnostic: &DiagnosticItem) -> AnyElement {
+    let (icon, color) = diagnostic.severity.icon();
+    h_flex()
+        .w_80()
+        .gap_1()
+        .child(Icon::new(icon).size(IconSize::Small).color(color))
+        .child(
+            div().flex_1().overflow_hidden().child(
+                Label::new(diagnostic.message.clone())
+                    .size(LabelSize::Small)
+                    .single_line(),
+            ),
+        )
+        .children(diagnostic.position.map(|(row, column)| {
+            Label::new(format!("{}:{}", row + 1, column + 1))
+                .size(LabelSize::XSmall)
+                .color(Color::Muted)
+        }))
+        .into_any_element()
+}
+
+impl RenderOnce for DiagnosticsIndicator {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (error_count, warning_count) = self.counts_or_default();
+        let diagnostics: Rc<[DiagnosticItem]> = self.diagnostics.into();
+
+        let trigger = ButtonLike::new("diagnostics-indicator-trigger")
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when(error_count == 0 && warning_count == 0, |this| {
+                        this.child(
+                            Icon::new(IconName::Check)
+                                .size(IconSize::Small)
+                                .color(Color::Default),
+                        )
+                    })
+                    .when(error_count > 0, |this| {
+                        this.child(render_count(DiagnosticItemSeverity::Error, error_count))
+                    })
+                    .when(warning_count > 0, |this| {
+                        this.child(render_count(DiagnosticItemSeverity::Warning, warning_count))
+                    })
+                    .when(self.checking, |this| {
+                        this.child(
+                            Icon::new(IconName::ArrowCircle)
+                                .size(IconSize::Small)
+                                .color(Color::Muted)
+                                .with_animation(
+                                    "diagnostics-indicator-spinner",
+                                    Animation::new(animation_duration(Duration::from_secs(2), cx))
+                                        .repeat(),
+                                    |icon, delta| {
+                                        icon.transform(Transformation::rotate(percentage(delta)))
+                                    },
+                                ),
+                        )
+                    }),
+            )
+            .tooltip(move |cx| {
+                Tooltip::text(
+                    format!("{error_count} errors, {warning_count} warnings"),
+                    cx,
+                )
+            });
+
+        PopoverMenu::new(self.id)
+            .anchor(AnchorCorner::BottomLeft)
+            .trigger(trigger)
+            .menu(move |cx| {
+                if diagnostics.is_empty() {
+                    return None;
+                }
+                let diagnostics = diagnostics.clone();
+                Some(ContextMenu::build(cx, move |menu, _| {
+                    let groups = group_diagnostics_by_file(&diagnostics);
+                    groups
+                        .into_iter()
+                        .enumerate()
+                        .fold(menu, |menu, (ix, (path, group))| {
+                            let menu = if ix > 0 { menu.separator() } else { menu };
+                            group
+                                .into_iter()
+                                .fold(menu.header(path), |menu, diagnostic| {
+                                    let on_jump = diagnostic.on_jump.clone();
+                                    let diagnostic = diagnostic.clone();
+                                    menu.custom_entry(
+                                        move |_| render_diagnostic(&diagnostic),
+                                        move |cx| {
+                                            if let Some(on_jump) = on_jump.as_ref() {
+                                                on_jump(cx);
+                                            }
+                                        },
+                                    )
+                                })
+                        })
+                }))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_diagnostics_by_file() {
+        use DiagnosticItemSeverity::*;
+
+        let diagnostics = [
+            DiagnosticItem::new("src/main.rs", Warning, "unused variable").position(4, 8),
+            DiagnosticItem::new("src/lib.rs", Error, "mismatched types").position(10, 0),
+            DiagnosticItem::new("src/main.rs", Error, "cannot find value").position(12, 4),
+            DiagnosticItem::new("src/main.rs", Warning, "unused import").position(0, 4),
+        ];
+
+        let groups = group_diagnostics_by_file(&diagnostics)
+            .into_iter()
+            .map(|(path, group)| {
+                (
+                    path,
+                    group
+                        .into_iter()
+                        .map(|diagnostic| diagnostic.message.clone())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![
+                (
+                    "src/main.rs".into(),
+                    vec![
+                        "cannot find value".into(),
+                        "unused import".into(),
+                        "unused variable".into()
+                    ]
+                ),
+                ("src/lib.rs".into(), vec!["mismatched types".into()]),
+            ]
+        );
+
+        let indicator = DiagnosticsIndicator::new("diagnostics").diagnostics(diagnostics);
+        assert_eq!(indicator.counts_or_default(), (2, 2));
+        assert_eq!(indicator.counts(5, 1).counts_or_default(), (5, 1));
+    }
+}
@@ -0,0 +1,729 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use collections::HashSet;
+use gpui::{combine_highlights, HighlightStyle, Hsla, StyledText, TextStyle};
+use settings::Settings;
+use similar::{ChangeTag, TextDiff};
+use theme::ThemeSettings;
+
+use crate::{align_wrapped_rows, prelude::*, soft_wrap, WrappedRow};
+
+type SyntaxHighlighter = Rc<dyn Fn(&str) -> Vec<(Range<usize>, HighlightStyle)>>;
+
+/// The number of unchanged lines shown around each change when unchanged regions
+/// are collapsed.
+const CONTEXT_LINES: usize = 3;
+
+/// How a [`DiffView`] lays out the old and new text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// Removed lines above the lines that replaced them, in a single column.
+    #[default]
+    Unified,
+    /// The old text on the left and the new text on the right.
+    SideBySide,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Unchanged,
+    Added,
+    Removed,
+    /// A removed line replaced by the line on the other side of its row.
+    Modified,
+}
+
+/// A line of the old or new text of a diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// The zero-based index of the line in its text.
+    pub line: usize,
+    pub text: String,
+    /// The byte ranges of the text that changed, for modified lines.
+    pub changes: Vec<Range<usize>>,
+}
+
+/// A row of a diff, pairing a line of the old text with a line of the new text.
+/// Added and removed lines only have a line on one side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    pub old: Option<DiffLine>,
+    pub new: Option<DiffLine>,
+}
+
+impl DiffRow {
+    fn is_unchanged(&self) -> bool {
+        matches!(
+            &self.old,
+            Some(DiffLine {
+                kind: DiffLineKind::Unchanged,
+                ..
+            })
+        )
+    }
+}
+
+/// Diffs two texts by line. In each run of changed lines, removed lines are paired
+/// with the added lines in order as modified lines, which get their changes within
+/// the line.
+pub fn diff_rows(old_text: &str, new_text: &str) -> Vec<DiffRow> {
+    let mut rows = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let mut old_line = 0;
+    let mut new_line = 0;
+
+    let diff = TextDiff::from_lines(old_text, new_text);
+    for change in diff.iter_all_changes() {
+        let text = change.value().trim_end_matches(['\n', '\r']).to_string();
+        match change.tag() {
+            ChangeTag::Equal => {
+                flush_changed_lines(&mut rows, &mut removed, &mut added);
+                let line = |line| DiffLine {
+                    kind: DiffLineKind::Unchanged,
+                    line,
+                    text: text.clone(),
+                    changes: Vec::new(),
+                };
+                rows.push(DiffRow {
+                    old: Some(line(old_line)),
+                    new: Some(line(new_line)),
+                });
+                old_line += 1;
+                new_line += 1;
+            }
+            ChangeTag::Delete => {
+                removed.push((old_line, text));
+                old_line += 1;
+            }
+            ChangeTag::Insert => {
+                added.push((new_line, text));
+                new_line += 1;
+            }
+        }
+    }
+    flush_changed_lines(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+fn flush_changed_lines(
+    rows: &mut Vec<DiffRow>,
+    removed: &mut Vec<(usize, String)>,
+    added: &mut Vec<(usize, String)>,
+) {
+    let row_count = removed.len().max(added.len());
+    let mut removed = removed.drain(..);
+    let mut added = added.drain(..);
+    for _ in 0..row_count {
+        let row = match (removed.next(), added.next()) {
+            (Some((old_line, old_text)), Some((new_line, new_text))) => {
+                let (old_changes, new_changes) = line_changes(&old_text, &new_text);
+                DiffRow {
+                    old: Some(DiffLine {
+                        kind: DiffLineKind::Modified,
+                        line: old_line,
+                        text: old_text,
+                        changes: old_changes,
+                    }),
+                    new: Some(DiffLine {
+                        kind: DiffLineKind::Modified,
+                        line: new_line,
+                        text: new_text,
+                        changes: new_changes,
+                    }),
+                }
+            }
+            (Some((line, text)), None) => DiffRow {
+                old: Some(DiffLine {
+                    kind: DiffLineKind::Removed,
+                    line,
+                    text,
+                    changes: Vec::new(),
+                }),
+                new: None,
+            },
+            (None, Some((line, text))) => DiffRow {
+                old: None,
+                new: Some(DiffLine {
+                    kind: DiffLineKind::Added,
+                    line,
+                    text,
+                    changes: Vec::new(),
+                }),
+            },
+            (None, None) => break,
+        };
+        rows.push(row);
+    }
+}
+
+/// Returns the byte ranges removed from the old line and inserted in the new line,
+/// merging adjacent changes.
+fn line_changes(old_text: &str, new_text: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    fn push(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+        match ranges.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => ranges.push(range),
+        }
+    }
+
+    let mut old_changes = Vec::new();
+    let mut new_changes = Vec::new();
+    let mut old_offset = 0;
+    let mut new_offset = 0;
+    let diff = TextDiff::from_chars(old_text, new_text);
+    for change in diff.iter_all_changes() {
+        let len = change.value().len();
+        match change.tag() {
+            ChangeTag::Equal => {
+                old_offset += len;
+                new_offset += len;
+            }
+            ChangeTag::Delete => {
+                push(&mut old_changes, old_offset..old_offset + len);
+                old_offset += len;
+            }
+            ChangeTag::Insert => {
+                push(&mut new_changes, new_offset..new_offset + len);
+                new_offset += len;
+            }
+        }
+    }
+    (old_changes, new_changes)
+}
+
+/// A run of the rows of a diff, by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiffSection {
+    Shown(Range<usize>),
+    /// Unchanged rows further than [`CONTEXT_LINES`] from any change.
+    Collapsible(Range<usize>),
+}
+
+fn diff_sections(rows: &[DiffRow], context: usize) -> Vec<DiffSection> {
+    let mut sections = Vec::new();
+    let mut shown_start = 0;
+    let mut ix = 0;
+    while ix < rows.len() {
+        if !rows[ix].is_unchanged() {
+            ix += 1;
+            continue;
+        }
+        let run_start = ix;
+        while ix < rows.len() && rows[ix].is_unchanged() {
+            ix += 1;
+        }
+        let collapsed_start = if run_start == 0 {
+            0
+        } else {
+            run_start + context
+        };
+        let collapsed_end = if ix == rows.len() {
+            ix
+        } else {
+            ix.saturating_sub(context)
+        };
+        // Hiding a single line behind a button wouldn't save any space.
+        if collapsed_end > collapsed_start + 1 {
+            if collapsed_start > shown_start {
+                sections.push(DiffSection::Shown(shown_start..collapsed_start));
+            }
+            sections.push(DiffSection::Collapsible(collapsed_start..collapsed_end));
+            shown_start = collapsed_end;
+        }
+    }
+    if shown_start < rows.len() {
+        sections.push(DiffSection::Shown(shown_start..rows.len()));
+    }
+    sections
+}
+
+/// The unchanged regions of a [`DiffView`] that were expanded, held by the view
+/// rendering it so that they stay expanded across frames.
+#[derive(Clone, Default)]
+pub struct DiffViewState(Rc<RefCell<HashSet<usize>>>);
+
+impl DiffViewState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn is_expanded(&self, first_row: usize) -> bool {
+        self.0.borrow().contains(&first_row)
+    }
+
+    fn expand(&self, first_row: usize) {
+        self.0.borrow_mut().insert(first_row);
+    }
+
+    /// Collapses the unchanged regions again.
+    pub fn collapse_all(&self) {
+        self.0.borrow_mut().clear();
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DiffSide {
+    Old,
+    New,
+}
+
+/// Renders a diff of two texts, in a single column or side by side, with the
+/// removed, added and modified lines highlighted with the status colors of the
+/// theme. Unchanged regions away from the changes are collapsed until clicked.
+#[derive(IntoElement)]
+pub struct DiffView {
+    id: ElementId,
+    rows: Vec<DiffRow>,
+    layout: DiffLayout,
+    state: Option<DiffViewState>,
+    wrap_columns: Option<usize>,
+    syntax: Option<SyntaxHighlighter>,
+}
+
+impl DiffView {
+    pub fn new(
+        id: impl Into<ElementId>,
+        old_text: impl AsRef<str>,
+        new_text: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            rows: diff_rows(old_text.as_ref(), new_text.as_ref()),
+            layout: DiffLayout::default(),
+            state: None,
+            wrap_columns: None,
+            syntax: None,
+        }
+    }
+
+    pub fn layout(mut self, layout: DiffLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Collapses the unchanged regions, which expand when clicked and stay expanded
+    /// in the given state.
+    pub fn collapse_unchanged(mut self, state: &DiffViewState) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
+    /// Wraps lines longer than the given number of characters. Side by side, each
+    /// side wraps separately, and the rows stay aligned.
+    pub fn soft_wrap(mut self, columns: usize) -> Self {
+        self.wrap_columns = Some(columns);
+        self
+    }
+
+    /// Highlights the syntax of each line with the styles returned for its byte
+    /// ranges. The changes within lines are highlighted over them.
+    pub fn syntax_highlights(
+        mut self,
+        highlighter: impl Fn(&str) -> Vec<(Range<usize>, HighlightStyle)> + 'static,
+    ) -> Self {
+        self.syntax = Some(Rc::new(highlighter));
+        self
+    }
+}
+
+struct DiffColors {
+    added: Hsla,
+    removed: Hsla,
+    modified: Hsla,
+    added_text: Hsla,
+    removed_text: Hsla,
+}
+
+impl DiffColors {
+    fn new(cx: &WindowContext) -> Self {
+        let status = cx.theme().status();
+        Self {
+            added: status.created_background,
+            removed: status.deleted_background,
+            modified: status.modified_background,
+            added_text: status.created.opacity(0.3),
+            removed_text: status.deleted.opacity(0.3),
+        }
+    }
+
+    fn line_background(&self, kind: DiffLineKind) -> Option<Hsla> {
+        match kind {
+            DiffLineKind::Unchanged => None,
+            DiffLineKind::Added => Some(self.added),
+            DiffLineKind::Removed => Some(self.removed),
+            DiffLineKind::Modified => Some(self.modified),
+        }
+    }
+}
+
+fn buffer_text_style(cx: &WindowContext) -> TextStyle {
+    let buffer_font = ThemeSettings::get_global(cx).buffer_font.clone();
+    let mut text_style = cx.text_style();
+    text_style.font_family = buffer_font.family;
+    text_style.font_features = buffer_font.features;
+    text_style.font_fallbacks = buffer_font.fallbacks;
+    text_style.font_weight = buffer_font.weight;
+    text_style
+}
+
+struct DiffRenderer {
+    text_style: TextStyle,
+    colors: DiffColors,
+    border_color: Hsla,
+    wrap_columns: Option<usize>,
+    syntax: Option<SyntaxHighlighter>,
+}
+
+/// Clips highlighted ranges of a line to a segment of it, relative to the segment.
+fn clip_highlights(
+    highlights: impl IntoIterator<Item = (Range<usize>, HighlightStyle)>,
+    segment: &Range<usize>,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    highlights
+        .into_iter()
+        .filter(|(range, _)| range.start < segment.end && range.end > segment.start)
+        .map(|(range, style)| {
+            let start = range.start.max(segment.start) - segment.start;
+            let end = range.end.min(segment.end) - segment.start;
+            (start..end, style)
+        })
+        .collect()
+}
+
+impl DiffRenderer {
+    fn segments(&self, line: &DiffLine) -> Vec<Range<usize>> {
+        match self.wrap_columns {
+            Some(columns) => soft_wrap(&line.text, columns),
+            None => vec![0..line.text.len()],
+        }
+    }
+
+    fn gutter(&self, line_number: Option<usize>) -> Div {
+        div()
+            .flex_none()
+            .w(rems(3.))
+            .pr_2()
+            .flex()
+            .justify_end()
+            .when_some(line_number, |this, line_number| {
+                this.child(
+                    Label::new((line_number + 1).to_string())
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                )
+            })
+    }
+
+    /// Renders a wrapped segment of a line, with the changes within the line that
+    /// overlap the segment highlighted.
+    fn text(&self, line: &DiffLine, side: DiffSide, segment: Range<usize>) -> StyledText {
+        let color = match side {
+            DiffSide::Old => self.colors.removed_text,
+            DiffSide::New => self.colors.added_text,
+        };
+        let changes = clip_highlights(
+            line.changes.iter().map(|change| {
+                (
+                    change.clone(),
+                    HighlightStyle {
+                        background_color: Some(color),
+                        ..Default::default()
+                    },
+                )
+            }),
+            &segment,
+        );
+        let highlights = match &self.syntax {
+            Some(syntax) => {
+                let syntax = clip_highlights(syntax(&line.text), &segment);
+                combine_highlights(syntax, changes).collect()
+            }
+            None => changes,
+        };
+        StyledText::new(line.text[segment].to_string())
+            .with_highlights(&self.text_style, highlights)
+    }
+
+    fn row(&self, background: Option<Hsla>) -> Div {
+        h_flex()
+            .w_full()
+            .min_h(rems(1.25))
+            .when_some(background, |this, background| this.bg(background))
+    }
+
+    fn render_unified(&self, rows: &[DiffRow], children: &mut Vec<AnyElement>) {
+        let mut pending_added = Vec::new();
+        let mut lines = Vec::new();
+        for row in rows {
+            if row.is_unchanged() {
+                lines.append(&mut pending_added);
+            }
+            match (&row.old, &row.new) {
+                (Some(old), Some(new)) if row.is_unchanged() => {
+                    lines.push((Some(old.line), Some(new.line), new, DiffSide::New));
+                }
+                (old, new) => {
+                    if let Some(old) = old {
+                        lines.push((Some(old.line), None, old, DiffSide::Old));
+                    }
+                    if let Some(new) = new {
+                        pending_added.push((None, Some(new.line), new, DiffSide::New));
+                    }
+                }
+            }
+        }
+        lines.append(&mut pending_added);
+
+        for (old_line, new_line, line, side) in lines {
+            let background = self.colors.line_background(line.kind);
+            for (ix, segment) in self.segments(line).into_iter().enumerate() {
+                let first = ix == 0;
+                children.push(
+                    self.row(background)
+                        .child(self.gutter(old_line.filter(|_| first)))
+                        .child(self.gutter(new_line.filter(|_| first)))
+                        .child(div().flex_1().child(self.text(line, side, segment)))
+                        .into_any_element(),
+                );
+            }
+        }
+    }
+
+    fn render_side(
+        &self,
+        line: Option<&DiffLine>,
+        side: DiffSide,
+        wrapped_row: WrappedRow,
+        segments: &[Vec<Range<usize>>],
+    ) -> Div {
+        let segment = match (line, wrapped_row) {
+            (
+                Some(line),
+                WrappedRow::Segment {
+                    line: row_ix,
+                    segment,
+                },
+            ) => Some((line, segment, segments[row_ix][segment].clone())),
+            _ => None,
+        };
+        let background = line.and_then(|line| self.colors.line_background(line.kind));
+
+        h_flex()
+            .w_1_2()
+            .h_full()
+            .overflow_hidden()
+            .when_some(background, |this, background| this.bg(background))
+            .map(|this| match segment {
+                Some((line, segment_ix, range)) => this
+                    .child(self.gutter(Some(line.line).filter(|_| segment_ix == 0)))
+                    .child(div().flex_1().child(self.text(line, side, range))),
+                None => this.child(self.gutter(None)),
+            })
+    }
+
+    fn render_side_by_side(&self, rows: &[DiffRow], children: &mut Vec<AnyElement>) {
+        let old_segments = rows
+            .iter()
+            .map(|row| {
+                row.old
+                    .as_ref()
+                    .map(|line| self.segments(line))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let new_segments = rows
+            .iter()
+            .map(|row| {
+                row.new
+                    .as_ref()
+                    .map(|line| self.segments(line))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let aligned_rows = rows
+            .iter()
+            .enumerate()
+            .map(|(ix, row)| (row.old.as_ref().map(|_| ix), row.new.as_ref().map(|_| ix)))
+            .collect::<Vec<_>>();
+
+        let visual_rows = align_wrapped_rows(
+            &aligned_rows,
+            |ix| old_segments[ix].len(),
+            |ix| new_segments[ix].len(),
+        );
+        for (left, right) in visual_rows {
+            let row_ix = match (left, right) {
+                (WrappedRow::Segment { line, .. }, _) | (_, WrappedRow::Segment { line, .. }) => {
+                    line
+                }
+                (WrappedRow::Spacer, WrappedRow::Spacer) => continue,
+            };
+            let row = &rows[row_ix];
+            children.push(
+                self.row(None)
+                    .child(self.render_side(row.old.as_ref(), DiffSide::Old, left, &old_segments))
+                    .child(div().flex_none().w_px().h_full().bg(self.border_color))
+                    .child(self.render_side(row.new.as_ref(), DiffSide::New, right, &new_segments))
+                    .into_any_element(),
+            );
+        }
+    }
+
+    fn render_rows(&self, layout: DiffLayout, rows: &[DiffRow], children: &mut Vec<AnyElement>) {
+        match layout {
+            DiffLayout::Unified => self.render_unified(rows, children),
+            DiffLayout::SideBySide => self.render_side_by_side(rows, children),
+        }
+    }
+}
+
+fn render_collapsed_rows(
+    first_row: usize,
+    row_count: usize,
+    state: DiffViewState,
+    cx: &WindowContext,
+) -> AnyElement {
+    h_flex()
+        .id(("diff-collapsed-rows", first_row))
+        .w_full()
+        .gap_1()
+        .px_2()
+        .py_0p5()
+        .cursor_pointer()
+        .bg(cx.theme().colors().editor_subheader_background)
+        .hover(|style| style.bg(cx.theme().colors().ghost_element_hover))
+        .child(
+            Icon::new(IconName::ChevronDown)
+                .size(IconSize::XSmall)
+                .color(Color::Muted),
+        )
+        .child(
+            Label::new(format!("Show {row_count} unchanged lines"))
+                .size(LabelSize::Small)
+                .color(Color::Muted),
+        )
+        .on_click(move |_, cx| {
+            state.expand(first_row);
+            cx.refresh();
+        })
+        .into_any_element()
+}
+
+impl RenderOnce for DiffView {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let sections = match &self.state {
+            Some(_) => diff_sections(&self.rows, CONTEXT_LINES),
+            None => vec![DiffSection::Shown(0..self.rows.len())],
+        };
+        let renderer = DiffRenderer {
+            text_style: buffer_text_style(cx),
+            colors: DiffColors::new(cx),
+            border_color: cx.theme().colors().border_variant,
+            wrap_columns: self.wrap_columns,
+            syntax: self.syntax.clone(),
+        };
+
+        let mut children = Vec::new();
+        for section in sections {
+            match section {
+                DiffSection::Shown(range) => {
+                    renderer.render_rows(self.layout, &self.rows[range], &mut children)
+                }
+                DiffSection::Collapsible(range) => match &self.state {
+                    Some(state) if !state.is_expanded(range.start) => {
+                        children.push(render_collapsed_rows(
+                            range.start,
+                            range.len(),
+                            state.clone(),
+                            cx,
+                        ));
+                    }
+                    _ => renderer.render_rows(self.layout, &self.rows[range], &mut children),
+                },
+            }
+        }
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .font_buffer(cx)
+            .text_buffer(cx)
+            .children(children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers lines from 0 to 19, replacing some of them.
+    fn numbered_lines(replaced: &[(usize, &str)]) -> String {
+        (0..20)
+            .map(|ix| match replaced.iter().find(|(line, _)| *line == ix) {
+                Some((_, text)) => format!("{text}\n"),
+                None => format!("{ix}\n"),
+            })
+            .collect()
+    }
+
+    fn kinds(rows: &[DiffRow]) -> Vec<(Option<DiffLineKind>, Option<DiffLineKind>)> {
+        rows.iter()
+            .map(|row| {
+                (
+                    row.old.as_ref().map(|line| line.kind),
+                    row.new.as_ref().map(|line| line.kind),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_rows() {
+        use DiffLineKind::*;
+
+        let rows = diff_rows("a\nlet x = 1;\nb\nc\n", "a\nlet x = 2;\nnew\nb\n");
+        assert_eq!(
+            kinds(&rows),
+            vec![
+                (Some(Unchanged), Some(Unchanged)),
+                (Some(Modified), Some(Modified)),
+                (None, Some(Added)),
+                (Some(Unchanged), Some(Unchanged)),
+                (Some(Removed), None),
+            ]
+        );
+
+        let modified = &rows[1];
+        assert_eq!(modified.old.as_ref().unwrap().changes, vec![8..9]);
+        assert_eq!(modified.new.as_ref().unwrap().changes, vec![8..9]);
+        assert_eq!(rows[2].new.as_ref().unwrap().line, 2);
+        assert_eq!(rows[4].old.as_ref().unwrap().line, 3);
+        assert_eq!(rows[4].old.as_ref().unwrap().text, "c");
+    }
+
+    #[test]
+    fn test_diff_sections() {
+        let old_text = numbered_lines(&[]);
+        let rows = diff_rows(&old_text, &numbered_lines(&[(10, "ten")]));
+        assert_eq!(
+            diff_sections(&rows, 3),
+            vec![
+                DiffSection::Collapsible(0..7),
+                DiffSection::Shown(7..14),
+                DiffSection::Collapsible(14..20),
+            ]
+        );
+
+        // Unchanged runs that are only context aren't collapsed.
+        let rows = diff_rows(&old_text, &numbered_lines(&[(1, "one"), (8, "eight")]));
+        assert_eq!(
+            diff_sections(&rows, 3),
+            vec![DiffSection::Shown(0..12), DiffSection::Collapsible(12..20),]
+        );
+    }
+}
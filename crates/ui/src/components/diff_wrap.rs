@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+use std::ops::Range;
+
+/// A visual row on one side of a side-by-side diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrappedRow {
+    /// The given wrapped segment of a line.
+    Segment { line: usize, segment: usize },
+    /// An empty row, keeping this side aligned with a longer line on the other side.
+    Spacer,
+}
+
+/// Splits a line into segments of at most `max_columns` characters, breaking after
+/// the last whitespace of each segment when there is one.
+///
+/// Returns the byte ranges of the segments. An empty line has a single, empty segment.
+pub fn soft_wrap(line: &str, max_columns: usize) -> Vec<Range<usize>> {
+    let max_columns = max_columns.max(1);
+    let mut segments = Vec::new();
+    let mut segment_start = 0;
+    let mut columns = 0;
+    let mut last_break = None;
+
+    for (ix, ch) in line.char_indices() {
+        if columns == max_columns {
+            let break_ix = last_break.filter(|&ix| ix > segment_start).unwrap_or(ix);
+            segments.push(segment_start..break_ix);
+            columns = line[break_ix..ix].chars().count();
+            segment_start = break_ix;
+            last_break = None;
+        }
+        columns += 1;
+        if ch.is_whitespace() {
+            last_break = Some(ix + ch.len_utf8());
+        }
+    }
+    segments.push(segment_start..line.len());
+    segments
+}
+
+/// Expands the rows of a side-by-side diff into visual rows, so that the two sides
+/// stay aligned when their lines wrap to a different number of segments.
+///
+/// Each row pairs the line shown on the left with the line shown on the right, where
+/// `None` means the row is empty on that side, e.g. for a line that was only added.
+/// The side with fewer segments is padded with [`WrappedRow::Spacer`] rows.
+pub fn align_wrapped_rows(
+    rows: &[(Option<usize>, Option<usize>)],
+    left_segment_count: impl Fn(usize) -> usize,
+    right_segment_count: impl Fn(usize) -> usize,
+) -> Vec<(WrappedRow, WrappedRow)> {
+    let mut visual_rows = Vec::with_capacity(rows.len());
+    for &(left, right) in rows {
+        let left_count = left.map_or(1, |line| left_segment_count(line).max(1));
+        let right_count = right.map_or(1, |line| right_segment_count(line).max(1));
+        for segment in 0..left_count.max(right_count) {
+            let side = |line: Option<usize>, count: usize| match line {
+                Some(line) if segment < count => WrappedRow::Segment { line, segment },
+                _ => WrappedRow::Spacer,
+            };
+            visual_rows.push((side(left, left_count), side(right, right_count)));
+        }
+    }
+    visual_rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soft_wrap() {
+        let line = "fn main() { println!(\"hello\"); }";
+        let segments = soft_wrap(line, 12);
+        assert_eq!(
+            segments
+                .iter()
+                .map(|range| &line[range.clone()])
+                .collect::<Vec<_>>(),
+            vec!["fn main() { ", "println!(\"he", "llo\"); }"]
+        );
+        assert_eq!(soft_wrap("", 10), vec![0..0]);
+        assert_eq!(soft_wrap("αβγδ", 2), vec![0..4, 4..8]);
+    }
+
+    #[test]
+    fn test_align_wrapped_rows() {
+        use WrappedRow::*;
+
+        let rows = [(Some(0), Some(0)), (None, Some(1))];
+        let aligned = align_wrapped_rows(&rows, |_| 1, |line| if line == 0 { 2 } else { 1 });
+        assert_eq!(
+            aligned,
+            vec![
+                (
+                    Segment {
+                        line: 0,
+                        segment: 0
+                    },
+                    Segment {
+                        line: 0,
+                        segment: 0
+                    }
+                ),
+                (
+                    Spacer,
+                    Segment {
+                        line: 0,
+                        segment: 1
+                    }
+                ),
+                (
+                    Spacer,
+                    Segment {
+                        line: 1,
+                        segment: 0
+                    }
+                ),
+            ]
+        );
+    }
+}
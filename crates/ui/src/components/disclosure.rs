@@ -1,7 +1,7 @@
 #![allow(missing_docs)]
 use std::sync::Arc;
 
-use gpui::{ClickEvent, CursorStyle};
+use gpui::{AccessibilityRole, ClickEvent, CursorStyle};
 
 use crate::{prelude::*, Color, IconButton, IconButtonShape, IconName, IconSize};
 
@@ -66,6 +66,11 @@ impl RenderOnce for Disclosure {
         .icon_color(Color::Muted)
         .icon_size(IconSize::Small)
         .selected(self.selected)
+        .map_accessibility(|properties| {
+            let mut properties = properties.expanded(Some(self.is_open));
+            properties.role = AccessibilityRole::Disclosure;
+            properties
+        })
         .when_some(self.on_toggle, move |this, on_toggle| {
             this.on_click(move |event, cx| on_toggle(event, cx))
         })
@@ -0,0 +1,100 @@
+#![allow(missing_docs)]
+use gpui::AnyElement;
+
+use crate::prelude::*;
+
+/// What a panel shows when it has no content, e.g. no search results, no open
+/// project or no collaborators: an icon or illustration, a title, a description
+/// and up to two actions.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    title: SharedString,
+    description: Option<SharedString>,
+    icon: Option<IconName>,
+    illustration: Option<AnyElement>,
+    primary_action: Option<AnyElement>,
+    secondary_action: Option<AnyElement>,
+}
+
+impl EmptyState {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            icon: None,
+            illustration: None,
+            primary_action: None,
+            secondary_action: None,
+        }
+    }
+
+    /// Sets the text shown under the title. It wraps to the width of the panel.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Shows an arbitrary element, like an SVG, in place of the icon.
+    pub fn illustration(mut self, illustration: impl IntoElement) -> Self {
+        self.illustration = Some(illustration.into_any_element());
+        self
+    }
+
+    /// Sets the main action, e.g. a filled "Open Project" button.
+    pub fn primary_action(mut self, action: impl IntoElement) -> Self {
+        self.primary_action = Some(action.into_any_element());
+        self
+    }
+
+    /// Sets an alternative action, shown after the primary one.
+    pub fn secondary_action(mut self, action: impl IntoElement) -> Self {
+        self.secondary_action = Some(action.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let has_actions = self.primary_action.is_some() || self.secondary_action.is_some();
+        let illustration = self.illustration.or_else(|| {
+            self.icon.map(|icon| {
+                div()
+                    .p_3()
+                    .rounded_full()
+                    .bg(cx.theme().colors().element_background)
+                    .child(Icon::new(icon).color(Color::Muted))
+                    .into_any_element()
+            })
+        });
+
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .p_4()
+            .children(illustration)
+            .child(Label::new(self.title))
+            .children(self.description.map(|description| {
+                div().max_w_80().text_center().child(
+                    Label::new(description)
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+            }))
+            .when(has_actions, |this| {
+                this.child(
+                    h_flex()
+                        .pt_2()
+                        .gap_2()
+                        .children(self.primary_action)
+                        .children(self.secondary_action),
+                )
+            })
+    }
+}
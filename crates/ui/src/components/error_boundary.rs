@@ -0,0 +1,261 @@
+#![allow(missing_docs)]
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use gpui::{AnyElement, Bounds, GlobalElementId, LayoutId};
+
+use crate::{prelude::*, Banner, BannerSeverity};
+
+thread_local! {
+    static BOUNDARY_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Whether an [`ErrorBoundary`] is rendering its content on this thread, and so
+/// will recover from a panic.
+///
+/// Panic hooks that abort the process should only report the panic when this is
+/// `true`, and return so that the panic unwinds to the boundary.
+pub fn is_rendering_in_error_boundary() -> bool {
+    BOUNDARY_DEPTH.with(|depth| depth.get() > 0)
+}
+
+type RenderContent = Box<dyn FnOnce(&mut WindowContext) -> anyhow::Result<AnyElement>>;
+type RetryHandler = Rc<dyn Fn(&mut WindowContext)>;
+
+/// Renders its content, or a themed error with a retry button if rendering the
+/// content panics or fails, rather than taking down the window.
+///
+/// Only building the content is guarded, not its layout and paint. The content must
+/// not update views or models while rendering, as a panic would leave them leased.
+///
+/// Retrying discards the element state of the content, like scroll positions, and
+/// calls the handler passed to [`ErrorBoundary::on_retry`] to reset any other state
+/// the content is rendered from.
+pub struct ErrorBoundary {
+    id: ElementId,
+    render_content: Option<RenderContent>,
+    fallback_message: SharedString,
+    on_retry: Option<RetryHandler>,
+}
+
+#[derive(Default)]
+struct ErrorBoundaryState {
+    /// Bumped on retry, so that the content is laid out in a new element namespace.
+    generation: Rc<Cell<usize>>,
+}
+
+impl ErrorBoundary {
+    pub fn new<E: IntoElement>(
+        id: impl Into<ElementId>,
+        render_content: impl FnOnce(&mut WindowContext) -> E + 'static,
+    ) -> Self {
+        Self::try_new(id, move |cx| Ok(render_content(cx)))
+    }
+
+    /// Creates a boundary around content whose rendering can fail with an error.
+    pub fn try_new<E: IntoElement>(
+        id: impl Into<ElementId>,
+        render_content: impl FnOnce(&mut WindowContext) -> anyhow::Result<E> + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            render_content: Some(Box::new(move |cx| {
+                render_content(cx).map(IntoElement::into_any_element)
+            })),
+            fallback_message: "Something went wrong while showing this content.".into(),
+            on_retry: None,
+        }
+    }
+
+    /// Replaces the message shown above the error details.
+    pub fn fallback_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.fallback_message = message.into();
+        self
+    }
+
+    /// Called when the retry button is clicked, before the content is rendered
+    /// again, e.g. to reset the state of the view the content comes from.
+    pub fn on_retry(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_retry = Some(Rc::new(handler));
+        self
+    }
+
+    fn render_content_or_fallback(
+        &mut self,
+        generation: Rc<Cell<usize>>,
+        cx: &mut WindowContext,
+    ) -> AnyElement {
+        let render_content = self.render_content.take().unwrap();
+        BOUNDARY_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| render_content(cx)));
+        BOUNDARY_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+        let error = match result {
+            Ok(Ok(content)) => return content,
+            Ok(Err(error)) => format!("{error:#}"),
+            Err(payload) => format!("panicked: {}", panic_message(payload.as_ref())),
+        };
+        log::error!("error boundary {:?} caught an error: {error}", self.id);
+
+        let on_retry = self.on_retry.clone();
+        v_flex()
+            .test_id("error-boundary-fallback")
+            .w_full()
+            .gap_1()
+            .child(
+                Banner::new("error-boundary-banner", self.fallback_message.clone())
+                    .severity(BannerSeverity::Error),
+            )
+            .child(
+                h_flex()
+                    .px_2()
+                    .gap_2()
+                    .justify_between()
+                    .child(
+                        Label::new(error)
+                            .size(LabelSize::XSmall)
+                            .color(Color::Muted),
+                    )
+                    .child(
+                        Button::new("error-boundary-retry", "Retry")
+                            .test_id("error-boundary-retry")
+                            .label_size(LabelSize::Small)
+                            .on_click(move |_, cx| {
+                                generation.set(generation.get() + 1);
+                                if let Some(on_retry) = &on_retry {
+                                    on_retry(cx);
+                                }
+                                cx.refresh();
+                            }),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+impl Element for ErrorBoundary {
+    type RequestLayoutState = (usize, AnyElement);
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state::<ErrorBoundaryState, _>(id.unwrap(), |state, cx| {
+            let state = state.unwrap_or_default();
+            let generation = state.generation.get();
+            let mut element = self.render_content_or_fallback(state.generation.clone(), cx);
+            let layout_id = cx.with_element_namespace(generation, |cx| element.request_layout(cx));
+            ((layout_id, (generation, element)), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        (generation, element): &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        cx.with_element_namespace(*generation, |cx| element.prepaint(cx));
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        (generation, element): &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        cx.with_element_namespace(*generation, |cx| element.paint(cx));
+    }
+}
+
+impl IntoElement for ErrorBoundary {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    struct BoundaryView {
+        broken: Rc<Cell<bool>>,
+    }
+
+    impl Render for BoundaryView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            let broken = self.broken.clone();
+            div().size_full().child(
+                ErrorBoundary::new("boundary", move |_| {
+                    if broken.get() {
+                        panic!("failed to render");
+                    }
+                    div().test_id("content").size_full()
+                })
+                .on_retry({
+                    let broken = self.broken.clone();
+                    move |_| broken.set(false)
+                }),
+            )
+        }
+    }
+
+    fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = settings::SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+    }
+
+    #[gpui::test]
+    fn test_panicking_content_renders_fallback(cx: &mut TestAppContext) {
+        init_test(cx);
+        let broken = Rc::new(Cell::new(true));
+        let (_, cx) = cx.add_window_view(|_| BoundaryView {
+            broken: broken.clone(),
+        });
+
+        assert!(cx.bounds_for_test_id("error-boundary-fallback").is_some());
+        assert!(cx.bounds_for_test_id("content").is_none());
+
+        cx.simulate_click_on_test_id("error-boundary-retry", gpui::Modifiers::default());
+        cx.run_until_parked();
+
+        assert!(!broken.get());
+        assert!(cx.bounds_for_test_id("error-boundary-fallback").is_none());
+        assert!(cx.bounds_for_test_id("content").is_some());
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload = panic::catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "static message");
+
+        let payload = panic::catch_unwind(|| panic!("formatted {}", 42)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "formatted 42");
+    }
+}
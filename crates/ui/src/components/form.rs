@@ -0,0 +1,465 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+
+use gpui::{AnyElement, AsyncAppContext, Model, ModelContext, Task};
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+/// The result of validating a form field: `Err` holds the message shown under the field.
+pub type ValidationResult = Result<(), SharedString>;
+
+type SyncValidator = Rc<dyn Fn(&str) -> ValidationResult>;
+type AsyncValidator = Rc<dyn Fn(String, AsyncAppContext) -> Task<ValidationResult>>;
+
+struct FieldState {
+    value: String,
+    initial_value: String,
+    error: Option<SharedString>,
+    /// The error reported by the async validator for the current value.
+    async_error: Option<SharedString>,
+    validators: Vec<SyncValidator>,
+    async_validator: Option<AsyncValidator>,
+    pending_validation: Option<Task<()>>,
+}
+
+impl FieldState {
+    fn validate_sync(&mut self) -> bool {
+        self.error = self
+            .validators
+            .iter()
+            .find_map(|validator| validator(&self.value).err());
+        self.error.is_none()
+    }
+
+    fn error(&self) -> Option<SharedString> {
+        self.error.clone().or_else(|| self.async_error.clone())
+    }
+}
+
+/// The values, validation errors and submission state of a form.
+///
+/// Create it with `cx.new_model(|_| FormState::new())`, register its fields, and
+/// render them with [`FormField`] and [`FormSubmitButton`]. Views rendering the form
+/// should observe the model, so that they re-render as validation completes.
+#[derive(Default)]
+pub struct FormState {
+    /// The fields in the order they were added, which is the order they are validated in.
+    fields: Vec<(&'static str, FieldState)>,
+    is_submitting: bool,
+    submit_error: Option<SharedString>,
+    pending_submit: Option<Task<()>>,
+}
+
+impl FormState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a field with its initial value.
+    pub fn add_field(&mut self, key: &'static str, initial_value: impl Into<String>) -> &mut Self {
+        let initial_value = initial_value.into();
+        let field = FieldState {
+            value: initial_value.clone(),
+            initial_value,
+            error: None,
+            async_error: None,
+            validators: Vec::new(),
+            async_validator: None,
+            pending_validation: None,
+        };
+        match self.field_mut(key) {
+            Some(existing) => *existing = field,
+            None => self.fields.push((key, field)),
+        }
+        self
+    }
+
+    fn field(&self, key: &str) -> Option<&FieldState> {
+        self.fields
+            .iter()
+            .find(|(field_key, _)| *field_key == key)
+            .map(|(_, field)| field)
+    }
+
+    fn field_mut(&mut self, key: &str) -> Option<&mut FieldState> {
+        self.fields
+            .iter_mut()
+            .find(|(field_key, _)| *field_key == key)
+            .map(|(_, field)| field)
+    }
+
+    /// Adds a validator that runs whenever the value of the field changes.
+    pub fn validate(
+        &mut self,
+        key: &'static str,
+        validator: impl Fn(&str) -> ValidationResult + 'static,
+    ) -> &mut Self {
+        if let Some(field) = self.field_mut(key) {
+            field.validators.push(Rc::new(validator));
+        }
+        self
+    }
+
+    /// Sets a validator that runs in the background once the synchronous validators
+    /// pass, e.g. to check that a name isn't taken. A newer value cancels the
+    /// validation of the previous one.
+    pub fn validate_async(
+        &mut self,
+        key: &'static str,
+        validator: impl Fn(String, AsyncAppContext) -> Task<ValidationResult> + 'static,
+    ) -> &mut Self {
+        if let Some(field) = self.field_mut(key) {
+            field.async_validator = Some(Rc::new(validator));
+        }
+        self
+    }
+
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.field(key).map(|field| field.value.as_str())
+    }
+
+    pub fn error(&self, key: &str) -> Option<SharedString> {
+        self.field(key).and_then(|field| field.error())
+    }
+
+    /// Returns the error of the last submission, if it failed.
+    pub fn submit_error(&self) -> Option<SharedString> {
+        self.submit_error.clone()
+    }
+
+    /// Whether the value of the field differs from its initial value.
+    pub fn is_field_dirty(&self, key: &str) -> bool {
+        self.field(key)
+            .map_or(false, |field| field.value != field.initial_value)
+    }
+
+    /// Whether any field differs from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|(_, field)| field.value != field.initial_value)
+    }
+
+    pub fn is_validating(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|(_, field)| field.pending_validation.is_some())
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        self.is_submitting
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|(_, field)| field.error().is_none())
+    }
+
+    /// Whether the form can be submitted right now.
+    pub fn can_submit(&self) -> bool {
+        self.is_valid() && !self.is_validating() && !self.is_submitting
+    }
+
+    /// Updates the value of a field and validates it.
+    pub fn set_value(
+        &mut self,
+        key: &'static str,
+        value: impl Into<String>,
+        cx: &mut ModelContext<Self>,
+    ) {
+        self.submit_error = None;
+        let Some(field) = self.field_mut(key) else {
+            return;
+        };
+        field.value = value.into();
+        field.async_error = None;
+        field.pending_validation = None;
+
+        if field.validate_sync() {
+            if let Some(validator) = field.async_validator.clone() {
+                let value = field.value.clone();
+                field.pending_validation = Some(cx.spawn(|this, mut cx| async move {
+                    let result = validator(value, cx.clone()).await;
+                    this.update(&mut cx, |this, cx| {
+                        if let Some(field) = this.field_mut(key) {
+                            field.pending_validation = None;
+                            field.async_error = result.err();
+                        }
+                        cx.notify();
+                    })
+                    .ok();
+                }));
+            }
+        }
+        cx.notify();
+    }
+
+    /// Validates every field and, if they are all valid, runs `submit` with the form.
+    ///
+    /// The form is marked as submitting until the returned task completes. On success,
+    /// the current values become the new initial values, so that the form is no
+    /// longer dirty.
+    pub fn submit(
+        &mut self,
+        submit: impl FnOnce(&Self, &mut ModelContext<Self>) -> Task<ValidationResult>,
+        cx: &mut ModelContext<Self>,
+    ) {
+        if self.is_submitting {
+            return;
+        }
+        let mut is_valid = true;
+        for (_, field) in &mut self.fields {
+            is_valid &= field.validate_sync();
+        }
+        if !is_valid || !self.can_submit() {
+            cx.notify();
+            return;
+        }
+
+        self.is_submitting = true;
+        self.submit_error = None;
+        let task = submit(self, cx);
+        self.pending_submit = Some(cx.spawn(|this, mut cx| async move {
+            let result = task.await;
+            this.update(&mut cx, |this, cx| {
+                this.is_submitting = false;
+                this.pending_submit = None;
+                match result {
+                    Ok(()) => {
+                        for (_, field) in &mut this.fields {
+                            field.initial_value = field.value.clone();
+                        }
+                    }
+                    Err(error) => this.submit_error = Some(error),
+                }
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+}
+
+/// Lays out the fields of a form, followed by the error of its last submission.
+#[derive(IntoElement)]
+pub struct Form {
+    form: Model<FormState>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Form {
+    pub fn new(form: &Model<FormState>) -> Self {
+        Self {
+            form: form.clone(),
+            children: SmallVec::new(),
+        }
+    }
+}
+
+impl ParentElement for Form {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Form {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let submit_error = self.form.read(cx).submit_error();
+
+        v_flex()
+            .w_full()
+            .gap(Spacing::Large.rems(cx))
+            .children(self.children)
+            .children(submit_error.map(|error| Label::new(error).color(Color::Error)))
+    }
+}
+
+/// A labeled form control, with the field's validation error or help text beneath it.
+#[derive(IntoElement)]
+pub struct FormField {
+    form: Model<FormState>,
+    key: &'static str,
+    label: SharedString,
+    help: Option<SharedString>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl FormField {
+    pub fn new(form: &Model<FormState>, key: &'static str, label: impl Into<SharedString>) -> Self {
+        Self {
+            form: form.clone(),
+            key,
+            label: label.into(),
+            help: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the text shown under the control while the field has no error.
+    pub fn help(mut self, help: impl Into<SharedString>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+impl ParentElement for FormField {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for FormField {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let error = self.form.read(cx).error(self.key);
+        let is_dirty = self.form.read(cx).is_field_dirty(self.key);
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(Label::new(self.label).size(LabelSize::Small))
+                    .when(is_dirty, |this| {
+                        this.child(
+                            Label::new("•")
+                                .size(LabelSize::Small)
+                                .color(Color::Modified),
+                        )
+                    }),
+            )
+            .children(self.children)
+            .map(|this| match (error, self.help) {
+                (Some(error), _) => {
+                    this.child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+                }
+                (None, Some(help)) => {
+                    this.child(Label::new(help).size(LabelSize::Small).color(Color::Muted))
+                }
+                (None, None) => this,
+            })
+    }
+}
+
+/// A button submitting a form, disabled while the form is invalid, validating or
+/// being submitted.
+#[derive(IntoElement)]
+pub struct FormSubmitButton {
+    form: Model<FormState>,
+    button: Button,
+    on_submit: Rc<dyn Fn(&FormState, &mut ModelContext<FormState>) -> Task<ValidationResult>>,
+}
+
+impl FormSubmitButton {
+    pub fn new(
+        form: &Model<FormState>,
+        id: impl Into<ElementId>,
+        label: impl Into<SharedString>,
+        on_submit: impl Fn(&FormState, &mut ModelContext<FormState>) -> Task<ValidationResult> + 'static,
+    ) -> Self {
+        Self {
+            form: form.clone(),
+            button: Button::new(id, label),
+            on_submit: Rc::new(on_submit),
+        }
+    }
+}
+
+impl RenderOnce for FormSubmitButton {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let form = self.form;
+        let on_submit = self.on_submit;
+
+        self.button
+            .disabled(!form.read(cx).can_submit())
+            .on_click(move |_, cx| {
+                let on_submit = on_submit.clone();
+                form.update(cx, |form, cx| {
+                    form.submit(|form, cx| on_submit(form, cx), cx)
+                });
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use gpui::{Context, TestAppContext};
+
+    use super::*;
+
+    fn required(value: &str) -> ValidationResult {
+        if value.trim().is_empty() {
+            Err("This field is required".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn form(cx: &mut TestAppContext) -> Model<FormState> {
+        cx.new_model(|_| {
+            let mut form = FormState::new();
+            form.add_field("name", "").validate("name", required);
+            form
+        })
+    }
+
+    #[gpui::test]
+    fn test_required_field_error(cx: &mut TestAppContext) {
+        let form = form(cx);
+        form.update(cx, |form, cx| {
+            assert_eq!(form.error("name"), None);
+            form.set_value("name", "  ", cx);
+            assert_eq!(form.error("name"), Some("This field is required".into()));
+            assert!(!form.is_valid());
+            assert!(!form.can_submit());
+        });
+    }
+
+    #[gpui::test]
+    fn test_editing_field_clears_error(cx: &mut TestAppContext) {
+        let form = form(cx);
+        form.update(cx, |form, cx| {
+            form.set_value("name", "", cx);
+            assert!(form.error("name").is_some());
+            form.set_value("name", "Zed", cx);
+            assert_eq!(form.error("name"), None);
+            assert!(form.is_field_dirty("name"));
+            assert!(form.can_submit());
+        });
+    }
+
+    #[gpui::test]
+    fn test_submit_blocked_by_errors(cx: &mut TestAppContext) {
+        let form = form(cx);
+        let submitted = Rc::new(Cell::new(false));
+        let submit = |submitted: &Rc<Cell<bool>>| {
+            let submitted = submitted.clone();
+            move |_: &FormState, _: &mut ModelContext<FormState>| {
+                submitted.set(true);
+                Task::ready(Ok(()))
+            }
+        };
+
+        // Submitting validates fields that were never edited.
+        form.update(cx, |form, cx| {
+            form.submit(submit(&submitted), cx);
+            assert!(!submitted.get());
+            assert!(!form.is_submitting());
+            assert!(form.error("name").is_some());
+        });
+
+        form.update(cx, |form, cx| {
+            form.set_value("name", "Zed", cx);
+            form.submit(submit(&submitted), cx);
+            assert!(submitted.get());
+            assert!(form.is_submitting());
+        });
+        cx.run_until_parked();
+        form.update(cx, |form, _| {
+            assert!(!form.is_submitting());
+            assert!(!form.is_dirty());
+        });
+    }
+}
@@ -0,0 +1,250 @@
+#![allow(missing_docs)]
+use gpui::{AnyElement, Bounds, GlobalElementId, LayoutId};
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+/// The width of a column of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridColumn {
+    /// A column of a fixed width.
+    Fixed(Pixels),
+    /// A share of the width left by the fixed columns, relative to the other
+    /// fractional columns, like `fr` units in CSS grids.
+    Fraction(f32),
+}
+
+enum GridColumns {
+    Template(Vec<GridColumn>),
+    /// Equal columns, as many as fit at their minimum width.
+    Responsive {
+        min_width: Pixels,
+        max_count: usize,
+    },
+}
+
+/// Returns how many columns of at least `min_width`, separated by `gap`, fit in
+/// `width`, between 1 and `max_count`.
+fn responsive_column_count(
+    width: Pixels,
+    min_width: Pixels,
+    gap: Pixels,
+    max_count: usize,
+) -> usize {
+    let count = ((width + gap) / (min_width + gap).max(px(1.))).floor() as usize;
+    count.clamp(1, max_count.max(1))
+}
+
+#[derive(Default)]
+struct GridState {
+    /// The width of the grid when it was last laid out.
+    width: Option<Pixels>,
+}
+
+/// Lays out elements in rows of columns, either following a template of fixed
+/// and fractional column widths, or with as many equal columns as fit in the
+/// available width, e.g. for settings forms, cards and thumbnails.
+///
+/// Elements fill the columns from left to right, wrapping to a new row after the
+/// last column. The gaps between the columns and rows are from the [`Spacing`]
+/// scale, so that they follow the user's UI density.
+///
+/// With [`Grid::responsive`], the number of columns follows the width the grid
+/// had when it was last laid out, so it takes a frame to adapt to a new width.
+pub struct Grid {
+    id: ElementId,
+    columns: GridColumns,
+    column_gap: Spacing,
+    row_gap: Spacing,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Grid {
+    /// Creates a grid of the given number of equal columns.
+    pub fn new(id: impl Into<ElementId>, columns: usize) -> Self {
+        Self {
+            id: id.into(),
+            columns: GridColumns::Template(vec![GridColumn::Fraction(1.); columns.max(1)]),
+            column_gap: Spacing::None,
+            row_gap: Spacing::None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Creates a grid with a column for each width of the template.
+    pub fn template(
+        id: impl Into<ElementId>,
+        columns: impl IntoIterator<Item = GridColumn>,
+    ) -> Self {
+        let mut columns = columns.into_iter().collect::<Vec<_>>();
+        if columns.is_empty() {
+            columns.push(GridColumn::Fraction(1.));
+        }
+        Self {
+            columns: GridColumns::Template(columns),
+            ..Self::new(id, 1)
+        }
+    }
+
+    /// Creates a grid of equal columns, as many as fit in its width without
+    /// becoming narrower than `min_column_width`, up to `max_columns`.
+    pub fn responsive(
+        id: impl Into<ElementId>,
+        min_column_width: Pixels,
+        max_columns: usize,
+    ) -> Self {
+        Self {
+            columns: GridColumns::Responsive {
+                min_width: min_column_width,
+                max_count: max_columns,
+            },
+            ..Self::new(id, 1)
+        }
+    }
+
+    /// Sets the gap between both columns and rows. Defaults to [`Spacing::None`].
+    pub fn gap(mut self, gap: Spacing) -> Self {
+        self.column_gap = gap;
+        self.row_gap = gap;
+        self
+    }
+
+    pub fn column_gap(mut self, gap: Spacing) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    pub fn row_gap(mut self, gap: Spacing) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    fn column_widths(&self, width: Option<Pixels>, cx: &WindowContext) -> Vec<GridColumn> {
+        match &self.columns {
+            GridColumns::Template(columns) => columns.clone(),
+            GridColumns::Responsive {
+                min_width,
+                max_count,
+            } => {
+                let count = width.map_or(1, |width| {
+                    responsive_column_count(width, *min_width, self.column_gap.px(cx), *max_count)
+                });
+                vec![GridColumn::Fraction(1.); count]
+            }
+        }
+    }
+
+    fn render_rows(&mut self, columns: Vec<GridColumn>, cx: &WindowContext) -> AnyElement {
+        let mut rows = Vec::new();
+        let mut children = self.children.drain(..).peekable();
+        while children.peek().is_some() {
+            let cells = columns.iter().map(|column| {
+                let cell = div().min_w_0().children(children.next());
+                match *column {
+                    GridColumn::Fixed(width) => cell.flex_none().w(width),
+                    GridColumn::Fraction(fraction) => {
+                        let mut cell = cell.flex_basis(px(0.));
+                        cell.style().flex_grow = Some(fraction);
+                        cell
+                    }
+                }
+            });
+            rows.push(
+                h_flex()
+                    .w_full()
+                    .items_start()
+                    .gap(self.column_gap.rems(cx))
+                    .children(cells.collect::<Vec<_>>()),
+            );
+        }
+        v_flex()
+            .w_full()
+            .gap(self.row_gap.rems(cx))
+            .children(rows)
+            .into_any_element()
+    }
+}
+
+impl ParentElement for Grid {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl Element for Grid {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state::<GridState, _>(id.unwrap(), |state, cx| {
+            let state = state.unwrap_or_default();
+            let columns = self.column_widths(state.width, cx);
+            let mut element = self.render_rows(columns, cx);
+            let layout_id = element.request_layout(cx);
+            ((layout_id, element), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        let responsive = matches!(self.columns, GridColumns::Responsive { .. });
+        cx.with_element_state::<GridState, _>(id.unwrap(), |state, cx| {
+            let mut state = state.unwrap_or_default();
+            let width = Some(bounds.size.width);
+            if responsive
+                && state.width != width
+                && self.column_widths(state.width, cx).len() != self.column_widths(width, cx).len()
+            {
+                cx.refresh();
+            }
+            state.width = width;
+            ((), state)
+        });
+        element.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        element.paint(cx);
+    }
+}
+
+impl IntoElement for Grid {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_responsive_column_count() {
+        assert_eq!(responsive_column_count(px(600.), px(200.), px(0.), 4), 3);
+        assert_eq!(responsive_column_count(px(600.), px(200.), px(8.), 4), 2);
+        assert_eq!(responsive_column_count(px(1000.), px(200.), px(0.), 4), 4);
+        assert_eq!(responsive_column_count(px(100.), px(200.), px(8.), 4), 1);
+    }
+}
@@ -0,0 +1,285 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{AnyElement, Task};
+
+use crate::{prelude::*, AnchoredPopover, PopoverPlacement, Skeleton};
+
+/// How long the pointer has to rest on a trigger before its card opens.
+const OPEN_DELAY: Duration = Duration::from_millis(500);
+/// How long a card stays open after the pointer leaves, so that it can travel
+/// from the trigger to the card.
+const CLOSE_DELAY: Duration = Duration::from_millis(150);
+
+type Loader<T> = Rc<dyn Fn(&mut WindowContext) -> Task<anyhow::Result<T>>>;
+
+enum HoverCardContent<T> {
+    Loading,
+    Loaded(Rc<T>),
+    Failed(SharedString),
+}
+
+struct HoverCardInner<T> {
+    is_open: bool,
+    trigger_hovered: bool,
+    card_hovered: bool,
+    /// Incremented on every hover change, so that a pending open or close can tell
+    /// whether it was superseded while waiting.
+    generation: usize,
+    content: Option<HoverCardContent<T>>,
+    loader: Option<Loader<T>>,
+    _load: Option<Task<()>>,
+}
+
+/// Whether a [`HoverCard`] is open and the content it loaded, held by the view
+/// rendering the card.
+///
+/// The content is loaded the first time the card opens, and kept for the next
+/// times. Call [`HoverCardState::invalidate`] to load it again.
+pub struct HoverCardState<T>(Rc<RefCell<HoverCardInner<T>>>);
+
+impl<T> Clone for HoverCardState<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> Default for HoverCardState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> HoverCardState<T> {
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(HoverCardInner {
+            is_open: false,
+            trigger_hovered: false,
+            card_hovered: false,
+            generation: 0,
+            content: None,
+            loader: None,
+            _load: None,
+        })))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.0.borrow().is_open
+    }
+
+    /// Forgets the loaded content, so that it's loaded again the next time the card opens.
+    pub fn invalidate(&self) {
+        let mut state = self.0.borrow_mut();
+        state.content = None;
+        state._load = None;
+    }
+
+    pub fn close(&self, cx: &mut WindowContext) {
+        let mut state = self.0.borrow_mut();
+        state.generation += 1;
+        // The card may be removed without reporting that the pointer left it.
+        state.card_hovered = false;
+        if state.is_open {
+            state.is_open = false;
+            cx.refresh();
+        }
+    }
+
+    fn set_hovered(&self, trigger: bool, hovered: bool, cx: &mut WindowContext) {
+        let (generation, delay) = {
+            let mut state = self.0.borrow_mut();
+            if trigger {
+                state.trigger_hovered = hovered;
+            } else {
+                state.card_hovered = hovered;
+            }
+            state.generation += 1;
+            let is_hovered = state.trigger_hovered || state.card_hovered;
+            let delay = match (state.is_open, is_hovered) {
+                (false, true) => OPEN_DELAY,
+                (true, false) => CLOSE_DELAY,
+                _ => return,
+            };
+            (state.generation, delay)
+        };
+
+        let this = self.clone();
+        cx.spawn(|mut cx| async move {
+            cx.background_executor().timer(delay).await;
+            if this.0.borrow().generation == generation {
+                cx.update(|cx| this.toggle(cx)).ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Opens the card if it's hovered, and closes it otherwise.
+    fn toggle(&self, cx: &mut WindowContext) {
+        let is_hovered = {
+            let state = self.0.borrow();
+            state.trigger_hovered || state.card_hovered
+        };
+        if !is_hovered {
+            self.close(cx);
+            return;
+        }
+        // Don't open while the user types or drags, as tooltips don't either.
+        if cx.tooltips_suppressed() {
+            return;
+        }
+        self.0.borrow_mut().is_open = true;
+        self.load(cx);
+        cx.refresh();
+    }
+
+    fn load(&self, cx: &mut WindowContext) {
+        let loader = {
+            let state = self.0.borrow();
+            if state.content.is_some() {
+                return;
+            }
+            let Some(loader) = state.loader.clone() else {
+                return;
+            };
+            loader
+        };
+        let task = loader(cx);
+        let this = self.clone();
+        let load = cx.spawn(|mut cx| async move {
+            let content = match task.await {
+                Ok(content) => HoverCardContent::Loaded(Rc::new(content)),
+                Err(error) => HoverCardContent::Failed(error.to_string().into()),
+            };
+            this.0.borrow_mut().content = Some(content);
+            cx.update(|cx| cx.refresh()).ok();
+        });
+        let mut state = self.0.borrow_mut();
+        state.content = Some(HoverCardContent::Loading);
+        state._load = Some(load);
+    }
+}
+
+/// A card opening next to a trigger after it's hovered for a moment, showing
+/// content that is loaded asynchronously, like a user profile or a link preview.
+///
+/// A skeleton is shown while the content loads, and the error if it fails. The
+/// card closes shortly after the pointer leaves both the trigger and the card.
+#[derive(IntoElement)]
+pub struct HoverCard<T: 'static> {
+    id: ElementId,
+    state: HoverCardState<T>,
+    trigger: Option<AnyElement>,
+    loader: Option<Loader<T>>,
+    render_content: Option<Rc<dyn Fn(&T, &mut WindowContext) -> AnyElement>>,
+    placement: PopoverPlacement,
+}
+
+impl<T: 'static> HoverCard<T> {
+    pub fn new(id: impl Into<ElementId>, state: &HoverCardState<T>) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            trigger: None,
+            loader: None,
+            render_content: None,
+            placement: PopoverPlacement::bottom_start(),
+        }
+    }
+
+    /// Sets the element that opens the card when hovered. It is rendered in place.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// Sets the callback loading the content, which is called when the card first opens.
+    pub fn load(
+        mut self,
+        loader: impl Fn(&mut WindowContext) -> Task<anyhow::Result<T>> + 'static,
+    ) -> Self {
+        self.loader = Some(Rc::new(loader));
+        self
+    }
+
+    /// Sets how the loaded content is rendered in the card.
+    pub fn render_content(
+        mut self,
+        render: impl Fn(&T, &mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        self.render_content = Some(Rc::new(render));
+        self
+    }
+
+    /// Sets where the card is placed relative to the trigger. Defaults to below it.
+    pub fn placement(mut self, placement: PopoverPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    fn render_card(&self, cx: &mut WindowContext) -> AnyElement {
+        let content = match self.state.0.borrow().content.as_ref() {
+            None | Some(HoverCardContent::Loading) => None,
+            Some(HoverCardContent::Loaded(content)) => Some(Ok(content.clone())),
+            Some(HoverCardContent::Failed(error)) => Some(Err(error.clone())),
+        };
+        let body = match content {
+            None => v_flex()
+                .gap_2()
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .child(Skeleton::circle("hover-card-avatar"))
+                        .child(Skeleton::line("hover-card-title").width(relative(0.6))),
+                )
+                .child(Skeleton::line("hover-card-line-1"))
+                .child(Skeleton::line("hover-card-line-2").width(relative(0.8)))
+                .into_any_element(),
+            Some(Ok(content)) => match self.render_content.as_ref() {
+                Some(render_content) => render_content(&content, cx),
+                None => div().into_any_element(),
+            },
+            Some(Err(error)) => h_flex()
+                .gap_1()
+                .child(
+                    Icon::new(IconName::Warning)
+                        .size(IconSize::Small)
+                        .color(Color::Error),
+                )
+                .child(Label::new(error).size(LabelSize::Small).color(Color::Muted))
+                .into_any_element(),
+        };
+
+        let state = self.state.clone();
+        v_flex()
+            .id("hover-card")
+            .w(rems(20.))
+            .p_2()
+            .elevation_2(cx)
+            .on_hover(move |hovered, cx| state.set_hovered(false, *hovered, cx))
+            .child(body)
+            .into_any_element()
+    }
+}
+
+impl<T: 'static> RenderOnce for HoverCard<T> {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        // The loader is kept by the state, for the timers opening the card.
+        self.state.0.borrow_mut().loader = self.loader.clone();
+
+        let card = self.state.is_open().then(|| self.render_card(cx));
+        let state = self.state.clone();
+        let trigger = div()
+            .id(self.id.clone())
+            .on_hover(move |hovered, cx| state.set_hovered(true, *hovered, cx))
+            .children(self.trigger);
+
+        let state = self.state.clone();
+        AnchoredPopover::new()
+            .trigger(trigger)
+            .content(card)
+            .placement(self.placement)
+            .on_dismiss(move |cx| state.close(cx))
+    }
+}
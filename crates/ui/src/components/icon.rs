@@ -1,10 +1,17 @@
 #![allow(missing_docs)]
-use gpui::{svg, AnimationElement, Hsla, IntoElement, Rems, Transformation};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use gpui::{svg, AnimationElement, AppContext, Global, Hsla, IntoElement, Rems, Transformation};
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, EnumString, IntoStaticStr};
 use ui_macros::DerivePathStr;
 
-use crate::{prelude::*, Indicator};
+use crate::{
+    prelude::*,
+    utils::{ensure_contrast, MIN_NON_TEXT_CONTRAST},
+    Indicator,
+};
 
 #[derive(IntoElement)]
 pub enum AnyIcon {
@@ -291,30 +298,81 @@ pub enum IconName {
     ZedXCopilot,
 }
 
+/// The names of the icons registered at runtime with [`register_icon`].
+#[derive(Default)]
+struct IconRegistry(HashSet<SharedString>);
+
+impl Global for IconRegistry {}
+
+fn registered_icon_path(name: &str) -> SharedString {
+    format!("registered-icons/{name}.svg").into()
+}
+
+/// Registers an SVG icon under the given name, e.g. for an extension, so that it
+/// can be rendered with [`Icon::registered`].
+///
+/// The icon is rendered like the built-in ones: as a mask tinted with the icon's
+/// color, and cached per size once rendered. Returns an error if `svg` isn't a
+/// valid SVG.
+pub fn register_icon(
+    name: impl Into<SharedString>,
+    svg: impl Into<Arc<[u8]>>,
+    cx: &mut AppContext,
+) -> gpui::Result<()> {
+    let name = name.into();
+    cx.register_svg(registered_icon_path(&name), svg)?;
+    cx.default_global::<IconRegistry>().0.insert(name);
+    Ok(())
+}
+
+/// Returns whether an icon was registered under the given name.
+pub fn is_icon_registered(name: &str, cx: &AppContext) -> bool {
+    cx.try_global::<IconRegistry>()
+        .map_or(false, |registry| registry.0.contains(name))
+}
+
 #[derive(IntoElement)]
 pub struct Icon {
     path: SharedString,
+    registered_name: Option<SharedString>,
     color: Color,
     size: Rems,
     transformation: Transformation,
+    parent_background: Option<Hsla>,
 }
 
 impl Icon {
     pub fn new(icon: IconName) -> Self {
         Self {
             path: icon.path().into(),
+            registered_name: None,
             color: Color::default(),
             size: IconSize::default().rems(),
             transformation: Transformation::default(),
+            parent_background: None,
         }
     }
 
     pub fn from_path(path: impl Into<SharedString>) -> Self {
         Self {
             path: path.into(),
+            registered_name: None,
             color: Color::default(),
             size: IconSize::default().rems(),
             transformation: Transformation::default(),
+            parent_background: None,
+        }
+    }
+
+    /// Creates an icon registered at runtime with [`register_icon`].
+    ///
+    /// Renders a placeholder glyph while no icon is registered under the name, e.g.
+    /// when the extension providing it was uninstalled.
+    pub fn registered(name: impl Into<SharedString>) -> Self {
+        let name = name.into();
+        Self {
+            registered_name: Some(name),
+            ..Self::new(IconName::Dash)
         }
     }
 
@@ -340,16 +398,36 @@ impl Icon {
         self.transformation = transformation;
         self
     }
+
+    /// Sets the background the icon is rendered on, such as the fill of an accent
+    /// button or a badge.
+    ///
+    /// When the icon's color doesn't contrast enough with it, a light or dark tint
+    /// is used instead, so that the icon stays legible.
+    pub fn parent_background(mut self, background: Option<Hsla>) -> Self {
+        self.parent_background = background;
+        self
+    }
 }
 
 impl RenderOnce for Icon {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.color.color(cx);
+        let color = match self.parent_background {
+            Some(background) => ensure_contrast(color, background, MIN_NON_TEXT_CONTRAST),
+            None => color,
+        };
+        let path = match self.registered_name {
+            Some(name) if is_icon_registered(&name, cx) => registered_icon_path(&name),
+            _ => self.path,
+        };
+
         svg()
             .with_transformation(self.transformation)
             .size(self.size)
             .flex_none()
-            .path(self.path)
-            .text_color(self.color.color(cx))
+            .path(path)
+            .text_color(color)
     }
 }
 
@@ -1,10 +1,15 @@
 #![allow(missing_docs)]
-use gpui::{svg, IntoElement, Rems, RenderOnce, Size, Styled, WindowContext};
+use std::collections::VecDeque;
+
+use gpui::{
+    img, svg, AnyElement, AppContext, Global, ImageLoadState, ImageSource, ObjectFit, RenderImage,
+    Size, StyleRefinement,
+};
 use serde::{Deserialize, Serialize};
 use strum::{EnumIter, EnumString, IntoStaticStr};
 use ui_macros::{path_str, DerivePathStr};
 
-use crate::Color;
+use crate::prelude::*;
 
 #[derive(
     Debug,
@@ -84,6 +89,213 @@ impl RenderOnce for Vector {
     }
 }
 
+/// How an [`Image`] fits the content in its bounds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// The whole image is shown, scaled down or up to fit, keeping its aspect ratio.
+    #[default]
+    Contain,
+    /// The bounds are covered, keeping the aspect ratio and cropping the overflow.
+    Cover,
+    /// The image is stretched to the bounds.
+    Fill,
+}
+
+impl From<ImageFit> for ObjectFit {
+    fn from(fit: ImageFit) -> Self {
+        match fit {
+            ImageFit::Contain => ObjectFit::Contain,
+            ImageFit::Cover => ObjectFit::Cover,
+            ImageFit::Fill => ObjectFit::Fill,
+        }
+    }
+}
+
+/// How many bytes of decoded images are kept cached by default.
+const DEFAULT_CACHE_BUDGET: usize = 128 * 1024 * 1024;
+
+/// A least recently used list of cache entries and their sizes, evicting the
+/// oldest ones once the sizes add up to more than a budget.
+struct ImageLru<K> {
+    budget: usize,
+    total: usize,
+    entries: VecDeque<(K, usize)>,
+}
+
+impl<K: PartialEq> ImageLru<K> {
+    fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            total: 0,
+            entries: VecDeque::new(),
+        }
+    }
+
+    /// Marks the entry as the most recently used, and returns the entries evicted
+    /// to stay within the budget. The entry itself is never evicted.
+    fn touch(&mut self, key: K, size: usize) -> Vec<K> {
+        if let Some(ix) = self.entries.iter().position(|(entry, _)| *entry == key) {
+            if ix + 1 == self.entries.len() {
+                return Vec::new();
+            }
+            if let Some((_, size)) = self.entries.remove(ix) {
+                self.total -= size;
+            }
+        }
+        self.entries.push_back((key, size));
+        self.total += size;
+        self.evict()
+    }
+
+    fn set_budget(&mut self, budget: usize) -> Vec<K> {
+        self.budget = budget;
+        self.evict()
+    }
+
+    fn evict(&mut self) -> Vec<K> {
+        let mut evicted = Vec::new();
+        while self.total > self.budget && self.entries.len() > 1 {
+            if let Some((key, size)) = self.entries.pop_front() {
+                self.total -= size;
+                evicted.push(key);
+            }
+        }
+        evicted
+    }
+}
+
+struct ImageCache(ImageLru<ImageSource>);
+
+impl Global for ImageCache {}
+
+impl ImageCache {
+    fn update(cx: &mut AppContext, f: impl FnOnce(&mut ImageLru<ImageSource>) -> Vec<ImageSource>) {
+        if !cx.has_global::<Self>() {
+            cx.set_global(Self(ImageLru::new(DEFAULT_CACHE_BUDGET)));
+        }
+        let evicted = f(&mut cx.global_mut::<Self>().0);
+        for source in evicted {
+            source.remove_from_cache(cx);
+        }
+    }
+}
+
+fn decoded_size(image: &RenderImage) -> usize {
+    (0..image.frame_count())
+        .map(|frame| {
+            let size = image.size(frame);
+            size.width.0.max(0) as usize * size.height.0.max(0) as usize * 4
+        })
+        .sum()
+}
+
+/// An image loaded from a path, a URL or bytes on a background task, as shown
+/// for avatars and in rendered markdown.
+///
+/// A placeholder is shown while the image loads, and a fallback if it fails.
+/// Decoded images are shared by all the [`Image`]s showing the same source, and
+/// the least recently shown ones are dropped once they add up to more than the
+/// budget set with [`Image::set_cache_budget`].
+#[derive(IntoElement)]
+pub struct Image {
+    source: ImageSource,
+    base: Div,
+    fit: ImageFit,
+    grayscale: bool,
+    placeholder: Option<AnyElement>,
+    fallback: Option<AnyElement>,
+}
+
+impl Image {
+    pub fn new(source: impl Into<ImageSource>) -> Self {
+        Self {
+            source: source.into(),
+            base: div(),
+            fit: ImageFit::default(),
+            grayscale: false,
+            placeholder: None,
+            fallback: None,
+        }
+    }
+
+    /// Sets how many bytes of decoded images are kept cached. Defaults to 128 MB.
+    pub fn set_cache_budget(budget: usize, cx: &mut AppContext) {
+        ImageCache::update(cx, |lru| lru.set_budget(budget));
+    }
+
+    /// Sets how the image fits its bounds. Defaults to [`ImageFit::Contain`].
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    pub fn grayscale(mut self, grayscale: bool) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+
+    /// Sets the element shown while the image loads. Defaults to an empty
+    /// element with the ghost background.
+    pub fn placeholder(mut self, placeholder: impl IntoElement) -> Self {
+        self.placeholder = Some(placeholder.into_any_element());
+        self
+    }
+
+    /// Sets the element shown when the image fails to load. Defaults to a muted
+    /// warning icon.
+    pub fn fallback(mut self, fallback: impl IntoElement) -> Self {
+        self.fallback = Some(fallback.into_any_element());
+        self
+    }
+}
+
+impl Styled for Image {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Image {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        // The content is clipped to the corners set on the image.
+        let corner_radii = self.base.style().corner_radii.clone();
+        let content = match self.source.use_load_state(cx) {
+            ImageLoadState::Loading => self.placeholder.unwrap_or_else(|| {
+                div()
+                    .size_full()
+                    .bg(cx.theme().colors().ghost_element_background)
+                    .into_any_element()
+            }),
+            ImageLoadState::Loaded(data) => {
+                if !matches!(self.source, ImageSource::Render(_)) {
+                    let size = decoded_size(&data);
+                    let source = self.source.clone();
+                    ImageCache::update(cx, |lru| lru.touch(source, size));
+                }
+                let mut image = img(ImageSource::Render(data))
+                    .size_full()
+                    .object_fit(self.fit.into())
+                    .grayscale(self.grayscale);
+                image.style().corner_radii = corner_radii;
+                image.into_any_element()
+            }
+            ImageLoadState::Failed => self.fallback.unwrap_or_else(|| {
+                h_flex()
+                    .size_full()
+                    .justify_center()
+                    .child(
+                        Icon::new(IconName::Warning)
+                            .size(IconSize::Small)
+                            .color(Color::Muted),
+                    )
+                    .into_any_element()
+            }),
+        };
+
+        self.base.flex_none().overflow_hidden().child(content)
+    }
+}
+
 #[cfg(feature = "stories")]
 pub mod story {
     use gpui::Render;
@@ -96,6 +308,8 @@ pub mod story {
 
     pub struct VectorStory;
 
+    story::register_story!("vector", VectorStory);
+
     impl Render for VectorStory {
         fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
             Story::container().child(StorySection::new().children(VectorName::iter().map(
@@ -113,4 +327,20 @@ mod tests {
     fn vector_path() {
         assert_eq!(VectorName::ZedLogo.path(), "images/zed_logo.svg");
     }
+
+    #[test]
+    fn test_image_lru_eviction() {
+        let mut lru = ImageLru::new(10);
+        assert_eq!(lru.touch("a", 4), Vec::<&str>::new());
+        assert_eq!(lru.touch("b", 4), Vec::<&str>::new());
+        // Using "a" again makes "b" the least recently used.
+        assert_eq!(lru.touch("a", 4), Vec::<&str>::new());
+        assert_eq!(lru.touch("c", 4), vec!["b"]);
+        assert_eq!(lru.total, 8);
+
+        // An entry larger than the budget is kept, alone.
+        assert_eq!(lru.touch("d", 20), vec!["a", "c"]);
+        assert_eq!(lru.set_budget(30), Vec::<&str>::new());
+        assert_eq!(lru.touch("e", 20), vec!["d"]);
+    }
 }
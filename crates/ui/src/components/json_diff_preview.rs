@@ -0,0 +1,204 @@
+#![allow(missing_docs)]
+use std::ops::Range;
+
+use gpui::HighlightStyle;
+use serde_json::Value;
+
+use crate::{prelude::*, DiffLayout, DiffView, DiffViewState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonToken {
+    Key,
+    String,
+    Number,
+    Constant,
+    Punctuation,
+}
+
+/// Splits a line of pretty-printed JSON into the byte ranges of its tokens.
+/// Strings followed by a colon are keys.
+fn json_line_tokens(line: &str) -> Vec<(Range<usize>, JsonToken)> {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let mut ix = 0;
+    while ix < bytes.len() {
+        let start = ix;
+        let token = match bytes[ix] {
+            b'"' => {
+                ix += 1;
+                while ix < bytes.len() && bytes[ix] != b'"' {
+                    ix += if bytes[ix] == b'\\' { 2 } else { 1 };
+                }
+                ix = (ix + 1).min(bytes.len());
+                let is_key = line[ix..].trim_start().starts_with(':');
+                if is_key {
+                    JsonToken::Key
+                } else {
+                    JsonToken::String
+                }
+            }
+            b'-' | b'0'..=b'9' => {
+                while ix < bytes.len()
+                    && matches!(bytes[ix], b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+                {
+                    ix += 1;
+                }
+                JsonToken::Number
+            }
+            b'a'..=b'z' => {
+                while ix < bytes.len() && bytes[ix].is_ascii_lowercase() {
+                    ix += 1;
+                }
+                JsonToken::Constant
+            }
+            b'{' | b'}' | b'[' | b']' | b',' | b':' => {
+                ix += 1;
+                JsonToken::Punctuation
+            }
+            _ => {
+                ix += 1;
+                continue;
+            }
+        };
+        tokens.push((start..ix, token));
+    }
+    tokens
+}
+
+/// The syntax styles of the theme for each kind of [`JsonToken`].
+#[derive(Clone, Copy)]
+struct JsonStyles {
+    key: HighlightStyle,
+    string: HighlightStyle,
+    number: HighlightStyle,
+    constant: HighlightStyle,
+    punctuation: HighlightStyle,
+}
+
+impl JsonStyles {
+    fn new(cx: &WindowContext) -> Self {
+        let syntax = cx.theme().syntax();
+        Self {
+            key: syntax.get("property"),
+            string: syntax.get("string"),
+            number: syntax.get("number"),
+            constant: syntax.get("constant"),
+            punctuation: syntax.get("punctuation.bracket"),
+        }
+    }
+
+    fn get(&self, token: JsonToken) -> HighlightStyle {
+        match token {
+            JsonToken::Key => self.key,
+            JsonToken::String => self.string,
+            JsonToken::Number => self.number,
+            JsonToken::Constant => self.constant,
+            JsonToken::Punctuation => self.punctuation,
+        }
+    }
+}
+
+/// Previews how a JSON document, like a settings file, changes, as a diff of the
+/// two values pretty-printed, with JSON syntax highlighting.
+///
+/// Used to show what a settings migration or an "apply suggested settings" action
+/// will modify before it's confirmed.
+#[derive(IntoElement)]
+pub struct JsonDiffPreview {
+    id: ElementId,
+    old_text: String,
+    new_text: String,
+    layout: DiffLayout,
+    state: Option<DiffViewState>,
+}
+
+impl JsonDiffPreview {
+    pub fn new(id: impl Into<ElementId>, old: &Value, new: &Value) -> Self {
+        Self {
+            id: id.into(),
+            old_text: pretty_print(old),
+            new_text: pretty_print(new),
+            layout: DiffLayout::default(),
+            state: None,
+        }
+    }
+
+    pub fn layout(mut self, layout: DiffLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Collapses the unchanged regions, which expand when clicked and stay expanded
+    /// in the given state.
+    pub fn collapse_unchanged(mut self, state: &DiffViewState) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+}
+
+fn pretty_print(value: &Value) -> String {
+    let mut text = serde_json::to_string_pretty(value).unwrap_or_default();
+    text.push('\n');
+    text
+}
+
+impl RenderOnce for JsonDiffPreview {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        if self.old_text == self.new_text {
+            return Label::new("No changes")
+                .size(LabelSize::Small)
+                .color(Color::Muted)
+                .into_any_element();
+        }
+
+        let styles = JsonStyles::new(cx);
+        DiffView::new(self.id, self.old_text, self.new_text)
+            .layout(self.layout)
+            .when_some(self.state, |this, state| this.collapse_unchanged(&state))
+            .syntax_highlights(move |line| {
+                json_line_tokens(line)
+                    .into_iter()
+                    .map(|(range, token)| (range, styles.get(token)))
+                    .collect()
+            })
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_line_tokens() {
+        use JsonToken::*;
+
+        let line = r#"  "tab_size": 4, "name": "a \"b\"", "x": [true, null, -1.5e3]"#;
+        let tokens = json_line_tokens(line)
+            .into_iter()
+            .map(|(range, token)| (&line[range], token))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                ("\"tab_size\"", Key),
+                (":", Punctuation),
+                ("4", Number),
+                (",", Punctuation),
+                ("\"name\"", Key),
+                (":", Punctuation),
+                (r#""a \"b\"""#, String),
+                (",", Punctuation),
+                ("\"x\"", Key),
+                (":", Punctuation),
+                ("[", Punctuation),
+                ("true", Constant),
+                (",", Punctuation),
+                ("null", Constant),
+                (",", Punctuation),
+                ("-1.5e3", Number),
+                ("]", Punctuation),
+            ]
+        );
+    }
+}
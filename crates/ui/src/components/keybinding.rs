@@ -64,6 +64,9 @@ impl KeyBinding {
     }
 
     /// Sets the [`PlatformStyle`] for this [`KeyBinding`].
+    ///
+    /// Defaults to the platform Zed is running on. Documentation surfaces, like
+    /// the keymap docs or a "shortcuts on other platforms" table, can force a style.
     pub fn platform_style(mut self, platform_style: PlatformStyle) -> Self {
         self.platform_style = platform_style;
         self
@@ -94,45 +97,21 @@ impl RenderOnce for KeyBinding {
                     .py_0p5()
                     .rounded_sm()
                     .text_color(cx.theme().colors().text_muted)
-                    .when(keystroke.modifiers.function, |el| {
-                        match self.platform_style {
-                            PlatformStyle::Mac => el.child(Key::new("fn")),
-                            PlatformStyle::Linux | PlatformStyle::Windows => {
-                                el.child(Key::new("Fn")).child(Key::new("+"))
-                            }
-                        }
-                    })
-                    .when(keystroke.modifiers.control, |el| {
-                        match self.platform_style {
-                            PlatformStyle::Mac => el.child(KeyIcon::new(IconName::Control)),
-                            PlatformStyle::Linux | PlatformStyle::Windows => {
-                                el.child(Key::new("Ctrl")).child(Key::new("+"))
-                            }
-                        }
-                    })
-                    .when(keystroke.modifiers.alt, |el| match self.platform_style {
-                        PlatformStyle::Mac => el.child(KeyIcon::new(IconName::Option)),
-                        PlatformStyle::Linux | PlatformStyle::Windows => {
-                            el.child(Key::new("Alt")).child(Key::new("+"))
-                        }
-                    })
-                    .when(keystroke.modifiers.platform, |el| {
-                        match self.platform_style {
-                            PlatformStyle::Mac => el.child(KeyIcon::new(IconName::Command)),
-                            PlatformStyle::Linux => {
-                                el.child(Key::new("Super")).child(Key::new("+"))
-                            }
-                            PlatformStyle::Windows => {
-                                el.child(Key::new("Win")).child(Key::new("+"))
-                            }
-                        }
-                    })
-                    .when(keystroke.modifiers.shift, |el| match self.platform_style {
-                        PlatformStyle::Mac => el.child(KeyIcon::new(IconName::Shift)),
-                        PlatformStyle::Linux | PlatformStyle::Windows => {
-                            el.child(Key::new("Shift")).child(Key::new("+"))
-                        }
-                    })
+                    .children(
+                        modifiers_in_order(keystroke, self.platform_style).map(
+                            |modifier| match self.platform_style {
+                                PlatformStyle::Mac => match modifier.mac_icon() {
+                                    Some(icon) => KeyIcon::new(icon).into_any_element(),
+                                    None => Key::new(modifier.name(self.platform_style))
+                                        .into_any_element(),
+                                },
+                                PlatformStyle::Linux | PlatformStyle::Windows => h_flex()
+                                    .child(Key::new(modifier.name(self.platform_style)))
+                                    .child(Key::new("+"))
+                                    .into_any_element(),
+                            },
+                        ),
+                    )
                     .map(|el| match key_icon {
                         Some(icon) => el.child(KeyIcon::new(icon)),
                         None => el.child(Key::new(keystroke.key.to_uppercase())),
@@ -225,59 +204,132 @@ pub fn text_for_key_binding(
         .join(" ")
 }
 
-/// Returns a textual representation of the given [`Keystroke`].
-pub fn text_for_keystroke(keystroke: &Keystroke, platform_style: PlatformStyle) -> String {
-    let mut text = String::new();
-
-    let delimiter = match platform_style {
-        PlatformStyle::Mac => '-',
-        PlatformStyle::Linux | PlatformStyle::Windows => '+',
-    };
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum KeyModifier {
+    Function,
+    Control,
+    Alt,
+    Shift,
+    Platform,
+}
 
-    if keystroke.modifiers.function {
-        match platform_style {
-            PlatformStyle::Mac => text.push_str("fn"),
-            PlatformStyle::Linux | PlatformStyle::Windows => text.push_str("Fn"),
+impl KeyModifier {
+    fn name(self, platform_style: PlatformStyle) -> &'static str {
+        match (self, platform_style) {
+            (KeyModifier::Function, PlatformStyle::Mac) => "fn",
+            (KeyModifier::Function, _) => "Fn",
+            (KeyModifier::Control, PlatformStyle::Mac) => "Control",
+            (KeyModifier::Control, _) => "Ctrl",
+            (KeyModifier::Alt, PlatformStyle::Mac) => "Option",
+            (KeyModifier::Alt, _) => "Alt",
+            (KeyModifier::Shift, _) => "Shift",
+            (KeyModifier::Platform, PlatformStyle::Mac) => "Command",
+            (KeyModifier::Platform, PlatformStyle::Linux) => "Super",
+            (KeyModifier::Platform, PlatformStyle::Windows) => "Win",
         }
-
-        text.push(delimiter);
     }
 
-    if keystroke.modifiers.control {
-        match platform_style {
-            PlatformStyle::Mac => text.push_str("Control"),
-            PlatformStyle::Linux | PlatformStyle::Windows => text.push_str("Ctrl"),
+    fn mac_glyph(self) -> &'static str {
+        match self {
+            KeyModifier::Function => "fn",
+            KeyModifier::Control => "⌃",
+            KeyModifier::Alt => "⌥",
+            KeyModifier::Shift => "⇧",
+            KeyModifier::Platform => "⌘",
         }
-
-        text.push(delimiter);
     }
 
-    if keystroke.modifiers.alt {
-        match platform_style {
-            PlatformStyle::Mac => text.push_str("Option"),
-            PlatformStyle::Linux | PlatformStyle::Windows => text.push_str("Alt"),
+    fn mac_icon(self) -> Option<IconName> {
+        match self {
+            KeyModifier::Function => None,
+            KeyModifier::Control => Some(IconName::Control),
+            KeyModifier::Alt => Some(IconName::Option),
+            KeyModifier::Shift => Some(IconName::Shift),
+            KeyModifier::Platform => Some(IconName::Command),
         }
-
-        text.push(delimiter);
     }
+}
 
-    if keystroke.modifiers.platform {
-        match platform_style {
-            PlatformStyle::Mac => text.push_str("Command"),
-            PlatformStyle::Linux => text.push_str("Super"),
-            PlatformStyle::Windows => text.push_str("Win"),
-        }
+/// Returns the modifiers of the keystroke in the order the platform lists them:
+/// ⌃⌥⇧⌘ on macOS, and Win/Super, Ctrl, Alt, Shift on Linux and Windows.
+fn modifiers_in_order(
+    keystroke: &Keystroke,
+    platform_style: PlatformStyle,
+) -> impl Iterator<Item = KeyModifier> {
+    let modifiers = keystroke.modifiers;
+    let order = match platform_style {
+        PlatformStyle::Mac => [
+            KeyModifier::Function,
+            KeyModifier::Control,
+            KeyModifier::Alt,
+            KeyModifier::Shift,
+            KeyModifier::Platform,
+        ],
+        PlatformStyle::Linux | PlatformStyle::Windows => [
+            KeyModifier::Function,
+            KeyModifier::Platform,
+            KeyModifier::Control,
+            KeyModifier::Alt,
+            KeyModifier::Shift,
+        ],
+    };
+    order.into_iter().filter(move |modifier| match modifier {
+        KeyModifier::Function => modifiers.function,
+        KeyModifier::Control => modifiers.control,
+        KeyModifier::Alt => modifiers.alt,
+        KeyModifier::Shift => modifiers.shift,
+        KeyModifier::Platform => modifiers.platform,
+    })
+}
 
-        text.push(delimiter);
+/// Returns the given [`Keystroke`] as it is conventionally written on the platform:
+/// with glyphs like `⌃⇧P` on macOS, and like `Ctrl+Shift+P` elsewhere.
+///
+/// Pass a fixed [`PlatformStyle`] to document a key binding for another platform
+/// than the one Zed is running on.
+pub fn glyphs_for_keystroke(keystroke: &Keystroke, platform_style: PlatformStyle) -> String {
+    if platform_style != PlatformStyle::Mac {
+        return text_for_keystroke(keystroke, platform_style);
     }
 
-    if keystroke.modifiers.shift {
-        match platform_style {
-            PlatformStyle::Mac | PlatformStyle::Linux | PlatformStyle::Windows => {
-                text.push_str("Shift")
-            }
+    let mut text = modifiers_in_order(keystroke, platform_style)
+        .map(KeyModifier::mac_glyph)
+        .collect::<String>();
+    let key = match keystroke.key.as_str() {
+        "left" => "←",
+        "right" => "→",
+        "up" => "↑",
+        "down" => "↓",
+        "backspace" => "⌫",
+        "delete" => "⌦",
+        "enter" | "return" => "⏎",
+        "tab" => "⇥",
+        "escape" => "⎋",
+        "pageup" => "⇞",
+        "pagedown" => "⇟",
+        "home" => "↖",
+        "end" => "↘",
+        "space" => "Space",
+        key => {
+            text.push_str(&key.to_uppercase());
+            return text;
         }
+    };
+    text.push_str(key);
+    text
+}
+
+/// Returns a textual representation of the given [`Keystroke`].
+pub fn text_for_keystroke(keystroke: &Keystroke, platform_style: PlatformStyle) -> String {
+    let mut text = String::new();
 
+    let delimiter = match platform_style {
+        PlatformStyle::Mac => '-',
+        PlatformStyle::Linux | PlatformStyle::Windows => '+',
+    };
+
+    for modifier in modifiers_in_order(keystroke, platform_style) {
+        text.push_str(modifier.name(platform_style));
         text.push(delimiter);
     }
 
@@ -363,4 +415,32 @@ mod tests {
             "Shift+PageUp".to_string()
         );
     }
+
+    #[test]
+    fn test_modifier_order() {
+        let keystroke = Keystroke::parse("cmd-shift-ctrl-alt-p").unwrap();
+        assert_eq!(
+            text_for_keystroke(&keystroke, PlatformStyle::Mac),
+            "Control-Option-Shift-Command-P"
+        );
+        assert_eq!(
+            text_for_keystroke(&keystroke, PlatformStyle::Windows),
+            "Win+Ctrl+Alt+Shift+P"
+        );
+        assert_eq!(
+            glyphs_for_keystroke(&keystroke, PlatformStyle::Mac),
+            "⌃⌥⇧⌘P"
+        );
+        assert_eq!(
+            glyphs_for_keystroke(
+                &Keystroke::parse("ctrl-shift-p").unwrap(),
+                PlatformStyle::Linux
+            ),
+            "Ctrl+Shift+P"
+        );
+        assert_eq!(
+            glyphs_for_keystroke(&Keystroke::parse("cmd-left").unwrap(), PlatformStyle::Mac),
+            "⌘←"
+        );
+    }
 }
@@ -0,0 +1,338 @@
+#![allow(missing_docs)]
+use std::time::Duration;
+
+use gpui::{
+    EventEmitter, FocusHandle, FocusableView, Keystroke, MouseButton, NoAction, Subscription, Task,
+};
+
+use crate::{prelude::*, KeyBinding};
+
+/// How long the recorder waits for the next keystroke of a chord, like the keymap
+/// does while a binding is pending.
+const CHORD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Keys that only change the keystroke they're held with.
+const MODIFIER_KEYS: &[&str] = &["shift", "control", "alt", "platform", "function"];
+
+pub enum KeystrokeRecorderEvent {
+    /// A shortcut was recorded, with one keystroke per step of the chord.
+    Recorded(Vec<Keystroke>),
+    /// Recording was cancelled with Escape before any key was pressed.
+    Cancelled,
+}
+
+/// A binding of the keymap using some or all of the recorded keystrokes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystrokeConflict {
+    pub action_name: SharedString,
+    /// Whether the binding has exactly the recorded keystrokes, rather than
+    /// starting with them or being started by them.
+    pub exact: bool,
+}
+
+/// Returns the bindings that would clash with binding `keystrokes`: those with the
+/// same keystrokes, and those whose keystrokes start with the others'. Contexts
+/// aren't taken into account, and bindings removed with `NoAction` are skipped.
+fn conflicting_bindings<'a>(
+    keystrokes: &[Keystroke],
+    bindings: impl IntoIterator<Item = &'a gpui::KeyBinding>,
+) -> Vec<KeystrokeConflict> {
+    if keystrokes.is_empty() {
+        return Vec::new();
+    }
+    let mut conflicts = Vec::<KeystrokeConflict>::new();
+    for binding in bindings {
+        if binding.action().as_any().is::<NoAction>() {
+            continue;
+        }
+        let len = binding.keystrokes().len().min(keystrokes.len());
+        let Some(pending) = binding.match_keystrokes(&keystrokes[..len]) else {
+            continue;
+        };
+        let exact = !pending && binding.keystrokes().len() == keystrokes.len();
+        let action_name = SharedString::from(binding.action().name());
+        if let Some(conflict) = conflicts
+            .iter_mut()
+            .find(|conflict| conflict.action_name == action_name)
+        {
+            conflict.exact |= exact;
+        } else {
+            conflicts.push(KeystrokeConflict { action_name, exact });
+        }
+    }
+    conflicts
+}
+
+/// A field recording a keyboard shortcut, e.g. to rebind an action.
+///
+/// While the field is focused, every keystroke is captured instead of triggering its
+/// binding, up to [`KeystrokeRecorder::set_max_keystrokes`] keystrokes pressed in
+/// quick succession. The shortcut is then reported as
+/// [`KeystrokeRecorderEvent::Recorded`], and shown with the bindings of the keymap
+/// it clashes with. Pressing a key once again records a new shortcut.
+pub struct KeystrokeRecorder {
+    focus_handle: FocusHandle,
+    keystrokes: Vec<Keystroke>,
+    max_keystrokes: usize,
+    /// Whether the keystrokes were reported, so that the next one starts over.
+    finished: bool,
+    conflicts: Vec<KeystrokeConflict>,
+    placeholder: SharedString,
+    finish_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<KeystrokeRecorderEvent> for KeystrokeRecorder {}
+
+impl FocusableView for KeystrokeRecorder {
+    fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl KeystrokeRecorder {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let recorder = cx.view().downgrade();
+        let subscriptions = vec![
+            cx.intercept_keystrokes(move |event, cx| {
+                recorder
+                    .update(cx, |recorder, cx| {
+                        if recorder.focus_handle.is_focused(cx) {
+                            cx.stop_propagation();
+                            recorder.record(&event.keystroke, cx);
+                        }
+                    })
+                    .ok();
+            }),
+            cx.on_blur(&focus_handle, |this, cx| {
+                if !this.finished && !this.keystrokes.is_empty() {
+                    this.finish(cx);
+                }
+            }),
+        ];
+
+        Self {
+            focus_handle,
+            keystrokes: Vec::new(),
+            max_keystrokes: 2,
+            finished: true,
+            conflicts: Vec::new(),
+            placeholder: "Press a shortcut…".into(),
+            finish_task: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// The recorded keystrokes, or the ones being recorded.
+    pub fn keystrokes(&self) -> &[Keystroke] {
+        &self.keystrokes
+    }
+
+    /// The recorded shortcut as written in keymap files, e.g. `ctrl-k ctrl-s`.
+    pub fn keystrokes_text(&self) -> String {
+        self.keystrokes
+            .iter()
+            .map(Keystroke::unparse)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Shows a shortcut, like the current binding of the action, without reporting it.
+    pub fn set_keystrokes(&mut self, keystrokes: Vec<Keystroke>, cx: &mut ViewContext<Self>) {
+        self.keystrokes = keystrokes;
+        self.finished = true;
+        self.finish_task = None;
+        self.update_conflicts(cx);
+        cx.notify();
+    }
+
+    /// Sets how many keystrokes a chord can have. Defaults to 2.
+    pub fn set_max_keystrokes(&mut self, max_keystrokes: usize) {
+        self.max_keystrokes = max_keystrokes.max(1);
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: impl Into<SharedString>) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// The bindings of the keymap the shortcut clashes with.
+    pub fn conflicts(&self) -> &[KeystrokeConflict] {
+        &self.conflicts
+    }
+
+    fn record(&mut self, keystroke: &Keystroke, cx: &mut ViewContext<Self>) {
+        if keystroke.key.is_empty() || MODIFIER_KEYS.contains(&keystroke.key.as_str()) {
+            return;
+        }
+        if self.finished {
+            if keystroke.key == "escape" && !keystroke.modifiers.modified() {
+                cx.emit(KeystrokeRecorderEvent::Cancelled);
+                cx.blur();
+                return;
+            }
+            self.keystrokes.clear();
+            self.finished = false;
+        }
+
+        self.keystrokes.push(Keystroke {
+            ime_key: None,
+            ..keystroke.clone()
+        });
+        self.update_conflicts(cx);
+        if self.keystrokes.len() >= self.max_keystrokes {
+            self.finish(cx);
+        } else {
+            self.finish_task = Some(cx.spawn(|this, mut cx| async move {
+                cx.background_executor().timer(CHORD_TIMEOUT).await;
+                this.update(&mut cx, |this, cx| this.finish(cx)).ok();
+            }));
+        }
+        cx.notify();
+    }
+
+    fn finish(&mut self, cx: &mut ViewContext<Self>) {
+        self.finish_task = None;
+        self.finished = true;
+        cx.emit(KeystrokeRecorderEvent::Recorded(self.keystrokes.clone()));
+        cx.notify();
+    }
+
+    fn update_conflicts(&mut self, cx: &mut ViewContext<Self>) {
+        let keymap = cx.key_bindings();
+        let keymap = keymap.borrow();
+        self.conflicts = conflicting_bindings(&self.keystrokes, keymap.bindings());
+    }
+
+    fn render_keystrokes(&self) -> Option<KeyBinding> {
+        if self.keystrokes.is_empty() {
+            return None;
+        }
+        let binding =
+            gpui::KeyBinding::load(&self.keystrokes_text(), Box::new(NoAction), None).ok()?;
+        Some(KeyBinding::new(binding))
+    }
+}
+
+impl Render for KeystrokeRecorder {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+        let has_exact_conflict = self.conflicts.iter().any(|conflict| conflict.exact);
+        let border_color = if focused {
+            cx.theme().colors().border_focused
+        } else if has_exact_conflict {
+            cx.theme().status().error_border
+        } else {
+            cx.theme().colors().border
+        };
+        let focus_handle = self.focus_handle.clone();
+
+        v_flex()
+            .gap_1()
+            .child(
+                h_flex()
+                    .id("keystroke-recorder")
+                    .track_focus(&self.focus_handle)
+                    .on_mouse_down(MouseButton::Left, move |_, cx| cx.focus(&focus_handle))
+                    .min_w(rems(10.))
+                    .h(rems(1.75))
+                    .px_2()
+                    .gap_1()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(border_color)
+                    .bg(cx.theme().colors().editor_background)
+                    .map(|this| match self.render_keystrokes() {
+                        Some(keystrokes) => this.child(keystrokes),
+                        None => this.child(
+                            Label::new(if focused {
+                                self.placeholder.clone()
+                            } else {
+                                "Click to record a shortcut".into()
+                            })
+                            .color(Color::Placeholder),
+                        ),
+                    })
+                    .when(focused && !self.finished, |this| {
+                        this.child(Label::new("…").color(Color::Muted))
+                    }),
+            )
+            .children(self.conflicts.iter().map(|conflict| {
+                let (icon, color, message) = if conflict.exact {
+                    (IconName::XCircle, Color::Error, "Already bound to")
+                } else {
+                    (IconName::Warning, Color::Warning, "Overlaps with")
+                };
+                h_flex()
+                    .gap_1()
+                    .child(Icon::new(icon).size(IconSize::Small).color(color))
+                    .child(
+                        Label::new(format!("{} {}", message, conflict.action_name))
+                            .size(LabelSize::Small)
+                            .color(color),
+                    )
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    gpui::actions!(keystroke_recorder, [Save, SaveAll, OpenKeymap]);
+
+    fn keystrokes(source: &str) -> Vec<Keystroke> {
+        source
+            .split_whitespace()
+            .map(|keystroke| Keystroke::parse(keystroke).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_conflicting_bindings() {
+        let bindings = [
+            gpui::KeyBinding::new("ctrl-s", Save, None),
+            gpui::KeyBinding::new("ctrl-s", NoAction, Some("Editor")),
+            gpui::KeyBinding::new("ctrl-k ctrl-s", OpenKeymap, None),
+            gpui::KeyBinding::new("ctrl-alt-s", SaveAll, None),
+        ];
+        let conflicts = |source| conflicting_bindings(&keystrokes(source), &bindings);
+
+        assert_eq!(
+            conflicts("ctrl-s"),
+            vec![KeystrokeConflict {
+                action_name: "keystroke_recorder::Save".into(),
+                exact: true,
+            }]
+        );
+        // Bindings starting with the shortcut, and bindings it starts with, would
+        // shadow each other.
+        assert_eq!(
+            conflicts("ctrl-k"),
+            vec![KeystrokeConflict {
+                action_name: "keystroke_recorder::OpenKeymap".into(),
+                exact: false,
+            }]
+        );
+        assert_eq!(
+            conflicts("ctrl-s ctrl-d"),
+            vec![KeystrokeConflict {
+                action_name: "keystroke_recorder::Save".into(),
+                exact: false,
+            }]
+        );
+        assert_eq!(conflicts("ctrl-shift-s"), vec![]);
+    }
+
+    #[test]
+    fn test_unparse_keystrokes() {
+        for source in ["ctrl-k ctrl-s", "alt-shift-cmd-f", "space", "ctrl--"] {
+            let text = keystrokes(source)
+                .iter()
+                .map(Keystroke::unparse)
+                .collect::<Vec<_>>()
+                .join(" ");
+            assert_eq!(text, source);
+        }
+    }
+}
@@ -1,7 +1,17 @@
 mod highlighted_label;
 mod label;
 mod label_like;
+mod label_with_detail;
+mod link_label;
+mod loading_label;
+mod selectable_text;
+mod timestamp_label;
 
 pub use highlighted_label::*;
 pub use label::*;
 pub use label_like::*;
+pub use label_with_detail::*;
+pub use link_label::*;
+pub use loading_label::*;
+pub use selectable_text::*;
+pub use timestamp_label::*;
@@ -1,10 +1,15 @@
 #![allow(missing_docs)]
 
+use std::hash::{Hash, Hasher};
 use std::ops::Range;
+use std::sync::Arc;
 
-use gpui::{FontWeight, HighlightStyle, StyledText};
+use collections::{FxHashMap, FxHasher};
+use fuzzy::StringMatch;
+use gpui::{FontWeight, Global, HighlightStyle, Hsla, StyledText, TextRun};
+use theme::Theme;
 
-use crate::{prelude::*, LabelCommon, LabelLike, LabelSize, LineHeightStyle};
+use crate::{prelude::*, LabelCommon, LabelFont, LabelLike, LabelSize, LineHeightStyle};
 
 #[derive(IntoElement)]
 pub struct HighlightedLabel {
@@ -23,6 +28,40 @@ impl HighlightedLabel {
             highlight_indices,
         }
     }
+
+    /// Constructs a label with the characters overlapping the given UTF-8 byte
+    /// ranges highlighted. The ranges can overlap and be in any order.
+    pub fn from_ranges(
+        label: impl Into<SharedString>,
+        ranges: impl IntoIterator<Item = Range<usize>>,
+    ) -> Self {
+        let label = label.into();
+        let mut highlight_indices = ranges
+            .into_iter()
+            .flat_map(|range| char_indices_in_range(&label, range))
+            .collect::<Vec<_>>();
+        highlight_indices.sort_unstable();
+        highlight_indices.dedup();
+        Self::new(label, highlight_indices)
+    }
+
+    /// Constructs a label with the characters of a fuzzy match highlighted, where
+    /// the label is the string that was matched.
+    pub fn from_fuzzy_match(label: impl Into<SharedString>, string_match: &StringMatch) -> Self {
+        let mut highlight_indices = string_match.positions.clone();
+        highlight_indices.sort_unstable();
+        Self::new(label, highlight_indices)
+    }
+}
+
+/// Returns the byte indices of the characters of the text overlapping the given
+/// byte range.
+fn char_indices_in_range(text: &str, range: Range<usize>) -> Vec<usize> {
+    text.char_indices()
+        .skip_while(|(ix, c)| ix + c.len_utf8() <= range.start)
+        .take_while(|(ix, _)| *ix < range.end)
+        .map(|(ix, _)| ix)
+        .collect()
 }
 
 impl LabelCommon for HighlightedLabel {
@@ -36,6 +75,11 @@ impl LabelCommon for HighlightedLabel {
         self
     }
 
+    fn font_family(mut self, font: impl Into<LabelFont>) -> Self {
+        self.base = self.base.font_family(font);
+        self
+    }
+
     fn line_height_style(mut self, line_height_style: LineHeightStyle) -> Self {
         self.base = self.base.line_height_style(line_height_style);
         self
@@ -72,47 +116,237 @@ pub fn highlight_ranges(
     indices: &[usize],
     style: HighlightStyle,
 ) -> Vec<(Range<usize>, HighlightStyle)> {
-    let mut highlight_indices = indices.iter().copied().peekable();
     let mut highlights: Vec<(Range<usize>, HighlightStyle)> = Vec::new();
 
-    while let Some(start_ix) = highlight_indices.next() {
-        let mut end_ix = start_ix;
-
-        loop {
-            end_ix = end_ix + text[end_ix..].chars().next().unwrap().len_utf8();
-            if let Some(&next_ix) = highlight_indices.peek() {
-                if next_ix == end_ix {
-                    end_ix = next_ix;
-                    highlight_indices.next();
-                    continue;
-                }
-            }
-            break;
+    for &ix in indices {
+        if ix >= text.len() || !text.is_char_boundary(ix) {
+            continue;
+        }
+        let end_ix = ix + utf8_char_width(text.as_bytes()[ix]);
+        match highlights.last_mut() {
+            // Extend the last range with the characters directly following it,
+            // rather than re-scanning the text.
+            Some((range, _)) if range.end == ix => range.end = end_ix,
+            // Indices are expected to be ascending, anything else is ignored.
+            Some((range, _)) if range.end > ix => {}
+            _ => highlights.push((ix..end_ix, style)),
         }
-
-        highlights.push((start_ix..end_ix, style));
     }
 
     highlights
 }
 
+/// Returns the length of the UTF-8 encoded character starting with the given byte.
+fn utf8_char_width(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// The maximum number of labels whose runs are kept, after which the cache is
+/// cleared. Comfortably fits the visible rows of every open picker.
+const MAX_CACHED_LABELS: usize = 4096;
+
+/// The text runs of the highlighted labels rendered recently, so that rows of long
+/// lists, like fuzzy finder results, don't re-derive them every frame.
+///
+/// Runs are keyed by a hash of the label, its highlights and its style, so that
+/// looking them up doesn't copy any of those, and are shared with the elements
+/// rendering them rather than cloned.
+#[derive(Default)]
+pub struct HighlightedLabelRuns {
+    /// The theme the runs were derived with. Holding on to it keeps its address
+    /// from being reused, so it identifies the theme's generation.
+    theme: Option<Arc<Theme>>,
+    runs: FxHashMap<u64, Arc<[TextRun]>>,
+}
+
+impl HighlightedLabelRuns {
+    /// Returns the runs of the given label, deriving them if they aren't cached.
+    ///
+    /// `default_run` styles the unhighlighted text and its length is ignored.
+    pub fn get_or_insert(
+        &mut self,
+        label: &str,
+        highlight_indices: &[usize],
+        default_run: &TextRun,
+        highlight_color: Hsla,
+    ) -> Arc<[TextRun]> {
+        let mut hasher = FxHasher::default();
+        label.hash(&mut hasher);
+        highlight_indices.hash(&mut hasher);
+        default_run.hash(&mut hasher);
+        highlight_color.hash(&mut hasher);
+        let key = hasher.finish();
+
+        if self.runs.len() >= MAX_CACHED_LABELS && !self.runs.contains_key(&key) {
+            self.runs.clear();
+        }
+        self.runs
+            .entry(key)
+            .or_insert_with(|| {
+                let highlights = highlight_ranges(
+                    label,
+                    highlight_indices,
+                    HighlightStyle {
+                        color: Some(highlight_color),
+                        ..Default::default()
+                    },
+                );
+                build_runs(label, highlights, default_run, highlight_color).into()
+            })
+            .clone()
+    }
+
+    fn invalidate_if_theme_changed(&mut self, theme: &Arc<Theme>) {
+        if !self
+            .theme
+            .as_ref()
+            .is_some_and(|cached_theme| Arc::ptr_eq(cached_theme, theme))
+        {
+            self.theme = Some(theme.clone());
+            self.runs.clear();
+        }
+    }
+}
+
+impl Global for HighlightedLabelRuns {}
+
+pub fn build_runs(
+    text: &str,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+    default_run: &TextRun,
+    highlight_color: Hsla,
+) -> Vec<TextRun> {
+    let run = |len: usize| TextRun {
+        len,
+        ..default_run.clone()
+    };
+    let mut runs = Vec::with_capacity(highlights.len() * 2 + 1);
+    let mut ix = 0;
+    for (range, _) in highlights {
+        if ix < range.start {
+            runs.push(run(range.start - ix));
+        }
+        runs.push(TextRun {
+            color: highlight_color,
+            ..run(range.len())
+        });
+        ix = range.end;
+    }
+    if ix < text.len() {
+        runs.push(run(text.len() - ix));
+    }
+    runs
+}
+
 impl RenderOnce for HighlightedLabel {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let highlight_color = cx.theme().colors().text_accent;
 
-        let highlights = highlight_ranges(
+        let mut text_style = cx.text_style();
+        text_style.color = self.base.color.color(cx);
+
+        let theme = cx.theme().clone();
+        let cache = cx.default_global::<HighlightedLabelRuns>();
+        cache.invalidate_if_theme_changed(&theme);
+        let runs = cache.get_or_insert(
             &self.label,
             &self.highlight_indices,
-            HighlightStyle {
-                color: Some(highlight_color),
-                ..Default::default()
-            },
+            &text_style.to_run(0),
+            highlight_color,
         );
 
-        let mut text_style = cx.text_style();
-        text_style.color = self.base.color.color(cx);
+        self.base.child(StyledText::new(self.label).with_runs(runs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_ranges() {
+        let style = HighlightStyle::default();
+        let ranges = |text, indices: &[usize]| {
+            highlight_ranges(text, indices, style)
+                .into_iter()
+                .map(|(range, _)| range)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ranges("hello world", &[0, 1, 2, 6, 8]), [0..3, 6..7, 8..9]);
+        assert_eq!(ranges("héllo", &[0, 1, 3]), [0..4]);
+        assert_eq!(ranges("héllo", &[2, 3, 4, 42]), [3..5]);
+        assert_eq!(ranges("hello", &[3, 1]), [3..4]);
+        assert!(ranges("hello", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_cached_runs_are_shared() {
+        let default_run = TextRun {
+            len: 0,
+            font: gpui::font("Zed Plex Sans"),
+            color: gpui::black(),
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+            overline: None,
+        };
+        let highlight_color = gpui::red();
+        let mut cache = HighlightedLabelRuns::default();
+
+        let runs = cache.get_or_insert("hello", &[1, 2], &default_run, highlight_color);
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.len, run.color))
+                .collect::<Vec<_>>(),
+            [
+                (1, default_run.color),
+                (2, highlight_color),
+                (2, default_run.color)
+            ]
+        );
+        let cached_runs = cache.get_or_insert("hello", &[1, 2], &default_run, highlight_color);
+        assert!(Arc::ptr_eq(&runs, &cached_runs));
+
+        let other_runs = cache.get_or_insert("hello", &[0], &default_run, highlight_color);
+        assert!(!Arc::ptr_eq(&runs, &other_runs));
+        assert_eq!(other_runs.len(), 2);
+    }
+
+    #[test]
+    fn test_from_ranges() {
+        let indices = |text, ranges: Vec<Range<usize>>| {
+            HighlightedLabel::from_ranges(text, ranges).highlight_indices
+        };
+
+        assert_eq!(
+            indices("hello world", vec![6..11, 0..2]),
+            [0, 1, 6, 7, 8, 9, 10]
+        );
+        assert_eq!(indices("hello", vec![0..3, 2..4]), [0, 1, 2, 3]);
+        // Ranges starting or ending inside a multi-byte character include it.
+        assert_eq!(indices("héllo", vec![2..3]), [1]);
+        assert_eq!(
+            indices("héllo wörld", vec![0..2, 8..20]),
+            [0, 1, 8, 10, 11, 12]
+        );
+        assert!(indices("hello", vec![3..3, 10..12]).is_empty());
+    }
 
-        self.base
-            .child(StyledText::new(self.label).with_highlights(&text_style, highlights))
+    #[test]
+    fn test_from_fuzzy_match() {
+        let string_match = StringMatch {
+            candidate_id: 0,
+            score: 1.,
+            positions: vec![4, 0, 1],
+            string: "héllo".into(),
+        };
+        let label = HighlightedLabel::from_fuzzy_match("héllo", &string_match);
+        assert_eq!(label.highlight_indices, [0, 1, 4]);
     }
 }
@@ -1,8 +1,12 @@
 #![allow(missing_docs)]
 
-use gpui::{StyleRefinement, WindowContext};
+use gpui::{
+    AccessibilityProperties, AccessibilityRole, StyleRefinement, StyledText, WindowContext,
+};
 
-use crate::{prelude::*, LabelCommon, LabelLike, LabelSize, LineHeightStyle};
+use crate::{
+    prelude::*, LabelCommon, LabelFont, LabelLike, LabelSize, LineHeightStyle, SelectableText,
+};
 
 /// A struct representing a label element in the UI.
 ///
@@ -37,6 +41,7 @@ pub struct Label {
     base: LabelLike,
     label: SharedString,
     single_line: bool,
+    selectable: Option<ElementId>,
 }
 
 impl Label {
@@ -54,6 +59,7 @@ impl Label {
             base: LabelLike::new(),
             label: label.into(),
             single_line: false,
+            selectable: None,
         }
     }
 
@@ -70,6 +76,28 @@ impl Label {
         self.single_line = true;
         self
     }
+
+    /// Lets the text of the label be selected and copied, e.g. for error messages.
+    /// The `id` keeps the selection across frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ui::prelude::*;
+    ///
+    /// let my_label = Label::new("Connection refused").selectable("error");
+    /// ```
+    pub fn selectable(mut self, id: impl Into<ElementId>) -> Self {
+        self.selectable = Some(id.into());
+        self
+    }
+
+    /// Attaches a stable identifier for tests to locate the label by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.base = self.base.test_id(id);
+        self
+    }
 }
 
 // Style methods.
@@ -103,6 +131,20 @@ impl LabelCommon for Label {
         self
     }
 
+    /// Sets the font family of the label using a [`LabelFont`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ui::prelude::*;
+    ///
+    /// let my_label = Label::new("src/main.rs").font_family(LabelFont::Buffer);
+    /// ```
+    fn font_family(mut self, font: impl Into<LabelFont>) -> Self {
+        self.base = self.base.font_family(font);
+        self
+    }
+
     /// Sets the line height style of the label using a [`LineHeightStyle`].
     ///
     /// # Examples
@@ -181,11 +223,18 @@ impl LabelCommon for Label {
 
 impl RenderOnce for Label {
     fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let accessibility =
+            AccessibilityProperties::new(AccessibilityRole::Label).name(self.label.clone());
         let target_label = if self.single_line {
             SharedString::from(self.label.replace('\n', "␤"))
         } else {
             self.label
         };
-        self.base.child(target_label)
+        let mut base = self.base;
+        base.base = base.base.accessibility(accessibility);
+        match self.selectable {
+            Some(id) => base.child(SelectableText::new(id, StyledText::new(target_label))),
+            None => base.child(target_label),
+        }
     }
 }
@@ -16,6 +16,30 @@ pub enum LabelSize {
     XSmall,
 }
 
+/// The font family of a label, taken from the theme settings unless a family is
+/// named.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum LabelFont {
+    /// The UI font, for most labels.
+    Ui,
+    /// The buffer font, for labels showing code, paths or other monospace text.
+    Buffer,
+    /// A font family by name.
+    Family(SharedString),
+}
+
+impl From<SharedString> for LabelFont {
+    fn from(family: SharedString) -> Self {
+        Self::Family(family)
+    }
+}
+
+impl From<&'static str> for LabelFont {
+    fn from(family: &'static str) -> Self {
+        Self::Family(family.into())
+    }
+}
+
 #[derive(Default, PartialEq, Copy, Clone)]
 pub enum LineHeightStyle {
     #[default]
@@ -32,6 +56,10 @@ pub trait LabelCommon {
     /// Sets the font weight of the label.
     fn weight(self, weight: FontWeight) -> Self;
 
+    /// Sets the font family of the label using a [`LabelFont`]. By default the
+    /// label uses the font of its parent.
+    fn font_family(self, font: impl Into<LabelFont>) -> Self;
+
     /// Sets the line height style of the label using a [`LineHeightStyle`].
     fn line_height_style(self, line_height_style: LineHeightStyle) -> Self;
 
@@ -56,6 +84,7 @@ pub struct LabelLike {
     pub(super) base: Div,
     size: LabelSize,
     weight: Option<FontWeight>,
+    font: Option<LabelFont>,
     line_height_style: LineHeightStyle,
     pub(crate) color: Color,
     strikethrough: bool,
@@ -77,6 +106,7 @@ impl LabelLike {
             base: div(),
             size: LabelSize::Default,
             weight: None,
+            font: None,
             line_height_style: LineHeightStyle::default(),
             color: Color::Default,
             strikethrough: false,
@@ -86,6 +116,13 @@ impl LabelLike {
             underline: false,
         }
     }
+
+    /// Attaches a stable identifier for tests to locate the label by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.base = self.base.test_id(id);
+        self
+    }
 }
 
 // Style methods.
@@ -110,6 +147,11 @@ impl LabelCommon for LabelLike {
         self
     }
 
+    fn font_family(mut self, font: impl Into<LabelFont>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
     fn line_height_style(mut self, line_height_style: LineHeightStyle) -> Self {
         self.line_height_style = line_height_style;
         self
@@ -156,16 +198,17 @@ impl RenderOnce for LabelLike {
             color.fade_out(1.0 - alpha);
         }
 
+        let default_weight = match self.font {
+            Some(LabelFont::Buffer) => settings.buffer_font.weight,
+            _ => settings.ui_font.weight,
+        };
+
         self.base
-            .when(self.strikethrough, |this| {
-                this.relative().child(
-                    div()
-                        .absolute()
-                        .top_1_2()
-                        .w_full()
-                        .h_px()
-                        .bg(Color::Hidden.color(cx)),
-                )
+            .map(|this| match &self.font {
+                Some(LabelFont::Ui) => this.font(settings.ui_font.clone()),
+                Some(LabelFont::Buffer) => this.font(settings.buffer_font.clone()),
+                Some(LabelFont::Family(family)) => this.font_family(family.clone()),
+                None => this,
             })
             .map(|this| match self.size {
                 LabelSize::Large => this.text_ui_lg(cx),
@@ -187,8 +230,9 @@ impl RenderOnce for LabelLike {
                 });
                 this
             })
+            .when(self.strikethrough, |this| this.line_through())
             .text_color(color)
-            .font_weight(self.weight.unwrap_or(settings.ui_font.weight))
+            .font_weight(self.weight.unwrap_or(default_weight))
             .children(self.children)
     }
 }
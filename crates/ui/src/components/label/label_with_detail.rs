@@ -0,0 +1,116 @@
+#![allow(missing_docs)]
+use gpui::AnyElement;
+
+use crate::{prelude::*, HighlightedLabel};
+
+/// A label followed by right-aligned detail, like a path, a shortcut or a count,
+/// as shown in picker rows and menu items.
+///
+/// When there's not enough room for both, the detail is truncated first, and the
+/// label only once the detail is gone.
+#[derive(IntoElement)]
+pub struct LabelWithDetail {
+    label: SharedString,
+    highlight_indices: Vec<usize>,
+    detail: Option<AnyElement>,
+    size: LabelSize,
+    color: Color,
+}
+
+impl LabelWithDetail {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            highlight_indices: Vec::new(),
+            detail: None,
+            size: LabelSize::Default,
+            color: Color::Default,
+        }
+    }
+
+    /// Highlights the characters of the label at the given UTF-8 byte positions,
+    /// e.g. the ones matching a fuzzy query.
+    pub fn highlights(mut self, highlight_indices: Vec<usize>) -> Self {
+        self.highlight_indices = highlight_indices;
+        self
+    }
+
+    /// Sets the detail text, shown muted and one size smaller than the label.
+    pub fn detail(mut self, detail: impl Into<SharedString>) -> Self {
+        let size = match self.size {
+            LabelSize::Large => LabelSize::Default,
+            LabelSize::Default => LabelSize::Small,
+            LabelSize::Small | LabelSize::XSmall => LabelSize::XSmall,
+        };
+        self.detail = Some(
+            Label::new(detail)
+                .size(size)
+                .color(Color::Muted)
+                .single_line()
+                .into_any_element(),
+        );
+        self
+    }
+
+    /// Sets an element as the detail, like a [`KeyBinding`](crate::KeyBinding).
+    pub fn detail_element(mut self, detail: impl IntoElement) -> Self {
+        self.detail = Some(detail.into_any_element());
+        self
+    }
+
+    /// Sets the size of the label. Set it before [`LabelWithDetail::detail`], which
+    /// sizes the detail after it.
+    pub fn size(mut self, size: LabelSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl RenderOnce for LabelWithDetail {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let label = if self.highlight_indices.is_empty() {
+            Label::new(self.label)
+                .size(self.size)
+                .color(self.color)
+                .single_line()
+                .into_any_element()
+        } else {
+            HighlightedLabel::new(self.label, self.highlight_indices)
+                .size(self.size)
+                .color(self.color)
+                .into_any_element()
+        };
+
+        h_flex()
+            .w_full()
+            .min_w_0()
+            .gap_4()
+            .justify_between()
+            .child(
+                // The label keeps its width, up to the whole row, so that the
+                // detail gives up the room first.
+                div()
+                    .flex_none()
+                    .max_w_full()
+                    .overflow_x_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(label),
+            )
+            .children(self.detail.map(|detail| {
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .justify_end()
+                    .overflow_x_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(detail)
+            }))
+    }
+}
@@ -0,0 +1,119 @@
+#![allow(missing_docs)]
+
+use gpui::{ClickEvent, FontWeight};
+
+use crate::{prelude::*, LabelCommon, LabelFont, LabelSize, LineHeightStyle, Tooltip};
+
+/// A label rendered as a link: accent-colored, underlined on hover, with a
+/// pointer cursor.
+///
+/// Clicking it opens its URL with the platform opener, or calls its click handler,
+/// e.g. for "Learn more" texts and error hints.
+///
+/// # Examples
+///
+/// ```
+/// use ui::{prelude::*, LinkLabel};
+///
+/// LinkLabel::new("docs-link", "Learn more").href("https://zed.dev/docs");
+/// ```
+#[derive(IntoElement)]
+pub struct LinkLabel {
+    id: ElementId,
+    base: Label,
+    href: Option<SharedString>,
+    on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+}
+
+impl LinkLabel {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: Label::new(label).color(Color::Accent),
+            href: None,
+            on_click: None,
+        }
+    }
+
+    /// Sets the URL opened when the link is clicked, unless it has a click handler.
+    pub fn href(mut self, href: impl Into<SharedString>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Calls the given handler when the link is clicked, instead of opening its URL.
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+}
+
+impl LabelCommon for LinkLabel {
+    fn size(mut self, size: LabelSize) -> Self {
+        self.base = self.base.size(size);
+        self
+    }
+
+    fn weight(mut self, weight: FontWeight) -> Self {
+        self.base = self.base.weight(weight);
+        self
+    }
+
+    fn font_family(mut self, font: impl Into<LabelFont>) -> Self {
+        self.base = self.base.font_family(font);
+        self
+    }
+
+    fn line_height_style(mut self, line_height_style: LineHeightStyle) -> Self {
+        self.base = self.base.line_height_style(line_height_style);
+        self
+    }
+
+    fn color(mut self, color: Color) -> Self {
+        self.base = self.base.color(color);
+        self
+    }
+
+    fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.base = self.base.strikethrough(strikethrough);
+        self
+    }
+
+    fn italic(mut self, italic: bool) -> Self {
+        self.base = self.base.italic(italic);
+        self
+    }
+
+    fn underline(mut self, underline: bool) -> Self {
+        self.base = self.base.underline(underline);
+        self
+    }
+
+    fn alpha(mut self, alpha: f32) -> Self {
+        self.base = self.base.alpha(alpha);
+        self
+    }
+}
+
+impl RenderOnce for LinkLabel {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let href = self.href;
+        let on_click = self.on_click;
+
+        div()
+            .id(self.id)
+            .cursor_pointer()
+            .hover(|style| style.text_decoration_1())
+            .when_some(href.clone(), |this, href| {
+                this.tooltip(move |cx| Tooltip::text(href.clone(), cx))
+            })
+            .on_click(move |event, cx| {
+                if let Some(on_click) = on_click.as_ref() {
+                    on_click(event, cx);
+                } else if let Some(href) = href.as_ref() {
+                    cx.open_url(href);
+                }
+            })
+            .child(self.base)
+    }
+}
@@ -0,0 +1,101 @@
+#![allow(missing_docs)]
+use std::time::{Duration, Instant};
+
+use gpui::{percentage, Animation, AnimationExt, Transformation};
+use theme::animation_duration;
+
+use crate::prelude::*;
+
+/// A small spinner followed by a muted label, for states like "Connecting…" and
+/// "Indexing…" in panel bodies and buttons.
+///
+/// With [`LoadingLabel::started_at`], the time elapsed since is shown after the
+/// label. It advances with the spinner's animation frames.
+#[derive(IntoElement)]
+pub struct LoadingLabel {
+    id: ElementId,
+    label: SharedString,
+    size: LabelSize,
+    started_at: Option<Instant>,
+}
+
+impl LoadingLabel {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            size: LabelSize::Small,
+            started_at: None,
+        }
+    }
+
+    pub fn size(mut self, size: LabelSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Shows the time elapsed since the given instant after the label.
+    pub fn started_at(mut self, started_at: Instant) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+}
+
+/// Formats an elapsed duration compactly, like `12s`, `1m 05s` or `1h 02m`.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    match seconds {
+        0..=59 => format!("{seconds}s"),
+        60..=3599 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+impl RenderOnce for LoadingLabel {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let icon_size = match self.size {
+            LabelSize::Large | LabelSize::Default => IconSize::Small,
+            LabelSize::Small | LabelSize::XSmall => IconSize::XSmall,
+        };
+        let label = match self.started_at {
+            Some(started_at) => {
+                format!("{} ({})", self.label, format_elapsed(started_at.elapsed())).into()
+            }
+            None => self.label,
+        };
+
+        h_flex()
+            .gap_1()
+            .min_w_0()
+            .child(
+                Icon::new(IconName::ArrowCircle)
+                    .size(icon_size)
+                    .color(Color::Muted)
+                    .with_animation(
+                        self.id,
+                        Animation::new(animation_duration(Duration::from_secs(2), cx)).repeat(),
+                        |icon, delta| icon.transform(Transformation::rotate(percentage(delta))),
+                    ),
+            )
+            .child(
+                div().min_w_0().overflow_x_hidden().child(
+                    Label::new(label)
+                        .size(self.size)
+                        .color(Color::Muted)
+                        .single_line(),
+                ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_secs(12)), "12s");
+        assert_eq!(format_elapsed(Duration::from_secs(65)), "1m 05s");
+        assert_eq!(format_elapsed(Duration::from_secs(3720)), "1h 02m");
+    }
+}
@@ -0,0 +1,331 @@
+#![allow(missing_docs)]
+
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use gpui::{
+    actions, point, quad, AnyElement, Bounds, ClipboardItem, CursorStyle, DispatchPhase, Edges,
+    Element, ElementId, FocusHandle, GlobalElementId, Hitbox, Hsla, KeyContext, LayoutId,
+    MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels, StyledText, TextLayout,
+};
+
+use crate::prelude::*;
+
+actions!(selectable_text, [Copy]);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    start: usize,
+    end: usize,
+    reversed: bool,
+    /// Whether the mouse is still down, extending the selection as it moves.
+    pending: bool,
+}
+
+impl Selection {
+    fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    fn tail(&self) -> usize {
+        if self.reversed {
+            self.end
+        } else {
+            self.start
+        }
+    }
+
+    fn set_head(&mut self, head: usize) {
+        if head < self.tail() {
+            if !self.reversed {
+                self.end = self.start;
+                self.reversed = true;
+            }
+            self.start = head;
+        } else {
+            if self.reversed {
+                self.start = self.end;
+                self.reversed = false;
+            }
+            self.end = head;
+        }
+    }
+}
+
+/// The range of the word around `ix`, which a double click selects.
+fn word_range(text: &str, ix: usize) -> Range<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let start = text[..ix]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(ix, |(start, _)| start);
+    let end = text[ix..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(text.len(), |(end, _)| ix + end);
+    start..end
+}
+
+#[derive(Clone)]
+struct SelectableTextState {
+    selection: Rc<RefCell<Selection>>,
+    focus_handle: FocusHandle,
+}
+
+/// Text that can be selected by dragging over it, and copied with the usual
+/// shortcut once selected. The selection spans all the runs of the text, and is
+/// drawn with the selection color of the theme.
+///
+/// Used by [`Label::selectable`](crate::Label::selectable), for text people may
+/// want to copy, like error messages.
+pub struct SelectableText {
+    id: ElementId,
+    layout: TextLayout,
+    child: AnyElement,
+}
+
+impl SelectableText {
+    pub fn new(id: impl Into<ElementId>, text: StyledText) -> Self {
+        let layout = text.layout().clone();
+        Self::with_layout(id, layout, text)
+    }
+
+    /// Makes `child` selectable, where `layout` is the layout of the text it draws,
+    /// e.g. for an [`InteractiveText`](gpui::InteractiveText) whose links can still
+    /// be clicked.
+    pub fn with_layout(
+        id: impl Into<ElementId>,
+        layout: TextLayout,
+        child: impl IntoElement,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            layout,
+            child: child.into_any_element(),
+        }
+    }
+
+    fn paint_selection(&self, selection: Selection, color: Hsla, cx: &mut WindowContext) {
+        if selection.start == selection.end {
+            return;
+        }
+        let (Some(start), Some(end)) = (
+            self.layout.position_for_index(selection.start),
+            self.layout.position_for_index(selection.end),
+        ) else {
+            return;
+        };
+        let bounds = self.layout.bounds();
+        let line_height = self.layout.line_height();
+        let mut paint = |from: gpui::Point<Pixels>, to: gpui::Point<Pixels>| {
+            cx.paint_quad(quad(
+                Bounds::from_corners(from, to),
+                Pixels::ZERO,
+                color,
+                Edges::default(),
+                Hsla::transparent_black(),
+            ))
+        };
+
+        if start.y == end.y {
+            paint(start, point(end.x, end.y + line_height));
+        } else {
+            paint(start, point(bounds.right(), start.y + line_height));
+            if end.y > start.y + line_height {
+                paint(
+                    point(bounds.left(), start.y + line_height),
+                    point(bounds.right(), end.y),
+                );
+            }
+            paint(
+                point(bounds.left(), end.y),
+                point(end.x, end.y + line_height),
+            );
+        }
+    }
+
+    fn paint_mouse_listeners(
+        &self,
+        hitbox: &Hitbox,
+        state: &SelectableTextState,
+        cx: &mut WindowContext,
+    ) {
+        cx.set_cursor_style(CursorStyle::IBeam, hitbox);
+
+        cx.on_mouse_event({
+            let hitbox = hitbox.clone();
+            let layout = self.layout.clone();
+            let state = state.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if hitbox.is_hovered(cx) {
+                    if phase == DispatchPhase::Bubble && event.button == MouseButton::Left {
+                        let ix = match layout.index_for_position(event.position) {
+                            Ok(ix) | Err(ix) => ix,
+                        };
+                        let range = if event.click_count == 2 {
+                            word_range(&layout.text(), ix)
+                        } else {
+                            ix..ix
+                        };
+                        *state.selection.borrow_mut() = Selection {
+                            start: range.start,
+                            end: range.end,
+                            reversed: false,
+                            pending: true,
+                        };
+                        cx.focus(&state.focus_handle);
+                        cx.prevent_default();
+                        cx.refresh();
+                    }
+                } else if phase == DispatchPhase::Capture {
+                    let mut selection = state.selection.borrow_mut();
+                    if *selection != Selection::default() {
+                        *selection = Selection::default();
+                        cx.refresh();
+                    }
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let layout = self.layout.clone();
+            let selection = state.selection.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                let mut selection = selection.borrow_mut();
+                if phase == DispatchPhase::Bubble && selection.pending {
+                    let ix = match layout.index_for_position(event.position) {
+                        Ok(ix) | Err(ix) => ix,
+                    };
+                    selection.set_head(ix);
+                    cx.refresh();
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            #[cfg(target_os = "linux")]
+            let layout = self.layout.clone();
+            let selection = state.selection.clone();
+            move |_: &MouseUpEvent, phase, cx| {
+                let mut selection = selection.borrow_mut();
+                if phase == DispatchPhase::Capture && selection.pending {
+                    selection.pending = false;
+                    #[cfg(target_os = "linux")]
+                    {
+                        if selection.start != selection.end {
+                            let text = layout.text()[selection.range()].to_string();
+                            cx.write_to_primary(ClipboardItem::new_string(text));
+                        }
+                    }
+                    cx.refresh();
+                }
+            }
+        });
+    }
+}
+
+impl Element for SelectableText {
+    type RequestLayoutState = ();
+    type PrepaintState = (Hitbox, SelectableTextState);
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, ()) {
+        (self.child.request_layout(cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut (),
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let state = cx.with_element_state(id.unwrap(), |state, cx| {
+            let state = state.unwrap_or_else(|| SelectableTextState {
+                selection: Rc::default(),
+                focus_handle: cx.focus_handle(),
+            });
+            (state.clone(), state)
+        });
+        cx.set_focus_handle(&state.focus_handle);
+        self.child.prepaint(cx);
+        (cx.insert_hitbox(bounds, false), state)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut (),
+        (hitbox, state): &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let mut context = KeyContext::default();
+        context.add("SelectableText");
+        cx.set_key_context(context);
+        cx.on_action(std::any::TypeId::of::<Copy>(), {
+            let layout = self.layout.clone();
+            let selection = state.selection.clone();
+            move |_, phase, cx| {
+                let selection = selection.borrow();
+                if phase == DispatchPhase::Bubble && selection.start != selection.end {
+                    let text = layout.text()[selection.range()].to_string();
+                    cx.write_to_clipboard(ClipboardItem::new_string(text));
+                }
+            }
+        });
+
+        self.paint_mouse_listeners(hitbox, state, cx);
+        let selection = *state.selection.borrow();
+        let color = cx.theme().players().local().selection;
+        self.paint_selection(selection, color, cx);
+        self.child.paint(cx);
+    }
+}
+
+impl IntoElement for SelectableText {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_head() {
+        let mut selection = Selection {
+            start: 4,
+            end: 4,
+            reversed: false,
+            pending: true,
+        };
+        selection.set_head(9);
+        assert_eq!(selection.range(), 4..9);
+        selection.set_head(1);
+        assert_eq!(selection.range(), 1..4);
+        assert!(selection.reversed);
+        selection.set_head(6);
+        assert_eq!(selection.range(), 4..6);
+    }
+
+    #[test]
+    fn test_word_range() {
+        let text = "failed to open foo_bar.rs";
+        assert_eq!(&text[word_range(text, 17)], "foo_bar");
+        assert_eq!(&text[word_range(text, 0)], "failed");
+        assert_eq!(&text[word_range(text, text.len())], "rs");
+        assert_eq!(word_range(text, 6), 0..6);
+    }
+}
@@ -0,0 +1,213 @@
+#![allow(missing_docs)]
+use gpui::{canvas, AppContext};
+use time::{OffsetDateTime, UtcOffset};
+use time_format::{format_localized_timestamp, TimestampFormat};
+
+use crate::prelude::*;
+use crate::utils::{format_distance_from_now, request_time_ago_tick, DateTimeType};
+use crate::Tooltip;
+
+/// How a [`TimestampLabel`] describes its date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampLabelFormat {
+    /// How long ago, or how far from now, the date is, like "about 2 hours ago".
+    #[default]
+    Distance,
+    /// A short relative time, like "2 min ago" or "yesterday", falling back to the
+    /// date formatted for the user's locale once it's more than a week old, e.g.
+    /// for chat messages and git blame.
+    Relative,
+}
+
+/// A label showing a date relative to now, like "about 2 hours ago" or "2 min ago".
+///
+/// The label redraws its view at the ticks of the shared time-ago ticker for as
+/// long as its text can change, see [`request_time_ago_tick`].
+#[derive(IntoElement)]
+pub struct TimestampLabel {
+    date: DateTimeType,
+    format: TimestampLabelFormat,
+    tooltip_id: Option<ElementId>,
+    size: LabelSize,
+    color: Color,
+}
+
+impl TimestampLabel {
+    pub fn new(date: DateTimeType) -> Self {
+        Self {
+            date,
+            format: TimestampLabelFormat::default(),
+            tooltip_id: None,
+            size: LabelSize::Small,
+            color: Color::Muted,
+        }
+    }
+
+    pub fn format(mut self, format: TimestampLabelFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Shows the precise time in a tooltip.
+    pub fn precise_time_tooltip(mut self, id: impl Into<ElementId>) -> Self {
+        self.tooltip_id = Some(id.into());
+        self
+    }
+
+    pub fn size(mut self, size: LabelSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// Returns the offset of the local timezone from UTC.
+fn local_timezone() -> UtcOffset {
+    let local_offset = chrono::Local::now().offset().local_minus_utc();
+    UtcOffset::from_whole_seconds(local_offset).unwrap_or(UtcOffset::UTC)
+}
+
+/// Converts the date to an instant, reading naive dates as local times.
+fn to_offset_date_time(date: &DateTimeType, timezone: UtcOffset) -> OffsetDateTime {
+    let unix_timestamp = match date {
+        DateTimeType::Naive(naive) => {
+            naive.and_utc().timestamp() - i64::from(timezone.whole_seconds())
+        }
+        DateTimeType::Local(local) => local.timestamp(),
+    };
+    OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+/// Formats a timestamp relative to the reference time, like "2 min ago" or
+/// "yesterday", falling back to the date formatted for the user's locale for
+/// timestamps more than a week old or in the future.
+pub fn format_relative_timestamp(
+    timestamp: OffsetDateTime,
+    reference: OffsetDateTime,
+    timezone: UtcOffset,
+    cx: &AppContext,
+) -> SharedString {
+    let elapsed = reference - timestamp;
+    // Timestamps slightly in the future are usually clock skew between machines.
+    if elapsed >= -time::Duration::MINUTE {
+        let minutes = elapsed.whole_minutes();
+        if minutes <= 0 {
+            return crate::t!(cx, "timestamp.just_now");
+        } else if minutes < 60 {
+            return crate::t!(cx, "timestamp.minutes_ago", count = minutes as u64);
+        }
+        let hours = elapsed.whole_hours();
+        if hours < 24 {
+            return crate::t!(cx, "timestamp.hours_ago", count = hours as u64);
+        }
+        let days = (reference.to_offset(timezone).date() - timestamp.to_offset(timezone).date())
+            .whole_days();
+        if days == 1 {
+            return crate::t!(cx, "timestamp.yesterday");
+        } else if days < 7 {
+            return crate::t!(cx, "timestamp.days_ago", count = days as u64);
+        }
+    }
+    format_localized_timestamp(
+        timestamp,
+        reference,
+        timezone,
+        TimestampFormat::MediumAbsolute,
+    )
+    .into()
+}
+
+/// Returns whether the text of [`format_relative_timestamp`] still changes as time
+/// passes, rather than showing an absolute date.
+fn relative_timestamp_changes(timestamp: OffsetDateTime, reference: OffsetDateTime) -> bool {
+    let elapsed = reference - timestamp;
+    elapsed >= -time::Duration::MINUTE && elapsed < time::Duration::WEEK
+}
+
+impl RenderOnce for TimestampLabel {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let timezone = local_timezone();
+        let timestamp = to_offset_date_time(&self.date, timezone);
+        let now = OffsetDateTime::now_utc();
+
+        let (text, changes) = match self.format {
+            TimestampLabelFormat::Distance => (
+                format_distance_from_now(self.date, false, true, false).into(),
+                true,
+            ),
+            TimestampLabelFormat::Relative => (
+                format_relative_timestamp(timestamp, now, timezone, cx),
+                relative_timestamp_changes(timestamp, now),
+            ),
+        };
+
+        // Views are only known once laid out, so ticks are requested on prepaint.
+        let label = div()
+            .relative()
+            .child(
+                Label::new(text)
+                    .size(self.size)
+                    .color(self.color)
+                    .single_line(),
+            )
+            .when(changes, |this| {
+                this.child(
+                    canvas(|_, cx| request_time_ago_tick(cx), |_, _, _| {})
+                        .absolute()
+                        .size_full(),
+                )
+            });
+
+        match self.tooltip_id {
+            Some(id) => {
+                let precise_time =
+                    format_localized_timestamp(timestamp, now, timezone, TimestampFormat::Absolute);
+                label
+                    .id(id)
+                    .tooltip(move |cx| Tooltip::text(precise_time.clone(), cx))
+                    .into_any_element()
+            }
+            None => label.into_any_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[gpui::test]
+    fn test_format_relative_timestamp(cx: &mut TestAppContext) {
+        let reference = datetime!(2024-03-15 18:30:00 UTC);
+        cx.update(|cx| {
+            let format = |timestamp| {
+                format_relative_timestamp(timestamp, reference, UtcOffset::UTC, cx).to_string()
+            };
+
+            assert_eq!(format(datetime!(2024-03-15 18:29:40 UTC)), "just now");
+            assert_eq!(format(datetime!(2024-03-15 18:30:30 UTC)), "just now");
+            assert_eq!(format(datetime!(2024-03-15 18:28:00 UTC)), "2 min ago");
+            assert_eq!(format(datetime!(2024-03-15 15:00:00 UTC)), "3 hr ago");
+            assert_eq!(format(datetime!(2024-03-14 12:00:00 UTC)), "yesterday");
+            assert_eq!(format(datetime!(2024-03-11 12:00:00 UTC)), "4 days ago");
+        });
+    }
+
+    #[test]
+    fn test_relative_timestamp_changes() {
+        let reference = datetime!(2024-03-15 18:30:00 UTC);
+        let changes = |timestamp| relative_timestamp_changes(timestamp, reference);
+
+        assert!(changes(datetime!(2024-03-15 18:29:40 UTC)));
+        assert!(changes(datetime!(2024-03-11 12:00:00 UTC)));
+        assert!(!changes(datetime!(2024-03-01 12:00:00 UTC)));
+        assert!(!changes(datetime!(2024-03-15 19:30:00 UTC)));
+    }
+}
@@ -1,11 +1,17 @@
 mod list;
 mod list_header;
 mod list_item;
+mod list_section;
 mod list_separator;
 mod list_sub_header;
+mod reorderable_list;
+mod selection_model;
 
 pub use list::*;
 pub use list_header::*;
 pub use list_item::*;
+pub use list_section::*;
 pub use list_separator::*;
 pub use list_sub_header::*;
+pub use reorderable_list::*;
+pub use selection_model::*;
@@ -1,9 +1,15 @@
 #![allow(missing_docs)]
 
-use gpui::AnyElement;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use collections::{HashMap, HashSet};
+use gpui::{AnyElement, FocusHandle, ScrollHandle};
 use smallvec::SmallVec;
 
-use crate::{prelude::*, v_flex, Label, ListHeader};
+use crate::{prelude::*, v_flex, Label, ListHeader, ListSection};
+
+use super::list_section::render_sections;
 
 pub enum EmptyMessage {
     Text(SharedString),
@@ -18,6 +24,158 @@ pub struct List {
     header: Option<ListHeader>,
     toggle: Option<bool>,
     children: SmallVec<[AnyElement; 2]>,
+    keyed_items: Option<KeyedItems>,
+    sections: Option<(ScrollHandle, Vec<ListSection>)>,
+    new_item_row: Option<AnyElement>,
+}
+
+type RenderKeyedItem = Box<dyn FnOnce(bool, &mut WindowContext) -> AnyElement>;
+
+struct KeyedItems {
+    state: KeyedListState,
+    items: Vec<(SharedString, RenderKeyedItem)>,
+}
+
+#[derive(Default)]
+struct KeyedListItems {
+    keys: Vec<SharedString>,
+    selected: Option<SharedString>,
+    focus_handles: HashMap<SharedString, FocusHandle>,
+    scroll_handle: ScrollHandle,
+}
+
+/// The selection, focus and scroll position of a [`List`] of
+/// [keyed items](List::keyed_items), held by the view rendering the list.
+///
+/// They follow the items by key when the items change, e.g. when they are refreshed
+/// from a background task, rather than staying at the same index.
+#[derive(Clone, Default)]
+pub struct KeyedListState(Rc<RefCell<KeyedListItems>>);
+
+impl KeyedListState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The handle of the scrollable container of the items.
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.0.borrow().scroll_handle.clone()
+    }
+
+    pub fn selected_key(&self) -> Option<SharedString> {
+        self.0.borrow().selected.clone()
+    }
+
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.0.borrow().selected.as_deref() == Some(key)
+    }
+
+    pub fn select(&self, key: impl Into<SharedString>) {
+        self.0.borrow_mut().selected = Some(key.into());
+    }
+
+    pub fn clear_selection(&self) {
+        self.0.borrow_mut().selected = None;
+    }
+
+    /// Selects the item after the selected one, or the first item.
+    pub fn select_next(&self) {
+        self.select_by_offset(1);
+    }
+
+    /// Selects the item before the selected one, or the last item.
+    pub fn select_previous(&self) {
+        self.select_by_offset(-1);
+    }
+
+    fn select_by_offset(&self, offset: isize) {
+        let mut items = self.0.borrow_mut();
+        let count = items.keys.len() as isize;
+        if count == 0 {
+            return;
+        }
+        let selected_ix = items
+            .selected
+            .as_ref()
+            .and_then(|selected| items.keys.iter().position(|key| key == selected));
+        let ix = match selected_ix {
+            Some(ix) => (ix as isize + offset).clamp(0, count - 1),
+            None if offset > 0 => 0,
+            None => count - 1,
+        };
+        items.selected = Some(items.keys[ix as usize].clone());
+    }
+
+    /// Returns a focus handle for the item with the given key, which the list gives
+    /// to the item's container. The handle stays with the key when the items change.
+    pub fn focus_handle(&self, key: impl Into<SharedString>, cx: &WindowContext) -> FocusHandle {
+        self.0
+            .borrow_mut()
+            .focus_handles
+            .entry(key.into())
+            .or_insert_with(|| cx.focus_handle())
+            .clone()
+    }
+
+    /// Replaces the keys of the items, keeping the selection, focus and scroll
+    /// position on the same items.
+    fn reconcile(&self, keys: Vec<SharedString>, cx: &mut WindowContext) {
+        let mut items = self.0.borrow_mut();
+        if items.keys == keys {
+            return;
+        }
+
+        let (top_ix, top_offset) = items.scroll_handle.logical_scroll_top();
+        let scroll_anchor = items.keys.get(top_ix).cloned();
+
+        let new_keys = keys.iter().cloned().collect::<HashSet<_>>();
+        let selected = reconcile_selection(&items.keys, items.selected.as_ref(), &new_keys);
+        let lost_focus = items
+            .focus_handles
+            .iter()
+            .any(|(key, handle)| !new_keys.contains(key) && handle.contains_focused(cx));
+        items.focus_handles.retain(|key, _| new_keys.contains(key));
+        if lost_focus {
+            if let Some(handle) = selected
+                .as_ref()
+                .and_then(|selected| items.focus_handles.get(selected))
+            {
+                let handle = handle.clone();
+                cx.defer(move |cx| cx.focus(&handle));
+            }
+        }
+        items.selected = selected;
+
+        if let Some(new_top_ix) = scroll_anchor
+            .and_then(|anchor| keys.iter().position(|key| *key == anchor))
+            .filter(|new_top_ix| *new_top_ix != top_ix)
+        {
+            items
+                .scroll_handle
+                .set_logical_scroll_top(new_top_ix, top_offset);
+        }
+        items.keys = keys;
+    }
+}
+
+/// Returns the key to select once the items change from `old_keys` to `new_keys`:
+/// the selected key while its item remains, then the nearest remaining item after
+/// it, then the nearest before it.
+fn reconcile_selection(
+    old_keys: &[SharedString],
+    selected: Option<&SharedString>,
+    new_keys: &HashSet<SharedString>,
+) -> Option<SharedString> {
+    let selected = selected?;
+    if new_keys.contains(selected) {
+        return Some(selected.clone());
+    }
+    let old_ix = old_keys.iter().position(|key| key == selected)?;
+    old_keys[old_ix + 1..]
+        .iter()
+        .chain(old_keys[..old_ix].iter().rev())
+        .find(|key| new_keys.contains(*key))
+        .cloned()
 }
 
 impl Default for List {
@@ -33,6 +191,9 @@ impl List {
             header: None,
             toggle: None,
             children: SmallVec::new(),
+            keyed_items: None,
+            sections: None,
+            new_item_row: None,
         }
     }
 
@@ -50,6 +211,87 @@ impl List {
         self.toggle = toggle.into();
         self
     }
+
+    /// Shows items identified by key after the children, in a scrollable container.
+    /// The selection, focus and scroll position are kept in the state by key, so that
+    /// they stay on the same items when the items are refreshed.
+    ///
+    /// Items are rendered with whether they are selected, and clicking one selects it.
+    pub fn keyed_items<T: 'static>(
+        mut self,
+        state: &KeyedListState,
+        items: impl IntoIterator<Item = T>,
+        key: impl Fn(&T) -> SharedString,
+        render_item: impl Fn(T, bool, &mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        let render_item = Rc::new(render_item);
+        let items = items
+            .into_iter()
+            .map(|item| {
+                let key = key(&item);
+                let render_item = render_item.clone();
+                let render: RenderKeyedItem =
+                    Box::new(move |selected, cx| render_item(item, selected, cx));
+                (key, render)
+            })
+            .collect();
+        self.keyed_items = Some(KeyedItems {
+            state: state.clone(),
+            items,
+        });
+        self
+    }
+
+    /// Shows groups of items under headers after the children, in a scrollable
+    /// container tracked by `scroll_handle`. The header of the section at the top
+    /// sticks there while the section scrolls, and is pushed out of the way by the
+    /// header of the next one.
+    pub fn sections(
+        mut self,
+        scroll_handle: &ScrollHandle,
+        sections: impl IntoIterator<Item = ListSection>,
+    ) -> Self {
+        self.sections = Some((scroll_handle.clone(), sections.into_iter().collect()));
+        self
+    }
+
+    /// Appends a row to name a new item in place, such as `ui_input::NewItemRow`.
+    /// The row is shown after the items, instead of the empty message.
+    pub fn new_item_row<E: IntoElement>(mut self, row: impl Into<Option<E>>) -> Self {
+        self.new_item_row = row.into().map(IntoElement::into_any_element);
+        self
+    }
+}
+
+fn render_keyed_items(keyed_items: KeyedItems, cx: &mut WindowContext) -> AnyElement {
+    let KeyedItems { state, items } = keyed_items;
+    state.reconcile(items.iter().map(|(key, _)| key.clone()).collect(), cx);
+
+    let selected = state.selected_key();
+    let (scroll_handle, focus_handles) = {
+        let items = state.0.borrow();
+        (items.scroll_handle.clone(), items.focus_handles.clone())
+    };
+    v_flex()
+        .id("keyed-list-items")
+        .size_full()
+        .overflow_y_scroll()
+        .track_scroll(&scroll_handle)
+        .children(items.into_iter().map(|(key, render)| {
+            let is_selected = selected.as_ref() == Some(&key);
+            let state = state.clone();
+            div()
+                .id(ElementId::Name(key.clone()))
+                .when_some(focus_handles.get(&key), |this, handle| {
+                    this.track_focus(handle)
+                })
+                .on_click(move |_, cx| {
+                    state.select(key.clone());
+                    cx.refresh();
+                })
+                .child(render(is_selected, cx))
+        }))
+        .into_any_element()
 }
 
 impl ParentElement for List {
@@ -78,12 +320,34 @@ impl From<AnyElement> for EmptyMessage {
 
 impl RenderOnce for List {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let is_empty = self.children.is_empty()
+            && self.new_item_row.is_none()
+            && self
+                .keyed_items
+                .as_ref()
+                .map_or(true, |keyed_items| keyed_items.items.is_empty())
+            && self
+                .sections
+                .as_ref()
+                .map_or(true, |(_, sections)| sections.is_empty());
+        // Keyed items are reconciled even when there are none, to clear the selection.
+        let keyed_items = self
+            .keyed_items
+            .map(|keyed_items| render_keyed_items(keyed_items, cx));
+        let sections = self
+            .sections
+            .map(|(scroll_handle, sections)| render_sections(scroll_handle, sections, cx));
+
         v_flex()
             .w_full()
             .py(Spacing::Small.rems(cx))
             .children(self.header)
-            .map(|this| match (self.children.is_empty(), self.toggle) {
-                (false, _) => this.children(self.children),
+            .map(|this| match (is_empty, self.toggle) {
+                (false, _) => this
+                    .children(self.children)
+                    .children(keyed_items)
+                    .children(sections)
+                    .children(self.new_item_row),
                 (true, Some(false)) => this,
                 (true, _) => match self.empty_message {
                     EmptyMessage::Text(text) => this.child(Label::new(text).color(Color::Muted)),
@@ -92,3 +356,37 @@ impl RenderOnce for List {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconcile_selection() {
+        let keys = |keys: &[&'static str]| {
+            keys.iter()
+                .map(|key| SharedString::from(*key))
+                .collect::<Vec<_>>()
+        };
+        let old_keys = keys(&["a", "b", "c", "d"]);
+        let selected = SharedString::from("b");
+        let reconcile = |new_keys: &[&'static str]| {
+            reconcile_selection(
+                &old_keys,
+                Some(&selected),
+                &keys(new_keys).into_iter().collect(),
+            )
+        };
+
+        // The selection follows its item when items move.
+        assert_eq!(reconcile(&["d", "c", "b", "a"]), Some("b".into()));
+        // Then goes to the next remaining item, then the previous one.
+        assert_eq!(reconcile(&["a", "d"]), Some("d".into()));
+        assert_eq!(reconcile(&["a"]), Some("a".into()));
+        assert_eq!(reconcile(&[]), None);
+        assert_eq!(
+            reconcile_selection(&old_keys, None, &keys(&["a"]).into_iter().collect()),
+            None
+        );
+    }
+}
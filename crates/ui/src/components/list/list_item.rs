@@ -2,10 +2,16 @@
 
 use std::sync::Arc;
 
-use gpui::{px, AnyElement, AnyView, ClickEvent, MouseButton, MouseDownEvent, Pixels};
+use gpui::{
+    px, AccessibilityProperties, AccessibilityRole, AnyElement, AnyView, ClickEvent, ImageSource,
+    MouseButton, MouseDownEvent, Pixels,
+};
 use smallvec::SmallVec;
 
-use crate::{prelude::*, Disclosure};
+use crate::{
+    prelude::*, utils::GestureHandlers, Disclosure, IndentGuideColors, InteractiveColors,
+    InteractiveState, InteractiveStates, Thumbnail,
+};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
 pub enum ListItemSpacing {
@@ -19,9 +25,11 @@ pub struct ListItem {
     id: ElementId,
     disabled: bool,
     selected: bool,
+    focused: bool,
     spacing: ListItemSpacing,
     indent_level: usize,
     indent_step_size: Pixels,
+    indent_guides: bool,
     /// A slot for content that appears before the children, like an icon or avatar.
     start_slot: Option<AnyElement>,
     /// A slot for content that appears after the children, usually on the other side of the header.
@@ -30,15 +38,26 @@ pub struct ListItem {
     /// A slot for content that appears on hover after the children
     /// It will obscure the `end_slot` when visible.
     end_hover_slot: Option<AnyElement>,
+    /// The main text of the item, shown after the start slot.
+    label: Option<SharedString>,
+    /// Muted text shown after the label, like the directory of a file.
+    secondary_label: Option<SharedString>,
+    /// Muted text shown at the end of the item, like a count or a timestamp.
+    meta: Option<SharedString>,
+    /// Buttons shown at the end of the item while it's hovered or selected.
+    actions: SmallVec<[AnyElement; 2]>,
     toggle: Option<bool>,
     inset: bool,
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    gestures: GestureHandlers,
     on_toggle: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     tooltip: Option<Box<dyn Fn(&mut WindowContext) -> AnyView + 'static>>,
     on_secondary_mouse_down: Option<Box<dyn Fn(&MouseDownEvent, &mut WindowContext) + 'static>>,
+    on_hover: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
     children: SmallVec<[AnyElement; 2]>,
     selectable: bool,
     overflow_x: bool,
+    test_id: Option<SharedString>,
 }
 
 impl ListItem {
@@ -47,29 +66,52 @@ impl ListItem {
             id: id.into(),
             disabled: false,
             selected: false,
+            focused: true,
             spacing: ListItemSpacing::Dense,
             indent_level: 0,
             indent_step_size: px(12.),
+            indent_guides: false,
             start_slot: None,
             end_slot: None,
             end_hover_slot: None,
+            label: None,
+            secondary_label: None,
+            meta: None,
+            actions: SmallVec::new(),
             toggle: None,
             inset: false,
             on_click: None,
+            gestures: GestureHandlers::default(),
             on_secondary_mouse_down: None,
+            on_hover: None,
             on_toggle: None,
             tooltip: None,
             children: SmallVec::new(),
             selectable: true,
             overflow_x: false,
+            test_id: None,
         }
     }
 
+    /// Attaches a stable identifier for tests to locate the item by. See
+    /// [`InteractiveElement::test_id`].
+    pub fn test_id(mut self, id: impl Into<SharedString>) -> Self {
+        self.test_id = Some(id.into());
+        self
+    }
+
     pub fn spacing(mut self, spacing: ListItemSpacing) -> Self {
         self.spacing = spacing;
         self
     }
 
+    /// Whether the list of the item has focus. A selected item of a list without
+    /// focus is shown with a fainter background. Defaults to `true`.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     pub fn selectable(mut self, has_hover: bool) -> Self {
         self.selectable = has_hover;
         self
@@ -88,6 +130,12 @@ impl ListItem {
         self
     }
 
+    /// Called with whether the pointer is over the item when it enters or leaves it.
+    pub fn on_hover(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_hover = Some(Box::new(handler));
+        self
+    }
+
     pub fn tooltip(mut self, tooltip: impl Fn(&mut WindowContext) -> AnyView + 'static) -> Self {
         self.tooltip = Some(Box::new(tooltip));
         self
@@ -108,6 +156,14 @@ impl ListItem {
         self
     }
 
+    /// Draws a guide line for each level of indentation, in the colors of the
+    /// project panel's guides, so that the lines of consecutive nested items join
+    /// into the same guides as in a tree.
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = indent_guides;
+        self
+    }
+
     pub fn toggle(mut self, toggle: impl Into<Option<bool>>) -> Self {
         self.toggle = toggle.into();
         self
@@ -126,6 +182,12 @@ impl ListItem {
         self
     }
 
+    /// Shows a [`Thumbnail`] of the given image in the start slot.
+    pub fn start_thumbnail(mut self, source: impl Into<ImageSource>) -> Self {
+        self.start_slot = Some(Thumbnail::new(source).height(rems(1.25)).into_any_element());
+        self
+    }
+
     pub fn end_slot<E: IntoElement>(mut self, end_slot: impl Into<Option<E>>) -> Self {
         self.end_slot = end_slot.into().map(IntoElement::into_any_element);
         self
@@ -136,6 +198,49 @@ impl ListItem {
         self
     }
 
+    /// Shows an icon in the start slot.
+    pub fn start_icon(mut self, icon: IconName) -> Self {
+        self.start_slot = Some(
+            Icon::new(icon)
+                .size(IconSize::Small)
+                .color(Color::Muted)
+                .into_any_element(),
+        );
+        self
+    }
+
+    /// Sets the main text of the item, shown before its children.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets muted text shown after the label, like the path of a file.
+    pub fn secondary_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.secondary_label = Some(label.into());
+        self
+    }
+
+    /// Sets muted text shown at the end of the item, like a count or a timestamp.
+    pub fn meta(mut self, meta: impl Into<SharedString>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    /// Adds a button to the inline actions, like "pin" or "close", shown at the end
+    /// of the item while it's hovered or selected. Their space is kept while they're
+    /// hidden, so that the item doesn't shift when they appear.
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.actions.push(action.into_any_element());
+        self
+    }
+
+    pub fn actions<E: IntoElement>(mut self, actions: impl IntoIterator<Item = E>) -> Self {
+        self.actions
+            .extend(actions.into_iter().map(IntoElement::into_any_element));
+        self
+    }
+
     pub fn overflow_x(mut self) -> Self {
         self.overflow_x = true;
         self
@@ -162,10 +267,38 @@ impl ParentElement for ListItem {
     }
 }
 
+impl Gestures for ListItem {
+    fn gesture_handlers(&mut self) -> &mut GestureHandlers {
+        &mut self.gestures
+    }
+}
+
 impl RenderOnce for ListItem {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        h_flex()
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        let gestures = if self.disabled {
+            GestureHandlers::default()
+        } else {
+            std::mem::take(&mut self.gestures)
+        };
+        let id = self.id.clone();
+        let colors = InteractiveColors::row(self.focused, cx);
+        let state = InteractiveState {
+            disabled: self.disabled,
+            selected: self.selected,
+            focused: false,
+        };
+        let states =
+            InteractiveStates::HOVER | InteractiveStates::ACTIVE | InteractiveStates::SELECTED;
+
+        let item = h_flex()
             .id(self.id)
+            .accessibility(
+                AccessibilityProperties::new(AccessibilityRole::ListItem)
+                    .selected(self.selected)
+                    .expanded(self.toggle)
+                    .disabled(self.disabled),
+            )
+            .when_some(self.test_id, |this, id| this.test_id(id))
             .w_full()
             .relative()
             // When an item is inset draw the indent spacing outside of the item
@@ -173,6 +306,29 @@ impl RenderOnce for ListItem {
                 this.ml(self.indent_level as f32 * self.indent_step_size)
                     .px(Spacing::Small.rems(cx))
             })
+            .when(self.indent_guides && self.indent_level > 0, |this| {
+                let guide_color = IndentGuideColors::panel(cx).default;
+                // Inset items are indented by a margin outside of them, non-inset
+                // ones by a margin inside of their padding.
+                let indent_start = if self.inset {
+                    -(self.indent_step_size * self.indent_level as f32)
+                } else {
+                    Spacing::Medium.rems(cx).to_pixels(cx.rem_size())
+                };
+                this.children((0..self.indent_level).map(|level| {
+                    div()
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .left(
+                            indent_start
+                                + self.indent_step_size * level as f32
+                                + self.indent_step_size * 0.5,
+                        )
+                        .w_px()
+                        .bg(guide_color)
+                }))
+            })
             .when(!self.inset && !self.disabled, |this| {
                 this
                     // TODO: Add focus state
@@ -180,13 +336,7 @@ impl RenderOnce for ListItem {
                     //     this.border_1()
                     //         .border_color(cx.theme().colors().border_focused)
                     // })
-                    .when(self.selectable, |this| {
-                        this.hover(|style| style.bg(cx.theme().colors().ghost_element_hover))
-                            .active(|style| style.bg(cx.theme().colors().ghost_element_active))
-                            .when(self.selected, |this| {
-                                this.bg(cx.theme().colors().ghost_element_selected)
-                            })
-                    })
+                    .when(self.selectable, |this| state.apply(this, &colors, states))
             })
             .child(
                 h_flex()
@@ -198,7 +348,7 @@ impl RenderOnce for ListItem {
                     .px(Spacing::Medium.rems(cx))
                     .map(|this| match self.spacing {
                         ListItemSpacing::Dense => this,
-                        ListItemSpacing::Sparse => this.py_1(),
+                        ListItemSpacing::Sparse => this.py(Spacing::Small.rems(cx)),
                     })
                     .group("list_item")
                     .when(self.inset && !self.disabled, |this| {
@@ -208,15 +358,7 @@ impl RenderOnce for ListItem {
                             //     this.border_1()
                             //         .border_color(cx.theme().colors().border_focused)
                             // })
-                            .when(self.selectable, |this| {
-                                this.hover(|style| {
-                                    style.bg(cx.theme().colors().ghost_element_hover)
-                                })
-                                .active(|style| style.bg(cx.theme().colors().ghost_element_active))
-                                .when(self.selected, |this| {
-                                    this.bg(cx.theme().colors().ghost_element_selected)
-                                })
-                            })
+                            .when(self.selectable, |this| state.apply(this, &colors, states))
                     })
                     .when_some(self.on_click, |this, on_click| {
                         this.cursor_pointer().on_click(on_click)
@@ -226,6 +368,7 @@ impl RenderOnce for ListItem {
                             (on_mouse_down)(event, cx)
                         })
                     })
+                    .when_some(self.on_hover, |this, on_hover| this.on_hover(on_hover))
                     .when_some(self.tooltip, |this, tooltip| this.tooltip(tooltip))
                     .map(|this| {
                         if self.inset {
@@ -257,8 +400,51 @@ impl RenderOnce for ListItem {
                                 }
                             })
                             .children(self.start_slot)
+                            .when(
+                                self.label.is_some() || self.secondary_label.is_some(),
+                                |this| {
+                                    this.child(
+                                        h_flex()
+                                            .min_w_0()
+                                            .gap(Spacing::Small.rems(cx))
+                                            .children(
+                                                self.label
+                                                    .map(|label| Label::new(label).single_line()),
+                                            )
+                                            .children(self.secondary_label.map(|label| {
+                                                Label::new(label)
+                                                    .size(LabelSize::Small)
+                                                    .color(Color::Muted)
+                                                    .single_line()
+                                            })),
+                                    )
+                                },
+                            )
                             .children(self.children),
                     )
+                    .when(self.meta.is_some() || !self.actions.is_empty(), |this| {
+                        this.child(
+                            h_flex()
+                                .flex_none()
+                                .gap(Spacing::Small.rems(cx))
+                                .children(self.meta.map(|meta| {
+                                    Label::new(meta)
+                                        .size(LabelSize::Small)
+                                        .color(Color::Muted)
+                                        .single_line()
+                                }))
+                                .when(!self.actions.is_empty(), |this| {
+                                    this.child(
+                                        h_flex()
+                                            .gap(Spacing::XSmall.rems(cx))
+                                            .when(!self.selected, |this| {
+                                                this.visible_on_hover("list_item")
+                                            })
+                                            .children(self.actions),
+                                    )
+                                }),
+                        )
+                    })
                     .when_some(self.end_slot, |this, end_slot| {
                         this.justify_between().child(
                             h_flex()
@@ -282,6 +468,7 @@ impl RenderOnce for ListItem {
                                 .child(end_hover_slot),
                         )
                     }),
-            )
+            );
+        gestures.wrap(id, item)
     }
 }
@@ -0,0 +1,214 @@
+#![allow(missing_docs)]
+
+use gpui::{
+    point, size, AnyElement, AvailableSpace, Bounds, ContentMask, GlobalElementId, LayoutId,
+    ScrollHandle, Style,
+};
+use smallvec::SmallVec;
+
+use crate::{prelude::*, ListSubHeader};
+
+/// A group of items of a [`List`](crate::List) under a header, like the results
+/// of a search for one kind of file. See [`List::sections`](crate::List::sections).
+pub struct ListSection {
+    header: ListSubHeader,
+    children: SmallVec<[AnyElement; 4]>,
+}
+
+impl ListSection {
+    pub fn new(header: ListSubHeader) -> Self {
+        Self {
+            header,
+            children: SmallVec::new(),
+        }
+    }
+}
+
+impl ParentElement for ListSection {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+/// Returns the section whose header sticks to the top of the list, and how far
+/// the header is pushed up by the header of the next section, given the tops of
+/// the sections relative to the top of the viewport.
+///
+/// No header sticks while the header of the first visible section is in view.
+pub fn layout_sticky_section_header(
+    section_tops: &[Pixels],
+    header_height: Pixels,
+) -> Option<(usize, Pixels)> {
+    let ix = section_tops.iter().rposition(|top| *top < Pixels::ZERO)?;
+    let offset = match section_tops.get(ix + 1) {
+        Some(next_top) if *next_top < header_height => *next_top - header_height,
+        _ => Pixels::ZERO,
+    };
+    Some((ix, offset))
+}
+
+/// Renders the sections of a list in a scrollable container, the header of the
+/// section at the top sticking there while the section scrolls.
+pub(crate) fn render_sections(
+    scroll_handle: ScrollHandle,
+    sections: Vec<ListSection>,
+    cx: &WindowContext,
+) -> AnyElement {
+    let background = cx.theme().colors().panel_background;
+    let sticky_headers = sections
+        .iter()
+        .map(|section| {
+            div()
+                .w_full()
+                .bg(background)
+                .occlude()
+                .child(section.header.clone())
+                .into_any_element()
+        })
+        .collect();
+
+    div()
+        .relative()
+        .size_full()
+        .child(
+            v_flex()
+                .id("list-sections")
+                .size_full()
+                .overflow_y_scroll()
+                .track_scroll(&scroll_handle)
+                .children(sections.into_iter().map(|section| {
+                    v_flex()
+                        .w_full()
+                        .child(section.header)
+                        .children(section.children)
+                })),
+        )
+        .child(StickySectionHeader {
+            scroll_handle,
+            headers: sticky_headers,
+            sticky_header: None,
+        })
+        .into_any_element()
+}
+
+/// Draws the header of the section at the top of a list over it.
+///
+/// The header is picked at prepaint, once the scrolled sections are laid out,
+/// so that it follows the scroll position of the same frame.
+struct StickySectionHeader {
+    scroll_handle: ScrollHandle,
+    headers: Vec<AnyElement>,
+    sticky_header: Option<AnyElement>,
+}
+
+impl IntoElement for StickySectionHeader {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for StickySectionHeader {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            position: gpui::Position::Absolute,
+            inset: gpui::Edges {
+                top: px(0.).into(),
+                left: px(0.).into(),
+                ..Default::default()
+            },
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Style::default()
+        };
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        let viewport_top = self.scroll_handle.bounds().top();
+        let section_tops = (0..self.headers.len())
+            .map_while(|ix| self.scroll_handle.bounds_for_item(ix))
+            .map(|section_bounds| section_bounds.top() - viewport_top)
+            .collect::<Vec<_>>();
+        let Some(ix) = section_tops.iter().rposition(|top| *top < Pixels::ZERO) else {
+            return;
+        };
+
+        let mut header = self.headers.swap_remove(ix);
+        let header_size = header.layout_as_root(
+            size(
+                AvailableSpace::Definite(bounds.size.width),
+                AvailableSpace::MinContent,
+            ),
+            cx,
+        );
+        if let Some((_, offset)) = layout_sticky_section_header(&section_tops, header_size.height) {
+            header.prepaint_at(point(bounds.left(), bounds.top() + offset), cx);
+            self.sticky_header = Some(header);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(header) = self.sticky_header.as_mut() {
+            cx.with_content_mask(Some(ContentMask { bounds }), |cx| header.paint(cx));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_sticky_section_header() {
+        let header_height = px(24.);
+
+        // The first section starts in view, so its own header is visible.
+        assert_eq!(
+            layout_sticky_section_header(&[px(0.), px(200.)], header_height),
+            None
+        );
+
+        // The first section scrolled past its header.
+        assert_eq!(
+            layout_sticky_section_header(&[px(-50.), px(150.)], header_height),
+            Some((0, px(0.)))
+        );
+
+        // The next section's header pushes the sticky one up.
+        assert_eq!(
+            layout_sticky_section_header(&[px(-190.), px(10.)], header_height),
+            Some((0, px(-14.)))
+        );
+
+        // Then it sticks in turn.
+        assert_eq!(
+            layout_sticky_section_header(&[px(-400.), px(-10.), px(300.)], header_height),
+            Some((1, px(0.)))
+        );
+    }
+}
@@ -3,7 +3,7 @@
 use crate::prelude::*;
 use crate::{h_flex, Icon, IconName, IconSize, Label};
 
-#[derive(IntoElement)]
+#[derive(IntoElement, Clone)]
 pub struct ListSubHeader {
     label: SharedString,
     start_slot: Option<IconName>,
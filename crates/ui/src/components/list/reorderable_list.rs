@@ -0,0 +1,365 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use gpui::{
+    point, AnimationExt, AnyElement, Bounds, DragMoveEvent, Pixels, Render, ScrollHandle, Task,
+};
+use theme::{transition, MotionDuration, MotionEasing};
+
+use crate::prelude::*;
+
+/// How close to the top or bottom edge of the list the dragged item must be for
+/// the list to scroll.
+const AUTO_SCROLL_EDGE: f32 = 32.;
+/// How far the list scrolls per frame when the dragged item is at its edge.
+const AUTO_SCROLL_MAX_SPEED: f32 = 12.;
+const AUTO_SCROLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// A move of an item of a [`ReorderableList`], committed when the item is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderEvent {
+    pub old_index: usize,
+    pub new_index: usize,
+}
+
+/// Returns the index an item moves to when dropped before the item at
+/// `drop_index`, or `None` if dropping it there leaves it in place.
+fn reordered_index(old_index: usize, drop_index: usize) -> Option<usize> {
+    let new_index = if drop_index > old_index {
+        drop_index - 1
+    } else {
+        drop_index
+    };
+    (new_index != old_index).then_some(new_index)
+}
+
+/// Returns how far the list should scroll per frame with the pointer at `y`:
+/// negative near its top edge, positive near its bottom edge, faster closer to
+/// the edge, and `None` away from the edges.
+fn auto_scroll_speed(y: Pixels, bounds: &Bounds<Pixels>) -> Option<Pixels> {
+    let edge = px(AUTO_SCROLL_EDGE).min(bounds.size.height / 2.);
+    let speed = |distance: Pixels| px(AUTO_SCROLL_MAX_SPEED) * (1. - (distance / edge).max(0.));
+    if y < bounds.top() + edge {
+        Some(-speed(y - bounds.top()))
+    } else if y > bounds.bottom() - edge {
+        Some(speed(bounds.bottom() - y))
+    } else {
+        None
+    }
+}
+
+#[derive(Default)]
+struct ReorderState {
+    scroll_handle: ScrollHandle,
+    item_count: usize,
+    /// The index of the item the dragged item would be dropped before, or the
+    /// number of items to drop it after the last one.
+    drop_index: Option<usize>,
+    auto_scroll_speed: Option<Pixels>,
+    _auto_scroll_task: Option<Task<()>>,
+}
+
+/// The drop position and scroll position of a [`ReorderableList`], held by the
+/// view rendering the list.
+#[derive(Clone, Default)]
+pub struct ReorderableListState(Rc<RefCell<ReorderState>>);
+
+impl ReorderableListState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The handle of the scrollable container of the items.
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.0.borrow().scroll_handle.clone()
+    }
+
+    fn set_drop_index(&self, drop_index: Option<usize>, cx: &mut WindowContext) {
+        let mut state = self.0.borrow_mut();
+        if state.drop_index != drop_index {
+            state.drop_index = drop_index;
+            cx.refresh();
+        }
+    }
+
+    fn end_drag(&self) -> Option<usize> {
+        let mut state = self.0.borrow_mut();
+        state.auto_scroll_speed = None;
+        state._auto_scroll_task = None;
+        state.drop_index.take()
+    }
+
+    /// Scrolls while the dragged item is near an edge of the list, including
+    /// when the pointer stops moving.
+    fn set_auto_scroll_speed(&self, speed: Option<Pixels>, cx: &mut WindowContext) {
+        let mut state = self.0.borrow_mut();
+        state.auto_scroll_speed = speed;
+        if speed.is_none() {
+            state._auto_scroll_task = None;
+        } else if state._auto_scroll_task.is_none() {
+            let this = Rc::downgrade(&self.0);
+            state._auto_scroll_task = Some(cx.spawn(|mut cx| async move {
+                loop {
+                    cx.background_executor().timer(AUTO_SCROLL_INTERVAL).await;
+                    let scrolled = cx.update(|cx| Self::auto_scroll(&this, cx));
+                    if !scrolled.unwrap_or(false) {
+                        break;
+                    }
+                }
+            }));
+        }
+    }
+
+    fn auto_scroll(state: &Weak<RefCell<ReorderState>>, cx: &mut WindowContext) -> bool {
+        let Some(state) = state.upgrade() else {
+            return false;
+        };
+        let mut state = state.borrow_mut();
+        let Some(speed) = state.auto_scroll_speed.filter(|_| cx.has_active_drag()) else {
+            state.auto_scroll_speed = None;
+            return false;
+        };
+
+        let handle = &state.scroll_handle;
+        let content_height = handle
+            .bounds_for_item(0)
+            .zip(handle.bounds_for_item(state.item_count.saturating_sub(1)))
+            .map_or(Pixels::ZERO, |(first, last)| last.bottom() - first.top());
+        let max_offset = (content_height - handle.bounds().size.height).max(Pixels::ZERO);
+        let offset = handle.offset();
+        let y = (offset.y - speed).clamp(-max_offset, Pixels::ZERO);
+        if y != offset.y {
+            handle.set_offset(point(offset.x, y));
+            cx.refresh();
+        }
+        true
+    }
+}
+
+/// Identifies the item of a [`ReorderableList`] being dragged, and renders the
+/// preview following the pointer.
+#[derive(Clone)]
+struct DraggedListItem {
+    list: ReorderableListState,
+    index: usize,
+    label: SharedString,
+}
+
+impl DraggedListItem {
+    fn is_from(&self, list: &ReorderableListState) -> bool {
+        Rc::ptr_eq(&self.list.0, &list.0)
+    }
+}
+
+impl Render for DraggedListItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .elevation_2(cx)
+            .child(Label::new(self.label.clone()).size(LabelSize::Small))
+    }
+}
+
+/// A vertical list whose items can be dragged to new positions, e.g. to order
+/// bookmarks, tabs or toolchains.
+///
+/// While an item is dragged, a line shows where it will be dropped, and the list
+/// scrolls when the item is dragged near its top or bottom edge. Dropping the
+/// item calls the [`ReorderableList::on_reorder`] handler, which is expected to
+/// move the item in the data the list is rendered from.
+#[derive(IntoElement)]
+pub struct ReorderableList {
+    id: ElementId,
+    state: ReorderableListState,
+    items: Vec<(SharedString, AnyElement)>,
+    on_reorder: Option<Rc<dyn Fn(&ReorderEvent, &mut WindowContext)>>,
+}
+
+impl ReorderableList {
+    pub fn new(id: impl Into<ElementId>, state: &ReorderableListState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            items: Vec::new(),
+            on_reorder: None,
+        }
+    }
+
+    /// Adds an item, labeled with `label` in the preview shown while it's dragged.
+    pub fn item(mut self, label: impl Into<SharedString>, item: impl IntoElement) -> Self {
+        self.items.push((label.into(), item.into_any_element()));
+        self
+    }
+
+    /// Adds items, as [`ReorderableList::item`] does.
+    pub fn items<L: Into<SharedString>, E: IntoElement>(
+        mut self,
+        items: impl IntoIterator<Item = (L, E)>,
+    ) -> Self {
+        self.items.extend(
+            items
+                .into_iter()
+                .map(|(label, item)| (label.into(), item.into_any_element())),
+        );
+        self
+    }
+
+    pub fn on_reorder(
+        mut self,
+        handler: impl Fn(&ReorderEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(handler));
+        self
+    }
+
+    fn render_drop_indicator(
+        &self,
+        index: usize,
+        at_bottom: bool,
+        cx: &WindowContext,
+    ) -> impl IntoElement {
+        div()
+            .absolute()
+            .left_0()
+            .right_0()
+            .h(px(2.))
+            .map(|this| {
+                if at_bottom {
+                    this.bottom(px(-1.))
+                } else {
+                    this.top(px(-1.))
+                }
+            })
+            .rounded_sm()
+            .bg(cx.theme().colors().border_focused)
+            .with_animation(
+                ("drop-indicator", index),
+                transition(MotionDuration::Fast, MotionEasing::Decelerate, cx),
+                |this, delta| this.opacity(delta),
+            )
+    }
+}
+
+impl RenderOnce for ReorderableList {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let item_count = self.items.len();
+        let (scroll_handle, drop_index) = {
+            let mut state = self.state.0.borrow_mut();
+            state.item_count = item_count;
+            (state.scroll_handle.clone(), state.drop_index)
+        };
+        let drop_index = drop_index.filter(|_| cx.has_active_drag());
+        let indicators = (0..item_count)
+            .map(|ix| {
+                let at_bottom = ix + 1 == item_count && drop_index == Some(item_count);
+                (drop_index == Some(ix) || at_bottom)
+                    .then(|| self.render_drop_indicator(drop_index.unwrap(), at_bottom, cx))
+            })
+            .collect::<Vec<_>>();
+
+        let rows = self.items.into_iter().zip(indicators).enumerate().map(
+            |(ix, ((label, item), indicator))| {
+                let state = self.state.clone();
+                let dragged = DraggedListItem {
+                    list: self.state.clone(),
+                    index: ix,
+                    label,
+                };
+                div()
+                    .id(ix)
+                    .relative()
+                    .on_drag(dragged, |dragged, cx| {
+                        dragged.list.set_drop_index(None, cx);
+                        cx.new_view(|_| dragged.clone())
+                    })
+                    .on_drag_move(move |event: &DragMoveEvent<DraggedListItem>, cx| {
+                        let position = event.event.position;
+                        if !event.drag(cx).is_from(&state)
+                            || position.y < event.bounds.top()
+                            || position.y >= event.bounds.bottom()
+                        {
+                            return;
+                        }
+                        let drop_index = if position.y < event.bounds.center().y {
+                            ix
+                        } else {
+                            ix + 1
+                        };
+                        state.set_drop_index(Some(drop_index), cx);
+                    })
+                    .child(item)
+                    .children(indicator)
+            },
+        );
+
+        let state = self.state.clone();
+        let on_reorder = self.on_reorder.clone();
+        div()
+            .id(self.id)
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle)
+            .on_drag_move({
+                let state = self.state.clone();
+                move |event: &DragMoveEvent<DraggedListItem>, cx| {
+                    if event.drag(cx).is_from(&state) {
+                        let speed = event
+                            .bounds
+                            .contains(&event.event.position)
+                            .then(|| auto_scroll_speed(event.event.position.y, &event.bounds))
+                            .flatten();
+                        state.set_auto_scroll_speed(speed, cx);
+                    }
+                }
+            })
+            .on_drop(move |dragged: &DraggedListItem, cx| {
+                if !dragged.is_from(&state) {
+                    return;
+                }
+                let Some(drop_index) = state.end_drag() else {
+                    return;
+                };
+                cx.refresh();
+                if let Some((new_index, on_reorder)) =
+                    reordered_index(dragged.index, drop_index).zip(on_reorder.as_ref())
+                {
+                    on_reorder(
+                        &ReorderEvent {
+                            old_index: dragged.index,
+                            new_index,
+                        },
+                        cx,
+                    );
+                }
+            })
+            .children(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::size;
+
+    use super::*;
+
+    #[test]
+    fn test_reordered_index() {
+        assert_eq!(reordered_index(1, 1), None);
+        assert_eq!(reordered_index(1, 2), None);
+        assert_eq!(reordered_index(1, 0), Some(0));
+        assert_eq!(reordered_index(1, 4), Some(3));
+        assert_eq!(reordered_index(3, 1), Some(1));
+    }
+
+    #[test]
+    fn test_auto_scroll_speed() {
+        let bounds = Bounds::new(point(px(0.), px(100.)), size(px(200.), px(400.)));
+        assert_eq!(auto_scroll_speed(px(300.), &bounds), None);
+        assert_eq!(auto_scroll_speed(px(100.), &bounds), Some(px(-12.)));
+        assert_eq!(auto_scroll_speed(px(116.), &bounds), Some(px(-6.)));
+        assert_eq!(auto_scroll_speed(px(484.), &bounds), Some(px(6.)));
+    }
+}
@@ -0,0 +1,259 @@
+#![allow(missing_docs)]
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use gpui::{EventEmitter, Modifiers};
+
+/// Whether a [`SelectionModel`] can select more than one item.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    /// Items are added to the selection with the secondary modifier, and ranges of
+    /// items with shift.
+    Multiple,
+}
+
+/// Emitted by a [`SelectionModel`] held in a model after its selection changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionChanged;
+
+/// The selected items of a list, by index, shared by the lists, trees and tables
+/// that let the user select their items.
+///
+/// Clicking an item selects it, clicking it with the secondary modifier (cmd or
+/// ctrl) adds it to the selection or removes it, and clicking it with shift selects
+/// the range from the anchor, which is the last item clicked without shift. The
+/// cursor is the item the keyboard moves from, and moving it with shift extends the
+/// range the same way.
+///
+/// The methods changing the selection return whether it changed. When the model is
+/// held in a [`Model`](gpui::Model), emit [`SelectionChanged`] when they do.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SelectionModel {
+    mode: SelectionMode,
+    item_count: usize,
+    selected: BTreeSet<usize>,
+    /// The selection before the current range was selected, which the range is
+    /// added to.
+    base: BTreeSet<usize>,
+    anchor: Option<usize>,
+    cursor: Option<usize>,
+}
+
+impl EventEmitter<SelectionChanged> for SelectionModel {}
+
+impl SelectionModel {
+    pub fn new(mode: SelectionMode, item_count: usize) -> Self {
+        Self {
+            mode,
+            item_count,
+            ..Default::default()
+        }
+    }
+
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Sets the number of items in the list, deselecting the items past the end.
+    pub fn set_item_count(&mut self, item_count: usize) -> bool {
+        self.item_count = item_count;
+        let previous_len = self.selected.len();
+        self.selected.retain(|ix| *ix < item_count);
+        self.base.retain(|ix| *ix < item_count);
+        self.anchor = self.anchor.filter(|ix| *ix < item_count);
+        self.cursor = self.cursor.filter(|ix| *ix < item_count);
+        self.selected.len() != previous_len
+    }
+
+    pub fn is_selected(&self, ix: usize) -> bool {
+        self.selected.contains(&ix)
+    }
+
+    /// Returns the selected items, in order.
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    pub fn selected_count(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Returns the runs of consecutive selected items, in order, e.g. to move or
+    /// delete them in bulk.
+    pub fn selected_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for &ix in &self.selected {
+            match ranges.last_mut() {
+                Some(range) if range.end == ix => range.end += 1,
+                _ => ranges.push(ix..ix + 1),
+            }
+        }
+        ranges
+    }
+
+    /// The item shift-selection extends from.
+    pub fn anchor(&self) -> Option<usize> {
+        self.anchor
+    }
+
+    /// The item the keyboard moves from, usually drawn with a focus ring.
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Selects only the given item.
+    pub fn select(&mut self, ix: usize) -> bool {
+        if ix >= self.item_count {
+            return false;
+        }
+        let selected = BTreeSet::from([ix]);
+        self.anchor = Some(ix);
+        self.cursor = Some(ix);
+        self.base = selected.clone();
+        self.set_selected(selected)
+    }
+
+    /// Adds the item to the selection, or removes it if it's selected. Selects only
+    /// the item in single selection mode.
+    pub fn toggle(&mut self, ix: usize) -> bool {
+        if self.mode == SelectionMode::Single {
+            return self.select(ix);
+        }
+        if ix >= self.item_count {
+            return false;
+        }
+        if !self.selected.remove(&ix) {
+            self.selected.insert(ix);
+        }
+        self.anchor = Some(ix);
+        self.cursor = Some(ix);
+        self.base = self.selected.clone();
+        true
+    }
+
+    /// Selects the items from the anchor to the given item, along with the items
+    /// selected before the anchor was set. Selects only the item in single selection
+    /// mode, or when there is no anchor.
+    pub fn select_range_to(&mut self, ix: usize) -> bool {
+        let Some(anchor) = self.anchor.filter(|_| self.mode == SelectionMode::Multiple) else {
+            return self.select(ix);
+        };
+        if ix >= self.item_count {
+            return false;
+        }
+        let mut selected = self.base.clone();
+        selected.extend(anchor.min(ix)..=anchor.max(ix));
+        self.cursor = Some(ix);
+        self.set_selected(selected)
+    }
+
+    /// Handles a click on an item, with the modifiers held.
+    pub fn click(&mut self, ix: usize, modifiers: &Modifiers) -> bool {
+        if modifiers.shift {
+            self.select_range_to(ix)
+        } else if modifiers.secondary() {
+            self.toggle(ix)
+        } else {
+            self.select(ix)
+        }
+    }
+
+    /// Moves the cursor by `delta` items, clamped to the list, and selects the item
+    /// under it. With `extend`, the range from the anchor is selected instead, as
+    /// with shift and the arrow keys.
+    pub fn move_cursor(&mut self, delta: isize, extend: bool) -> bool {
+        if self.item_count == 0 {
+            return false;
+        }
+        let ix = match self.cursor {
+            Some(cursor) => cursor.saturating_add_signed(delta).min(self.item_count - 1),
+            None if delta < 0 => self.item_count - 1,
+            None => 0,
+        };
+        if extend {
+            self.select_range_to(ix)
+        } else {
+            self.select(ix)
+        }
+    }
+
+    /// Selects every item, in multiple selection mode.
+    pub fn select_all(&mut self) -> bool {
+        if self.mode == SelectionMode::Single {
+            return false;
+        }
+        let selected = (0..self.item_count).collect::<BTreeSet<_>>();
+        self.base = selected.clone();
+        self.set_selected(selected)
+    }
+
+    /// Deselects every item, keeping the cursor where it is.
+    pub fn clear(&mut self) -> bool {
+        self.anchor = None;
+        self.base.clear();
+        self.set_selected(BTreeSet::new())
+    }
+
+    fn set_selected(&mut self, selected: BTreeSet<usize>) -> bool {
+        if self.selected == selected {
+            return false;
+        }
+        self.selected = selected;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(model: &SelectionModel) -> Vec<usize> {
+        model.selected().collect()
+    }
+
+    #[test]
+    fn test_multiple_selection() {
+        let mut model = SelectionModel::new(SelectionMode::Multiple, 10);
+        let secondary = Modifiers::secondary_key();
+        let shift = Modifiers::shift();
+
+        assert!(model.click(2, &Modifiers::default()));
+        assert!(model.click(5, &secondary));
+        assert_eq!(selected(&model), vec![2, 5]);
+
+        // The range from the anchor is added to the items selected before it.
+        assert!(model.click(7, &shift));
+        assert_eq!(selected(&model), vec![2, 5, 6, 7]);
+        assert!(model.click(4, &shift));
+        assert_eq!(selected(&model), vec![2, 4, 5]);
+        assert_eq!(model.selected_ranges(), vec![2..3, 4..6]);
+
+        assert!(model.move_cursor(2, true));
+        assert_eq!(selected(&model), vec![2, 5, 6]);
+        assert!(model.move_cursor(1, false));
+        assert_eq!(selected(&model), vec![7]);
+        assert!(!model.move_cursor(0, false));
+
+        assert!(model.set_item_count(5));
+        assert_eq!(selected(&model), Vec::<usize>::new());
+        assert!(model.select_all());
+        assert_eq!(model.selected_ranges(), vec![0..5]);
+    }
+
+    #[test]
+    fn test_single_selection() {
+        let mut model = SelectionModel::new(SelectionMode::Single, 3);
+        model.click(0, &Modifiers::default());
+        model.click(2, &Modifiers::shift());
+        assert_eq!(selected(&model), vec![2]);
+        assert!(!model.select_all());
+        assert!(model.move_cursor(-5, false));
+        assert_eq!(selected(&model), vec![0]);
+    }
+}
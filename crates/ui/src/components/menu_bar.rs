@@ -0,0 +1,389 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::{
+    Action, AnchorCorner, FocusHandle, HighlightStyle, OwnedMenu, OwnedMenuItem, StyledText,
+    UnderlineStyle, View,
+};
+
+use crate::{prelude::*, ButtonLike, ContextMenu, PopoverMenu, PopoverMenuHandle};
+
+/// An item of a [`Menu`].
+pub enum MenuEntry {
+    Separator,
+    /// A submenu, shown under a header in the menu it belongs to.
+    Submenu(Menu),
+    Action {
+        label: SharedString,
+        action: Box<dyn Action>,
+        /// Whether a check mark is shown next to the item, if it can be checked.
+        checked: Option<bool>,
+    },
+}
+
+/// A menu of a [`MenuBar`].
+///
+/// The name and labels may mark the character of their mnemonic with a `&`, as in
+/// `&File`, and use `&&` for a literal `&`. The first letter or digit is used when no
+/// character is marked.
+pub struct Menu {
+    name: SharedString,
+    entries: Vec<MenuEntry>,
+}
+
+impl Menu {
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Adds an item dispatching an action to the view that was focused when the
+    /// menu was opened. The item is disabled when that view can't handle the action.
+    pub fn action(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        self.entries.push(MenuEntry::Action {
+            label: label.into(),
+            action,
+            checked: None,
+        });
+        self
+    }
+
+    /// Adds an item like [`Menu::action`], with a check mark when `checked` is `true`.
+    pub fn checkable_action(
+        mut self,
+        label: impl Into<SharedString>,
+        checked: bool,
+        action: Box<dyn Action>,
+    ) -> Self {
+        self.entries.push(MenuEntry::Action {
+            label: label.into(),
+            action,
+            checked: Some(checked),
+        });
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    pub fn submenu(mut self, submenu: Menu) -> Self {
+        self.entries.push(MenuEntry::Submenu(submenu));
+        self
+    }
+}
+
+impl From<OwnedMenu> for Menu {
+    fn from(menu: OwnedMenu) -> Self {
+        menu.items
+            .into_iter()
+            .fold(Menu::new(menu.name), |menu, item| match item {
+                OwnedMenuItem::Separator => menu.separator(),
+                OwnedMenuItem::Submenu(submenu) => menu.submenu(submenu.into()),
+                OwnedMenuItem::Action { name, action, .. } => menu.action(name, action),
+            })
+    }
+}
+
+/// Removes the `&` markers from a label, returning it with the byte range of its
+/// mnemonic character, if it has one.
+fn parse_mnemonic(label: &str) -> (String, Option<Range<usize>>) {
+    let mut text = String::with_capacity(label.len());
+    let mut marked = None;
+    let mut chars = label.chars();
+    while let Some(char) = chars.next() {
+        if char == '&' {
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(char) => {
+                    if marked.is_none() {
+                        marked = Some(text.len()..text.len() + char.len_utf8());
+                    }
+                    text.push(char);
+                }
+                None => {}
+            }
+        } else {
+            text.push(char);
+        }
+    }
+
+    let mnemonic = marked.or_else(|| {
+        text.char_indices()
+            .find(|(_, char)| char.is_alphanumeric())
+            .map(|(ix, char)| ix..ix + char.len_utf8())
+    });
+    (text, mnemonic)
+}
+
+fn mnemonic_char(text: &str, range: &Option<Range<usize>>) -> Option<char> {
+    range
+        .as_ref()
+        .and_then(|range| text[range.clone()].chars().next())
+        .map(|char| char.to_ascii_lowercase())
+}
+
+#[derive(Default)]
+struct MenuBarMenus {
+    names: Vec<SharedString>,
+    mnemonics: Vec<Option<char>>,
+    handles: Vec<PopoverMenuHandle<ContextMenu>>,
+}
+
+/// The menus of a [`MenuBar`], held by the view rendering it to open the menus
+/// from actions, e.g. those bound to the mnemonics of the menus.
+#[derive(Clone, Default)]
+pub struct MenuBarState(Rc<RefCell<MenuBarMenus>>);
+
+impl MenuBarState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether one of the menus is open.
+    pub fn is_open(&self) -> bool {
+        self.0
+            .borrow()
+            .handles
+            .iter()
+            .any(|handle| handle.is_deployed())
+    }
+
+    /// Opens the menu with the given name, ignoring `&` markers and case, or with
+    /// the given mnemonic character. Returns whether there is such a menu.
+    pub fn open_menu(&self, name: &str, cx: &mut WindowContext) -> bool {
+        let (name, _) = parse_mnemonic(name);
+        let mut chars = name.chars();
+        let mnemonic = match (chars.next(), chars.next()) {
+            (Some(char), None) => Some(char.to_ascii_lowercase()),
+            _ => None,
+        };
+
+        let menus = self.0.borrow();
+        let ix = menus
+            .names
+            .iter()
+            .position(|menu_name| menu_name.eq_ignore_ascii_case(&name))
+            .or_else(|| {
+                mnemonic.and_then(|mnemonic| {
+                    menus
+                        .mnemonics
+                        .iter()
+                        .position(|menu_mnemonic| *menu_mnemonic == Some(mnemonic))
+                })
+            });
+        drop(menus);
+
+        match ix {
+            Some(ix) => {
+                self.open(ix, cx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn handle(&self, ix: usize) -> PopoverMenuHandle<ContextMenu> {
+        self.0.borrow().handles[ix].clone()
+    }
+
+    fn open(&self, ix: usize, cx: &mut WindowContext) {
+        let handles = self.0.borrow().handles.clone();
+        for (handle_ix, handle) in handles.iter().enumerate() {
+            if handle_ix != ix {
+                handle.hide(cx);
+            }
+        }
+        // Wait for the closed menu to give the focus back, so the new menu is built
+        // for the view that was focused before the bar.
+        let handle = handles[ix].clone();
+        if !handle.is_deployed() {
+            cx.defer(move |cx| handle.show(cx));
+        }
+    }
+}
+
+/// A bar of application menus rendered in the window, for platforms without a
+/// global menu bar, and for macOS in full screen.
+///
+/// Items dispatch their action to the view that was focused when their menu was
+/// opened, are disabled when that view can't handle it, and show the key binding
+/// of the action for that view.
+#[derive(IntoElement)]
+pub struct MenuBar {
+    id: ElementId,
+    state: MenuBarState,
+    menus: Vec<Menu>,
+}
+
+impl MenuBar {
+    pub fn new(id: impl Into<ElementId>, state: &MenuBarState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            menus: Vec::new(),
+        }
+    }
+
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    pub fn menus(mut self, menus: impl IntoIterator<Item = Menu>) -> Self {
+        self.menus.extend(menus);
+        self
+    }
+}
+
+fn build_menu(menu: &Menu, cx: &mut WindowContext) -> View<ContextMenu> {
+    let context = cx.focused();
+    ContextMenu::build(cx, |context_menu, cx| {
+        let context_menu =
+            context_menu.when_some(context.clone(), |this, context| this.context(context));
+        add_entries(context_menu, &menu.entries, &context, cx)
+    })
+}
+
+fn add_entries(
+    mut context_menu: ContextMenu,
+    entries: &[MenuEntry],
+    context: &Option<FocusHandle>,
+    cx: &mut WindowContext,
+) -> ContextMenu {
+    for entry in entries {
+        context_menu = match entry {
+            MenuEntry::Separator => context_menu.separator(),
+            MenuEntry::Submenu(submenu) => {
+                let context_menu = context_menu.header(parse_mnemonic(&submenu.name).0);
+                add_entries(context_menu, &submenu.entries, context, cx)
+            }
+            MenuEntry::Action {
+                label,
+                action,
+                checked,
+            } => {
+                let label = parse_mnemonic(label).0;
+                if !cx.is_action_available(action.as_ref()) {
+                    context_menu.disabled_action(label, action.boxed_clone())
+                } else if let Some(checked) = checked {
+                    let context = context.clone();
+                    let handler_action = action.boxed_clone();
+                    context_menu.toggleable_entry(
+                        label,
+                        *checked,
+                        IconPosition::Start,
+                        Some(action.boxed_clone()),
+                        move |cx| {
+                            if let Some(context) = &context {
+                                cx.focus(context);
+                            }
+                            cx.dispatch_action(handler_action.boxed_clone());
+                        },
+                    )
+                } else {
+                    context_menu.action(label, action.boxed_clone())
+                }
+            }
+        };
+    }
+    context_menu
+}
+
+impl RenderOnce for MenuBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let labels = self
+            .menus
+            .iter()
+            .map(|menu| parse_mnemonic(&menu.name))
+            .collect::<Vec<_>>();
+        {
+            let mut menus = self.state.0.borrow_mut();
+            menus.names = labels
+                .iter()
+                .map(|(text, _)| SharedString::from(text.clone()))
+                .collect();
+            menus.mnemonics = labels
+                .iter()
+                .map(|(text, mnemonic)| mnemonic_char(text, mnemonic))
+                .collect();
+            menus
+                .handles
+                .resize_with(self.menus.len(), Default::default);
+        }
+
+        // Mnemonics are underlined while the bar is in use, like on Windows.
+        let show_mnemonics = self.state.is_open();
+        let text_style = cx.text_style();
+        let underline = HighlightStyle {
+            underline: Some(UnderlineStyle {
+                thickness: px(1.),
+                color: None,
+                wavy: false,
+            }),
+            ..Default::default()
+        };
+
+        h_flex()
+            .id(self.id)
+            .gap_0p5()
+            .children(self.menus.into_iter().zip(labels).enumerate().map(
+                |(ix, (menu, (text, mnemonic)))| {
+                    let menu = Rc::new(menu);
+                    let handle = self.state.handle(ix);
+                    let highlights = mnemonic
+                        .filter(|_| show_mnemonics)
+                        .map(|range| (range, underline));
+                    let label = StyledText::new(text).with_highlights(&text_style, highlights);
+
+                    let state = self.state.clone();
+                    div()
+                        .id(("menu-bar-item", ix))
+                        .on_hover(move |hovered, cx| {
+                            if *hovered && state.is_open() && !state.handle(ix).is_deployed() {
+                                state.open(ix, cx);
+                            }
+                        })
+                        .child(
+                            PopoverMenu::new(("menu-bar-menu", ix))
+                                .menu(move |cx| Some(build_menu(&menu, cx)))
+                                .trigger(
+                                    ButtonLike::new(("menu-bar-trigger", ix))
+                                        .child(div().px_1().text_ui_sm(cx).child(label)),
+                                )
+                                .anchor(AnchorCorner::TopLeft)
+                                .with_handle(handle),
+                        )
+                },
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mnemonic() {
+        assert_eq!(parse_mnemonic("&File"), ("File".to_string(), Some(0..1)));
+        assert_eq!(
+            parse_mnemonic("Sele&ction"),
+            ("Selection".to_string(), Some(4..5))
+        );
+        assert_eq!(
+            parse_mnemonic("Find && &Replace"),
+            ("Find & Replace".to_string(), Some(7..8))
+        );
+        assert_eq!(
+            parse_mnemonic("...Help"),
+            ("...Help".to_string(), Some(3..4))
+        );
+        assert_eq!(parse_mnemonic("&&"), ("&".to_string(), None));
+        assert_eq!(parse_mnemonic("Edit&"), ("Edit".to_string(), Some(0..1)));
+    }
+}
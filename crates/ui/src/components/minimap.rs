@@ -0,0 +1,383 @@
+#![allow(missing_docs)]
+use std::{cell::Cell, ops::Range, rc::Rc};
+
+use gpui::{
+    fill, point, size, Bounds, CursorStyle, GlobalElementId, Hitbox, Hsla, LayoutId, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, Style,
+};
+
+use crate::prelude::*;
+
+/// The width of the marker stripe along the right edge of a [`Minimap`].
+const MARKER_WIDTH: f32 = 3.;
+
+/// A row of the content shown by a [`Minimap`], drawn as a block spanning the
+/// columns its text spans.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimapRow {
+    /// The column the text starts at, after its indentation.
+    pub start: u32,
+    /// The column the text ends at.
+    pub end: u32,
+    /// The color of the block. Defaults to a faded text color.
+    pub color: Option<Hsla>,
+}
+
+impl MinimapRow {
+    pub fn new(start: u32, end: u32) -> Self {
+        Self {
+            start,
+            end,
+            color: None,
+        }
+    }
+
+    /// Makes a row from a line of text, its leading whitespace being left empty.
+    pub fn from_line(line: &str) -> Self {
+        let end = line.trim_end().chars().count() as u32;
+        let indent = line.chars().take_while(|c| c.is_whitespace()).count() as u32;
+        Self::new(indent.min(end), end)
+    }
+
+    /// A row spanning the whole width, for content like list items.
+    pub fn full() -> Self {
+        Self::new(0, u32::MAX)
+    }
+
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A highlighted range of rows of a [`Minimap`], like a search hit or a diagnostic,
+/// shown along its right edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimapMarker {
+    pub rows: Range<usize>,
+    pub color: Hsla,
+}
+
+impl MinimapMarker {
+    pub fn new(rows: Range<usize>, color: Hsla) -> Self {
+        Self { rows, color }
+    }
+}
+
+/// Returns the height of a row of a minimap `height` tall showing `row_count` rows,
+/// rows being squeezed for all of them to fit.
+pub fn minimap_row_height(row_count: usize, height: Pixels, max_row_height: Pixels) -> Pixels {
+    if row_count == 0 {
+        return max_row_height;
+    }
+    max_row_height.min(height * (1. / row_count as f32))
+}
+
+/// Returns the top row to scroll to for `row` to be at the center of the
+/// `visible_row_count` rows shown, staying within the `row_count` rows.
+pub fn minimap_jump_target(row: f32, visible_row_count: f32, row_count: usize) -> f32 {
+    let max_top_row = (row_count as f32 - visible_row_count).max(0.);
+    (row - visible_row_count / 2.).clamp(0., max_top_row)
+}
+
+/// A block covering the rows drawn at one pixel row of a squeezed minimap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MinimapBlock {
+    rows: Range<usize>,
+    start: u32,
+    end: u32,
+    color: Option<Hsla>,
+}
+
+/// Merges runs of consecutive rows into one block per `rows_per_block` rows,
+/// spanning the columns of all of them, so that a long file isn't drawn one
+/// sub-pixel row at a time.
+fn minimap_blocks(rows: &[MinimapRow], rows_per_block: usize) -> Vec<MinimapBlock> {
+    let rows_per_block = rows_per_block.max(1);
+    rows.chunks(rows_per_block)
+        .enumerate()
+        .filter_map(|(ix, chunk)| {
+            let non_empty = chunk.iter().filter(|row| row.start < row.end);
+            let start = non_empty.clone().map(|row| row.start).min()?;
+            let end = non_empty.clone().map(|row| row.end).max()?;
+            let first_row = ix * rows_per_block;
+            Some(MinimapBlock {
+                rows: first_row..first_row + chunk.len(),
+                start,
+                end,
+                color: non_empty.filter_map(|row| row.color).next(),
+            })
+        })
+        .collect()
+}
+
+/// A miniature of long scrollable content, like a buffer or a list, showing each
+/// row as a block, the rows currently visible as a rectangle, and markers for
+/// search hits or diagnostics along the right edge.
+///
+/// Clicking the minimap jumps to the clicked row, and dragging it scrolls the
+/// content, through [`Minimap::on_jump`]. Rows are squeezed for all of them to fit.
+pub struct Minimap {
+    id: ElementId,
+    rows: Vec<MinimapRow>,
+    visible_rows: Range<f32>,
+    markers: Vec<MinimapMarker>,
+    column_width: Pixels,
+    max_row_height: Pixels,
+    on_jump: Option<Rc<dyn Fn(f32, &mut WindowContext)>>,
+}
+
+impl Minimap {
+    /// Creates a minimap of `rows`, with the rows in `visible_rows` shown as visible.
+    pub fn new(
+        id: impl Into<ElementId>,
+        rows: impl IntoIterator<Item = MinimapRow>,
+        visible_rows: Range<f32>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            rows: rows.into_iter().collect(),
+            visible_rows,
+            markers: Vec::new(),
+            column_width: px(1.),
+            max_row_height: px(2.),
+            on_jump: None,
+        }
+    }
+
+    pub fn markers(mut self, markers: impl IntoIterator<Item = MinimapMarker>) -> Self {
+        self.markers.extend(markers);
+        self
+    }
+
+    /// Sets the width of a column of text. Defaults to 1px.
+    pub fn column_width(mut self, column_width: Pixels) -> Self {
+        self.column_width = column_width;
+        self
+    }
+
+    /// Sets the height of a row when the rows don't need squeezing. Defaults to 2px.
+    pub fn max_row_height(mut self, max_row_height: Pixels) -> Self {
+        self.max_row_height = max_row_height;
+        self
+    }
+
+    /// Called with the top row to scroll to when the minimap is clicked or dragged.
+    pub fn on_jump(mut self, handler: impl Fn(f32, &mut WindowContext) + 'static) -> Self {
+        self.on_jump = Some(Rc::new(handler));
+        self
+    }
+
+    fn visible_row_count(&self) -> f32 {
+        (self.visible_rows.end - self.visible_rows.start).max(0.)
+    }
+
+    fn row_bounds(rows: Range<f32>, row_height: Pixels, bounds: Bounds<Pixels>) -> Bounds<Pixels> {
+        Bounds {
+            origin: point(bounds.left(), bounds.top() + row_height * rows.start),
+            size: size(
+                bounds.size.width,
+                (row_height * (rows.end - rows.start)).max(px(1.)),
+            ),
+        }
+    }
+}
+
+impl IntoElement for Minimap {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Minimap {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Style::default()
+        };
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        cx.insert_hitbox(bounds, false)
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let row_count = self.rows.len();
+        let row_height = minimap_row_height(row_count, bounds.size.height, self.max_row_height);
+        let rows_per_block = (1. / row_height.0).ceil().max(1.) as usize;
+        let text_width = bounds.size.width - px(MARKER_WIDTH);
+        let default_color = cx.theme().colors().text.opacity(0.25);
+        let visible_color = cx.theme().colors().scrollbar_thumb_background;
+
+        for block in minimap_blocks(&self.rows, rows_per_block) {
+            let start = (self.column_width * block.start as f32).min(text_width);
+            let end = (self.column_width * block.end as f32).min(text_width);
+            if end <= start {
+                continue;
+            }
+            let row_bounds = Self::row_bounds(
+                block.rows.start as f32..block.rows.end as f32,
+                row_height,
+                bounds,
+            );
+            cx.paint_quad(fill(
+                Bounds {
+                    origin: point(bounds.left() + start, row_bounds.top()),
+                    size: size(end - start, row_bounds.size.height),
+                },
+                block.color.unwrap_or(default_color),
+            ));
+        }
+
+        let visible_bounds = Self::row_bounds(self.visible_rows.clone(), row_height, bounds);
+        cx.paint_quad(fill(visible_bounds, visible_color));
+
+        for marker in &self.markers {
+            let marker_bounds = Self::row_bounds(
+                marker.rows.start as f32..marker.rows.end.max(marker.rows.start + 1) as f32,
+                row_height,
+                bounds,
+            );
+            cx.paint_quad(fill(
+                Bounds {
+                    origin: point(bounds.right() - px(MARKER_WIDTH), marker_bounds.top()),
+                    size: size(px(MARKER_WIDTH), marker_bounds.size.height),
+                },
+                marker.color,
+            ));
+        }
+
+        let Some(on_jump) = self.on_jump.clone() else {
+            return;
+        };
+        cx.set_cursor_style(CursorStyle::PointingHand, hitbox);
+
+        // While the mouse is down, the row under it, relative to the top of the
+        // visible rows, so that dragging keeps it under the mouse.
+        let drag_row_offset = cx.with_element_state(id.unwrap(), |state, _| {
+            let state: Rc<Cell<Option<f32>>> = state.unwrap_or_default();
+            (state.clone(), state)
+        });
+        let visible_row_count = self.visible_row_count();
+        let visible_rows = self.visible_rows.clone();
+        let row_at = move |y: Pixels| (y - bounds.top()).0 / row_height.0.max(f32::EPSILON);
+
+        cx.on_mouse_event({
+            let hitbox = hitbox.clone();
+            let drag_row_offset = drag_row_offset.clone();
+            let on_jump = on_jump.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if !phase.bubble() || event.button != MouseButton::Left || !hitbox.is_hovered(cx) {
+                    return;
+                }
+                let row = row_at(event.position.y);
+                if visible_rows.contains(&row) {
+                    drag_row_offset.set(Some(row - visible_rows.start));
+                } else {
+                    let top_row = minimap_jump_target(row, visible_row_count, row_count);
+                    drag_row_offset.set(Some(row - top_row));
+                    on_jump(top_row, cx);
+                }
+                cx.stop_propagation();
+            }
+        });
+
+        cx.on_mouse_event({
+            let drag_row_offset = drag_row_offset.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if !phase.bubble() {
+                    return;
+                }
+                let Some(offset) = drag_row_offset.get() else {
+                    return;
+                };
+                if event.pressed_button != Some(MouseButton::Left) {
+                    drag_row_offset.set(None);
+                    return;
+                }
+                let max_top_row = (row_count as f32 - visible_row_count).max(0.);
+                let top_row = (row_at(event.position.y) - offset).clamp(0., max_top_row);
+                on_jump(top_row, cx);
+                cx.stop_propagation();
+            }
+        });
+
+        cx.on_mouse_event(move |_: &MouseUpEvent, phase, _| {
+            if phase.bubble() {
+                drag_row_offset.set(None);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimap_row_height() {
+        assert_eq!(minimap_row_height(100, px(400.), px(2.)), px(2.));
+        assert_eq!(minimap_row_height(1000, px(500.), px(2.)), px(0.5));
+        assert_eq!(minimap_row_height(0, px(500.), px(2.)), px(2.));
+    }
+
+    #[test]
+    fn test_minimap_jump_target() {
+        // The clicked row is centered among the visible rows.
+        assert_eq!(minimap_jump_target(50., 20., 100), 40.);
+        // The content isn't scrolled past its start or its end.
+        assert_eq!(minimap_jump_target(5., 20., 100), 0.);
+        assert_eq!(minimap_jump_target(98., 20., 100), 80.);
+        assert_eq!(minimap_jump_target(3., 20., 10), 0.);
+    }
+
+    #[test]
+    fn test_minimap_blocks() {
+        let rows = ["fn main() {", "    let x = 1;", "", "}"]
+            .into_iter()
+            .map(MinimapRow::from_line)
+            .collect::<Vec<_>>();
+        assert_eq!(rows[1], MinimapRow::new(4, 14));
+
+        let blocks = minimap_blocks(&rows, 1);
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|block| block.rows.clone())
+                .collect::<Vec<_>>(),
+            vec![0..1, 1..2, 3..4]
+        );
+
+        let blocks = minimap_blocks(&rows, 2);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[0].start, blocks[0].end), (0, 14));
+        assert_eq!((blocks[1].rows.clone(), blocks[1].end), (2..4, 1));
+    }
+}
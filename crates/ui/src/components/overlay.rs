@@ -0,0 +1,112 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+
+use gpui::{anchored, deferred, point, AnchoredPositionMode, AnyElement, ClickEvent};
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+/// A layer of the window that floating content is drawn on, above the content of
+/// the window.
+///
+/// Everything floating that is drawn as deferred elements should use one of these
+/// layers, so that it stacks the same way wherever it comes from. Modals and
+/// notifications are part of the workspace's layout, so they are below every
+/// layer, and gpui draws tooltips above every layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OverlayLayer {
+    /// Context menus, popover menus and other popovers anchored to an element.
+    Popover,
+}
+
+impl OverlayLayer {
+    /// The priority of the layer among deferred elements. Content drawn on the same
+    /// layer stacks in the order it's rendered.
+    pub fn priority(self) -> usize {
+        match self {
+            OverlayLayer::Popover => 1,
+        }
+    }
+}
+
+/// Draws its children on an [`OverlayLayer`], above the rest of the window, while
+/// keeping their position in the layout of the parent.
+///
+/// The children are drawn for as long as the view rendering the portal renders it,
+/// so they're cleaned up with the view that owns them.
+#[derive(IntoElement)]
+pub struct Portal {
+    layer: OverlayLayer,
+    block_input: bool,
+    on_backdrop_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+/// Draws the children on the given [`OverlayLayer`].
+pub fn portal(layer: OverlayLayer) -> Portal {
+    Portal {
+        layer,
+        block_input: false,
+        on_backdrop_click: None,
+        children: SmallVec::new(),
+    }
+}
+
+impl Portal {
+    /// Sets whether the portal keeps the mouse from reaching what's below it, over the
+    /// whole window. Defaults to `false`.
+    pub fn block_input(mut self, block_input: bool) -> Self {
+        self.block_input = block_input;
+        self
+    }
+
+    /// Called when the window is clicked outside of the children while the portal
+    /// blocks input, e.g. to emit a [`DismissEvent`](gpui::DismissEvent).
+    pub fn on_backdrop_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_backdrop_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl ParentElement for Portal {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Portal {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let priority = self.layer.priority();
+        let block_input = self.block_input;
+
+        // The backdrop is drawn on the same layer right before the children, so that
+        // it's below them and above everything else.
+        let backdrop = block_input.then(|| {
+            let viewport_size = cx.viewport_size();
+            let on_backdrop_click = self.on_backdrop_click.clone();
+            deferred(
+                anchored()
+                    .position_mode(AnchoredPositionMode::Window)
+                    .position(point(px(0.), px(0.)))
+                    .child(
+                        div()
+                            .id("overlay-backdrop")
+                            .w(viewport_size.width)
+                            .h(viewport_size.height)
+                            .occlude()
+                            .when_some(on_backdrop_click, |this, on_backdrop_click| {
+                                this.on_click(move |event, cx| on_backdrop_click(event, cx))
+                            }),
+                    ),
+            )
+            .with_priority(priority)
+        });
+
+        div()
+            .children(backdrop)
+            .child(deferred(div().occlude().children(self.children)).with_priority(priority))
+    }
+}
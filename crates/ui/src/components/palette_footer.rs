@@ -0,0 +1,94 @@
+#![allow(missing_docs)]
+use gpui::{Action, FocusHandle};
+
+use crate::{prelude::*, ButtonLike, KeyBinding};
+
+/// A hint shown in a [`PaletteFooter`]: an action that can be performed on the
+/// selected item, along with its key binding.
+pub struct PaletteHint {
+    label: SharedString,
+    action: Box<dyn Action>,
+}
+
+impl PaletteHint {
+    pub fn new(label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        Self {
+            label: label.into(),
+            action,
+        }
+    }
+}
+
+/// The footer of a palette or picker, listing what can be done with the selected item.
+///
+/// Clicking a hint dispatches its action.
+#[derive(IntoElement)]
+pub struct PaletteFooter {
+    hints: Vec<PaletteHint>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl PaletteFooter {
+    pub fn new() -> Self {
+        Self {
+            hints: Vec::new(),
+            focus_handle: None,
+        }
+    }
+
+    pub fn hint(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        self.hints.push(PaletteHint::new(label, action));
+        self
+    }
+
+    pub fn hints(mut self, hints: impl IntoIterator<Item = PaletteHint>) -> Self {
+        self.hints.extend(hints);
+        self
+    }
+
+    /// Looks up key bindings from the given focus handle, rather than from the
+    /// currently focused element.
+    pub fn key_context(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+}
+
+impl Default for PaletteFooter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for PaletteFooter {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let focus_handle = self.focus_handle;
+
+        h_flex()
+            .w_full()
+            .justify_end()
+            .gap_4()
+            .py_1()
+            .px_2()
+            .border_t_1()
+            .border_color(cx.theme().colors().border_variant)
+            .children(self.hints.into_iter().enumerate().map(|(ix, hint)| {
+                let key_binding = match focus_handle.as_ref() {
+                    Some(focus_handle) => {
+                        KeyBinding::for_action_in(hint.action.as_ref(), focus_handle, cx)
+                    }
+                    None => KeyBinding::for_action(hint.action.as_ref(), cx),
+                };
+                let action = hint.action;
+
+                ButtonLike::new(("palette-hint", ix))
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(Label::new(hint.label).color(Color::Muted))
+                            .children(key_binding),
+                    )
+                    .on_click(move |_, cx| cx.dispatch_action(action.boxed_clone()))
+            }))
+    }
+}
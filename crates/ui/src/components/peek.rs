@@ -0,0 +1,143 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// How long the pointer has to rest on an item before it's peeked.
+const HOVER_PEEK_DELAY: Duration = Duration::from_millis(300);
+
+/// What caused an item to be peeked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeekTrigger {
+    /// The item was hovered, and is peeked after a short delay so that moving the
+    /// pointer across a list doesn't preview every item on the way.
+    Hover,
+    /// The item was selected with the keyboard, and is peeked right away.
+    Keyboard,
+}
+
+/// A change of the item previewed by a [`PeekController`].
+pub enum PeekEvent<T> {
+    /// The item is to be shown in the preview pane.
+    Peek(T),
+    /// The preview pane is to be hidden.
+    Dismiss,
+    /// The previewed item is to be opened for good, e.g. after pressing Enter.
+    Open(T),
+}
+
+struct PeekState<T> {
+    peeked: Option<T>,
+    /// Incremented on every change, so that a pending hover peek can tell whether
+    /// it was superseded while waiting.
+    generation: usize,
+    hover_delay: Duration,
+    on_event: Rc<dyn Fn(PeekEvent<T>, &mut WindowContext)>,
+}
+
+/// Coordinates a list with a transient preview pane: the item under the pointer
+/// or the keyboard selection is previewed, and can be promoted to be opened.
+///
+/// The controller only tracks which item is previewed and reports changes to the
+/// view holding it, which renders the preview pane and opens the items.
+pub struct PeekController<T>(Rc<RefCell<PeekState<T>>>);
+
+impl<T> Clone for PeekController<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> PeekController<T> {
+    pub fn new(on_event: impl Fn(PeekEvent<T>, &mut WindowContext) + 'static) -> Self {
+        Self(Rc::new(RefCell::new(PeekState {
+            peeked: None,
+            generation: 0,
+            hover_delay: HOVER_PEEK_DELAY,
+            on_event: Rc::new(on_event),
+        })))
+    }
+
+    /// Sets how long an item has to be hovered before it's peeked.
+    pub fn hover_delay(self, hover_delay: Duration) -> Self {
+        self.0.borrow_mut().hover_delay = hover_delay;
+        self
+    }
+
+    /// The item shown in the preview pane, if any.
+    pub fn peeked(&self) -> Option<T> {
+        self.0.borrow().peeked.clone()
+    }
+
+    pub fn is_peeking(&self) -> bool {
+        self.0.borrow().peeked.is_some()
+    }
+
+    /// Previews an item, right away when selected with the keyboard and after a
+    /// delay when hovered. Peeking the previewed item again does nothing.
+    pub fn peek(&self, item: T, trigger: PeekTrigger, cx: &mut WindowContext) {
+        let generation = {
+            let mut state = self.0.borrow_mut();
+            state.generation += 1;
+            if state.peeked.as_ref() == Some(&item) {
+                return;
+            }
+            state.generation
+        };
+
+        match trigger {
+            PeekTrigger::Keyboard => self.set_peeked(Some(item), cx),
+            PeekTrigger::Hover => {
+                let delay = self.0.borrow().hover_delay;
+                let this = self.clone();
+                cx.spawn(|mut cx| async move {
+                    cx.background_executor().timer(delay).await;
+                    if this.0.borrow().generation == generation {
+                        cx.update(|cx| this.set_peeked(Some(item), cx)).ok();
+                    }
+                })
+                .detach();
+            }
+        }
+    }
+
+    /// Stops waiting to peek a hovered item, e.g. when the pointer leaves it.
+    pub fn cancel_hover(&self) {
+        self.0.borrow_mut().generation += 1;
+    }
+
+    /// Hides the preview pane.
+    pub fn dismiss(&self, cx: &mut WindowContext) {
+        self.cancel_hover();
+        self.set_peeked(None, cx);
+    }
+
+    /// Opens the previewed item for good, hiding the preview pane. Returns the
+    /// item, or `None` if nothing was previewed.
+    pub fn promote(&self, cx: &mut WindowContext) -> Option<T> {
+        self.cancel_hover();
+        let (item, on_event) = {
+            let mut state = self.0.borrow_mut();
+            (state.peeked.take()?, state.on_event.clone())
+        };
+        on_event(PeekEvent::Open(item.clone()), cx);
+        Some(item)
+    }
+
+    fn set_peeked(&self, item: Option<T>, cx: &mut WindowContext) {
+        let on_event = {
+            let mut state = self.0.borrow_mut();
+            if state.peeked == item {
+                return;
+            }
+            state.peeked = item.clone();
+            state.on_event.clone()
+        };
+        match item {
+            Some(item) => on_event(PeekEvent::Peek(item), cx),
+            None => on_event(PeekEvent::Dismiss, cx),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 #![allow(missing_docs)]
 
 use crate::prelude::*;
-use crate::v_flex;
+use crate::Surface;
 use gpui::{
     div, AnyElement, Element, IntoElement, ParentElement, RenderOnce, Styled, WindowContext,
 };
 use smallvec::SmallVec;
+use theme::Elevation;
 
 /// A popover is used to display a menu or show some options.
 ///
@@ -45,11 +46,16 @@ impl RenderOnce for Popover {
         div()
             .flex()
             .gap_1()
-            .child(v_flex().elevation_2(cx).py_1().children(self.children))
+            .child(
+                Surface::new(Elevation::Overlay)
+                    .v_flex()
+                    .py_1()
+                    .children(self.children),
+            )
             .when_some(self.aside, |this, aside| {
                 this.child(
-                    v_flex()
-                        .elevation_2(cx)
+                    Surface::new(Elevation::Overlay)
+                        .v_flex()
                         .bg(cx.theme().colors().surface_background)
                         .px_1()
                         .child(aside),
@@ -9,7 +9,7 @@ use gpui::{
     Pixels, Point, Style, View, VisualContext, WindowContext,
 };
 
-use crate::prelude::*;
+use crate::{prelude::*, OverlayLayer};
 
 pub trait PopoverTrigger: IntoElement + Clickable + Selectable + 'static {}
 
@@ -263,7 +263,7 @@ impl<M: ManagedView> Element for PopoverMenu<M> {
                         );
                     }
                     let mut element = deferred(anchored.child(div().occlude().child(menu.clone())))
-                        .with_priority(1)
+                        .with_priority(OverlayLayer::Popover.priority())
                         .into_any();
 
                     menu_layout_id = Some(element.request_layout(cx));
@@ -0,0 +1,125 @@
+#![allow(missing_docs)]
+
+use gpui::Hsla;
+
+use crate::prelude::*;
+
+/// A named stage of a [`ProgressBar`] in segmented mode.
+#[derive(Debug, Clone)]
+pub struct ProgressStage {
+    label: SharedString,
+}
+
+impl ProgressStage {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+        }
+    }
+}
+
+/// A horizontal bar showing the progress of a task.
+///
+/// In segmented mode, the bar is split into one segment per stage of a multi-stage
+/// task (e.g. "Resolving → Downloading → Installing"). Finished stages are shown as
+/// complete, and the current stage is labeled and filled according to its progress.
+#[derive(IntoElement)]
+pub struct ProgressBar {
+    /// The progress of the task, or of the current stage in segmented mode, from `0.` to `1.`.
+    progress: f32,
+    stages: Vec<ProgressStage>,
+    current_stage: usize,
+    show_label: bool,
+}
+
+impl ProgressBar {
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0., 1.),
+            stages: Vec::new(),
+            current_stage: 0,
+            show_label: true,
+        }
+    }
+
+    /// Creates a progress bar for a multi-stage task, of which the stage at
+    /// `current_stage` is in progress.
+    pub fn segmented(
+        stages: impl IntoIterator<Item = ProgressStage>,
+        current_stage: usize,
+        stage_progress: f32,
+    ) -> Self {
+        Self {
+            stages: stages.into_iter().collect(),
+            current_stage,
+            ..Self::new(stage_progress)
+        }
+    }
+
+    /// Whether to show the label of the current stage above the bar. Defaults to `true`.
+    pub fn show_label(mut self, show_label: bool) -> Self {
+        self.show_label = show_label;
+        self
+    }
+
+    fn render_segment(fill: f32, track: Hsla, color: Hsla) -> impl IntoElement {
+        div()
+            .flex_1()
+            .h_1()
+            .rounded_sm()
+            .overflow_hidden()
+            .bg(track)
+            .child(div().h_full().w(relative(fill)).bg(color))
+    }
+}
+
+impl RenderOnce for ProgressBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let track = cx.theme().colors().element_background;
+        let accent = cx.theme().colors().text_accent;
+        let complete = cx.theme().status().success;
+
+        if self.stages.is_empty() {
+            return h_flex()
+                .w_full()
+                .child(Self::render_segment(self.progress, track, accent))
+                .into_any_element();
+        }
+
+        let stage_count = self.stages.len();
+        let current_stage = self.current_stage.min(stage_count - 1);
+        let current_label = self.stages[current_stage].label.clone();
+        let is_finished = self.current_stage >= stage_count;
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .when(self.show_label, |this| {
+                this.child(
+                    h_flex()
+                        .justify_between()
+                        .child(Label::new(current_label).size(LabelSize::Small))
+                        .child(
+                            Label::new(format!("{}/{}", current_stage + 1, stage_count))
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        ),
+                )
+            })
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap_0p5()
+                    .children((0..stage_count).map(|ix| {
+                        if ix < current_stage || is_finished {
+                            Self::render_segment(1., track, complete)
+                        } else if ix == current_stage {
+                            Self::render_segment(self.progress, track, accent)
+                        } else {
+                            Self::render_segment(0., track, accent)
+                        }
+                    })),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,182 @@
+#![allow(missing_docs)]
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use gpui::{canvas, point, Bounds, Hsla, Path, Pixels, Point};
+
+use crate::prelude::*;
+
+/// The number of segments a full ring is drawn with.
+const RING_SEGMENTS: usize = 48;
+
+/// A ring filling up clockwise from the top with the progress of a task.
+///
+/// Sized like an icon, so it fits in the slots of buttons and tabs, e.g. to show
+/// the progress of an operation on a tab's file or next to an "Installing" label.
+/// An icon or a short label, like a percentage, can be shown in its center.
+#[derive(IntoElement)]
+pub struct ProgressRing {
+    /// The progress of the task, from `0.` to `1.`.
+    progress: f32,
+    size: IconSize,
+    color: Option<Color>,
+    icon: Option<IconName>,
+    label: Option<SharedString>,
+}
+
+impl ProgressRing {
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0., 1.),
+            size: IconSize::Small,
+            color: None,
+            icon: None,
+            label: None,
+        }
+    }
+
+    pub fn size(mut self, size: IconSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// The color of the filled part of the ring. Defaults to the accent color.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Shows an icon in the center of the ring.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Shows a short label, like a percentage, in the center of the ring. Only fits
+    /// rings of at least [`IconSize::Medium`].
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Returns the start and end angles of the segments of the filled part of a ring,
+/// in radians clockwise from the top.
+fn filled_segments(progress: f32) -> impl Iterator<Item = (f32, f32)> {
+    let filled = progress * RING_SEGMENTS as f32;
+    let segment_angle = TAU / RING_SEGMENTS as f32;
+    (0..filled.ceil() as usize).map(move |ix| {
+        let start = ix as f32 * segment_angle;
+        let end = (ix as f32 + 1.).min(filled) * segment_angle;
+        (start, end)
+    })
+}
+
+fn paint_ring_segment(
+    center: Point<Pixels>,
+    inner_radius: Pixels,
+    outer_radius: Pixels,
+    (start, end): (f32, f32),
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let at = |angle: f32, radius: Pixels| {
+        let angle = angle - FRAC_PI_2;
+        point(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    };
+
+    let mut path = Path::new(at(start, outer_radius));
+    path.line_to(at(end, outer_radius));
+    path.line_to(at(end, inner_radius));
+    path.line_to(at(start, inner_radius));
+    path.line_to(at(start, outer_radius));
+    cx.paint_path(path, color);
+}
+
+fn paint_ring(
+    bounds: Bounds<Pixels>,
+    progress: f32,
+    track: Hsla,
+    fill: Hsla,
+    cx: &mut WindowContext,
+) {
+    let center = bounds.center();
+    let outer_radius = bounds.size.width.min(bounds.size.height) / 2.;
+    let thickness = (outer_radius * 0.25).max(px(1.5));
+    let inner_radius = outer_radius - thickness;
+
+    for segment in filled_segments(1.) {
+        paint_ring_segment(center, inner_radius, outer_radius, segment, track, cx);
+    }
+    for segment in filled_segments(progress) {
+        paint_ring_segment(center, inner_radius, outer_radius, segment, fill, cx);
+    }
+}
+
+impl RenderOnce for ProgressRing {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let progress = self.progress;
+        let track = cx.theme().colors().element_background;
+        let fill = self
+            .color
+            .map(|color| color.color(cx))
+            .unwrap_or(cx.theme().colors().text_accent);
+        let icon_size = match self.size {
+            IconSize::Medium => IconSize::XSmall,
+            _ => IconSize::Indicator,
+        };
+
+        div()
+            .relative()
+            .flex_none()
+            .size(self.size.rems())
+            .child(
+                canvas(
+                    |_, _| {},
+                    move |bounds, _, cx| paint_ring(bounds, progress, track, fill, cx),
+                )
+                .size_full(),
+            )
+            .when(self.icon.is_some() || self.label.is_some(), |this| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .inset_0()
+                        .justify_center()
+                        .children(
+                            self.icon
+                                .map(|icon| Icon::new(icon).size(icon_size).color(Color::Muted)),
+                        )
+                        .when(self.icon.is_none(), |this| {
+                            this.children(self.label.map(|label| {
+                                Label::new(label)
+                                    .size(LabelSize::XSmall)
+                                    .color(Color::Muted)
+                            }))
+                        }),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filled_segments() {
+        assert_eq!(filled_segments(0.).count(), 0);
+        assert_eq!(filled_segments(1.).count(), RING_SEGMENTS);
+
+        let half = filled_segments(0.5).collect::<Vec<_>>();
+        assert_eq!(half.len(), RING_SEGMENTS / 2);
+        assert!((half.last().unwrap().1 - TAU / 2.).abs() < 1e-4);
+
+        let partial = filled_segments(1.5 / RING_SEGMENTS as f32).collect::<Vec<_>>();
+        assert_eq!(partial.len(), 2);
+        assert!((partial[1].1 - partial[1].0 - TAU / RING_SEGMENTS as f32 / 2.).abs() < 1e-4);
+    }
+}
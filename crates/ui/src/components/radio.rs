@@ -1,9 +1,278 @@
 #![allow(missing_docs)]
 
+use std::rc::Rc;
 use std::sync::Arc;
 
+use gpui::{ClickEvent, FocusHandle, KeyDownEvent, MouseButton};
+
 use crate::prelude::*;
 
+/// A round button for picking one of several exclusive options. See [`RadioGroup`]
+/// for a set of options with a label each.
+#[derive(IntoElement)]
+pub struct Radio {
+    id: ElementId,
+    selected: bool,
+    disabled: bool,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+}
+
+impl Radio {
+    pub fn new(id: impl Into<ElementId>, selected: bool) -> Self {
+        Self {
+            id: id.into(),
+            selected,
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Radio {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        render_radio_circle(self.selected, self.disabled, None, cx)
+            .id(self.id)
+            .when(!self.disabled, |this| {
+                this.hover(|style| style.bg(cx.theme().colors().element_hover))
+            })
+            .when_some(
+                self.on_click.filter(|_| !self.disabled),
+                |this, on_click| this.on_click(move |event, cx| on_click(event, cx)),
+            )
+    }
+}
+
+/// Renders the circle of a radio button, with the hover background applied when
+/// the group of the given name is hovered.
+fn render_radio_circle(
+    selected: bool,
+    disabled: bool,
+    hover_group: Option<SharedString>,
+    cx: &WindowContext,
+) -> Div {
+    let inner_diameter = rems_from_px(6.);
+    let outer_diameter = rems_from_px(16.);
+    let border_width = rems_from_px(1.);
+    let colors = cx.theme().colors();
+    let (border_color, dot_color) = if disabled {
+        (colors.border_disabled, colors.icon_disabled)
+    } else if selected {
+        (colors.border_selected, colors.icon_accent)
+    } else {
+        (colors.border, colors.icon_accent)
+    };
+    div()
+        .flex_none()
+        .size(outer_diameter)
+        .rounded(outer_diameter / 2.)
+        .border_color(border_color)
+        .border(border_width)
+        .when(disabled, |this| this.bg(colors.element_disabled))
+        .when_some(hover_group.filter(|_| !disabled), |this, group| {
+            this.group_hover(group, |style| style.bg(colors.element_hover))
+        })
+        .when(selected, |this| {
+            this.child(
+                div()
+                    .m((outer_diameter - inner_diameter) / 2. - border_width)
+                    .size(inner_diameter)
+                    .rounded(inner_diameter / 2.)
+                    .bg(dot_color),
+            )
+        })
+}
+
+/// An option of a [`RadioGroup`].
+pub struct RadioOption {
+    label: SharedString,
+    description: Option<SharedString>,
+    disabled: bool,
+}
+
+impl RadioOption {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    /// Sets a muted line of text shown under the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Returns the next option that isn't disabled, wrapping around the ends, or the
+/// current one if all others are disabled.
+pub(crate) fn next_enabled_option(
+    disabled: &[bool],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = disabled.len();
+    let start = match current {
+        Some(current) => current,
+        // Start right before the first option, or right after the last one.
+        None if forward => count.checked_sub(1)?,
+        None => 0,
+    };
+    (1..=count)
+        .map(|offset| {
+            if forward {
+                (start + offset) % count
+            } else {
+                (start + count - offset % count) % count
+            }
+        })
+        .find(|&ix| !disabled[ix])
+}
+
+/// A set of exclusive options, each with a [`Radio`] and a label.
+///
+/// The selected option is held by the view rendering the group, which updates it
+/// in [`RadioGroup::on_change`]. While the group is focused, the arrow keys select
+/// the previous or next option.
+#[derive(IntoElement)]
+pub struct RadioGroup {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    options: Vec<RadioOption>,
+    selected: Option<usize>,
+    on_change: Option<Rc<dyn Fn(usize, &mut WindowContext) + 'static>>,
+}
+
+impl RadioGroup {
+    pub fn new(id: impl Into<ElementId>, focus_handle: &FocusHandle) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: focus_handle.clone(),
+            options: Vec::new(),
+            selected: None,
+            on_change: None,
+        }
+    }
+
+    pub fn option(mut self, option: RadioOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn options(mut self, options: impl IntoIterator<Item = RadioOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    /// Sets the index of the selected option.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Called with the index of the option the user selected.
+    pub fn on_change(mut self, handler: impl Fn(usize, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for RadioGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+        let disabled = self
+            .options
+            .iter()
+            .map(|option| option.disabled)
+            .collect::<Vec<_>>();
+        let selected = self.selected;
+        let on_change = self.on_change.clone();
+        let focus_handle = self.focus_handle.clone();
+
+        v_flex()
+            .id(self.id)
+            .track_focus(&self.focus_handle)
+            .on_mouse_down(MouseButton::Left, move |_, cx| cx.focus(&focus_handle))
+            .on_key_down(move |event: &KeyDownEvent, cx| {
+                let forward = match event.keystroke.key.as_str() {
+                    "down" | "right" => true,
+                    "up" | "left" => false,
+                    _ => return,
+                };
+                cx.stop_propagation();
+                if let Some((ix, on_change)) =
+                    next_enabled_option(&disabled, selected, forward).zip(on_change.as_ref())
+                {
+                    if Some(ix) != selected {
+                        on_change(ix, cx);
+                    }
+                }
+            })
+            .gap_1()
+            .children(self.options.into_iter().enumerate().map(|(ix, option)| {
+                let is_selected = self.selected == Some(ix);
+                let group_name = SharedString::from(format!("radio-option-{ix}"));
+                let on_change = self.on_change.clone();
+                h_flex()
+                    .id(ix)
+                    .group(group_name.clone())
+                    .items_start()
+                    .gap(Spacing::Large.rems(cx))
+                    .px_1()
+                    .rounded_sm()
+                    .when(focused && is_selected, |this| {
+                        this.bg(cx.theme().colors().ghost_element_selected)
+                    })
+                    .child(div().pt_0p5().child(render_radio_circle(
+                        is_selected,
+                        option.disabled,
+                        Some(group_name),
+                        cx,
+                    )))
+                    .child(
+                        v_flex()
+                            .child(Label::new(option.label).color(if option.disabled {
+                                Color::Disabled
+                            } else {
+                                Color::Default
+                            }))
+                            .when_some(option.description, |this, description| {
+                                this.child(Label::new(description).size(LabelSize::Small).color(
+                                    if option.disabled {
+                                        Color::Disabled
+                                    } else {
+                                        Color::Muted
+                                    },
+                                ))
+                            }),
+                    )
+                    .when_some(on_change.filter(|_| !option.disabled), |this, on_change| {
+                        this.cursor_pointer().on_click(move |_, cx| {
+                            if !is_selected {
+                                on_change(ix, cx);
+                            }
+                        })
+                    })
+            }))
+    }
+}
+
 /// A [`Checkbox`] that has a [`Label`].
 #[derive(IntoElement)]
 pub struct RadioWithLabel {
@@ -61,3 +330,21 @@ impl RenderOnce for RadioWithLabel {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_enabled_option() {
+        let disabled = [false, true, false, false];
+        assert_eq!(next_enabled_option(&disabled, Some(0), true), Some(2));
+        assert_eq!(next_enabled_option(&disabled, Some(3), true), Some(0));
+        assert_eq!(next_enabled_option(&disabled, Some(2), false), Some(0));
+        assert_eq!(next_enabled_option(&disabled, Some(0), false), Some(3));
+        assert_eq!(next_enabled_option(&disabled, None, true), Some(0));
+        assert_eq!(next_enabled_option(&disabled, None, false), Some(3));
+        assert_eq!(next_enabled_option(&[true, false], Some(1), true), Some(1));
+        assert_eq!(next_enabled_option(&[], None, true), None);
+    }
+}
@@ -9,6 +9,8 @@ use gpui::{
     WindowContext,
 };
 
+use crate::OverlayLayer;
+
 pub struct RightClickMenu<M: ManagedView> {
     id: ElementId,
     child_builder: Option<Box<dyn FnOnce(bool) -> AnyElement + 'static>>,
@@ -127,7 +129,7 @@ impl<M: ManagedView> Element for RightClickMenu<M> {
                 anchored = anchored.position(*element_state.position.borrow());
 
                 let mut element = deferred(anchored.child(div().occlude().child(menu.clone())))
-                    .with_priority(1)
+                    .with_priority(OverlayLayer::Popover.priority())
                     .into_any();
 
                 menu_layout_id = Some(element.request_layout(cx));
@@ -0,0 +1,121 @@
+#![allow(missing_docs)]
+use gpui::{ListOffset, ListState, ScrollHandle, UniformListScrollHandle};
+use serde::{Deserialize, Serialize};
+
+use crate::{prelude::*, ScrollableHandle, ScrollbarState};
+
+/// A scroll handle whose position can be read and restored in terms of its items.
+pub trait LogicalScroll {
+    /// Returns the index of the topmost visible item, and how many pixels of it are
+    /// scrolled out of view.
+    fn logical_scroll_top(&self) -> (usize, Pixels);
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels);
+}
+
+impl LogicalScroll for ScrollHandle {
+    fn logical_scroll_top(&self) -> (usize, Pixels) {
+        ScrollHandle::logical_scroll_top(self)
+    }
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        ScrollHandle::set_logical_scroll_top(self, ix, offset_in_item)
+    }
+}
+
+impl LogicalScroll for UniformListScrollHandle {
+    fn logical_scroll_top(&self) -> (usize, Pixels) {
+        UniformListScrollHandle::logical_scroll_top(self)
+    }
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        UniformListScrollHandle::set_logical_scroll_top(self, ix, offset_in_item)
+    }
+}
+
+impl LogicalScroll for ListState {
+    fn logical_scroll_top(&self) -> (usize, Pixels) {
+        let offset = ListState::logical_scroll_top(self);
+        (offset.item_ix, offset.offset_in_item)
+    }
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        self.scroll_to(ListOffset {
+            item_ix: ix,
+            offset_in_item,
+        })
+    }
+}
+
+impl LogicalScroll for ScrollableHandle {
+    fn logical_scroll_top(&self) -> (usize, Pixels) {
+        match self {
+            ScrollableHandle::Uniform(handle) => LogicalScroll::logical_scroll_top(handle),
+            ScrollableHandle::NonUniform(handle) => LogicalScroll::logical_scroll_top(handle),
+        }
+    }
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        match self {
+            ScrollableHandle::Uniform(handle) => {
+                LogicalScroll::set_logical_scroll_top(handle, ix, offset_in_item)
+            }
+            ScrollableHandle::NonUniform(handle) => {
+                LogicalScroll::set_logical_scroll_top(handle, ix, offset_in_item)
+            }
+        }
+    }
+}
+
+impl LogicalScroll for ScrollbarState {
+    fn logical_scroll_top(&self) -> (usize, Pixels) {
+        self.scroll_handle().logical_scroll_top()
+    }
+
+    fn set_logical_scroll_top(&self, ix: usize, offset_in_item: Pixels) {
+        self.scroll_handle()
+            .set_logical_scroll_top(ix, offset_in_item)
+    }
+}
+
+/// A scroll position that can be stored with the workspace.
+///
+/// The position is recorded as the key of the topmost visible item and an offset into
+/// it, rather than in raw pixels, so that it stays meaningful when items are added or
+/// removed, or when their height changes, between sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScrollAnchor {
+    pub item_key: String,
+    pub offset_in_item: f32,
+}
+
+impl ScrollAnchor {
+    /// Records the current position of `handle`, given the key of each item.
+    ///
+    /// Returns `None` if the topmost visible item has no key.
+    pub fn capture(
+        handle: &impl LogicalScroll,
+        key_for_item: impl FnOnce(usize) -> Option<String>,
+    ) -> Option<Self> {
+        let (ix, offset_in_item) = handle.logical_scroll_top();
+        Some(Self {
+            item_key: key_for_item(ix)?,
+            offset_in_item: offset_in_item.0,
+        })
+    }
+
+    /// Scrolls `handle` back to the anchored item, given the index of each key.
+    ///
+    /// Returns `false` if the item no longer exists, leaving the position unchanged.
+    pub fn restore(
+        &self,
+        handle: &impl LogicalScroll,
+        item_for_key: impl FnOnce(&str) -> Option<usize>,
+    ) -> bool {
+        let Some(ix) = item_for_key(&self.item_key) else {
+            return false;
+        };
+        handle.set_logical_scroll_top(ix, px(self.offset_in_item.max(0.)));
+        true
+    }
+}
@@ -0,0 +1,79 @@
+#![allow(missing_docs)]
+
+use gpui::AnyElement;
+use smallvec::SmallVec;
+
+use crate::{prelude::*, ScrollableHandle, Scrollbar, ScrollbarMarker, ScrollbarState};
+
+/// A vertically scrollable container with a themed [`Scrollbar`] overlaid on its trailing edge.
+///
+/// When the [`ScrollbarState`] wraps a regular scroll handle, the container scrolls its
+/// children itself. When it wraps the handle of a uniform list, the list is expected to be
+/// the only child and to track its handle, and the container only draws the scrollbar.
+#[derive(IntoElement)]
+pub struct ScrollView {
+    id: ElementId,
+    state: ScrollbarState,
+    markers: Vec<ScrollbarMarker>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl ScrollView {
+    pub fn new(id: impl Into<ElementId>, state: &ScrollbarState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            markers: Vec::new(),
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Draws the given markers along the scrollbar track.
+    pub fn markers(mut self, markers: impl IntoIterator<Item = ScrollbarMarker>) -> Self {
+        self.markers.extend(markers);
+        self
+    }
+}
+
+impl ParentElement for ScrollView {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for ScrollView {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let scroll_handle = self.state.scroll_handle();
+
+        div()
+            .id(self.id)
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .child(
+                div()
+                    .id("scroll-view-content")
+                    .size_full()
+                    .map(|this| match &scroll_handle {
+                        ScrollableHandle::NonUniform(handle) => {
+                            this.overflow_y_scroll().track_scroll(handle)
+                        }
+                        ScrollableHandle::Uniform(_) => this,
+                    })
+                    .children(self.children),
+            )
+            .children(Scrollbar::vertical(self.state).map(|scrollbar| {
+                div()
+                    .id("scroll-view-scrollbar")
+                    .occlude()
+                    .absolute()
+                    .top_0()
+                    .right_0()
+                    .bottom_0()
+                    .w(px(12.))
+                    .cursor_default()
+                    .on_any_mouse_down(|_, cx| cx.stop_propagation())
+                    .child(scrollbar.markers(self.markers))
+            }))
+    }
+}
@@ -1,18 +1,47 @@
 #![allow(missing_docs)]
-use std::{cell::Cell, ops::Range, rc::Rc};
+use std::{
+    cell::Cell,
+    ops::Range,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::{prelude::*, px, relative, IntoElement};
 use gpui::{
-    point, quad, Along, Axis as ScrollbarAxis, Bounds, ContentMask, Corners, Edges, Element,
+    fill, point, quad, Along, Axis as ScrollbarAxis, Bounds, ContentMask, Corners, Edges, Element,
     ElementId, Entity, EntityId, GlobalElementId, Hitbox, Hsla, LayoutId, MouseDownEvent,
     MouseMoveEvent, MouseUpEvent, Pixels, Point, ScrollHandle, ScrollWheelEvent, Size, Style,
     UniformListScrollHandle, View, WindowContext,
 };
 
+/// How long an auto-hiding scrollbar stays visible after the last scroll.
+const AUTO_HIDE_DELAY: Duration = Duration::from_secs(1);
+
 pub struct Scrollbar {
     thumb: Range<f32>,
     state: ScrollbarState,
     kind: ScrollbarAxis,
+    markers: Vec<ScrollbarMarker>,
+}
+
+/// A colored tick drawn along the scrollbar track, e.g. for a diagnostic or a search result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollbarMarker {
+    /// The range covered by the marker, as fractions (`0.` to `1.`) of the content length.
+    pub range: Range<f32>,
+    pub color: Hsla,
+}
+
+impl ScrollbarMarker {
+    pub fn new(range: Range<f32>, color: Hsla) -> Self {
+        Self { range, color }
+    }
+
+    /// Creates a marker for the item at `ix` within a list of `count` items.
+    pub fn for_item(ix: usize, count: usize, color: Hsla) -> Self {
+        let count = count.max(1) as f32;
+        Self::new(ix as f32 / count..(ix + 1) as f32 / count, color)
+    }
 }
 
 /// Wrapper around scroll handles.
@@ -97,6 +126,10 @@ pub struct ScrollbarState {
     drag: Rc<Cell<Option<f32>>>,
     parent_id: Option<EntityId>,
     scroll_handle: ScrollableHandle,
+    auto_hide: bool,
+    /// The scroll offset the last time the scrollbar was painted, to detect scrolling.
+    last_offset: Rc<Cell<Option<Point<Pixels>>>>,
+    visible_until: Rc<Cell<Option<Instant>>>,
 }
 
 impl ScrollbarState {
@@ -105,9 +138,20 @@ impl ScrollbarState {
             drag: Default::default(),
             parent_id: None,
             scroll_handle: scroll.into(),
+            auto_hide: false,
+            last_offset: Default::default(),
+            visible_until: Default::default(),
         }
     }
 
+    /// Only show the thumb while scrolling, and while the scrollbar is hovered or dragged.
+    ///
+    /// Requires a [parent view](Self::parent_view) that is notified when the scrollbar hides.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
     /// Set a parent view which should be notified whenever this Scrollbar gets a scroll event.
     pub fn parent_view<V: 'static>(mut self, v: &View<V>) -> Self {
         self.parent_id = Some(v.entity_id());
@@ -122,6 +166,35 @@ impl ScrollbarState {
         self.drag.get().is_some()
     }
 
+    /// Returns whether an auto-hiding scrollbar should be shown, and keeps it visible
+    /// for a while after the content was scrolled.
+    fn is_visible(&self, is_hovered: bool, cx: &mut WindowContext) -> bool {
+        if !self.auto_hide || is_hovered || self.is_dragging() {
+            return true;
+        }
+
+        let offset = self.scroll_handle.offset();
+        let was_scrolled = self
+            .last_offset
+            .replace(Some(offset))
+            .map_or(false, |last_offset| last_offset != offset);
+        if was_scrolled {
+            self.visible_until
+                .set(Some(Instant::now() + AUTO_HIDE_DELAY));
+            if let Some(parent_id) = self.parent_id {
+                cx.spawn(|mut cx| async move {
+                    cx.background_executor().timer(AUTO_HIDE_DELAY).await;
+                    cx.update(|cx| cx.notify(parent_id)).ok();
+                })
+                .detach();
+            }
+        }
+
+        self.visible_until
+            .get()
+            .map_or(false, |visible_until| Instant::now() < visible_until)
+    }
+
     fn thumb_range(&self, axis: ScrollbarAxis) -> Option<Range<f32>> {
         const MINIMUM_SCROLLBAR_PERCENTAGE_SIZE: f32 = 0.005;
         let ContentSize {
@@ -172,7 +245,18 @@ impl Scrollbar {
     }
     fn new(state: ScrollbarState, kind: ScrollbarAxis) -> Option<Self> {
         let thumb = state.thumb_range(kind)?;
-        Some(Self { thumb, state, kind })
+        Some(Self {
+            thumb,
+            state,
+            kind,
+            markers: Vec::new(),
+        })
+    }
+
+    /// Draws the given markers along the track.
+    pub fn markers(mut self, markers: impl IntoIterator<Item = ScrollbarMarker>) -> Self {
+        self.markers.extend(markers);
+        self
     }
 }
 
@@ -222,12 +306,20 @@ impl Element for Scrollbar {
         _id: Option<&GlobalElementId>,
         bounds: Bounds<Pixels>,
         _request_layout: &mut Self::RequestLayoutState,
-        _prepaint: &mut Self::PrepaintState,
+        hitbox: &mut Self::PrepaintState,
         cx: &mut WindowContext,
     ) {
         cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            let is_hovered = hitbox.is_hovered(cx);
+            let is_visible = self.state.is_visible(is_hovered, cx);
+            let is_expanded = is_hovered || self.state.is_dragging();
             let colors = cx.theme().colors();
-            let thumb_background = colors.scrollbar_thumb_background;
+            let thumb_background = if is_expanded {
+                colors.scrollbar_thumb_hover_background
+            } else {
+                colors.scrollbar_thumb_background
+            };
+            let track_background = colors.scrollbar_track_background;
             let is_vertical = self.kind == ScrollbarAxis::Vertical;
             let extra_padding = px(5.0);
             let padded_bounds = if is_vertical {
@@ -267,20 +359,57 @@ impl Element for Scrollbar {
                 );
                 Bounds::from_corners(thumb_upper_left, thumb_lower_right)
             };
+            // The thumb widens to the full width of the scrollbar while it's hovered or dragged.
             let corners = if is_vertical {
-                thumb_bounds.size.width /= 1.5;
+                if !is_expanded {
+                    thumb_bounds.size.width /= 1.5;
+                }
                 Corners::all(thumb_bounds.size.width / 2.0)
             } else {
-                thumb_bounds.size.height /= 1.5;
+                if !is_expanded {
+                    thumb_bounds.size.height /= 1.5;
+                }
                 Corners::all(thumb_bounds.size.height / 2.0)
             };
-            cx.paint_quad(quad(
-                thumb_bounds,
-                corners,
-                thumb_background,
-                Edges::default(),
-                Hsla::transparent_black(),
-            ));
+
+            if is_visible {
+                if is_expanded {
+                    cx.paint_quad(fill(padded_bounds, track_background));
+                }
+
+                for marker in &self.markers {
+                    let marker_bounds = if is_vertical {
+                        let start = padded_bounds.size.height * marker.range.start.clamp(0., 1.);
+                        let end = padded_bounds.size.height * marker.range.end.clamp(0., 1.);
+                        Bounds::from_corners(
+                            point(padded_bounds.origin.x, padded_bounds.origin.y + start),
+                            point(
+                                padded_bounds.right(),
+                                padded_bounds.origin.y + end.max(start + px(2.)),
+                            ),
+                        )
+                    } else {
+                        let start = padded_bounds.size.width * marker.range.start.clamp(0., 1.);
+                        let end = padded_bounds.size.width * marker.range.end.clamp(0., 1.);
+                        Bounds::from_corners(
+                            point(padded_bounds.origin.x + start, padded_bounds.origin.y),
+                            point(
+                                padded_bounds.origin.x + end.max(start + px(2.)),
+                                padded_bounds.bottom(),
+                            ),
+                        )
+                    };
+                    cx.paint_quad(fill(marker_bounds, marker.color));
+                }
+
+                cx.paint_quad(quad(
+                    thumb_bounds,
+                    corners,
+                    thumb_background,
+                    Edges::default(),
+                    Hsla::transparent_black(),
+                ));
+            }
 
             let scroll = self.state.scroll_handle.clone();
             let kind = self.kind;
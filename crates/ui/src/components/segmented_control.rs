@@ -0,0 +1,206 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+
+use gpui::{FocusHandle, KeyDownEvent, MouseButton};
+
+use crate::{next_enabled_option, prelude::*, ButtonLike, ButtonLikeRounding, Tooltip};
+
+/// A segment of a [`SegmentedControl`], with an icon, a label or both.
+pub struct Segment {
+    icon: Option<IconName>,
+    label: Option<SharedString>,
+    tooltip: Option<SharedString>,
+    disabled: bool,
+}
+
+impl Segment {
+    pub fn new() -> Self {
+        Self {
+            icon: None,
+            label: None,
+            tooltip: None,
+            disabled: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the tooltip of the segment, which is also announced by screen readers.
+    /// Segments with only an icon should have one.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Default for Segment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A row of exclusive toggle buttons sharing their borders, for switching between a
+/// few modes, like the kinds of search or the layouts of a view.
+///
+/// The selected segment is held by the view rendering the control, which updates it
+/// in [`SegmentedControl::on_change`]. While the control is focused, the arrow keys
+/// select the previous or next segment.
+#[derive(IntoElement)]
+pub struct SegmentedControl {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    segments: Vec<Segment>,
+    selected: Option<usize>,
+    size: ButtonSize,
+    on_change: Option<Rc<dyn Fn(usize, &mut WindowContext) + 'static>>,
+}
+
+impl SegmentedControl {
+    pub fn new(id: impl Into<ElementId>, focus_handle: &FocusHandle) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: focus_handle.clone(),
+            segments: Vec::new(),
+            selected: None,
+            size: ButtonSize::Default,
+            on_change: None,
+        }
+    }
+
+    pub fn segment(mut self, segment: Segment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    pub fn segments(mut self, segments: impl IntoIterator<Item = Segment>) -> Self {
+        self.segments.extend(segments);
+        self
+    }
+
+    /// Sets the index of the selected segment.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Called with the index of the segment the user selected.
+    pub fn on_change(mut self, handler: impl Fn(usize, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for SegmentedControl {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+        let disabled = self
+            .segments
+            .iter()
+            .map(|segment| segment.disabled)
+            .collect::<Vec<_>>();
+        let selected = self.selected;
+        let on_change = self.on_change.clone();
+        let focus_handle = self.focus_handle.clone();
+        let last_ix = self.segments.len().saturating_sub(1);
+        let separator_color = cx.theme().colors().border;
+        let border_color = if focused {
+            cx.theme().colors().border_focused
+        } else {
+            cx.theme().colors().border
+        };
+
+        h_flex()
+            .id(self.id)
+            .track_focus(&self.focus_handle)
+            .on_mouse_down(MouseButton::Left, move |_, cx| cx.focus(&focus_handle))
+            .on_key_down(move |event: &KeyDownEvent, cx| {
+                let forward = match event.keystroke.key.as_str() {
+                    "right" => true,
+                    "left" => false,
+                    _ => return,
+                };
+                cx.stop_propagation();
+                if let Some((ix, on_change)) =
+                    next_enabled_option(&disabled, selected, forward).zip(on_change.as_ref())
+                {
+                    if Some(ix) != selected {
+                        on_change(ix, cx);
+                    }
+                }
+            })
+            .flex_none()
+            .rounded_md()
+            .border_1()
+            .border_color(border_color)
+            .children(self.segments.into_iter().enumerate().map(|(ix, segment)| {
+                let is_selected = self.selected == Some(ix);
+                let on_change = self.on_change.clone();
+                let color = if segment.disabled {
+                    Color::Disabled
+                } else if is_selected {
+                    Color::Selected
+                } else {
+                    Color::Default
+                };
+                let rounding = match (ix == 0, ix == last_ix) {
+                    (true, true) => Some(ButtonLikeRounding::All),
+                    (true, false) => Some(ButtonLikeRounding::Left),
+                    (false, true) => Some(ButtonLikeRounding::Right),
+                    (false, false) => None,
+                };
+                let accessible_name = segment.tooltip.clone().or(segment.label.clone());
+
+                ButtonLike::new(ix)
+                    .rounding(rounding)
+                    .size(self.size)
+                    .style(ButtonStyle::Subtle)
+                    .selected_style(ButtonStyle::Filled)
+                    .selected(is_selected)
+                    .disabled(segment.disabled)
+                    .when(ix > 0, |this| {
+                        this.override_style(move |style| {
+                            style.border_l_1().border_color(separator_color)
+                        })
+                    })
+                    .when_some(accessible_name, |this, name| this.accessible_name(name))
+                    .when_some(segment.tooltip, |this, tooltip| {
+                        this.tooltip(move |cx| Tooltip::text(tooltip.clone(), cx))
+                    })
+                    .when_some(on_change, |this, on_change| {
+                        this.on_click(move |_, cx| {
+                            if !is_selected {
+                                on_change(ix, cx);
+                            }
+                        })
+                    })
+                    .children(
+                        segment
+                            .icon
+                            .map(|icon| Icon::new(icon).size(IconSize::Small).color(color)),
+                    )
+                    .children(segment.label.map(|label| {
+                        Label::new(label)
+                            .color(color)
+                            .line_height_style(LineHeightStyle::UiLabel)
+                    }))
+            }))
+    }
+}
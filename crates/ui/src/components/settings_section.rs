@@ -0,0 +1,125 @@
+#![allow(missing_docs)]
+
+use gpui::{AnyElement, FontWeight};
+use smallvec::SmallVec;
+
+use crate::{prelude::*, Divider};
+
+/// A titled section of a settings page, with an optional description and a
+/// divider above its rows, which are usually [`SettingsRow`]s.
+#[derive(IntoElement)]
+pub struct SettingsSection {
+    title: SharedString,
+    description: Option<SharedString>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl SettingsSection {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets a muted line of text shown under the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl ParentElement for SettingsSection {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for SettingsSection {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        v_flex()
+            .w_full()
+            .gap(Spacing::Large.rems(cx))
+            .child(
+                v_flex()
+                    .gap_0p5()
+                    .child(Label::new(self.title).weight(FontWeight::SEMIBOLD))
+                    .when_some(self.description, |this, description| {
+                        this.child(
+                            Label::new(description)
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        )
+                    }),
+            )
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap(Spacing::XLarge.rems(cx))
+                    .children(self.children),
+            )
+    }
+}
+
+/// A row of a [`SettingsSection`], with a label and an optional description on
+/// the left and the control changing the setting on the right.
+///
+/// When the row is too narrow for both, the control wraps under the label.
+#[derive(IntoElement)]
+pub struct SettingsRow {
+    label: SharedString,
+    description: Option<SharedString>,
+    control: Option<AnyElement>,
+}
+
+impl SettingsRow {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            control: None,
+        }
+    }
+
+    /// Sets a muted line of text shown under the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the control changing the setting, like a [`Checkbox`](crate::Checkbox)
+    /// or a [`DropdownMenu`](crate::DropdownMenu).
+    pub fn control(mut self, control: impl IntoElement) -> Self {
+        self.control = Some(control.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for SettingsRow {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .flex_wrap()
+            .justify_between()
+            .gap_x(Spacing::XLarge.rems(cx))
+            .gap_y(Spacing::Small.rems(cx))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_w(rems(12.))
+                    .child(Label::new(self.label))
+                    .when_some(self.description, |this, description| {
+                        this.child(
+                            Label::new(description)
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        )
+                    }),
+            )
+            .children(
+                self.control
+                    .map(|control| h_flex().flex_none().child(control)),
+            )
+    }
+}
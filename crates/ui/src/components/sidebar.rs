@@ -0,0 +1,371 @@
+#![allow(missing_docs)]
+use std::rc::Rc;
+
+use collections::HashMap;
+use gpui::{
+    AnimationExt, AnyElement, AppContext, CursorStyle, DragMoveEvent, Global, MouseButton,
+    MouseUpEvent, Render,
+};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use theme::{transition, MotionDuration, MotionEasing};
+
+use crate::prelude::*;
+
+const HANDLE_SIZE: f32 = 6.;
+/// The width of the icon rail shown in place of a collapsed sidebar.
+const RAIL_WIDTH: f32 = 36.;
+
+/// The edge of the window a [`Sidebar`] is docked to. The drag handle is on the
+/// other edge of the sidebar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarSide {
+    #[default]
+    Left,
+    Right,
+}
+
+/// The width of a [`Sidebar`] and whether it is collapsed, in a form that can be
+/// stored with the workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SidebarLayout {
+    /// The width of the sidebar when expanded. Kept while the sidebar is collapsed.
+    pub width: Pixels,
+    pub collapsed: bool,
+}
+
+struct SidebarLayoutEntry {
+    layout: SidebarLayout,
+    /// Incremented whenever the sidebar collapses or expands, to restart the
+    /// animation between the two.
+    generation: usize,
+}
+
+/// The layouts of the sidebars of the app, by key, so that a sidebar keeps its
+/// width when the view rendering it is recreated.
+#[derive(Default)]
+struct SidebarLayouts(HashMap<SharedString, SidebarLayoutEntry>);
+
+impl Global for SidebarLayouts {}
+
+/// Identifies the sidebar being resized while its handle is dragged.
+#[derive(Clone)]
+struct DraggedSidebar(SharedString);
+
+impl Render for DraggedSidebar {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        gpui::Empty
+    }
+}
+
+/// Returns the layout of a sidebar dragged to `width`: below `collapse_width` it
+/// collapses and keeps its previous width, and otherwise the width is clamped
+/// between `min_width` and `max_width`.
+fn resize_sidebar(
+    layout: SidebarLayout,
+    width: Pixels,
+    collapse_width: Pixels,
+    min_width: Pixels,
+    max_width: Pixels,
+) -> SidebarLayout {
+    if width < collapse_width {
+        SidebarLayout {
+            width: layout.width,
+            collapsed: true,
+        }
+    } else {
+        SidebarLayout {
+            width: width.clamp(min_width, max_width.max(min_width)),
+            collapsed: false,
+        }
+    }
+}
+
+/// A panel docked to the side of the window, resized by dragging its edge.
+///
+/// Dragging the edge below the collapse width, or double-clicking it, collapses the
+/// sidebar to an icon rail, animating the change. The width is kept by key for the
+/// rest of the session; use [`Sidebar::on_layout_change`] and
+/// [`Sidebar::restore_layout`] to keep it across sessions.
+#[derive(IntoElement)]
+pub struct Sidebar {
+    key: SharedString,
+    side: SidebarSide,
+    default_width: Pixels,
+    min_width: Pixels,
+    max_width: Pixels,
+    collapse_width: Pixels,
+    rail: Option<AnyElement>,
+    on_layout_change: Option<Rc<dyn Fn(&SidebarLayout, &mut WindowContext)>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Sidebar {
+    /// Creates a sidebar whose layout is kept under the given key, like
+    /// `"project_panel"`.
+    pub fn new(key: impl Into<SharedString>) -> Self {
+        Self {
+            key: key.into(),
+            side: SidebarSide::default(),
+            default_width: px(240.),
+            min_width: px(160.),
+            max_width: px(640.),
+            collapse_width: px(80.),
+            rail: None,
+            on_layout_change: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Returns the layout of the sidebar with the given key, if it was rendered or
+    /// restored.
+    pub fn layout(key: &str, cx: &AppContext) -> Option<SidebarLayout> {
+        cx.try_global::<SidebarLayouts>()?
+            .0
+            .get(key)
+            .map(|entry| entry.layout)
+    }
+
+    /// Restores a layout, e.g. one stored with the workspace, for the sidebar with
+    /// the given key.
+    pub fn restore_layout(
+        key: impl Into<SharedString>,
+        layout: SidebarLayout,
+        cx: &mut AppContext,
+    ) {
+        cx.default_global::<SidebarLayouts>().0.insert(
+            key.into(),
+            SidebarLayoutEntry {
+                layout,
+                generation: 0,
+            },
+        );
+        cx.refresh();
+    }
+
+    /// Collapses the sidebar with the given key if it is expanded, and expands it
+    /// otherwise.
+    pub fn toggle_collapsed(key: &str, cx: &mut AppContext) {
+        let collapsed = Self::layout(key, cx).is_some_and(|layout| layout.collapsed);
+        Self::set_collapsed(key, !collapsed, cx);
+    }
+
+    /// Collapses or expands the sidebar with the given key, once it was rendered or
+    /// restored.
+    pub fn set_collapsed(key: &str, collapsed: bool, cx: &mut AppContext) {
+        let Some(entry) = cx.default_global::<SidebarLayouts>().0.get_mut(key) else {
+            return;
+        };
+        if entry.layout.collapsed != collapsed {
+            entry.layout.collapsed = collapsed;
+            entry.generation += 1;
+            cx.refresh();
+        }
+    }
+
+    pub fn side(mut self, side: SidebarSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the width of the sidebar until it's resized. Defaults to 240px.
+    pub fn default_width(mut self, width: Pixels) -> Self {
+        self.default_width = width;
+        self
+    }
+
+    /// Sets the narrowest the sidebar can be resized to. Defaults to 160px.
+    pub fn min_width(mut self, width: Pixels) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Sets the widest the sidebar can be resized to. Defaults to 640px.
+    pub fn max_width(mut self, width: Pixels) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Sets the width below which dragging the edge collapses the sidebar. Defaults
+    /// to 80px.
+    pub fn collapse_width(mut self, width: Pixels) -> Self {
+        self.collapse_width = width;
+        self
+    }
+
+    /// Sets the content shown while the sidebar is collapsed, usually a column of
+    /// icon buttons.
+    pub fn rail(mut self, rail: impl IntoElement) -> Self {
+        self.rail = Some(rail.into_any_element());
+        self
+    }
+
+    /// Called after the sidebar was resized, collapsed or expanded, e.g. to store
+    /// the new layout with the workspace.
+    pub fn on_layout_change(
+        mut self,
+        handler: impl Fn(&SidebarLayout, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_layout_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn entry(&self, cx: &mut WindowContext) -> (SidebarLayout, usize) {
+        let default_width = self.default_width;
+        let entry = cx
+            .default_global::<SidebarLayouts>()
+            .0
+            .entry(self.key.clone())
+            .or_insert_with(|| SidebarLayoutEntry {
+                layout: SidebarLayout {
+                    width: default_width,
+                    collapsed: false,
+                },
+                generation: 0,
+            });
+        (entry.layout, entry.generation)
+    }
+
+    fn render_handle(&self, cx: &WindowContext) -> impl IntoElement {
+        let key = self.key.clone();
+        let on_layout_change = self.on_layout_change.clone();
+        div()
+            .id("sidebar-resize-handle")
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .w(px(HANDLE_SIZE))
+            .map(|this| match self.side {
+                SidebarSide::Left => this.right(px(-HANDLE_SIZE / 2.)),
+                SidebarSide::Right => this.left(px(-HANDLE_SIZE / 2.)),
+            })
+            .cursor(CursorStyle::ResizeLeftRight)
+            .hover(|style| style.bg(cx.theme().colors().border_focused.opacity(0.5)))
+            .on_drag(DraggedSidebar(self.key.clone()), |dragged, cx| {
+                cx.stop_propagation();
+                cx.new_view(|_| dragged.clone())
+            })
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .on_mouse_up(MouseButton::Left, move |event: &MouseUpEvent, cx| {
+                if event.click_count == 2 {
+                    Sidebar::toggle_collapsed(&key, cx);
+                    if let Some((on_layout_change, layout)) =
+                        on_layout_change.as_ref().zip(Sidebar::layout(&key, cx))
+                    {
+                        on_layout_change(&layout, cx);
+                    }
+                }
+            })
+    }
+}
+
+impl ParentElement for Sidebar {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Sidebar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (layout, generation) = self.entry(cx);
+        let rail_width = px(RAIL_WIDTH);
+        let (from_width, to_width) = if layout.collapsed {
+            (layout.width, rail_width)
+        } else {
+            (rail_width, layout.width)
+        };
+
+        let key = self.key.clone();
+        let side = self.side;
+        let (collapse_width, min_width, max_width) =
+            (self.collapse_width, self.min_width, self.max_width);
+        let on_layout_change = self.on_layout_change.clone();
+        let handle = self.render_handle(cx);
+
+        let container = div()
+            .id(ElementId::Name(self.key.clone()))
+            .relative()
+            .flex_none()
+            .h_full()
+            .w(to_width)
+            .bg(cx.theme().colors().panel_background)
+            .map(|this| match side {
+                SidebarSide::Left => this.border_r_1(),
+                SidebarSide::Right => this.border_l_1(),
+            })
+            .border_color(cx.theme().colors().border)
+            .on_drag_move(move |event: &DragMoveEvent<DraggedSidebar>, cx| {
+                if event.drag(cx).0 != key {
+                    return;
+                }
+                let width = match side {
+                    SidebarSide::Left => event.event.position.x - event.bounds.left(),
+                    SidebarSide::Right => event.bounds.right() - event.event.position.x,
+                };
+                let Some(previous) = Sidebar::layout(&key, cx) else {
+                    return;
+                };
+                let layout = resize_sidebar(previous, width, collapse_width, min_width, max_width);
+                if layout == previous {
+                    return;
+                }
+                if let Some(entry) = cx.default_global::<SidebarLayouts>().0.get_mut(&key) {
+                    if entry.layout.collapsed != layout.collapsed {
+                        entry.generation += 1;
+                    }
+                    entry.layout = layout;
+                }
+                if let Some(on_layout_change) = on_layout_change.as_ref() {
+                    on_layout_change(&layout, cx);
+                }
+                cx.refresh();
+            })
+            .child(div().size_full().overflow_hidden().map(|this| {
+                if layout.collapsed {
+                    this.children(self.rail)
+                } else {
+                    this.children(self.children)
+                }
+            }))
+            .child(handle);
+
+        // Only animate after the sidebar collapsed or expanded, not when it first
+        // renders.
+        if generation == 0 {
+            container.into_any_element()
+        } else {
+            container
+                .with_animation(
+                    ("sidebar-collapse", generation),
+                    transition(MotionDuration::Normal, MotionEasing::Standard, cx),
+                    move |this, delta| this.w(from_width + (to_width - from_width) * delta),
+                )
+                .into_any_element()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_sidebar() {
+        let layout = SidebarLayout {
+            width: px(240.),
+            collapsed: false,
+        };
+        let resize = |width| resize_sidebar(layout, px(width), px(80.), px(160.), px(640.));
+
+        assert_eq!(resize(300.).width, px(300.));
+        assert_eq!(resize(100.).width, px(160.));
+        assert_eq!(resize(900.).width, px(640.));
+        assert_eq!(
+            resize(40.),
+            SidebarLayout {
+                width: px(240.),
+                collapsed: true
+            }
+        );
+    }
+}
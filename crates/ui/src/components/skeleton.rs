@@ -0,0 +1,156 @@
+#![allow(missing_docs)]
+use std::time::Duration;
+
+use gpui::{pulsating_between, Animation, AnimationExt};
+use theme::animation_duration;
+
+use crate::prelude::*;
+
+const SHIMMER_DURATION: Duration = Duration::from_millis(1500);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SkeletonShape {
+    /// A line of text.
+    Line,
+    /// An avatar or icon.
+    Circle,
+    /// A larger area, like an image or a code block.
+    Block,
+}
+
+/// A placeholder shown in place of content that is still loading, shimmering
+/// between the surface and hover colors of the theme.
+#[derive(IntoElement)]
+pub struct Skeleton {
+    id: ElementId,
+    shape: SkeletonShape,
+    width: Option<DefiniteLength>,
+    height: Option<DefiniteLength>,
+}
+
+impl Skeleton {
+    pub fn new(id: impl Into<ElementId>, shape: SkeletonShape) -> Self {
+        Self {
+            id: id.into(),
+            shape,
+            width: None,
+            height: None,
+        }
+    }
+
+    pub fn line(id: impl Into<ElementId>) -> Self {
+        Self::new(id, SkeletonShape::Line)
+    }
+
+    pub fn circle(id: impl Into<ElementId>) -> Self {
+        Self::new(id, SkeletonShape::Circle)
+    }
+
+    pub fn block(id: impl Into<ElementId>) -> Self {
+        Self::new(id, SkeletonShape::Block)
+    }
+
+    /// Sets the width of the placeholder. Lines and blocks fill their container by default.
+    pub fn width(mut self, width: impl Into<DefiniteLength>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Sets the height of the placeholder. For circles, this also sets the width.
+    pub fn height(mut self, height: impl Into<DefiniteLength>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+}
+
+impl RenderOnce for Skeleton {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let base_color = cx.theme().colors().element_background;
+        let highlight_color = cx.theme().colors().element_hover;
+
+        let (width, height) = match self.shape {
+            SkeletonShape::Line => (
+                self.width.unwrap_or(relative(1.)),
+                self.height.unwrap_or(rems(0.75).into()),
+            ),
+            SkeletonShape::Circle => {
+                let diameter = self.height.or(self.width).unwrap_or(rems(1.5).into());
+                (diameter, diameter)
+            }
+            SkeletonShape::Block => (
+                self.width.unwrap_or(relative(1.)),
+                self.height.unwrap_or(rems(4.).into()),
+            ),
+        };
+
+        div()
+            .id(self.id.clone())
+            .flex_none()
+            .w(width)
+            .h(height)
+            .map(|this| match self.shape {
+                SkeletonShape::Circle => this.rounded_full(),
+                SkeletonShape::Line => this.rounded_sm(),
+                SkeletonShape::Block => this.rounded_md(),
+            })
+            .bg(base_color)
+            .with_animation(
+                self.id,
+                Animation::new(animation_duration(SHIMMER_DURATION, cx))
+                    .repeat()
+                    .with_easing(pulsating_between(0., 1.)),
+                move |this, delta| this.bg(base_color.blend(highlight_color.opacity(delta))),
+            )
+    }
+}
+
+/// A column of placeholder rows, resembling a list that is still loading.
+#[derive(IntoElement)]
+pub struct SkeletonList {
+    id: ElementId,
+    row_count: usize,
+    show_avatars: bool,
+}
+
+impl SkeletonList {
+    pub fn new(id: impl Into<ElementId>, row_count: usize) -> Self {
+        Self {
+            id: id.into(),
+            row_count,
+            show_avatars: false,
+        }
+    }
+
+    /// Shows a circle at the start of each row, e.g. for lists of people.
+    pub fn show_avatars(mut self, show_avatars: bool) -> Self {
+        self.show_avatars = show_avatars;
+        self
+    }
+}
+
+impl RenderOnce for SkeletonList {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        // Vary the width of the rows, so that the placeholder reads as a list of items.
+        const ROW_WIDTHS: [f32; 4] = [0.8, 0.6, 0.7, 0.5];
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .gap_2()
+            .p_2()
+            .children((0..self.row_count).map(|ix| {
+                h_flex()
+                    .w_full()
+                    .gap_2()
+                    .when(self.show_avatars, |this| {
+                        this.child(Skeleton::circle(("skeleton-avatar", ix)))
+                    })
+                    .child(
+                        div().flex_1().child(
+                            Skeleton::line(("skeleton-line", ix))
+                                .width(relative(ROW_WIDTHS[ix % ROW_WIDTHS.len()])),
+                        ),
+                    )
+            }))
+    }
+}
@@ -0,0 +1,646 @@
+#![allow(missing_docs)]
+use std::{cell::RefCell, mem, rc::Rc};
+
+use gpui::{
+    actions, fill, point, size, Along, AnyElement, AvailableSpace, Axis, Bounds, CursorStyle,
+    FocusHandle, GlobalElementId, Hitbox, KeyContext, LayoutId, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, Style,
+};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+const DIVIDER_SIZE: f32 = 1.;
+const HANDLE_HITBOX_SIZE: f32 = 4.;
+/// How far a focused divider moves per key press.
+const KEYBOARD_STEP: f32 = 16.;
+
+actions!(
+    split_pane,
+    [MoveDividerBackward, MoveDividerForward, ResetSplitRatio]
+);
+
+/// The split ratios of a [`SplitPane`], in a form that can be stored with the workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializedSplitPane {
+    pub ratios: Vec<f32>,
+}
+
+#[derive(Debug, Default)]
+struct SplitPaneStateInner {
+    /// The share of the container taken up by each pane. Sums to `1.`.
+    ratios: Vec<f32>,
+    /// The ratios restored by [`SplitPaneState::reset`], instead of an even split.
+    default_ratios: Vec<f32>,
+    dragged_divider: Option<usize>,
+    divider_focus_handles: Vec<FocusHandle>,
+}
+
+/// The split ratios of a [`SplitPane`].
+///
+/// Keep this on the view that renders the split, so that the ratios survive across
+/// renders, and serialize it to restore the layout across sessions.
+///
+/// It also holds the focus handles of the dividers, which are focused by clicking
+/// them or with [`SplitPaneState::focus_divider`], and then resized with the arrow
+/// keys.
+#[derive(Debug, Clone, Default)]
+pub struct SplitPaneState(Rc<RefCell<SplitPaneStateInner>>);
+
+impl SplitPaneState {
+    /// Creates a state that splits the space evenly between the panes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a state that splits the space according to the given ratios, and
+    /// returns to them when reset.
+    pub fn with_default_ratios(ratios: Vec<f32>) -> Self {
+        let state = Self::new();
+        state.0.borrow_mut().default_ratios = ratios;
+        state
+    }
+
+    pub fn ratios(&self) -> Vec<f32> {
+        self.0.borrow().ratios.clone()
+    }
+
+    /// Resets the panes to their default ratios, or to an even split.
+    pub fn reset(&self) {
+        self.0.borrow_mut().ratios.clear();
+    }
+
+    /// Focuses the divider after the pane at `divider_ix`, so that it can be moved
+    /// with the keyboard.
+    pub fn focus_divider(&self, divider_ix: usize, cx: &mut WindowContext) {
+        let handle = self
+            .0
+            .borrow()
+            .divider_focus_handles
+            .get(divider_ix)
+            .cloned();
+        if let Some(handle) = handle {
+            cx.focus(&handle);
+        }
+    }
+
+    pub fn serialize(&self) -> SerializedSplitPane {
+        SerializedSplitPane {
+            ratios: self.ratios(),
+        }
+    }
+
+    /// Restores serialized ratios. Invalid ratios are discarded in favor of an even split.
+    pub fn deserialize(serialized: &SerializedSplitPane) -> Self {
+        let state = Self::new();
+        let total = serialized.ratios.iter().sum::<f32>();
+        let is_valid = serialized
+            .ratios
+            .iter()
+            .all(|ratio| ratio.is_finite() && *ratio > 0.)
+            && (total - 1.).abs() < 0.001;
+        if is_valid {
+            state.0.borrow_mut().ratios = serialized.ratios.clone();
+        }
+        state
+    }
+
+    /// Returns the ratios for the given number of panes, falling back to an even
+    /// split when the number of panes changed.
+    fn ratios_for(&self, pane_count: usize) -> Vec<f32> {
+        let mut state = self.0.borrow_mut();
+        if state.ratios.len() != pane_count {
+            state.ratios = if state.default_ratios.len() == pane_count {
+                state.default_ratios.clone()
+            } else {
+                vec![1. / pane_count as f32; pane_count]
+            };
+        }
+        state.ratios.clone()
+    }
+
+    fn divider_focus_handles(&self, divider_count: usize, cx: &WindowContext) -> Vec<FocusHandle> {
+        let mut state = self.0.borrow_mut();
+        state
+            .divider_focus_handles
+            .resize_with(divider_count, || cx.focus_handle());
+        state.divider_focus_handles.clone()
+    }
+}
+
+/// Moves the divider after the pane at `divider_ix` to `position`, measured in pixels
+/// from the start of the container, keeping both adjacent panes between `min_size`
+/// and `max_size`. Returns whether the ratios changed.
+pub fn drag_split_divider(
+    ratios: &mut [f32],
+    divider_ix: usize,
+    position: f32,
+    container_size: f32,
+    min_size: f32,
+    max_size: f32,
+) -> bool {
+    if divider_ix + 1 >= ratios.len() || container_size <= 0. {
+        return false;
+    }
+
+    let start = ratios[..divider_ix].iter().sum::<f32>() * container_size;
+    let pair_size = (ratios[divider_ix] + ratios[divider_ix + 1]) * container_size;
+    let lower = min_size.max(pair_size - max_size);
+    let upper = max_size.min(pair_size - min_size);
+    if lower > upper {
+        return false;
+    }
+
+    let new_size = (position - start).clamp(lower, upper);
+    let new_ratio = new_size / container_size;
+    if (new_ratio - ratios[divider_ix]).abs() < f32::EPSILON {
+        return false;
+    }
+    let pair_ratio = ratios[divider_ix] + ratios[divider_ix + 1];
+    ratios[divider_ix] = new_ratio;
+    ratios[divider_ix + 1] = pair_ratio - new_ratio;
+    true
+}
+
+/// Moves the divider after the pane at `divider_ix` by `delta` pixels, keeping both
+/// adjacent panes between `min_size` and `max_size`. Returns whether the ratios
+/// changed.
+pub fn move_split_divider(
+    ratios: &mut [f32],
+    divider_ix: usize,
+    delta: f32,
+    container_size: f32,
+    min_size: f32,
+    max_size: f32,
+) -> bool {
+    if divider_ix + 1 >= ratios.len() {
+        return false;
+    }
+    let position = ratios[..=divider_ix].iter().sum::<f32>() * container_size + delta;
+    drag_split_divider(
+        ratios,
+        divider_ix,
+        position,
+        container_size,
+        min_size,
+        max_size,
+    )
+}
+
+/// Lays out its children side by side along an axis, separated by dividers that can
+/// be dragged to resize the adjacent panes, or double-clicked to reset the split.
+///
+/// A focused divider is moved with the arrow keys, showing the sizes of the adjacent
+/// panes while it is focused, and [`ResetSplitRatio`] resets the split.
+///
+/// Split panes can be nested to build more complex layouts, each with its own
+/// [`SplitPaneState`].
+pub struct SplitPane {
+    id: ElementId,
+    axis: Axis,
+    state: SplitPaneState,
+    min_size: Pixels,
+    max_size: Pixels,
+    on_resize: Option<Rc<dyn Fn(&SplitPaneState, &mut WindowContext)>>,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl SplitPane {
+    pub fn new(id: impl Into<ElementId>, axis: Axis, state: &SplitPaneState) -> Self {
+        Self {
+            id: id.into(),
+            axis,
+            state: state.clone(),
+            min_size: px(80.),
+            max_size: Pixels::MAX,
+            on_resize: None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the minimum size of each pane along the split axis. Defaults to 80px.
+    pub fn min_size(mut self, min_size: Pixels) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the maximum size of each pane along the split axis.
+    pub fn max_size(mut self, max_size: Pixels) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Called after a divider was dragged or reset, e.g. to serialize the new ratios.
+    pub fn on_resize(
+        mut self,
+        handler: impl Fn(&SplitPaneState, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl ParentElement for SplitPane {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl IntoElement for SplitPane {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+pub struct SplitPaneLayout {
+    children: Vec<AnyElement>,
+    dividers: Vec<SplitPaneDivider>,
+    size_readout: Option<AnyElement>,
+}
+
+struct SplitPaneDivider {
+    hitbox: Hitbox,
+    bounds: Bounds<Pixels>,
+    focus_handle: FocusHandle,
+    element: AnyElement,
+}
+
+impl SplitPane {
+    fn on_divider_moved(
+        &self,
+        divider_ix: usize,
+        delta: f32,
+        bounds: Bounds<Pixels>,
+    ) -> impl Fn(&mut WindowContext) {
+        let state = self.state.clone();
+        let on_resize = self.on_resize.clone();
+        let axis = self.axis;
+        let min_size = self.min_size;
+        let max_size = self.max_size;
+        move |cx| {
+            let changed = move_split_divider(
+                &mut state.0.borrow_mut().ratios,
+                divider_ix,
+                delta,
+                bounds.size.along(axis).0,
+                min_size.0,
+                max_size.0,
+            );
+            if changed {
+                if let Some(on_resize) = on_resize.as_ref() {
+                    on_resize(&state, cx);
+                }
+                cx.refresh();
+            }
+        }
+    }
+
+    /// Renders the focusable element of the divider after the pane at `divider_ix`,
+    /// handling the keyboard actions that move it.
+    fn render_divider(
+        &self,
+        divider_ix: usize,
+        divider_bounds: Bounds<Pixels>,
+        bounds: Bounds<Pixels>,
+        focus_handle: &FocusHandle,
+    ) -> AnyElement {
+        let mut key_context = KeyContext::new_with_defaults();
+        key_context.add("SplitPaneDivider");
+        key_context.set(
+            "axis",
+            match self.axis {
+                Axis::Horizontal => "horizontal",
+                Axis::Vertical => "vertical",
+            },
+        );
+
+        let move_backward = self.on_divider_moved(divider_ix, -KEYBOARD_STEP, bounds);
+        let move_forward = self.on_divider_moved(divider_ix, KEYBOARD_STEP, bounds);
+        let state = self.state.clone();
+        let on_resize = self.on_resize.clone();
+
+        div()
+            .key_context(key_context)
+            .track_focus(focus_handle)
+            .w(divider_bounds.size.width)
+            .h(divider_bounds.size.height)
+            .on_action(move |_: &MoveDividerBackward, cx| move_backward(cx))
+            .on_action(move |_: &MoveDividerForward, cx| move_forward(cx))
+            .on_action(move |_: &ResetSplitRatio, cx| {
+                state.reset();
+                if let Some(on_resize) = on_resize.as_ref() {
+                    on_resize(&state, cx);
+                }
+                cx.refresh();
+            })
+            .into_any_element()
+    }
+
+    /// Renders the sizes of the panes on either side of a focused divider.
+    fn render_size_readout(before: Pixels, after: Pixels, cx: &WindowContext) -> AnyElement {
+        div()
+            .px_1()
+            .rounded_sm()
+            .border_1()
+            .border_color(cx.theme().colors().border_focused)
+            .bg(cx.theme().colors().elevated_surface_background)
+            .child(
+                Label::new(format!("{} | {}", before.0.round(), after.0.round()))
+                    .size(LabelSize::XSmall)
+                    .color(Color::Muted),
+            )
+            .into_any_element()
+    }
+}
+
+impl Element for SplitPane {
+    type RequestLayoutState = ();
+    type PrepaintState = SplitPaneLayout;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            flex_grow: 1.,
+            flex_shrink: 1.,
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Style::default()
+        };
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let children = mem::take(&mut self.children);
+        let ratios = self.state.ratios_for(children.len());
+        let container_size = bounds.size.along(self.axis);
+
+        let pane_count = children.len();
+        let focus_handles = self
+            .state
+            .divider_focus_handles(pane_count.saturating_sub(1), cx);
+
+        let mut layout = SplitPaneLayout {
+            children: Vec::with_capacity(children.len()),
+            dividers: Vec::new(),
+            size_readout: None,
+        };
+        let mut origin = bounds.origin;
+        for (ix, mut child) in children.into_iter().enumerate() {
+            let child_size = bounds
+                .size
+                .apply_along(self.axis, |_| (container_size * ratios[ix]).round());
+            child.layout_as_root(child_size.into(), cx);
+            child.prepaint_at(origin, cx);
+            layout.children.push(child);
+
+            origin = origin.apply_along(self.axis, |val| val + child_size.along(self.axis));
+            if ix + 1 < pane_count {
+                let hitbox_bounds = Bounds {
+                    origin: origin.apply_along(self.axis, |val| val - px(HANDLE_HITBOX_SIZE / 2.)),
+                    size: bounds
+                        .size
+                        .apply_along(self.axis, |_| px(HANDLE_HITBOX_SIZE)),
+                };
+                let divider_bounds = Bounds {
+                    origin,
+                    size: bounds.size.apply_along(self.axis, |_| px(DIVIDER_SIZE)),
+                };
+                let focus_handle = focus_handles[ix].clone();
+                let mut element = self.render_divider(ix, divider_bounds, bounds, &focus_handle);
+                element.layout_as_root(divider_bounds.size.into(), cx);
+                element.prepaint_at(divider_bounds.origin, cx);
+
+                if focus_handle.is_focused(cx) {
+                    let mut readout = Self::render_size_readout(
+                        (container_size * ratios[ix]).round(),
+                        (container_size * ratios[ix + 1]).round(),
+                        cx,
+                    );
+                    let readout_size = readout.layout_as_root(AvailableSpace::min_size(), cx);
+                    let readout_origin = match self.axis {
+                        Axis::Horizontal => point(
+                            origin.x - readout_size.width / 2.,
+                            bounds.center().y - readout_size.height / 2.,
+                        ),
+                        Axis::Vertical => point(
+                            bounds.center().x - readout_size.width / 2.,
+                            origin.y - readout_size.height / 2.,
+                        ),
+                    };
+                    readout.prepaint_at(readout_origin, cx);
+                    layout.size_readout = Some(readout);
+                }
+
+                layout.dividers.push(SplitPaneDivider {
+                    hitbox: cx.insert_hitbox(hitbox_bounds, true),
+                    bounds: divider_bounds,
+                    focus_handle,
+                    element,
+                });
+            }
+        }
+
+        layout
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        layout: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        for child in &mut layout.children {
+            child.paint(cx);
+        }
+
+        let cursor_style = match self.axis {
+            Axis::Horizontal => CursorStyle::ResizeColumn,
+            Axis::Vertical => CursorStyle::ResizeRow,
+        };
+        for (ix, divider) in layout.dividers.iter_mut().enumerate() {
+            cx.set_cursor_style(cursor_style, &divider.hitbox);
+            let divider_color = if divider.focus_handle.is_focused(cx) {
+                cx.theme().colors().border_focused
+            } else {
+                cx.theme().colors().border_variant
+            };
+            cx.paint_quad(fill(divider.bounds, divider_color));
+            divider.element.paint(cx);
+
+            cx.on_mouse_event({
+                let state = self.state.clone();
+                let on_resize = self.on_resize.clone();
+                let hitbox = divider.hitbox.clone();
+                let focus_handle = divider.focus_handle.clone();
+                move |event: &MouseDownEvent, phase, cx| {
+                    if phase.bubble() && hitbox.is_hovered(cx) {
+                        state.0.borrow_mut().dragged_divider = Some(ix);
+                        cx.focus(&focus_handle);
+                        if event.click_count >= 2 {
+                            state.reset();
+                            if let Some(on_resize) = on_resize.as_ref() {
+                                on_resize(&state, cx);
+                            }
+                            cx.refresh();
+                        }
+                        cx.stop_propagation();
+                    }
+                }
+            });
+        }
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            let on_resize = self.on_resize.clone();
+            let axis = self.axis;
+            let min_size = self.min_size;
+            let max_size = self.max_size;
+            move |event: &MouseMoveEvent, phase, cx| {
+                if !phase.bubble() {
+                    return;
+                }
+                let changed = {
+                    let mut state = state.0.borrow_mut();
+                    let Some(divider_ix) = state.dragged_divider else {
+                        return;
+                    };
+                    drag_split_divider(
+                        &mut state.ratios,
+                        divider_ix,
+                        (event.position - bounds.origin).along(axis).0,
+                        bounds.size.along(axis).0,
+                        min_size.0,
+                        max_size.0,
+                    )
+                };
+                if changed {
+                    if let Some(on_resize) = on_resize.as_ref() {
+                        on_resize(&state, cx);
+                    }
+                    cx.refresh();
+                }
+                cx.stop_propagation();
+            }
+        });
+
+        if let Some(readout) = layout.size_readout.as_mut() {
+            readout.paint(cx);
+        }
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            move |_: &MouseUpEvent, phase, _cx| {
+                if phase.bubble() {
+                    state.0.borrow_mut().dragged_divider = None;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ratios(ratios: &[f32], expected: &[f32]) {
+        assert_eq!(ratios.len(), expected.len());
+        for (ratio, expected) in ratios.iter().zip(expected) {
+            assert!(
+                (ratio - expected).abs() < 0.001,
+                "{ratios:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_drag_split_divider() {
+        let mut ratios = vec![0.5, 0.5];
+        assert!(drag_split_divider(&mut ratios, 0, 300., 1000., 100., 1000.));
+        assert_ratios(&ratios, &[0.3, 0.7]);
+
+        // The panes are kept above their minimum size.
+        assert!(drag_split_divider(&mut ratios, 0, 10., 1000., 100., 1000.));
+        assert_ratios(&ratios, &[0.1, 0.9]);
+
+        // The panes are kept below their maximum size.
+        let mut ratios = vec![0.5, 0.5];
+        assert!(drag_split_divider(&mut ratios, 0, 900., 1000., 100., 600.));
+        assert_ratios(&ratios, &[0.6, 0.4]);
+
+        // Only the panes next to the divider are resized.
+        let mut ratios = vec![0.25, 0.25, 0.5];
+        assert!(drag_split_divider(&mut ratios, 1, 400., 1000., 50., 1000.));
+        assert_ratios(&ratios, &[0.25, 0.15, 0.6]);
+
+        // The last pane has no divider after it.
+        assert!(!drag_split_divider(&mut ratios, 2, 400., 1000., 50., 1000.));
+    }
+
+    #[test]
+    fn test_move_split_divider() {
+        let mut ratios = vec![0.5, 0.5];
+        assert!(move_split_divider(
+            &mut ratios,
+            0,
+            -100.,
+            1000.,
+            100.,
+            1000.
+        ));
+        assert_ratios(&ratios, &[0.4, 0.6]);
+        assert!(move_split_divider(&mut ratios, 0, 250., 1000., 100., 1000.));
+        assert_ratios(&ratios, &[0.65, 0.35]);
+
+        // The divider stops at the minimum size of the panes.
+        assert!(move_split_divider(&mut ratios, 0, 500., 1000., 100., 1000.));
+        assert_ratios(&ratios, &[0.9, 0.1]);
+        assert!(!move_split_divider(
+            &mut ratios,
+            0,
+            100.,
+            1000.,
+            100.,
+            1000.
+        ));
+    }
+
+    #[test]
+    fn test_reset_to_default_ratios() {
+        let state = SplitPaneState::with_default_ratios(vec![0.7, 0.3]);
+        assert_eq!(state.ratios_for(2), vec![0.7, 0.3]);
+        state.0.borrow_mut().ratios = vec![0.5, 0.5];
+        state.reset();
+        assert_eq!(state.ratios_for(2), vec![0.7, 0.3]);
+        assert_eq!(state.ratios_for(3).len(), 3);
+    }
+
+    #[test]
+    fn test_deserialize_split_pane() {
+        let state = SplitPaneState::deserialize(&SerializedSplitPane {
+            ratios: vec![0.25, 0.75],
+        });
+        assert_eq!(state.ratios(), vec![0.25, 0.75]);
+
+        let state = SplitPaneState::deserialize(&SerializedSplitPane {
+            ratios: vec![0.25, 0.25],
+        });
+        assert_eq!(state.ratios_for(2), vec![0.5, 0.5]);
+    }
+}
@@ -1,8 +1,9 @@
 #![allow(missing_docs)]
 
-use gpui::{div, Div};
+use gpui::{div, AnyElement, Div, StyleRefinement};
+use smallvec::SmallVec;
 
-use crate::StyledExt;
+use crate::prelude::*;
 
 /// Horizontally stacks elements. Sets `flex()`, `flex_row()`, `items_center()`
 #[track_caller]
@@ -15,3 +16,146 @@ pub fn h_flex() -> Div {
 pub fn v_flex() -> Div {
     div().v_flex()
 }
+
+/// Horizontally stacks elements, centered vertically, with a gap from the
+/// [`Spacing`] scale between them, so that it follows the user's UI density.
+///
+/// Unlike [`h_flex`], the gap is resolved when the stack renders. The alignment
+/// can be changed with the usual [`Styled`] methods, like `items_start()` or
+/// `justify_between()`.
+#[derive(IntoElement)]
+pub struct HStack {
+    base: Div,
+    gap: Spacing,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl HStack {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            base: h_flex(),
+            gap: Spacing::None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the gap between the elements. Defaults to [`Spacing::None`].
+    pub fn gap(mut self, gap: Spacing) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl Default for HStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Styled for HStack {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for HStack {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for HStack {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.base.gap(self.gap.rems(cx)).children(self.children)
+    }
+}
+
+/// Vertically stacks elements, stretched horizontally, with a gap from the
+/// [`Spacing`] scale between them, so that it follows the user's UI density.
+///
+/// Unlike [`v_flex`], the gap is resolved when the stack renders. The alignment
+/// can be changed with the usual [`Styled`] methods, like `items_center()` or
+/// `justify_end()`.
+#[derive(IntoElement)]
+pub struct VStack {
+    base: Div,
+    gap: Spacing,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl VStack {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            base: v_flex(),
+            gap: Spacing::None,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the gap between the elements. Defaults to [`Spacing::None`].
+    pub fn gap(mut self, gap: Spacing) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl Default for VStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Styled for VStack {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for VStack {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for VStack {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.base.gap(self.gap.rems(cx)).children(self.children)
+    }
+}
+
+/// Empty space in a stack: either taking up the space left by the other
+/// elements, e.g. to push the elements after it to the end of an [`HStack`],
+/// or a fixed amount of space from the [`Spacing`] scale.
+#[derive(IntoElement)]
+pub struct Spacer {
+    size: Option<Spacing>,
+}
+
+impl Spacer {
+    /// Creates a spacer taking up the space left by the other elements.
+    pub fn new() -> Self {
+        Self { size: None }
+    }
+
+    /// Creates a spacer of the given size along both axes.
+    pub fn fixed(size: Spacing) -> Self {
+        Self { size: Some(size) }
+    }
+}
+
+impl Default for Spacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for Spacer {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        match self.size {
+            Some(size) => div().flex_none().size(size.rems(cx)),
+            None => div().flex_1(),
+        }
+    }
+}
@@ -0,0 +1,311 @@
+#![allow(missing_docs)]
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gpui::{AnyElement, ClickEvent};
+
+use crate::{prelude::*, Divider};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StepState {
+    Completed,
+    Current,
+    Upcoming,
+}
+
+impl StepState {
+    fn for_step(ix: usize, current_step: usize) -> Self {
+        if ix < current_step {
+            StepState::Completed
+        } else if ix == current_step {
+            StepState::Current
+        } else {
+            StepState::Upcoming
+        }
+    }
+}
+
+/// A row of numbered steps, showing which ones are completed, current and upcoming.
+#[derive(IntoElement)]
+pub struct Stepper {
+    steps: Vec<SharedString>,
+    current_step: usize,
+}
+
+impl Stepper {
+    pub fn new(
+        steps: impl IntoIterator<Item = impl Into<SharedString>>,
+        current_step: usize,
+    ) -> Self {
+        Self {
+            steps: steps.into_iter().map(Into::into).collect(),
+            current_step,
+        }
+    }
+}
+
+impl RenderOnce for Stepper {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let step_count = self.steps.len();
+        let colors = cx.theme().colors();
+
+        h_flex()
+            .w_full()
+            .gap_2()
+            .children(self.steps.into_iter().enumerate().map(|(ix, label)| {
+                let state = StepState::for_step(ix, self.current_step);
+                let (badge_background, badge_color, label_color) = match state {
+                    StepState::Completed => (colors.element_selected, Color::Default, Color::Muted),
+                    StepState::Current => (colors.text_accent, Color::Default, Color::Default),
+                    StepState::Upcoming => (colors.element_background, Color::Muted, Color::Muted),
+                };
+                let badge = match state {
+                    StepState::Completed => Icon::new(IconName::Check)
+                        .size(IconSize::XSmall)
+                        .into_any_element(),
+                    _ => Label::new((ix + 1).to_string())
+                        .size(LabelSize::XSmall)
+                        .color(badge_color)
+                        .into_any_element(),
+                };
+
+                h_flex()
+                    .gap_2()
+                    .when(ix + 1 < step_count, |this| this.flex_1())
+                    .child(
+                        h_flex()
+                            .flex_none()
+                            .size_5()
+                            .justify_center()
+                            .rounded_full()
+                            .bg(badge_background)
+                            .child(badge),
+                    )
+                    .child(
+                        Label::new(label)
+                            .size(LabelSize::Small)
+                            .color(label_color)
+                            .single_line(),
+                    )
+                    .when(ix + 1 < step_count, |this| {
+                        this.child(div().flex_1().child(Divider::horizontal()))
+                    })
+            }))
+    }
+}
+
+/// The current step of a [`Wizard`].
+///
+/// Keep this on the view that renders the wizard, so that the step is remembered
+/// across renders.
+#[derive(Debug, Clone, Default)]
+pub struct WizardState(Rc<Cell<usize>>);
+
+impl WizardState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn current_step(&self) -> usize {
+        self.0.get()
+    }
+
+    pub fn set_current_step(&self, step: usize) {
+        self.0.set(step);
+    }
+
+    /// Moves to the next step, returning whether there was one.
+    pub fn next(&self, step_count: usize) -> bool {
+        let step = self.0.get();
+        if step + 1 < step_count {
+            self.0.set(step + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves to the previous step, returning whether there was one.
+    pub fn back(&self) -> bool {
+        let step = self.0.get();
+        if step > 0 {
+            self.0.set(step - 1);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A step of a [`Wizard`]: a label shown in the [`Stepper`] and the content shown
+/// while the step is current.
+pub struct WizardStep {
+    label: SharedString,
+    content: AnyElement,
+    can_advance: bool,
+}
+
+impl WizardStep {
+    pub fn new(label: impl Into<SharedString>, content: impl IntoElement) -> Self {
+        Self {
+            label: label.into(),
+            content: content.into_any_element(),
+            can_advance: true,
+        }
+    }
+
+    /// Whether the step is complete enough to move on, e.g. whether its fields
+    /// are valid. The next and finish buttons are disabled otherwise.
+    pub fn can_advance(mut self, can_advance: bool) -> Self {
+        self.can_advance = can_advance;
+        self
+    }
+}
+
+/// A multi-step flow, like onboarding or setting up a remote project, with a
+/// [`Stepper`] above the current step and back, next and finish buttons below it.
+///
+/// Only the content of the current step is rendered.
+#[derive(IntoElement)]
+pub struct Wizard {
+    id: ElementId,
+    state: WizardState,
+    steps: Vec<WizardStep>,
+    on_step_change: Option<Rc<dyn Fn(usize, &mut WindowContext) + 'static>>,
+    on_finish: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    finish_label: SharedString,
+}
+
+impl Wizard {
+    pub fn new(id: impl Into<ElementId>, state: &WizardState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            steps: Vec::new(),
+            on_step_change: None,
+            on_finish: None,
+            finish_label: "Finish".into(),
+        }
+    }
+
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(mut self, steps: impl IntoIterator<Item = WizardStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// Called with the new step after moving back or forward.
+    pub fn on_step_change(mut self, handler: impl Fn(usize, &mut WindowContext) + 'static) -> Self {
+        self.on_step_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when the finish button on the last step is clicked.
+    pub fn on_finish(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_finish = Some(Box::new(handler));
+        self
+    }
+
+    pub fn finish_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.finish_label = label.into();
+        self
+    }
+}
+
+impl RenderOnce for Wizard {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let step_count = self.steps.len();
+        let current_step = self.state.current_step().min(step_count.saturating_sub(1));
+        let is_last_step = current_step + 1 >= step_count;
+        let labels = self
+            .steps
+            .iter()
+            .map(|step| step.label.clone())
+            .collect::<Vec<_>>();
+        let current = self.steps.into_iter().nth(current_step);
+        let can_advance = current.as_ref().map_or(false, |step| step.can_advance);
+
+        let back_button = {
+            let state = self.state.clone();
+            let on_step_change = self.on_step_change.clone();
+            Button::new("wizard-back", crate::t!(cx, "common.back"))
+                .disabled(current_step == 0)
+                .on_click(move |_, cx| {
+                    if state.back() {
+                        if let Some(on_step_change) = on_step_change.as_ref() {
+                            on_step_change(state.current_step(), cx);
+                        }
+                        cx.refresh();
+                    }
+                })
+        };
+
+        let forward_button = if is_last_step {
+            let on_finish = self.on_finish;
+            Button::new("wizard-finish", self.finish_label)
+                .style(ButtonStyle::Filled)
+                .disabled(!can_advance)
+                .on_click(move |event, cx| {
+                    if let Some(on_finish) = on_finish.as_ref() {
+                        on_finish(event, cx);
+                    }
+                })
+        } else {
+            let state = self.state;
+            let on_step_change = self.on_step_change;
+            Button::new("wizard-next", crate::t!(cx, "common.next"))
+                .style(ButtonStyle::Filled)
+                .disabled(!can_advance)
+                .on_click(move |_, cx| {
+                    if state.next(step_count) {
+                        if let Some(on_step_change) = on_step_change.as_ref() {
+                            on_step_change(state.current_step(), cx);
+                        }
+                        cx.refresh();
+                    }
+                })
+        };
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .gap_4()
+            .child(Stepper::new(labels, current_step))
+            .children(current.map(|step| div().w_full().child(step.content)))
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .child(back_button)
+                    .child(forward_button),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wizard_state_navigation() {
+        let state = WizardState::new();
+        assert!(!state.back());
+        assert!(state.next(3));
+        assert!(state.next(3));
+        assert!(!state.next(3));
+        assert_eq!(state.current_step(), 2);
+        assert!(state.back());
+        assert_eq!(state.current_step(), 1);
+
+        assert_eq!(StepState::for_step(0, 1), StepState::Completed);
+        assert_eq!(StepState::for_step(1, 1), StepState::Current);
+        assert_eq!(StepState::for_step(2, 1), StepState::Upcoming);
+    }
+}
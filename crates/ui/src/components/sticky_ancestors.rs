@@ -0,0 +1,256 @@
+#![allow(missing_docs)]
+use gpui::{AnyElement, Bounds, Hsla, UniformListDecoration, UniformListScrollHandle, View};
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+/// Pins the ancestor rows of the topmost visible row of a tree-like
+/// [`gpui::UniformList`] to the top of its viewport, similar to the
+/// editor's sticky scroll.
+///
+/// The list keeps ownership of its hierarchy: it reports the ancestors of a
+/// given row, and renders those rows again on request.
+pub struct StickyAncestors {
+    scroll_handle: UniformListScrollHandle,
+    max_rows: usize,
+    background: Option<Hsla>,
+    compute_ancestors_fn: Box<dyn Fn(usize, &mut WindowContext) -> SmallVec<[usize; 8]>>,
+    render_fn: Box<dyn Fn(SmallVec<[usize; 8]>, &mut WindowContext) -> SmallVec<[AnyElement; 8]>>,
+}
+
+/// Creates a [`StickyAncestors`] decoration for a uniform list.
+///
+/// `compute_ancestors_fn` returns the indices of the ancestors of the given row,
+/// ordered from the outermost ancestor to the direct parent.
+/// `render_fn` renders the given rows, in order, the same way the list would.
+pub fn sticky_ancestors<V: Render>(
+    view: View<V>,
+    scroll_handle: UniformListScrollHandle,
+    compute_ancestors_fn: impl Fn(&mut V, usize, &mut ViewContext<V>) -> SmallVec<[usize; 8]> + 'static,
+    render_fn: impl Fn(&mut V, SmallVec<[usize; 8]>, &mut ViewContext<V>) -> SmallVec<[AnyElement; 8]>
+        + 'static,
+) -> StickyAncestors {
+    let compute_ancestors_fn = Box::new({
+        let view = view.clone();
+        move |ix, cx: &mut WindowContext| {
+            view.update(cx, |this, cx| compute_ancestors_fn(this, ix, cx))
+        }
+    });
+    let render_fn = Box::new(move |rows, cx: &mut WindowContext| {
+        view.update(cx, |this, cx| render_fn(this, rows, cx))
+    });
+    StickyAncestors {
+        scroll_handle,
+        max_rows: 5,
+        background: None,
+        compute_ancestors_fn,
+        render_fn,
+    }
+}
+
+impl StickyAncestors {
+    /// Sets the maximum amount of ancestor rows that are pinned at once.
+    ///
+    /// The outermost ancestors are kept when a branch is deeper than this.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Sets the background of the pinned rows.
+    ///
+    /// Defaults to the panel background.
+    pub fn background(mut self, background: Hsla) -> Self {
+        self.background = Some(background);
+        self
+    }
+}
+
+/// The rows that are pinned to the top of the viewport for a given scroll position.
+#[derive(Debug, PartialEq)]
+pub struct StickyAncestorsLayout {
+    /// The pinned rows, ordered from the outermost ancestor to the innermost one.
+    pub rows: SmallVec<[usize; 8]>,
+    /// How far the innermost pinned row is pushed up, as a fraction of a row.
+    ///
+    /// This is non-zero while the branch of the innermost row is scrolling out of view.
+    pub push_offset: f32,
+}
+
+/// Computes which rows should be pinned when `top_ix` is the topmost visible row,
+/// and `top_row_offset` is the fraction (`0.0..1.0`) of that row that is scrolled out of view.
+pub fn layout_sticky_ancestors(
+    top_ix: usize,
+    top_row_offset: f32,
+    max_rows: usize,
+    mut ancestors_for_row: impl FnMut(usize) -> SmallVec<[usize; 8]>,
+) -> StickyAncestorsLayout {
+    let mut rows = ancestors_for_row(top_ix);
+    rows.truncate(max_rows);
+
+    // Once the row right below the pinned rows no longer belongs to the innermost
+    // pinned row, that row has to make room for the rows that follow it.
+    let push_offset = match rows.last() {
+        Some(innermost) if !ancestors_for_row(top_ix + rows.len()).contains(innermost) => {
+            top_row_offset.clamp(0., 1.)
+        }
+        _ => 0.,
+    };
+
+    StickyAncestorsLayout { rows, push_offset }
+}
+
+/// Returns the ancestors of the row at `ix` within a flattened tree, where
+/// `depths` holds the depth of every row.
+///
+/// The ancestors are ordered from the outermost ancestor to the direct parent.
+pub fn ancestors_from_depths(depths: &[usize], ix: usize) -> SmallVec<[usize; 8]> {
+    let mut ancestors = SmallVec::new();
+    let Some(mut depth) = depths.get(ix).copied() else {
+        return ancestors;
+    };
+
+    for (row, &row_depth) in depths[..ix].iter().enumerate().rev() {
+        if depth == 0 {
+            break;
+        }
+        if row_depth < depth {
+            ancestors.push(row);
+            depth = row_depth;
+        }
+    }
+
+    ancestors.reverse();
+    ancestors
+}
+
+impl UniformListDecoration for StickyAncestors {
+    fn compute(
+        &self,
+        visible_range: std::ops::Range<usize>,
+        bounds: Bounds<Pixels>,
+        item_height: Pixels,
+        cx: &mut WindowContext,
+    ) -> AnyElement {
+        let scroll_top = -self.scroll_handle.0.borrow().base_handle.offset().y;
+        let top_ix = visible_range.start;
+        let top_row_offset = (scroll_top - item_height * top_ix as f32) / item_height;
+
+        let layout = layout_sticky_ancestors(top_ix, top_row_offset, self.max_rows, |ix| {
+            (self.compute_ancestors_fn)(ix, cx)
+        });
+        if layout.rows.is_empty() {
+            return div().into_any_element();
+        }
+
+        let row_count = layout.rows.len();
+        let push_offset = item_height * layout.push_offset;
+        let background = self
+            .background
+            .unwrap_or_else(|| cx.theme().colors().panel_background);
+        let border_color = cx.theme().colors().border_variant;
+        let rows = (self.render_fn)(layout.rows, cx);
+
+        div()
+            .w(bounds.size.width)
+            .h(bounds.size.height)
+            // Paint the innermost row first, so that it slides underneath
+            // its ancestors when it is pushed up.
+            .children(rows.into_iter().enumerate().rev().map(|(ix, row)| {
+                let is_innermost = ix + 1 == row_count;
+                div()
+                    .absolute()
+                    .left_0()
+                    .w_full()
+                    .h(item_height)
+                    .top(
+                        scroll_top + item_height * ix as f32
+                            - if is_innermost { push_offset } else { px(0.) },
+                    )
+                    .bg(background)
+                    .occlude()
+                    .when(is_innermost, |this| {
+                        this.border_b_1().border_color(border_color)
+                    })
+                    .child(row)
+            }))
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec::smallvec;
+
+    use super::*;
+
+    #[test]
+    fn test_ancestors_from_depths() {
+        let depths = [0, 1, 2, 2, 1, 2, 3, 0];
+
+        assert_eq!(
+            ancestors_from_depths(&depths, 0),
+            SmallVec::<[usize; 8]>::new()
+        );
+        assert_eq!(
+            ancestors_from_depths(&depths, 3),
+            smallvec![0, 1] as SmallVec<[usize; 8]>
+        );
+        assert_eq!(
+            ancestors_from_depths(&depths, 4),
+            smallvec![0] as SmallVec<[usize; 8]>
+        );
+        assert_eq!(
+            ancestors_from_depths(&depths, 6),
+            smallvec![0, 4, 5] as SmallVec<[usize; 8]>
+        );
+        assert_eq!(
+            ancestors_from_depths(&depths, 7),
+            SmallVec::<[usize; 8]>::new()
+        );
+        assert_eq!(
+            ancestors_from_depths(&depths, 8),
+            SmallVec::<[usize; 8]>::new()
+        );
+    }
+
+    #[test]
+    fn test_layout_sticky_ancestors() {
+        let depths = [0, 1, 2, 2, 1, 2, 3, 0];
+        let ancestors = |ix| ancestors_from_depths(&depths, ix);
+
+        assert_eq!(
+            layout_sticky_ancestors(0, 0.5, 5, ancestors),
+            StickyAncestorsLayout {
+                rows: smallvec![],
+                push_offset: 0.,
+            }
+        );
+
+        // Row 3 is below both of its pinned ancestors and still belongs to row 1.
+        assert_eq!(
+            layout_sticky_ancestors(2, 0.25, 5, ancestors),
+            StickyAncestorsLayout {
+                rows: smallvec![0, 1],
+                push_offset: 0.,
+            }
+        );
+
+        // Row 4 is a sibling of row 1, so row 1 is pushed out of the way.
+        assert_eq!(
+            layout_sticky_ancestors(3, 0.25, 5, ancestors),
+            StickyAncestorsLayout {
+                rows: smallvec![0, 1],
+                push_offset: 0.25,
+            }
+        );
+
+        assert_eq!(
+            layout_sticky_ancestors(6, 0., 2, ancestors),
+            StickyAncestorsLayout {
+                rows: smallvec![0, 4],
+                push_offset: 0.,
+            }
+        );
+    }
+}
@@ -6,6 +6,8 @@ use crate::{Avatar, AvatarAudioStatusIndicator};
 
 pub struct AvatarStory;
 
+story::register_story!("avatar", AvatarStory);
+
 impl Render for AvatarStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
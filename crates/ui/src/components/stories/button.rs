@@ -2,10 +2,12 @@ use gpui::Render;
 use story::Story;
 
 use crate::{prelude::*, IconName};
-use crate::{Button, ButtonStyle};
+use crate::{Button, ButtonStyle, TintColor};
 
 pub struct ButtonStory;
 
+story::register_story!("button", ButtonStory);
+
 impl Render for ButtonStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -34,5 +36,39 @@ impl Render for ButtonStory {
             .child(Button::new("default_subtle", "Click me").style(ButtonStyle::Subtle))
             .child(Story::label("Default (Transparent)"))
             .child(Button::new("default_transparent", "Click me").style(ButtonStyle::Transparent))
+            .child(Story::label("Default (Outline)"))
+            .child(Button::new("default_outline", "Click me").style(ButtonStyle::Outline))
+            .child(Story::label("Danger"))
+            .child(
+                Button::new("danger", "Delete")
+                    .style(ButtonStyle::Tinted(TintColor::Negative))
+                    .icon(IconName::Trash)
+                    .icon_position(IconPosition::Start),
+            )
+            .child(Story::label("With `icon` and `end_icon`"))
+            .child(
+                Button::new("with_end_icon", "Branch")
+                    .icon(IconName::FileGit)
+                    .icon_position(IconPosition::Start)
+                    .end_icon(IconName::ChevronDown),
+            )
+            .child(Story::label("Loading"))
+            .child(
+                Button::new("loading", "Save")
+                    .style(ButtonStyle::Filled)
+                    .loading(true),
+            )
+            .child(Story::label("Full width"))
+            .child(
+                Button::new("full_width", "Continue")
+                    .style(ButtonStyle::Filled)
+                    .full_width(),
+            )
+            .child(Story::label("With style overrides"))
+            .child(
+                Button::new("with_style_overrides", "Click me")
+                    .override_style(|style| style.bg(gpui::rgb(0x2a4d69)).rounded_full())
+                    .override_hover_style(|style| style.bg(gpui::rgb(0x4b86b4))),
+            )
     }
 }
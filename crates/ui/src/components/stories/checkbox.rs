@@ -6,6 +6,8 @@ use crate::{h_flex, Checkbox};
 
 pub struct CheckboxStory;
 
+story::register_story!("checkbox", CheckboxStory);
+
 impl Render for CheckboxStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -7,18 +7,40 @@ use crate::{right_click_menu, ContextMenu, Label};
 actions!(context_menu, [PrintCurrentDate, PrintBestFood]);
 
 fn build_menu(cx: &mut WindowContext, header: impl Into<SharedString>) -> View<ContextMenu> {
-    ContextMenu::build(cx, |menu, _| {
+    ContextMenu::build(cx, |menu, cx| {
+        let executor = cx.background_executor().clone();
+        let branches = cx.background_executor().spawn(async move {
+            executor.timer(std::time::Duration::from_secs(1)).await;
+            anyhow::Ok(vec!["main", "release"])
+        });
         menu.header(header)
             .separator()
             .action("Print current time", Box::new(PrintCurrentDate))
             .entry("Print best food", Some(Box::new(PrintBestFood)), |cx| {
                 cx.dispatch_action(Box::new(PrintBestFood))
             })
+            .separator()
+            .checkable_entry("Show whitespace", true, None, |_| {})
+            .radio_entry("Sort by name", true, None, |_| {})
+            .radio_entry("Sort by date", false, None, |_| {})
+            .separator()
+            .async_section(
+                "Loading branches…",
+                branches,
+                |menu, branches, _| {
+                    branches
+                        .into_iter()
+                        .fold(menu, |menu, branch| menu.entry(branch, None, |_| {}))
+                },
+                cx,
+            )
     })
 }
 
 pub struct ContextMenuStory;
 
+story::register_story!("context_menu", ContextMenuStory);
+
 impl Render for ContextMenuStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -6,6 +6,8 @@ use crate::Disclosure;
 
 pub struct DisclosureStory;
 
+story::register_story!("disclosure", DisclosureStory);
+
 impl Render for DisclosureStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
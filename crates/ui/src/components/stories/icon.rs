@@ -7,6 +7,8 @@ use crate::{Icon, IconName};
 
 pub struct IconStory;
 
+story::register_story!("icon", IconStory);
+
 impl Render for IconStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         let icons = IconName::iter();
@@ -6,6 +6,8 @@ use crate::{IconButton, IconName};
 
 pub struct IconButtonStory;
 
+story::register_story!("icon_button", IconButtonStory);
+
 impl Render for IconButtonStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         let default_button = StoryItem::new(
@@ -7,6 +7,8 @@ use crate::{prelude::*, KeyBinding};
 
 pub struct KeybindingStory;
 
+story::register_story!("keybinding", KeybindingStory);
+
 pub fn binding(key: &str) -> gpui::KeyBinding {
     gpui::KeyBinding::new(key, NoAction {}, None)
 }
@@ -2,16 +2,30 @@ use std::time::Duration;
 
 use crate::{prelude::*, HighlightedLabel, Label};
 use gpui::{pulsating_between, Animation, AnimationExt, Render};
-use story::Story;
+use story::{Story, StoryControls};
 
 pub struct LabelStory;
 
+story::register_story!("label", LabelStory);
+
 impl Render for LabelStory {
-    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let color = [Color::Default, Color::Muted, Color::Accent, Color::Error]
+            [StoryControls::choice("Color", &["Default", "Muted", "Accent", "Error"], 0, cx)];
+        let strikethrough = StoryControls::toggle("Strikethrough", false, cx);
+        let italic = StoryControls::toggle("Italic", false, cx);
+
         Story::container()
             .child(Story::title_for::<Label>())
             .child(Story::label("Default"))
             .child(Label::new("Hello, world!"))
+            .child(Story::label("With controls"))
+            .child(
+                Label::new("Change my props in the controls panel")
+                    .color(color)
+                    .strikethrough(strikethrough)
+                    .italic(italic),
+            )
             .child(Story::label("Highlighted"))
             .child(HighlightedLabel::new(
                 "Hello, world!",
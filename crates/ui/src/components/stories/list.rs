@@ -6,6 +6,8 @@ use crate::{List, ListItem};
 
 pub struct ListStory;
 
+story::register_story!("list", ListStory);
+
 impl Render for ListStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
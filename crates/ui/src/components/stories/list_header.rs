@@ -6,6 +6,8 @@ use crate::{IconName, ListHeader};
 
 pub struct ListHeaderStory;
 
+story::register_story!("list_header", ListHeaderStory);
+
 impl Render for ListHeaderStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -8,6 +8,8 @@ const OVERFLOWING_TEXT: &str = "Lorem ipsum dolor sit amet, consectetur adipisci
 
 pub struct ListItemStory;
 
+story::register_story!("list_item", ListItemStory);
+
 impl Render for ListItemStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -8,6 +8,8 @@ use crate::{Indicator, Tab};
 
 pub struct TabStory;
 
+story::register_story!("tab", TabStory);
+
 impl Render for TabStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -5,6 +5,8 @@ use crate::{prelude::*, Tab, TabBar, TabPosition};
 
 pub struct TabBarStory;
 
+story::register_story!("tab_bar", TabBarStory);
+
 impl Render for TabBarStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         let tab_count = 20;
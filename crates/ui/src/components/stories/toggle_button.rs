@@ -5,6 +5,8 @@ use crate::{prelude::*, ToggleButton};
 
 pub struct ToggleButtonStory;
 
+story::register_story!("toggle_button", ToggleButtonStory);
+
 impl Render for ToggleButtonStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -1,10 +1,12 @@
 use gpui::Render;
 use story::{Story, StoryItem, StorySection};
 
-use crate::{prelude::*, ToolStrip, Tooltip};
+use crate::{prelude::*, ToolStrip, ToolStripItem, Tooltip};
 
 pub struct ToolStripStory;
 
+story::register_story!("tool_strip", ToolStripStory);
+
 impl Render for ToolStripStory {
     fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
         Story::container()
@@ -29,5 +31,32 @@ impl Render for ToolStripStory {
                     ),
                 )),
             )
+            .child(
+                StorySection::new().child(StoryItem::new(
+                    "With active items, badges and overflow",
+                    h_flex().child(
+                        ToolStrip::vertical("tool_strip_items_example")
+                            .max_visible(3)
+                            .item(
+                                ToolStripItem::new("project", IconName::FileTree, "Project")
+                                    .active(true),
+                            )
+                            .item(ToolStripItem::new("git", IconName::FileGit, "Git").badge(12))
+                            .item(ToolStripItem::new(
+                                "search",
+                                IconName::MagnifyingGlass,
+                                "Search",
+                            ))
+                            .item(
+                                ToolStripItem::new(
+                                    "notifications",
+                                    IconName::Bell,
+                                    "Notifications",
+                                )
+                                .badge(150),
+                            ),
+                    ),
+                )),
+            )
     }
 }
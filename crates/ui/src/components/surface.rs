@@ -0,0 +1,68 @@
+#![allow(missing_docs)]
+
+use gpui::{AnyElement, Div, Refineable, StyleRefinement};
+use smallvec::SmallVec;
+use theme::Elevation;
+
+use crate::prelude::*;
+
+/// A container drawn at an [`Elevation`], with the background, border, corner
+/// radius and shadow the theme gives that elevation.
+///
+/// Use it for popovers, dialogs and toasts rather than styling them by hand, so
+/// that they look the same in every theme.
+#[derive(IntoElement)]
+pub struct Surface {
+    base: Div,
+    elevation: Elevation,
+    border: bool,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Surface {
+    pub fn new(elevation: Elevation) -> Self {
+        Self {
+            base: div(),
+            elevation,
+            border: true,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Leaves out the border, for surfaces that are outlined by their content.
+    pub fn borderless(mut self) -> Self {
+        self.border = false;
+        self
+    }
+}
+
+impl Styled for Surface {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Surface {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for Surface {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        let style = cx.theme().elevation(self.elevation);
+        let mut surface = div()
+            .bg(style.background)
+            .map(|this| match self.elevation {
+                Elevation::Surface => this.rounded_md(),
+                _ => this.rounded_lg(),
+            })
+            .when(self.border, |this| {
+                this.border_1().border_color(style.border)
+            })
+            .shadow(style.shadow);
+        // The styles set on the surface win over the ones of its elevation.
+        surface.style().refine(self.base.style());
+        surface.children(self.children)
+    }
+}
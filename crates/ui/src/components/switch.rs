@@ -0,0 +1,75 @@
+#![allow(missing_docs)]
+
+use crate::prelude::*;
+
+/// A switch turning an option on or off, which applies right away, like a
+/// setting. Use a [`Checkbox`](crate::Checkbox) for choices confirmed later,
+/// e.g. in a form.
+#[derive(IntoElement)]
+pub struct Switch {
+    id: ElementId,
+    on: bool,
+    disabled: bool,
+    on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+}
+
+impl Switch {
+    pub fn new(id: impl Into<ElementId>, on: bool) -> Self {
+        Self {
+            id: id.into(),
+            on,
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    /// Called with the new state when the switch is clicked.
+    pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for Switch {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl RenderOnce for Switch {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let (track_color, knob_color) = match (self.disabled, self.on) {
+            (true, _) => (colors.element_disabled, colors.icon_disabled),
+            (false, true) => (colors.text_accent, colors.background),
+            (false, false) => (colors.element_background, colors.icon_muted),
+        };
+        let on = self.on;
+
+        h_flex()
+            .id(self.id)
+            .flex_none()
+            .w(rems_from_px(28.))
+            .h(rems_from_px(16.))
+            .p(px(2.))
+            .rounded_full()
+            .border_1()
+            .border_color(if self.on && !self.disabled {
+                track_color
+            } else {
+                colors.border
+            })
+            .bg(track_color)
+            .when(self.on, |this| this.justify_end())
+            .child(div().size(rems_from_px(10.)).rounded_full().bg(knob_color))
+            .when(!self.disabled, |this| {
+                this.cursor_pointer()
+                    .hover(|style| style.border_color(colors.border_focused))
+            })
+            .when_some(
+                self.on_click.filter(|_| !self.disabled),
+                |this, on_click| this.on_click(move |_, cx| on_click(&!on, cx)),
+            )
+    }
+}
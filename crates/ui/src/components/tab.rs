@@ -1,10 +1,12 @@
 #![allow(missing_docs)]
 use std::cmp::Ordering;
 
-use gpui::{AnyElement, IntoElement, Stateful};
+use gpui::{AccessibilityProperties, AccessibilityRole, AnyElement, IntoElement, Stateful};
 use smallvec::SmallVec;
 
-use crate::{prelude::*, BASE_REM_SIZE_IN_PX};
+use crate::{
+    prelude::*, InteractiveColors, InteractiveState, InteractiveStates, BASE_REM_SIZE_IN_PX,
+};
 
 /// The position of a [`Tab`] within a list of tabs.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -103,20 +105,26 @@ impl ParentElement for Tab {
 impl RenderOnce for Tab {
     #[allow(refining_impl_trait)]
     fn render(self, cx: &mut WindowContext) -> Stateful<Div> {
-        let (text_color, tab_bg, _tab_hover_bg, _tab_active_bg) = match self.selected {
+        let (text_color, tab_bg) = match self.selected {
             false => (
                 cx.theme().colors().text_muted,
                 cx.theme().colors().tab_inactive_background,
-                cx.theme().colors().ghost_element_hover,
-                cx.theme().colors().ghost_element_active,
             ),
             true => (
                 cx.theme().colors().text,
                 cx.theme().colors().tab_active_background,
-                cx.theme().colors().element_hover,
-                cx.theme().colors().element_active,
             ),
         };
+        // Tabs only show whether they're selected, with a background of their own
+        // in either case, and don't react to hover or press.
+        let colors = InteractiveColors {
+            selected: tab_bg,
+            ..InteractiveColors::from_background(tab_bg, cx)
+        };
+        let state = InteractiveState {
+            selected: self.selected,
+            ..InteractiveState::default()
+        };
 
         let (start_slot, end_slot) = {
             let start_slot = h_flex().size_3().justify_center().children(self.start_slot);
@@ -129,9 +137,12 @@ impl RenderOnce for Tab {
             }
         };
 
-        self.div
+        state
+            .apply(self.div, &colors, InteractiveStates::SELECTED)
+            .accessibility(
+                AccessibilityProperties::new(AccessibilityRole::Tab).selected(self.selected),
+            )
             .h(rems(Self::CONTAINER_HEIGHT_IN_REMS))
-            .bg(tab_bg)
             .border_color(cx.theme().colors().border)
             .map(|this| match self.position {
                 TabPosition::First => {
@@ -0,0 +1,1160 @@
+#![allow(missing_docs)]
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use collections::{HashMap, HashSet};
+
+use gpui::{
+    point, AnyElement, BoxShadow, ClickEvent, ClipboardItem, FocusHandle, FontFeatures, FontWeight,
+    Hsla, KeyDownEvent, Keystroke, ScrollHandle,
+};
+use smallvec::smallvec;
+
+use crate::{
+    prelude::*, ContextMenu, Disclosure, HighlightedLabel, InteractiveColors, PopoverMenu,
+};
+
+/// How the contents of a [`Table`] column are aligned in their cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// How the cells of a [`Table`] column are formatted.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ColumnFormat {
+    pub alignment: ColumnAlignment,
+    /// Renders digits with equal widths, so numbers in the column line up.
+    pub tabular_digits: bool,
+    /// A suffix appended to numbers, like `" KB"` or `"ms"`.
+    pub unit: Option<SharedString>,
+    /// Groups the digits of numbers by thousands, like `12,345`.
+    pub thousands_separator: bool,
+    /// The number of decimals numbers are shown with. Numbers are shown as short
+    /// as possible when `None`.
+    pub decimals: Option<usize>,
+}
+
+impl ColumnFormat {
+    /// Formats a number for a cell of a column with this format.
+    pub fn format_number(&self, value: f64) -> String {
+        let mut text = match self.decimals {
+            Some(decimals) => format!("{value:.decimals$}"),
+            None => value.to_string(),
+        };
+        if self.thousands_separator {
+            text = group_thousands(&text);
+        }
+        if let Some(unit) = &self.unit {
+            text.push_str(unit);
+        }
+        text
+    }
+}
+
+/// Inserts a comma between each group of three digits of the integer part of a
+/// formatted number.
+fn group_thousands(number: &str) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", number),
+    };
+    let (integer, fraction) = unsigned.split_at(unsigned.find('.').unwrap_or(unsigned.len()));
+    if !integer.bytes().all(|byte| byte.is_ascii_digit()) {
+        return number.to_string();
+    }
+
+    let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+    grouped.push_str(sign);
+    for (ix, digit) in integer.chars().enumerate() {
+        if ix > 0 && (integer.len() - ix) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+/// An aggregate of the values of a column, shown in the header of each group of
+/// rows of a grouped [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The number of rows in the group.
+    Count,
+    Sum,
+    Average,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    /// Aggregates the numbers in the cells of a column of a group of rows. Cells
+    /// that aren't numbers are skipped, but still counted by [`Aggregate::Count`].
+    fn compute(self, row_count: usize, numbers: &[f64]) -> Option<f64> {
+        match self {
+            Aggregate::Count => Some(row_count as f64),
+            Aggregate::Sum => Some(numbers.iter().sum()),
+            Aggregate::Average => {
+                (!numbers.is_empty()).then(|| numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+            Aggregate::Min => numbers.iter().copied().reduce(f64::min),
+            Aggregate::Max => numbers.iter().copied().reduce(f64::max),
+        }
+    }
+}
+
+/// A column of a [`Table`].
+pub struct TableColumn {
+    header: SharedString,
+    width: Option<DefiniteLength>,
+    format: ColumnFormat,
+    aggregate: Option<Aggregate>,
+}
+
+impl TableColumn {
+    pub fn new(header: impl Into<SharedString>) -> Self {
+        Self {
+            header: header.into(),
+            width: None,
+            format: ColumnFormat::default(),
+            aggregate: None,
+        }
+    }
+
+    /// Sets a fixed width for the column. Columns without one share the remaining
+    /// width of the table.
+    pub fn width(mut self, width: impl Into<DefiniteLength>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Formats the column for numbers, like file sizes and durations: right
+    /// aligned, with tabular digits and grouped thousands.
+    pub fn numeric(mut self) -> Self {
+        self.format.alignment = ColumnAlignment::End;
+        self.format.tabular_digits = true;
+        self.format.thousands_separator = true;
+        self
+    }
+
+    pub fn align(mut self, alignment: ColumnAlignment) -> Self {
+        self.format.alignment = alignment;
+        self
+    }
+
+    pub fn tabular_digits(mut self, tabular_digits: bool) -> Self {
+        self.format.tabular_digits = tabular_digits;
+        self
+    }
+
+    pub fn unit(mut self, unit: impl Into<SharedString>) -> Self {
+        self.format.unit = Some(unit.into());
+        self
+    }
+
+    pub fn thousands_separator(mut self, thousands_separator: bool) -> Self {
+        self.format.thousands_separator = thousands_separator;
+        self
+    }
+
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.format.decimals = Some(decimals);
+        self
+    }
+
+    /// Shows an aggregate of the column's values in the header of each group of
+    /// rows, when the table is grouped. Ignored for the first column, which shows
+    /// the group.
+    pub fn aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregate = Some(aggregate);
+        self
+    }
+}
+
+/// The contents of a cell of a [`Table`].
+pub enum TableCell {
+    Text(SharedString),
+    /// A number, formatted with the [`ColumnFormat`] of its column.
+    Number(f64),
+    Element(AnyElement),
+}
+
+impl TableCell {
+    pub fn element(element: impl IntoElement) -> Self {
+        Self::Element(element.into_any_element())
+    }
+}
+
+impl From<&'static str> for TableCell {
+    fn from(text: &'static str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<String> for TableCell {
+    fn from(text: String) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<SharedString> for TableCell {
+    fn from(text: SharedString) -> Self {
+        Self::Text(text)
+    }
+}
+
+macro_rules! impl_from_number_for_table_cell {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for TableCell {
+                fn from(number: $number) -> Self {
+                    Self::Number(number as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number_for_table_cell!(f32, f64, i32, i64, u32, u64, usize);
+
+/// Which groups of a grouped [`Table`] are collapsed. Held by the view rendering
+/// the table, so groups stay collapsed across frames.
+#[derive(Clone, Default)]
+pub struct TableGroupState(Rc<RefCell<HashSet<SharedString>>>);
+
+impl TableGroupState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_collapsed(&self, group: &str) -> bool {
+        self.0.borrow().contains(group)
+    }
+
+    pub fn set_collapsed(&self, group: SharedString, collapsed: bool) {
+        if collapsed {
+            self.0.borrow_mut().insert(group);
+        } else {
+            self.0.borrow_mut().remove(&group);
+        }
+    }
+}
+
+/// Returns the value a row is grouped by, from its cell in the column rows are
+/// grouped by.
+fn group_key(columns: &[TableColumn], row: &[TableCell], column_ix: usize) -> SharedString {
+    columns
+        .get(column_ix)
+        .zip(row.get(column_ix))
+        .and_then(|(column, cell)| cell_text(column, cell))
+        .unwrap_or_default()
+}
+
+/// Returns the text shown in a cell, unless it shows an element.
+fn cell_text(column: &TableColumn, cell: &TableCell) -> Option<SharedString> {
+    match cell {
+        TableCell::Text(text) => Some(text.clone()),
+        TableCell::Number(number) => Some(column.format.format_number(*number).into()),
+        TableCell::Element(_) => None,
+    }
+}
+
+/// Groups rows by the values of a column, keeping the groups in the order their
+/// first rows appear in.
+fn group_rows(
+    rows: Vec<Vec<TableCell>>,
+    columns: &[TableColumn],
+    column_ix: usize,
+) -> Vec<(SharedString, Vec<Vec<TableCell>>)> {
+    let mut groups: Vec<(SharedString, Vec<Vec<TableCell>>)> = Vec::new();
+    let mut group_ixs = HashMap::default();
+    for row in rows {
+        let key = group_key(columns, &row, column_ix);
+        let group_ix = *group_ixs.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[group_ix].1.push(row);
+    }
+    groups
+}
+
+/// What typing in a [`Table`] with a [type-ahead search](Table::type_ahead) does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TypeAheadMode {
+    /// Highlights the first row matching what was typed. Typing after a pause starts
+    /// a new search.
+    #[default]
+    Jump,
+    /// Hides the rows that don't match what was typed, until it's cleared with
+    /// escape.
+    Filter,
+}
+
+/// How long a pause in typing has to be for a new [`TypeAheadMode::Jump`] search to
+/// start.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+struct TypeAheadQuery {
+    text: String,
+    last_keystroke: Option<Instant>,
+}
+
+/// The type-ahead search of a [`Table`], held by the view rendering the table so
+/// that the table keeps its focus and query across frames.
+#[derive(Clone)]
+pub struct TableTypeAhead {
+    focus_handle: FocusHandle,
+    query: Rc<RefCell<TypeAheadQuery>>,
+}
+
+impl TableTypeAhead {
+    pub fn new(cx: &mut WindowContext) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            query: Rc::new(RefCell::new(TypeAheadQuery {
+                text: String::new(),
+                last_keystroke: None,
+            })),
+        }
+    }
+
+    /// The focus handle of the table, which has to be focused for typing to search.
+    pub fn focus_handle(&self) -> &FocusHandle {
+        &self.focus_handle
+    }
+
+    pub fn query(&self) -> String {
+        self.query.borrow().text.clone()
+    }
+
+    pub fn clear(&self) {
+        self.query.borrow_mut().text.clear();
+    }
+
+    fn handle_key_down(&self, event: &KeyDownEvent, mode: TypeAheadMode, cx: &mut WindowContext) {
+        let mut query = self.query.borrow_mut();
+        let now = Instant::now();
+        match event.keystroke.key.as_str() {
+            "backspace" if !query.text.is_empty() => {
+                query.text.pop();
+            }
+            "escape" if !query.text.is_empty() => query.text.clear(),
+            _ => {
+                let Some(text) = typed_text(&event.keystroke) else {
+                    return;
+                };
+                let paused = query.last_keystroke.map_or(true, |last_keystroke| {
+                    now - last_keystroke > TYPE_AHEAD_TIMEOUT
+                });
+                if mode == TypeAheadMode::Jump && paused {
+                    query.text.clear();
+                }
+                query.text.push_str(&text);
+            }
+        }
+        query.last_keystroke = Some(now);
+        cx.stop_propagation();
+        cx.refresh();
+    }
+}
+
+/// Returns the text typed with a keystroke, if it isn't a shortcut.
+fn typed_text(keystroke: &Keystroke) -> Option<String> {
+    let modifiers = &keystroke.modifiers;
+    if modifiers.control || modifiers.platform || modifiers.function {
+        return None;
+    }
+    if let Some(ime_key) = &keystroke.ime_key {
+        return Some(ime_key.clone());
+    }
+    match keystroke.key.as_str() {
+        "space" => Some(" ".to_string()),
+        key if key.chars().count() == 1 && !modifiers.alt => Some(if modifiers.shift {
+            key.to_uppercase()
+        } else {
+            key.to_string()
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the byte indices of the characters of the first case-insensitive match
+/// of a query in a text.
+fn match_positions(text: &str, query: &str) -> Option<Vec<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+    text.char_indices().find_map(|(start, _)| {
+        let mut text_chars = text[start..].char_indices();
+        let mut positions = Vec::new();
+        for query_char in query.chars() {
+            let (ix, text_char) = text_chars.next()?;
+            if !text_char.to_lowercase().eq(query_char.to_lowercase()) {
+                return None;
+            }
+            positions.push(start + ix);
+        }
+        Some(positions)
+    })
+}
+
+struct TypeAheadSearch {
+    column_ix: usize,
+    type_ahead: TableTypeAhead,
+    mode: TypeAheadMode,
+}
+
+/// A plain-text format the rows of a [`Table`] can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableExportFormat {
+    Csv,
+    Tsv,
+}
+
+impl TableExportFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            TableExportFormat::Csv => "CSV",
+            TableExportFormat::Tsv => "TSV",
+        }
+    }
+
+    fn delimiter(self) -> char {
+        match self {
+            TableExportFormat::Csv => ',',
+            TableExportFormat::Tsv => '\t',
+        }
+    }
+
+    /// Escapes a field so it can't be mistaken for several fields or rows. CSV
+    /// fields are quoted, while TSV can't escape tabs and newlines, so they are
+    /// replaced with spaces.
+    fn escape_field(self, field: &str) -> Cow<str> {
+        match self {
+            TableExportFormat::Csv => {
+                if field.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", field.replace('"', "\"\"")).into()
+                } else {
+                    field.into()
+                }
+            }
+            TableExportFormat::Tsv => {
+                if field.contains(['\t', '\n', '\r']) {
+                    field.replace(['\t', '\n', '\r'], " ").into()
+                } else {
+                    field.into()
+                }
+            }
+        }
+    }
+
+    /// Serializes rows of fields, one line per row.
+    fn serialize(self, rows: &[Vec<SharedString>]) -> String {
+        let mut text = String::new();
+        for row in rows {
+            for (ix, field) in row.iter().enumerate() {
+                if ix > 0 {
+                    text.push(self.delimiter());
+                }
+                text.push_str(&self.escape_field(field));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Returns the exported text of a cell. Numbers are exported without their unit
+/// and thousands separators, so that spreadsheets can read them, and elements
+/// can't be exported.
+fn export_cell(column: &TableColumn, cell: &TableCell) -> SharedString {
+    match cell {
+        TableCell::Text(text) => text.clone(),
+        TableCell::Number(number) => ColumnFormat {
+            decimals: column.format.decimals,
+            ..Default::default()
+        }
+        .format_number(*number)
+        .into(),
+        TableCell::Element(_) => SharedString::default(),
+    }
+}
+
+/// The width of the columns without a fixed width in tables that scroll
+/// horizontally.
+const SCROLLING_COLUMN_WIDTH: Pixels = px(120.);
+
+/// Rows of values shown in columns with a header, like the files of a directory
+/// with their sizes.
+#[derive(IntoElement)]
+pub struct Table {
+    id: ElementId,
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<TableCell>>,
+    striped: bool,
+    scroll_handle: Option<ScrollHandle>,
+    sticky_first_column: bool,
+    grouping: Option<(usize, TableGroupState)>,
+    export_button: bool,
+    type_ahead: Option<TypeAheadSearch>,
+}
+
+impl Table {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            striped: false,
+            scroll_handle: None,
+            sticky_first_column: false,
+            grouping: None,
+            export_button: false,
+            type_ahead: None,
+        }
+    }
+
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Adds a row with a cell for each column, in order.
+    pub fn row(mut self, cells: impl IntoIterator<Item = impl Into<TableCell>>) -> Self {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Alternates the background of rows, to make wide tables easier to read.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Scrolls the columns horizontally, header included, when they are wider than
+    /// the table. The handle keeps the scroll position across frames, so it should
+    /// be held by the view rendering the table.
+    ///
+    /// Columns without a fixed width are 120px wide in scrolling tables.
+    pub fn horizontal_scroll(mut self, scroll_handle: ScrollHandle) -> Self {
+        self.scroll_handle = Some(scroll_handle);
+        self
+    }
+
+    /// Keeps the first column, usually identifying the rows, in place while the
+    /// other columns scroll horizontally.
+    pub fn sticky_first_column(mut self, sticky: bool) -> Self {
+        self.sticky_first_column = sticky;
+        self
+    }
+
+    /// Groups the rows by the values of a column, under collapsible headers showing
+    /// the column [aggregates](TableColumn::aggregate) of each group.
+    pub fn group_by(mut self, column_ix: usize, state: TableGroupState) -> Self {
+        self.grouping = Some((column_ix, state));
+        self
+    }
+
+    /// Shows a button above the table to copy its rows to the clipboard or save
+    /// them to a file, as CSV or TSV.
+    pub fn export_button(mut self, export_button: bool) -> Self {
+        self.export_button = export_button;
+        self
+    }
+
+    /// Searches the cells of a column for what is typed while the table is focused,
+    /// highlighting the matches.
+    pub fn type_ahead(
+        mut self,
+        column_ix: usize,
+        type_ahead: &TableTypeAhead,
+        mode: TypeAheadMode,
+    ) -> Self {
+        self.type_ahead = Some(TypeAheadSearch {
+            column_ix,
+            type_ahead: type_ahead.clone(),
+            mode,
+        });
+        self
+    }
+
+    /// Serializes the header and rows of the table, in the order they are shown.
+    /// Rows of collapsed groups are included.
+    pub fn export(&self, format: TableExportFormat) -> String {
+        format.serialize(&self.export_fields())
+    }
+
+    fn export_fields(&self) -> Vec<Vec<SharedString>> {
+        let mut row_order = (0..self.rows.len()).collect::<Vec<_>>();
+        if let Some((column_ix, _)) = &self.grouping {
+            let mut group_ixs = HashMap::default();
+            let row_groups = self
+                .rows
+                .iter()
+                .map(|row| {
+                    let group_count = group_ixs.len();
+                    *group_ixs
+                        .entry(group_key(&self.columns, row, *column_ix))
+                        .or_insert(group_count)
+                })
+                .collect::<Vec<_>>();
+            row_order.sort_by_key(|&row_ix| row_groups[row_ix]);
+        }
+
+        let header = self
+            .columns
+            .iter()
+            .map(|column| column.header.clone())
+            .collect();
+        let rows = row_order.into_iter().map(|row_ix| {
+            self.columns
+                .iter()
+                .zip(&self.rows[row_ix])
+                .map(|(column, cell)| export_cell(column, cell))
+                .collect()
+        });
+        std::iter::once(header).chain(rows).collect()
+    }
+}
+
+fn save_export(contents: String, cx: &mut WindowContext) {
+    let directory = std::env::current_dir().unwrap_or_default();
+    let path = cx.prompt_for_new_path(&directory);
+    cx.spawn(|cx| async move {
+        let Some(path) = path.await?? else {
+            return Ok(());
+        };
+        cx.background_executor()
+            .spawn(async move { std::fs::write(path, contents) })
+            .await?;
+        anyhow::Ok(())
+    })
+    .detach_and_log_err(cx);
+}
+
+fn render_export_menu(fields: Rc<Vec<Vec<SharedString>>>) -> AnyElement {
+    PopoverMenu::new("table-export")
+        .trigger(
+            IconButton::new("table-export-trigger", IconName::Download)
+                .icon_size(IconSize::Small)
+                .icon_color(Color::Muted),
+        )
+        .menu(move |cx| {
+            let fields = fields.clone();
+            Some(ContextMenu::build(cx, move |mut menu, _| {
+                for format in [TableExportFormat::Csv, TableExportFormat::Tsv] {
+                    let copied_fields = fields.clone();
+                    let saved_fields = fields.clone();
+                    menu = menu
+                        .entry(format!("Copy as {}", format.label()), None, move |cx| {
+                            cx.write_to_clipboard(ClipboardItem::new_string(
+                                format.serialize(&copied_fields),
+                            ))
+                        })
+                        .entry(format!("Save as {}…", format.label()), None, move |cx| {
+                            save_export(format.serialize(&saved_fields), cx)
+                        });
+                }
+                menu
+            }))
+        })
+        .into_any_element()
+}
+
+fn column_width(column: &TableColumn, scrolling: bool) -> Option<DefiniteLength> {
+    column
+        .width
+        .or_else(|| scrolling.then(|| SCROLLING_COLUMN_WIDTH.into()))
+}
+
+fn render_cell(column: &TableColumn, scrolling: bool, content: AnyElement) -> Div {
+    h_flex()
+        .map(|this| match column_width(column, scrolling) {
+            Some(width) => this.flex_none().w(width),
+            None => this.flex_1().min_w_0(),
+        })
+        .px_2()
+        .overflow_hidden()
+        .map(|this| match column.format.alignment {
+            ColumnAlignment::Start => this.justify_start(),
+            ColumnAlignment::Center => this.justify_center(),
+            ColumnAlignment::End => this.justify_end(),
+        })
+        .when(column.format.tabular_digits, |mut this| {
+            this.text_style()
+                .get_or_insert_with(Default::default)
+                .font_features = Some(FontFeatures(Arc::new(vec![("tnum".into(), 1)])));
+            this
+        })
+        .child(content)
+}
+
+/// A cell kept in place while the rest of its row scrolls horizontally.
+struct StickyCell {
+    /// The horizontal scroll offset of the table, which the cell is moved back by.
+    scroll_offset: Pixels,
+    background: Hsla,
+}
+
+fn render_row(
+    columns: &[TableColumn],
+    scrolling: bool,
+    sticky_cell: Option<StickyCell>,
+    cells: Vec<AnyElement>,
+) -> Div {
+    let mut cells = columns
+        .iter()
+        .zip(cells)
+        .map(|(column, content)| render_cell(column, scrolling, content));
+
+    let Some(sticky_cell) = sticky_cell else {
+        return h_flex().w_full().children(cells);
+    };
+    let Some(first_cell) = cells.next() else {
+        return h_flex().w_full();
+    };
+    let first_column_width = column_width(&columns[0], scrolling).unwrap_or_default();
+
+    // The sticky cell is painted last, over the cells scrolling underneath it, with
+    // an empty cell reserving its space.
+    h_flex()
+        .relative()
+        .w_full()
+        .child(div().flex_none().w(first_column_width))
+        .children(cells)
+        .child(
+            h_flex()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(-sticky_cell.scroll_offset)
+                .bg(sticky_cell.background)
+                .child(first_cell.h_full()),
+        )
+}
+
+fn render_cell_content(column: &TableColumn, cell: TableCell) -> AnyElement {
+    match cell {
+        TableCell::Text(text) => Label::new(text).single_line().into_any_element(),
+        TableCell::Number(number) => Label::new(column.format.format_number(number))
+            .single_line()
+            .into_any_element(),
+        TableCell::Element(element) => element,
+    }
+}
+
+fn render_group_title(
+    group_ix: usize,
+    group: SharedString,
+    row_count: usize,
+    collapsed: bool,
+    state: TableGroupState,
+) -> AnyElement {
+    let toggled_group = group.clone();
+    let on_toggle: Arc<dyn Fn(&ClickEvent, &mut WindowContext)> = Arc::new(move |_, cx| {
+        state.set_collapsed(toggled_group.clone(), !collapsed);
+        cx.refresh();
+    });
+    h_flex()
+        .gap_1()
+        .child(Disclosure::new(("table-group", group_ix), !collapsed).on_toggle(on_toggle))
+        .child(Label::new(group).single_line())
+        .child(
+            Label::new(row_count.to_string())
+                .size(LabelSize::Small)
+                .color(Color::Muted),
+        )
+        .into_any_element()
+}
+
+fn render_aggregate(column: &TableColumn, column_ix: usize, rows: &[Vec<TableCell>]) -> AnyElement {
+    let Some(aggregate) = column.aggregate else {
+        return div().into_any_element();
+    };
+    let numbers = rows
+        .iter()
+        .filter_map(|row| match row.get(column_ix) {
+            Some(TableCell::Number(number)) => Some(*number),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let Some(value) = aggregate.compute(rows.len(), &numbers) else {
+        return div().into_any_element();
+    };
+    let text = match aggregate {
+        Aggregate::Count => ColumnFormat {
+            thousands_separator: true,
+            ..Default::default()
+        }
+        .format_number(value),
+        _ => column.format.format_number(value),
+    };
+    Label::new(text)
+        .single_line()
+        .weight(FontWeight::SEMIBOLD)
+        .into_any_element()
+}
+
+/// A shadow cast over the columns scrolling under the edge of a table, hinting
+/// that there is more content in that direction.
+fn render_edge_shadow(offset_x: Pixels, cx: &WindowContext) -> Div {
+    div()
+        .absolute()
+        .top_0()
+        .bottom_0()
+        .w(px(1.))
+        .shadow(smallvec![BoxShadow {
+            color: cx.theme().colors().border.opacity(0.6),
+            offset: point(offset_x, px(0.)),
+            blur_radius: px(6.),
+            spread_radius: px(0.),
+        }])
+}
+
+impl RenderOnce for Table {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        let query = self
+            .type_ahead
+            .as_ref()
+            .map(|search| search.type_ahead.query())
+            .unwrap_or_default();
+        let search = self
+            .type_ahead
+            .as_ref()
+            .map(|search| (search.column_ix, search.mode));
+        if let Some((column_ix, TypeAheadMode::Filter)) = search {
+            if !query.is_empty() {
+                let columns = &self.columns;
+                self.rows.retain(|row| {
+                    columns
+                        .get(column_ix)
+                        .zip(row.get(column_ix))
+                        .and_then(|(column, cell)| cell_text(column, cell))
+                        .map_or(false, |text| match_positions(&text, &query).is_some())
+                });
+            }
+        }
+
+        let export_fields = self.export_button.then(|| Rc::new(self.export_fields()));
+        let scrolling = self.scroll_handle.is_some();
+        let scroll_offset = self
+            .scroll_handle
+            .as_ref()
+            .map_or(px(0.), |handle| handle.offset().x);
+        let sticky = scrolling && self.sticky_first_column && !self.columns.is_empty();
+        let surface = cx.theme().colors().surface_background;
+        let stripe_color = cx.theme().colors().element_background.opacity(0.5);
+        let sticky_cell = |background: Hsla| {
+            sticky.then_some(StickyCell {
+                scroll_offset,
+                background,
+            })
+        };
+
+        let header_cells = self
+            .columns
+            .iter()
+            .map(|column| {
+                Label::new(column.header.clone())
+                    .size(LabelSize::Small)
+                    .weight(FontWeight::SEMIBOLD)
+                    .color(Color::Muted)
+                    .into_any_element()
+            })
+            .collect();
+        let header = render_row(&self.columns, scrolling, sticky_cell(surface), header_cells)
+            .py_1()
+            .border_b_1()
+            .border_color(cx.theme().colors().border_variant);
+
+        let focused = self.type_ahead.as_ref().map_or(false, |search| {
+            search.type_ahead.focus_handle.is_focused(cx)
+        });
+        let selected_color = InteractiveColors::row(focused, cx).selected;
+        let mut jumped = false;
+        let mut render_data_row = |row_ix: usize, cells: Vec<TableCell>| {
+            let striped = self.striped && row_ix % 2 == 1;
+            let mut is_match = false;
+            let cells = self
+                .columns
+                .iter()
+                .enumerate()
+                .zip(cells)
+                .map(|((column_ix, column), cell)| {
+                    let positions = search
+                        .filter(|(search_column_ix, _)| *search_column_ix == column_ix)
+                        .and_then(|_| cell_text(column, &cell))
+                        .and_then(|text| Some((match_positions(&text, &query)?, text)));
+                    match positions {
+                        Some((positions, text)) => {
+                            is_match = true;
+                            HighlightedLabel::new(text, positions).into_any_element()
+                        }
+                        None => render_cell_content(column, cell),
+                    }
+                })
+                .collect();
+            // Jumping highlights the first matching row that is shown.
+            let selected = is_match && !jumped && matches!(search, Some((_, TypeAheadMode::Jump)));
+            jumped |= selected;
+            let background = if selected {
+                surface.blend(selected_color)
+            } else if striped {
+                surface.blend(stripe_color)
+            } else {
+                surface
+            };
+            render_row(&self.columns, scrolling, sticky_cell(background), cells)
+                .py_0p5()
+                .when(striped, |this| this.bg(stripe_color))
+                .when(selected, |this| this.bg(selected_color))
+        };
+
+        let mut rows = Vec::new();
+        match self.grouping {
+            None => rows.extend(
+                self.rows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row_ix, cells)| render_data_row(row_ix, cells)),
+            ),
+            Some((column_ix, state)) => {
+                let group_background = cx.theme().colors().element_background;
+                for (group_ix, (group, rows_in_group)) in
+                    group_rows(self.rows, &self.columns, column_ix)
+                        .into_iter()
+                        .enumerate()
+                {
+                    let collapsed = state.is_collapsed(&group);
+                    let cells = self
+                        .columns
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, column)| {
+                            if ix == 0 {
+                                render_group_title(
+                                    group_ix,
+                                    group.clone(),
+                                    rows_in_group.len(),
+                                    collapsed,
+                                    state.clone(),
+                                )
+                            } else {
+                                render_aggregate(column, ix, &rows_in_group)
+                            }
+                        })
+                        .collect();
+                    rows.push(
+                        render_row(
+                            &self.columns,
+                            scrolling,
+                            sticky_cell(surface.blend(group_background)),
+                            cells,
+                        )
+                        .py_0p5()
+                        .bg(group_background),
+                    );
+                    if !collapsed {
+                        rows.extend(
+                            rows_in_group
+                                .into_iter()
+                                .enumerate()
+                                .map(|(row_ix, cells)| render_data_row(row_ix, cells)),
+                        );
+                    }
+                }
+            }
+        }
+
+        let table = match self.scroll_handle {
+            None => v_flex()
+                .id(self.id.clone())
+                .w_full()
+                .child(header)
+                .children(rows)
+                .into_any_element(),
+            Some(scroll_handle) => {
+                // The bounds of the table and its content as of the last frame.
+                let viewport_width = scroll_handle.bounds().size.width;
+                let content_width = scroll_handle
+                    .bounds_for_item(0)
+                    .map_or(px(0.), |bounds| bounds.size.width);
+                let more_start = scroll_offset < px(0.);
+                let more_end = content_width + scroll_offset > viewport_width + px(0.5);
+                let shadow_start = if sticky {
+                    column_width(&self.columns[0], scrolling).unwrap_or_default()
+                } else {
+                    px(0.).into()
+                };
+
+                div()
+                    .relative()
+                    .w_full()
+                    .child(
+                        h_flex()
+                            .id(self.id.clone())
+                            .w_full()
+                            .overflow_x_scroll()
+                            .track_scroll(&scroll_handle)
+                            .child(
+                                v_flex()
+                                    .flex_none()
+                                    .min_w_full()
+                                    .child(header)
+                                    .children(rows),
+                            ),
+                    )
+                    .when(more_start, |this| {
+                        this.child(render_edge_shadow(px(2.), cx).left(shadow_start))
+                    })
+                    .when(more_end, |this| {
+                        this.child(render_edge_shadow(px(-2.), cx).right_0())
+                    })
+                    .into_any_element()
+            }
+        };
+
+        if export_fields.is_none() && self.type_ahead.is_none() {
+            return table;
+        }
+        let show_bar = export_fields.is_some() || !query.is_empty();
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .when_some(self.type_ahead, |this, search| {
+                let type_ahead = search.type_ahead.clone();
+                let focus_handle = search.type_ahead.focus_handle.clone();
+                this.track_focus(&search.type_ahead.focus_handle)
+                    .on_mouse_down(MouseButton::Left, move |_, cx| cx.focus(&focus_handle))
+                    .on_key_down(move |event, cx| {
+                        type_ahead.handle_key_down(event, search.mode, cx)
+                    })
+            })
+            .when(show_bar, |this| {
+                this.child(
+                    h_flex()
+                        .gap_1()
+                        .px_1()
+                        .child(h_flex().flex_1().gap_1().when(!query.is_empty(), |this| {
+                            this.child(
+                                Icon::new(IconName::MagnifyingGlass)
+                                    .size(IconSize::Small)
+                                    .color(Color::Muted),
+                            )
+                            .child(Label::new(query.clone()).size(LabelSize::Small))
+                        }))
+                        .children(export_fields.map(render_export_menu)),
+                )
+            })
+            .child(table)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        let format = ColumnFormat::default();
+        assert_eq!(format.format_number(1234.5), "1234.5");
+
+        let format = ColumnFormat {
+            thousands_separator: true,
+            ..Default::default()
+        };
+        assert_eq!(format.format_number(999.), "999");
+        assert_eq!(format.format_number(1234567.), "1,234,567");
+        assert_eq!(format.format_number(-12345.678), "-12,345.678");
+        assert_eq!(format.format_number(f64::NAN), "NaN");
+
+        let format = ColumnFormat {
+            thousands_separator: true,
+            decimals: Some(1),
+            unit: Some(" KB".into()),
+            ..Default::default()
+        };
+        assert_eq!(format.format_number(20480.26), "20,480.3 KB");
+        assert_eq!(format.format_number(0.), "0.0 KB");
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let numbers = [3., 1., 2.];
+        assert_eq!(Aggregate::Count.compute(4, &numbers), Some(4.));
+        assert_eq!(Aggregate::Sum.compute(4, &numbers), Some(6.));
+        assert_eq!(Aggregate::Average.compute(4, &numbers), Some(2.));
+        assert_eq!(Aggregate::Min.compute(4, &numbers), Some(1.));
+        assert_eq!(Aggregate::Max.compute(4, &numbers), Some(3.));
+        assert_eq!(Aggregate::Average.compute(0, &[]), None);
+        assert_eq!(Aggregate::Max.compute(0, &[]), None);
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let columns = [TableColumn::new("Kind"), TableColumn::new("Count")];
+        let row = |kind: &'static str, count: u32| vec![kind.into(), count.into()];
+        let groups = group_rows(
+            vec![row("error", 1), row("warning", 2), row("error", 3)],
+            &columns,
+            0,
+        );
+
+        let groups = groups
+            .iter()
+            .map(|(group, rows)| (group.as_ref(), rows.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(groups, [("error", 2), ("warning", 1)]);
+    }
+
+    #[test]
+    fn test_match_positions() {
+        assert_eq!(
+            match_positions("Cargo.toml", "toml"),
+            Some(vec![6, 7, 8, 9])
+        );
+        assert_eq!(match_positions("Cargo.toml", "CAR"), Some(vec![0, 1, 2]));
+        assert_eq!(match_positions("Crème", "ÈM"), Some(vec![2, 4]));
+        assert_eq!(match_positions("Cargo.toml", "lock"), None);
+        assert_eq!(match_positions("Cargo.toml", ""), None);
+    }
+
+    #[test]
+    fn test_export() {
+        let table = Table::new("table")
+            .column(TableColumn::new("Name"))
+            .column(TableColumn::new("Size").numeric().unit(" KB").decimals(1))
+            .row([TableCell::from("a, \"b\""), 1234.5f64.into()])
+            .row([TableCell::from("c\td"), 2u32.into()]);
+
+        assert_eq!(
+            table.export(TableExportFormat::Csv),
+            "Name,Size\n\"a, \"\"b\"\"\",1234.5\nc\td,2.0\n"
+        );
+        assert_eq!(
+            table.export(TableExportFormat::Tsv),
+            "Name\tSize\na, \"b\"\t1234.5\nc d\t2.0\n"
+        );
+
+        let table = Table::new("table")
+            .column(TableColumn::new("Kind"))
+            .row(["error"])
+            .row(["warning"])
+            .row(["error"])
+            .group_by(0, TableGroupState::new());
+        assert_eq!(
+            table.export(TableExportFormat::Csv),
+            "Kind\nerror\nerror\nwarning\n"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(missing_docs)]
+use gpui::{img, ImageSource, Img, ObjectFit};
+
+use crate::prelude::*;
+
+/// A small preview of an image, e.g. at the start of a [`ListItem`](crate::ListItem)
+/// listing assets, extension screenshots or theme previews.
+///
+/// The image is loaded in the background and cached by source, so scrolling a list
+/// back and forth doesn't reload it. Until it has loaded, or if it fails to load, a
+/// placeholder icon is shown in its place. The image is cropped to the aspect ratio
+/// of the thumbnail rather than letterboxed.
+#[derive(IntoElement)]
+pub struct Thumbnail {
+    image: Img,
+    height: AbsoluteLength,
+    aspect_ratio: f32,
+    placeholder_icon: IconName,
+}
+
+impl Thumbnail {
+    pub fn new(source: impl Into<ImageSource>) -> Self {
+        Self {
+            image: img(source),
+            height: rems(1.5).into(),
+            aspect_ratio: 1.,
+            placeholder_icon: IconName::File,
+        }
+    }
+
+    /// Sets the height of the thumbnail. Defaults to the height of a list row.
+    pub fn height(mut self, height: impl Into<AbsoluteLength>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the ratio of the width of the thumbnail to its height. Defaults to `1.`.
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = aspect_ratio.max(0.1);
+        self
+    }
+
+    pub fn grayscale(mut self, grayscale: bool) -> Self {
+        self.image = self.image.grayscale(grayscale);
+        self
+    }
+
+    /// Sets the icon shown while the image is loading.
+    pub fn placeholder_icon(mut self, icon: IconName) -> Self {
+        self.placeholder_icon = icon;
+        self
+    }
+}
+
+impl RenderOnce for Thumbnail {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let height = self.height.to_pixels(cx.rem_size());
+        let width = height * self.aspect_ratio;
+
+        div()
+            .relative()
+            .flex_none()
+            .flex()
+            .items_center()
+            .justify_center()
+            .w(width)
+            .h(height)
+            .overflow_hidden()
+            .rounded_sm()
+            .bg(cx.theme().colors().element_background)
+            .child(
+                Icon::new(self.placeholder_icon)
+                    .size(IconSize::XSmall)
+                    .color(Color::Muted),
+            )
+            .child(
+                self.image
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .object_fit(ObjectFit::Cover),
+            )
+    }
+}
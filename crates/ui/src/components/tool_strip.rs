@@ -1,12 +1,122 @@
 #![allow(missing_docs)]
 
-use crate::prelude::*;
+use std::rc::Rc;
+
+use crate::{prelude::*, ContextMenu, PopoverMenu, Tooltip};
 use gpui::*;
 
+/// The count shown on a badge, which doesn't grow past two digits.
+fn badge_text(count: usize) -> SharedString {
+    if count > 99 {
+        "99+".into()
+    } else {
+        count.to_string().into()
+    }
+}
+
+/// A tool of a [`ToolStrip`], like a panel of an activity bar.
+#[derive(Clone)]
+pub struct ToolStripItem {
+    id: ElementId,
+    icon: IconName,
+    label: SharedString,
+    active: bool,
+    badge: Option<usize>,
+    on_click: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl ToolStripItem {
+    /// Creates a tool with the given icon. The label is shown in its tooltip, and
+    /// in the overflow menu.
+    pub fn new(id: impl Into<ElementId>, icon: IconName, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            icon,
+            label: label.into(),
+            active: false,
+            badge: None,
+            on_click: None,
+        }
+    }
+
+    /// Marks the tool as active, e.g. because its panel is open, with an indicator
+    /// on the edge of the strip.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Shows a count on the tool, like the number of unread notifications. A count
+    /// of zero hides the badge.
+    pub fn badge(mut self, count: impl Into<Option<usize>>) -> Self {
+        self.badge = count.into().filter(|count| *count > 0);
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn render(self, axis: Axis, cx: &WindowContext) -> impl IntoElement {
+        let label = self.label.clone();
+        let on_click = self.on_click.clone();
+        let indicator_color = cx.theme().colors().text_accent;
+
+        div()
+            .relative()
+            .child(
+                IconButton::new(self.id.clone(), self.icon)
+                    .icon_size(IconSize::Medium)
+                    .selected(self.active)
+                    .tooltip(move |cx| Tooltip::text(label.clone(), cx))
+                    .when_some(on_click, |this, on_click| {
+                        this.on_click(move |_, cx| on_click(cx))
+                    }),
+            )
+            .when(self.active, |this| {
+                this.child(div().absolute().bg(indicator_color).rounded_sm().map(
+                    |this| match axis {
+                        Axis::Vertical => this.left(px(-4.)).top_1().bottom_1().w(px(2.)),
+                        Axis::Horizontal => this.bottom(px(-4.)).left_1().right_1().h(px(2.)),
+                    },
+                ))
+            })
+            .when_some(self.badge, |this, count| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top(px(-2.))
+                        .right(px(-4.))
+                        .min_w(rems_from_px(14.))
+                        .h(rems_from_px(14.))
+                        .px(px(3.))
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .rounded_full()
+                        .bg(cx.theme().status().info)
+                        .child(
+                            Label::new(badge_text(count))
+                                .size(LabelSize::XSmall)
+                                .color(Color::Custom(cx.theme().colors().background))
+                                .line_height_style(LineHeightStyle::UiLabel),
+                        ),
+                )
+            })
+    }
+}
+
+/// A strip of icon buttons, like the activity bar on the side of a dock.
+///
+/// Tools beyond [`ToolStrip::max_visible`] are moved into a menu at the end of the
+/// strip, so that the strip fits in short windows.
 #[derive(IntoElement)]
 pub struct ToolStrip {
     id: ElementId,
     tools: Vec<IconButton>,
+    items: Vec<ToolStripItem>,
+    max_visible: Option<usize>,
     axis: Axis,
 }
 
@@ -15,6 +125,8 @@ impl ToolStrip {
         Self {
             id,
             tools: vec![],
+            items: vec![],
+            max_visible: None,
             axis,
         }
     }
@@ -23,6 +135,10 @@ impl ToolStrip {
         Self::new(id.into(), Axis::Vertical)
     }
 
+    pub fn horizontal(id: impl Into<ElementId>) -> Self {
+        Self::new(id.into(), Axis::Horizontal)
+    }
+
     pub fn tools(mut self, tools: Vec<IconButton>) -> Self {
         self.tools = tools;
         self
@@ -32,16 +148,101 @@ impl ToolStrip {
         self.tools.push(tool);
         self
     }
+
+    /// Adds a tool with an active indicator and a badge, drawn after the tools
+    /// added with [`ToolStrip::tool`].
+    pub fn item(mut self, item: ToolStripItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ToolStripItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Sets how many [`ToolStripItem`]s are shown before the rest are moved into the
+    /// overflow menu.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = Some(max_visible);
+        self
+    }
 }
 
 impl RenderOnce for ToolStrip {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
         let group = format!("tool_strip_{}", self.id.clone());
+        let axis = self.axis;
+
+        let overflow = match self.max_visible {
+            // Keep a slot for the overflow button, rather than overflowing a single item.
+            Some(max_visible) if self.items.len() > max_visible => {
+                let split_ix = max_visible.saturating_sub(1);
+                Some(Rc::new(self.items.split_off(split_ix)))
+            }
+            _ => None,
+        };
+        let overflow_menu = overflow.map(|overflow| {
+            let any_active = overflow.iter().any(|item| item.active);
+            let badge = overflow.iter().filter_map(|item| item.badge).sum::<usize>();
+            let overflow_count = overflow.len();
+            div()
+                .relative()
+                .child(
+                    PopoverMenu::new("tool_strip_overflow")
+                        .menu(move |cx| {
+                            let overflow = overflow.clone();
+                            Some(ContextMenu::build(cx, move |mut menu, _| {
+                                for item in overflow.iter() {
+                                    let on_click = item.on_click.clone();
+                                    let label = match item.badge {
+                                        Some(count) => format!("{} ({})", item.label, count),
+                                        None => item.label.to_string(),
+                                    };
+                                    menu =
+                                        menu.checkable_entry(label, item.active, None, move |cx| {
+                                            if let Some(on_click) = on_click.as_ref() {
+                                                on_click(cx);
+                                            }
+                                        });
+                                }
+                                menu
+                            }))
+                        })
+                        .trigger(
+                            IconButton::new("tool_strip_overflow_trigger", IconName::Ellipsis)
+                                .icon_size(IconSize::Medium)
+                                .selected(any_active)
+                                .tooltip(move |cx| {
+                                    Tooltip::text(format!("{} more", overflow_count), cx)
+                                }),
+                        )
+                        .anchor(match axis {
+                            Axis::Vertical => AnchorCorner::TopLeft,
+                            Axis::Horizontal => AnchorCorner::TopRight,
+                        })
+                        .attach(match axis {
+                            Axis::Vertical => AnchorCorner::TopRight,
+                            Axis::Horizontal => AnchorCorner::BottomRight,
+                        }),
+                )
+                .when(badge > 0, |this| {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top(px(-2.))
+                            .right(px(-2.))
+                            .size(px(6.))
+                            .rounded_full()
+                            .bg(cx.theme().status().info),
+                    )
+                })
+        });
 
         div()
             .id(self.id.clone())
             .group(group)
-            .map(|element| match self.axis {
+            .map(|element| match axis {
                 Axis::Vertical => element.v_flex(),
                 Axis::Horizontal => element.h_flex(),
             })
@@ -53,5 +254,19 @@ impl RenderOnce for ToolStrip {
             .rounded(rems_from_px(6.0))
             .bg(cx.theme().colors().elevated_surface_background)
             .children(self.tools)
+            .children(self.items.into_iter().map(|item| item.render(axis, cx)))
+            .children(overflow_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_text() {
+        assert_eq!(badge_text(7), "7");
+        assert_eq!(badge_text(99), "99");
+        assert_eq!(badge_text(100), "99+");
     }
 }
@@ -0,0 +1,494 @@
+#![allow(missing_docs)]
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::mem;
+use std::rc::Rc;
+
+use gpui::{
+    point, quad, size, AnyElement, AvailableSpace, Bounds, ContentMask, Corners, Edges,
+    GlobalElementId, LayoutId, PaintQuad, Size, Style,
+};
+
+use crate::{prelude::*, ContextMenu, Divider, PopoverMenu};
+
+/// Where an item is placed in a [`Toolbar`] or [`StatusBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolbarSlot {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone)]
+struct OverflowEntry {
+    label: SharedString,
+    on_click: Rc<dyn Fn(&mut WindowContext)>,
+}
+
+/// An item of a [`Toolbar`] or [`StatusBar`].
+pub struct ToolbarItem {
+    element: AnyElement,
+    priority: u32,
+    overflow_entry: Option<OverflowEntry>,
+}
+
+impl ToolbarItem {
+    pub fn new(element: impl IntoElement) -> Self {
+        Self {
+            element: element.into_any_element(),
+            priority: 0,
+            overflow_entry: None,
+        }
+    }
+
+    /// Items with a lower priority are collapsed into the overflow menu first.
+    /// Defaults to 0.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Collapses the item into the overflow menu of the bar, as an entry with this
+    /// label, when the bar is too narrow to show all of its items. Items without an
+    /// overflow entry are always shown, and clipped if they still don't fit.
+    pub fn overflow_entry(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        self.overflow_entry = Some(OverflowEntry {
+            label: label.into(),
+            on_click: Rc::new(on_click),
+        });
+        self
+    }
+}
+
+enum BarEntry {
+    Item(ToolbarItem),
+    Separator,
+}
+
+#[derive(Clone, Copy)]
+enum BarKind {
+    Toolbar,
+    StatusBar,
+}
+
+/// The layout shared by [`Toolbar`] and [`StatusBar`].
+struct ItemBar {
+    id: ElementId,
+    kind: BarKind,
+    entries: Vec<(ToolbarSlot, BarEntry)>,
+}
+
+/// An entry of a bar, laid out on its own to measure it.
+struct MeasuredEntry {
+    slot: ToolbarSlot,
+    element: AnyElement,
+    size: Size<Pixels>,
+    is_separator: bool,
+    priority: u32,
+    overflow_entry: Option<OverflowEntry>,
+}
+
+/// The entries of a bar shown in this frame, positioned within its bounds.
+struct ItemBarLayout {
+    background: Option<PaintQuad>,
+    elements: Vec<(ToolbarSlot, AnyElement)>,
+    /// Clips the start slot to the space left by the other slots, so that items
+    /// that can't be collapsed are cut off rather than drawn over their neighbours.
+    start_mask: ContentMask<Pixels>,
+}
+
+impl ItemBar {
+    fn new(id: ElementId, kind: BarKind) -> Self {
+        Self {
+            id,
+            kind,
+            entries: Vec::new(),
+        }
+    }
+
+    fn height(&self) -> Rems {
+        match self.kind {
+            BarKind::Toolbar => rems_from_px(32.),
+            BarKind::StatusBar => rems_from_px(28.),
+        }
+    }
+
+    fn gap(&self, cx: &WindowContext) -> Rems {
+        match self.kind {
+            BarKind::Toolbar => Spacing::Small.rems(cx),
+            BarKind::StatusBar => Spacing::Large.rems(cx),
+        }
+    }
+
+    fn padding(&self, cx: &WindowContext) -> Rems {
+        match self.kind {
+            BarKind::Toolbar => Spacing::Medium.rems(cx),
+            BarKind::StatusBar => Spacing::Large.rems(cx),
+        }
+    }
+
+    fn request_layout(&mut self, cx: &mut WindowContext) -> LayoutId {
+        let mut style = Style::default();
+        style.flex_grow = 1.;
+        style.flex_shrink = 1.;
+        style.size.width = relative(1.).into();
+        style.size.height = self.height().into();
+        style.min_size.width = px(0.).into();
+        cx.request_layout(style, None)
+    }
+
+    /// Lays out every entry once, at its content width, then collapses items into
+    /// the overflow menu until the rest fit, and positions what's left by hand,
+    /// so that no item is laid out twice in a frame.
+    fn prepaint(&mut self, bounds: Bounds<Pixels>, cx: &mut WindowContext) -> ItemBarLayout {
+        let rem_size = cx.rem_size();
+        let height = self.height();
+        let gap = self.gap(cx).to_pixels(rem_size);
+        let padding = self.padding(cx).to_pixels(rem_size);
+        let available_space = size(
+            AvailableSpace::MinContent,
+            AvailableSpace::Definite(bounds.size.height),
+        );
+
+        let mut entries = mem::take(&mut self.entries)
+            .into_iter()
+            .map(|(slot, entry)| {
+                let (mut element, is_separator, priority, overflow_entry) = match entry {
+                    BarEntry::Item(item) => {
+                        (item.element, false, item.priority, item.overflow_entry)
+                    }
+                    BarEntry::Separator => (
+                        h_flex()
+                            .h(height)
+                            .child(Divider::vertical())
+                            .into_any_element(),
+                        true,
+                        0,
+                        None,
+                    ),
+                };
+                let size = element.layout_as_root(available_space, cx);
+                MeasuredEntry {
+                    slot,
+                    element,
+                    size,
+                    is_separator,
+                    priority,
+                    overflow_entry,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The items that can collapse, lowest priority first and, among items with
+        // the same priority, last first.
+        let mut collapsible = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.overflow_entry.is_some())
+            .map(|(ix, entry)| (ix, entry.priority))
+            .collect::<Vec<_>>();
+        collapsible.sort_by_key(|(ix, priority)| (*priority, Reverse(*ix)));
+
+        let overflow_entries = Rc::new(RefCell::new(Vec::new()));
+        let overflow_menu = (!collapsible.is_empty()).then(|| {
+            let mut menu = render_overflow_menu(overflow_entries.clone());
+            let size = menu.layout_as_root(available_space, cx);
+            (menu, size)
+        });
+
+        let mut collapsed = vec![false; entries.len()];
+        let mut collapsible = collapsible.into_iter();
+        loop {
+            let shown = shown_entries(&entries, &collapsed);
+            let any_collapsed = collapsed.contains(&true);
+            let menu_width = overflow_menu
+                .as_ref()
+                .filter(|_| any_collapsed)
+                .map(|(_, size)| size.width);
+            let width = padding * 2. + content_width(&entries, &shown, menu_width, gap);
+            if width <= bounds.size.width {
+                break;
+            }
+            let Some((ix, _)) = collapsible.next() else {
+                break;
+            };
+            collapsed[ix] = true;
+        }
+
+        let shown = shown_entries(&entries, &collapsed);
+        overflow_entries.borrow_mut().extend(
+            entries
+                .iter()
+                .zip(&collapsed)
+                .filter(|(_, collapsed)| **collapsed)
+                .filter_map(|(entry, _)| entry.overflow_entry.clone()),
+        );
+        let overflow_menu = overflow_menu.filter(|_| collapsed.contains(&true));
+
+        let slot_width = |slot: ToolbarSlot, menu_width: Option<Pixels>| {
+            let mut widths = entries
+                .iter()
+                .zip(&shown)
+                .filter(|(entry, shown)| **shown && entry.slot == slot)
+                .map(|(entry, _)| entry.size.width)
+                .chain(menu_width)
+                .peekable();
+            let mut width = px(0.);
+            while let Some(entry_width) = widths.next() {
+                width += entry_width;
+                if widths.peek().is_some() {
+                    width += gap;
+                }
+            }
+            width
+        };
+        let menu_width = overflow_menu.as_ref().map(|(_, size)| size.width);
+        let start_width = slot_width(ToolbarSlot::Start, None);
+        let center_width = slot_width(ToolbarSlot::Center, None);
+        let end_width = slot_width(ToolbarSlot::End, menu_width);
+
+        let start_x = bounds.left() + padding;
+        let end_x = bounds.right() - padding - end_width;
+        let center_x = (bounds.center().x - center_width / 2.)
+            .min(end_x - gap - center_width)
+            .max(start_x + start_width + gap);
+        let start_limit = if center_width > px(0.) {
+            center_x - gap
+        } else if end_width > px(0.) {
+            end_x - gap
+        } else {
+            bounds.right() - padding
+        };
+
+        let mut elements = Vec::new();
+        let mut x = [start_x, center_x, end_x];
+        let start_mask = ContentMask {
+            bounds: Bounds::from_corners(
+                bounds.origin,
+                point(start_limit.max(bounds.left()), bounds.bottom()),
+            ),
+        };
+        let menu = overflow_menu.map(|(element, size)| (ToolbarSlot::End, element, size));
+        let positioned = entries
+            .drain(..)
+            .zip(shown)
+            .filter(|(_, shown)| *shown)
+            .map(|(entry, _)| (entry.slot, entry.element, entry.size))
+            .chain(menu);
+        for (slot, mut element, size) in positioned {
+            let slot_x = &mut x[slot as usize];
+            let origin = point(
+                *slot_x,
+                bounds.top() + (bounds.size.height - size.height) / 2.,
+            );
+            let mask = (slot == ToolbarSlot::Start).then_some(start_mask.clone());
+            cx.with_content_mask(mask, |cx| element.prepaint_at(origin, cx));
+            *slot_x += size.width + gap;
+            elements.push((slot, element));
+        }
+
+        let background = match self.kind {
+            BarKind::Toolbar => Some(quad(
+                bounds,
+                Corners::default(),
+                cx.theme().colors().toolbar_background,
+                Edges {
+                    bottom: px(1.),
+                    ..Default::default()
+                },
+                cx.theme().colors().border_variant,
+            )),
+            BarKind::StatusBar => None,
+        };
+
+        ItemBarLayout {
+            background,
+            elements,
+            start_mask,
+        }
+    }
+
+    fn paint(&mut self, layout: &mut ItemBarLayout, cx: &mut WindowContext) {
+        if let Some(background) = layout.background.take() {
+            cx.paint_quad(background);
+        }
+        for (slot, element) in &mut layout.elements {
+            let mask = (*slot == ToolbarSlot::Start).then(|| layout.start_mask.clone());
+            cx.with_content_mask(mask, |cx| element.paint(cx));
+        }
+    }
+}
+
+/// Returns which entries are shown when the given items are collapsed. Separators
+/// are only shown between two shown items of their slot.
+fn shown_entries(entries: &[MeasuredEntry], collapsed: &[bool]) -> Vec<bool> {
+    let mut shown = vec![false; entries.len()];
+    // For each slot, whether an item was shown and the separator to show before
+    // the next one.
+    let mut slot_states = [(false, None); 3];
+    for (ix, entry) in entries.iter().enumerate() {
+        let (any_shown, pending_separator) = &mut slot_states[entry.slot as usize];
+        if entry.is_separator {
+            if *any_shown {
+                *pending_separator = Some(ix);
+            }
+        } else if !collapsed[ix] {
+            if let Some(separator_ix) = pending_separator.take() {
+                shown[separator_ix] = true;
+            }
+            shown[ix] = true;
+            *any_shown = true;
+        }
+    }
+    shown
+}
+
+/// Returns the width of the shown entries and the overflow menu, with a gap
+/// between entries and between slots.
+fn content_width(
+    entries: &[MeasuredEntry],
+    shown: &[bool],
+    menu_width: Option<Pixels>,
+    gap: Pixels,
+) -> Pixels {
+    let widths = entries
+        .iter()
+        .zip(shown)
+        .filter(|(_, shown)| **shown)
+        .map(|(entry, _)| entry.size.width)
+        .chain(menu_width)
+        .collect::<Vec<_>>();
+    let gaps = widths.len().saturating_sub(1) as f32;
+    widths
+        .into_iter()
+        .fold(px(0.), |width, entry_width| width + entry_width)
+        + gap * gaps
+}
+
+fn render_overflow_menu(entries: Rc<RefCell<Vec<OverflowEntry>>>) -> AnyElement {
+    PopoverMenu::new("overflow-menu")
+        .trigger(
+            IconButton::new("overflow-menu-trigger", IconName::Ellipsis)
+                .icon_size(IconSize::Small)
+                .icon_color(Color::Muted),
+        )
+        .menu(move |cx| {
+            let entries = entries.borrow().clone();
+            // Entries act on what was focused before the menu took focus, like the
+            // items they stand in for.
+            let previous_focus = cx.focused();
+            Some(ContextMenu::build(cx, move |mut menu, _| {
+                for entry in entries {
+                    let on_click = entry.on_click.clone();
+                    let previous_focus = previous_focus.clone();
+                    menu = menu.entry(entry.label, None, move |cx| {
+                        if let Some(previous_focus) = &previous_focus {
+                            cx.focus(previous_focus);
+                        }
+                        on_click(cx)
+                    });
+                }
+                menu
+            }))
+        })
+        .into_any_element()
+}
+
+macro_rules! impl_item_bar {
+    ($bar:ident, $kind:expr) => {
+        impl $bar {
+            pub fn new(id: impl Into<ElementId>) -> Self {
+                Self {
+                    bar: ItemBar::new(id.into(), $kind),
+                }
+            }
+
+            pub fn item(mut self, slot: ToolbarSlot, item: ToolbarItem) -> Self {
+                self.bar.entries.push((slot, BarEntry::Item(item)));
+                self
+            }
+
+            /// Adds a separator to a slot, shown when there are items on both sides
+            /// of it.
+            pub fn separator(mut self, slot: ToolbarSlot) -> Self {
+                self.bar.entries.push((slot, BarEntry::Separator));
+                self
+            }
+        }
+
+        impl IntoElement for $bar {
+            type Element = Self;
+
+            fn into_element(self) -> Self::Element {
+                self
+            }
+        }
+
+        impl Element for $bar {
+            type RequestLayoutState = ();
+            type PrepaintState = ItemBarLayout;
+
+            fn id(&self) -> Option<ElementId> {
+                Some(self.bar.id.clone())
+            }
+
+            fn request_layout(
+                &mut self,
+                _global_id: Option<&GlobalElementId>,
+                cx: &mut WindowContext,
+            ) -> (LayoutId, Self::RequestLayoutState) {
+                (self.bar.request_layout(cx), ())
+            }
+
+            fn prepaint(
+                &mut self,
+                _global_id: Option<&GlobalElementId>,
+                bounds: Bounds<Pixels>,
+                _request_layout: &mut Self::RequestLayoutState,
+                cx: &mut WindowContext,
+            ) -> Self::PrepaintState {
+                cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+                    self.bar.prepaint(bounds, cx)
+                })
+            }
+
+            fn paint(
+                &mut self,
+                _global_id: Option<&GlobalElementId>,
+                bounds: Bounds<Pixels>,
+                _request_layout: &mut Self::RequestLayoutState,
+                layout: &mut Self::PrepaintState,
+                cx: &mut WindowContext,
+            ) {
+                cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+                    self.bar.paint(layout, cx)
+                });
+            }
+        }
+    };
+}
+
+/// A bar of items with start, center and end slots and a bottom border, for the
+/// top of panes and panels.
+///
+/// Items that don't fit collapse into an overflow menu at the end of the bar, by
+/// [priority](ToolbarItem::priority).
+pub struct Toolbar {
+    bar: ItemBar,
+}
+
+impl_item_bar!(Toolbar, BarKind::Toolbar);
+
+/// A bar of items with start, center and end slots, for the bottom of windows.
+///
+/// Like in a [`Toolbar`], items that don't fit collapse into an overflow menu. The
+/// background is left to the container, which may round the corners of the window.
+pub struct StatusBar {
+    bar: ItemBar,
+}
+
+impl_item_bar!(StatusBar, BarKind::StatusBar);
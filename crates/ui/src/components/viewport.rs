@@ -0,0 +1,488 @@
+#![allow(missing_docs)]
+use std::{cell::RefCell, ops::RangeInclusive, rc::Rc};
+
+use gpui::{
+    point, size, AnyElement, AvailableSpace, Bounds, ContentMask, CursorStyle, GlobalElementId,
+    Hitbox, LayoutId, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, Point,
+    ScrollWheelEvent, Size, Style,
+};
+
+use crate::prelude::*;
+
+/// How much a scroll of one pixel with the secondary modifier held zooms in or out.
+const ZOOM_PER_PIXEL: f32 = 0.01;
+
+/// Maps the canvas coordinates of a [`Viewport`] to the coordinates it's drawn at:
+/// a point `p` of the canvas is drawn at `p * zoom + offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportTransform {
+    pub zoom: f32,
+    pub offset: Point<Pixels>,
+}
+
+impl Default for ViewportTransform {
+    fn default() -> Self {
+        Self {
+            zoom: 1.,
+            offset: Point::default(),
+        }
+    }
+}
+
+impl ViewportTransform {
+    /// Returns where the canvas point `position` is drawn.
+    pub fn to_view(&self, position: Point<Pixels>) -> Point<Pixels> {
+        point(
+            position.x * self.zoom + self.offset.x,
+            position.y * self.zoom + self.offset.y,
+        )
+    }
+
+    /// Returns the canvas point drawn at `position`.
+    pub fn to_canvas(&self, position: Point<Pixels>) -> Point<Pixels> {
+        point(
+            (position.x - self.offset.x) * (1. / self.zoom),
+            (position.y - self.offset.y) * (1. / self.zoom),
+        )
+    }
+
+    /// Returns the transform at `zoom`, keeping the canvas point under `anchor` in
+    /// place, like zooming towards the mouse.
+    pub fn zoomed_around(&self, anchor: Point<Pixels>, zoom: f32) -> Self {
+        let canvas_anchor = self.to_canvas(anchor);
+        Self {
+            zoom,
+            offset: point(
+                anchor.x - canvas_anchor.x * zoom,
+                anchor.y - canvas_anchor.y * zoom,
+            ),
+        }
+    }
+
+    /// Returns the transform showing all of `content` centered in a viewport of
+    /// `viewport_size`, leaving `padding` around it, with a zoom within `zoom_range`.
+    pub fn fit(
+        content: Bounds<Pixels>,
+        viewport_size: Size<Pixels>,
+        padding: Pixels,
+        zoom_range: RangeInclusive<f32>,
+    ) -> Self {
+        let available_width = (viewport_size.width - padding * 2.).0.max(1.);
+        let available_height = (viewport_size.height - padding * 2.).0.max(1.);
+        let zoom = (available_width / content.size.width.0.max(1.))
+            .min(available_height / content.size.height.0.max(1.))
+            .clamp(*zoom_range.start(), *zoom_range.end());
+        let center = content.center();
+        Self {
+            zoom,
+            offset: point(
+                viewport_size.width * 0.5 - center.x * zoom,
+                viewport_size.height * 0.5 - center.y * zoom,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ViewportStateInner {
+    transform: ViewportTransform,
+    zoom_range: RangeInclusive<f32>,
+    /// The bounds of the children in canvas coordinates, as of the last frame.
+    content_bounds: Option<Bounds<Pixels>>,
+    viewport_size: Size<Pixels>,
+    /// Where the mouse was last seen while dragging the canvas.
+    drag_position: Option<Point<Pixels>>,
+    fit_pending: bool,
+}
+
+impl Default for ViewportStateInner {
+    fn default() -> Self {
+        Self {
+            transform: ViewportTransform::default(),
+            zoom_range: 0.1..=4.,
+            content_bounds: None,
+            viewport_size: Size::default(),
+            drag_position: None,
+            fit_pending: false,
+        }
+    }
+}
+
+impl ViewportStateInner {
+    fn fit(&mut self, padding: Pixels) -> bool {
+        let Some(content_bounds) = self.content_bounds else {
+            return false;
+        };
+        self.transform = ViewportTransform::fit(
+            content_bounds,
+            self.viewport_size,
+            padding,
+            self.zoom_range.clone(),
+        );
+        true
+    }
+}
+
+/// The zoom and pan of a [`Viewport`].
+///
+/// Keep this on the view that renders the viewport, so that the zoom and pan survive
+/// across renders.
+#[derive(Debug, Clone, Default)]
+pub struct ViewportState(Rc<RefCell<ViewportStateInner>>);
+
+impl ViewportState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits how far the canvas can be zoomed out and in. Defaults to `0.1..=4.`.
+    pub fn with_zoom_range(self, zoom_range: RangeInclusive<f32>) -> Self {
+        self.0.borrow_mut().zoom_range = zoom_range;
+        self
+    }
+
+    pub fn transform(&self) -> ViewportTransform {
+        self.0.borrow().transform
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.0.borrow().transform.zoom
+    }
+
+    /// Zooms around the center of the viewport.
+    pub fn set_zoom(&self, zoom: f32) {
+        let mut state = self.0.borrow_mut();
+        let center = point(
+            state.viewport_size.width * 0.5,
+            state.viewport_size.height * 0.5,
+        );
+        let zoom = zoom.clamp(*state.zoom_range.start(), *state.zoom_range.end());
+        state.transform = state.transform.zoomed_around(center, zoom);
+    }
+
+    /// Moves the canvas by `delta`, in the coordinates of the viewport.
+    pub fn pan_by(&self, delta: Point<Pixels>) {
+        let mut state = self.0.borrow_mut();
+        state.transform.offset = state.transform.offset + delta;
+    }
+
+    /// Zooms and pans so that all of the children are shown. If the viewport wasn't
+    /// drawn yet, this happens when it first is.
+    pub fn fit_to_content(&self) {
+        self.0.borrow_mut().fit_pending = true;
+    }
+
+    /// Goes back to a zoom of 1, with the canvas origin at the top left corner.
+    pub fn reset(&self) {
+        self.0.borrow_mut().transform = ViewportTransform::default();
+    }
+}
+
+/// A canvas that can be zoomed and panned, for graph views like a commit graph or a
+/// call graph. Children are positioned in canvas coordinates with
+/// [`Viewport::child_at`].
+///
+/// Scrolling pans the canvas, and zooms it around the mouse with the secondary
+/// modifier held, which is also how touchpads report pinches. Dragging a part of the
+/// canvas that doesn't handle the mouse itself pans it too.
+///
+/// Zooming scales the positions of the children, and the sizes that are given in
+/// rems, by drawing them with a scaled rem size. Sizes given in pixels aren't scaled.
+pub struct Viewport {
+    id: ElementId,
+    state: ViewportState,
+    fit_padding: Pixels,
+    children: Vec<(Point<Pixels>, AnyElement)>,
+    on_paint: Option<Box<dyn Fn(&ViewportTransform, &mut WindowContext)>>,
+}
+
+impl Viewport {
+    pub fn new(id: impl Into<ElementId>, state: &ViewportState) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            fit_padding: px(24.),
+            children: Vec::new(),
+            on_paint: None,
+        }
+    }
+
+    /// Places `child` with its top left corner at `position` on the canvas.
+    pub fn child_at(mut self, position: Point<Pixels>, child: impl IntoElement) -> Self {
+        self.children.push((position, child.into_any_element()));
+        self
+    }
+
+    pub fn children_at<E: IntoElement>(
+        mut self,
+        children: impl IntoIterator<Item = (Point<Pixels>, E)>,
+    ) -> Self {
+        self.children.extend(
+            children
+                .into_iter()
+                .map(|(position, child)| (position, child.into_any_element())),
+        );
+        self
+    }
+
+    /// Sets the space left around the children by [`ViewportState::fit_to_content`].
+    /// Defaults to 24px.
+    pub fn fit_padding(mut self, padding: Pixels) -> Self {
+        self.fit_padding = padding;
+        self
+    }
+
+    /// Called before the children are painted, with the transform from canvas
+    /// coordinates to window coordinates, e.g. to draw the edges of a graph.
+    pub fn on_paint(
+        mut self,
+        handler: impl Fn(&ViewportTransform, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_paint = Some(Box::new(handler));
+        self
+    }
+
+    /// The transform from canvas coordinates to window coordinates, for a viewport
+    /// drawn at `bounds`.
+    fn window_transform(&self, bounds: Bounds<Pixels>) -> ViewportTransform {
+        let transform = self.state.transform();
+        ViewportTransform {
+            zoom: transform.zoom,
+            offset: transform.offset + bounds.origin,
+        }
+    }
+}
+
+impl IntoElement for Viewport {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Viewport {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let style = Style {
+            flex_grow: 1.,
+            flex_shrink: 1.,
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Style::default()
+        };
+        (cx.request_layout(style, None), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        {
+            let mut state = self.state.0.borrow_mut();
+            state.viewport_size = bounds.size;
+            if state.fit_pending && state.fit(self.fit_padding) {
+                state.fit_pending = false;
+            }
+        }
+
+        // Inserted before the children's, so that they're on top of it.
+        let hitbox = cx.insert_hitbox(bounds, false);
+        let transform = self.window_transform(bounds);
+        let rem_size = cx.rem_size() * transform.zoom;
+        let mut content_bounds: Option<Bounds<Pixels>> = None;
+        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            cx.with_rem_size(Some(rem_size), |cx| {
+                for (position, child) in &mut self.children {
+                    let child_size = child.layout_as_root(AvailableSpace::min_size(), cx);
+                    child.prepaint_at(transform.to_view(*position), cx);
+
+                    let child_bounds = Bounds {
+                        origin: *position,
+                        size: size(
+                            child_size.width * (1. / transform.zoom),
+                            child_size.height * (1. / transform.zoom),
+                        ),
+                    };
+                    content_bounds = Some(match content_bounds {
+                        Some(content_bounds) => content_bounds.union(&child_bounds),
+                        None => child_bounds,
+                    });
+                }
+            })
+        });
+
+        let mut state = self.state.0.borrow_mut();
+        state.content_bounds = content_bounds;
+        // The children are only measured once laid out, so a fit requested before
+        // the first frame is applied on the next one.
+        if state.fit_pending && state.fit(self.fit_padding) {
+            state.fit_pending = false;
+            cx.refresh();
+        }
+
+        hitbox
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let is_dragging = self.state.0.borrow().drag_position.is_some();
+        cx.set_cursor_style(
+            if is_dragging {
+                CursorStyle::ClosedHand
+            } else {
+                CursorStyle::Arrow
+            },
+            hitbox,
+        );
+
+        // Registered before the children paint, so that the children handle the
+        // mouse first and can stop it from panning the canvas.
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if phase.bubble()
+                    && hitbox.is_hovered(cx)
+                    && matches!(event.button, MouseButton::Left | MouseButton::Middle)
+                {
+                    state.0.borrow_mut().drag_position = Some(event.position);
+                    cx.refresh();
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if !phase.bubble() {
+                    return;
+                }
+                let mut state = state.0.borrow_mut();
+                let Some(drag_position) = state.drag_position else {
+                    return;
+                };
+                if event.pressed_button.is_none() {
+                    state.drag_position = None;
+                } else {
+                    state.transform.offset =
+                        state.transform.offset + (event.position - drag_position);
+                    state.drag_position = Some(event.position);
+                }
+                cx.refresh();
+            }
+        });
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            move |_: &MouseUpEvent, phase, cx| {
+                if phase.bubble() && state.0.borrow_mut().drag_position.take().is_some() {
+                    cx.refresh();
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            let hitbox = hitbox.clone();
+            move |event: &ScrollWheelEvent, phase, cx| {
+                if !phase.bubble() || !hitbox.is_hovered(cx) {
+                    return;
+                }
+                let delta = event.delta.pixel_delta(cx.line_height());
+                let mut state = state.0.borrow_mut();
+                if event.modifiers.secondary() {
+                    let zoom = (state.transform.zoom * (1. + delta.y.0 * ZOOM_PER_PIXEL))
+                        .clamp(*state.zoom_range.start(), *state.zoom_range.end());
+                    state.transform = state
+                        .transform
+                        .zoomed_around(event.position - bounds.origin, zoom);
+                } else {
+                    state.transform.offset = state.transform.offset + delta;
+                }
+                cx.refresh();
+                cx.stop_propagation();
+            }
+        });
+
+        let transform = self.window_transform(bounds);
+        let rem_size = cx.rem_size() * transform.zoom;
+        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+            if let Some(on_paint) = self.on_paint.as_ref() {
+                on_paint(&transform, cx);
+            }
+            cx.with_rem_size(Some(rem_size), |cx| {
+                for (_, child) in &mut self.children {
+                    child.paint(cx);
+                }
+            })
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point_eq(a: Point<Pixels>, b: Point<Pixels>) {
+        assert!(
+            (a.x.0 - b.x.0).abs() < 0.01 && (a.y.0 - b.y.0).abs() < 0.01,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_zoom_around_anchor() {
+        let transform = ViewportTransform {
+            zoom: 1.,
+            offset: point(px(10.), px(20.)),
+        };
+        let anchor = point(px(110.), px(70.));
+        let zoomed = transform.zoomed_around(anchor, 2.);
+        assert_eq!(zoomed.zoom, 2.);
+
+        // The canvas point under the anchor stays there.
+        assert_point_eq(zoomed.to_view(transform.to_canvas(anchor)), anchor);
+        assert_point_eq(zoomed.to_canvas(zoomed.to_view(anchor)), anchor);
+    }
+
+    #[test]
+    fn test_fit_to_content() {
+        let content = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(400.), px(100.)),
+        };
+        let fit = ViewportTransform::fit(content, size(px(220.), px(220.)), px(10.), 0.1..=4.);
+        // The width limits the zoom, and the content is centered.
+        assert_eq!(fit.zoom, 0.5);
+        assert_point_eq(fit.to_view(content.center()), point(px(110.), px(110.)));
+
+        // The zoom stays within its range.
+        let fit = ViewportTransform::fit(content, size(px(220.), px(220.)), px(10.), 0.8..=4.);
+        assert_eq!(fit.zoom, 0.8);
+        let tiny = Bounds {
+            origin: point(px(0.), px(0.)),
+            size: size(px(1.), px(1.)),
+        };
+        let fit = ViewportTransform::fit(tiny, size(px(220.), px(220.)), px(10.), 0.1..=4.);
+        assert_eq!(fit.zoom, 4.);
+    }
+}
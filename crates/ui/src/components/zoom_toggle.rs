@@ -0,0 +1,60 @@
+#![allow(missing_docs)]
+use std::sync::Arc;
+
+use gpui::{Action, ClickEvent};
+
+use crate::{prelude::*, Tooltip};
+
+/// A button that maximizes a panel or pane over the workspace, and restores it.
+#[derive(IntoElement)]
+pub struct ZoomToggle {
+    id: ElementId,
+    zoomed: bool,
+    action: Option<Box<dyn Action>>,
+    on_toggle: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+}
+
+impl ZoomToggle {
+    pub fn new(id: impl Into<ElementId>, zoomed: bool) -> Self {
+        Self {
+            id: id.into(),
+            zoomed,
+            action: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets the action whose key binding is shown in the tooltip.
+    pub fn action(mut self, action: Box<dyn Action>) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn on_toggle(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Arc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for ZoomToggle {
+    fn render(self, _cx: &mut WindowContext) -> impl IntoElement {
+        let zoomed = self.zoomed;
+        let title = if zoomed { "Zoom Out" } else { "Zoom In" };
+        let action = self.action;
+
+        IconButton::new(self.id, IconName::Maximize)
+            .icon_size(IconSize::Small)
+            .selected(zoomed)
+            .selected_icon(IconName::Minimize)
+            .when_some(self.on_toggle, |this, on_toggle| {
+                this.on_click(move |event, cx| on_toggle(event, cx))
+            })
+            .tooltip(move |cx| match action.as_ref() {
+                Some(action) => Tooltip::for_action(title, action.as_ref(), cx),
+                None => Tooltip::text(title, cx),
+            })
+    }
+}
@@ -0,0 +1,469 @@
+//! Translations of UI strings.
+//!
+//! Strings are looked up by key in the catalog of the current locale with [`t!`],
+//! falling back to the English catalog, which is built in, and then to the key
+//! itself. The current locale is the one of the system, unless changed with
+//! [`set_locale`].
+//!
+//! Catalogs are JSON files in `assets/locales`, named after their locale, e.g.
+//! `en.json` or `pt-BR.json`. Each key maps to a string, or to the plural forms of
+//! a string, selected by the `count` argument:
+//!
+//! ```json
+//! {
+//!   "common.cancel": "Cancel",
+//!   "search.matches": {
+//!     "one": "{count} match in {file}",
+//!     "other": "{count} matches in {file}"
+//!   }
+//! }
+//! ```
+//!
+//! Arguments are interpolated into `{name}` placeholders:
+//!
+//! ```ignore
+//! Label::new(t!(cx, "search.matches", count = matches.len(), file = file_name))
+//! ```
+
+use std::fmt::{self, Write};
+use std::sync::LazyLock;
+
+use collections::HashMap;
+use gpui::{AppContext, Global, SharedString};
+use serde::Deserialize;
+
+/// The locale whose catalog is used for keys missing from other catalogs.
+pub const FALLBACK_LOCALE: &str = "en";
+
+static FALLBACK_CATALOG: LazyLock<Catalog> = LazyLock::new(|| {
+    Catalog::from_json(include_str!("../../../assets/locales/en.json"))
+        .expect("the English catalog is valid")
+});
+
+/// The plural forms of a string, by the plural category of its count.
+///
+/// Only `other` is required. Languages without a distinct form for a category fall
+/// back to `other`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct PluralForms {
+    /// The form for a count of zero, in languages that have one.
+    pub zero: Option<String>,
+    /// The form for a count of one, or for counts like 21 in e.g. Russian.
+    pub one: Option<String>,
+    /// The form for a count of two, in languages that have one.
+    pub two: Option<String>,
+    /// The form for small counts, like 2-4 in e.g. Polish or Russian.
+    pub few: Option<String>,
+    /// The form for large counts, like 5-20 in e.g. Polish or Russian.
+    pub many: Option<String>,
+    /// The form for all other counts.
+    pub other: String,
+}
+
+/// A translated string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Message {
+    /// A string with a single form.
+    Text(String),
+    /// A string with a form per plural category.
+    Plural(PluralForms),
+}
+
+/// The translated strings of a locale, by key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Catalog(HashMap<String, Message>);
+
+impl Catalog {
+    /// Parses a catalog from its JSON representation.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the message for the given key.
+    pub fn get(&self, key: &str) -> Option<&Message> {
+        self.0.get(key)
+    }
+}
+
+/// The plural category of a count, as defined by the Unicode CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    /// E.g. 0 in Arabic.
+    Zero,
+    /// E.g. 1 in English.
+    One,
+    /// E.g. 2 in Arabic.
+    Two,
+    /// E.g. 2-4 in Polish.
+    Few,
+    /// E.g. 5-20 in Polish.
+    Many,
+    /// Every other count.
+    Other,
+}
+
+/// Returns the plural category of a count in the given locale, for the languages
+/// Zed is translated to.
+pub fn plural_category(locale: &str, count: u64) -> PluralCategory {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let (mod10, mod100) = (count % 10, count % 100);
+    match language {
+        "ja" | "ko" | "zh" | "vi" | "th" | "id" => PluralCategory::Other,
+        "fr" | "pt" if count <= 1 => PluralCategory::One,
+        "fr" | "pt" => PluralCategory::Other,
+        "ru" | "uk" => {
+            if mod10 == 1 && mod100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "pl" => {
+            if count == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "ar" => match count {
+            0 => PluralCategory::Zero,
+            1 => PluralCategory::One,
+            2 => PluralCategory::Two,
+            _ if (3..=10).contains(&mod100) => PluralCategory::Few,
+            _ if (11..=99).contains(&mod100) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        },
+        _ if count == 1 => PluralCategory::One,
+        _ => PluralCategory::Other,
+    }
+}
+
+impl PluralForms {
+    /// Returns the form for the given plural category.
+    pub fn get(&self, category: PluralCategory) -> &str {
+        let form = match category {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => return &self.other,
+        };
+        form.as_deref().unwrap_or(&self.other)
+    }
+}
+
+/// A value interpolated into a translated string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    /// A string, inserted as is.
+    Text(SharedString),
+    /// A number, which selects the plural form of the string when named `count`.
+    Number(u64),
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::Text(text) => f.write_str(text),
+            Arg::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+impl From<&str> for Arg {
+    fn from(text: &str) -> Self {
+        Arg::Text(SharedString::from(text.to_string()))
+    }
+}
+
+impl From<String> for Arg {
+    fn from(text: String) -> Self {
+        Arg::Text(text.into())
+    }
+}
+
+impl From<SharedString> for Arg {
+    fn from(text: SharedString) -> Self {
+        Arg::Text(text)
+    }
+}
+
+macro_rules! impl_number_arg {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Arg {
+                fn from(number: $ty) -> Self {
+                    Arg::Number(number as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_arg!(u8, u16, u32, u64, usize);
+
+#[derive(Default)]
+struct Localization {
+    locale: SharedString,
+    catalogs: HashMap<SharedString, Catalog>,
+}
+
+impl Global for Localization {}
+
+/// Returns the locale of the catalogs best matching the requested locale: the
+/// locale itself, or another region of its language, or the fallback locale.
+fn resolve_locale<'a>(
+    requested: &str,
+    locales: impl IntoIterator<Item = &'a SharedString>,
+) -> SharedString {
+    let requested = requested.replace('_', "-");
+    let language = requested.split('-').next().unwrap_or(&requested);
+    let mut language_match = None;
+    for locale in locales {
+        if locale.eq_ignore_ascii_case(&requested) {
+            return locale.clone();
+        }
+        if language_match.is_none()
+            && locale.split('-').next().map_or(false, |locale_language| {
+                locale_language.eq_ignore_ascii_case(language)
+            })
+        {
+            language_match = Some(locale.clone());
+        }
+    }
+    language_match.unwrap_or_else(|| FALLBACK_LOCALE.into())
+}
+
+/// Loads the catalogs in `assets/locales`, and translates to the catalog best
+/// matching the locale of the system.
+pub fn init(cx: &mut AppContext) {
+    let asset_source = cx.asset_source().clone();
+    let mut localization = Localization {
+        locale: FALLBACK_LOCALE.into(),
+        catalogs: HashMap::default(),
+    };
+
+    for path in asset_source.list("locales/").unwrap_or_default() {
+        let Some(locale) = path
+            .strip_prefix("locales/")
+            .and_then(|file_name| file_name.strip_suffix(".json"))
+        else {
+            continue;
+        };
+        let catalog = asset_source
+            .load(&path)
+            .ok()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("missing catalog {path}"))
+            .and_then(|json| Catalog::from_json(std::str::from_utf8(&json)?));
+        match catalog {
+            Ok(catalog) => {
+                localization
+                    .catalogs
+                    .insert(SharedString::from(locale.to_string()), catalog);
+            }
+            Err(error) => log::error!("failed to load the catalog of locale {locale}: {error}"),
+        }
+    }
+
+    if let Some(system_locale) = sys_locale::get_locale() {
+        localization.locale = resolve_locale(&system_locale, localization.catalogs.keys());
+    }
+    cx.set_global(localization);
+}
+
+/// Returns the locale UI strings are translated to.
+pub fn locale(cx: &AppContext) -> SharedString {
+    cx.try_global::<Localization>()
+        .map(|localization| localization.locale.clone())
+        .unwrap_or_else(|| FALLBACK_LOCALE.into())
+}
+
+/// Switches the locale UI strings are translated to, redrawing all windows.
+pub fn set_locale(locale: impl Into<SharedString>, cx: &mut AppContext) {
+    cx.default_global::<Localization>().locale = locale.into();
+    cx.refresh();
+}
+
+/// Adds or replaces the catalog of a locale, e.g. one provided by an extension.
+pub fn add_catalog(locale: impl Into<SharedString>, catalog: Catalog, cx: &mut AppContext) {
+    cx.default_global::<Localization>()
+        .catalogs
+        .insert(locale.into(), catalog);
+    cx.refresh();
+}
+
+/// Translates the string with the given key to the current locale, interpolating
+/// the arguments. Use [`t!`] rather than calling this directly.
+pub fn translate(cx: &AppContext, key: &str, args: &[(&str, Arg)]) -> SharedString {
+    let localization = cx.try_global::<Localization>();
+    let locale = localization.map_or(FALLBACK_LOCALE, |localization| &localization.locale);
+    let message = localization
+        .and_then(|localization| {
+            [locale, FALLBACK_LOCALE]
+                .into_iter()
+                .find_map(|locale| localization.catalogs.get(locale)?.get(key))
+        })
+        .or_else(|| FALLBACK_CATALOG.get(key));
+    match message {
+        Some(message) => format_message(locale, message, args).into(),
+        None => SharedString::from(key.to_string()),
+    }
+}
+
+fn format_message(locale: &str, message: &Message, args: &[(&str, Arg)]) -> String {
+    let template = match message {
+        Message::Text(text) => text.as_str(),
+        Message::Plural(forms) => {
+            let count = args.iter().find_map(|(name, arg)| match arg {
+                Arg::Number(count) if *name == "count" => Some(*count),
+                _ => None,
+            });
+            forms.get(plural_category(locale, count.unwrap_or(0)))
+        }
+    };
+
+    // Placeholders are replaced in a single pass, so that arguments containing
+    // braces, like file names, are inserted as is.
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let arg = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            let (_, arg) = args.iter().find(|(arg_name, _)| *arg_name == name)?;
+            Some((arg, end))
+        });
+        match arg {
+            Some((arg, end)) => {
+                write!(result, "{arg}").unwrap();
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Translates a UI string to the current locale, returning a [`SharedString`].
+///
+/// Takes a context, the key of the string, and named arguments to interpolate into
+/// it. A numeric `count` argument selects the plural form of the string.
+///
+/// ```ignore
+/// Button::new("back", t!(cx, "common.back"));
+/// Label::new(t!(cx, "project.files_selected", count = selected.len()));
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($cx:expr, $key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::translate(
+            $cx,
+            $key,
+            &[$((stringify!($name), $crate::i18n::Arg::from($value))),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_message() {
+        let catalog = Catalog::from_json(
+            r#"{
+                "greeting": "Hello, {name}!",
+                "files": { "one": "{count} file", "other": "{count} files" },
+                "files.pl": {
+                    "one": "{count} plik",
+                    "few": "{count} pliki",
+                    "many": "{count} plików",
+                    "other": "{count} pliku"
+                }
+            }"#,
+        )
+        .unwrap();
+        let format = |locale, key, args: &[(&str, Arg)]| {
+            format_message(locale, catalog.get(key).unwrap(), args)
+        };
+
+        assert_eq!(
+            format("en", "greeting", &[("name", Arg::from("Zed"))]),
+            "Hello, Zed!"
+        );
+        assert_eq!(
+            format("en", "files", &[("count", Arg::from(1u32))]),
+            "1 file"
+        );
+        assert_eq!(
+            format("en", "files", &[("count", Arg::from(3u32))]),
+            "3 files"
+        );
+        assert_eq!(
+            format("pl", "files.pl", &[("count", Arg::from(3u32))]),
+            "3 pliki"
+        );
+        assert_eq!(
+            format("pl", "files.pl", &[("count", Arg::from(12u32))]),
+            "12 plików"
+        );
+        assert_eq!(
+            format("pl", "files.pl", &[("count", Arg::from(22u32))]),
+            "22 pliki"
+        );
+    }
+
+    #[test]
+    fn test_format_message_inserts_arguments_as_is() {
+        let message = Message::Text("Renamed {old} to {new} {unknown}".into());
+        assert_eq!(
+            format_message(
+                "en",
+                &message,
+                &[("old", Arg::from("{new}")), ("new", Arg::from("b{.rs"))]
+            ),
+            "Renamed {new} to b{.rs {unknown}"
+        );
+    }
+
+    #[test]
+    fn test_resolve_locale() {
+        let locales = ["en", "pt-BR", "zh-Hans"].map(SharedString::from);
+        assert_eq!(&*resolve_locale("pt-BR", &locales), "pt-BR");
+        assert_eq!(&*resolve_locale("pt_PT", &locales), "pt-BR");
+        assert_eq!(&*resolve_locale("zh-Hans-CN", &locales), "zh-Hans");
+        assert_eq!(&*resolve_locale("de-DE", &locales), "en");
+    }
+
+    #[test]
+    fn test_fallback_catalog_is_valid() {
+        assert_eq!(
+            FALLBACK_CATALOG.get("common.cancel"),
+            Some(&Message::Text("Cancel".into()))
+        );
+    }
+
+    #[test]
+    fn test_plural_category() {
+        assert_eq!(plural_category("en-US", 1), PluralCategory::One);
+        assert_eq!(plural_category("en", 0), PluralCategory::Other);
+        assert_eq!(plural_category("fr", 0), PluralCategory::One);
+        assert_eq!(plural_category("ru", 21), PluralCategory::One);
+        assert_eq!(plural_category("ru", 11), PluralCategory::Many);
+        assert_eq!(plural_category("ja", 1), PluralCategory::Other);
+        assert_eq!(plural_category("ar", 2), PluralCategory::Two);
+    }
+}
@@ -11,7 +11,9 @@ pub use crate::styles::{rems_from_px, vh, vw, PlatformStyle, StyledTypography, T
 pub use crate::traits::clickable::*;
 pub use crate::traits::disableable::*;
 pub use crate::traits::fixed::*;
+pub use crate::traits::gestures::*;
 pub use crate::traits::selectable::*;
+pub use crate::traits::style_overrides::*;
 pub use crate::traits::styled_ext::*;
 pub use crate::traits::visible_on_hover::*;
 pub use crate::Spacing;
@@ -20,5 +22,5 @@ pub use crate::{Button, ButtonSize, ButtonStyle, IconButton, SelectableButton};
 pub use crate::{ButtonCommon, Color};
 pub use crate::{Headline, HeadlineSize};
 pub use crate::{Icon, IconName, IconPosition, IconSize};
-pub use crate::{Label, LabelCommon, LabelSize, LineHeightStyle};
+pub use crate::{Label, LabelCommon, LabelFont, LabelSize, LineHeightStyle};
 pub use theme::ActiveTheme;
@@ -1,6 +1,8 @@
 mod appearance;
 mod color;
+mod direction;
 mod elevation;
+mod interactive_state;
 mod platform;
 mod spacing;
 mod typography;
@@ -8,7 +10,9 @@ mod units;
 
 pub use appearance::*;
 pub use color::*;
+pub use direction::*;
 pub use elevation::*;
+pub use interactive_state::*;
 pub use platform::*;
 pub use spacing::*;
 pub use typography::*;
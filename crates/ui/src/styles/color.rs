@@ -63,7 +63,7 @@ pub enum Color {
 impl Color {
     /// Returns the Color's HSLA value.
     pub fn color(&self, cx: &WindowContext) -> Hsla {
-        match self {
+        let color = match self {
             Color::Default => cx.theme().colors().text,
             Color::Muted => cx.theme().colors().text_muted,
             Color::Created => cx.theme().status().created,
@@ -83,6 +83,8 @@ impl Color {
             Color::Success => cx.theme().status().success,
             Color::Warning => cx.theme().status().warning,
             Color::Custom(color) => *color,
-        }
+        };
+        cx.record_theme_token(|| format!("Color::{self:?}"), color);
+        color
     }
 }
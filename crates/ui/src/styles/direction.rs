@@ -0,0 +1,80 @@
+use gpui::{AppContext, Global};
+
+/// The direction the UI is laid out in, following the reading direction of its
+/// language.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// Left to right, like English.
+    #[default]
+    Ltr,
+    /// Right to left, like Arabic and Hebrew.
+    Rtl,
+}
+
+#[derive(Default)]
+struct GlobalLayoutDirection(LayoutDirection);
+
+impl Global for GlobalLayoutDirection {}
+
+impl LayoutDirection {
+    /// Returns the direction the UI is laid out in.
+    pub fn get(cx: &AppContext) -> Self {
+        cx.try_global::<GlobalLayoutDirection>()
+            .map(|direction| direction.0)
+            .unwrap_or_default()
+    }
+
+    /// Sets the direction the UI is laid out in, mirroring the logical start and
+    /// end of [`StyledExt`](crate::StyledExt) layouts.
+    pub fn set_global(direction: Self, cx: &mut AppContext) {
+        cx.set_global(GlobalLayoutDirection(direction));
+        cx.refresh();
+    }
+
+    /// Returns whether this is [`LayoutDirection::Rtl`].
+    pub fn is_rtl(self) -> bool {
+        self == LayoutDirection::Rtl
+    }
+
+    /// Returns the direction of the first character of the text with a strong
+    /// direction, or `None` if it has none, like numbers and punctuation.
+    pub fn of_text(text: &str) -> Option<Self> {
+        text.chars().find_map(|c| {
+            if is_rtl_char(c) {
+                Some(LayoutDirection::Rtl)
+            } else if c.is_alphabetic() {
+                Some(LayoutDirection::Ltr)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Whether the character is of a right-to-left script: Hebrew, Arabic, Syriac,
+/// Thaana, N'Ko and their presentation forms.
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+    ) && c.is_alphabetic()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_of_text() {
+        assert_eq!(
+            LayoutDirection::of_text("hello"),
+            Some(LayoutDirection::Ltr)
+        );
+        assert_eq!(LayoutDirection::of_text("שלום"), Some(LayoutDirection::Rtl));
+        assert_eq!(
+            LayoutDirection::of_text("12. مرحبا world"),
+            Some(LayoutDirection::Rtl)
+        );
+        assert_eq!(LayoutDirection::of_text("42 ()"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use gpui::{hsla, point, px, BoxShadow};
 use smallvec::{smallvec, SmallVec};
+use theme::Elevation;
 
 /// Today, elevation is primarily used to add shadows to elements, and set the correct background for elements like buttons.
 ///
@@ -63,3 +64,15 @@ impl ElevationIndex {
         }
     }
 }
+
+impl From<ElevationIndex> for Elevation {
+    fn from(index: ElevationIndex) -> Self {
+        match index {
+            ElevationIndex::Background | ElevationIndex::Surface => Elevation::Raised,
+            ElevationIndex::ElevatedSurface => Elevation::Overlay,
+            ElevationIndex::Wash
+            | ElevationIndex::ModalSurface
+            | ElevationIndex::DraggedElement => Elevation::Modal,
+        }
+    }
+}
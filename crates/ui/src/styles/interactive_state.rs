@@ -0,0 +1,288 @@
+use bitflags::bitflags;
+use gpui::{Hsla, StatefulInteractiveElement, Styled, WindowContext};
+use theme::ActiveTheme;
+
+use crate::Color;
+
+/// The backgrounds of an interactive element in each of its states, along with
+/// the color of the ring drawn around it while it has keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractiveColors {
+    /// The background while the element isn't interacted with.
+    pub background: Hsla,
+    /// The background while the pointer is over the element.
+    pub hover: Hsla,
+    /// The background while the element is pressed.
+    pub active: Hsla,
+    /// The background while the element is selected, like the current row of a list.
+    pub selected: Hsla,
+    /// The background while the element can't be interacted with.
+    pub disabled: Hsla,
+    /// The border color while the element has keyboard focus.
+    pub focus_ring: Hsla,
+}
+
+impl InteractiveColors {
+    /// The colors of elements that blend into their container until interacted
+    /// with, like subtle buttons and menu items.
+    pub fn ghost(cx: &WindowContext) -> Self {
+        let colors = cx.theme().colors();
+        Self {
+            background: colors.ghost_element_background,
+            hover: colors.ghost_element_hover,
+            active: colors.ghost_element_active,
+            selected: colors.ghost_element_selected,
+            disabled: colors.ghost_element_disabled,
+            focus_ring: colors.border_focused,
+        }
+    }
+
+    /// The colors of elements with a background of their own, like filled buttons.
+    pub fn filled(cx: &WindowContext) -> Self {
+        let colors = cx.theme().colors();
+        Self {
+            background: colors.element_background,
+            hover: colors.element_hover,
+            active: colors.element_active,
+            selected: colors.element_selected,
+            disabled: colors.element_disabled,
+            focus_ring: colors.border_focused,
+        }
+    }
+
+    /// The colors of the rows of lists and tables. A selected row of a list without
+    /// focus is shown with a fainter background.
+    pub fn row(list_focused: bool, cx: &WindowContext) -> Self {
+        let colors = cx.theme().colors();
+        Self {
+            hover: colors.row_hover,
+            selected: if list_focused {
+                colors.row_selected
+            } else {
+                colors.row_selected_unfocused
+            },
+            ..Self::ghost(cx)
+        }
+    }
+
+    /// Derives the colors of the other states from the resting `background`, by
+    /// laying the theme's text color over it, so that elements with a background
+    /// of their own react like the rest of the UI.
+    pub fn from_background(background: Hsla, cx: &WindowContext) -> Self {
+        let colors = cx.theme().colors();
+        let tint = |amount: f32| background.blend(colors.text.opacity(amount));
+        Self {
+            background,
+            hover: tint(0.06),
+            active: tint(0.12),
+            selected: tint(0.1),
+            disabled: background.opacity(0.5),
+            focus_ring: colors.border_focused,
+        }
+    }
+}
+
+bitflags! {
+    /// The states an element shows with [`InteractiveState::apply`]. An element
+    /// looks the same in the states it doesn't opt into as at rest, e.g. tabs
+    /// don't react to the pointer.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    pub struct InteractiveStates: u8 {
+        /// Shows the hover background while the pointer is over the element.
+        const HOVER = 0b0001;
+        /// Shows the active background while the element is pressed.
+        const ACTIVE = 0b0010;
+        /// Shows the selected background while the element is selected.
+        const SELECTED = 0b0100;
+        /// Shows the disabled background while the element is disabled.
+        const DISABLED = 0b1000;
+    }
+}
+
+/// The state of an interactive element, from which its colors are picked out of
+/// [`InteractiveColors`].
+///
+/// Components that show a state should style it with [`InteractiveState::apply`],
+/// so that rows, buttons and tabs look the same in each state.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InteractiveState {
+    /// Whether the element can't be interacted with. A disabled element doesn't
+    /// react to the pointer.
+    pub disabled: bool,
+    /// Whether the element is selected.
+    pub selected: bool,
+    /// Whether the element has keyboard focus.
+    pub focused: bool,
+}
+
+impl InteractiveState {
+    /// Returns the background of the element while the pointer isn't over it.
+    pub fn background(self, colors: &InteractiveColors, states: InteractiveStates) -> Hsla {
+        if self.disabled && states.contains(InteractiveStates::DISABLED) {
+            colors.disabled
+        } else if self.selected && states.contains(InteractiveStates::SELECTED) {
+            colors.selected
+        } else {
+            colors.background
+        }
+    }
+
+    /// Returns the background of the element while the pointer is over it, if it
+    /// reacts to hover.
+    pub fn hover(self, colors: &InteractiveColors, states: InteractiveStates) -> Option<Hsla> {
+        (!self.disabled && states.contains(InteractiveStates::HOVER)).then_some(colors.hover)
+    }
+
+    /// Returns the background of the element while it's pressed, if it reacts to
+    /// presses.
+    pub fn active(self, colors: &InteractiveColors, states: InteractiveStates) -> Option<Hsla> {
+        (!self.disabled && states.contains(InteractiveStates::ACTIVE)).then_some(colors.active)
+    }
+
+    /// Returns the color of the labels of the element, [`Color::Disabled`] while
+    /// it's disabled.
+    pub fn label_color(self, color: Color) -> Color {
+        if self.disabled {
+            Color::Disabled
+        } else {
+            color
+        }
+    }
+
+    /// Styles `element` for this state in the given `states`: its background,
+    /// its backgrounds on hover and press, and the focus ring while it has
+    /// keyboard focus.
+    ///
+    /// The ring colors the border, so the element should have one, even if it's
+    /// transparent, for the content not to move when it's focused.
+    pub fn apply<E>(self, element: E, colors: &InteractiveColors, states: InteractiveStates) -> E
+    where
+        E: StatefulInteractiveElement + Styled,
+    {
+        let element = element.bg(self.background(colors, states));
+        let element = match self.hover(colors, states) {
+            Some(hover) => element.hover(move |style| style.bg(hover)),
+            None => element,
+        };
+        let element = match self.active(colors, states) {
+            Some(active) => element.active(move |style| style.bg(active)),
+            None => element,
+        };
+        if self.focused {
+            element.border_color(colors.focus_ring)
+        } else {
+            element
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::hsla;
+
+    use super::*;
+
+    fn colors() -> InteractiveColors {
+        InteractiveColors {
+            background: hsla(0., 0., 0.1, 1.),
+            hover: hsla(0., 0., 0.2, 1.),
+            active: hsla(0., 0., 0.3, 1.),
+            selected: hsla(0., 0., 0.4, 1.),
+            disabled: hsla(0., 0., 0.5, 1.),
+            focus_ring: hsla(0., 0., 0.6, 1.),
+        }
+    }
+
+    #[test]
+    fn test_background_precedence() {
+        let colors = colors();
+        let states = InteractiveStates::all();
+        let state = InteractiveState::default();
+        assert_eq!(state.background(&colors, states), colors.background);
+
+        let selected = InteractiveState {
+            selected: true,
+            ..state
+        };
+        assert_eq!(selected.background(&colors, states), colors.selected);
+        assert_eq!(selected.label_color(Color::Muted), Color::Muted);
+
+        let disabled = InteractiveState {
+            disabled: true,
+            ..selected
+        };
+        assert_eq!(disabled.background(&colors, states), colors.disabled);
+        assert_eq!(disabled.label_color(Color::Muted), Color::Disabled);
+    }
+
+    #[test]
+    fn test_hover_state() {
+        let colors = colors();
+        let state = InteractiveState::default();
+        assert_eq!(
+            state.hover(&colors, InteractiveStates::HOVER),
+            Some(colors.hover)
+        );
+        assert_eq!(state.hover(&colors, InteractiveStates::ACTIVE), None);
+
+        let disabled = InteractiveState {
+            disabled: true,
+            ..state
+        };
+        assert_eq!(disabled.hover(&colors, InteractiveStates::HOVER), None);
+    }
+
+    #[test]
+    fn test_active_state() {
+        let colors = colors();
+        let state = InteractiveState::default();
+        assert_eq!(
+            state.active(&colors, InteractiveStates::ACTIVE),
+            Some(colors.active)
+        );
+        assert_eq!(state.active(&colors, InteractiveStates::HOVER), None);
+
+        let disabled = InteractiveState {
+            disabled: true,
+            ..state
+        };
+        assert_eq!(disabled.active(&colors, InteractiveStates::ACTIVE), None);
+    }
+
+    #[test]
+    fn test_selected_state() {
+        let colors = colors();
+        let selected = InteractiveState {
+            selected: true,
+            ..InteractiveState::default()
+        };
+        assert_eq!(
+            selected.background(&colors, InteractiveStates::SELECTED),
+            colors.selected
+        );
+        assert_eq!(
+            selected.background(&colors, InteractiveStates::empty()),
+            colors.background
+        );
+    }
+
+    #[test]
+    fn test_disabled_state() {
+        let colors = colors();
+        let disabled = InteractiveState {
+            disabled: true,
+            ..InteractiveState::default()
+        };
+        assert_eq!(
+            disabled.background(&colors, InteractiveStates::DISABLED),
+            colors.disabled
+        );
+        // Elements that don't show the disabled state keep their background, and
+        // only dim their labels.
+        assert_eq!(
+            disabled.background(&colors, InteractiveStates::empty()),
+            colors.background
+        );
+        assert_eq!(disabled.label_color(Color::Default), Color::Disabled);
+    }
+}
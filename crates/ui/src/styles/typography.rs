@@ -132,12 +132,13 @@ impl TextSize {
     /// Returns the text size in rems.
     pub fn rems(self, cx: &WindowContext) -> Rems {
         let theme_settings = ThemeSettings::get_global(cx);
+        let text_ratio = theme_settings.ui_density.text_ratio();
 
         match self {
-            Self::Large => rems_from_px(16.),
-            Self::Default => rems_from_px(14.),
-            Self::Small => rems_from_px(12.),
-            Self::XSmall => rems_from_px(10.),
+            Self::Large => rems_from_px(16. * text_ratio),
+            Self::Default => rems_from_px(14. * text_ratio),
+            Self::Small => rems_from_px(12. * text_ratio),
+            Self::XSmall => rems_from_px(10. * text_ratio),
             Self::Ui => rems_from_px(theme_settings.ui_font_size.into()),
             Self::Editor => rems_from_px(theme_settings.buffer_font_size.into()),
         }
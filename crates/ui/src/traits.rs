@@ -1,6 +1,8 @@
 pub mod clickable;
 pub mod disableable;
 pub mod fixed;
+pub mod gestures;
 pub mod selectable;
+pub mod style_overrides;
 pub mod styled_ext;
 pub mod visible_on_hover;
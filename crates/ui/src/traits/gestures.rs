@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use gpui::WindowContext;
+
+use crate::utils::{GestureEvent, GestureHandlers, GestureSettings};
+
+/// A trait for components that recognize gestures beyond a single click: double
+/// and triple clicks, long presses, and drags.
+///
+/// A press that became a long press or a drag doesn't also click the component,
+/// the two being told apart with the [`GestureSettings`] thresholds.
+pub trait Gestures: Sized {
+    /// Returns the gesture handlers of the component, to add to them.
+    fn gesture_handlers(&mut self) -> &mut GestureHandlers;
+
+    /// Sets the handler called when the component is clicked twice in a row.
+    fn on_double_click(
+        mut self,
+        handler: impl Fn(&GestureEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.gesture_handlers().on_double_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Sets the handler called when the component is clicked three times in a row.
+    fn on_triple_click(
+        mut self,
+        handler: impl Fn(&GestureEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.gesture_handlers().on_triple_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Sets the handler called when the mouse is held down on the component
+    /// without moving.
+    fn on_long_press(
+        mut self,
+        handler: impl Fn(&GestureEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.gesture_handlers().on_long_press = Some(Rc::new(handler));
+        self
+    }
+
+    /// Sets the handler called when the mouse moves past the drag threshold while
+    /// held down on the component.
+    fn on_drag_start(
+        mut self,
+        handler: impl Fn(&GestureEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.gesture_handlers().on_drag_start = Some(Rc::new(handler));
+        self
+    }
+
+    /// Uses other thresholds than the global [`GestureSettings`] for this component.
+    fn gesture_settings(mut self, settings: GestureSettings) -> Self {
+        self.gesture_handlers().settings = Some(settings);
+        self
+    }
+}
@@ -0,0 +1,90 @@
+use gpui::{StyleRefinement, Styled};
+
+type Refinement = Box<dyn FnOnce(StyleRefinement) -> StyleRefinement>;
+
+/// A trait for components whose themed styles can be refined where they are used,
+/// e.g. to give a single button a custom background without forking it.
+///
+/// The overrides are applied after the component resolved its styles from the
+/// theme, so they take precedence over them, including in its hover and active
+/// states.
+pub trait StyleOverride: Sized {
+    /// Returns the overrides of the component, to add to them.
+    fn style_overrides(&mut self) -> &mut StyleOverrides;
+
+    /// Refines the style of the component in its default state.
+    fn override_style(
+        mut self,
+        f: impl FnOnce(StyleRefinement) -> StyleRefinement + 'static,
+    ) -> Self {
+        let overrides = self.style_overrides();
+        overrides.base = chain(overrides.base.take(), f);
+        self
+    }
+
+    /// Refines the style of the component while it's hovered.
+    fn override_hover_style(
+        mut self,
+        f: impl FnOnce(StyleRefinement) -> StyleRefinement + 'static,
+    ) -> Self {
+        let overrides = self.style_overrides();
+        overrides.hover = chain(overrides.hover.take(), f);
+        self
+    }
+
+    /// Refines the style of the component while it's pressed.
+    fn override_active_style(
+        mut self,
+        f: impl FnOnce(StyleRefinement) -> StyleRefinement + 'static,
+    ) -> Self {
+        let overrides = self.style_overrides();
+        overrides.active = chain(overrides.active.take(), f);
+        self
+    }
+}
+
+fn chain(
+    previous: Option<Refinement>,
+    f: impl FnOnce(StyleRefinement) -> StyleRefinement + 'static,
+) -> Option<Refinement> {
+    Some(match previous {
+        Some(previous) => Box::new(move |style| f(previous(style))),
+        None => Box::new(f),
+    })
+}
+
+/// The style overrides of a component, see [`StyleOverride`].
+#[derive(Default)]
+pub struct StyleOverrides {
+    base: Option<Refinement>,
+    hover: Option<Refinement>,
+    active: Option<Refinement>,
+}
+
+impl StyleOverrides {
+    /// Refines the themed style of an element in its default state with the
+    /// overrides.
+    pub(crate) fn refine<E: Styled>(&mut self, mut element: E) -> E {
+        if let Some(refine) = self.base.take() {
+            let style = std::mem::take(element.style());
+            *element.style() = refine(style);
+        }
+        element
+    }
+
+    /// Refines the themed hover style of an element with the overrides.
+    pub(crate) fn refine_hover(&mut self, style: StyleRefinement) -> StyleRefinement {
+        match self.hover.take() {
+            Some(refine) => refine(style),
+            None => style,
+        }
+    }
+
+    /// Refines the themed active style of an element with the overrides.
+    pub(crate) fn refine_active(&mut self, style: StyleRefinement) -> StyleRefinement {
+        match self.active.take() {
+            Some(refine) => refine(style),
+            None => style,
+        }
+    }
+}
@@ -1,20 +1,20 @@
-use gpui::{hsla, Styled, WindowContext};
+use gpui::{hsla, DefiniteLength, Length, Styled, WindowContext};
 
 use crate::prelude::*;
-use crate::ElevationIndex;
+use crate::{ElevationIndex, LayoutDirection};
 
 fn elevated<E: Styled>(this: E, cx: &WindowContext, index: ElevationIndex) -> E {
-    this.bg(cx.theme().colors().elevated_surface_background)
+    let style = cx.theme().elevation(index.into());
+    this.bg(style.background)
         .rounded_lg()
         .border_1()
-        .border_color(cx.theme().colors().border_variant)
-        .shadow(index.shadow())
+        .border_color(style.border)
+        .shadow(style.shadow)
 }
 
 fn elevated_borderless<E: Styled>(this: E, cx: &WindowContext, index: ElevationIndex) -> E {
-    this.bg(cx.theme().colors().elevated_surface_background)
-        .rounded_lg()
-        .shadow(index.shadow())
+    let style = cx.theme().elevation(index.into());
+    this.bg(style.background).rounded_lg().shadow(style.shadow)
 }
 
 /// Extends [`gpui::Styled`] with Zed-specific styling methods.
@@ -33,6 +33,58 @@ pub trait StyledExt: Styled + Sized {
         self.flex().flex_col()
     }
 
+    /// Horizontally stacks elements from the logical start, reversing their order
+    /// when the [`LayoutDirection`] is right to left.
+    ///
+    /// Sets `flex()`, `flex_row()` or `flex_row_reverse()`, `items_center()`
+    fn h_flex_logical(self, cx: &WindowContext) -> Self {
+        if LayoutDirection::get(cx).is_rtl() {
+            self.flex().flex_row_reverse().items_center()
+        } else {
+            self.h_flex()
+        }
+    }
+
+    /// Sets the padding on the logical start side: the left, or the right when the
+    /// [`LayoutDirection`] is right to left.
+    fn padding_start(self, length: impl Into<DefiniteLength>, cx: &WindowContext) -> Self {
+        if LayoutDirection::get(cx).is_rtl() {
+            self.pr(length)
+        } else {
+            self.pl(length)
+        }
+    }
+
+    /// Sets the padding on the logical end side: the right, or the left when the
+    /// [`LayoutDirection`] is right to left.
+    fn padding_end(self, length: impl Into<DefiniteLength>, cx: &WindowContext) -> Self {
+        if LayoutDirection::get(cx).is_rtl() {
+            self.pl(length)
+        } else {
+            self.pr(length)
+        }
+    }
+
+    /// Sets the margin on the logical start side: the left, or the right when the
+    /// [`LayoutDirection`] is right to left.
+    fn margin_start(self, length: impl Into<Length>, cx: &WindowContext) -> Self {
+        if LayoutDirection::get(cx).is_rtl() {
+            self.mr(length)
+        } else {
+            self.ml(length)
+        }
+    }
+
+    /// Sets the margin on the logical end side: the right, or the left when the
+    /// [`LayoutDirection`] is right to left.
+    fn margin_end(self, length: impl Into<Length>, cx: &WindowContext) -> Self {
+        if LayoutDirection::get(cx).is_rtl() {
+            self.ml(length)
+        } else {
+            self.mr(length)
+        }
+    }
+
     /// The [`Surface`](ElevationIndex::Surface) elevation level, located above the app background, is the standard level for all elements
     ///
     /// Sets `bg()`, `rounded_lg()`, `border()`, `border_color()`, `shadow()`
@@ -11,6 +11,7 @@
 //!
 
 mod components;
+pub mod i18n;
 pub mod prelude;
 mod styles;
 mod tests;
@@ -1,7 +1,19 @@
 //! UI-related utilities
 
+mod dismiss_stack;
 mod format_distance;
+mod formatted_text;
+mod gestures;
+mod time_ago_ticker;
 mod with_rem_size;
 
+pub use dismiss_stack::*;
 pub use format_distance::*;
+pub use formatted_text::*;
+pub use gestures::*;
+pub use theme::{
+    contrast_ratio, ensure_contrast, raise_contrast, relative_luminance, MIN_NON_TEXT_CONTRAST,
+    MIN_TEXT_CONTRAST,
+};
+pub use time_ago_ticker::*;
 pub use with_rem_size::*;
@@ -0,0 +1,120 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use gpui::{AnyWindowHandle, AppContext, FocusHandle, Global, WindowContext};
+
+struct DismissEntry {
+    id: usize,
+    window: AnyWindowHandle,
+    focus_handle: FocusHandle,
+    dismiss: Box<dyn Fn(&mut WindowContext)>,
+}
+
+#[derive(Default)]
+struct DismissStackState {
+    next_id: usize,
+    entries: Vec<DismissEntry>,
+}
+
+/// The dismissible surfaces that are shown, like modals and context menus, in the
+/// order they were shown.
+///
+/// Surfaces handling [`menu::Cancel`] themselves, like pickers, close first, since
+/// the stack is only consulted once the action bubbles up to the workspace. Of the
+/// remaining surfaces, a single Escape closes the innermost one containing focus,
+/// e.g. a popover inside of a modal before the modal itself.
+#[derive(Default)]
+struct DismissStack(Rc<RefCell<DismissStackState>>);
+
+impl Global for DismissStack {}
+
+/// Keeps a surface on the dismiss stack until it is dropped.
+///
+/// Create it when the surface is shown, and drop it when the surface closes.
+#[must_use]
+pub struct DismissRegistration {
+    id: usize,
+    stack: Weak<RefCell<DismissStackState>>,
+}
+
+impl Drop for DismissRegistration {
+    fn drop(&mut self) {
+        if let Some(stack) = self.stack.upgrade() {
+            stack
+                .borrow_mut()
+                .entries
+                .retain(|entry| entry.id != self.id);
+        }
+    }
+}
+
+fn dismiss_stack(cx: &mut AppContext) -> Rc<RefCell<DismissStackState>> {
+    cx.default_global::<DismissStack>().0.clone()
+}
+
+/// Adds a surface of the current window on top of the dismiss stack.
+///
+/// `focus_handle` is tracked by the surface, which is only dismissed while it contains
+/// the focused element. `dismiss` is called when the surface is the innermost one on
+/// Escape, and should close the surface.
+pub fn register_dismissible(
+    focus_handle: &FocusHandle,
+    cx: &mut WindowContext,
+    dismiss: impl Fn(&mut WindowContext) + 'static,
+) -> DismissRegistration {
+    let window = cx.window_handle();
+    let stack = dismiss_stack(cx);
+    let mut state = stack.borrow_mut();
+    let id = state.next_id;
+    state.next_id += 1;
+    state.entries.push(DismissEntry {
+        id,
+        window,
+        focus_handle: focus_handle.clone(),
+        dismiss: Box::new(dismiss),
+    });
+
+    DismissRegistration {
+        id,
+        stack: Rc::downgrade(&stack),
+    }
+}
+
+/// Dismisses the innermost surface of the current window containing focus, returning
+/// whether there was one.
+///
+/// The surface is taken off the stack before it is dismissed, so a surface that
+/// stays open, e.g. to confirm discarding changes, handles the next Escape itself.
+pub fn dismiss_innermost(cx: &mut WindowContext) -> bool {
+    let window = cx.window_handle();
+    let stack = dismiss_stack(cx);
+    let entry = {
+        let mut state = stack.borrow_mut();
+        state
+            .entries
+            .iter()
+            .rposition(|entry| entry.window == window && entry.focus_handle.contains_focused(cx))
+            .map(|ix| state.entries.remove(ix))
+    };
+
+    match entry {
+        Some(entry) => {
+            (entry.dismiss)(cx);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Hides the tooltip of the current window, returning whether one was shown.
+///
+/// Tooltips are above every other surface, so they are dismissed before the
+/// focused element handles Escape.
+pub fn dismiss_tooltip(cx: &mut WindowContext) -> bool {
+    if cx.is_tooltip_visible() {
+        cx.suppress_tooltips();
+        true
+    } else {
+        false
+    }
+}
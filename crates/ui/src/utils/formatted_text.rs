@@ -0,0 +1,279 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use gpui::{ClipboardItem, FontStyle, HighlightStyle, Hsla, Rgba, TextStyle};
+
+/// Returns a clipboard item with the given text, along with HTML and RTF versions of it
+/// styled with its highlights, so that pasting it into an application that accepts
+/// formatted text, like a document or an email, keeps its colors and font.
+///
+/// The highlights must be sorted and not overlap, as for [`StyledText`](gpui::StyledText).
+/// The color, font family and background color of `style` apply to the whole text.
+pub fn formatted_clipboard_item(
+    text: &str,
+    style: &TextStyle,
+    highlights: &[(Range<usize>, HighlightStyle)],
+) -> ClipboardItem {
+    ClipboardItem::new_string_with_formatting(
+        text.to_string(),
+        formatted_text_html(text, style, highlights),
+        formatted_text_rtf(text, style, highlights),
+    )
+}
+
+/// Splits the text into runs, each with the highlight covering it, if any.
+fn runs<'a>(
+    text: &'a str,
+    highlights: &'a [(Range<usize>, HighlightStyle)],
+) -> Vec<(&'a str, Option<&'a HighlightStyle>)> {
+    let mut runs = Vec::new();
+    let mut offset = 0;
+    for (range, highlight) in highlights {
+        let range = range.start.max(offset)..range.end.min(text.len());
+        if range.is_empty() {
+            continue;
+        }
+        if offset < range.start {
+            runs.push((&text[offset..range.start], None));
+        }
+        runs.push((&text[range.clone()], Some(highlight)));
+        offset = range.end;
+    }
+    if offset < text.len() {
+        runs.push((&text[offset..], None));
+    }
+    runs
+}
+
+fn color_channels(color: Hsla) -> [u8; 4] {
+    let Rgba { r, g, b, a } = color.to_rgb();
+    [r, g, b, a].map(|value| (value.clamp(0., 1.) * 255.).round() as u8)
+}
+
+fn css_color(color: Hsla) -> String {
+    let [r, g, b, a] = color_channels(color);
+    let mut css = format!("#{r:02x}{g:02x}{b:02x}");
+    if a < u8::MAX {
+        write!(css, "{a:02x}").unwrap();
+    }
+    css
+}
+
+fn escape_html(text: &str, html: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            '\0' => {}
+            c => html.push(c),
+        }
+    }
+}
+
+fn formatted_text_html(
+    text: &str,
+    style: &TextStyle,
+    highlights: &[(Range<usize>, HighlightStyle)],
+) -> String {
+    let mut html = String::from("<div style=\"white-space:pre-wrap;font-family:");
+    escape_html(&format!("'{}'", style.font_family), &mut html);
+    write!(html, ";color:{}", css_color(style.color)).unwrap();
+    if let Some(background) = style.background_color {
+        write!(html, ";background-color:{}", css_color(background)).unwrap();
+    }
+    html.push_str("\">");
+
+    for (run, highlight) in runs(text, highlights) {
+        let Some(highlight) = highlight else {
+            escape_html(run, &mut html);
+            continue;
+        };
+        let mut declarations = Vec::new();
+        if let Some(color) = highlight.color {
+            declarations.push(format!("color:{}", css_color(color)));
+        }
+        if let Some(background) = highlight.background_color {
+            declarations.push(format!("background-color:{}", css_color(background)));
+        }
+        if let Some(weight) = highlight.font_weight {
+            declarations.push(format!("font-weight:{}", weight.0.round()));
+        }
+        match highlight.font_style {
+            Some(FontStyle::Italic) => declarations.push("font-style:italic".into()),
+            Some(FontStyle::Oblique) => declarations.push("font-style:oblique".into()),
+            Some(FontStyle::Normal) | None => {}
+        }
+        let decorations = [
+            highlight.underline.map(|_| "underline"),
+            highlight.strikethrough.map(|_| "line-through"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if !decorations.is_empty() {
+            declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+        }
+
+        if declarations.is_empty() {
+            escape_html(run, &mut html);
+        } else {
+            write!(html, "<span style=\"{}\">", declarations.join(";")).unwrap();
+            escape_html(run, &mut html);
+            html.push_str("</span>");
+        }
+    }
+
+    html.push_str("</div>");
+    html
+}
+
+fn escape_rtf(text: &str, rtf: &mut String) {
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' => {
+                rtf.push('\\');
+                rtf.push(c);
+            }
+            '\n' => rtf.push_str("\\line "),
+            '\t' => rtf.push_str("\\tab "),
+            '\r' | '\0' => {}
+            c if c.is_ascii() => rtf.push(c),
+            // RTF encodes other characters as signed 16-bit UTF-16 code units, each
+            // followed by a replacement for readers that don't support Unicode.
+            c => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    write!(rtf, "\\u{}?", *unit as i16).unwrap();
+                }
+            }
+        }
+    }
+}
+
+fn formatted_text_rtf(
+    text: &str,
+    style: &TextStyle,
+    highlights: &[(Range<usize>, HighlightStyle)],
+) -> String {
+    // Colors are referred to by their index in the color table, which starts at 1
+    // since the first entry is the reader's default color.
+    let mut colors = Vec::new();
+    let mut color_index = |color: Hsla| {
+        let [r, g, b, _] = color_channels(color);
+        let color = [r, g, b];
+        match colors.iter().position(|existing| *existing == color) {
+            Some(ix) => ix + 1,
+            None => {
+                colors.push(color);
+                colors.len()
+            }
+        }
+    };
+
+    let mut body = String::new();
+    write!(body, "\\cf{}", color_index(style.color)).unwrap();
+    if let Some(background) = style.background_color {
+        let ix = color_index(background);
+        write!(body, "\\chshdng0\\chcbpat{ix}\\cb{ix}").unwrap();
+    }
+    body.push(' ');
+    for (run, highlight) in runs(text, highlights) {
+        let Some(highlight) = highlight else {
+            escape_rtf(run, &mut body);
+            continue;
+        };
+        body.push('{');
+        if let Some(color) = highlight.color {
+            write!(body, "\\cf{}", color_index(color)).unwrap();
+        }
+        if let Some(background) = highlight.background_color {
+            let ix = color_index(background);
+            write!(body, "\\chshdng0\\chcbpat{ix}\\cb{ix}").unwrap();
+        }
+        if highlight
+            .font_weight
+            .map_or(false, |weight| weight.0 >= 600.)
+        {
+            body.push_str("\\b");
+        }
+        if matches!(
+            highlight.font_style,
+            Some(FontStyle::Italic | FontStyle::Oblique)
+        ) {
+            body.push_str("\\i");
+        }
+        if highlight.underline.is_some() {
+            body.push_str("\\ul");
+        }
+        if highlight.strikethrough.is_some() {
+            body.push_str("\\strike");
+        }
+        body.push(' ');
+        escape_rtf(run, &mut body);
+        body.push('}');
+    }
+
+    let mut rtf = String::from("{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0\\fmodern ");
+    escape_rtf(&style.font_family, &mut rtf);
+    rtf.push_str(";}}{\\colortbl;");
+    for [r, g, b] in &colors {
+        write!(rtf, "\\red{r}\\green{g}\\blue{b};").unwrap();
+    }
+    rtf.push_str("}\\f0");
+    rtf.push_str(&body);
+    rtf.push('}');
+    rtf
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{black, red, white, FontWeight};
+
+    use super::*;
+
+    fn style() -> TextStyle {
+        TextStyle {
+            color: black(),
+            font_family: "Zed Mono".into(),
+            background_color: Some(white()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_formatted_text_html() {
+        let highlights = [(
+            0..2,
+            HighlightStyle {
+                color: Some(red()),
+                font_weight: Some(FontWeight::BOLD),
+                ..Default::default()
+            },
+        )];
+        assert_eq!(
+            formatted_text_html("fn a<'b>() {}", &style(), &highlights),
+            "<div style=\"white-space:pre-wrap;font-family:&#39;Zed Mono&#39;;color:#000000;\
+             background-color:#ffffff\"><span style=\"color:#ff0000;font-weight:700\">fn</span> \
+             a&lt;&#39;b&gt;() {}</div>"
+        );
+    }
+
+    #[test]
+    fn test_formatted_text_rtf() {
+        let highlights = [(
+            2..6,
+            HighlightStyle {
+                color: Some(red()),
+                ..Default::default()
+            },
+        )];
+        assert_eq!(
+            formatted_text_rtf("{a}\nél", &style(), &highlights),
+            "{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0\\fmodern Zed Mono;}}\
+             {\\colortbl;\\red0\\green0\\blue0;\\red255\\green255\\blue255;\\red255\\green0\\blue0;}\
+             \\f0\\cf1\\chshdng0\\chcbpat2\\cb2 \\{a{\\cf3 \\}\\line \\u233?}l}"
+        );
+    }
+}
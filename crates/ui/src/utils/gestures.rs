@@ -0,0 +1,506 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    AnyElement, AppContext, Bounds, Element, ElementId, Global, GlobalElementId, Hitbox,
+    IntoElement, LayoutId, Modifiers, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
+    Pixels, Point, Task, WindowContext,
+};
+
+/// The thresholds telling gestures apart, shared by all of the [`GestureDetector`]s
+/// unless one is given its own.
+///
+/// Set them with `cx.set_global`, e.g. to honor the double-click speed of the
+/// platform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureSettings {
+    /// How soon after a click the next one must start to count as a double or
+    /// triple click.
+    pub multi_click_interval: Duration,
+    /// How long the mouse must be held down without moving for a long press.
+    pub long_press_duration: Duration,
+    /// How far the mouse must move while held down to start a drag, rather than
+    /// clicking.
+    pub drag_threshold: Pixels,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self {
+            multi_click_interval: Duration::from_millis(500),
+            long_press_duration: Duration::from_millis(500),
+            drag_threshold: Pixels(4.),
+        }
+    }
+}
+
+impl Global for GestureSettings {}
+
+impl GestureSettings {
+    /// Returns the settings set globally, or the default ones.
+    pub fn get(cx: &AppContext) -> Self {
+        cx.try_global::<Self>().copied().unwrap_or_default()
+    }
+}
+
+/// A gesture told apart by a [`GestureRecognizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    /// A click, `count` being 2 for a double click and 3 for a triple click.
+    Click {
+        /// How many clicks in a row this one ends.
+        count: usize,
+    },
+    /// The mouse was held down without moving.
+    LongPress,
+    /// The mouse moved past the drag threshold while held down.
+    DragStart,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    position: Point<Pixels>,
+    started_at: Instant,
+    /// Set once the press became a long press or a drag, so that it's no longer
+    /// a click.
+    recognized: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LastClick {
+    position: Point<Pixels>,
+    ended_at: Instant,
+    count: usize,
+}
+
+/// Tells clicks, multiple clicks, long presses and drags apart, from the mouse
+/// events it's fed.
+#[derive(Debug, Clone)]
+pub struct GestureRecognizer {
+    settings: GestureSettings,
+    press: Option<Press>,
+    last_click: Option<LastClick>,
+}
+
+impl GestureRecognizer {
+    /// Creates a recognizer telling gestures apart with the given thresholds.
+    pub fn new(settings: GestureSettings) -> Self {
+        Self {
+            settings,
+            press: None,
+            last_click: None,
+        }
+    }
+
+    /// Returns the thresholds the recognizer uses.
+    pub fn settings(&self) -> GestureSettings {
+        self.settings
+    }
+
+    /// Starts a press at `position`.
+    pub fn mouse_down(&mut self, position: Point<Pixels>, now: Instant) {
+        self.press = Some(Press {
+            position,
+            started_at: now,
+            recognized: false,
+        });
+    }
+
+    /// Returns [`Gesture::DragStart`] when the mouse first moves past the drag
+    /// threshold while held down.
+    pub fn mouse_move(&mut self, position: Point<Pixels>) -> Option<Gesture> {
+        let press = self.press.as_mut()?;
+        if press.recognized
+            || !self
+                .settings
+                .exceeds_drag_threshold(press.position, position)
+        {
+            return None;
+        }
+        press.recognized = true;
+        self.last_click = None;
+        Some(Gesture::DragStart)
+    }
+
+    /// Returns [`Gesture::LongPress`] if the mouse is still held down, without
+    /// having been dragged, `long_press_duration` after it was pressed.
+    pub fn long_press(&mut self, now: Instant) -> Option<Gesture> {
+        let press = self.press.as_mut()?;
+        if press.recognized || now - press.started_at < self.settings.long_press_duration {
+            return None;
+        }
+        press.recognized = true;
+        self.last_click = None;
+        Some(Gesture::LongPress)
+    }
+
+    /// Returns the click ending with this release, counting the clicks before it
+    /// that were close enough in time and space, or `None` if the press was a long
+    /// press or a drag.
+    pub fn mouse_up(&mut self, position: Point<Pixels>, now: Instant) -> Option<Gesture> {
+        let press = self.press.take()?;
+        if press.recognized {
+            return None;
+        }
+        let count = match self.last_click {
+            Some(last_click)
+                if press.started_at - last_click.ended_at <= self.settings.multi_click_interval
+                    && !self
+                        .settings
+                        .exceeds_drag_threshold(last_click.position, position) =>
+            {
+                last_click.count + 1
+            }
+            _ => 1,
+        };
+        self.last_click = Some(LastClick {
+            position,
+            ended_at: now,
+            count,
+        });
+        Some(Gesture::Click { count })
+    }
+
+    /// Forgets the current press, e.g. when the mouse is released outside of the
+    /// element.
+    pub fn cancel(&mut self) {
+        self.press = None;
+        self.last_click = None;
+    }
+
+    /// Whether the current press became a long press or a drag.
+    pub fn is_recognized(&self) -> bool {
+        self.press.map_or(false, |press| press.recognized)
+    }
+}
+
+impl GestureSettings {
+    fn exceeds_drag_threshold(&self, from: Point<Pixels>, to: Point<Pixels>) -> bool {
+        let delta = to - from;
+        (delta.x.0 * delta.x.0 + delta.y.0 * delta.y.0).sqrt() > self.drag_threshold.0
+    }
+}
+
+/// Where and with which modifiers a gesture happened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureEvent {
+    /// The position of the mouse when the gesture was recognized.
+    pub position: Point<Pixels>,
+    /// The modifiers held down when the gesture was recognized.
+    pub modifiers: Modifiers,
+}
+
+type GestureHandler = Rc<dyn Fn(&GestureEvent, &mut WindowContext)>;
+
+/// The gesture handlers of a component, see [`Gestures`](crate::Gestures).
+#[derive(Default, Clone)]
+pub struct GestureHandlers {
+    pub(crate) on_double_click: Option<GestureHandler>,
+    pub(crate) on_triple_click: Option<GestureHandler>,
+    pub(crate) on_long_press: Option<GestureHandler>,
+    pub(crate) on_drag_start: Option<GestureHandler>,
+    pub(crate) settings: Option<GestureSettings>,
+}
+
+impl GestureHandlers {
+    /// Whether no gesture is handled.
+    pub fn is_empty(&self) -> bool {
+        self.on_double_click.is_none()
+            && self.on_triple_click.is_none()
+            && self.on_long_press.is_none()
+            && self.on_drag_start.is_none()
+    }
+
+    /// Wraps `child` in a [`GestureDetector`] running the handlers, unless there
+    /// are none.
+    pub fn wrap(self, id: impl Into<ElementId>, child: impl IntoElement) -> AnyElement {
+        if self.is_empty() {
+            child.into_any_element()
+        } else {
+            GestureDetector {
+                id: id.into(),
+                handlers: self,
+                child: child.into_any_element(),
+            }
+            .into_any_element()
+        }
+    }
+}
+
+struct GestureDetectorState {
+    recognizer: GestureRecognizer,
+    long_press_task: Option<Task<()>>,
+}
+
+/// Recognizes double and triple clicks, long presses and drags on its child, and
+/// runs the matching [`GestureHandlers`].
+///
+/// A press that became a long press or a drag doesn't click the child when it's
+/// released.
+pub struct GestureDetector {
+    id: ElementId,
+    handlers: GestureHandlers,
+    child: AnyElement,
+}
+
+impl GestureDetector {
+    /// Creates a detector running `handlers` for the gestures made on `child`.
+    pub fn new(
+        id: impl Into<ElementId>,
+        handlers: GestureHandlers,
+        child: impl IntoElement,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            handlers,
+            child: child.into_any_element(),
+        }
+    }
+}
+
+impl IntoElement for GestureDetector {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for GestureDetector {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, ()) {
+        (self.child.request_layout(cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut (),
+        cx: &mut WindowContext,
+    ) -> Hitbox {
+        let hitbox = cx.insert_hitbox(bounds, false);
+        self.child.prepaint(cx);
+        hitbox
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut (),
+        hitbox: &mut Hitbox,
+        cx: &mut WindowContext,
+    ) {
+        let settings = self
+            .handlers
+            .settings
+            .unwrap_or_else(|| GestureSettings::get(cx));
+        let state = cx.with_element_state(id.unwrap(), |state, _| {
+            let state: Rc<RefCell<GestureDetectorState>> = state.unwrap_or_else(|| {
+                Rc::new(RefCell::new(GestureDetectorState {
+                    recognizer: GestureRecognizer::new(settings),
+                    long_press_task: None,
+                }))
+            });
+            (state.clone(), state)
+        });
+        state.borrow_mut().recognizer.settings = settings;
+
+        // Listening in the capture phase, the gestures are recognized before the
+        // child handles the mouse, so that it can be kept from clicking.
+        cx.on_mouse_event({
+            let state = state.clone();
+            let hitbox = hitbox.clone();
+            let on_long_press = self.handlers.on_long_press.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if !phase.capture() || event.button != MouseButton::Left || !hitbox.is_hovered(cx) {
+                    return;
+                }
+                let mut gesture_state = state.borrow_mut();
+                gesture_state
+                    .recognizer
+                    .mouse_down(event.position, Instant::now());
+                gesture_state.long_press_task = on_long_press.clone().map(|on_long_press| {
+                    let state = state.clone();
+                    let gesture_event = GestureEvent {
+                        position: event.position,
+                        modifiers: event.modifiers,
+                    };
+                    cx.spawn(|mut cx| async move {
+                        cx.background_executor()
+                            .timer(settings.long_press_duration)
+                            .await;
+                        cx.update(|cx| {
+                            let gesture = state.borrow_mut().recognizer.long_press(Instant::now());
+                            if gesture == Some(Gesture::LongPress) {
+                                on_long_press(&gesture_event, cx);
+                                cx.refresh();
+                            }
+                        })
+                        .ok();
+                    })
+                });
+            }
+        });
+
+        cx.on_mouse_event({
+            let state = state.clone();
+            let on_drag_start = self.handlers.on_drag_start.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if !phase.capture() || event.pressed_button != Some(MouseButton::Left) {
+                    return;
+                }
+                let gesture = state.borrow_mut().recognizer.mouse_move(event.position);
+                if gesture == Some(Gesture::DragStart) {
+                    state.borrow_mut().long_press_task = None;
+                    if let Some(on_drag_start) = on_drag_start.as_ref() {
+                        on_drag_start(
+                            &GestureEvent {
+                                position: event.position,
+                                modifiers: event.modifiers,
+                            },
+                            cx,
+                        );
+                    }
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let hitbox = hitbox.clone();
+            let on_double_click = self.handlers.on_double_click.clone();
+            let on_triple_click = self.handlers.on_triple_click.clone();
+            move |event: &MouseUpEvent, phase, cx| {
+                if !phase.capture() || event.button != MouseButton::Left {
+                    return;
+                }
+                let mut gesture_state = state.borrow_mut();
+                gesture_state.long_press_task = None;
+                if !hitbox.is_hovered(cx) {
+                    gesture_state.recognizer.cancel();
+                    return;
+                }
+                let was_recognized = gesture_state.recognizer.is_recognized();
+                let gesture = gesture_state
+                    .recognizer
+                    .mouse_up(event.position, Instant::now());
+                drop(gesture_state);
+
+                if was_recognized {
+                    cx.stop_propagation();
+                    return;
+                }
+                let handler = match gesture {
+                    Some(Gesture::Click { count: 2 }) => on_double_click.as_ref(),
+                    Some(Gesture::Click { count: 3 }) => on_triple_click.as_ref(),
+                    _ => None,
+                };
+                if let Some(handler) = handler {
+                    handler(
+                        &GestureEvent {
+                            position: event.position,
+                            modifiers: event.modifiers,
+                        },
+                        cx,
+                    );
+                }
+            }
+        });
+
+        self.child.paint(cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px};
+
+    use super::*;
+
+    #[test]
+    fn test_multi_click() {
+        let settings = GestureSettings::default();
+        let mut recognizer = GestureRecognizer::new(settings);
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let position = point(px(10.), px(10.));
+
+        recognizer.mouse_down(position, at(0));
+        assert_eq!(
+            recognizer.mouse_up(position, at(50)),
+            Some(Gesture::Click { count: 1 })
+        );
+        recognizer.mouse_down(position, at(200));
+        assert_eq!(
+            recognizer.mouse_up(position, at(250)),
+            Some(Gesture::Click { count: 2 })
+        );
+        recognizer.mouse_down(position, at(400));
+        assert_eq!(
+            recognizer.mouse_up(position, at(450)),
+            Some(Gesture::Click { count: 3 })
+        );
+
+        // Clicks further apart than the interval start over.
+        recognizer.mouse_down(position, at(1000));
+        assert_eq!(
+            recognizer.mouse_up(position, at(1050)),
+            Some(Gesture::Click { count: 1 })
+        );
+
+        // So do clicks far apart on the screen.
+        let far = point(px(40.), px(10.));
+        recognizer.mouse_down(far, at(1100));
+        assert_eq!(
+            recognizer.mouse_up(far, at(1150)),
+            Some(Gesture::Click { count: 1 })
+        );
+    }
+
+    #[test]
+    fn test_drag_and_long_press() {
+        let mut recognizer = GestureRecognizer::new(GestureSettings::default());
+        let start = Instant::now();
+        let position = point(px(10.), px(10.));
+
+        // Moving within the threshold is still a click.
+        recognizer.mouse_down(position, start);
+        assert_eq!(recognizer.mouse_move(point(px(12.), px(12.))), None);
+        assert_eq!(
+            recognizer.mouse_move(point(px(20.), px(10.))),
+            Some(Gesture::DragStart)
+        );
+        assert_eq!(recognizer.mouse_move(point(px(30.), px(10.))), None);
+        assert_eq!(recognizer.mouse_up(point(px(30.), px(10.)), start), None);
+
+        recognizer.mouse_down(position, start);
+        assert_eq!(
+            recognizer.long_press(start + Duration::from_millis(100)),
+            None
+        );
+        assert_eq!(
+            recognizer.long_press(start + Duration::from_millis(600)),
+            Some(Gesture::LongPress)
+        );
+        // A long press isn't dragged, nor a click once released.
+        assert_eq!(recognizer.mouse_move(point(px(30.), px(10.))), None);
+        assert_eq!(
+            recognizer.mouse_up(position, start + Duration::from_millis(700)),
+            None
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+use std::mem;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    AnyWindowHandle, AppContext, Context, EntityId, Global, Subscription, Task, ViewContext,
+    WindowContext,
+};
+
+/// How often views showing relative times, like "5 minutes ago", are redrawn.
+pub const TIME_AGO_TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+struct TimeAgoSubscriber {
+    id: usize,
+    window: AnyWindowHandle,
+    view_id: EntityId,
+}
+
+#[derive(Default)]
+struct TimeAgoTickerState {
+    next_id: usize,
+    subscribers: Vec<TimeAgoSubscriber>,
+    /// The views to redraw at the next tick only, see [`request_time_ago_tick`].
+    requested: Vec<(AnyWindowHandle, EntityId)>,
+    tick: Option<Task<()>>,
+}
+
+/// A single timer shared by every view showing relative times.
+///
+/// The timer only runs while there are subscribers or requested ticks, and each
+/// tick notifies all of them in one pass, so relative times across the app update together.
+#[derive(Default)]
+struct TimeAgoTicker(Rc<RefCell<TimeAgoTickerState>>);
+
+impl Global for TimeAgoTicker {}
+
+/// Redraws the view every [`TIME_AGO_TICK_INTERVAL`], for as long as the returned
+/// subscription is held, so that the relative times it shows stay current.
+///
+/// Store the subscription on the view, e.g. one formatting relative times into
+/// its own labels. [`TimestampLabel`](crate::TimestampLabel)s don't need one, as
+/// they request ticks themselves.
+pub fn observe_time_ago_ticks<V: 'static>(cx: &mut ViewContext<V>) -> Subscription {
+    let window = cx.window_handle();
+    let view_id = cx.entity_id();
+    let ticker = cx.default_global::<TimeAgoTicker>().0.clone();
+
+    let id = {
+        let mut state = ticker.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.subscribers.push(TimeAgoSubscriber {
+            id,
+            window,
+            view_id,
+        });
+        if state.tick.is_none() {
+            state.tick = Some(spawn_tick(ticker.clone(), cx));
+        }
+        id
+    };
+
+    Subscription::new(move || {
+        let mut state = ticker.borrow_mut();
+        state.subscribers.retain(|subscriber| subscriber.id != id);
+        if state.subscribers.is_empty() && state.requested.is_empty() {
+            state.tick.take();
+        }
+    })
+}
+
+/// Redraws the view being rendered at the next tick.
+///
+/// Called by elements showing relative times, like [`TimestampLabel`](crate::TimestampLabel),
+/// while they're rendered, so that their views stay current without holding a
+/// subscription, and stop being redrawn once they no longer show any.
+pub fn request_time_ago_tick(cx: &mut WindowContext) {
+    let Some(view_id) = cx.parent_view_id() else {
+        return;
+    };
+    let window = cx.window_handle();
+    let ticker = cx.default_global::<TimeAgoTicker>().0.clone();
+
+    let mut state = ticker.borrow_mut();
+    if !state.requested.contains(&(window, view_id)) {
+        state.requested.push((window, view_id));
+    }
+    if state.tick.is_none() {
+        state.tick = Some(spawn_tick(ticker.clone(), cx));
+    }
+}
+
+fn spawn_tick(ticker: Rc<RefCell<TimeAgoTickerState>>, cx: &mut AppContext) -> Task<()> {
+    cx.spawn(|mut cx| async move {
+        loop {
+            cx.background_executor().timer(TIME_AGO_TICK_INTERVAL).await;
+            let keep_ticking = cx.update(|cx| notify_subscribers(&ticker, cx));
+            if !keep_ticking.unwrap_or(false) {
+                break;
+            }
+        }
+    })
+}
+
+/// Notifies the subscribers and the views that requested a tick, returning whether
+/// the ticker should keep running.
+fn notify_subscribers(ticker: &Rc<RefCell<TimeAgoTickerState>>, cx: &mut AppContext) -> bool {
+    let views = {
+        let mut state = ticker.borrow_mut();
+        let mut views = mem::take(&mut state.requested);
+        for subscriber in &state.subscribers {
+            if !views.contains(&(subscriber.window, subscriber.view_id)) {
+                views.push((subscriber.window, subscriber.view_id));
+            }
+        }
+        views
+    };
+
+    for (window, view_id) in views {
+        cx.update_window(window, |_, cx| cx.notify(view_id)).ok();
+    }
+
+    // Views that still show relative times request another tick when redrawn.
+    let mut state = ticker.borrow_mut();
+    if state.subscribers.is_empty() && state.requested.is_empty() {
+        // The tick is the task running this, so let it finish rather than drop it.
+        if let Some(tick) = state.tick.take() {
+            tick.detach();
+        }
+        false
+    } else {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{canvas, div, IntoElement, ParentElement, Render, Styled, TestAppContext};
+
+    use super::*;
+
+    struct TestView {
+        renders: usize,
+        subscription: Option<Subscription>,
+        request_ticks: bool,
+    }
+
+    impl Render for TestView {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            self.renders += 1;
+            let request_ticks = self.request_ticks;
+            div().size_full().child(
+                canvas(
+                    move |_, cx| {
+                        if request_ticks {
+                            request_time_ago_tick(cx);
+                        }
+                    },
+                    |_, _, _| {},
+                )
+                .size_full(),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_observe_time_ago_ticks(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|cx| TestView {
+            renders: 0,
+            subscription: Some(observe_time_ago_ticks(cx)),
+            request_ticks: false,
+        });
+        cx.run_until_parked();
+        let renders = view.update(cx, |view, _| view.renders);
+
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 1);
+
+        // Once the subscription is dropped, the view is no longer redrawn.
+        view.update(cx, |view, _| view.subscription.take());
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 1);
+    }
+
+    #[gpui::test]
+    fn test_request_time_ago_tick(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| TestView {
+            renders: 0,
+            subscription: None,
+            request_ticks: true,
+        });
+        cx.run_until_parked();
+        let renders = view.update(cx, |view, _| view.renders);
+
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 1);
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 2);
+
+        // Once the view stops requesting ticks, it's only redrawn at the tick it
+        // last requested.
+        view.update(cx, |view, cx| {
+            view.request_ticks = false;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        let renders = view.update(cx, |view, _| view.renders);
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 1);
+        cx.executor().advance_clock(TIME_AGO_TICK_INTERVAL);
+        cx.run_until_parked();
+        assert_eq!(view.update(cx, |view, _| view.renders), renders + 1);
+    }
+}
@@ -0,0 +1,534 @@
+use std::rc::Rc;
+
+use editor::*;
+use gpui::*;
+use ui::{Tooltip, *};
+
+/// The number of cells along each side of the saturation/lightness square.
+const SQUARE_STEPS: usize = 16;
+/// The number of segments of the hue and alpha sliders.
+const SLIDER_STEPS: usize = 36;
+
+pub enum ColorPickerEvent {
+    /// The user picked a new color.
+    Changed(Hsla),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorPickerControl {
+    SaturationLightness,
+    Hue,
+    Alpha,
+}
+
+type Eyedropper = Rc<dyn Fn(&mut WindowContext) -> Task<Option<Hsla>>>;
+
+/// A color picker with a saturation/lightness square, hue and alpha sliders, a text
+/// field accepting hex and `rgb()`/`rgba()` colors, and a row of swatches.
+///
+/// The swatches default to the accent and status colors of the active theme. An
+/// eyedropper button is shown once the host provides a way of sampling a color with
+/// [`ColorPicker::set_eyedropper`].
+pub struct ColorPicker {
+    color: Hsla,
+    text_input: View<Editor>,
+    is_text_invalid: bool,
+    swatches: Option<Vec<Hsla>>,
+    eyedropper: Option<Eyedropper>,
+    pending_eyedropper: Option<Task<()>>,
+    dragging: Option<ColorPickerControl>,
+    _subscription: Subscription,
+}
+
+impl EventEmitter<ColorPickerEvent> for ColorPicker {}
+
+impl FocusableView for ColorPicker {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.text_input.focus_handle(cx)
+    }
+}
+
+impl ColorPicker {
+    pub fn new(color: Hsla, cx: &mut ViewContext<Self>) -> Self {
+        let text_input = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_text(format_color_hex(color), cx);
+            editor
+        });
+        let subscription = cx.subscribe(&text_input, Self::on_text_input_event);
+
+        Self {
+            color,
+            text_input,
+            is_text_invalid: false,
+            swatches: None,
+            eyedropper: None,
+            pending_eyedropper: None,
+            dragging: None,
+            _subscription: subscription,
+        }
+    }
+
+    pub fn color(&self) -> Hsla {
+        self.color
+    }
+
+    /// Sets the color without reporting it as [`ColorPickerEvent::Changed`].
+    pub fn set_color(&mut self, color: Hsla, cx: &mut ViewContext<Self>) {
+        self.color = color;
+        self.sync_text_input(cx);
+        cx.notify();
+    }
+
+    /// Replaces the theme-derived swatches.
+    pub fn set_swatches(&mut self, swatches: Vec<Hsla>, cx: &mut ViewContext<Self>) {
+        self.swatches = Some(swatches);
+        cx.notify();
+    }
+
+    /// Shows an eyedropper button that samples a color with the given function,
+    /// e.g. from the screen. The returned task resolves to `None` if picking was
+    /// cancelled.
+    pub fn set_eyedropper(
+        &mut self,
+        eyedropper: impl Fn(&mut WindowContext) -> Task<Option<Hsla>> + 'static,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.eyedropper = Some(Rc::new(eyedropper));
+        cx.notify();
+    }
+
+    fn pick_color(&mut self, color: Hsla, cx: &mut ViewContext<Self>) {
+        self.set_color(color, cx);
+        cx.emit(ColorPickerEvent::Changed(color));
+    }
+
+    fn sync_text_input(&mut self, cx: &mut ViewContext<Self>) {
+        let text = format_color_hex(self.color);
+        self.is_text_invalid = false;
+        self.text_input.update(cx, |editor, cx| {
+            if editor.text(cx) != text {
+                editor.set_text(text, cx);
+            }
+        });
+    }
+
+    fn on_text_input_event(
+        &mut self,
+        _: View<Editor>,
+        event: &EditorEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            EditorEvent::BufferEdited => {
+                let text = self.text_input.read(cx).text(cx);
+                if text == format_color_hex(self.color) {
+                    return;
+                }
+                match parse_color(&text) {
+                    Some(color) => {
+                        self.color = color;
+                        self.is_text_invalid = false;
+                        cx.emit(ColorPickerEvent::Changed(color));
+                    }
+                    None => self.is_text_invalid = true,
+                }
+                cx.notify();
+            }
+            EditorEvent::Blurred => {
+                // Replace incomplete or invalid input with the color that is in effect.
+                self.sync_text_input(cx);
+                cx.notify();
+            }
+            _ => {}
+        }
+    }
+
+    fn pick_at(
+        &mut self,
+        control: ColorPickerControl,
+        bounds: Bounds<Pixels>,
+        position: Point<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let x = ((position.x - bounds.left()) / bounds.size.width).clamp(0., 1.);
+        let y = ((position.y - bounds.top()) / bounds.size.height).clamp(0., 1.);
+        let mut color = self.color;
+        match control {
+            ColorPickerControl::SaturationLightness => {
+                color.s = x;
+                color.l = 1. - y;
+            }
+            ColorPickerControl::Hue => color.h = x,
+            ColorPickerControl::Alpha => color.a = x,
+        }
+        self.pick_color(color, cx);
+    }
+
+    fn use_eyedropper(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(eyedropper) = self.eyedropper.clone() else {
+            return;
+        };
+        let task = eyedropper(cx);
+        self.pending_eyedropper = Some(cx.spawn(|this, mut cx| async move {
+            let color = task.await;
+            this.update(&mut cx, |this, cx| {
+                this.pending_eyedropper = None;
+                if let Some(color) = color {
+                    this.pick_color(color, cx);
+                }
+            })
+            .ok();
+        }));
+    }
+
+    fn swatches(&self, cx: &WindowContext) -> Vec<Hsla> {
+        if let Some(swatches) = &self.swatches {
+            return swatches.clone();
+        }
+
+        let theme = cx.theme();
+        let status = theme.status();
+        let mut swatches = theme.accents().0.clone();
+        swatches.extend([
+            status.error,
+            status.warning,
+            status.success,
+            status.info,
+            theme.colors().text,
+            theme.colors().background,
+        ]);
+        swatches
+    }
+
+    fn render_control(
+        &self,
+        control: ColorPickerControl,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let view = cx.view().clone();
+        let color = self.color;
+
+        canvas(
+            |bounds, cx| cx.insert_hitbox(bounds, false),
+            move |bounds, hitbox, cx| {
+                paint_control(control, color, bounds, cx);
+                cx.set_cursor_style(CursorStyle::Crosshair, &hitbox);
+
+                cx.on_mouse_event({
+                    let view = view.clone();
+                    move |event: &MouseDownEvent, phase, cx| {
+                        if phase.bubble()
+                            && event.button == MouseButton::Left
+                            && hitbox.is_hovered(cx)
+                        {
+                            view.update(cx, |this, cx| {
+                                this.dragging = Some(control);
+                                this.pick_at(control, bounds, event.position, cx);
+                            });
+                            cx.stop_propagation();
+                        }
+                    }
+                });
+                cx.on_mouse_event({
+                    let view = view.clone();
+                    move |event: &MouseMoveEvent, phase, cx| {
+                        if !phase.bubble() {
+                            return;
+                        }
+                        view.update(cx, |this, cx| {
+                            if this.dragging != Some(control) {
+                                return;
+                            }
+                            if event.dragging() {
+                                this.pick_at(control, bounds, event.position, cx);
+                            } else {
+                                this.dragging = None;
+                            }
+                        });
+                    }
+                });
+                cx.on_mouse_event(move |_: &MouseUpEvent, phase, cx| {
+                    if phase.bubble() {
+                        view.update(cx, |this, _| {
+                            if this.dragging == Some(control) {
+                                this.dragging = None;
+                            }
+                        });
+                    }
+                });
+            },
+        )
+        .size_full()
+    }
+}
+
+fn paint_control(
+    control: ColorPickerControl,
+    color: Hsla,
+    bounds: Bounds<Pixels>,
+    cx: &mut WindowContext,
+) {
+    match control {
+        ColorPickerControl::SaturationLightness => {
+            let cell_size = size(
+                bounds.size.width / SQUARE_STEPS as f32,
+                bounds.size.height / SQUARE_STEPS as f32,
+            );
+            for column in 0..SQUARE_STEPS {
+                for row in 0..SQUARE_STEPS {
+                    let saturation = (column as f32 + 0.5) / SQUARE_STEPS as f32;
+                    let lightness = 1. - (row as f32 + 0.5) / SQUARE_STEPS as f32;
+                    let origin = bounds.origin
+                        + point(
+                            cell_size.width * column as f32,
+                            cell_size.height * row as f32,
+                        );
+                    cx.paint_quad(fill(
+                        Bounds::new(origin, cell_size),
+                        hsla(color.h, saturation, lightness, 1.),
+                    ));
+                }
+            }
+            paint_marker(
+                bounds.origin
+                    + point(
+                        bounds.size.width * color.s,
+                        bounds.size.height * (1. - color.l),
+                    ),
+                color.l,
+                cx,
+            );
+        }
+        ColorPickerControl::Hue | ColorPickerControl::Alpha => {
+            let segment_size = size(bounds.size.width / SLIDER_STEPS as f32, bounds.size.height);
+            if control == ColorPickerControl::Alpha {
+                paint_checkerboard(bounds, cx);
+            }
+            for segment in 0..SLIDER_STEPS {
+                let position = (segment as f32 + 0.5) / SLIDER_STEPS as f32;
+                let segment_color = match control {
+                    ColorPickerControl::Hue => hsla(position, 1., 0.5, 1.),
+                    _ => hsla(color.h, color.s, color.l, position),
+                };
+                let origin = bounds.origin + point(segment_size.width * segment as f32, px(0.));
+                cx.paint_quad(fill(Bounds::new(origin, segment_size), segment_color));
+            }
+            let value = match control {
+                ColorPickerControl::Hue => color.h,
+                _ => color.a,
+            };
+            paint_marker(
+                bounds.origin + point(bounds.size.width * value, bounds.size.height / 2.),
+                0.5,
+                cx,
+            );
+        }
+    }
+}
+
+/// Paints a ring centered on `center`, contrasting with a background of the given lightness.
+fn paint_marker(center: Point<Pixels>, background_lightness: f32, cx: &mut WindowContext) {
+    let radius = px(5.);
+    let border_color = if background_lightness > 0.5 {
+        black()
+    } else {
+        white()
+    };
+    cx.paint_quad(quad(
+        Bounds::new(
+            center - point(radius, radius),
+            size(radius * 2., radius * 2.),
+        ),
+        radius,
+        transparent_black(),
+        px(2.),
+        border_color,
+    ));
+}
+
+/// Paints the checkerboard shown through translucent colors.
+fn paint_checkerboard(bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+    let square = bounds.size.height / 2.;
+    let columns = (bounds.size.width / square).ceil() as usize;
+    cx.paint_quad(fill(bounds, white()));
+    cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+        for column in 0..columns {
+            let row = column % 2;
+            let origin = bounds.origin + point(square * column as f32, square * row as f32);
+            cx.paint_quad(fill(
+                Bounds::new(origin, size(square, square)),
+                hsla(0., 0., 0.8, 1.),
+            ));
+        }
+    });
+}
+
+impl Render for ColorPicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let border_color = colors.border_variant;
+        let text_input_border = if self.is_text_invalid {
+            cx.theme().status().error_border
+        } else {
+            colors.border
+        };
+
+        v_flex()
+            .key_context("ColorPicker")
+            .w(px(240.))
+            .gap_2()
+            .p_2()
+            .child(
+                div()
+                    .h(px(160.))
+                    .w_full()
+                    .border_1()
+                    .border_color(border_color)
+                    .child(self.render_control(ColorPickerControl::SaturationLightness, cx)),
+            )
+            .child(
+                div()
+                    .h(px(12.))
+                    .w_full()
+                    .child(self.render_control(ColorPickerControl::Hue, cx)),
+            )
+            .child(
+                div()
+                    .h(px(12.))
+                    .w_full()
+                    .child(self.render_control(ColorPickerControl::Alpha, cx)),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .size_6()
+                            .flex_none()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(border_color)
+                            .bg(self.color),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(text_input_border)
+                            .bg(colors.editor_background)
+                            .child(self.text_input.clone()),
+                    )
+                    .when(self.eyedropper.is_some(), |this| {
+                        this.child(
+                            IconButton::new("eyedropper", IconName::Eye)
+                                .icon_size(IconSize::Small)
+                                .selected(self.pending_eyedropper.is_some())
+                                .tooltip(|cx| Tooltip::text("Pick a Color from the Screen", cx))
+                                .on_click(cx.listener(|this, _, cx| this.use_eyedropper(cx))),
+                        )
+                    }),
+            )
+            .child(
+                h_flex().flex_wrap().gap_1().children(
+                    self.swatches(cx)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(ix, swatch)| {
+                            div()
+                                .id(("swatch", ix))
+                                .size_5()
+                                .rounded_sm()
+                                .border_1()
+                                .border_color(border_color)
+                                .bg(swatch)
+                                .cursor_pointer()
+                                .on_click(
+                                    cx.listener(move |this, _, cx| this.pick_color(swatch, cx)),
+                                )
+                        }),
+                ),
+            )
+    }
+}
+
+/// Parses a color entered as hex (with or without `#`), `rgb(r, g, b)` or
+/// `rgba(r, g, b, a)`, where `r`, `g` and `b` range from 0 to 255 and `a` from 0 to 1.
+pub fn parse_color(text: &str) -> Option<Hsla> {
+    let text = text.trim();
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Rgba::try_from(format!("#{hex}").as_str())
+            .ok()
+            .map(Into::into);
+    }
+
+    let (function, arguments) = text.strip_suffix(')')?.split_once('(')?;
+    let components = arguments
+        .split(',')
+        .map(|component| component.trim().parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let (r, g, b, a) = match (function.trim(), components.as_slice()) {
+        ("rgb", [r, g, b]) => (*r, *g, *b, 1.),
+        ("rgba", [r, g, b, a]) => (*r, *g, *b, *a),
+        _ => return None,
+    };
+    if [r, g, b]
+        .iter()
+        .any(|component| !(0. ..=255.).contains(component))
+        || !(0. ..=1.).contains(&a)
+    {
+        return None;
+    }
+
+    Some(
+        Rgba {
+            r: r / 255.,
+            g: g / 255.,
+            b: b / 255.,
+            a,
+        }
+        .into(),
+    )
+}
+
+/// Formats a color as `#rrggbb`, or `#rrggbbaa` if it is translucent.
+pub fn format_color_hex(color: Hsla) -> String {
+    let rgba = Rgba::from(color);
+    let [r, g, b, a] = [rgba.r, rgba.g, rgba.b, rgba.a]
+        .map(|component| (component.clamp(0., 1.) * 255.).round() as u8);
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_colors() {
+        let orange = parse_color("#ff8000").unwrap();
+        assert_eq!(format_color_hex(orange), "#ff8000");
+        assert_eq!(parse_color("ff8000"), Some(orange));
+        assert_eq!(
+            format_color_hex(parse_color("rgb(255, 128, 0)").unwrap()),
+            "#ff8000"
+        );
+        assert_eq!(
+            format_color_hex(parse_color("rgba(255, 128, 0, 0.5)").unwrap()),
+            "#ff800080"
+        );
+        assert_eq!(format_color_hex(parse_color("#f80").unwrap()), "#ff8800");
+
+        assert_eq!(parse_color(""), None);
+        assert_eq!(parse_color("rgb(256, 0, 0)"), None);
+        assert_eq!(parse_color("rgba(0, 0, 0)"), None);
+        assert_eq!(parse_color("hsl(0, 0, 0)"), None);
+    }
+}
@@ -0,0 +1,229 @@
+use editor::{actions::SelectAll, *};
+use gpui::*;
+use ui::{Tooltip, *};
+
+pub enum EditableLabelEvent {
+    /// The text was edited and confirmed, and passed validation.
+    Renamed(String),
+    /// Editing was cancelled with Escape, or by leaving the field with invalid text.
+    Cancelled,
+}
+
+type Validate = Box<dyn Fn(&str) -> Result<(), SharedString>>;
+
+/// A label that is renamed in place, e.g. a file, a tab or a channel.
+///
+/// It renders as a [`Label`] until it's double-clicked, or F2 is pressed while it's
+/// focused, and then as a text field with the text selected. Enter or leaving the
+/// field confirms the new text, reported as [`EditableLabelEvent::Renamed`], and
+/// Escape cancels. While the text is invalid, according to
+/// [`EditableLabel::set_validation`], the field is tinted and shows the error in a
+/// tooltip, and confirming is refused.
+pub struct EditableLabel {
+    text: SharedString,
+    editor: View<Editor>,
+    focus_handle: FocusHandle,
+    is_editing: bool,
+    size: LabelSize,
+    color: Color,
+    validate: Option<Validate>,
+    error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<EditableLabelEvent> for EditableLabel {}
+
+impl FocusableView for EditableLabel {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        if self.is_editing {
+            self.editor.focus_handle(cx)
+        } else {
+            self.focus_handle.clone()
+        }
+    }
+}
+
+impl EditableLabel {
+    pub fn new(text: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let editor = cx.new_view(Editor::single_line);
+        let subscriptions = vec![
+            cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+                if let EditorEvent::BufferEdited = event {
+                    this.validate(cx);
+                }
+            }),
+            cx.on_blur(&editor.focus_handle(cx), |this, cx| {
+                if this.is_editing {
+                    if this.validate(cx) {
+                        this.commit(cx);
+                    } else {
+                        this.cancel_editing(cx);
+                    }
+                }
+            }),
+        ];
+
+        Self {
+            text: text.into(),
+            editor,
+            focus_handle: cx.focus_handle(),
+            is_editing: false,
+            size: LabelSize::Default,
+            color: Color::Default,
+            validate: None,
+            error: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn text(&self) -> &SharedString {
+        &self.text
+    }
+
+    /// Replaces the text, without reporting it. Editing is cancelled.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.text = text.into();
+        self.is_editing = false;
+        self.error = None;
+        cx.notify();
+    }
+
+    pub fn set_size(&mut self, size: LabelSize) {
+        self.size = size;
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Sets the validation of the edited text, which returns the error to show.
+    pub fn set_validation(
+        &mut self,
+        validate: impl Fn(&str) -> Result<(), SharedString> + 'static,
+    ) {
+        self.validate = Some(Box::new(validate));
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.is_editing
+    }
+
+    /// Switches to the text field, with the whole text selected.
+    pub fn start_editing(&mut self, cx: &mut ViewContext<Self>) {
+        if self.is_editing {
+            return;
+        }
+        self.is_editing = true;
+        self.error = None;
+        let text = self.text.clone();
+        self.editor.update(cx, |editor, cx| {
+            editor.set_text(text.to_string(), cx);
+            editor.select_all(&SelectAll, cx);
+        });
+        cx.focus_view(&self.editor);
+        cx.notify();
+    }
+
+    pub fn cancel_editing(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.is_editing {
+            return;
+        }
+        self.is_editing = false;
+        self.error = None;
+        cx.focus(&self.focus_handle);
+        cx.emit(EditableLabelEvent::Cancelled);
+        cx.notify();
+    }
+
+    /// Validates the edited text, showing the error if any. Returns whether it's valid.
+    fn validate(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        let text = self.editor.read(cx).text(cx);
+        let error = self
+            .validate
+            .as_ref()
+            .and_then(|validate| validate(&text).err());
+        let is_valid = error.is_none();
+        if self.error != error {
+            self.error = error;
+            cx.notify();
+        }
+        is_valid
+    }
+
+    fn commit(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.editor.read(cx).text(cx);
+        self.is_editing = false;
+        self.error = None;
+        if text != self.text.as_ref() {
+            self.text = text.clone().into();
+            cx.emit(EditableLabelEvent::Renamed(text));
+        }
+        cx.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        if self.validate(cx) {
+            self.commit(cx);
+        }
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        self.cancel_editing(cx);
+    }
+}
+
+impl Render for EditableLabel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !self.is_editing {
+            return div()
+                .id("editable-label")
+                .track_focus(&self.focus_handle)
+                .child(
+                    Label::new(self.text.clone())
+                        .size(self.size)
+                        .color(self.color)
+                        .single_line(),
+                )
+                .on_key_down(cx.listener(|this, event: &KeyDownEvent, cx| {
+                    if event.keystroke.key == "f2" {
+                        cx.stop_propagation();
+                        this.start_editing(cx);
+                    }
+                }))
+                .on_click(cx.listener(|this, event: &ClickEvent, cx| {
+                    if event.up.click_count == 2 {
+                        this.start_editing(cx);
+                    }
+                }))
+                .into_any_element();
+        }
+
+        let (border_color, background) = if self.error.is_some() {
+            (
+                cx.theme().status().error_border,
+                Some(cx.theme().status().error_background),
+            )
+        } else {
+            (cx.theme().colors().border_focused, None)
+        };
+        let error = self.error.clone();
+
+        div()
+            .id("editable-label")
+            .key_context("EditableLabel")
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .min_w(rems(6.))
+            .px_0p5()
+            .border_1()
+            .rounded_sm()
+            .border_color(border_color)
+            .when_some(background, |this, background| this.bg(background))
+            .when_some(error, |this, error| {
+                this.tooltip(move |cx| Tooltip::text(error.clone(), cx))
+            })
+            .child(self.editor.clone())
+            .into_any_element()
+    }
+}
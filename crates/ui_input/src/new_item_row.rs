@@ -0,0 +1,160 @@
+use editor::*;
+use gpui::*;
+use ui::*;
+
+pub enum NewItemRowEvent {
+    /// The name was confirmed and passed validation.
+    Create(String),
+    /// Creating the item was cancelled, with Escape or by leaving the row empty.
+    Cancel,
+}
+
+type Validate = Box<dyn Fn(&str) -> Result<(), SharedString>>;
+
+/// An editable row appended to a [`List`] with [`List::new_item_row`] to name a new
+/// item in place, e.g. a new file or channel, rather than in a modal.
+///
+/// Confirming with Enter validates the name and reports it as
+/// [`NewItemRowEvent::Create`], or shows the validation error under the row.
+/// Names are only required not to be blank unless set otherwise with
+/// [`NewItemRow::set_validation`].
+pub struct NewItemRow {
+    editor: View<Editor>,
+    icon: Option<IconName>,
+    indent_level: usize,
+    validate: Validate,
+    error: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<NewItemRowEvent> for NewItemRow {}
+
+impl FocusableView for NewItemRow {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.editor.focus_handle(cx)
+    }
+}
+
+impl NewItemRow {
+    pub fn new(placeholder: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let placeholder = placeholder.into();
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text(placeholder, cx);
+            editor
+        });
+        let subscriptions = vec![
+            cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+                if let EditorEvent::BufferEdited = event {
+                    if this.error.take().is_some() {
+                        cx.notify();
+                    }
+                }
+            }),
+            cx.on_blur(&editor.focus_handle(cx), |this, cx| {
+                if this.name(cx).trim().is_empty() {
+                    cx.emit(NewItemRowEvent::Cancel);
+                }
+            }),
+        ];
+
+        Self {
+            editor,
+            icon: None,
+            indent_level: 0,
+            validate: Box::new(|name| {
+                if name.trim().is_empty() {
+                    Err("A name must be provided".into())
+                } else {
+                    Ok(())
+                }
+            }),
+            error: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Sets the icon shown at the start of the row, e.g. that of the kind of item.
+    pub fn set_icon(&mut self, icon: IconName) {
+        self.icon = Some(icon);
+    }
+
+    /// Sets the indentation of the row, to match the items it's created among.
+    pub fn set_indent_level(&mut self, indent_level: usize) {
+        self.indent_level = indent_level;
+    }
+
+    /// Replaces the validation of the name, which returns the error to show.
+    pub fn set_validation(
+        &mut self,
+        validate: impl Fn(&str) -> Result<(), SharedString> + 'static,
+    ) {
+        self.validate = Box::new(validate);
+    }
+
+    pub fn name(&self, cx: &AppContext) -> String {
+        self.editor.read(cx).text(cx)
+    }
+
+    pub fn editor(&self) -> &View<Editor> {
+        &self.editor
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        let name = self.name(cx);
+        match (self.validate)(&name) {
+            Ok(()) => {
+                self.error = None;
+                cx.emit(NewItemRowEvent::Create(name));
+            }
+            Err(error) => self.error = Some(error),
+        }
+        cx.notify();
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        cx.emit(NewItemRowEvent::Cancel);
+    }
+}
+
+impl Render for NewItemRow {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let border_color = if self.error.is_some() {
+            cx.theme().status().error_border
+        } else {
+            cx.theme().colors().border_focused
+        };
+
+        v_flex()
+            .key_context("NewItemRow")
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .w_full()
+            .child(
+                ListItem::new("new-item-row")
+                    .indent_level(self.indent_level)
+                    .selectable(false)
+                    .start_slot(
+                        self.icon
+                            .map(|icon| Icon::new(icon).size(IconSize::Small).color(Color::Muted)),
+                    )
+                    .child(
+                        div()
+                            .w_full()
+                            .px_1()
+                            .border_1()
+                            .rounded_sm()
+                            .border_color(border_color)
+                            .child(self.editor.clone()),
+                    ),
+            )
+            .when_some(self.error.clone(), |this, error| {
+                this.child(
+                    div()
+                        .pl(px(12.) * (self.indent_level as f32 + 1.))
+                        .pr_2()
+                        .child(Label::new(error).size(LabelSize::Small).color(Color::Error)),
+                )
+            })
+    }
+}
@@ -0,0 +1,293 @@
+use editor::{actions::MoveDown, actions::MoveUp, *};
+use gpui::*;
+use ui::{Tooltip, *};
+
+pub enum NumberInputEvent {
+    /// The value changed to a valid number, either typed or stepped.
+    Changed(f64),
+}
+
+/// Parses the text of a [`NumberInput`], which may end with its unit.
+fn parse_number(text: &str, unit: Option<&str>) -> Option<f64> {
+    let text = text.trim();
+    let text = unit
+        .and_then(|unit| text.strip_suffix(unit))
+        .unwrap_or(text)
+        .trim_end();
+    text.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+/// Formats a value with at most `precision` decimals, without trailing zeros.
+fn format_number(value: f64, precision: usize) -> String {
+    let formatted = format!("{value:.precision$}");
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Moves a value by a number of steps, snapping it to a multiple of the step so
+/// that repeated steps don't accumulate rounding errors, and clamping it to the range.
+fn step_value(value: f64, step: f64, steps: i32, min: Option<f64>, max: Option<f64>) -> f64 {
+    let stepped = ((value / step).round() + steps as f64) * step;
+    clamp(stepped, min, max)
+}
+
+fn clamp(value: f64, min: Option<f64>, max: Option<f64>) -> f64 {
+    let value = min.map_or(value, |min| value.max(min));
+    max.map_or(value, |max| value.min(max))
+}
+
+/// A field for a number, e.g. a font size or a tab width, with buttons to step it
+/// up and down.
+///
+/// While focused, the up and down arrow keys and the scroll wheel step the value
+/// too. Typed values are validated against the range, and reported with
+/// [`NumberInputEvent::Changed`] once valid. Leaving the field with an invalid
+/// value restores the last valid one.
+pub struct NumberInput {
+    editor: View<Editor>,
+    value: f64,
+    step: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    precision: usize,
+    unit: Option<SharedString>,
+    error: Option<SharedString>,
+    /// The scrolled distance not yet turned into steps, for trackpads.
+    scroll_remainder: Pixels,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<NumberInputEvent> for NumberInput {}
+
+impl FocusableView for NumberInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.editor.focus_handle(cx)
+    }
+}
+
+impl NumberInput {
+    pub fn new(value: f64, cx: &mut ViewContext<Self>) -> Self {
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_text(format_number(value, 2), cx);
+            editor
+        });
+        let subscriptions = vec![
+            cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+                if let EditorEvent::BufferEdited = event {
+                    this.validate(cx);
+                }
+            }),
+            cx.on_blur(&editor.focus_handle(cx), |this, cx| {
+                if this.error.is_some() {
+                    this.set_value(this.value, cx);
+                }
+            }),
+        ];
+
+        Self {
+            editor,
+            value,
+            step: 1.,
+            min: None,
+            max: None,
+            precision: 2,
+            unit: None,
+            error: None,
+            scroll_remainder: px(0.),
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Sets how much a step changes the value. Defaults to 1.
+    pub fn set_step(&mut self, step: f64) {
+        if step > 0. {
+            self.step = step;
+        }
+    }
+
+    /// Sets the lowest and highest valid values. Both are unbounded by default.
+    pub fn set_range(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Sets the most decimals shown. Defaults to 2.
+    pub fn set_precision(&mut self, precision: usize, cx: &mut ViewContext<Self>) {
+        self.precision = precision;
+        self.set_value(self.value, cx);
+    }
+
+    /// Sets the unit shown after the value, e.g. `px`, `%` or `ms`. It may also be
+    /// typed after the value.
+    pub fn set_unit(&mut self, unit: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.unit = Some(unit.into());
+        cx.notify();
+    }
+
+    /// The last valid value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Replaces the value, clamped to the range, without reporting it.
+    pub fn set_value(&mut self, value: f64, cx: &mut ViewContext<Self>) {
+        self.value = clamp(value, self.min, self.max);
+        self.error = None;
+        let text = format_number(self.value, self.precision);
+        self.editor
+            .update(cx, |editor, cx| editor.set_text(text, cx));
+        cx.notify();
+    }
+
+    pub fn editor(&self) -> &View<Editor> {
+        &self.editor
+    }
+
+    fn validate(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.editor.read(cx).text(cx);
+        let error = match parse_number(&text, self.unit.as_deref()) {
+            None => Some("Not a number".into()),
+            Some(value) => match (self.min, self.max) {
+                (Some(min), _) if value < min => {
+                    Some(format!("Must be at least {}", format_number(min, self.precision)).into())
+                }
+                (_, Some(max)) if value > max => {
+                    Some(format!("Must be at most {}", format_number(max, self.precision)).into())
+                }
+                _ => {
+                    if value != self.value {
+                        self.value = value;
+                        cx.emit(NumberInputEvent::Changed(value));
+                    }
+                    None
+                }
+            },
+        };
+        self.error = error;
+        cx.notify();
+    }
+
+    fn step_by(&mut self, steps: i32, cx: &mut ViewContext<Self>) {
+        let value = step_value(self.value, self.step, steps, self.min, self.max);
+        let changed = value != self.value;
+        self.set_value(value, cx);
+        if changed {
+            cx.emit(NumberInputEvent::Changed(value));
+        }
+    }
+
+    fn increment(&mut self, _: &MoveUp, cx: &mut ViewContext<Self>) {
+        self.step_by(1, cx);
+    }
+
+    fn decrement(&mut self, _: &MoveDown, cx: &mut ViewContext<Self>) {
+        self.step_by(-1, cx);
+    }
+
+    fn scroll(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        if !self.editor.focus_handle(cx).is_focused(cx) {
+            return;
+        }
+        let line_height = cx.line_height();
+        self.scroll_remainder += event.delta.pixel_delta(line_height).y;
+        let steps = (self.scroll_remainder / line_height).trunc();
+        if steps != 0. {
+            self.scroll_remainder -= line_height * steps;
+            self.step_by(steps as i32, cx);
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let border_color = if self.error.is_some() {
+            cx.theme().status().error_border
+        } else if self.editor.focus_handle(cx).is_focused(cx) {
+            cx.theme().colors().border_focused
+        } else {
+            cx.theme().colors().border
+        };
+        let can_increment = self.max.map_or(true, |max| self.value < max);
+        let can_decrement = self.min.map_or(true, |min| self.value > min);
+
+        v_flex()
+            .key_context("NumberInput")
+            .on_action(cx.listener(Self::increment))
+            .on_action(cx.listener(Self::decrement))
+            .gap_0p5()
+            .child(
+                h_flex()
+                    .id("number-input")
+                    .on_scroll_wheel(cx.listener(Self::scroll))
+                    .w_full()
+                    .gap_1()
+                    .pl_2()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(border_color)
+                    .bg(cx.theme().colors().editor_background)
+                    .when_some(self.error.clone(), |this, error| {
+                        this.tooltip(move |cx| Tooltip::text(error.clone(), cx))
+                    })
+                    .child(div().flex_1().py_0p5().child(self.editor.clone()))
+                    .when_some(self.unit.clone(), |this, unit| {
+                        this.child(Label::new(unit).size(LabelSize::Small).color(Color::Muted))
+                    })
+                    .child(
+                        v_flex()
+                            .child(
+                                IconButton::new("increment", IconName::ChevronUp)
+                                    .icon_size(IconSize::XSmall)
+                                    .disabled(!can_increment)
+                                    .on_click(cx.listener(|this, _, cx| this.step_by(1, cx))),
+                            )
+                            .child(
+                                IconButton::new("decrement", IconName::ChevronDown)
+                                    .icon_size(IconSize::XSmall)
+                                    .disabled(!can_decrement)
+                                    .on_click(cx.listener(|this, _, cx| this.step_by(-1, cx))),
+                            ),
+                    ),
+            )
+            .when_some(self.error.clone(), |this, error| {
+                this.child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number(" 14 ", None), Some(14.));
+        assert_eq!(parse_number("12.5px", Some("px")), Some(12.5));
+        assert_eq!(parse_number("50 %", Some("%")), Some(50.));
+        assert_eq!(parse_number("12px", None), None);
+        assert_eq!(parse_number("inf", None), None);
+    }
+
+    #[test]
+    fn test_step_value() {
+        assert_eq!(step_value(0.3, 0.1, 1, None, None), 0.4);
+        assert_eq!(step_value(13.7, 1., -1, None, None), 13.);
+        assert_eq!(step_value(4., 1., 1, Some(1.), Some(4.)), 4.);
+        assert_eq!(step_value(1., 1., -3, Some(1.), None), 1.);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(14., 2), "14");
+        assert_eq!(format_number(0.1 + 0.2, 2), "0.3");
+        assert_eq!(format_number(-2.50, 1), "-2.5");
+    }
+}
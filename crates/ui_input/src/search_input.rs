@@ -0,0 +1,176 @@
+use std::{ops::Range, time::Duration};
+
+use editor::*;
+use fuzzy::StringMatch;
+use gpui::*;
+use ui::{Tooltip, *};
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+pub enum SearchInputEvent {
+    /// The query changed, and didn't change again for the duration of the debounce.
+    QueryChanged(String),
+}
+
+/// A search field with a magnifier icon and a clear button.
+///
+/// Edits to the query are debounced, and reported as [`SearchInputEvent::QueryChanged`].
+/// Pressing Escape clears a non-empty query, and otherwise lets the event propagate,
+/// e.g. to dismiss the surrounding modal.
+pub struct SearchInput {
+    editor: View<Editor>,
+    debounce: Duration,
+    /// The last query reported to subscribers.
+    query: String,
+    pending_query_change: Option<Task<()>>,
+    _subscription: Subscription,
+}
+
+impl EventEmitter<SearchInputEvent> for SearchInput {}
+
+impl FocusableView for SearchInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.editor.focus_handle(cx)
+    }
+}
+
+impl SearchInput {
+    pub fn new(placeholder: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let placeholder = placeholder.into();
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text(placeholder, cx);
+            editor
+        });
+        let subscription = cx.subscribe(&editor, Self::on_editor_event);
+
+        Self {
+            editor,
+            debounce: DEFAULT_DEBOUNCE,
+            query: String::new(),
+            pending_query_change: None,
+            _subscription: subscription,
+        }
+    }
+
+    /// Sets how long the query must stay unchanged before it is reported. Defaults to 50ms.
+    pub fn set_debounce(&mut self, debounce: Duration) {
+        self.debounce = debounce;
+    }
+
+    pub fn query(&self, cx: &AppContext) -> String {
+        self.editor.read(cx).text(cx)
+    }
+
+    pub fn set_query(&mut self, query: &str, cx: &mut ViewContext<Self>) {
+        self.editor
+            .update(cx, |editor, cx| editor.set_text(query, cx));
+    }
+
+    pub fn clear(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_query("", cx);
+    }
+
+    pub fn editor(&self) -> &View<Editor> {
+        &self.editor
+    }
+
+    fn on_editor_event(
+        &mut self,
+        _: View<Editor>,
+        event: &EditorEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let EditorEvent::BufferEdited = event {
+            self.schedule_query_change(cx);
+            cx.notify();
+        }
+    }
+
+    fn schedule_query_change(&mut self, cx: &mut ViewContext<Self>) {
+        let debounce = self.debounce;
+        self.pending_query_change = Some(cx.spawn(|this, mut cx| async move {
+            if !debounce.is_zero() {
+                cx.background_executor().timer(debounce).await;
+            }
+            this.update(&mut cx, |this, cx| {
+                this.pending_query_change = None;
+                let query = this.query(cx);
+                if query != this.query {
+                    this.query = query.clone();
+                    cx.emit(SearchInputEvent::QueryChanged(query));
+                }
+            })
+            .ok();
+        }));
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        if self.query(cx).is_empty() {
+            cx.propagate();
+        } else {
+            self.clear(cx);
+        }
+    }
+}
+
+impl Render for SearchInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_empty = self.query(cx).is_empty();
+
+        h_flex()
+            .key_context("SearchInput")
+            .on_action(cx.listener(Self::cancel))
+            .w_full()
+            .gap_1()
+            .px(Spacing::Large.rems(cx))
+            .py(Spacing::Small.rems(cx))
+            .rounded_lg()
+            .border_1()
+            .border_color(cx.theme().colors().border)
+            .bg(cx.theme().colors().editor_background)
+            .child(
+                Icon::new(IconName::MagnifyingGlass)
+                    .size(IconSize::Small)
+                    .color(Color::Muted),
+            )
+            .child(div().flex_1().child(self.editor.clone()))
+            .when(!is_empty, |this| {
+                this.child(
+                    IconButton::new("clear-search", IconName::XCircle)
+                        .icon_size(IconSize::Small)
+                        .icon_color(Color::Muted)
+                        .tooltip(|cx| Tooltip::text("Clear", cx))
+                        .on_click(cx.listener(|this, _, cx| this.clear(cx))),
+                )
+            })
+    }
+}
+
+/// Returns the match positions that fall within `range`, relative to its start.
+///
+/// This is useful when a match was computed against a longer string than the one
+/// displayed, e.g. a full path of which only the file name is shown.
+pub fn match_positions_in_range(positions: &[usize], range: Range<usize>) -> Vec<usize> {
+    positions
+        .iter()
+        .filter(|position| range.contains(position))
+        .map(|position| position - range.start)
+        .collect()
+}
+
+/// Returns a label for a fuzzy match, highlighting the matched characters.
+pub fn highlighted_match_label(string_match: &StringMatch) -> HighlightedLabel {
+    HighlightedLabel::from_fuzzy_match(string_match.string.clone(), string_match)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_positions_in_range() {
+        assert_eq!(match_positions_in_range(&[0, 4, 5, 9], 4..8), vec![0, 1]);
+        assert_eq!(match_positions_in_range(&[0, 1], 2..4), Vec::<usize>::new());
+    }
+}
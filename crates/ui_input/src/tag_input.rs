@@ -0,0 +1,354 @@
+use std::rc::Rc;
+
+use editor::{actions::Backspace, actions::MoveDown, actions::MoveUp, *};
+use gpui::*;
+use ui::{Tooltip, *};
+
+/// The most suggestions shown below a [`TagInput`] at once.
+const MAX_SUGGESTIONS: usize = 8;
+
+pub enum TagInputEvent {
+    /// A tag was added, either typed or picked from the suggestions.
+    Added(SharedString),
+    /// A tag was removed, with its remove button or with Backspace.
+    Removed(SharedString),
+}
+
+/// Returns the candidates containing the query, ignoring case, with the ones
+/// starting with it first. Tags already added are left out.
+fn filter_suggestions(
+    query: &str,
+    candidates: Vec<SharedString>,
+    tags: &[SharedString],
+) -> Vec<SharedString> {
+    let query = query.trim().to_lowercase();
+    let mut matches = candidates
+        .into_iter()
+        .filter(|candidate| !tags.contains(candidate))
+        .filter_map(|candidate| {
+            let position = candidate.to_lowercase().find(&query)?;
+            Some((position != 0, candidate))
+        })
+        .collect::<Vec<_>>();
+    // The sort is stable, so the candidates keep the order of the source otherwise.
+    matches.sort_by_key(|(is_inner_match, _)| *is_inner_match);
+    matches
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// A field holding a list of tokens, like labels, reviewers or file types.
+///
+/// Typing and pressing Enter, or typing a comma, adds the text as a tag. While
+/// typing, suggestions from the [`TagInput::set_suggestion_source`] are shown below
+/// the field and can be picked with the arrow keys. Tags render as removable chips
+/// that wrap onto more lines as needed, and Backspace in the empty field removes
+/// the last one.
+pub struct TagInput {
+    editor: View<Editor>,
+    tags: Vec<SharedString>,
+    suggestion_source: Option<Rc<dyn Fn(&str, &AppContext) -> Vec<SharedString>>>,
+    suggestions: Vec<SharedString>,
+    selected_suggestion: Option<usize>,
+    allow_custom_tags: bool,
+    _subscription: Subscription,
+}
+
+impl EventEmitter<TagInputEvent> for TagInput {}
+
+impl FocusableView for TagInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.editor.focus_handle(cx)
+    }
+}
+
+impl TagInput {
+    pub fn new(placeholder: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let placeholder = placeholder.into();
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text(placeholder, cx);
+            editor
+        });
+        let subscription = cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+            if let EditorEvent::BufferEdited = event {
+                this.query_changed(cx);
+            }
+        });
+
+        Self {
+            editor,
+            tags: Vec::new(),
+            suggestion_source: None,
+            suggestions: Vec::new(),
+            selected_suggestion: None,
+            allow_custom_tags: true,
+            _subscription: subscription,
+        }
+    }
+
+    /// Sets the function returning the tags to suggest for the typed text. The
+    /// candidates it returns are filtered by the text, so it may return all of them.
+    pub fn set_suggestion_source(
+        &mut self,
+        source: impl Fn(&str, &AppContext) -> Vec<SharedString> + 'static,
+    ) {
+        self.suggestion_source = Some(Rc::new(source));
+    }
+
+    /// Sets whether tags can be added that aren't suggested. Defaults to `true`.
+    pub fn set_allow_custom_tags(&mut self, allow_custom_tags: bool) {
+        self.allow_custom_tags = allow_custom_tags;
+    }
+
+    pub fn tags(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    /// Replaces the tags, without reporting it.
+    pub fn set_tags(&mut self, tags: Vec<SharedString>, cx: &mut ViewContext<Self>) {
+        self.tags = tags;
+        cx.notify();
+    }
+
+    pub fn editor(&self) -> &View<Editor> {
+        &self.editor
+    }
+
+    /// Adds a tag, unless it's empty or already added.
+    pub fn add_tag(&mut self, tag: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let tag: SharedString = tag.into().trim().to_string().into();
+        if tag.is_empty() || self.tags.contains(&tag) {
+            return;
+        }
+        self.tags.push(tag.clone());
+        cx.emit(TagInputEvent::Added(tag));
+        cx.notify();
+    }
+
+    pub fn remove_tag(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix < self.tags.len() {
+            let tag = self.tags.remove(ix);
+            cx.emit(TagInputEvent::Removed(tag));
+            cx.notify();
+        }
+    }
+
+    fn query(&self, cx: &AppContext) -> String {
+        self.editor.read(cx).text(cx)
+    }
+
+    fn clear_query(&mut self, cx: &mut ViewContext<Self>) {
+        self.editor.update(cx, |editor, cx| editor.clear(cx));
+        self.suggestions.clear();
+        self.selected_suggestion = None;
+    }
+
+    fn query_changed(&mut self, cx: &mut ViewContext<Self>) {
+        let query = self.query(cx);
+        if let Some((tags, rest)) = query.rsplit_once(',') {
+            let tags = tags.split(',').map(str::to_string).collect::<Vec<_>>();
+            for tag in tags {
+                self.add_typed_tag(&tag, cx);
+            }
+            let rest = rest.to_string();
+            self.editor
+                .update(cx, |editor, cx| editor.set_text(rest, cx));
+            return;
+        }
+
+        self.suggestions = match (&self.suggestion_source, query.trim().is_empty()) {
+            (Some(source), false) => filter_suggestions(&query, source(&query, cx), &self.tags),
+            _ => Vec::new(),
+        };
+        self.selected_suggestion = (!self.suggestions.is_empty()).then_some(0);
+        cx.notify();
+    }
+
+    /// Adds typed text as a tag, matching it to a suggestion when only those are
+    /// allowed.
+    fn add_typed_tag(&mut self, text: &str, cx: &mut ViewContext<Self>) {
+        if self.allow_custom_tags {
+            self.add_tag(text.to_string(), cx);
+        } else if let Some(source) = self.suggestion_source.clone() {
+            let text = text.trim();
+            if let Some(tag) = source(text, cx)
+                .into_iter()
+                .find(|candidate| candidate.eq_ignore_ascii_case(text))
+            {
+                self.add_tag(tag, cx);
+            }
+        }
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        let suggestion = self
+            .selected_suggestion
+            .and_then(|ix| self.suggestions.get(ix).cloned());
+        match suggestion {
+            Some(suggestion) => self.add_tag(suggestion, cx),
+            None => {
+                let query = self.query(cx);
+                if query.trim().is_empty() {
+                    cx.propagate();
+                    return;
+                }
+                self.add_typed_tag(&query, cx);
+            }
+        }
+        self.clear_query(cx);
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        if self.suggestions.is_empty() {
+            cx.propagate();
+        } else {
+            self.suggestions.clear();
+            self.selected_suggestion = None;
+            cx.notify();
+        }
+    }
+
+    fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if self.query(cx).is_empty() && !self.tags.is_empty() {
+            self.remove_tag(self.tags.len() - 1, cx);
+            cx.stop_propagation();
+        }
+    }
+
+    fn select_next(&mut self, _: &MoveDown, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.selected_suggestion {
+            self.selected_suggestion = Some((ix + 1) % self.suggestions.len());
+            cx.notify();
+        }
+    }
+
+    fn select_prev(&mut self, _: &MoveUp, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.selected_suggestion {
+            let count = self.suggestions.len();
+            self.selected_suggestion = Some((ix + count - 1) % count);
+            cx.notify();
+        }
+    }
+
+    fn render_tag(&self, ix: usize, tag: &SharedString, cx: &ViewContext<Self>) -> Div {
+        h_flex()
+            .gap_0p5()
+            .pl_1()
+            .rounded_sm()
+            .border_1()
+            .border_color(cx.theme().colors().border_variant)
+            .bg(cx.theme().colors().element_background)
+            .child(Label::new(tag.clone()).size(LabelSize::Small))
+            .child(
+                IconButton::new(("remove-tag", ix), IconName::Close)
+                    .icon_size(IconSize::XSmall)
+                    .icon_color(Color::Muted)
+                    .tooltip(|cx| Tooltip::text("Remove", cx))
+                    .on_click(cx.listener(move |this, _, cx| this.remove_tag(ix, cx))),
+            )
+    }
+
+    fn render_suggestions(&self, cx: &ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.suggestions.is_empty() {
+            return None;
+        }
+        Some(
+            v_flex()
+                .id("tag-suggestions")
+                .min_w(px(160.))
+                .p_1()
+                .elevation_2(cx)
+                .children(self.suggestions.iter().enumerate().map(|(ix, suggestion)| {
+                    let suggestion = suggestion.clone();
+                    ListItem::new(ix)
+                        .inset(true)
+                        .selected(self.selected_suggestion == Some(ix))
+                        .child(Label::new(suggestion.clone()))
+                        .on_click(cx.listener(move |this, _, cx| {
+                            this.add_tag(suggestion.clone(), cx);
+                            this.clear_query(cx);
+                        }))
+                })),
+        )
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let border_color = if self.editor.focus_handle(cx).is_focused(cx) {
+            cx.theme().colors().border_focused
+        } else {
+            cx.theme().colors().border
+        };
+
+        let field = h_flex()
+            .key_context("TagInput")
+            .capture_action(cx.listener(Self::backspace))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::select_prev))
+            .w_full()
+            .flex_wrap()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .rounded_md()
+            .border_1()
+            .border_color(border_color)
+            .bg(cx.theme().colors().editor_background)
+            .children(
+                self.tags
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, tag)| self.render_tag(ix, tag, cx)),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(rems(6.))
+                    .px_1()
+                    .child(self.editor.clone()),
+            );
+
+        let this = cx.view().downgrade();
+        AnchoredPopover::new()
+            .trigger(field)
+            .content(self.render_suggestions(cx))
+            .placement(PopoverPlacement::bottom_start())
+            .on_dismiss(move |cx| {
+                this.update(cx, |this, cx| {
+                    this.suggestions.clear();
+                    this.selected_suggestion = None;
+                    cx.notify();
+                })
+                .ok();
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&'static str]) -> Vec<SharedString> {
+        items.iter().map(|item| SharedString::from(*item)).collect()
+    }
+
+    #[test]
+    fn test_filter_suggestions() {
+        let candidates = strings(&["bug", "debug", "docs", "Build"]);
+        assert_eq!(
+            filter_suggestions("bu", candidates.clone(), &[]),
+            strings(&["bug", "Build", "debug"])
+        );
+        assert_eq!(
+            filter_suggestions("BU", candidates.clone(), &strings(&["bug"])),
+            strings(&["Build", "debug"])
+        );
+        assert_eq!(filter_suggestions("x", candidates, &[]), strings(&[]));
+    }
+}
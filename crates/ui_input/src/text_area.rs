@@ -0,0 +1,255 @@
+use editor::*;
+use gpui::*;
+use ui::*;
+
+/// The fraction of a limit from which the counter is shown as a warning.
+const WARNING_THRESHOLD: f32 = 0.9;
+
+pub enum TextAreaEvent {
+    /// The text was submitted, with the secondary confirm binding, while within its limits.
+    Submitted(String),
+}
+
+/// How close a count is to its limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CounterState {
+    Normal,
+    /// The count is close to the limit.
+    Warning,
+    /// The count is past the limit.
+    Exceeded,
+}
+
+impl CounterState {
+    pub fn for_count(count: usize, limit: Option<usize>) -> Self {
+        match limit {
+            Some(limit) if count > limit => CounterState::Exceeded,
+            Some(limit) if count as f32 >= limit as f32 * WARNING_THRESHOLD => {
+                CounterState::Warning
+            }
+            _ => CounterState::Normal,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            CounterState::Normal => Color::Muted,
+            CounterState::Warning => Color::Warning,
+            CounterState::Exceeded => Color::Error,
+        }
+    }
+}
+
+/// A multi-line text field, e.g. for commit messages and feedback forms, with an
+/// optional footer counting characters and lines against their limits.
+///
+/// The counter turns to warning colors near a limit and to error colors past it.
+/// Submitting, with the secondary confirm binding, is blocked while a limit is
+/// exceeded.
+pub struct TextArea {
+    editor: View<Editor>,
+    char_limit: Option<usize>,
+    line_limit: Option<usize>,
+    show_counter: bool,
+    _subscription: Subscription,
+}
+
+impl EventEmitter<TextAreaEvent> for TextArea {}
+
+impl FocusableView for TextArea {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.editor.focus_handle(cx)
+    }
+}
+
+impl TextArea {
+    pub fn new(
+        placeholder: impl Into<SharedString>,
+        max_lines: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let placeholder = placeholder.into();
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::auto_height(max_lines, cx);
+            editor.set_placeholder_text(placeholder, cx);
+            editor
+        });
+        let subscription = cx.subscribe(&editor, |_, _, event: &EditorEvent, cx| {
+            if let EditorEvent::BufferEdited = event {
+                cx.notify();
+            }
+        });
+
+        Self {
+            editor,
+            char_limit: None,
+            line_limit: None,
+            show_counter: false,
+            _subscription: subscription,
+        }
+    }
+
+    /// Limits the number of characters, showing the counter.
+    pub fn char_limit(mut self, limit: usize) -> Self {
+        self.char_limit = Some(limit);
+        self.show_counter = true;
+        self
+    }
+
+    /// Limits the number of lines, showing the counter.
+    pub fn line_limit(mut self, limit: usize) -> Self {
+        self.line_limit = Some(limit);
+        self.show_counter = true;
+        self
+    }
+
+    /// Shows the counter, even without limits.
+    pub fn show_counter(mut self, show_counter: bool) -> Self {
+        self.show_counter = show_counter;
+        self
+    }
+
+    pub fn text(&self, cx: &AppContext) -> String {
+        self.editor.read(cx).text(cx)
+    }
+
+    pub fn set_text(&mut self, text: &str, cx: &mut ViewContext<Self>) {
+        self.editor
+            .update(cx, |editor, cx| editor.set_text(text, cx));
+    }
+
+    pub fn editor(&self) -> &View<Editor> {
+        &self.editor
+    }
+
+    fn counter_states(&self, text: &str) -> (usize, usize, CounterState, CounterState) {
+        counter_states(text, self.char_limit, self.line_limit)
+    }
+
+    /// Whether the text is within its limits, and so can be submitted.
+    pub fn can_submit(&self, cx: &AppContext) -> bool {
+        let (_, _, chars, lines) = self.counter_states(&self.text(cx));
+        chars.max(lines) != CounterState::Exceeded
+    }
+
+    fn submit(&mut self, _: &menu::SecondaryConfirm, cx: &mut ViewContext<Self>) {
+        if self.can_submit(cx) {
+            cx.emit(TextAreaEvent::Submitted(self.text(cx)));
+        }
+    }
+
+    fn render_counter(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (chars, lines, chars_state, lines_state) = self.counter_states(&self.text(cx));
+        let count_label = |count: usize, limit: Option<usize>, unit: &str| match limit {
+            Some(limit) => format!("{count}/{limit} {unit}"),
+            None => format!("{count} {unit}"),
+        };
+
+        h_flex()
+            .w_full()
+            .justify_end()
+            .gap_2()
+            .child(
+                Label::new(count_label(chars, self.char_limit, "characters"))
+                    .size(LabelSize::XSmall)
+                    .color(chars_state.color()),
+            )
+            .when(self.line_limit.is_some(), |this| {
+                this.child(
+                    Label::new(count_label(lines, self.line_limit, "lines"))
+                        .size(LabelSize::XSmall)
+                        .color(lines_state.color()),
+                )
+            })
+    }
+}
+
+impl Render for TextArea {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (_, _, chars_state, lines_state) = self.counter_states(&self.text(cx));
+        let border_color = match chars_state.max(lines_state) {
+            CounterState::Exceeded => cx.theme().status().error_border,
+            _ => cx.theme().colors().border,
+        };
+
+        v_flex()
+            .key_context("TextArea")
+            .on_action(cx.listener(Self::submit))
+            .w_full()
+            .gap_1()
+            .child(
+                div()
+                    .w_full()
+                    .px(Spacing::Large.rems(cx))
+                    .py(Spacing::Small.rems(cx))
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(border_color)
+                    .bg(cx.theme().colors().editor_background)
+                    .child(self.editor.clone()),
+            )
+            .when(self.show_counter, |this| {
+                this.child(self.render_counter(cx))
+            })
+    }
+}
+
+/// Counts the characters and lines of the text, a trailing newline starting a new line
+/// as it does in the editor, along with their states against the limits.
+fn counter_states(
+    text: &str,
+    char_limit: Option<usize>,
+    line_limit: Option<usize>,
+) -> (usize, usize, CounterState, CounterState) {
+    let chars = text.chars().count();
+    let lines = text.split('\n').count();
+    (
+        chars,
+        lines,
+        CounterState::for_count(chars, char_limit),
+        CounterState::for_count(lines, line_limit),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_state() {
+        assert_eq!(CounterState::for_count(10, None), CounterState::Normal);
+        assert_eq!(CounterState::for_count(80, Some(100)), CounterState::Normal);
+        assert_eq!(
+            CounterState::for_count(90, Some(100)),
+            CounterState::Warning
+        );
+        assert_eq!(
+            CounterState::for_count(100, Some(100)),
+            CounterState::Warning
+        );
+        assert_eq!(
+            CounterState::for_count(101, Some(100)),
+            CounterState::Exceeded
+        );
+    }
+
+    #[test]
+    fn test_counter_states() {
+        assert_eq!(
+            counter_states("", None, None),
+            (0, 1, CounterState::Normal, CounterState::Normal)
+        );
+        assert_eq!(
+            counter_states("abc", Some(3), Some(1)),
+            (3, 1, CounterState::Warning, CounterState::Warning)
+        );
+        assert_eq!(
+            counter_states("abc\n", Some(10), Some(1)),
+            (4, 2, CounterState::Normal, CounterState::Exceeded)
+        );
+        assert_eq!(
+            counter_states("a\nb\nc", Some(4), Some(3)),
+            (5, 3, CounterState::Exceeded, CounterState::Warning)
+        );
+    }
+}
@@ -5,12 +5,28 @@
 //! It can't be located in the `ui` crate because it depends on `editor`.
 //!
 
+mod color_picker;
+mod editable_label;
+mod new_item_row;
+mod number_input;
+mod search_input;
+mod tag_input;
+mod text_area;
+
 use editor::*;
 use gpui::*;
 use settings::Settings;
 use theme::ThemeSettings;
 use ui::*;
 
+pub use color_picker::*;
+pub use editable_label::*;
+pub use new_item_row::*;
+pub use number_input::*;
+pub use search_input::*;
+pub use tag_input::*;
+pub use text_area::*;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FieldLabelLayout {
     Hidden,
@@ -165,8 +181,8 @@ impl Render for TextField {
                         })
                         .child(
                             h_flex()
-                                .px_2()
-                                .py_1()
+                                .px(Spacing::Large.rems(cx))
+                                .py(Spacing::Small.rems(cx))
                                 .bg(style.background_color)
                                 .text_color(style.text_color)
                                 .rounded_lg()
@@ -0,0 +1,539 @@
+use client::{telemetry::Telemetry, TelemetrySettings};
+use db::kvp::KEY_VALUE_STORE;
+use fs::Fs;
+use gpui::{
+    actions, AnyElement, AppContext, EventEmitter, FocusHandle, FocusableView, Render, Stateful,
+    Subscription, View, ViewContext, WeakView, WindowContext,
+};
+use serde::{Deserialize, Serialize};
+use settings::{Settings, SettingsStore};
+use std::sync::Arc;
+use theme::{Appearance, Elevation, ThemeRegistry, ThemeSettings};
+use ui::{prelude::*, CheckboxWithLabel, Wizard, WizardState, WizardStep};
+use util::ResultExt;
+use workspace::{
+    item::{Item, ItemEvent},
+    Workspace, WorkspaceId,
+};
+
+use crate::{base_keymap_picker, BaseKeymap};
+
+actions!(
+    onboarding,
+    [
+        ShowOnboarding,
+        ChooseTheme,
+        ChooseBaseKeymap,
+        InstallCli,
+        ConfigureTelemetry
+    ]
+);
+
+const ONBOARDING_STATE_KEY: &str = "onboarding_state";
+
+/// The themes offered on the theme page, the ones missing from the registry
+/// being left out.
+const FEATURED_THEMES: &[&str] = &[
+    "One Dark",
+    "One Light",
+    "Ayu Dark",
+    "Ayu Light",
+    "Gruvbox Dark",
+    "Gruvbox Light",
+];
+
+const KEYMAPS: &[(BaseKeymap, &str)] = &[
+    (BaseKeymap::VSCode, "The default keybindings of Zed."),
+    (
+        BaseKeymap::JetBrains,
+        "Keybindings of IntelliJ IDEA, PyCharm and the other JetBrains IDEs.",
+    ),
+    (BaseKeymap::SublimeText, "Keybindings of Sublime Text."),
+];
+
+pub fn init(cx: &mut AppContext) {
+    cx.observe_new_views(|workspace: &mut Workspace, _cx| {
+        workspace
+            .register_action(|workspace, _: &ShowOnboarding, cx| {
+                OnboardingView::open(workspace, None, cx)
+            })
+            .register_action(|workspace, _: &ChooseTheme, cx| {
+                OnboardingView::open(workspace, Some(OnboardingPage::Theme), cx)
+            })
+            .register_action(|workspace, _: &ChooseBaseKeymap, cx| {
+                OnboardingView::open(workspace, Some(OnboardingPage::Keymap), cx)
+            })
+            .register_action(|workspace, _: &InstallCli, cx| {
+                OnboardingView::open(workspace, Some(OnboardingPage::Cli), cx)
+            })
+            .register_action(|workspace, _: &ConfigureTelemetry, cx| {
+                OnboardingView::open(workspace, Some(OnboardingPage::Telemetry), cx)
+            });
+    })
+    .detach();
+}
+
+/// A page of the onboarding flow, in the order they're shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingPage {
+    Theme,
+    Keymap,
+    Cli,
+    Telemetry,
+}
+
+impl OnboardingPage {
+    pub const ALL: [Self; 4] = [Self::Theme, Self::Keymap, Self::Cli, Self::Telemetry];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Theme => "Theme",
+            Self::Keymap => "Keymap",
+            Self::Cli => "CLI",
+            Self::Telemetry => "Telemetry",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|page| *page == self).unwrap()
+    }
+}
+
+/// What is remembered of the onboarding flow across restarts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct OnboardingState {
+    /// The index of the page that was last shown.
+    page: usize,
+    /// Whether the flow was finished once.
+    completed: bool,
+}
+
+impl OnboardingState {
+    fn load() -> Self {
+        KEY_VALUE_STORE
+            .read_kvp(ONBOARDING_STATE_KEY)
+            .log_err()
+            .flatten()
+            .and_then(|state| serde_json::from_str(&state).log_err())
+            .unwrap_or_default()
+    }
+
+    fn save(self, cx: &AppContext) {
+        db::write_and_log(cx, move || async move {
+            KEY_VALUE_STORE
+                .write_kvp(
+                    ONBOARDING_STATE_KEY.to_string(),
+                    serde_json::to_string(&self)?,
+                )
+                .await
+        });
+    }
+}
+
+/// The onboarding flow, walking through the theme, the base keymap, the CLI and
+/// telemetry one page at a time.
+///
+/// The page shown is persisted, so the flow picks up where it was left, and each
+/// page can be opened again from the command palette.
+pub struct OnboardingView {
+    workspace: WeakView<Workspace>,
+    fs: Arc<dyn Fs>,
+    telemetry: Arc<Telemetry>,
+    focus_handle: FocusHandle,
+    wizard: WizardState,
+    _settings_subscription: Subscription,
+}
+
+impl OnboardingView {
+    /// Opens the onboarding flow at `page`, or where it was left, reusing the one
+    /// already open in the workspace.
+    pub fn open(
+        workspace: &mut Workspace,
+        page: Option<OnboardingPage>,
+        cx: &mut ViewContext<Workspace>,
+    ) {
+        let view = match workspace.item_of_type::<Self>(cx) {
+            Some(view) => {
+                workspace.activate_item(&view, true, true, cx);
+                view
+            }
+            None => {
+                let view = Self::new(workspace, cx);
+                workspace.add_item_to_active_pane(Box::new(view.clone()), None, true, cx);
+                view
+            }
+        };
+        if let Some(page) = page {
+            view.update(cx, |view, cx| view.show_page(page, cx));
+        }
+    }
+
+    pub fn new(workspace: &Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
+        let fs = workspace.app_state().fs.clone();
+        let telemetry = workspace.client().telemetry().clone();
+        let workspace = workspace.weak_handle();
+        cx.new_view(|cx| {
+            let wizard = WizardState::new();
+            wizard.set_current_step(
+                OnboardingState::load()
+                    .page
+                    .min(OnboardingPage::ALL.len() - 1),
+            );
+            Self {
+                workspace,
+                fs,
+                telemetry,
+                focus_handle: cx.focus_handle(),
+                wizard,
+                _settings_subscription: cx.observe_global::<SettingsStore>(|_, cx| cx.notify()),
+            }
+        })
+    }
+
+    fn show_page(&mut self, page: OnboardingPage, cx: &mut ViewContext<Self>) {
+        self.wizard.set_current_step(page.index());
+        OnboardingState {
+            page: page.index(),
+            ..OnboardingState::load()
+        }
+        .save(cx);
+        cx.notify();
+    }
+
+    fn finish(&mut self, cx: &mut ViewContext<Self>) {
+        self.telemetry
+            .report_app_event("onboarding: finish".to_string());
+        OnboardingState {
+            page: 0,
+            completed: true,
+        }
+        .save(cx);
+        cx.emit(ItemEvent::CloseItem);
+    }
+
+    /// A clickable card, outlined with the focus color while `selected`.
+    fn render_card(
+        id: impl Into<ElementId>,
+        title: impl Into<SharedString>,
+        selected: bool,
+        cx: &WindowContext,
+    ) -> Stateful<Div> {
+        let style = cx.theme().elevation(Elevation::Surface);
+        let hover = cx.theme().colors().element_hover;
+        let border = if selected {
+            cx.theme().colors().border_focused
+        } else {
+            style.border
+        };
+        v_flex()
+            .id(id)
+            .gap_1()
+            .p_3()
+            .rounded_md()
+            .bg(style.background)
+            .border_1()
+            .border_color(border)
+            .cursor_pointer()
+            .hover(move |style| style.bg(hover))
+            .child(Label::new(title.into()))
+    }
+
+    fn render_theme_page(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let registry = ThemeRegistry::global(cx);
+        let active_theme = cx.theme().name.clone();
+        let themes = FEATURED_THEMES
+            .iter()
+            .filter_map(|name| registry.get(name).ok())
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .gap_3()
+            .child(Label::new("Pick a theme. You can change it any time.").color(Color::Muted))
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .gap_2()
+                    .children(themes.into_iter().map(|theme| {
+                        let name = theme.name.clone();
+                        let colors = theme.colors();
+                        Self::render_card(
+                            SharedString::from(format!("theme-{name}")),
+                            name.clone(),
+                            name == active_theme,
+                            cx,
+                        )
+                        .w_40()
+                        .child(
+                            h_flex()
+                                .mt_1()
+                                .h_6()
+                                .rounded_sm()
+                                .overflow_hidden()
+                                .child(div().flex_1().h_full().bg(colors.editor_background))
+                                .child(div().flex_1().h_full().bg(colors.text_accent))
+                                .child(div().flex_1().h_full().bg(colors.text)),
+                        )
+                        .on_click(cx.listener(move |this, _, cx| {
+                            this.telemetry
+                                .report_setting_event("theme", name.to_string());
+                            let name = name.to_string();
+                            let appearance = Appearance::from(cx.appearance());
+                            settings::update_settings_file::<ThemeSettings>(
+                                this.fs.clone(),
+                                cx,
+                                move |settings, _| settings.set_theme(name, appearance),
+                            );
+                        }))
+                    })),
+            )
+            .child(
+                Button::new("browse-themes", "Browse All Themes").on_click(cx.listener(
+                    |this, _, cx| {
+                        this.workspace
+                            .update(cx, |workspace, cx| {
+                                theme_selector::toggle(workspace, &Default::default(), cx)
+                            })
+                            .ok();
+                    },
+                )),
+            )
+    }
+
+    fn render_keymap_page(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let active_keymap = *BaseKeymap::get_global(cx);
+
+        v_flex()
+            .gap_3()
+            .child(
+                Label::new("Use the keybindings of the editor you're used to.").color(Color::Muted),
+            )
+            .children(KEYMAPS.iter().map(|(keymap, description)| {
+                let keymap = *keymap;
+                Self::render_card(
+                    SharedString::from(format!("keymap-{keymap}")),
+                    keymap.to_string(),
+                    keymap == active_keymap,
+                    cx,
+                )
+                .child(
+                    Label::new(*description)
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+                .on_click(cx.listener(move |this, _, cx| {
+                    this.telemetry
+                        .report_setting_event("keymap", keymap.to_string());
+                    settings::update_settings_file::<BaseKeymap>(
+                        this.fs.clone(),
+                        cx,
+                        move |setting, _| *setting = Some(keymap),
+                    );
+                }))
+            }))
+            .child(
+                Button::new("more-keymaps", "More Keymaps").on_click(cx.listener(|this, _, cx| {
+                    this.workspace
+                        .update(cx, |workspace, cx| {
+                            base_keymap_picker::toggle(workspace, &Default::default(), cx)
+                        })
+                        .ok();
+                })),
+            )
+    }
+
+    fn render_cli_page(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .child(
+                Label::new("Open files and folders in Zed from a terminal with the `zed` command.")
+                    .color(Color::Muted),
+            )
+            .map(|this| {
+                if cfg!(target_os = "macos") {
+                    this.child(
+                        Button::new("install-cli", "Install the CLI").on_click(cx.listener(
+                            |this, _, cx| {
+                                this.telemetry
+                                    .report_app_event("onboarding: install cli".to_string());
+                                cx.app_mut()
+                                    .spawn(|cx| async move { install_cli::install_cli(&cx).await })
+                                    .detach_and_log_err(cx);
+                            },
+                        )),
+                    )
+                } else {
+                    this.child(
+                        Label::new("The CLI is installed along with Zed on this platform.")
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                }
+            })
+    }
+
+    fn render_telemetry_page(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let telemetry_settings = TelemetrySettings::get_global(cx);
+        let selection = |enabled: bool| {
+            if enabled {
+                ui::Selection::Selected
+            } else {
+                ui::Selection::Unselected
+            }
+        };
+
+        v_flex()
+            .gap_3()
+            .child(
+                Label::new("Help improve Zed by sending anonymous usage data and crash reports.")
+                    .color(Color::Muted),
+            )
+            .child(CheckboxWithLabel::new(
+                "onboarding-metrics",
+                Label::new("Send anonymous usage data"),
+                selection(telemetry_settings.metrics),
+                cx.listener(|this, selection, cx| {
+                    this.update_telemetry_setting(
+                        selection,
+                        cx,
+                        "metric telemetry",
+                        |settings, value| settings.metrics = Some(value),
+                    );
+                }),
+            ))
+            .child(CheckboxWithLabel::new(
+                "onboarding-diagnostics",
+                Label::new("Send crash reports"),
+                selection(telemetry_settings.diagnostics),
+                cx.listener(|this, selection, cx| {
+                    this.update_telemetry_setting(
+                        selection,
+                        cx,
+                        "diagnostic telemetry",
+                        |settings, value| settings.diagnostics = Some(value),
+                    );
+                }),
+            ))
+    }
+
+    fn update_telemetry_setting(
+        &mut self,
+        selection: &ui::Selection,
+        cx: &mut ViewContext<Self>,
+        setting_name: &'static str,
+        callback: impl 'static + Send + Fn(&mut <TelemetrySettings as Settings>::FileContent, bool),
+    ) {
+        let value = match selection {
+            ui::Selection::Unselected => false,
+            ui::Selection::Selected => true,
+            _ => return,
+        };
+        self.telemetry
+            .report_setting_event(setting_name, value.to_string());
+        settings::update_settings_file::<TelemetrySettings>(
+            self.fs.clone(),
+            cx,
+            move |settings, _| callback(settings, value),
+        );
+    }
+
+    fn render_page(&self, page: OnboardingPage, cx: &mut ViewContext<Self>) -> AnyElement {
+        match page {
+            OnboardingPage::Theme => self.render_theme_page(cx).into_any_element(),
+            OnboardingPage::Keymap => self.render_keymap_page(cx).into_any_element(),
+            OnboardingPage::Cli => self.render_cli_page(cx).into_any_element(),
+            OnboardingPage::Telemetry => self.render_telemetry_page(cx).into_any_element(),
+        }
+    }
+}
+
+impl Render for OnboardingView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let steps = OnboardingPage::ALL
+            .into_iter()
+            .map(|page| WizardStep::new(page.label(), self.render_page(page, cx)))
+            .collect::<Vec<_>>();
+        let this = cx.view().downgrade();
+
+        h_flex()
+            .size_full()
+            .bg(cx.theme().colors().editor_background)
+            .track_focus(&self.focus_handle)
+            .child(
+                v_flex()
+                    .w(rems(36.))
+                    .mx_auto()
+                    .gap_6()
+                    .child(Headline::new("Set up Zed").size(HeadlineSize::Large))
+                    .child(
+                        Wizard::new("onboarding", &self.wizard)
+                            .steps(steps)
+                            .finish_label("Start Coding")
+                            .on_step_change(|page, cx| {
+                                OnboardingState {
+                                    page,
+                                    ..OnboardingState::load()
+                                }
+                                .save(cx);
+                            })
+                            .on_finish(move |_, cx| {
+                                this.update(cx, |this, cx| this.finish(cx)).ok();
+                            }),
+                    ),
+            )
+    }
+}
+
+impl EventEmitter<ItemEvent> for OnboardingView {}
+
+impl FocusableView for OnboardingView {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Item for OnboardingView {
+    type Event = ItemEvent;
+
+    fn tab_content_text(&self, _cx: &WindowContext) -> Option<SharedString> {
+        Some("Set up Zed".into())
+    }
+
+    fn telemetry_event_text(&self) -> Option<&'static str> {
+        Some("onboarding")
+    }
+
+    fn show_toolbar(&self) -> bool {
+        false
+    }
+
+    fn clone_on_split(
+        &self,
+        _workspace_id: Option<WorkspaceId>,
+        _cx: &mut ViewContext<Self>,
+    ) -> Option<View<Self>> {
+        None
+    }
+
+    fn to_item_events(event: &Self::Event, mut f: impl FnMut(workspace::item::ItemEvent)) {
+        f(*event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onboarding_state_round_trip() {
+        let state = OnboardingState {
+            page: OnboardingPage::Cli.index(),
+            completed: true,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            serde_json::from_str::<OnboardingState>(&json).unwrap(),
+            state
+        );
+        assert_eq!(OnboardingPage::ALL[state.page], OnboardingPage::Cli);
+    }
+}
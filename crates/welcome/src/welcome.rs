@@ -1,6 +1,7 @@
 mod base_keymap_picker;
 mod base_keymap_setting;
 mod multibuffer_hint;
+mod onboarding;
 
 use client::{telemetry::Telemetry, TelemetrySettings};
 use db::kvp::KEY_VALUE_STORE;
@@ -21,6 +22,7 @@ use workspace::{
 
 pub use base_keymap_setting::BaseKeymap;
 pub use multibuffer_hint::*;
+pub use onboarding::*;
 
 actions!(welcome, [ResetHints]);
 
@@ -41,6 +43,7 @@ pub fn init(cx: &mut AppContext) {
     .detach();
 
     base_keymap_picker::init(cx);
+    onboarding::init(cx);
 }
 
 pub fn show_welcome_view(
@@ -89,6 +92,17 @@ impl Render for WelcomePage {
                     .child(
                         v_flex()
                             .gap_2()
+                            .child(
+                                Button::new("set-up-zed", "Set Up Zed")
+                                    .style(ButtonStyle::Filled)
+                                    .full_width()
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.telemetry.report_app_event(
+                                            "welcome page: set up zed".to_string(),
+                                        );
+                                        cx.dispatch_action(Box::new(ShowOnboarding));
+                                    })),
+                            )
                             .child(
                                 Button::new("choose-theme", "Choose Theme")
                                     .full_width()
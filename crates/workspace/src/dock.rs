@@ -161,6 +161,10 @@ impl From<&dyn PanelHandle> for AnyView {
 
 /// A container with a fixed [`DockPosition`] adjacent to a certain widown edge.
 /// Can contain multiple panels and show/hide itself with all contents.
+///
+/// A closed dock isn't collapsed to a strip of panel icons next to the center: its
+/// buttons in the status bar serve as the collapsed form, and can be dragged to
+/// reorder the panels.
 pub struct Dock {
     position: DockPosition,
     panel_entries: Vec<PanelEntry>,
@@ -205,6 +209,9 @@ impl DockPosition {
 
 struct PanelEntry {
     panel: Arc<dyn PanelHandle>,
+    /// Whether the dock zoomed this panel on its behalf, for panels that
+    /// don't keep track of their zoom state themselves.
+    zoomed: bool,
     _subscriptions: [Subscription; 3],
 }
 
@@ -246,10 +253,10 @@ impl Dock {
                 let Some(dock) = dock.upgrade() else {
                     return;
                 };
-                let Some(panel) = dock.read(cx).active_panel() else {
+                let Some(panel) = dock.read(cx).active_panel().cloned() else {
                     return;
                 };
-                if panel.is_zoomed(cx) {
+                if dock.read(cx).is_panel_zoomed(panel.panel_id(), cx) {
                     workspace.zoomed = Some(panel.to_any().downgrade());
                     workspace.zoomed_position = Some(position);
                 } else {
@@ -264,15 +271,11 @@ impl Dock {
         .detach();
 
         cx.observe(&dock, move |workspace, dock, cx| {
-            if dock.read(cx).is_open() {
-                if let Some(panel) = dock.read(cx).active_panel() {
-                    if panel.is_zoomed(cx) {
-                        workspace.zoomed = Some(panel.to_any().downgrade());
-                        workspace.zoomed_position = Some(position);
-                        cx.emit(Event::ZoomChanged);
-                        return;
-                    }
-                }
+            if let Some(panel) = dock.read(cx).zoomed_panel(cx) {
+                workspace.zoomed = Some(panel.to_any().downgrade());
+                workspace.zoomed_position = Some(position);
+                cx.emit(Event::ZoomChanged);
+                return;
             }
             if workspace.zoomed_position == Some(position) {
                 workspace.zoomed = None;
@@ -339,11 +342,15 @@ impl Dock {
     pub fn set_panel_zoomed(&mut self, panel: &AnyView, zoomed: bool, cx: &mut ViewContext<Self>) {
         for entry in &mut self.panel_entries {
             if entry.panel.panel_id() == panel.entity_id() {
+                entry.zoomed = zoomed;
                 if zoomed != entry.panel.is_zoomed(cx) {
                     entry.panel.set_zoomed(zoomed, cx);
                 }
-            } else if entry.panel.is_zoomed(cx) {
-                entry.panel.set_zoomed(false, cx);
+            } else {
+                entry.zoomed = false;
+                if entry.panel.is_zoomed(cx) {
+                    entry.panel.set_zoomed(false, cx);
+                }
             }
         }
 
@@ -352,12 +359,35 @@ impl Dock {
 
     pub fn zoom_out(&mut self, cx: &mut ViewContext<Self>) {
         for entry in &mut self.panel_entries {
+            entry.zoomed = false;
             if entry.panel.is_zoomed(cx) {
                 entry.panel.set_zoomed(false, cx);
             }
         }
     }
 
+    /// Returns whether the given panel is zoomed, either by the panel itself or
+    /// by the dock on its behalf.
+    pub fn is_panel_zoomed(&self, panel_id: EntityId, cx: &WindowContext) -> bool {
+        self.panel_entries
+            .iter()
+            .find(|entry| entry.panel.panel_id() == panel_id)
+            .map_or(false, |entry| entry.zoomed || entry.panel.is_zoomed(cx))
+    }
+
+    /// Zooms the visible panel in, or restores it if it is already zoomed.
+    pub fn toggle_active_panel_zoom(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.visible_panel().cloned() else {
+            return;
+        };
+
+        let zoomed = !self.is_panel_zoomed(panel.panel_id(), cx);
+        self.set_panel_zoomed(&panel.to_any(), zoomed, cx);
+        if zoomed && !panel.focus_handle(cx).contains_focused(cx) {
+            panel.focus_handle(cx).focus(cx);
+        }
+    }
+
     pub(crate) fn add_panel<T: Panel>(
         &mut self,
         panel: View<T>,
@@ -376,8 +406,9 @@ impl Dock {
                         return;
                     }
 
+                    let is_zoomed = this.is_panel_zoomed(panel.entity_id(), cx);
                     let Ok(new_dock) = workspace.update(cx, |workspace, cx| {
-                        if panel.is_zoomed(cx) {
+                        if is_zoomed {
                             workspace.zoomed_position = Some(new_position);
                         }
                         match new_position {
@@ -402,7 +433,11 @@ impl Dock {
                         new_dock.add_panel(panel.clone(), workspace.clone(), cx);
                         if was_visible {
                             new_dock.set_open(true, cx);
-                            new_dock.activate_panel(new_dock.panels_len() - 1, cx);
+                            if let Some(ix) = new_dock.panel_entries.iter().position(|entry| {
+                                entry.panel.panel_id() == Entity::entity_id(&panel)
+                            }) {
+                                new_dock.activate_panel(ix, cx);
+                            }
                         }
                     });
                 }
@@ -458,6 +493,7 @@ impl Dock {
 
         self.panel_entries.push(PanelEntry {
             panel: Arc::new(panel.clone()),
+            zoomed: false,
             _subscriptions: subscriptions,
         });
 
@@ -471,6 +507,7 @@ impl Dock {
 
     pub fn restore_state(&mut self, cx: &mut ViewContext<Self>) -> bool {
         if let Some(serialized) = self.serialized_dock.clone() {
+            self.restore_panel_order(&serialized.panel_order);
             if let Some(active_panel) = serialized.active_panel {
                 if let Some(idx) = self.panel_index_for_persistent_name(active_panel.as_str(), cx) {
                     self.activate_panel(idx, cx);
@@ -478,8 +515,8 @@ impl Dock {
             }
 
             if serialized.zoom {
-                if let Some(panel) = self.active_panel() {
-                    panel.set_zoomed(true, cx)
+                if let Some(panel) = self.active_panel().cloned() {
+                    self.set_panel_zoomed(&panel.to_any(), true, cx)
                 }
             }
             self.set_open(serialized.visible, cx);
@@ -509,6 +546,65 @@ impl Dock {
         }
     }
 
+    /// Moves a panel, and its button, to another index in the dock.
+    pub fn move_panel(&mut self, panel_id: EntityId, to_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(from_ix) = self
+            .panel_entries
+            .iter()
+            .position(|entry| entry.panel.panel_id() == panel_id)
+        else {
+            return;
+        };
+        let active_panel_id = self.active_panel().map(|panel| panel.panel_id());
+
+        let entry = self.panel_entries.remove(from_ix);
+        self.panel_entries
+            .insert(to_ix.min(self.panel_entries.len()), entry);
+        if let Some(active_ix) = self
+            .panel_entries
+            .iter()
+            .position(|entry| Some(entry.panel.panel_id()) == active_panel_id)
+        {
+            self.active_panel_index = active_ix;
+        }
+        // Panels added later, e.g. once their feature loaded, follow the new order.
+        let panel_order = self.panel_order();
+        if let Some(serialized) = self.serialized_dock.as_mut() {
+            serialized.panel_order = panel_order;
+        }
+        cx.notify();
+    }
+
+    /// Sorts the panels by their place in the given persistent names, keeping the
+    /// panels that aren't in it after the others, in the order they were added in.
+    fn restore_panel_order(&mut self, panel_order: &[String]) {
+        if panel_order.is_empty() {
+            return;
+        }
+        let active_panel_id = self.active_panel().map(|panel| panel.panel_id());
+        self.panel_entries.sort_by_key(|entry| {
+            panel_order
+                .iter()
+                .position(|name| name == entry.panel.persistent_name())
+                .unwrap_or(panel_order.len())
+        });
+        if let Some(active_ix) = self
+            .panel_entries
+            .iter()
+            .position(|entry| Some(entry.panel.panel_id()) == active_panel_id)
+        {
+            self.active_panel_index = active_ix;
+        }
+    }
+
+    /// The persistent names of the panels, in the order they are shown in.
+    pub(crate) fn panel_order(&self) -> Vec<String> {
+        self.panel_entries
+            .iter()
+            .map(|entry| entry.panel.persistent_name().to_string())
+            .collect()
+    }
+
     pub fn panels_len(&self) -> usize {
         self.panel_entries.len()
     }
@@ -547,7 +643,7 @@ impl Dock {
 
     pub fn zoomed_panel(&self, cx: &WindowContext) -> Option<Arc<dyn PanelHandle>> {
         let entry = self.visible_entry()?;
-        if entry.panel.is_zoomed(cx) {
+        if entry.zoomed || entry.panel.is_zoomed(cx) {
             Some(entry.panel.clone())
         } else {
             None
@@ -699,6 +795,53 @@ impl PanelButtons {
         cx.observe(&dock, |_, _, cx| cx.notify()).detach();
         Self { dock }
     }
+
+    /// Moves a dragged panel to the place of the button it was dropped on, or to the
+    /// dock of these buttons when it was dragged from another dock.
+    fn drop_panel(
+        &mut self,
+        dragged: &DraggedPanel,
+        target_ix: Option<usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let position = self.dock.read(cx).position;
+        if dragged.position == position {
+            if let Some(target_ix) = target_ix {
+                let panel_id = dragged.panel.panel_id();
+                self.dock
+                    .update(cx, |dock, cx| dock.move_panel(panel_id, target_ix, cx));
+            }
+        } else if dragged.panel.position_is_valid(position, cx) {
+            dragged.panel.set_position(position, cx);
+        }
+    }
+}
+
+/// A panel button dragged to another place among the buttons of its dock, or to
+/// the buttons of another dock.
+#[derive(Clone)]
+struct DraggedPanel {
+    panel: Arc<dyn PanelHandle>,
+    icon: IconName,
+    position: DockPosition,
+}
+
+impl DraggedPanel {
+    fn can_drop_at(&self, position: DockPosition, cx: &WindowContext) -> bool {
+        self.position == position || self.panel.position_is_valid(position, cx)
+    }
+}
+
+impl Render for DraggedPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .p_1()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().colors().border)
+            .bg(cx.theme().colors().elevated_surface_background)
+            .child(Icon::new(self.icon).size(IconSize::Small))
+    }
 }
 
 impl Render for PanelButtons {
@@ -739,51 +882,87 @@ impl Render for PanelButtons {
                     (action, icon_tooltip.into())
                 };
 
+                let dragged_panel = DraggedPanel {
+                    panel: panel.clone(),
+                    icon,
+                    position: dock_position,
+                };
+
                 Some(
-                    right_click_menu(name)
-                        .menu(move |cx| {
-                            const POSITIONS: [DockPosition; 3] = [
-                                DockPosition::Left,
-                                DockPosition::Right,
-                                DockPosition::Bottom,
-                            ];
-
-                            ContextMenu::build(cx, |mut menu, cx| {
-                                for position in POSITIONS {
-                                    if position != dock_position
-                                        && panel.position_is_valid(position, cx)
-                                    {
-                                        let panel = panel.clone();
-                                        menu = menu.entry(
-                                            format!("Dock {}", position.label()),
-                                            None,
-                                            move |cx| {
-                                                panel.set_position(position, cx);
-                                            },
-                                        )
-                                    }
-                                }
-                                menu
-                            })
+                    div()
+                        .id(("panel-button", i))
+                        .on_drag(dragged_panel, |dragged, cx| {
+                            cx.new_view(|_| dragged.clone())
+                        })
+                        .drag_over::<DraggedPanel>(move |style, dragged, cx| {
+                            if dragged.can_drop_at(dock_position, cx) {
+                                style.bg(cx.theme().colors().drop_target_background)
+                            } else {
+                                style
+                            }
                         })
-                        .anchor(menu_anchor)
-                        .attach(menu_attach)
-                        .trigger(
-                            IconButton::new(name, icon)
-                                .icon_size(IconSize::Small)
-                                .selected(is_active_button)
-                                .on_click({
-                                    let action = action.boxed_clone();
-                                    move |_, cx| cx.dispatch_action(action.boxed_clone())
+                        .on_drop(cx.listener(move |this, dragged: &DraggedPanel, cx| {
+                            this.drop_panel(dragged, Some(i), cx)
+                        }))
+                        .child(
+                            right_click_menu(name)
+                                .menu(move |cx| {
+                                    const POSITIONS: [DockPosition; 3] = [
+                                        DockPosition::Left,
+                                        DockPosition::Right,
+                                        DockPosition::Bottom,
+                                    ];
+
+                                    ContextMenu::build(cx, |mut menu, cx| {
+                                        for position in POSITIONS {
+                                            if position != dock_position
+                                                && panel.position_is_valid(position, cx)
+                                            {
+                                                let panel = panel.clone();
+                                                menu = menu.entry(
+                                                    format!("Dock {}", position.label()),
+                                                    None,
+                                                    move |cx| {
+                                                        panel.set_position(position, cx);
+                                                    },
+                                                )
+                                            }
+                                        }
+                                        menu
+                                    })
                                 })
-                                .tooltip(move |cx| {
-                                    Tooltip::for_action(tooltip.clone(), &*action, cx)
-                                }),
+                                .anchor(menu_anchor)
+                                .attach(menu_attach)
+                                .trigger(
+                                    IconButton::new(name, icon)
+                                        .icon_size(IconSize::Small)
+                                        .selected(is_active_button)
+                                        .on_click({
+                                            let action = action.boxed_clone();
+                                            move |_, cx| cx.dispatch_action(action.boxed_clone())
+                                        })
+                                        .tooltip(move |cx| {
+                                            Tooltip::for_action(tooltip.clone(), &*action, cx)
+                                        }),
+                                ),
                         ),
                 )
             });
 
-        h_flex().gap_0p5().children(buttons)
+        h_flex()
+            .id("panel-buttons")
+            .gap_0p5()
+            .drag_over::<DraggedPanel>(move |style, dragged, cx| {
+                if dragged.position != dock_position && dragged.can_drop_at(dock_position, cx) {
+                    style.bg(cx.theme().colors().drop_target_background)
+                } else {
+                    style
+                }
+            })
+            .on_drop(
+                cx.listener(|this, dragged: &DraggedPanel, cx| this.drop_panel(dragged, None, cx)),
+            )
+            .children(buttons)
     }
 }
 
@@ -888,3 +1067,63 @@ pub mod test {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fs::FakeFs;
+    use gpui::{TestAppContext, VisualTestContext};
+    use project::Project;
+
+    use super::{test::TestPanel, *};
+    use crate::tests::init_test;
+
+    fn panel_ids(dock: &View<Dock>, cx: &mut VisualTestContext) -> Vec<EntityId> {
+        dock.read_with(cx, |dock, _| {
+            dock.panel_entries
+                .iter()
+                .map(|entry| entry.panel.panel_id())
+                .collect()
+        })
+    }
+
+    #[gpui::test]
+    async fn test_move_panel(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        let (dock, [a, b, c]) = workspace.update(cx, |workspace, cx| {
+            let panels = [(); 3].map(|_| {
+                let panel = cx.new_view(|cx| TestPanel::new(DockPosition::Left, cx));
+                workspace.add_panel(panel.clone(), cx);
+                panel.entity_id()
+            });
+            (workspace.left_dock().clone(), panels)
+        });
+        dock.update(cx, |dock, cx| dock.activate_panel(0, cx));
+        assert_eq!(panel_ids(&dock, cx), [a, b, c]);
+
+        // Moving to the last position, with the index of the last button.
+        dock.update(cx, |dock, cx| dock.move_panel(a, 2, cx));
+        assert_eq!(panel_ids(&dock, cx), [b, c, a]);
+        dock.read_with(cx, |dock, _| {
+            assert_eq!(dock.active_panel().unwrap().panel_id(), a);
+            assert_eq!(dock.active_panel_index, 2);
+        });
+
+        // Moving to the same position keeps the order.
+        dock.update(cx, |dock, cx| dock.move_panel(c, 1, cx));
+        assert_eq!(panel_ids(&dock, cx), [b, c, a]);
+
+        // Moving to the first position, and past the end.
+        dock.update(cx, |dock, cx| dock.move_panel(a, 0, cx));
+        assert_eq!(panel_ids(&dock, cx), [a, b, c]);
+        dock.update(cx, |dock, cx| dock.move_panel(b, 5, cx));
+        assert_eq!(panel_ids(&dock, cx), [a, c, b]);
+        dock.read_with(cx, |dock, _| {
+            assert_eq!(dock.active_panel().unwrap().panel_id(), a);
+            assert_eq!(dock.active_panel_index, 0);
+        });
+    }
+}
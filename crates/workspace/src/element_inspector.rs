@@ -0,0 +1,132 @@
+use gpui::{actions, AnyElement, InspectedElement, Render, ViewContext};
+use ui::prelude::*;
+
+use crate::performance_hud::short_type_name;
+
+actions!(debug, [ToggleInspector]);
+
+/// A panel describing the element last clicked while the element inspector is
+/// enabled: the components it was rendered by and the theme tokens they
+/// resolved, the source location of its builder call, and its bounds.
+///
+/// The element inspector is enabled on the window while the panel is shown.
+pub struct InspectorPanel;
+
+impl InspectorPanel {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        cx.set_inspector_enabled(true);
+        Self
+    }
+}
+
+fn render_property(name: &'static str, value: String) -> impl IntoElement {
+    h_flex()
+        .gap_2()
+        .child(Label::new(name).size(LabelSize::XSmall).color(Color::Muted))
+        .child(Label::new(value).size(LabelSize::XSmall).single_line())
+}
+
+fn render_element(element: InspectedElement, cx: &WindowContext) -> Vec<AnyElement> {
+    let border_color = cx.theme().colors().border;
+    let bounds = element.bounds;
+    let padding = element.padding;
+    let mut children = vec![
+        render_property(
+            "Source",
+            element.source_location.map_or_else(
+                || "only tracked in debug builds".into(),
+                |location| {
+                    format!(
+                        "{}:{}:{}",
+                        location.file(),
+                        location.line(),
+                        location.column()
+                    )
+                },
+            ),
+        )
+        .into_any_element(),
+        render_property(
+            "Bounds",
+            format!(
+                "{:.0}×{:.0} at ({:.0}, {:.0})",
+                f32::from(bounds.size.width),
+                f32::from(bounds.size.height),
+                f32::from(bounds.origin.x),
+                f32::from(bounds.origin.y),
+            ),
+        )
+        .into_any_element(),
+        render_property(
+            "Padding",
+            format!(
+                "{:.0} {:.0} {:.0} {:.0}",
+                f32::from(padding.top),
+                f32::from(padding.right),
+                f32::from(padding.bottom),
+                f32::from(padding.left),
+            ),
+        )
+        .into_any_element(),
+    ];
+
+    if element.components.is_empty() {
+        children.push(
+            Label::new("Not rendered by a component")
+                .size(LabelSize::XSmall)
+                .color(Color::Muted)
+                .into_any_element(),
+        );
+    }
+    for (depth, component) in element.components.into_iter().enumerate() {
+        children.push(
+            v_flex()
+                .pl(px(8. * depth as f32))
+                .child(Label::new(short_type_name(component.name)).size(LabelSize::XSmall))
+                .children(component.theme_tokens.into_iter().map(|token| {
+                    h_flex()
+                        .pl_2()
+                        .gap_1()
+                        .child(
+                            div()
+                                .size_2()
+                                .border_1()
+                                .border_color(border_color)
+                                .bg(token.value),
+                        )
+                        .child(
+                            Label::new(token.name)
+                                .size(LabelSize::XSmall)
+                                .color(Color::Muted),
+                        )
+                }))
+                .into_any_element(),
+        );
+    }
+    children
+}
+
+impl Render for InspectorPanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let element = cx.inspected_element();
+        v_flex()
+            .absolute()
+            .bottom_2()
+            .right_2()
+            .w_80()
+            .max_h_96()
+            .overflow_hidden()
+            .p_2()
+            .gap_1()
+            .elevation_3(cx)
+            .child(Label::new("Inspector").size(LabelSize::Small))
+            .map(|this| match element {
+                Some(element) => this.children(render_element(element, cx)),
+                None => this.child(
+                    Label::new("Click an element to inspect it")
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                ),
+            })
+    }
+}
@@ -1,5 +1,8 @@
 use gpui::{AnyView, DismissEvent, FocusHandle, ManagedView, Subscription, View};
-use ui::prelude::*;
+use ui::{
+    prelude::*,
+    utils::{register_dismissible, DismissRegistration},
+};
 
 pub enum DismissDecision {
     Dismiss(bool),
@@ -39,6 +42,7 @@ impl<V: ModalView> ModalViewHandle for View<V> {
 pub struct ActiveModal {
     modal: Box<dyn ModalViewHandle>,
     _subscriptions: [Subscription; 2],
+    _dismiss_registration: DismissRegistration,
     previous_focus_handle: Option<FocusHandle>,
     focus_handle: FocusHandle,
 }
@@ -83,6 +87,14 @@ impl ModalLayer {
         V: ModalView,
     {
         let focus_handle = cx.focus_handle();
+        let modal_layer = cx.view().downgrade();
+        let dismiss_registration = register_dismissible(&focus_handle, cx, move |cx| {
+            modal_layer
+                .update(cx, |this, cx| {
+                    this.hide_modal(cx);
+                })
+                .ok();
+        });
         self.active_modal = Some(ActiveModal {
             modal: Box::new(new_modal.clone()),
             _subscriptions: [
@@ -95,6 +107,7 @@ impl ModalLayer {
                     }
                 }),
             ],
+            _dismiss_registration: dismiss_registration,
             previous_focus_handle: cx.focused(),
             focus_handle,
         });
@@ -0,0 +1,270 @@
+use std::rc::Rc;
+
+use chrono::{DateTime, Local};
+use gpui::{
+    AppContext, Context, EventEmitter, Global, Model, ModelContext, SharedString, WindowContext,
+};
+use ui::{Color, IconName};
+
+/// How many notifications the center keeps, dropping the oldest ones past it.
+const MAX_NOTIFICATIONS: usize = 200;
+
+pub fn init(cx: &mut AppContext) {
+    let center = cx.new_model(|_| NotificationCenter::default());
+    cx.set_global(GlobalNotificationCenter(center));
+}
+
+/// How important a notification is, which sets its icon.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotificationLevel {
+    pub fn icon(self) -> IconName {
+        match self {
+            NotificationLevel::Info => IconName::Bell,
+            NotificationLevel::Success => IconName::Check,
+            NotificationLevel::Warning => IconName::Warning,
+            NotificationLevel::Error => IconName::XCircle,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            NotificationLevel::Info => Color::Muted,
+            NotificationLevel::Success => Color::Success,
+            NotificationLevel::Warning => Color::Warning,
+            NotificationLevel::Error => Color::Error,
+        }
+    }
+}
+
+/// A button shown on a notification in the notification center.
+#[derive(Clone)]
+pub struct NotificationAction {
+    pub label: SharedString,
+    pub handler: Rc<dyn Fn(&mut WindowContext)>,
+}
+
+/// A notification posted to the [`NotificationCenter`], which keeps it after any
+/// toast showing it is gone.
+///
+/// ```ignore
+/// NotificationCenter::post(
+///     RichNotification::new("Extensions", "Installed 3 extension updates")
+///         .level(NotificationLevel::Success)
+///         .action("Show Extensions", |cx| cx.dispatch_action(Box::new(Extensions))),
+///     cx,
+/// );
+/// ```
+#[derive(Clone)]
+pub struct RichNotification {
+    /// The feature that posted the notification, which the center groups by.
+    pub source: SharedString,
+    pub title: SharedString,
+    pub body: Option<SharedString>,
+    pub level: NotificationLevel,
+    pub actions: Vec<NotificationAction>,
+}
+
+impl RichNotification {
+    pub fn new(source: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            source: source.into(),
+            title: title.into(),
+            body: None,
+            level: NotificationLevel::default(),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn body(mut self, body: impl Into<SharedString>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn level(mut self, level: NotificationLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Adds a button, which runs `handler` and then dismisses the notification.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        self.actions.push(NotificationAction {
+            label: label.into(),
+            handler: Rc::new(handler),
+        });
+        self
+    }
+}
+
+/// A notification in the history of the [`NotificationCenter`].
+#[derive(Clone)]
+pub struct PostedNotification {
+    pub id: usize,
+    pub notification: RichNotification,
+    pub posted_at: DateTime<Local>,
+    pub read: bool,
+}
+
+pub enum NotificationCenterEvent {
+    Posted(usize),
+}
+
+/// The history of the notifications posted in this session, shown in the
+/// notification center panel.
+#[derive(Default)]
+pub struct NotificationCenter {
+    /// From the oldest to the newest.
+    notifications: Vec<PostedNotification>,
+    next_id: usize,
+}
+
+struct GlobalNotificationCenter(Model<NotificationCenter>);
+
+impl Global for GlobalNotificationCenter {}
+
+impl EventEmitter<NotificationCenterEvent> for NotificationCenter {}
+
+impl NotificationCenter {
+    /// Returns the notification center, if [`init`] was called.
+    pub fn global(cx: &AppContext) -> Option<Model<Self>> {
+        Some(cx.try_global::<GlobalNotificationCenter>()?.0.clone())
+    }
+
+    /// Adds a notification to the history, and returns its id.
+    pub fn post(notification: RichNotification, cx: &mut AppContext) -> Option<usize> {
+        let center = Self::global(cx)?;
+        Some(center.update(cx, |center, cx| center.push(notification, cx)))
+    }
+
+    fn push(&mut self, notification: RichNotification, cx: &mut ModelContext<Self>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.notifications.push(PostedNotification {
+            id,
+            notification,
+            posted_at: Local::now(),
+            read: false,
+        });
+        if self.notifications.len() > MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+        }
+        cx.emit(NotificationCenterEvent::Posted(id));
+        cx.notify();
+        id
+    }
+
+    /// The notifications, from the oldest to the newest.
+    pub fn notifications(&self) -> &[PostedNotification] {
+        &self.notifications
+    }
+
+    /// The notifications grouped by their source, the group with the newest
+    /// notification first, and the newest notification first within groups.
+    pub fn groups(&self) -> Vec<(SharedString, Vec<&PostedNotification>)> {
+        let mut groups: Vec<(SharedString, Vec<&PostedNotification>)> = Vec::new();
+        for posted in self.notifications.iter().rev() {
+            let source = &posted.notification.source;
+            match groups.iter_mut().find(|(group, _)| group == source) {
+                Some((_, notifications)) => notifications.push(posted),
+                None => groups.push((source.clone(), vec![posted])),
+            }
+        }
+        groups
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.notifications
+            .iter()
+            .filter(|posted| !posted.read)
+            .count()
+    }
+
+    pub fn mark_all_read(&mut self, cx: &mut ModelContext<Self>) {
+        if self.unread_count() > 0 {
+            for posted in &mut self.notifications {
+                posted.read = true;
+            }
+            cx.notify();
+        }
+    }
+
+    pub fn dismiss(&mut self, id: usize, cx: &mut ModelContext<Self>) {
+        self.notifications.retain(|posted| posted.id != id);
+        cx.notify();
+    }
+
+    pub fn clear_source(&mut self, source: &SharedString, cx: &mut ModelContext<Self>) {
+        self.notifications
+            .retain(|posted| &posted.notification.source != source);
+        cx.notify();
+    }
+
+    pub fn clear_all(&mut self, cx: &mut ModelContext<Self>) {
+        self.notifications.clear();
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[gpui::test]
+    fn test_notification_center(cx: &mut AppContext) {
+        init(cx);
+        let center = NotificationCenter::global(cx).unwrap();
+        NotificationCenter::post(RichNotification::new("Git", "Pushed main"), cx);
+        let error = NotificationCenter::post(
+            RichNotification::new("Extensions", "Failed to install")
+                .level(NotificationLevel::Error),
+            cx,
+        )
+        .unwrap();
+        NotificationCenter::post(RichNotification::new("Git", "Pulled main"), cx);
+
+        center.update(cx, |center, cx| {
+            assert_eq!(center.unread_count(), 3);
+            let groups = center
+                .groups()
+                .into_iter()
+                .map(|(source, notifications)| {
+                    let titles = notifications
+                        .iter()
+                        .map(|posted| posted.notification.title.to_string())
+                        .collect::<Vec<_>>();
+                    (source.to_string(), titles)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                groups,
+                vec![
+                    (
+                        "Git".to_string(),
+                        vec!["Pulled main".to_string(), "Pushed main".to_string()]
+                    ),
+                    (
+                        "Extensions".to_string(),
+                        vec!["Failed to install".to_string()]
+                    ),
+                ]
+            );
+
+            center.mark_all_read(cx);
+            assert_eq!(center.unread_count(), 0);
+            center.dismiss(error, cx);
+            center.clear_source(&"Git".into(), cx);
+            assert!(center.notifications().is_empty());
+        });
+    }
+}
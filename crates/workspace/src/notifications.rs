@@ -1,3 +1,4 @@
+use crate::notification_center::{NotificationCenter, NotificationLevel, RichNotification};
 use crate::{Toast, Workspace};
 use collections::HashMap;
 use gpui::{
@@ -154,6 +155,11 @@ impl Workspace {
     {
         struct WorkspaceErrorNotification;
 
+        NotificationCenter::post(
+            RichNotification::new("Errors", format!("{err:#}")).level(NotificationLevel::Error),
+            cx,
+        );
+
         self.show_notification(
             NotificationId::unique::<WorkspaceErrorNotification>(),
             cx,
@@ -180,6 +186,11 @@ impl Workspace {
 
     pub fn show_toast(&mut self, toast: Toast, cx: &mut ViewContext<Self>) {
         self.dismiss_notification(&toast.id, cx);
+        let mut notification = RichNotification::new("General", toast.msg.to_string());
+        if let Some((click_msg, on_click)) = toast.on_click.clone() {
+            notification = notification.action(click_msg.to_string(), move |cx| on_click(cx));
+        }
+        NotificationCenter::post(notification, cx);
         self.show_notification(toast.id.clone(), cx, |cx| {
             cx.new_view(|_cx| match toast.on_click.as_ref() {
                 Some((click_msg, on_click)) => {
@@ -340,7 +351,7 @@ impl Render for LanguageServerPrompt {
                                                 ))
                                             }
                                         })
-                                        .tooltip(|cx| Tooltip::text("Copy", cx))
+                                        .tooltip(|cx| Tooltip::text(ui::t!(cx, "common.copy"), cx))
                                         .visible_on_hover(""),
                                 ),
                             )
@@ -424,9 +435,11 @@ impl Render for ErrorMessagePrompt {
                             ),
                     )
                     .child(
-                        div()
-                            .max_w_80()
-                            .child(Label::new(self.message.clone()).size(LabelSize::Small)),
+                        div().max_w_80().child(
+                            Label::new(self.message.clone())
+                                .size(LabelSize::Small)
+                                .selectable("message"),
+                        ),
                     )
                     .when_some(self.label_and_url_button.clone(), |elm, (label, url)| {
                         elm.child(
@@ -43,12 +43,13 @@ use theme::ThemeSettings;
 use ui::{
     prelude::*, right_click_menu, ButtonSize, Color, IconButton, IconButtonShape, IconName,
     IconSize, Indicator, Label, PopoverMenu, PopoverMenuHandle, Tab, TabBar, TabPosition, Tooltip,
+    ZoomToggle,
 };
-use ui::{v_flex, ContextMenu};
+use ui::{v_flex, ContextMenu, OverlayLayer};
 use util::{debug_panic, maybe, truncate_and_remove_front, ResultExt};
 
 /// A selected entry in e.g. project panel.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct SelectedEntry {
     pub worktree_id: WorktreeId,
     pub entry_id: ProjectEntryId,
@@ -402,7 +403,7 @@ impl Pane {
                             .trigger(
                                 IconButton::new("plus", IconName::Plus)
                                     .icon_size(IconSize::Small)
-                                    .tooltip(|cx| Tooltip::text("New...", cx)),
+                                    .tooltip(|cx| Tooltip::text(ui::t!(cx, "pane.new"), cx)),
                             )
                             .anchor(AnchorCorner::TopRight)
                             .with_handle(pane.new_item_context_menu_handle.clone())
@@ -435,7 +436,7 @@ impl Pane {
                             .trigger(
                                 IconButton::new("split", IconName::Split)
                                     .icon_size(IconSize::Small)
-                                    .tooltip(|cx| Tooltip::text("Split Pane", cx)),
+                                    .tooltip(|cx| Tooltip::text(ui::t!(cx, "pane.split"), cx)),
                             )
                             .anchor(AnchorCorner::TopRight)
                             .with_handle(pane.split_item_context_menu_handle.clone())
@@ -449,23 +450,13 @@ impl Pane {
                                 .into()
                             }),
                     )
-                    .child({
-                        let zoomed = pane.is_zoomed();
-                        IconButton::new("toggle_zoom", IconName::Maximize)
-                            .icon_size(IconSize::Small)
-                            .selected(zoomed)
-                            .selected_icon(IconName::Minimize)
-                            .on_click(cx.listener(|pane, _, cx| {
+                    .child(
+                        ZoomToggle::new("toggle_zoom", pane.is_zoomed())
+                            .action(Box::new(ToggleZoom))
+                            .on_toggle(cx.listener(|pane, _, cx| {
                                 pane.toggle_zoom(&crate::ToggleZoom, cx);
-                            }))
-                            .tooltip(move |cx| {
-                                Tooltip::for_action(
-                                    if zoomed { "Zoom Out" } else { "Zoom In" },
-                                    &ToggleZoom,
-                                    cx,
-                                )
-                            })
-                    })
+                            })),
+                    )
                     .into_any_element()
                     .into();
                 (None, right_children)
@@ -2275,7 +2266,7 @@ impl Pane {
                     .anchor(AnchorCorner::TopRight)
                     .child(menu.clone()),
             )
-            .with_priority(1),
+            .with_priority(OverlayLayer::Popover.priority()),
         )
     }
 
@@ -2711,10 +2702,8 @@ impl Render for Pane {
                             if has_worktrees {
                                 placeholder
                             } else {
-                                placeholder.child(
-                                    Label::new("Open a file or project to get started.")
-                                        .color(Color::Muted),
-                                )
+                                placeholder
+                                    .child(Label::new(ui::t!(cx, "pane.empty")).color(Color::Muted))
                             }
                         }
                     })
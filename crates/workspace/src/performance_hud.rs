@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use gpui::{actions, FrameProfile, Render, Task, ViewContext};
+use ui::prelude::*;
+
+actions!(debug, [TogglePerformanceHud]);
+
+/// The time a frame can take at 60 frames per second.
+const FRAME_BUDGET: Duration = Duration::from_micros(16_667);
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+const SLOWEST_SPAN_COUNT: usize = 12;
+
+/// An overlay showing the timings of the frames drawn by the window, and the
+/// components that took the longest to draw in the slowest of them.
+///
+/// Frame profiling is enabled on the window while the overlay is shown.
+pub struct PerformanceHud {
+    _refresh: Task<()>,
+}
+
+impl PerformanceHud {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        cx.set_frame_profiling(true);
+        // The overlay is refreshed periodically rather than on every frame, so
+        // that it doesn't keep the window drawing.
+        let refresh = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(REFRESH_INTERVAL).await;
+                if this.update(&mut cx, |_, cx| cx.notify()).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { _refresh: refresh }
+    }
+}
+
+/// Strips the module paths from a type name, e.g. `ui::components::Button`
+/// becomes `Button`.
+pub(crate) fn short_type_name(name: &str) -> String {
+    let mut short_name = String::with_capacity(name.len());
+    let mut segment_start = 0;
+    for (ix, c) in name.char_indices() {
+        if matches!(c, '<' | '>' | ',' | ' ' | '(' | ')' | '&' | '[' | ']') {
+            short_name.push_str(last_path_segment(&name[segment_start..ix]));
+            short_name.push(c);
+            segment_start = ix + c.len_utf8();
+        }
+    }
+    short_name.push_str(last_path_segment(&name[segment_start..]));
+    short_name
+}
+
+fn last_path_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Returns the indices of the spans that took the longest, in the order they
+/// were drawn so that they read as a tree.
+fn slowest_spans(frame: &FrameProfile, count: usize) -> Vec<usize> {
+    let mut span_ixs = (0..frame.spans.len()).collect::<Vec<_>>();
+    span_ixs.sort_by_key(|ix| std::cmp::Reverse(frame.spans[*ix].duration));
+    span_ixs.truncate(count);
+    span_ixs.sort();
+    span_ixs
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.)
+}
+
+fn duration_color(duration: Duration) -> Color {
+    if duration > FRAME_BUDGET {
+        Color::Error
+    } else if duration > FRAME_BUDGET / 2 {
+        Color::Warning
+    } else {
+        Color::Muted
+    }
+}
+
+impl Render for PerformanceHud {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let frames = cx.frame_profiles().cloned().collect::<Vec<_>>();
+        let last_frame = frames.last().cloned().unwrap_or_default();
+        let slowest_frame = frames
+            .iter()
+            .max_by_key(|frame| frame.frame_duration)
+            .cloned()
+            .unwrap_or_default();
+        let average_duration = frames
+            .iter()
+            .map(|frame| frame.frame_duration)
+            .sum::<Duration>()
+            .checked_div(frames.len() as u32)
+            .unwrap_or_default();
+
+        let colors = cx.theme().colors();
+        let status = cx.theme().status();
+        let frame_bars = h_flex()
+            .h_8()
+            .w_full()
+            .items_end()
+            .gap_px()
+            .children(frames.iter().map(|frame| {
+                let fraction =
+                    (frame.frame_duration.as_secs_f32() / (FRAME_BUDGET * 2).as_secs_f32()).min(1.);
+                let color = match duration_color(frame.frame_duration) {
+                    Color::Error => status.error,
+                    Color::Warning => status.warning,
+                    _ => colors.icon_accent,
+                };
+                div().flex_1().h(relative(fraction.max(0.05))).bg(color)
+            }));
+
+        let spans = slowest_spans(&slowest_frame, SLOWEST_SPAN_COUNT)
+            .into_iter()
+            .map(|ix| {
+                let span = &slowest_frame.spans[ix];
+                let fraction = (span.duration.as_secs_f32()
+                    / slowest_frame.frame_duration.as_secs_f32().max(f32::EPSILON))
+                .min(1.);
+                h_flex()
+                    .gap_1()
+                    .child(
+                        div()
+                            .relative()
+                            .flex_1()
+                            .overflow_hidden()
+                            .pl(px(8. * span.depth as f32))
+                            .child(
+                                div()
+                                    .absolute()
+                                    .top_0()
+                                    .bottom_0()
+                                    .left_0()
+                                    .w(relative(fraction))
+                                    .bg(colors.element_selected),
+                            )
+                            .child(
+                                Label::new(short_type_name(span.name))
+                                    .size(LabelSize::XSmall)
+                                    .single_line(),
+                            ),
+                    )
+                    .child(
+                        Label::new(format_duration(span.duration))
+                            .size(LabelSize::XSmall)
+                            .color(duration_color(span.duration)),
+                    )
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .absolute()
+            .top_2()
+            .right_2()
+            .w_80()
+            .p_2()
+            .gap_1()
+            .elevation_3(cx)
+            .child(Label::new("Performance").size(LabelSize::Small))
+            .child(
+                Label::new(format!(
+                    "Frame {} · avg {} · max {}",
+                    format_duration(last_frame.frame_duration),
+                    format_duration(average_duration),
+                    format_duration(slowest_frame.frame_duration),
+                ))
+                .size(LabelSize::XSmall)
+                .color(duration_color(last_frame.frame_duration)),
+            )
+            .child(
+                Label::new(format!(
+                    "Layout {} · Paint {} · {} elements",
+                    format_duration(last_frame.layout_duration),
+                    format_duration(last_frame.paint_duration),
+                    last_frame.element_count,
+                ))
+                .size(LabelSize::XSmall)
+                .color(Color::Muted),
+            )
+            .child(frame_bars)
+            .when(!spans.is_empty(), |this| {
+                this.child(
+                    Label::new("Slowest components in the slowest frame")
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                )
+                .children(spans)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::ProfileSpan;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("ui::components::button::Button"), "Button");
+        assert_eq!(
+            short_type_name("ui::components::list::List<alloc::string::String>"),
+            "List<String>"
+        );
+        assert_eq!(short_type_name("Tab"), "Tab");
+    }
+
+    #[test]
+    fn test_slowest_spans() {
+        let span = |millis| ProfileSpan {
+            name: "",
+            depth: 0,
+            duration: Duration::from_millis(millis),
+        };
+        let frame = FrameProfile {
+            spans: vec![span(1), span(8), span(3), span(5)],
+            ..Default::default()
+        };
+        assert_eq!(slowest_spans(&frame, 2), vec![1, 3]);
+        assert_eq!(slowest_spans(&frame, 10), vec![0, 1, 2, 3]);
+    }
+}
@@ -367,6 +367,12 @@ define_connection! {
     sql!(
         ALTER TABLE ssh_projects RENAME COLUMN path TO paths;
     ),
+    // Add panel order persistence
+    sql!(
+        ALTER TABLE workspaces ADD COLUMN left_dock_panel_order TEXT; // JSON array of panel names
+        ALTER TABLE workspaces ADD COLUMN right_dock_panel_order TEXT; // JSON array of panel names
+        ALTER TABLE workspaces ADD COLUMN bottom_dock_panel_order TEXT; // JSON array of panel names
+    ),
     ];
 }
 
@@ -418,12 +424,15 @@ impl WorkspaceDb {
                     left_dock_visible,
                     left_dock_active_panel,
                     left_dock_zoom,
+                    left_dock_panel_order,
                     right_dock_visible,
                     right_dock_active_panel,
                     right_dock_zoom,
+                    right_dock_panel_order,
                     bottom_dock_visible,
                     bottom_dock_active_panel,
                     bottom_dock_zoom,
+                    bottom_dock_panel_order,
                     window_id
                 FROM workspaces
                 WHERE local_paths = ?
@@ -483,12 +492,15 @@ impl WorkspaceDb {
                     left_dock_visible,
                     left_dock_active_panel,
                     left_dock_zoom,
+                    left_dock_panel_order,
                     right_dock_visible,
                     right_dock_active_panel,
                     right_dock_zoom,
+                    right_dock_panel_order,
                     bottom_dock_visible,
                     bottom_dock_active_panel,
                     bottom_dock_zoom,
+                    bottom_dock_panel_order,
                     window_id
                 FROM workspaces
                 WHERE ssh_project_id = ?
@@ -541,17 +553,20 @@ impl WorkspaceDb {
                                 left_dock_visible,
                                 left_dock_active_panel,
                                 left_dock_zoom,
+                                left_dock_panel_order,
                                 right_dock_visible,
                                 right_dock_active_panel,
                                 right_dock_zoom,
+                                right_dock_panel_order,
                                 bottom_dock_visible,
                                 bottom_dock_active_panel,
                                 bottom_dock_zoom,
+                                bottom_dock_panel_order,
                                 session_id,
                                 window_id,
                                 timestamp
                             )
-                            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, CURRENT_TIMESTAMP)
+                            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, CURRENT_TIMESTAMP)
                             ON CONFLICT DO
                             UPDATE SET
                                 local_paths = ?2,
@@ -559,14 +574,17 @@ impl WorkspaceDb {
                                 left_dock_visible = ?4,
                                 left_dock_active_panel = ?5,
                                 left_dock_zoom = ?6,
-                                right_dock_visible = ?7,
-                                right_dock_active_panel = ?8,
-                                right_dock_zoom = ?9,
-                                bottom_dock_visible = ?10,
-                                bottom_dock_active_panel = ?11,
-                                bottom_dock_zoom = ?12,
-                                session_id = ?13,
-                                window_id = ?14,
+                                left_dock_panel_order = ?7,
+                                right_dock_visible = ?8,
+                                right_dock_active_panel = ?9,
+                                right_dock_zoom = ?10,
+                                right_dock_panel_order = ?11,
+                                bottom_dock_visible = ?12,
+                                bottom_dock_active_panel = ?13,
+                                bottom_dock_zoom = ?14,
+                                bottom_dock_panel_order = ?15,
+                                session_id = ?16,
+                                window_id = ?17,
                                 timestamp = CURRENT_TIMESTAMP
                         );
                         let mut prepared_query = conn.exec_bound(query)?;
@@ -588,31 +606,37 @@ impl WorkspaceDb {
                                 left_dock_visible,
                                 left_dock_active_panel,
                                 left_dock_zoom,
+                                left_dock_panel_order,
                                 right_dock_visible,
                                 right_dock_active_panel,
                                 right_dock_zoom,
+                                right_dock_panel_order,
                                 bottom_dock_visible,
                                 bottom_dock_active_panel,
                                 bottom_dock_zoom,
+                                bottom_dock_panel_order,
                                 session_id,
                                 window_id,
                                 timestamp
                             )
-                            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, CURRENT_TIMESTAMP)
+                            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, CURRENT_TIMESTAMP)
                             ON CONFLICT DO
                             UPDATE SET
                                 ssh_project_id = ?2,
                                 left_dock_visible = ?3,
                                 left_dock_active_panel = ?4,
                                 left_dock_zoom = ?5,
-                                right_dock_visible = ?6,
-                                right_dock_active_panel = ?7,
-                                right_dock_zoom = ?8,
-                                bottom_dock_visible = ?9,
-                                bottom_dock_active_panel = ?10,
-                                bottom_dock_zoom = ?11,
-                                session_id = ?12,
-                                window_id = ?13,
+                                left_dock_panel_order = ?6,
+                                right_dock_visible = ?7,
+                                right_dock_active_panel = ?8,
+                                right_dock_zoom = ?9,
+                                right_dock_panel_order = ?10,
+                                bottom_dock_visible = ?11,
+                                bottom_dock_active_panel = ?12,
+                                bottom_dock_zoom = ?13,
+                                bottom_dock_panel_order = ?14,
+                                session_id = ?15,
+                                window_id = ?16,
                                 timestamp = CURRENT_TIMESTAMP
                         ))?((
                             workspace.id,
@@ -1059,7 +1083,9 @@ impl WorkspaceDb {
 mod tests {
     use super::*;
     use crate::persistence::model::SerializedWorkspace;
-    use crate::persistence::model::{SerializedItem, SerializedPane, SerializedPaneGroup};
+    use crate::persistence::model::{
+        DockData, SerializedItem, SerializedPane, SerializedPaneGroup,
+    };
     use db::open_test_db;
     use gpui::{self};
 
@@ -1277,6 +1303,38 @@ mod tests {
         assert_eq!(workspace, round_trip_workspace.unwrap());
     }
 
+    #[gpui::test]
+    async fn test_dock_panel_order_serialization() {
+        env_logger::try_init().ok();
+
+        let db = WorkspaceDb(open_test_db("test_dock_panel_order_serialization").await);
+
+        let workspace = SerializedWorkspace {
+            id: WorkspaceId(1),
+            location: SerializedWorkspaceLocation::from_local_paths(["/tmp"]),
+            center_group: Default::default(),
+            window_bounds: Default::default(),
+            display: Default::default(),
+            docks: DockStructure {
+                left: DockData {
+                    visible: true,
+                    active_panel: Some("OutlinePanel".to_string()),
+                    zoom: false,
+                    panel_order: vec!["OutlinePanel".to_string(), "ProjectPanel".to_string()],
+                },
+                ..Default::default()
+            },
+            centered_layout: false,
+            session_id: None,
+            window_id: None,
+        };
+
+        db.save_workspace(workspace.clone()).await;
+        let round_trip_workspace = db.workspace_for_roots(&["/tmp"]).unwrap();
+        assert_eq!(round_trip_workspace.docks, workspace.docks);
+        assert!(round_trip_workspace.docks.right.panel_order.is_empty());
+    }
+
     #[gpui::test]
     async fn test_workspace_assignment() {
         env_logger::try_init().ok();
@@ -279,6 +279,8 @@ pub struct DockData {
     pub(crate) visible: bool,
     pub(crate) active_panel: Option<String>,
     pub(crate) zoom: bool,
+    /// The persistent names of the dock's panels, in the order they were arranged in.
+    pub(crate) panel_order: Vec<String>,
 }
 
 impl Column for DockData {
@@ -286,11 +288,15 @@ impl Column for DockData {
         let (visible, next_index) = Option::<bool>::column(statement, start_index)?;
         let (active_panel, next_index) = Option::<String>::column(statement, next_index)?;
         let (zoom, next_index) = Option::<bool>::column(statement, next_index)?;
+        let (panel_order, next_index) = Option::<String>::column(statement, next_index)?;
         Ok((
             DockData {
                 visible: visible.unwrap_or(false),
                 active_panel,
                 zoom: zoom.unwrap_or(false),
+                panel_order: panel_order
+                    .and_then(|order| serde_json::from_str(&order).log_err())
+                    .unwrap_or_default(),
             },
             next_index,
         ))
@@ -301,7 +307,13 @@ impl Bind for DockData {
     fn bind(&self, statement: &Statement, start_index: i32) -> Result<i32> {
         let next_index = statement.bind(&self.visible, start_index)?;
         let next_index = statement.bind(&self.active_panel, next_index)?;
-        statement.bind(&self.zoom, next_index)
+        let next_index = statement.bind(&self.zoom, next_index)?;
+        let panel_order = if self.panel_order.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&self.panel_order)?)
+        };
+        statement.bind(&panel_order, next_index)
     }
 }
 
@@ -1,11 +1,11 @@
 use crate::{ItemHandle, Pane};
 use gpui::{
-    AnyView, Decorations, IntoElement, ParentElement, Render, Styled, Subscription, View,
+    Action, AnyView, Decorations, IntoElement, ParentElement, Render, Styled, Subscription, View,
     ViewContext, WindowContext,
 };
 use std::any::TypeId;
 use theme::CLIENT_SIDE_DECORATION_ROUNDING;
-use ui::{h_flex, prelude::*};
+use ui::{h_flex, prelude::*, ToolbarItem, ToolbarSlot};
 use util::ResultExt;
 
 pub trait StatusItemView: Render {
@@ -14,6 +14,13 @@ pub trait StatusItemView: Render {
         active_pane_item: Option<&dyn crate::ItemHandle>,
         cx: &mut ViewContext<Self>,
     );
+
+    /// The label and action of the item's entry in the status bar's overflow menu,
+    /// which the item collapses into when the window is too narrow to show every
+    /// item. Items without an entry are always shown, and clipped if they don't fit.
+    fn overflow_entry(&self, _cx: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+        None
+    }
 }
 
 trait StatusItemViewHandle: Send {
@@ -24,6 +31,7 @@ trait StatusItemViewHandle: Send {
         cx: &mut WindowContext,
     );
     fn item_type(&self) -> TypeId;
+    fn overflow_entry(&self, cx: &WindowContext) -> Option<(SharedString, Box<dyn Action>)>;
 }
 
 pub struct StatusBar {
@@ -35,12 +43,29 @@ pub struct StatusBar {
 
 impl Render for StatusBar {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let left_items = self
+            .left_items
+            .iter()
+            .map(|item| (ToolbarSlot::Start, item));
+        let right_items = self
+            .right_items
+            .iter()
+            .rev()
+            .map(|item| (ToolbarSlot::End, item));
+        let bar = left_items.chain(right_items).fold(
+            ui::StatusBar::new("status-bar"),
+            |bar, (slot, item)| {
+                let mut toolbar_item = ToolbarItem::new(item.to_any());
+                if let Some((label, action)) = item.overflow_entry(cx) {
+                    toolbar_item = toolbar_item
+                        .overflow_entry(label, move |cx| cx.dispatch_action(action.boxed_clone()));
+                }
+                bar.item(slot, toolbar_item)
+            },
+        );
+
         h_flex()
             .w_full()
-            .justify_between()
-            .gap(Spacing::Large.rems(cx))
-            .py(Spacing::Small.rems(cx))
-            .px(Spacing::Large.rems(cx))
             .bg(cx.theme().colors().status_bar_background)
             .map(|el| match cx.window_decorations() {
                 Decorations::Server => el,
@@ -56,23 +81,7 @@ impl Render for StatusBar {
                     .border_b(px(1.0))
                     .border_color(cx.theme().colors().status_bar_background),
             })
-            .child(self.render_left_tools(cx))
-            .child(self.render_right_tools(cx))
-    }
-}
-
-impl StatusBar {
-    fn render_left_tools(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        h_flex()
-            .gap(Spacing::Large.rems(cx))
-            .overflow_x_hidden()
-            .children(self.left_items.iter().map(|item| item.to_any()))
-    }
-
-    fn render_right_tools(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        h_flex()
-            .gap(Spacing::Large.rems(cx))
-            .children(self.right_items.iter().rev().map(|item| item.to_any()))
+            .child(bar)
     }
 }
 
@@ -197,6 +206,10 @@ impl<T: StatusItemView> StatusItemViewHandle for View<T> {
     fn item_type(&self) -> TypeId {
         TypeId::of::<T>()
     }
+
+    fn overflow_entry(&self, cx: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+        self.read(cx).overflow_entry(cx)
+    }
 }
 
 impl From<&dyn StatusItemViewHandle> for AnyView {
@@ -204,3 +217,71 @@ impl From<&dyn StatusItemViewHandle> for AnyView {
         val.to_any().clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::init_test, Workspace};
+    use fs::FakeFs;
+    use gpui::{size, NoAction, TestAppContext, VisualTestContext};
+    use project::Project;
+
+    struct TestStatusItem {
+        name: &'static str,
+        collapsible: bool,
+    }
+
+    impl Render for TestStatusItem {
+        fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+            div().w(px(100.)).h_4().test_id(self.name)
+        }
+    }
+
+    impl StatusItemView for TestStatusItem {
+        fn set_active_pane_item(&mut self, _: Option<&dyn ItemHandle>, _: &mut ViewContext<Self>) {}
+
+        fn overflow_entry(&self, _: &WindowContext) -> Option<(SharedString, Box<dyn Action>)> {
+            self.collapsible
+                .then(|| (self.name.into(), Box::new(NoAction) as Box<dyn Action>))
+        }
+    }
+
+    #[gpui::test]
+    async fn test_status_bar_collapses_items_in_narrow_window(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+        let status_bar = workspace.update(cx, |workspace, _| workspace.status_bar().clone());
+        status_bar.update(cx, |status_bar, cx| {
+            for (name, collapsible) in [("pinned", false), ("first", true), ("second", true)] {
+                let item = cx.new_view(|_| TestStatusItem { name, collapsible });
+                status_bar.add_left_item(item, cx);
+            }
+        });
+
+        let shown_items = |cx: &mut VisualTestContext| {
+            ["pinned", "first", "second"]
+                .into_iter()
+                .filter(|name| cx.bounds_for_test_id(name).is_some())
+                .collect::<Vec<_>>()
+        };
+
+        cx.simulate_resize(size(px(1000.), px(400.)));
+        cx.run_until_parked();
+        assert_eq!(shown_items(cx), ["pinned", "first", "second"]);
+
+        // The last collapsible item collapses first.
+        cx.simulate_resize(size(px(320.), px(400.)));
+        cx.run_until_parked();
+        assert_eq!(shown_items(cx), ["pinned", "first"]);
+
+        // Items without an overflow entry stay, clipped to the bar.
+        cx.simulate_resize(size(px(100.), px(400.)));
+        cx.run_until_parked();
+        assert_eq!(shown_items(cx), ["pinned"]);
+        let pinned_bounds = cx.bounds_for_test_id("pinned").unwrap();
+        assert_eq!(pinned_bounds.size.width, px(100.));
+    }
+}
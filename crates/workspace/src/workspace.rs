@@ -1,9 +1,12 @@
 pub mod dock;
+mod element_inspector;
 pub mod item;
 mod modal_layer;
+pub mod notification_center;
 pub mod notifications;
 pub mod pane;
 pub mod pane_group;
+mod performance_hud;
 mod persistence;
 pub mod searchable;
 pub mod shared_screen;
@@ -21,22 +24,23 @@ use client::{
 use collections::{hash_map, HashMap, HashSet};
 use derive_more::{Deref, DerefMut};
 use dock::{Dock, DockPosition, Panel, PanelButtons, PanelHandle};
+pub use element_inspector::*;
 use futures::{
     channel::{
         mpsc::{self, UnboundedReceiver, UnboundedSender},
         oneshot,
     },
-    future::try_join_all,
+    future::{try_join_all, LocalBoxFuture},
     Future, FutureExt, StreamExt,
 };
 use gpui::{
-    action_as, actions, canvas, impl_action_as, impl_actions, point, relative, size,
-    transparent_black, Action, AnyView, AnyWeakView, AppContext, AsyncAppContext,
-    AsyncWindowContext, Bounds, CursorStyle, Decorations, DragMoveEvent, Entity as _, EntityId,
-    EventEmitter, Flatten, FocusHandle, FocusableView, Global, Hsla, KeyContext, Keystroke,
-    ManagedView, Model, ModelContext, MouseButton, PathPromptOptions, Point, PromptLevel, Render,
-    ResizeEdge, Size, Stateful, Subscription, Task, Tiling, View, WeakView, WindowBounds,
-    WindowHandle, WindowId, WindowOptions,
+    action_as, actions, canvas, ease_in_out, impl_action_as, impl_actions, point, relative, size,
+    transparent_black, Action, Animation, AnimationExt, AnyView, AnyWeakView, AppContext,
+    AsyncAppContext, AsyncWindowContext, Bounds, CursorStyle, Decorations, DragMoveEvent,
+    Entity as _, EntityId, EventEmitter, Flatten, FocusHandle, FocusableView, Global, Hsla,
+    KeyContext, Keystroke, ManagedView, Model, ModelContext, MouseButton, PathPromptOptions, Point,
+    PromptLevel, Render, ResizeEdge, Size, Stateful, Subscription, Task, Tiling, View, WeakView,
+    WindowBounds, WindowHandle, WindowId, WindowOptions,
 };
 pub use item::{
     FollowableItem, FollowableItemHandle, Item, ItemHandle, ItemSettings, PreviewTabsSettings,
@@ -51,6 +55,7 @@ use notifications::{
 };
 pub use pane::*;
 pub use pane_group::*;
+pub use performance_hud::*;
 pub use persistence::{
     model::{ItemId, LocalPaths, SerializedWorkspaceLocation},
     WorkspaceDb, DB as WORKSPACE_DB,
@@ -323,6 +328,7 @@ pub fn init_settings(cx: &mut AppContext) {
 pub fn init(app_state: Arc<AppState>, cx: &mut AppContext) {
     init_settings(cx);
     notifications::init(cx);
+    notification_center::init(cx);
 
     cx.on_action(Workspace::close_global);
     cx.on_action(reload);
@@ -460,6 +466,45 @@ impl FollowableViewRegistry {
     }
 }
 
+type AddPanel = Box<dyn FnOnce(&mut Workspace, &mut ViewContext<Workspace>)>;
+type LoadPanel = Rc<
+    dyn Fn(WeakView<Workspace>, AsyncWindowContext) -> LocalBoxFuture<'static, Result<AddPanel>>,
+>;
+
+/// The panels features add to the docks of every workspace, loaded with
+/// [`Workspace::load_registered_panels`].
+#[derive(Default)]
+pub struct PanelRegistry(Vec<LoadPanel>);
+
+impl Global for PanelRegistry {}
+
+impl PanelRegistry {
+    /// Registers a panel to load into each workspace. Panels are added to their
+    /// docks in the order they were registered, which is the order of their buttons.
+    pub fn register<T, F>(
+        cx: &mut AppContext,
+        load: impl Fn(WeakView<Workspace>, AsyncWindowContext) -> F + 'static,
+    ) where
+        T: Panel,
+        F: Future<Output = Result<View<T>>> + 'static,
+    {
+        cx.default_global::<Self>()
+            .0
+            .push(Rc::new(move |workspace, cx| {
+                let panel = load(workspace, cx);
+                async move {
+                    let panel = panel.await?;
+                    Ok(Box::new(
+                        move |workspace: &mut Workspace, cx: &mut ViewContext<Workspace>| {
+                            workspace.add_panel(panel, cx)
+                        },
+                    ) as AddPanel)
+                }
+                .boxed_local()
+            }));
+    }
+}
+
 #[derive(Copy, Clone)]
 struct SerializableItemDescriptor {
     deserialize: fn(
@@ -759,6 +804,8 @@ pub struct Workspace {
     pane_history_timestamp: Arc<AtomicUsize>,
     bounds: Bounds<Pixels>,
     centered_layout: bool,
+    performance_hud: Option<View<PerformanceHud>>,
+    inspector_panel: Option<View<InspectorPanel>>,
     bounds_save_task_queued: Option<Task<()>>,
     on_prompt_for_new_path: Option<PromptForNewPath>,
     on_prompt_for_open_path: Option<PromptForOpenPath>,
@@ -1062,6 +1109,8 @@ impl Workspace {
             // This data will be incorrect, but it will be overwritten by the time it needs to be used.
             bounds: Default::default(),
             centered_layout: false,
+            performance_hud: None,
+            inspector_panel: None,
             bounds_save_task_queued: None,
             on_prompt_for_new_path: None,
             on_prompt_for_open_path: None,
@@ -1258,6 +1307,31 @@ impl Workspace {
         });
     }
 
+    /// Loads the panels registered with [`PanelRegistry`] and adds them to the docks
+    /// once they have all loaded. Panels that fail to load are skipped.
+    pub fn load_registered_panels(&self, cx: &mut ViewContext<Self>) {
+        let loaders = cx
+            .try_global::<PanelRegistry>()
+            .map(|registry| registry.0.clone())
+            .unwrap_or_default();
+        cx.spawn(|workspace, mut cx| async move {
+            let panels = futures::future::join_all(
+                loaders
+                    .iter()
+                    .map(|load| load(workspace.clone(), cx.clone())),
+            )
+            .await;
+            workspace
+                .update(&mut cx, |workspace, cx| {
+                    for add_panel in panels.into_iter().filter_map(|panel| panel.log_err()) {
+                        add_panel(workspace, cx);
+                    }
+                })
+                .ok();
+        })
+        .detach();
+    }
+
     pub fn status_bar(&self) -> &View<StatusBar> {
         &self.status_bar
     }
@@ -2237,6 +2311,22 @@ impl Workspace {
         }
     }
 
+    /// Zooms the focused dock panel in, or restores it if it is already zoomed.
+    ///
+    /// Panels that contain a pane handle [`ToggleZoom`] themselves.
+    pub fn toggle_panel_zoom(&mut self, cx: &mut ViewContext<Self>) {
+        for dock in [&self.left_dock, &self.right_dock, &self.bottom_dock] {
+            let contains_focus = dock
+                .read(cx)
+                .visible_panel()
+                .map_or(false, |panel| panel.focus_handle(cx).contains_focused(cx));
+            if contains_focus {
+                dock.update(cx, |dock, cx| dock.toggle_active_panel_zoom(cx));
+                return;
+            }
+        }
+    }
+
     pub fn toggle_dock(&mut self, dock_side: DockPosition, cx: &mut ViewContext<Self>) {
         let dock = match dock_side {
             DockPosition::Left => &self.left_dock,
@@ -2408,7 +2498,7 @@ impl Workspace {
             dock.update(cx, |dock, cx| {
                 if Some(dock.position()) != dock_to_reveal {
                     if let Some(panel) = dock.active_panel() {
-                        if panel.is_zoomed(cx) {
+                        if dock.is_panel_zoomed(panel.panel_id(), cx) {
                             focus_center |= panel.focus_handle(cx).contains_focused(cx);
                             dock.set_open(false, cx);
                         }
@@ -4085,46 +4175,43 @@ impl Workspace {
             let left_active_panel = left_dock
                 .visible_panel()
                 .map(|panel| panel.persistent_name().to_string());
-            let left_dock_zoom = left_dock
-                .visible_panel()
-                .map(|panel| panel.is_zoomed(cx))
-                .unwrap_or(false);
+            let left_dock_zoom = left_dock.zoomed_panel(cx).is_some();
+            let left_panel_order = left_dock.panel_order();
 
             let right_dock = this.right_dock.read(cx);
             let right_visible = right_dock.is_open();
             let right_active_panel = right_dock
                 .visible_panel()
                 .map(|panel| panel.persistent_name().to_string());
-            let right_dock_zoom = right_dock
-                .visible_panel()
-                .map(|panel| panel.is_zoomed(cx))
-                .unwrap_or(false);
+            let right_dock_zoom = right_dock.zoomed_panel(cx).is_some();
+            let right_panel_order = right_dock.panel_order();
 
             let bottom_dock = this.bottom_dock.read(cx);
             let bottom_visible = bottom_dock.is_open();
             let bottom_active_panel = bottom_dock
                 .visible_panel()
                 .map(|panel| panel.persistent_name().to_string());
-            let bottom_dock_zoom = bottom_dock
-                .visible_panel()
-                .map(|panel| panel.is_zoomed(cx))
-                .unwrap_or(false);
+            let bottom_dock_zoom = bottom_dock.zoomed_panel(cx).is_some();
+            let bottom_panel_order = bottom_dock.panel_order();
 
             DockStructure {
                 left: DockData {
                     visible: left_visible,
                     active_panel: left_active_panel,
                     zoom: left_dock_zoom,
+                    panel_order: left_panel_order,
                 },
                 right: DockData {
                     visible: right_visible,
                     active_panel: right_active_panel,
                     zoom: right_dock_zoom,
+                    panel_order: right_panel_order,
                 },
                 bottom: DockData {
                     visible: bottom_visible,
                     active_panel: bottom_active_panel,
                     zoom: bottom_dock_zoom,
+                    panel_order: bottom_panel_order,
                 },
             }
         }
@@ -4331,6 +4418,16 @@ impl Workspace {
 
     fn actions(&self, div: Div, cx: &mut ViewContext<Self>) -> Div {
         self.add_workspace_actions_listeners(div, cx)
+            .capture_action(|_: &menu::Cancel, cx| {
+                if ui::utils::dismiss_tooltip(cx) {
+                    cx.stop_propagation();
+                }
+            })
+            .on_action(|_: &menu::Cancel, cx| {
+                if !ui::utils::dismiss_innermost(cx) {
+                    cx.propagate();
+                }
+            })
             .on_action(cx.listener(Self::close_inactive_items_and_panes))
             .on_action(cx.listener(Self::close_all_items_and_panes))
             .on_action(cx.listener(Self::save_all))
@@ -4385,6 +4482,11 @@ impl Workspace {
                     workspace.toggle_dock(DockPosition::Bottom, cx);
                 }),
             )
+            .on_action(
+                cx.listener(|workspace: &mut Workspace, _: &ToggleZoom, cx| {
+                    workspace.toggle_panel_zoom(cx);
+                }),
+            )
             .on_action(
                 cx.listener(|workspace: &mut Workspace, _: &CloseAllDocks, cx| {
                     workspace.close_all_docks(cx);
@@ -4401,6 +4503,8 @@ impl Workspace {
                 }),
             )
             .on_action(cx.listener(Workspace::toggle_centered_layout))
+            .on_action(cx.listener(Workspace::toggle_performance_hud))
+            .on_action(cx.listener(Workspace::toggle_inspector))
     }
 
     #[cfg(any(test, feature = "test-support"))]
@@ -4477,6 +4581,24 @@ impl Workspace {
         cx.notify();
     }
 
+    pub fn toggle_performance_hud(&mut self, _: &TogglePerformanceHud, cx: &mut ViewContext<Self>) {
+        if self.performance_hud.take().is_some() {
+            cx.set_frame_profiling(false);
+        } else {
+            self.performance_hud = Some(cx.new_view(PerformanceHud::new));
+        }
+        cx.notify();
+    }
+
+    pub fn toggle_inspector(&mut self, _: &ToggleInspector, cx: &mut ViewContext<Self>) {
+        if self.inspector_panel.take().is_some() {
+            cx.set_inspector_enabled(false);
+        } else {
+            self.inspector_panel = Some(cx.new_view(InspectorPanel::new));
+        }
+        cx.notify();
+    }
+
     fn adjust_padding(padding: Option<f32>) -> f32 {
         padding
             .unwrap_or(Self::DEFAULT_PADDING)
@@ -4865,16 +4987,27 @@ impl Render for Workspace {
                                         .inset_0()
                                         .shadow_lg();
 
-                                    Some(match self.zoomed_position {
+                                    let div = match self.zoomed_position {
                                         Some(DockPosition::Left) => div.right_2().border_r_1(),
                                         Some(DockPosition::Right) => div.left_2().border_l_1(),
                                         Some(DockPosition::Bottom) => div.top_2().border_t_1(),
                                         None => {
                                             div.top_2().bottom_2().left_2().right_2().border_1()
                                         }
-                                    })
+                                    };
+
+                                    Some(
+                                        div.with_animation(
+                                            "zoomed-view",
+                                            Animation::new(Duration::from_millis(150))
+                                                .with_easing(ease_in_out),
+                                            |div, delta| div.opacity(delta),
+                                        ),
+                                    )
                                 }))
-                                .children(self.render_notifications(cx)),
+                                .children(self.render_notifications(cx))
+                                .children(self.performance_hud.clone())
+                                .children(self.inspector_panel.clone()),
                         )
                         .child(self.status_bar.clone())
                         .child(self.modal_layer.clone()),
@@ -6115,7 +6248,10 @@ pub fn move_item(
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::RefCell, rc::Rc};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
 
     use super::*;
     use crate::{
@@ -6133,6 +6269,7 @@ mod tests {
     use project::{Project, ProjectEntryId};
     use serde_json::json;
     use settings::SettingsStore;
+    use ui::ContextMenu;
 
     #[gpui::test]
     async fn test_tab_disambiguation(cx: &mut TestAppContext) {
@@ -6887,6 +7024,74 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    async fn test_zoom_dock_panel_and_move_focus(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        let panel = workspace.update(cx, |workspace, cx| {
+            let panel = cx.new_view(|cx| TestPanel::new(DockPosition::Left, cx));
+            workspace.add_panel(panel.clone(), cx);
+            panel
+        });
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        pane.update(cx, |pane, cx| {
+            let item = cx.new_view(TestItem::new);
+            pane.add_item(Box::new(item), true, true, None, cx);
+        });
+
+        // Zoom the panel from within it.
+        workspace.update(cx, |workspace, cx| {
+            workspace.toggle_panel_focus::<TestPanel>(cx);
+        });
+        cx.dispatch_action(ToggleZoom);
+        // The dock keeps the panel zoomed for panels that don't keep track of it.
+        panel.update(cx, |panel, _| panel.zoomed = false);
+        workspace.update(cx, |workspace, cx| {
+            let left_dock = workspace.left_dock().read(cx);
+            assert!(left_dock.is_open());
+            assert!(left_dock.is_panel_zoomed(panel.entity_id(), cx));
+            assert_eq!(workspace.zoomed, Some(panel.to_any().downgrade()));
+            assert_eq!(workspace.zoomed_position, Some(DockPosition::Left));
+        });
+
+        // Focusing the center hides the zoomed dock, which keeps the panel zoomed.
+        pane.update(cx, |pane, cx| pane.focus(cx));
+        workspace.update(cx, |workspace, cx| {
+            let left_dock = workspace.left_dock().read(cx);
+            assert!(!left_dock.is_open());
+            assert!(left_dock.is_panel_zoomed(panel.entity_id(), cx));
+            assert!(!panel.read(cx).focus_handle(cx).contains_focused(cx));
+            assert_eq!(workspace.zoomed, None);
+            assert_eq!(workspace.zoomed_position, None);
+        });
+
+        // Focusing the panel again shows it zoomed.
+        workspace.update(cx, |workspace, cx| {
+            workspace.toggle_panel_focus::<TestPanel>(cx);
+        });
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.left_dock().read(cx).is_open());
+            assert!(panel.read(cx).focus_handle(cx).contains_focused(cx));
+            assert_eq!(workspace.zoomed, Some(panel.to_any().downgrade()));
+            assert_eq!(workspace.zoomed_position, Some(DockPosition::Left));
+        });
+
+        // Toggling the zoom again restores the panel.
+        cx.dispatch_action(ToggleZoom);
+        workspace.update(cx, |workspace, cx| {
+            let left_dock = workspace.left_dock().read(cx);
+            assert!(left_dock.is_open());
+            assert!(!left_dock.is_panel_zoomed(panel.entity_id(), cx));
+            assert!(!panel.is_zoomed(cx));
+            assert_eq!(workspace.zoomed, None);
+            assert_eq!(workspace.zoomed_position, None);
+        });
+    }
+
     #[gpui::test]
     async fn test_join_pane_into_next(cx: &mut gpui::TestAppContext) {
         init_test(cx);
@@ -7122,6 +7327,107 @@ mod tests {
         }
     }
 
+    /// A modal without its own `menu::Cancel` handler, which can show a context menu.
+    struct MenuModal {
+        focus_handle: FocusHandle,
+        menu: Option<View<ContextMenu>>,
+    }
+
+    impl MenuModal {
+        fn new(cx: &mut ViewContext<Self>) -> Self {
+            Self {
+                focus_handle: cx.focus_handle(),
+                menu: None,
+            }
+        }
+
+        fn show_menu(&mut self, cx: &mut ViewContext<Self>) {
+            let menu = ContextMenu::build(cx, |menu, _| menu.entry("One", None, |_| {}));
+            cx.subscribe(&menu, |this, _, _: &DismissEvent, cx| {
+                this.menu = None;
+                cx.focus(&this.focus_handle);
+                cx.notify();
+            })
+            .detach();
+            cx.focus_view(&menu);
+            self.menu = Some(menu);
+            cx.notify();
+        }
+    }
+
+    impl EventEmitter<DismissEvent> for MenuModal {}
+
+    impl FocusableView for MenuModal {
+        fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
+            self.focus_handle.clone()
+        }
+    }
+
+    impl ModalView for MenuModal {}
+
+    impl Render for MenuModal {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div()
+                .track_focus(&self.focus_handle)
+                .children(self.menu.clone())
+        }
+    }
+
+    #[gpui::test]
+    async fn test_escape_dismisses_innermost_surface(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        workspace.update(cx, |workspace, cx| {
+            workspace.toggle_modal(cx, MenuModal::new)
+        });
+        cx.run_until_parked();
+        let modal = workspace.update(cx, |workspace, cx| {
+            workspace.active_modal::<MenuModal>(cx).unwrap()
+        });
+        modal.update(cx, |modal, cx| modal.show_menu(cx));
+        cx.run_until_parked();
+
+        // The menu shown in the modal closes first.
+        cx.dispatch_action(menu::Cancel);
+        cx.run_until_parked();
+        assert!(modal.read_with(cx, |modal, _| modal.menu.is_none()));
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.active_modal::<MenuModal>(cx).is_some());
+        });
+
+        cx.dispatch_action(menu::Cancel);
+        cx.run_until_parked();
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.active_modal::<MenuModal>(cx).is_none());
+        });
+    }
+
+    #[gpui::test]
+    async fn test_escape_without_open_surface(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        let cancel_count = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            let cancel_count = cancel_count.clone();
+            cx.on_action(move |_: &menu::Cancel, _| cancel_count.set(cancel_count.get() + 1));
+        });
+
+        // Menus built ahead of being shown, like those of dropdowns, don't take Escape.
+        let _menu = workspace.update(cx, |_, cx| {
+            ContextMenu::build(cx, |menu, _| menu.entry("One", None, |_| {}))
+        });
+        cx.run_until_parked();
+
+        cx.dispatch_action(menu::Cancel);
+        assert_eq!(cancel_count.get(), 1);
+    }
+
     #[gpui::test]
     async fn test_panels(cx: &mut gpui::TestAppContext) {
         init_test(cx);
@@ -374,6 +374,7 @@ fn main() {
 
         SystemAppearance::init(cx);
         theme::init(theme::LoadThemes::All(Box::new(Assets)), cx);
+        ui::i18n::init(cx);
         command_palette::init(cx);
         let copilot_language_server_id = app_state.languages.next_language_server_id();
         copilot::init(
@@ -392,7 +393,7 @@ fn main() {
         );
         snippet_provider::init(cx);
         inline_completion_registry::init(app_state.client.telemetry().clone(), cx);
-        let prompt_builder = assistant::init(
+        assistant::init(
             app_state.fs.clone(),
             app_state.client.clone(),
             stdout_is_a_pty(),
@@ -433,6 +434,7 @@ fn main() {
         project_symbols::init(cx);
         project_panel::init(Assets, cx);
         outline_panel::init(Assets, cx);
+        notification_center::init(cx);
         tasks_ui::init(cx);
         snippets_ui::init(cx);
         channel::init(&app_state.client.clone(), app_state.user_store.clone(), cx);
@@ -489,7 +491,7 @@ fn main() {
         watch_file_types(fs.clone(), cx);
 
         cx.set_menus(app_menus());
-        initialize_workspace(app_state.clone(), prompt_builder, cx);
+        initialize_workspace(app_state.clone(), cx);
 
         cx.activate(true);
 
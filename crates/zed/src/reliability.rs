@@ -13,9 +13,10 @@ use release_channel::RELEASE_CHANNEL;
 use settings::Settings;
 use smol::stream::StreamExt;
 use std::{
+    collections::BTreeSet,
     env,
     ffi::OsStr,
-    sync::{atomic::Ordering, Arc},
+    sync::{atomic::Ordering, Arc, Mutex},
 };
 use std::{io::Write, panic, sync::atomic::AtomicU32, thread};
 use telemetry_events::LocationData;
@@ -25,6 +26,7 @@ use util::ResultExt;
 
 use crate::stdout_is_a_pty;
 static PANIC_COUNT: AtomicU32 = AtomicU32::new(0);
+static REPORTED_CAUGHT_PANICS: Mutex<BTreeSet<Option<(String, u32)>>> = Mutex::new(BTreeSet::new());
 
 pub fn init_panic_hook(
     app_version: SemanticVersion,
@@ -35,11 +37,24 @@ pub fn init_panic_hook(
     let is_pty = stdout_is_a_pty();
 
     panic::set_hook(Box::new(move |info| {
-        let prior_panic_count = PANIC_COUNT.fetch_add(1, Ordering::SeqCst);
-        if prior_panic_count > 0 {
-            // Give the panic-ing thread time to write the panic file
-            loop {
-                std::thread::yield_now();
+        // A panic while rendering in an error boundary unwinds to the boundary, which
+        // shows an error instead of the content. It is still reported, once per
+        // location, but doesn't abort.
+        let caught = ui::is_rendering_in_error_boundary();
+        if caught {
+            let location = info
+                .location()
+                .map(|location| (location.file().to_string(), location.line()));
+            if !REPORTED_CAUGHT_PANICS.lock().unwrap().insert(location) {
+                return;
+            }
+        } else {
+            let prior_panic_count = PANIC_COUNT.fetch_add(1, Ordering::SeqCst);
+            if prior_panic_count > 0 {
+                // Give the panic-ing thread time to write the panic file
+                loop {
+                    std::thread::yield_now();
+                }
             }
         }
 
@@ -65,6 +80,9 @@ pub fn init_panic_hook(
                 location.column(),
                 backtrace,
             );
+            if caught {
+                return;
+            }
             std::process::exit(-1);
         }
 
@@ -114,7 +132,15 @@ pub fn init_panic_hook(
         if !is_pty {
             if let Some(panic_data_json) = serde_json::to_string(&panic_data).log_err() {
                 let timestamp = chrono::Utc::now().format("%Y_%m_%d %H_%M_%S").to_string();
-                let panic_file_path = paths::logs_dir().join(format!("zed-{timestamp}.panic"));
+                // Each panic file holds a single panic, and caught panics can happen
+                // within the same second.
+                let file_name = if caught {
+                    let count = REPORTED_CAUGHT_PANICS.lock().unwrap().len();
+                    format!("zed-{timestamp}-caught-{count}.panic")
+                } else {
+                    format!("zed-{timestamp}.panic")
+                };
+                let panic_file_path = paths::logs_dir().join(file_name);
                 let panic_file = std::fs::OpenOptions::new()
                     .append(true)
                     .create(true)
@@ -127,6 +153,9 @@ pub fn init_panic_hook(
             }
         }
 
+        if caught {
+            return;
+        }
         std::process::abort();
     }));
 }